@@ -39,6 +39,8 @@
 #[macro_use]
 extern crate test_ur_code_xd;
 
+use test_ur_code_xd::utilities::failure_behavior::{set_failure_behavior, FailureBehavior};
+
 #[allow(
     // Because some assertions will be commented out
     unused_variables,
@@ -46,6 +48,9 @@ extern crate test_ur_code_xd;
     clippy::print_stdout
 )]
 fn main() {
+    // Print failures and exit instead of panicking, since this is not a test
+    set_failure_behavior(FailureBehavior::Exit(1));
+
     let x = 5;
     println!();
     println!();
@@ -15,9 +15,9 @@
 
 //! Error types for test ur code XD macros.
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use quote::quote_spanned;
-use syn::{spanned::Spanned, Expr, FnArg, PatType, Receiver};
+use syn::{spanned::Spanned, Expr, FnArg, LitStr, Pat, PatType, Receiver};
 use thiserror::Error;
 
 /// A general error type for test ur code XD.
@@ -48,7 +48,8 @@ pub enum TestUrCodeXDMacroError {
     #[error("argument has no parameter set")]
     ArgumentHasNoParameter(PatType),
 
-    /// An error that occurs when the left-hand side of a parameter is not a simple identifier.
+    /// An error that occurs when the left-hand side of a parameter is not a simple identifier or a
+    /// tuple of identifiers.
     ///
     /// # Example
     ///
@@ -56,7 +57,7 @@ pub enum TestUrCodeXDMacroError {
     /// # use test_ur_code_xd_macro as test_ur_code_xd;
     /// #
     /// #[test_ur_code_xd::test_with_parameter_values(
-    ///     (x, y) = [1, 2]
+    ///     x.y = [1, 2]
     /// )]
     /// fn example(x: i32, y: i32) {
     ///     // ...
@@ -65,7 +66,8 @@ pub enum TestUrCodeXDMacroError {
     #[error("parameter's assignment left hand side is not an identifier")]
     ParameterAssignmentLeftHandSideIsNotIdentifier(Expr),
 
-    /// An error that occurs when the right-hand side of a parameter is not an array literal.
+    /// An error that occurs when the right-hand side of a parameter is not an array literal, a range,
+    /// a path, `from(...)`, or `boundaries(...)`.
     ///
     /// # Example
     ///
@@ -73,15 +75,327 @@ pub enum TestUrCodeXDMacroError {
     /// # use test_ur_code_xd_macro as test_ur_code_xd;
     /// #
     /// #[test_ur_code_xd::test_with_parameter_values(
-    ///     (x, y) = [1, 2]
+    ///     x = 5
     /// )]
-    /// fn example(x: i32, y: i32) {
+    /// fn example(x: i32) {
     ///     // ...
     /// }
     /// ```
     #[error("parameter's assignment right hand side is not an array literal")]
     ParameterAssignmentRightHandSideIsNotArrayLiteral(Expr),
 
+    /// An error that occurs when a range parameter is missing a bound or has a bound that is not an
+    /// integer literal.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = 0..
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("parameter's range bounds are not integer literals")]
+    RangeBoundsAreNotIntegerLiterals(Expr),
+
+    /// An error that occurs when a value of a tuple parameter is not a tuple with one element for
+    /// each name on the left-hand side.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     (x, expected) = [(1, 2), (3, 4, 5)]
+    /// )]
+    /// fn example(x: i32, expected: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("tuple parameter's value does not have one element for each name")]
+    TupleParameterValueDoesNotMatchNames(Expr),
+
+    /// An error that occurs when a `from(...)` parameter does not have exactly one argument.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = from(CASES, OTHER_CASES)
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("parameter's `from` collection does not have exactly one argument")]
+    ParameterCollectionHasWrongArgumentCount(Expr),
+
+    /// An error that occurs when a `boundaries(...)` parameter does not have exactly one argument.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = boundaries(i32, u32)
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("parameter's `boundaries` does not have exactly one argument")]
+    BoundariesHaveWrongArgumentCount(Expr),
+
+    /// An error that occurs when a `boundaries(...)` parameter is given a type that has no
+    /// boundary values.
+    ///
+    /// Boundary values exist for integer types, `f32`, `f64`, `bool`, and `char`.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = boundaries(String)
+    /// )]
+    /// fn example(x: String) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("parameter's `boundaries` type is not supported")]
+    BoundariesTypeIsNotSupported(Expr),
+
+    /// An error that occurs when `mode` is not given `permute` or `zip`.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     mode = diagonal
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`mode` is not recognized")]
+    ModeIsNotRecognized(Expr),
+
+    /// An error that occurs when parameters are zipped but do not all have the same number of
+    /// values.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2, 3],
+    ///     y = [4, 5],
+    ///     mode = zip
+    /// )]
+    /// fn example(x: i32, y: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("zipped parameters have different numbers of values")]
+    ZippedParametersHaveDifferentLengths(Span),
+
+    /// An error that occurs when `name_format` is not given a string literal.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     name_format = x
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`name_format` is not a string literal")]
+    NameFormatIsNotStringLiteral(Expr),
+
+    /// An error that occurs when `max_permutations` is not given a positive integer literal.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     max_permutations = "many"
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`max_permutations` is not a positive integer literal")]
+    MaxPermutationsIsNotPositiveIntegerLiteral(Expr),
+
+    /// An error that occurs when a `{` in `name_format` is not followed by a `}`.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     name_format = "x_{x"
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`name_format` has an unclosed placeholder")]
+    NameFormatHasUnclosedPlaceholder(LitStr),
+
+    /// An error that occurs when a placeholder in `name_format` does not refer to a parameter with
+    /// an array literal value.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     name_format = "y_{y}"
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`name_format` refers to unknown parameter `{1}`")]
+    NameFormatReferencesUnknownParameter(LitStr, String),
+
+    /// An error that occurs when `name_format` produces a name that is not a valid identifier.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [true, false],
+    ///     name_format = "{x}"
+    /// )]
+    /// fn example(x: bool) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`name_format` produces `{1}`, which is not a valid identifier")]
+    NameFormatProducesInvalidIdentifier(LitStr, String),
+
+    /// An error that occurs when `exclude` is not given an array literal of parenthesized
+    /// parameter assignments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     exclude = [1]
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`exclude` is not a list of parameter assignments")]
+    ExcludeIsNotListOfAssignments(Expr),
+
+    /// An error that occurs when an exclusion refers to a parameter that does not have an array
+    /// literal value.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     exclude = [(y = 1)]
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("exclusion refers to unknown parameter `{1}`")]
+    ExclusionReferencesUnknownParameter(Expr, String),
+
+    /// An error that occurs when an exclusion does not match any permutation.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     exclude = [(x = 3)]
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("exclusion does not match any permutation")]
+    ExclusionMatchesNoPermutation(Expr),
+
+    /// An error that occurs when `filter` is not given a closure.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     filter = true
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`filter` is not a closure")]
+    FilterIsNotClosure(Expr),
+
+    /// An error that occurs when an argument of the `filter` closure is not the name of one of the
+    /// test function's arguments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2],
+    ///     filter = |y| *y > 1
+    /// )]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("`filter` argument is not a parameter")]
+    FilterArgumentIsNotParameter(Pat),
+
     /// An error that occurs when the pattern of a function argument is not a single identifier.
     ///
     /// # Example
@@ -106,8 +420,9 @@ pub enum TestUrCodeXDMacroError {
     /// Emitted when too many permutations are generated for a parameterized test.
     ///
     /// This count is [`DEFAULT_MAX_PERMUTATION_COUNT`] by default, but can be overridden with the
-    /// `TEST_UR_CODE_XD_MAX_PERMUTATION_COUNT` environment variable.
-    #[error("too many permutations generated for parameterized test (limit is {limit}, but {actual} permutations were generated)")]
+    /// `TEST_UR_CODE_XD_MAX_PERMUTATION_COUNT` environment variable or for a single test with the
+    /// `max_permutations` argument.
+    #[error("too many permutations generated for parameterized test (limit is {limit}, but {actual} permutations were generated from {})", format_cardinalities(.cardinalities))]
     TooManyPermutations {
         /// The span to use for the compile-time error
         span: Span,
@@ -117,11 +432,152 @@ pub enum TestUrCodeXDMacroError {
 
         /// The actual number of permutations generated
         actual: usize,
+
+        /// The name and number of values of each parameter that contributed to the permutations
+        cardinalities: Vec<(String, usize)>,
     },
 
     /// Emitted when no parameters are provided to a parameterized test.
     #[error("no parameters provided")]
     NoParameters(Span),
+
+    /// Emitted when a generic parameter of a test function cannot be inferred from the function's
+    /// arguments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_parameter_values(
+    ///     x = [1, 2]
+    /// )]
+    /// fn example<T: Default>(x: i32) {
+    ///     // `T` is not used by any argument
+    /// }
+    /// ```
+    #[error("generic parameter `{0}` cannot be inferred from the test function's arguments")]
+    GenericParameterCannotBeInferred(Ident),
+
+    /// Emitted when two functions generated for the same test would have the same name.
+    #[error("generated test name `{0}` collides with another generated test")]
+    GeneratedNameCollision(Ident),
+
+    /// Emitted when a test function with retries has arguments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_retries(count = 3)]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("unexpected argument in test function with retries")]
+    ArgumentInRetriedTest(FnArg),
+
+    /// Emitted when `#[test_with_retries]` is not given a retry count.
+    #[error("no retry count provided")]
+    NoRetryCount(Span),
+
+    /// Emitted when a test function with a timeout has arguments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_timeout(ms = 500)]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("unexpected argument in test function with timeout")]
+    ArgumentInTimeoutTest(FnArg),
+
+    /// Emitted when `#[test_with_timeout]` is not given a time limit.
+    #[error("no time limit provided")]
+    NoTimeoutLimit(Span),
+
+    /// Emitted when a test function with a seed does not have exactly one argument for the random
+    /// number generator.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_with_seed]
+    /// fn example() {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("test function with seed must have exactly one argument")]
+    SeededTestArgumentCount(Span),
+
+    /// Emitted when a test function with setup or teardown has arguments.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// fn setup() {}
+    ///
+    /// #[test_ur_code_xd::test_with_setup(setup = setup)]
+    /// fn example(x: i32) {
+    ///     // ...
+    /// }
+    /// ```
+    #[error("unexpected argument in test function with setup or teardown")]
+    ArgumentInSetupTest(FnArg),
+
+    /// Emitted when `#[test_with_setup]` is not given a setup or teardown function, or when a
+    /// `#[test_suite]` module has neither.
+    #[error("no setup or teardown function provided")]
+    NoSetupOrTeardown(Span),
+
+    /// Emitted when `#[test_suite]` is used on a module whose items are in another file.
+    ///
+    /// # Example
+    ///
+    /// ```compile_fail
+    /// # use test_ur_code_xd_macro as test_ur_code_xd;
+    /// #
+    /// #[test_ur_code_xd::test_suite(setup = setup)]
+    /// mod tests;
+    /// ```
+    #[error("test suite module `{0}` is not inline")]
+    TestSuiteIsNotInline(Ident),
+
+    /// Emitted when manifests are enabled with `TEST_UR_CODE_XD_MANIFEST`, but the crate being
+    /// compiled has no `OUT_DIR` to write them to.
+    ///
+    /// Cargo only sets `OUT_DIR` for packages with a build script.
+    #[error("no `OUT_DIR` to write the manifest for parameterized test `{0}` to")]
+    ManifestOutDirNotSet(Ident),
+
+    /// Emitted when the manifest for a parameterized test cannot be written.
+    #[error("unable to write the manifest for parameterized test `{0}`: {1}")]
+    ManifestWriteFailed(Ident, std::io::Error),
+}
+
+/// Formats the number of values of each parameter for [`TestUrCodeXDMacroError::TooManyPermutations`],
+/// like ``"`a` (2 values), `b` (3 values)"``.
+fn format_cardinalities(cardinalities: &[(String, usize)]) -> String {
+    cardinalities
+        .iter()
+        .map(|(name, count)| {
+            if *count == 1 {
+                format!("`{name}` (1 value)")
+            } else {
+                format!("`{name}` ({count} values)")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl TestUrCodeXDMacroError {
@@ -137,21 +593,136 @@ impl TestUrCodeXDMacroError {
                 quote_spanned! { argument_pattern.span() => compile_error!("no parameterization exists for argument"); }
             }
             Self::ParameterAssignmentLeftHandSideIsNotIdentifier(expr) => {
-                quote_spanned! { expr.span() => compile_error!("parameter's left-hand side must be an identifier"); }
+                quote_spanned! { expr.span() => compile_error!("parameter's left-hand side must be an identifier or a tuple of identifiers"); }
             }
             Self::ParameterAssignmentRightHandSideIsNotArrayLiteral(expr) => {
-                quote_spanned! { expr.span() => compile_error!("parameter's right-hand side must be an array literal"); }
+                quote_spanned! { expr.span() => compile_error!("parameter's right-hand side must be an array literal, a range, a path to a constant, `from(...)`, or `boundaries(...)`"); }
+            }
+            Self::TupleParameterValueDoesNotMatchNames(expr) => {
+                quote_spanned! { expr.span() => compile_error!("value must be a tuple with one element for each name on the parameter's left-hand side"); }
+            }
+            Self::RangeBoundsAreNotIntegerLiterals(expr) => {
+                quote_spanned! { expr.span() => compile_error!("parameter's range must have integer literal bounds, like `0..10`"); }
+            }
+            Self::ParameterCollectionHasWrongArgumentCount(expr) => {
+                quote_spanned! { expr.span() => compile_error!("parameter's `from` must have exactly one argument"); }
+            }
+            Self::BoundariesHaveWrongArgumentCount(expr) => {
+                quote_spanned! { expr.span() => compile_error!("parameter's `boundaries` must have exactly one argument"); }
+            }
+            Self::BoundariesTypeIsNotSupported(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`boundaries` only supports integer types, `f32`, `f64`, `bool`, and `char`"); }
+            }
+            Self::ModeIsNotRecognized(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`mode` must be `permute` or `zip`"); }
+            }
+            Self::ZippedParametersHaveDifferentLengths(span) => {
+                quote_spanned! { *span => compile_error!("parameters must all have the same number of values when `mode = zip`"); }
+            }
+            Self::NameFormatIsNotStringLiteral(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`name_format` must be a string literal"); }
+            }
+            Self::MaxPermutationsIsNotPositiveIntegerLiteral(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`max_permutations` must be a positive integer literal"); }
+            }
+            Self::NameFormatHasUnclosedPlaceholder(lit_str) => {
+                quote_spanned! { lit_str.span() => compile_error!("`name_format` has a `{` without a matching `}`"); }
+            }
+            Self::NameFormatReferencesUnknownParameter(lit_str, name) => {
+                let message = format!("`name_format` refers to `{name}`, which is not a parameter with an array literal value");
+
+                quote_spanned! { lit_str.span() => compile_error!(#message); }
+            }
+            Self::NameFormatProducesInvalidIdentifier(lit_str, name) => {
+                let message =
+                    format!("`name_format` produces `{name}`, which is not a valid identifier");
+
+                quote_spanned! { lit_str.span() => compile_error!(#message); }
+            }
+            Self::ExcludeIsNotListOfAssignments(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`exclude` must be a list of parameter assignments like `[(x = 1, y = 2)]`"); }
+            }
+            Self::ExclusionReferencesUnknownParameter(expr, name) => {
+                let message = format!(
+                    "exclusion refers to `{name}`, which is not a parameter with an array literal value"
+                );
+
+                quote_spanned! { expr.span() => compile_error!(#message); }
+            }
+            Self::ExclusionMatchesNoPermutation(expr) => {
+                quote_spanned! { expr.span() => compile_error!("exclusion does not match any permutation"); }
+            }
+            Self::FilterIsNotClosure(expr) => {
+                quote_spanned! { expr.span() => compile_error!("`filter` must be a closure"); }
+            }
+            Self::FilterArgumentIsNotParameter(pat) => {
+                quote_spanned! { pat.span() => compile_error!("`filter` arguments must be named after the test function's arguments"); }
             }
             Self::ArgumentPatternIsNotSingleIdentifier(fn_arg) => {
                 quote_spanned! { fn_arg.span() => compile_error!("argument must be a single identifier, not a pattern"); }
             }
             Self::ParsingError(error) => error.to_compile_error(),
-            Self::TooManyPermutations { span, .. } => {
-                quote_spanned! { *span => compile_error!("too many permutations generated for parameterized test"); }
+            Self::TooManyPermutations {
+                span,
+                limit,
+                actual,
+                cardinalities,
+            } => {
+                let message = format!(
+                    "too many permutations generated for parameterized test: {actual} permutations from {} exceed the limit of {limit}, which can be raised with `max_permutations = <count>`",
+                    format_cardinalities(cardinalities)
+                );
+
+                quote_spanned! { *span => compile_error!(#message); }
             }
             Self::NoParameters(span) => {
                 quote_spanned! { *span => compile_error!("no parameters provided"); }
             }
+            Self::GenericParameterCannotBeInferred(ident) => {
+                let message = format!("generic parameter `{ident}` cannot be inferred from the test function's arguments");
+
+                quote_spanned! { ident.span() => compile_error!(#message); }
+            }
+            Self::GeneratedNameCollision(ident) => {
+                let message =
+                    format!("generated test name `{ident}` collides with another generated test");
+
+                quote_spanned! { ident.span() => compile_error!(#message); }
+            }
+            Self::ArgumentInRetriedTest(fn_arg) => {
+                quote_spanned! { fn_arg.span() => compile_error!("test functions with retries cannot have arguments"); }
+            }
+            Self::NoRetryCount(span) => {
+                quote_spanned! { *span => compile_error!("no retry count provided, expected `count = <value>`"); }
+            }
+            Self::ArgumentInTimeoutTest(fn_arg) => {
+                quote_spanned! { fn_arg.span() => compile_error!("test functions with timeouts cannot have arguments"); }
+            }
+            Self::SeededTestArgumentCount(span) => {
+                quote_spanned! { *span => compile_error!("test functions with seeds must take a single argument for the random number generator, like `rng: &mut Rng`"); }
+            }
+            Self::NoTimeoutLimit(span) => {
+                quote_spanned! { *span => compile_error!("no time limit provided, expected `ms = <value>`"); }
+            }
+            Self::ArgumentInSetupTest(fn_arg) => {
+                quote_spanned! { fn_arg.span() => compile_error!("test functions with setup or teardown cannot have arguments"); }
+            }
+            Self::NoSetupOrTeardown(span) => {
+                quote_spanned! { *span => compile_error!("no setup or teardown function provided, expected `setup = <function>` or `teardown = <function>`"); }
+            }
+            Self::TestSuiteIsNotInline(ident) => {
+                quote_spanned! { ident.span() => compile_error!("`#[test_suite]` can only be used on modules with their items inline, like `mod tests { ... }`"); }
+            }
+            Self::ManifestOutDirNotSet(ident) => {
+                quote_spanned! { ident.span() => compile_error!("`TEST_UR_CODE_XD_MANIFEST` is set, but there is no `OUT_DIR` to write the manifest to (add a build script to the package)"); }
+            }
+            Self::ManifestWriteFailed(ident, error) => {
+                let message = format!(
+                    "unable to write the manifest for parameterized test `{ident}`: {error}"
+                );
+
+                quote_spanned! { ident.span() => compile_error!(#message); }
+            }
         }
     }
 
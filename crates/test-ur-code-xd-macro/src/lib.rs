@@ -20,13 +20,29 @@
 //! how to use this crate.
 
 mod errors;
+mod manifest;
 mod parameters;
 mod permute;
+mod random_values;
+mod retries;
+mod seed;
+mod setup;
+mod timeout;
+mod zip;
 
-use crate::parameters::get_permuted_parameter_map_iter;
-use parameters::{generate_permuted_test_function, get_max_permutation_count};
-use std::collections::HashMap;
-use syn::{parse_macro_input, Expr, ItemFn};
+use parameters::{
+    generate_permuted_test_function, get_max_permutation_count, get_parameterization,
+    Parameterization,
+};
+use random_values::{generate_random_values_test_function, parse_random_values_arguments};
+use retries::{generate_retries_test_function, parse_retries_arguments};
+use seed::{generate_seed_test_function, parse_seed_arguments};
+use setup::{
+    generate_setup_test_function, generate_test_suite_module, parse_setup_arguments,
+    parse_test_suite_arguments,
+};
+use syn::{parse_macro_input, ItemFn, ItemMod};
+use timeout::{generate_timeout_test_function, parse_timeout_arguments};
 
 /// Permutes a test case.
 ///
@@ -44,17 +60,221 @@ pub fn test_with_parameter_values(
     // Parse the function item
     let item_fn = parse_macro_input!(item as ItemFn);
 
-    // Parse the attribute's parameters into a vector of permuted parameter maps
-    let vector_of_parameter_maps: Vec<HashMap<String, Expr>> =
-        match get_permuted_parameter_map_iter(tokens, get_max_permutation_count()) {
-            Ok(iter_of_parameter_maps) => iter_of_parameter_maps.collect(),
+    // Parse the attribute's parameters into a vector of permuted parameter maps, the collection
+    // parameters that are iterated over at runtime, and the other arguments
+    let parameterization: Parameterization =
+        match get_parameterization(tokens, get_max_permutation_count()) {
+            Ok(parameterization) => parameterization,
             Err(error) => {
                 return error.into_compile_error().into();
             }
         };
 
     // Generate the permuted test function
-    match generate_permuted_test_function(item_fn, vector_of_parameter_maps) {
+    match generate_permuted_test_function(item_fn, parameterization) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Runs a test with randomly generated parameter values.
+///
+/// Failing values are shrunk to the simplest value that still fails, and the seed needed to
+/// reproduce the failure is included in the panic message.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/random-values](https://sophie-katz.github.io/test-ur-code-XD/tests/random-values/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_with_random_values(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the function item
+    let item_fn = parse_macro_input!(item as ItemFn);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_random_values_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the test function
+    match generate_random_values_test_function(item_fn, arguments) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Re-runs a failing test up to a number of times, only failing if every attempt fails.
+///
+/// The panic message of each failed attempt is printed. This is meant for tests that touch real
+/// networks or timing-sensitive code.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/retries](https://sophie-katz.github.io/test-ur-code-XD/tests/retries/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_with_retries(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the function item
+    let item_fn = parse_macro_input!(item as ItemFn);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_retries_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the test function
+    match generate_retries_test_function(item_fn, arguments) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Runs a test with a deterministic random number generator.
+///
+/// The generator is seeded from the test's name so that the test gets the same values every time
+/// it runs. If the test fails, the seed is printed so that the failure can be reproduced.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/random-values](https://sophie-katz.github.io/test-ur-code-XD/tests/random-values/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_with_seed(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the function item
+    let item_fn = parse_macro_input!(item as ItemFn);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_seed_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the test function
+    match generate_seed_test_function(item_fn, arguments) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Fails a test if it runs for longer than a time limit.
+///
+/// The test body is run on a separate thread. If it does not finish in time, the test fails with a
+/// "test timed out" panic. The built-in test harness has no per-test timeout.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/timeouts](https://sophie-katz.github.io/test-ur-code-XD/tests/timeouts/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_with_timeout(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the function item
+    let item_fn = parse_macro_input!(item as ItemFn);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_timeout_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the test function
+    match generate_timeout_test_function(item_fn, arguments) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Runs a test between a setup function and a teardown function.
+///
+/// The teardown function is called even if the test panics.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown](https://sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_with_setup(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the function item
+    let item_fn = parse_macro_input!(item as ItemFn);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_setup_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the test function
+    match generate_setup_test_function(item_fn, &arguments) {
+        Ok(generated) => generated.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+/// Runs every `#[test]` function in a module between a setup function and a teardown function.
+///
+/// Functions named `setup` and `teardown` in the module are used unless others are given as
+/// arguments. The teardown function is called even if the test panics.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown](https://sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown/)
+/// for a usage guide.
+#[proc_macro_attribute]
+pub fn test_suite(
+    attribute: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // Convert attribute token stream into proc_macro2 tokens
+    let tokens = proc_macro2::TokenStream::from(attribute);
+
+    // Parse the module item
+    let item_mod = parse_macro_input!(item as ItemMod);
+
+    // Parse the attribute's arguments
+    let arguments = match parse_test_suite_arguments(tokens) {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            return error.into_compile_error().into();
+        }
+    };
+
+    // Generate the module
+    match generate_test_suite_module(item_mod, arguments) {
         Ok(generated) => generated.into(),
         Err(error) => error.into_compile_error().into(),
     }
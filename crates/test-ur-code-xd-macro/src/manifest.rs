@@ -0,0 +1,238 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Machine-readable manifests of the test cases generated for parameterized tests.
+//!
+//! When the `TEST_UR_CODE_XD_MANIFEST` environment variable is set, each parameterized test writes
+//! a JSON file describing its generated cases into `$OUT_DIR/test-ur-code-xd-manifest/`. Test
+//! selection tools can read these to map code changes to the cases they affect.
+
+use crate::errors::TestUrCodeXDMacroError;
+use quote::ToTokens;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    env, fs,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+};
+use syn::{Expr, Ident};
+
+/// The environment variable that enables writing manifests.
+const MANIFEST_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_MANIFEST";
+
+/// The name of the directory within `OUT_DIR` that manifests are written to.
+const MANIFEST_DIRECTORY_NAME: &str = "test-ur-code-xd-manifest";
+
+/// A single generated test case to be described in a manifest.
+pub struct ManifestCase<'case> {
+    /// The path of the generated test function relative to the module of the original test.
+    pub name: String,
+
+    /// The values of the parameters with array literal values for this case.
+    pub parameter_map: &'case HashMap<String, Expr>,
+}
+
+/// Gets whether or not manifests should be written.
+#[must_use]
+pub fn is_manifest_enabled() -> bool {
+    env::var_os(MANIFEST_ENVIRONMENT_VARIABLE).map_or(false, |value| !value.is_empty())
+}
+
+/// Escapes a string so that it can be written between quotes in JSON.
+//
+// Format push string allowed because control characters are rare enough in parameter values that allocating for each is fine.
+#[allow(clippy::format_push_string)]
+fn escape_json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ if character.is_control() => {
+                escaped.push_str(&format!("\\u{:04x}", u32::from(character)));
+            }
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Formats a JSON object whose values are the source code of expressions, with the keys sorted so
+/// that the output is deterministic.
+fn format_expr_map<'expr>(
+    entries: impl IntoIterator<Item = (&'expr String, &'expr Expr)>,
+) -> String {
+    let mut entries: Vec<(&String, &Expr)> = entries.into_iter().collect();
+
+    entries.sort_by_key(|(name, _)| *name);
+
+    let formatted: Vec<String> = entries
+        .into_iter()
+        .map(|(name, expr)| {
+            format!(
+                "\"{}\": \"{}\"",
+                escape_json_string(name),
+                escape_json_string(&expr.to_token_stream().to_string())
+            )
+        })
+        .collect();
+
+    format!("{{{}}}", formatted.join(", "))
+}
+
+/// Formats the manifest for a parameterized test as JSON.
+///
+/// # Arguments
+///
+/// * `crate_name` - The name of the crate being compiled.
+/// * `test_ident` - The identifier of the original test function.
+/// * `cases` - The generated test cases.
+/// * `collection_parameters` - The names and collection expressions of parameters whose values
+///   are iterated over at runtime within each case.
+#[must_use]
+pub fn format_manifest(
+    crate_name: &str,
+    test_ident: &Ident,
+    cases: &[ManifestCase<'_>],
+    collection_parameters: &[(String, Expr)],
+) -> String {
+    let formatted_cases: Vec<String> = cases
+        .iter()
+        .map(|case| {
+            format!(
+                "    {{\"name\": \"{}\", \"parameters\": {}}}",
+                escape_json_string(&case.name),
+                format_expr_map(case.parameter_map.iter())
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"crate\": \"{}\",\n  \"test\": \"{}\",\n  \"collection_parameters\": {},\n  \"cases\": [\n{}\n  ]\n}}\n",
+        escape_json_string(crate_name),
+        escape_json_string(&test_ident.to_string()),
+        format_expr_map(
+            collection_parameters
+                .iter()
+                .map(|(name, collection)| (name, collection))
+        ),
+        formatted_cases.join(",\n")
+    )
+}
+
+/// Writes the manifest for a parameterized test into `OUT_DIR`.
+///
+/// The file name includes a hash of the manifest so that tests with the same name in different
+/// modules do not overwrite each other.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ManifestOutDirNotSet`] if the crate being compiled has no
+///   `OUT_DIR`.
+/// * Returns [`TestUrCodeXDMacroError::ManifestWriteFailed`] if the manifest cannot be written.
+pub fn write_manifest(
+    test_ident: &Ident,
+    cases: &[ManifestCase<'_>],
+    collection_parameters: &[(String, Expr)],
+) -> Result<(), TestUrCodeXDMacroError> {
+    let out_dir = env::var_os("OUT_DIR")
+        .ok_or_else(|| TestUrCodeXDMacroError::ManifestOutDirNotSet(test_ident.clone()))?;
+
+    let crate_name = env::var("CARGO_CRATE_NAME").unwrap_or_default();
+
+    let manifest = format_manifest(&crate_name, test_ident, cases, collection_parameters);
+
+    let mut hasher = DefaultHasher::new();
+    manifest.hash(&mut hasher);
+
+    let directory = PathBuf::from(out_dir).join(MANIFEST_DIRECTORY_NAME);
+
+    fs::create_dir_all(&directory)
+        .and_then(|()| {
+            fs::write(
+                directory.join(format!("{test_ident}-{:016x}.json", hasher.finish())),
+                manifest,
+            )
+        })
+        .map_err(|error| TestUrCodeXDMacroError::ManifestWriteFailed(test_ident.clone(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+    use syn::parse_quote;
+
+    #[test]
+    fn escape_json_string_plain() {
+        assert_eq!(escape_json_string("hello"), "hello");
+    }
+
+    #[test]
+    fn escape_json_string_special_characters() {
+        assert_eq!(
+            escape_json_string("\"a\\b\"\n\u{1}"),
+            "\\\"a\\\\b\\\"\\n\\u0001"
+        );
+    }
+
+    #[test]
+    fn format_manifest_cases() {
+        let first: HashMap<String, Expr> = HashMap::from([
+            ("b".to_owned(), parse_quote! { "x" }),
+            ("a".to_owned(), parse_quote! { 1 }),
+        ]);
+
+        let second: HashMap<String, Expr> = HashMap::from([
+            ("b".to_owned(), parse_quote! { "y" }),
+            ("a".to_owned(), parse_quote! { 2 }),
+        ]);
+
+        let manifest = format_manifest(
+            "my_crate",
+            &Ident::new("my_test", Span::call_site()),
+            &[
+                ManifestCase {
+                    name: "my_test_0".to_owned(),
+                    parameter_map: &first,
+                },
+                ManifestCase {
+                    name: "my_test_1".to_owned(),
+                    parameter_map: &second,
+                },
+            ],
+            &[("c".to_owned(), parse_quote! { VALUES })],
+        );
+
+        assert_eq!(
+            manifest,
+            r#"{
+  "crate": "my_crate",
+  "test": "my_test",
+  "collection_parameters": {"c": "VALUES"},
+  "cases": [
+    {"name": "my_test_0", "parameters": {"a": "1", "b": "\"x\""}},
+    {"name": "my_test_1", "parameters": {"a": "2", "b": "\"y\""}}
+  ]
+}
+"#
+        );
+    }
+}
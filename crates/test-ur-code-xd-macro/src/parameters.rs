@@ -16,22 +16,41 @@
 //! Utility functions for dealing with test parameters.
 
 pub mod extracting;
+pub mod filtering;
 pub mod generating;
+pub mod naming;
 pub mod parsing;
 
 use self::{
     extracting::iter_parameterized_fn_inputs,
-    generating::{generate_parameter_function, generate_permutation_function},
+    filtering::{apply_exclusions, generate_filter_condition},
+    generating::{
+        ensure_generated_idents_are_unique, generate_collection_permutation_function,
+        generate_parameter_function, generate_permutation_function, generate_permutation_module,
+        get_parameter_function_ident, get_permutation_function_ident,
+    },
+    naming::format_permutation_function_ident,
 };
-use super::permute::permute_map_of_vectors;
+use super::{permute::permute_map_of_vectors, zip::zip_map_of_vectors};
 use crate::{
     errors::TestUrCodeXDMacroError,
-    parameters::extracting::{filter_fn_attrs_without_this_macro, take_fn_attrs},
+    manifest::{is_manifest_enabled, write_manifest, ManifestCase},
+    parameters::extracting::{
+        ensure_generic_parameters_are_inferable, get_generic_parameter_names, iter_fn_inputs,
+        take_permutation_fn_attrs, type_depends_on_generics,
+    },
+};
+use extracting::{
+    get_map_of_parameter_vectors_from_expr_assign_iter, partition_collection_parameters,
+    partition_options, split_tuple_parameters, CollectionParameters, ParameterMode,
 };
-use extracting::get_map_of_parameter_vectors_from_expr_assign_iter;
 use parsing::parse_expr_assign_iter;
-use std::{collections::HashMap, env};
-use syn::{spanned::Spanned, Attribute, Expr, ItemFn};
+use quote::{format_ident, quote};
+use std::{
+    collections::{HashMap, HashSet},
+    env, iter,
+};
+use syn::{parse_quote, spanned::Spanned, Expr, ExprClosure, Ident, ItemFn, LitStr, Type};
 
 /// The default maximum number of permutations allowed for parameterized tests.
 const DEFAULT_MAX_PERMUTATION_COUNT: usize = 256;
@@ -107,10 +126,176 @@ pub fn get_permuted_parameter_map_iter(
             span,
             limit: max_permutation_count,
             actual: actual_permutation_count,
+            cardinalities: get_parameter_cardinalities(&map_of_parameter_vectors),
+        });
+    }
+
+    Ok(permute_map_of_vectors(map_of_parameter_vectors)
+        .into_iter()
+        .map(split_tuple_parameters))
+}
+
+/// Gets an iterator over parameter maps from the token stream taken from a given attribute, pairing
+/// the parameter values by their index instead of permuting them.
+///
+/// # Example
+///
+/// ```ignore
+/// get_zipped_parameter_map_iter(
+///     quote! {
+///         a = [1, 2],
+///         b = [3, 4]
+///     }
+/// );
+/// ```
+///
+/// This will result in an iterator over maps that look like this:
+///
+/// ```json
+/// [
+///     {
+///         "a": 1,
+///         "b": 3
+///     },
+///     {
+///         "a": 2,
+///         "b": 4
+///     }
+/// ]
+/// ```
+///
+/// # Arguments
+///
+/// * `tokens` - a token stream taken from the attribute
+/// * `max_permutation_count` - the maximum number of permutations to generate
+///
+/// # Returns
+///
+/// An iterator over parameter maps.
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+/// * Returns [`TestUrCodeXDMacroError::ZippedParametersHaveDifferentLengths`] if the parameters
+///   do not all have the same number of values.
+pub fn get_zipped_parameter_map_iter(
+    tokens: proc_macro2::TokenStream,
+    max_permutation_count: usize,
+) -> Result<impl Iterator<Item = HashMap<String, Expr>>, TestUrCodeXDMacroError> {
+    let span = tokens.span();
+
+    let map_of_parameter_vectors =
+        get_map_of_parameter_vectors_from_expr_assign_iter(parse_expr_assign_iter(tokens)?)?;
+
+    if map_of_parameter_vectors.is_empty() {
+        return Err(TestUrCodeXDMacroError::NoParameters(span));
+    }
+
+    let cardinalities = get_parameter_cardinalities(&map_of_parameter_vectors);
+
+    let vec_of_parameter_maps = zip_map_of_vectors(map_of_parameter_vectors)
+        .ok_or(TestUrCodeXDMacroError::ZippedParametersHaveDifferentLengths(span))?;
+
+    if vec_of_parameter_maps.len() > max_permutation_count {
+        return Err(TestUrCodeXDMacroError::TooManyPermutations {
+            span,
+            limit: max_permutation_count,
+            actual: vec_of_parameter_maps.len(),
+            cardinalities,
         });
     }
 
-    Ok(permute_map_of_vectors(map_of_parameter_vectors).into_iter())
+    Ok(vec_of_parameter_maps
+        .into_iter()
+        .map(split_tuple_parameters))
+}
+
+/// Gets the name and number of values of each parameter, sorted by name, for reporting why there
+/// are too many permutations.
+fn get_parameter_cardinalities(
+    map_of_parameter_vectors: &HashMap<String, Vec<Expr>>,
+) -> Vec<(String, usize)> {
+    let mut cardinalities: Vec<(String, usize)> = map_of_parameter_vectors
+        .iter()
+        .map(|(name, values)| (name.clone(), values.len()))
+        .collect();
+
+    cardinalities.sort();
+
+    cardinalities
+}
+
+/// The parsed attribute arguments of a parameterized test.
+pub struct Parameterization {
+    /// The permuted parameter maps of the parameters with array literal values, without the
+    /// excluded permutations.
+    pub vec_of_parameter_maps: Vec<HashMap<String, Expr>>,
+
+    /// The names and collection expressions of parameters whose values are iterated over at
+    /// runtime.
+    pub collection_parameters: CollectionParameters,
+
+    /// The format to use for the names of the permutation functions, if any.
+    pub name_format: Option<LitStr>,
+
+    /// The closure that decides at runtime whether or not each permutation runs, if any.
+    pub filter: Option<ExprClosure>,
+}
+
+/// Gets the parameterization of a parameterized test from the token stream taken from a given
+/// attribute.
+///
+/// Parameters with array literal values are permuted like in [`get_permuted_parameter_map_iter`],
+/// or zipped like in [`get_zipped_parameter_map_iter`] when `mode = zip` is given, and then any
+/// permutations listed in `exclude` are removed. Parameters with `from(...)` values
+/// are returned separately since their values can only be iterated over at runtime.
+///
+/// # Arguments
+///
+/// * `tokens` - a token stream taken from the attribute
+/// * `max_permutation_count` - the maximum number of permutations to generate, unless the
+///   attribute overrides it with `max_permutations`
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+pub fn get_parameterization(
+    tokens: proc_macro2::TokenStream,
+    max_permutation_count: usize,
+) -> Result<Parameterization, TestUrCodeXDMacroError> {
+    let (assignments, options) = partition_options(parse_expr_assign_iter(tokens)?)?;
+
+    let max_permutation_count = options
+        .max_permutation_count
+        .unwrap_or(max_permutation_count);
+
+    let (assignments, collection_parameters) =
+        partition_collection_parameters(assignments.into_iter())?;
+
+    // If all parameters are collection parameters, there is a single permutation with no array
+    // literal parameters
+    let vec_of_parameter_maps = if assignments.is_empty() && !collection_parameters.is_empty() {
+        vec![HashMap::new()]
+    } else {
+        // The remaining array literal parameters are converted back into tokens to be combined
+        let assignment_tokens = quote! { #( #assignments ),* };
+
+        match options.mode {
+            ParameterMode::Permute => {
+                get_permuted_parameter_map_iter(assignment_tokens, max_permutation_count)?.collect()
+            }
+            ParameterMode::Zip => {
+                get_zipped_parameter_map_iter(assignment_tokens, max_permutation_count)?.collect()
+            }
+        }
+    };
+
+    Ok(Parameterization {
+        vec_of_parameter_maps: apply_exclusions(vec_of_parameter_maps, &options.exclusions)?,
+        collection_parameters,
+        name_format: options.name_format,
+        filter: options.filter,
+    })
 }
 
 /// Generates a permutation function for a given test function and parameterization. This is the
@@ -122,24 +307,90 @@ pub fn get_permuted_parameter_map_iter(
 /// # Arguments
 ///
 /// * `item` - The test case's original function.
-/// * `vec_of_parameter_maps` - The vector of parameter maps parsed from the attribute.
+/// * `parameterization` - The parameterization parsed from the attribute.
 ///
 /// # Returns
 ///
 /// A token stream.
 pub fn generate_permuted_test_function(
     mut item: ItemFn,
-    vec_of_parameter_maps: Vec<HashMap<String, Expr>>,
+    parameterization: Parameterization,
 ) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
-    // Take attribute list
-    let attributes: Vec<Attribute> =
-        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+    let Parameterization {
+        vec_of_parameter_maps,
+        collection_parameters,
+        name_format,
+        filter,
+    } = parameterization;
+
+    let name_format = name_format.as_ref();
+
+    // Take attribute list, leaving the ones that also apply to the parameter function
+    let attributes = take_permutation_fn_attrs(&mut item);
+
+    // Make sure that the generic parameters can be inferred from the parameter values
+    ensure_generic_parameters_are_inferable(&item)?;
+
+    let generic_parameter_names = get_generic_parameter_names(&item);
+
+    // Get the names of the permutation functions, formatted from the parameter values if there is
+    // a name format
+    let permutation_function_idents =
+        get_permutation_function_idents(&item, &vec_of_parameter_maps, name_format)?;
+
+    // Make sure that none of the generated functions would have the same name
+    ensure_generated_idents_are_unique(
+        &iter::once(get_parameter_function_ident(&item))
+            .chain(permutation_function_idents.iter().cloned())
+            .collect::<Vec<_>>(),
+    )?;
+
+    // Describe the generated cases for test selection tooling if requested
+    if is_manifest_enabled() {
+        write_manifest(
+            &item.sig.ident,
+            &vec_of_parameter_maps
+                .iter()
+                .zip(&permutation_function_idents)
+                .map(|(parameter_map, permutation_function_ident)| ManifestCase {
+                    name: if name_format.is_some() {
+                        format!("{}::{permutation_function_ident}", item.sig.ident)
+                    } else {
+                        permutation_function_ident.to_string()
+                    },
+                    parameter_map,
+                })
+                .collect::<Vec<_>>(),
+            &collection_parameters,
+        )?;
+    }
+
+    // Generate the condition that calls the filter closure, if there is one
+    let filter_condition = match &filter {
+        Some(filter) => Some(generate_filter_condition(
+            filter,
+            &iter_fn_inputs(&item)
+                .map(|input| input.map(|(name, _)| name))
+                .collect::<Result<_, _>>()?,
+        )?),
+        None => None,
+    };
 
     // Initialize token stream
-    let mut result = proc_macro2::TokenStream::new();
+    let mut permutation_functions = proc_macro2::TokenStream::new();
 
     // For each permutation, generate a permutation function
-    for (counter, parameter_map) in vec_of_parameter_maps.into_iter().enumerate() {
+    for (mut parameter_map, permutation_function_ident) in vec_of_parameter_maps
+        .into_iter()
+        .zip(&permutation_function_idents)
+    {
+        // Bind collection parameters to the loop variables of the same name
+        for (name, _) in &collection_parameters {
+            let ident = format_ident!("{}", name);
+
+            parameter_map.insert(name.clone(), parse_quote! { #ident });
+        }
+
         // Initialize vector for parameterized function inputs
         let mut parameterized_fn_inputs = Vec::new();
 
@@ -148,21 +399,44 @@ pub fn generate_permuted_test_function(
         for input in iter_parameterized_fn_inputs(&item, &parameter_map) {
             match input {
                 Ok((name, ty, expr)) => {
-                    parameterized_fn_inputs.push((name, ty.clone(), expr.clone()));
+                    parameterized_fn_inputs.push((
+                        name,
+                        get_binding_type(ty, &generic_parameter_names),
+                        expr.clone(),
+                    ));
                 }
                 Err(error) => return Err(error),
             }
         }
 
         // Generate the permutation function
-        result.extend(generate_permutation_function(
-            &attributes,
-            &item,
-            &parameterized_fn_inputs,
-            counter,
-        ));
+        if collection_parameters.is_empty() {
+            permutation_functions.extend(generate_permutation_function(
+                &attributes,
+                &item,
+                &parameterized_fn_inputs,
+                filter_condition.as_ref(),
+                permutation_function_ident,
+            ));
+        } else {
+            permutation_functions.extend(generate_collection_permutation_function(
+                &attributes,
+                &item,
+                &parameterized_fn_inputs,
+                &collection_parameters,
+                filter_condition.as_ref(),
+                permutation_function_ident,
+            ));
+        }
     }
 
+    // Tests with formatted names are grouped into a module named after the test
+    let mut result = if name_format.is_some() {
+        generate_permutation_module(&item, &permutation_functions)
+    } else {
+        permutation_functions
+    };
+
     // Generate the parameter function
     result.extend(generate_parameter_function(item));
 
@@ -170,6 +444,46 @@ pub fn generate_permuted_test_function(
     Ok(result)
 }
 
+/// Gets the identifiers of the permutation functions for a parameterized test.
+///
+/// Without a name format, the permutation functions are numbered. With a name format, each name is
+/// formatted from the permutation's parameter values.
+///
+/// # Errors
+///
+/// * Returns an error if a name cannot be formatted.
+fn get_permutation_function_idents(
+    item: &ItemFn,
+    vec_of_parameter_maps: &[HashMap<String, Expr>],
+    name_format: Option<&LitStr>,
+) -> Result<Vec<Ident>, TestUrCodeXDMacroError> {
+    match name_format {
+        Some(name_format) => vec_of_parameter_maps
+            .iter()
+            .map(|parameter_map| {
+                format_permutation_function_ident(name_format, parameter_map, &item.sig.ident)
+            })
+            .collect(),
+        None => Ok((0..vec_of_parameter_maps.len())
+            .map(|index| get_permutation_function_ident(item, index))
+            .collect()),
+    }
+}
+
+/// Gets the type to use when binding a parameter value in a generated function.
+///
+/// Types that refer to the test function's generic parameters or use `impl Trait` cannot be written
+/// in the generated function, so they are replaced with `_` and inferred from the call to the
+/// parameter function instead.
+#[must_use]
+pub fn get_binding_type(ty: &Type, generic_parameter_names: &HashSet<String>) -> Type {
+    if type_depends_on_generics(ty, generic_parameter_names) {
+        parse_quote! { _ }
+    } else {
+        ty.clone()
+    }
+}
+
 /// Gets the maximum number of permutations allows for parameterized tests.
 #[must_use]
 pub fn get_max_permutation_count() -> usize {
@@ -188,7 +502,7 @@ pub fn get_max_permutation_count() -> usize {
 #[allow(clippy::unwrap_used, clippy::indexing_slicing, clippy::panic)]
 mod tests {
     use super::*;
-    use quote::{quote, ToTokens};
+    use quote::ToTokens;
 
     #[test]
     fn get_permuted_parameter_map_iter_empty() {
@@ -264,6 +578,298 @@ mod tests {
         }
     }
 
+    #[test]
+    fn get_permuted_parameter_map_iter_above_limit_cardinalities() {
+        assert!(matches!(
+            get_permuted_parameter_map_iter(quote! {b = [0, 1, 2], a = [0, 1], c = [0]}, 5),
+            Err(TestUrCodeXDMacroError::TooManyPermutations { cardinalities, .. })
+                if cardinalities == vec![
+                    ("a".to_owned(), 2),
+                    ("b".to_owned(), 3),
+                    ("c".to_owned(), 1),
+                ]
+        ));
+    }
+
+    #[test]
+    fn get_parameterization_max_permutations_raises_limit() {
+        let Parameterization {
+            vec_of_parameter_maps,
+            ..
+        } = get_parameterization(
+            quote! {a = [1, 2, 3], b = [4, 5, 6], max_permutations = 9},
+            4,
+        )
+        .unwrap();
+
+        assert_eq!(vec_of_parameter_maps.len(), 9);
+    }
+
+    #[test]
+    fn get_parameterization_max_permutations_lowers_limit() {
+        assert!(matches!(
+            get_parameterization(
+                quote! {a = [1, 2, 3], b = [4, 5, 6], max_permutations = 8},
+                256
+            ),
+            Err(TestUrCodeXDMacroError::TooManyPermutations {
+                limit: 8,
+                actual: 9,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn get_zipped_parameter_map_iter_two_full() {
+        let vec_of_maps: Vec<HashMap<String, Expr>> =
+            get_zipped_parameter_map_iter(quote! {a = [1, 2, 3], b = [4, 5, 6]}, 10)
+                .unwrap()
+                .collect();
+
+        assert_eq!(vec_of_maps.len(), 3);
+        assert_eq!(vec_of_maps[1]["a"].to_token_stream().to_string(), "2");
+        assert_eq!(vec_of_maps[1]["b"].to_token_stream().to_string(), "5");
+    }
+
+    #[test]
+    fn get_permuted_parameter_map_iter_tuple() {
+        let vec_of_maps: Vec<HashMap<String, Expr>> = get_permuted_parameter_map_iter(
+            quote! {(a, expected) = [(1, 2), (2, 4)], b = [5, 6]},
+            10,
+        )
+        .unwrap()
+        .collect();
+
+        // The tuple values stay together, so there are 2 * 2 permutations instead of 2 * 2 * 2
+        assert_eq!(vec_of_maps.len(), 4);
+
+        for map in &vec_of_maps {
+            assert_eq!(map.len(), 3);
+
+            let a: i32 = map["a"].to_token_stream().to_string().parse().unwrap();
+            let expected: i32 = map["expected"]
+                .to_token_stream()
+                .to_string()
+                .parse()
+                .unwrap();
+
+            assert_eq!(a * 2, expected);
+        }
+    }
+
+    #[test]
+    fn get_zipped_parameter_map_iter_different_lengths() {
+        assert!(matches!(
+            get_zipped_parameter_map_iter(quote! {a = [1, 2, 3], b = [4, 5]}, 10),
+            Err(TestUrCodeXDMacroError::ZippedParametersHaveDifferentLengths(_))
+        ));
+    }
+
+    #[test]
+    fn get_zipped_parameter_map_iter_above_limit() {
+        assert!(matches!(
+            get_zipped_parameter_map_iter(quote! {a = [1, 2, 3], b = [4, 5, 6]}, 2),
+            Err(TestUrCodeXDMacroError::TooManyPermutations { .. })
+        ));
+    }
+
+    #[test]
+    fn get_parameterization_zip() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            ..
+        } = get_parameterization(quote! {a = [1, 2, 3], b = [4, 5, 6], mode = zip}, 10).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 3);
+    }
+
+    #[test]
+    fn get_parameterization_no_collections() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            collection_parameters,
+            ..
+        } = get_parameterization(quote! {a = [1, 2], b = [3, 4]}, 10).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 4);
+        assert!(collection_parameters.is_empty());
+    }
+
+    #[test]
+    fn get_parameterization_only_collections() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            collection_parameters,
+            ..
+        } = get_parameterization(quote! {a = from(CASES)}, 10).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 1);
+        assert!(vec_of_maps[0].is_empty());
+        assert_eq!(collection_parameters.len(), 1);
+        assert_eq!(collection_parameters[0].0, "a");
+    }
+
+    #[test]
+    fn get_parameterization_mixed() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            collection_parameters,
+            ..
+        } = get_parameterization(quote! {a = [1, 2], b = from(CASES)}, 10).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 2);
+        assert_eq!(vec_of_maps[0].len(), 1);
+        assert_eq!(collection_parameters.len(), 1);
+        assert_eq!(collection_parameters[0].0, "b");
+    }
+
+    #[test]
+    fn get_parameterization_empty() {
+        assert!(get_parameterization(quote! {}, 10).is_err());
+    }
+
+    #[test]
+    fn generate_permuted_test_function_collections() {
+        let item = parse_quote! {
+            fn asdf(a: u32, b: u32) {}
+        };
+
+        let parameterization = get_parameterization(quote! {a = [1], b = from(CASES)}, 10).unwrap();
+
+        assert_eq!(
+            generate_permuted_test_function(item, parameterization)
+                .unwrap()
+                .to_string(),
+            "# [test] fn asdf_0 () { for b in :: std :: iter :: Iterator :: cloned ((CASES) . iter ()) { let a : u32 = 1 ; let b : u32 = b ; _test_ur_code_xd_asdf_parameter_function (a , b) ; } } fn _test_ur_code_xd_asdf_parameter_function (a : u32 , b : u32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_permuted_test_function_generics() {
+        let item = parse_quote! {
+            fn asdf<T: Display>(a: T, b: impl Display, c: u32) {}
+        };
+
+        let parameterization =
+            get_parameterization(quote! {a = [1], b = [2], c = [3]}, 10).unwrap();
+
+        assert_eq!(
+            generate_permuted_test_function(item, parameterization)
+                .unwrap()
+                .to_string(),
+            "# [test] fn asdf_0 () { let a : _ = 1 ; let b : _ = 2 ; let c : u32 = 3 ; _test_ur_code_xd_asdf_parameter_function (a , b , c) ; } fn _test_ur_code_xd_asdf_parameter_function < T : Display > (a : T , b : impl Display , c : u32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_permuted_test_function_attributes() {
+        let item = parse_quote! {
+            #[test]
+            #[cfg(unix)]
+            #[should_panic(expected = "oops")]
+            #[allow(clippy::panic)]
+            fn asdf(a: u32) {}
+        };
+
+        let parameterization = get_parameterization(quote! {a = [1]}, 10).unwrap();
+
+        assert_eq!(
+            generate_permuted_test_function(item, parameterization)
+                .unwrap()
+                .to_string(),
+            "# [test] # [cfg (unix)] # [should_panic (expected = \"oops\")] # [allow (clippy :: panic)] fn asdf_0 () { let a : u32 = 1 ; _test_ur_code_xd_asdf_parameter_function (a) ; } # [cfg (unix)] # [allow (clippy :: panic)] fn _test_ur_code_xd_asdf_parameter_function (a : u32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_permuted_test_function_generics_not_inferable() {
+        let item = parse_quote! {
+            fn asdf<T: Default>(a: u32) {}
+        };
+
+        let parameterization = get_parameterization(quote! {a = [1]}, 10).unwrap();
+
+        assert!(generate_permuted_test_function(item, parameterization).is_err());
+    }
+
+    #[test]
+    fn get_parameterization_name_format() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            collection_parameters,
+            name_format,
+            ..
+        } = get_parameterization(quote! {a = [1, 2], name_format = "a_{a}"}, 10).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 2);
+        assert!(collection_parameters.is_empty());
+        assert_eq!(name_format.unwrap().value(), "a_{a}");
+    }
+
+    #[test]
+    fn get_parameterization_exclude() {
+        let Parameterization {
+            vec_of_parameter_maps: vec_of_maps,
+            ..
+        } = get_parameterization(
+            quote! {a = [1, 2], b = [3, 4], exclude = [(a = 1, b = 3), (a = 2, b = 4)]},
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(vec_of_maps.len(), 2);
+    }
+
+    #[test]
+    fn generate_permuted_test_function_filter() {
+        let item = parse_quote! {
+            fn asdf(a: i32) {}
+        };
+
+        let parameterization =
+            get_parameterization(quote! {a = [1], filter = |a| *a > 0}, 10).unwrap();
+
+        assert_eq!(
+            generate_permuted_test_function(item, parameterization)
+                .unwrap()
+                .to_string(),
+            "# [test] fn asdf_0 () { let a : i32 = 1 ; if (| a | * a > 0) (& a) { _test_ur_code_xd_asdf_parameter_function (a) ; } } fn _test_ur_code_xd_asdf_parameter_function (a : i32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_permuted_test_function_name_format() {
+        let item = parse_quote! {
+            fn asdf(a: i32) {}
+        };
+
+        let parameterization =
+            get_parameterization(quote! {a = [-1], name_format = "a_{a}"}, 10).unwrap();
+
+        assert_eq!(
+            generate_permuted_test_function(item, parameterization)
+            .unwrap()
+            .to_string(),
+            "# [cfg (test)] # [allow (non_snake_case)] mod asdf { use super :: * ; # [test] fn a_neg_1 () { let a : i32 = - 1 ; _test_ur_code_xd_asdf_parameter_function (a) ; } } fn _test_ur_code_xd_asdf_parameter_function (a : i32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_permuted_test_function_name_format_collision() {
+        let item = parse_quote! {
+            fn asdf(a: i32, b: i32) {}
+        };
+
+        let parameterization =
+            get_parameterization(quote! {a = [1, 2], b = [3], name_format = "b_{b}"}, 10).unwrap();
+
+        assert!(matches!(
+            generate_permuted_test_function(item, parameterization),
+            Err(TestUrCodeXDMacroError::GeneratedNameCollision(_))
+        ));
+    }
+
     #[test]
     fn get_max_permutation_count_default() {
         env::remove_var("TEST_UR_CODE_XD_MAX_PERMUTATION_COUNT");
@@ -15,12 +15,34 @@
 
 //! Functions for extracting information from syntax trees.
 
-use std::{collections::HashMap, mem};
-
-use syn::{Attribute, Expr, ExprAssign, FnArg, ItemFn, Meta, Pat, PatType, Type};
-
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
+
+use proc_macro2::{Ident, Span};
+use quote::ToTokens;
+use syn::{
+    parse_quote,
+    spanned::Spanned,
+    visit::{self, Visit},
+    Attribute, Expr, ExprAssign, ExprClosure, ExprLit, ExprUnary, FnArg, GenericParam, ItemFn,
+    Lifetime, Lit, LitInt, LitStr, Meta, Pat, PatType, Path, RangeLimits, Type, TypeImplTrait,
+    UnOp, WherePredicate,
+};
+
+use super::{
+    filtering::{
+        get_exclusions_from_expr, get_filter_from_expr, Exclusion, EXCLUDE_ARGUMENT_NAME,
+        FILTER_ARGUMENT_NAME,
+    },
+    naming::NAME_FORMAT_ARGUMENT_NAME,
+};
 use crate::errors::TestUrCodeXDMacroError;
 
+/// The names and collection expressions of parameters whose values come from `from(...)`.
+pub type CollectionParameters = Vec<(String, Expr)>;
+
 /// Extracts an identifier name from an identifier expression.
 ///
 /// # Example
@@ -110,8 +132,588 @@ fn iter_expr_literal_array(expr: &Expr) -> Option<impl Iterator<Item = &Expr>> {
     }
 }
 
+/// Extracts the collection expression from a `from(...)` expression or a path.
+///
+/// A path like `CASES` or `my_module::CASES` is treated the same as `from(CASES)`, since the
+/// values of constants can only be iterated over at runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_collection_expr_from_expr(&parse_quote! { from(CASES) })
+///         .unwrap()
+///         .unwrap()
+///         .to_token_stream()
+///         .to_string(),
+///     "CASES"
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Some(collection))` if the expression is a `from(...)` call or a path.
+/// * `Ok(None)` otherwise.
+///
+/// # Errors
+///
+/// * Returns a [`Error::ParameterCollectionHasWrongArgumentCount`] if the `from(...)` call does not
+///   have exactly one argument.
+fn get_collection_expr_from_expr(expr: &Expr) -> Result<Option<&Expr>, TestUrCodeXDMacroError> {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    match expr {
+        Expr::Call(call)
+            if get_identifier_name_from_expr(&call.func).as_deref() == Some("from") =>
+        {
+            if call.args.len() == 1 {
+                Ok(call.args.first())
+            } else {
+                Err(TestUrCodeXDMacroError::ParameterCollectionHasWrongArgumentCount(expr.clone()))
+            }
+        }
+        Expr::Path(_) => Ok(Some(expr)),
+        _ => Ok(None),
+    }
+}
+
+/// The maximum number of values that a range parameter can have.
+///
+/// Ranges are expanded into one value per test case at compile time, so this keeps a typo like
+/// `0..1000000000` from exhausting memory before the permutation limit is checked.
+const MAX_RANGE_VALUE_COUNT: usize = 10_000;
+
+/// Extracts the value and suffix of an integer literal expression, which may be negated.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_integer_from_expr(&parse_quote! { -5u8 }),
+///     Some((-5, "u8".to_owned()))
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Some((value, suffix))` if the expression is an integer literal that fits in an `i128`.
+/// * `None` otherwise.
+#[must_use]
+fn get_integer_from_expr(expr: &Expr) -> Option<(i128, String)> {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => Some((
+            lit_int.base10_parse::<i128>().ok()?,
+            lit_int.suffix().to_owned(),
+        )),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: negated_expr,
+            ..
+        }) => {
+            let (value, suffix) = get_integer_from_expr(negated_expr)?;
+
+            Some((value.checked_neg()?, suffix))
+        }
+        _ => None,
+    }
+}
+
+/// Extracts the values from a range expression with integer literal bounds.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_range_values_from_expr(&parse_quote! { 0..3 })
+///         .unwrap()
+///         .unwrap(),
+///     vec![parse_quote! { 0 }, parse_quote! { 1 }, parse_quote! { 2 }]
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Some(values))` if the expression is a range.
+/// * `Ok(None)` otherwise.
+///
+/// # Errors
+///
+/// * Returns a [`Error::RangeBoundsAreNotIntegerLiterals`] if the range is missing a bound or a
+///   bound is not an integer literal.
+/// * Returns a [`Error::TooManyPermutations`] if the range has more than
+///   [`MAX_RANGE_VALUE_COUNT`] values.
+fn get_range_values_from_expr(expr: &Expr) -> Result<Option<Vec<Expr>>, TestUrCodeXDMacroError> {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    let Expr::Range(range) = expr
+    else {
+        return Ok(None);
+    };
+
+    let bounds_error = || TestUrCodeXDMacroError::RangeBoundsAreNotIntegerLiterals(expr.clone());
+
+    let (start, start_suffix) = range
+        .start
+        .as_deref()
+        .and_then(get_integer_from_expr)
+        .ok_or_else(bounds_error)?;
+
+    let (end, end_suffix) = range
+        .end
+        .as_deref()
+        .and_then(get_integer_from_expr)
+        .ok_or_else(bounds_error)?;
+
+    // Keep the suffix from whichever bound has one so that the values have the intended type
+    let suffix = if start_suffix.is_empty() {
+        end_suffix
+    } else {
+        start_suffix
+    };
+
+    let end = match range.limits {
+        RangeLimits::HalfOpen(_) => end,
+        RangeLimits::Closed(_) => end.checked_add(1).ok_or_else(bounds_error)?,
+    };
+
+    let value_count = usize::try_from(end.saturating_sub(start)).unwrap_or(0);
+
+    if value_count > MAX_RANGE_VALUE_COUNT {
+        return Err(TestUrCodeXDMacroError::TooManyPermutations {
+            span: expr.span(),
+            limit: MAX_RANGE_VALUE_COUNT,
+            actual: value_count,
+            cardinalities: vec![(expr.to_token_stream().to_string(), value_count)],
+        });
+    }
+
+    Ok(Some(
+        (start..end)
+            .map(|value| {
+                let lit_int = LitInt::new(
+                    &format!("{}{suffix}", value.unsigned_abs()),
+                    Span::call_site(),
+                );
+
+                if value < 0 {
+                    parse_quote! { -#lit_int }
+                } else {
+                    parse_quote! { #lit_int }
+                }
+            })
+            .collect(),
+    ))
+}
+
+/// Gets the canonical boundary values for a primitive type.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_boundary_values_for_type("u8").unwrap(),
+///     vec![
+///         parse_quote! { 0 },
+///         parse_quote! { 1 },
+///         parse_quote! { u8::MAX },
+///     ],
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Some(values)` if the type is a supported primitive type.
+/// * `None` otherwise.
+#[must_use]
+fn get_boundary_values_for_type(type_name: &str) -> Option<Vec<Expr>> {
+    let ty = Ident::new(type_name, Span::call_site());
+
+    match type_name {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => Some(vec![
+            parse_quote! { #ty::MIN },
+            parse_quote! { -1 },
+            parse_quote! { 0 },
+            parse_quote! { 1 },
+            parse_quote! { #ty::MAX },
+        ]),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => Some(vec![
+            parse_quote! { 0 },
+            parse_quote! { 1 },
+            parse_quote! { #ty::MAX },
+        ]),
+        "f32" | "f64" => Some(vec![
+            parse_quote! { #ty::NEG_INFINITY },
+            parse_quote! { #ty::MIN },
+            parse_quote! { -1.0 },
+            parse_quote! { -0.0 },
+            parse_quote! { 0.0 },
+            parse_quote! { #ty::MIN_POSITIVE },
+            parse_quote! { 1.0 },
+            parse_quote! { #ty::MAX },
+            parse_quote! { #ty::INFINITY },
+            parse_quote! { #ty::NAN },
+        ]),
+        "bool" => Some(vec![parse_quote! { false }, parse_quote! { true }]),
+        "char" => Some(vec![
+            parse_quote! { '\0' },
+            parse_quote! { '\u{7f}' },
+            parse_quote! { '\u{80}' },
+            parse_quote! { '\u{ffff}' },
+            parse_quote! { char::MAX },
+        ]),
+        _ => None,
+    }
+}
+
+/// Extracts the boundary values from a `boundaries(...)` expression.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_boundary_values_from_expr(&parse_quote! { boundaries(bool) })
+///         .unwrap()
+///         .unwrap(),
+///     vec![parse_quote! { false }, parse_quote! { true }]
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Some(values))` if the expression is a `boundaries(...)` call.
+/// * `Ok(None)` otherwise.
+///
+/// # Errors
+///
+/// * Returns a [`Error::BoundariesHaveWrongArgumentCount`] if the `boundaries(...)` call does not
+///   have exactly one argument.
+/// * Returns a [`Error::BoundariesTypeIsNotSupported`] if the argument is not a supported
+///   primitive type.
+fn get_boundary_values_from_expr(expr: &Expr) -> Result<Option<Vec<Expr>>, TestUrCodeXDMacroError> {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    match expr {
+        Expr::Call(call)
+            if get_identifier_name_from_expr(&call.func).as_deref() == Some("boundaries") =>
+        {
+            let Some(type_expr) = call.args.first().filter(|_| call.args.len() == 1) else {
+                return Err(TestUrCodeXDMacroError::BoundariesHaveWrongArgumentCount(
+                    expr.clone(),
+                ));
+            };
+
+            get_identifier_name_from_expr(type_expr)
+                .and_then(|type_name| get_boundary_values_for_type(&type_name))
+                .map(Some)
+                .ok_or_else(|| {
+                    TestUrCodeXDMacroError::BoundariesTypeIsNotSupported(type_expr.clone())
+                })
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Separates parameters whose values come from a collection with `from(...)` from parameters whose
+/// values are array literals.
+///
+/// # Example
+///
+/// ```ignore
+/// let (assignments, collection_parameters) = partition_collection_parameters(
+///     vec![
+///         parse_quote! { a = [1, 2, 3] },
+///         parse_quote! { b = from(CASES) },
+///     ]
+///     .into_iter()
+/// )
+/// .unwrap();
+///
+/// // assignments contains `a = [1, 2, 3]`
+/// // collection_parameters contains `("b", CASES)`
+/// ```
+///
+/// # Arguments
+///
+/// * `expr_assign_iter` - An iterator of assignment expressions.
+///
+/// # Returns
+///
+/// A tuple of the array literal assignments and the names and collection expressions of the
+/// collection parameters, in the order they were written.
+pub fn partition_collection_parameters(
+    expr_assign_iter: impl Iterator<Item = ExprAssign>,
+) -> Result<(Vec<ExprAssign>, CollectionParameters), TestUrCodeXDMacroError> {
+    let mut assignments = Vec::new();
+    let mut collection_parameters = Vec::new();
+
+    for assign in expr_assign_iter {
+        if let Some(collection) = get_collection_expr_from_expr(&assign.right)? {
+            let key = get_identifier_name_from_expr(&assign.left).ok_or(
+                TestUrCodeXDMacroError::ParameterAssignmentLeftHandSideIsNotIdentifier(
+                    (*assign.left).clone(),
+                ),
+            )?;
+
+            collection_parameters.push((key, collection.clone()));
+        } else {
+            assignments.push(assign);
+        }
+    }
+
+    Ok((assignments, collection_parameters))
+}
+
+/// The name of the attribute argument that sets how parameter values are combined.
+pub const MODE_ARGUMENT_NAME: &str = "mode";
+
+/// How the values of a parameterized test's parameters are combined into test cases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParameterMode {
+    /// Every combination of parameter values is tested. This is the default.
+    #[default]
+    Permute,
+
+    /// Parameter values are paired by their index, so all parameters must have the same number of
+    /// values.
+    Zip,
+}
+
+/// Gets the parameter mode from the value of a `mode` argument.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ModeIsNotRecognized`] if the value is not `permute` or
+///   `zip`.
+pub fn get_mode_from_expr(expr: Expr) -> Result<ParameterMode, TestUrCodeXDMacroError> {
+    match get_identifier_name_from_expr(&expr).as_deref() {
+        Some("permute") => Ok(ParameterMode::Permute),
+        Some("zip") => Ok(ParameterMode::Zip),
+        Some(_) | None => Err(TestUrCodeXDMacroError::ModeIsNotRecognized(expr)),
+    }
+}
+
+/// The name of the attribute argument that overrides the maximum number of permutations for a
+/// single test.
+pub const MAX_PERMUTATIONS_ARGUMENT_NAME: &str = "max_permutations";
+
+/// Gets the maximum number of permutations from the value of a `max_permutations` argument.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::MaxPermutationsIsNotPositiveIntegerLiteral`] if the
+///   expression is not a positive integer literal.
+pub fn get_max_permutations_from_expr(expr: Expr) -> Result<usize, TestUrCodeXDMacroError> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(lit_int),
+        ..
+    }) = &expr
+    {
+        if let Ok(max_permutations) = lit_int.base10_parse::<usize>() {
+            if max_permutations > 0 {
+                return Ok(max_permutations);
+            }
+        }
+    }
+
+    Err(TestUrCodeXDMacroError::MaxPermutationsIsNotPositiveIntegerLiteral(expr))
+}
+
+/// The arguments of a parameterized test that are not parameters.
+#[derive(Default)]
+pub struct ParameterizationOptions {
+    /// How parameter values are combined into test cases.
+    pub mode: ParameterMode,
+
+    /// The `name_format` argument, if one was given.
+    pub name_format: Option<LitStr>,
+
+    /// The exclusions from the `exclude` argument.
+    pub exclusions: Vec<Exclusion>,
+
+    /// The `filter` argument, if one was given.
+    pub filter: Option<ExprClosure>,
+
+    /// The `max_permutations` argument, if one was given.
+    pub max_permutation_count: Option<usize>,
+}
+
+/// Separates the `mode`, `name_format`, `exclude`, `filter`, and `max_permutations` arguments from
+/// the parameter assignments.
+///
+/// # Arguments
+///
+/// * `expr_assign_iter` - An iterator of assignment expressions.
+///
+/// # Returns
+///
+/// A tuple of the parameter assignments and the other arguments.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::NameFormatIsNotStringLiteral`] if `name_format` is not
+///   given a string literal.
+/// * Returns an error if `mode`, `exclude`, `filter`, or `max_permutations` are not given the
+///   expected kinds of expressions.
+pub fn partition_options(
+    expr_assign_iter: impl Iterator<Item = ExprAssign>,
+) -> Result<(Vec<ExprAssign>, ParameterizationOptions), TestUrCodeXDMacroError> {
+    let mut assignments = Vec::new();
+    let mut options = ParameterizationOptions::default();
+
+    for assign in expr_assign_iter {
+        match get_identifier_name_from_expr(&assign.left).as_deref() {
+            Some(MODE_ARGUMENT_NAME) => {
+                options.mode = get_mode_from_expr(*assign.right)?;
+            }
+            Some(NAME_FORMAT_ARGUMENT_NAME) => {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) = *assign.right
+                {
+                    options.name_format = Some(lit_str);
+                } else {
+                    return Err(TestUrCodeXDMacroError::NameFormatIsNotStringLiteral(
+                        *assign.right,
+                    ));
+                }
+            }
+            Some(EXCLUDE_ARGUMENT_NAME) => {
+                options
+                    .exclusions
+                    .extend(get_exclusions_from_expr(*assign.right)?);
+            }
+            Some(FILTER_ARGUMENT_NAME) => {
+                options.filter = Some(get_filter_from_expr(*assign.right)?);
+            }
+            Some(MAX_PERMUTATIONS_ARGUMENT_NAME) => {
+                options.max_permutation_count =
+                    Some(get_max_permutations_from_expr(*assign.right)?);
+            }
+            Some(_) | None => assignments.push(assign),
+        }
+    }
+
+    Ok((assignments, options))
+}
+
+/// Extracts the names from a tuple of identifiers, like `(a, b, expected)`.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_tuple_identifier_names_from_expr(&parse_quote! { (a, b) }).unwrap(),
+///     vec!["a", "b"]
+/// );
+/// ```
+///
+/// # Returns
+///
+/// * `Some(names)` if the expression is a non-empty tuple of identifiers.
+/// * `None` otherwise.
+#[must_use]
+fn get_tuple_identifier_names_from_expr(expr: &Expr) -> Option<Vec<String>> {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    match expr {
+        Expr::Tuple(tuple) if !tuple.elems.is_empty() => tuple
+            .elems
+            .iter()
+            .map(get_identifier_name_from_expr)
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Formats the key used for a tuple parameter in maps of parameter values.
+///
+/// Tuple parameters are permuted as a single parameter and then split into their elements with
+/// [`split_tuple_parameters`]. Identifiers cannot contain parentheses, so these keys never collide
+/// with the names of other parameters.
+#[must_use]
+fn format_tuple_parameter_key(names: &[String]) -> String {
+    format!("({})", names.join(", "))
+}
+
+/// Gets the names of a tuple parameter from its key in a map of parameter values.
+///
+/// # Returns
+///
+/// * `Some(names)` if the key was formatted by [`format_tuple_parameter_key`].
+/// * `None` otherwise.
+#[must_use]
+fn parse_tuple_parameter_key(key: &str) -> Option<Vec<&str>> {
+    key.strip_prefix('(')
+        .and_then(|key| key.strip_suffix(')'))
+        .map(|names| names.split(", ").collect())
+}
+
+/// Splits the values of tuple parameters into one value per name.
+///
+/// # Example
+///
+/// ```ignore
+/// let parameter_map = split_tuple_parameters(HashMap::from([
+///     ("(a, expected)".to_owned(), parse_quote! { (1, 2) }),
+///     ("b".to_owned(), parse_quote! { 3 }),
+/// ]));
+///
+/// // parameter_map contains `a = 1`, `expected = 2`, and `b = 3`
+/// ```
+#[must_use]
+pub fn split_tuple_parameters(parameter_map: HashMap<String, Expr>) -> HashMap<String, Expr> {
+    let mut split_parameter_map = HashMap::new();
+
+    for (key, value) in parameter_map {
+        match (parse_tuple_parameter_key(&key), value) {
+            (Some(names), Expr::Tuple(tuple)) => {
+                for (name, element) in names.into_iter().zip(tuple.elems) {
+                    split_parameter_map.insert(name.to_owned(), element);
+                }
+            }
+            (_, value) => {
+                split_parameter_map.insert(key, value);
+            }
+        }
+    }
+
+    split_parameter_map
+}
+
+/// Ensures that every value of a tuple parameter is a tuple with one element per name.
+///
+/// # Errors
+///
+/// * Returns a [`Error::TupleParameterValueDoesNotMatchNames`] for the first value that does not
+///   match.
+fn ensure_tuple_parameter_values_match_names(
+    names: &[String],
+    values: &[Expr],
+) -> Result<(), TestUrCodeXDMacroError> {
+    for value in values {
+        let is_match = matches!(value, Expr::Tuple(tuple) if tuple.elems.len() == names.len());
+
+        if !is_match {
+            return Err(
+                TestUrCodeXDMacroError::TupleParameterValueDoesNotMatchNames(value.clone()),
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Extracts a parameter map from an expression vector.
 ///
+/// Tuple parameters like `(a, expected) = [(1, 2), (3, 4)]` get a single key from
+/// [`format_tuple_parameter_key`] so that their values stay together when permuted.
+///
 /// # Example
 ///
 /// ```ignore
@@ -157,20 +759,35 @@ pub fn get_map_of_parameter_vectors_from_expr_assign_iter(
     let mut map = HashMap::new();
 
     for assign in expr_assign_iter {
-        let key = get_identifier_name_from_expr(&assign.left).ok_or(
-            TestUrCodeXDMacroError::ParameterAssignmentLeftHandSideIsNotIdentifier(
-                (*assign.left).clone(),
-            ),
-        )?;
+        let tuple_names = get_tuple_identifier_names_from_expr(&assign.left);
 
-        let value = iter_expr_literal_array(&assign.right)
-            .ok_or(
-                TestUrCodeXDMacroError::ParameterAssignmentRightHandSideIsNotArrayLiteral(
-                    (*assign.right).clone(),
+        let key = match &tuple_names {
+            Some(names) => format_tuple_parameter_key(names),
+            None => get_identifier_name_from_expr(&assign.left).ok_or(
+                TestUrCodeXDMacroError::ParameterAssignmentLeftHandSideIsNotIdentifier(
+                    (*assign.left).clone(),
                 ),
-            )?
-            .cloned()
-            .collect::<Vec<Expr>>();
+            )?,
+        };
+
+        let value = if let Some(boundary_values) = get_boundary_values_from_expr(&assign.right)? {
+            boundary_values
+        } else if let Some(range_values) = get_range_values_from_expr(&assign.right)? {
+            range_values
+        } else {
+            iter_expr_literal_array(&assign.right)
+                .ok_or(
+                    TestUrCodeXDMacroError::ParameterAssignmentRightHandSideIsNotArrayLiteral(
+                        (*assign.right).clone(),
+                    ),
+                )?
+                .cloned()
+                .collect::<Vec<Expr>>()
+        };
+
+        if let Some(names) = &tuple_names {
+            ensure_tuple_parameter_values_match_names(names, &value)?;
+        }
 
         map.insert(key, value);
     }
@@ -201,7 +818,7 @@ pub fn get_map_of_parameter_vectors_from_expr_assign_iter(
 /// # Errors
 ///
 /// * Returns a [`Error::SelfArgumentInTest`] if the function has a `self` argument.
-fn iter_fn_inputs(
+pub fn iter_fn_inputs(
     item: &ItemFn,
 ) -> impl Iterator<Item = Result<(String, &PatType), TestUrCodeXDMacroError>> + '_ {
     item.sig.inputs.iter().map(|input| match input {
@@ -264,10 +881,179 @@ pub fn iter_parameterized_fn_inputs<'item, 'parameter_map>(
                 .get(&name)
                 .ok_or_else(|| TestUrCodeXDMacroError::ArgumentHasNoParameter(pat_type.clone()))?;
 
-            Ok((name, &*pat_type.ty, expression))
+            Ok((name, &*pat_type.ty, expression))
+        }
+        Err(error) => Err(error),
+    })
+}
+
+/// A visitor that checks whether or not a syntax tree refers to any of a set of generic parameters or
+/// contains an `impl Trait` type.
+struct GenericUsageVisitor<'names> {
+    /// The names of the generic parameters to look for. Lifetimes include their leading `'`.
+    names: &'names HashSet<String>,
+
+    /// Whether or not a generic parameter or `impl Trait` type has been found.
+    found: bool,
+}
+
+// Only the methods for syntax that can refer to generic parameters need to be overridden.
+#[allow(clippy::missing_trait_methods)]
+impl<'ast> Visit<'ast> for GenericUsageVisitor<'_> {
+    fn visit_type_impl_trait(&mut self, _: &'ast TypeImplTrait) {
+        self.found = true;
+    }
+
+    fn visit_path(&mut self, path: &'ast Path) {
+        if path.leading_colon.is_none()
+            && path.segments.first().map_or(false, |segment| {
+                self.names.contains(&segment.ident.to_string())
+            })
+        {
+            self.found = true;
+        }
+
+        visit::visit_path(self, path);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        if self.names.contains(&lifetime.to_string()) {
+            self.found = true;
+        }
+    }
+}
+
+/// Gets the names of the generic parameters of a function.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(
+///     get_generic_parameter_names(&parse_quote! {
+///         fn test<'a, T, const N: usize>(a: &'a T, b: [i32; N]) {}
+///     }),
+///     HashSet::from(["'a".to_owned(), "T".to_owned(), "N".to_owned()])
+/// );
+/// ```
+///
+/// # Returns
+///
+/// A set of generic parameter names. Lifetimes include their leading `'`.
+#[must_use]
+pub fn get_generic_parameter_names(item: &ItemFn) -> HashSet<String> {
+    item.sig
+        .generics
+        .params
+        .iter()
+        .map(|parameter| match parameter {
+            GenericParam::Lifetime(lifetime) => lifetime.lifetime.to_string(),
+            GenericParam::Type(ty) => ty.ident.to_string(),
+            GenericParam::Const(constant) => constant.ident.to_string(),
+        })
+        .collect()
+}
+
+/// Checks whether or not a type refers to any of a function's generic parameters or contains an
+/// `impl Trait` type.
+///
+/// Parameter values for arguments of these types cannot be bound with an explicit type annotation,
+/// so the type must be inferred from the call to the parameter function instead.
+///
+/// # Example
+///
+/// ```ignore
+/// let names = HashSet::from(["T".to_owned()]);
+///
+/// assert!(type_depends_on_generics(&parse_quote! { Vec<T> }, &names));
+/// assert!(type_depends_on_generics(&parse_quote! { impl Display }, &names));
+/// assert!(!type_depends_on_generics(&parse_quote! { i32 }, &names));
+/// ```
+#[must_use]
+pub fn type_depends_on_generics(ty: &Type, generic_parameter_names: &HashSet<String>) -> bool {
+    let mut visitor = GenericUsageVisitor {
+        names: generic_parameter_names,
+        found: false,
+    };
+
+    visitor.visit_type(ty);
+
+    visitor.found
+}
+
+/// Ensures that every generic type and const parameter of a function can be inferred from the
+/// function's arguments.
+///
+/// A parameter can be inferred if it is used in an argument's type or in the bounds of another
+/// generic parameter. Lifetime parameters are always inferred.
+///
+/// # Errors
+///
+/// * Returns a [`Error::GenericParameterCannotBeInferred`] for the first generic parameter that
+///   cannot be inferred.
+pub fn ensure_generic_parameters_are_inferable(
+    item: &ItemFn,
+) -> Result<(), TestUrCodeXDMacroError> {
+    for parameter in &item.sig.generics.params {
+        let ident = match parameter {
+            GenericParam::Lifetime(_) => continue,
+            GenericParam::Type(ty) => &ty.ident,
+            GenericParam::Const(constant) => &constant.ident,
+        };
+
+        let mut visitor = GenericUsageVisitor {
+            names: &HashSet::from([ident.to_string()]),
+            found: false,
+        };
+
+        // Look for the parameter in the argument types
+        for input in &item.sig.inputs {
+            if let FnArg::Typed(pat_type) = input {
+                visitor.visit_type(&pat_type.ty);
+            }
         }
-        Err(error) => Err(error),
-    })
+
+        // Look for the parameter in the bounds of the other generic parameters
+        for other_parameter in &item.sig.generics.params {
+            if let GenericParam::Type(other_ty) = other_parameter {
+                if other_ty.ident != *ident {
+                    for bound in &other_ty.bounds {
+                        visitor.visit_type_param_bound(bound);
+                    }
+                }
+            }
+        }
+
+        // Look for the parameter in where clause predicates for other types
+        if let Some(where_clause) = &item.sig.generics.where_clause {
+            for predicate in &where_clause.predicates {
+                if let WherePredicate::Type(predicate_type) = predicate {
+                    if !type_is_ident(&predicate_type.bounded_ty, ident) {
+                        for bound in &predicate_type.bounds {
+                            visitor.visit_type_param_bound(bound);
+                        }
+                    }
+                }
+            }
+        }
+
+        if !visitor.found {
+            return Err(TestUrCodeXDMacroError::GenericParameterCannotBeInferred(
+                ident.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether or not a type is exactly a single identifier.
+fn type_is_ident(ty: &Type, ident: &Ident) -> bool {
+    // We intentionally ignore any other arms
+    #[allow(clippy::wildcard_enum_match_arm)]
+    match ty {
+        Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident(ident),
+        _ => false,
+    }
 }
 
 /// Removes the attributes for a function and returns them as an iterator.
@@ -295,6 +1081,55 @@ pub fn filter_fn_attrs_without_this_macro(
     })
 }
 
+/// The names of the attributes that also apply to the parameter function, since it contains the
+/// test function's body.
+const PARAMETER_FUNCTION_ATTRIBUTE_NAMES: &[&str] =
+    &["cfg", "allow", "warn", "deny", "forbid", "expect"];
+
+/// Checks whether or not an attribute should be applied to the parameter function as well as to
+/// the permutation functions.
+///
+/// Conditional compilation attributes need to be on both so that the parameter function is not
+/// compiled when the permutation functions that call it are not. Lint attributes need to be on
+/// the parameter function because that is where the test function's body ends up.
+#[must_use]
+pub fn is_parameter_function_attr(attribute: &Attribute) -> bool {
+    PARAMETER_FUNCTION_ATTRIBUTE_NAMES
+        .iter()
+        .any(|name| attribute.path().is_ident(name))
+}
+
+/// Checks whether or not an attribute should be applied to the permutation functions.
+///
+/// `#[test]` is left out since the permutation functions already have it and each one would
+/// otherwise run twice. `#[expect(...)]` is left out since the lint it expects would be emitted
+/// in the parameter function instead.
+#[must_use]
+pub fn is_permutation_function_attr(attribute: &Attribute) -> bool {
+    !attribute.path().is_ident("test") && !attribute.path().is_ident("expect")
+}
+
+/// Removes the attributes for a parameterized test function and returns the ones to apply to the
+/// permutation functions.
+///
+/// The attributes that also apply to the parameter function, like `#[cfg(...)]`, are left on the
+/// function.
+pub fn take_permutation_fn_attrs(item: &mut ItemFn) -> Vec<Attribute> {
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(item)).collect();
+
+    item.attrs = attributes
+        .iter()
+        .filter(|attribute| is_parameter_function_attr(attribute))
+        .cloned()
+        .collect();
+
+    attributes
+        .into_iter()
+        .filter(is_permutation_function_attr)
+        .collect()
+}
+
 #[cfg(test)]
 // Panic allowed to help with match expressions.
 //
@@ -305,7 +1140,6 @@ pub fn filter_fn_attrs_without_this_macro(
 mod tests {
     use std::iter;
 
-    use quote::ToTokens;
     use syn::parse_quote;
 
     use super::*;
@@ -369,6 +1203,164 @@ mod tests {
         assert!(iter_expr_literal_array(&parse_quote! { 1 }).is_none());
     }
 
+    #[test]
+    fn get_boundary_values_for_type_signed() {
+        let values: Vec<String> = get_boundary_values_for_type("i32")
+            .unwrap()
+            .iter()
+            .map(|value| value.to_token_stream().to_string())
+            .collect();
+
+        assert_eq!(values, vec!["i32 :: MIN", "- 1", "0", "1", "i32 :: MAX"]);
+    }
+
+    #[test]
+    fn get_boundary_values_for_type_unsigned() {
+        let values: Vec<String> = get_boundary_values_for_type("u8")
+            .unwrap()
+            .iter()
+            .map(|value| value.to_token_stream().to_string())
+            .collect();
+
+        assert_eq!(values, vec!["0", "1", "u8 :: MAX"]);
+    }
+
+    #[test]
+    fn get_boundary_values_for_type_float() {
+        let values = get_boundary_values_for_type("f64").unwrap();
+
+        assert_eq!(values.len(), 10);
+        assert_eq!(values[9].to_token_stream().to_string(), "f64 :: NAN");
+    }
+
+    #[test]
+    fn get_boundary_values_for_type_unsupported() {
+        assert!(get_boundary_values_for_type("String").is_none());
+    }
+
+    #[test]
+    fn get_boundary_values_from_expr_boundaries() {
+        let values = get_boundary_values_from_expr(&parse_quote! { boundaries(bool) })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].to_token_stream().to_string(), "false");
+        assert_eq!(values[1].to_token_stream().to_string(), "true");
+    }
+
+    #[test]
+    fn get_boundary_values_from_expr_not_boundaries() {
+        assert!(get_boundary_values_from_expr(&parse_quote! { [1, 2] })
+            .unwrap()
+            .is_none());
+        assert!(get_boundary_values_from_expr(&parse_quote! { from(CASES) })
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn get_boundary_values_from_expr_wrong_argument_count() {
+        assert!(matches!(
+            get_boundary_values_from_expr(&parse_quote! { boundaries(i32, u32) }),
+            Err(TestUrCodeXDMacroError::BoundariesHaveWrongArgumentCount(_))
+        ));
+        assert!(matches!(
+            get_boundary_values_from_expr(&parse_quote! { boundaries() }),
+            Err(TestUrCodeXDMacroError::BoundariesHaveWrongArgumentCount(_))
+        ));
+    }
+
+    #[test]
+    fn get_boundary_values_from_expr_unsupported_type() {
+        assert!(matches!(
+            get_boundary_values_from_expr(&parse_quote! { boundaries(String) }),
+            Err(TestUrCodeXDMacroError::BoundariesTypeIsNotSupported(_))
+        ));
+        assert!(matches!(
+            get_boundary_values_from_expr(&parse_quote! { boundaries(std::primitive::i32) }),
+            Err(TestUrCodeXDMacroError::BoundariesTypeIsNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn get_map_of_parameter_vectors_from_expr_assign_iter_boundaries() {
+        let map = get_map_of_parameter_vectors_from_expr_assign_iter(
+            vec![parse_quote! { a = boundaries(u16) }].into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["a"].len(), 3);
+        assert_eq!(map["a"][2].to_token_stream().to_string(), "u16 :: MAX");
+    }
+
+    #[test]
+    fn get_map_of_parameter_vectors_from_expr_assign_iter_range() {
+        let map = get_map_of_parameter_vectors_from_expr_assign_iter(
+            vec![parse_quote! { a = 1..=4 }].into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["a"].len(), 4);
+        assert_eq!(map["a"][3].to_token_stream().to_string(), "4");
+    }
+
+    #[test]
+    fn get_map_of_parameter_vectors_from_expr_assign_iter_tuple() {
+        let map = get_map_of_parameter_vectors_from_expr_assign_iter(
+            vec![parse_quote! { (a, expected) = [(1, 2), (3, 4)] }].into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["(a, expected)"].len(), 2);
+        assert_eq!(
+            map["(a, expected)"][1].to_token_stream().to_string(),
+            "(3 , 4)"
+        );
+    }
+
+    #[test]
+    fn get_map_of_parameter_vectors_from_expr_assign_iter_tuple_wrong_length() {
+        assert!(matches!(
+            get_map_of_parameter_vectors_from_expr_assign_iter(
+                vec![parse_quote! { (a, expected) = [(1, 2), (3, 4, 5)] }].into_iter(),
+            ),
+            Err(TestUrCodeXDMacroError::TupleParameterValueDoesNotMatchNames(_))
+        ));
+        assert!(matches!(
+            get_map_of_parameter_vectors_from_expr_assign_iter(
+                vec![parse_quote! { (a, expected) = [1, 2] }].into_iter(),
+            ),
+            Err(TestUrCodeXDMacroError::TupleParameterValueDoesNotMatchNames(_))
+        ));
+    }
+
+    #[test]
+    fn get_map_of_parameter_vectors_from_expr_assign_iter_tuple_not_identifiers() {
+        assert!(matches!(
+            get_map_of_parameter_vectors_from_expr_assign_iter(
+                vec![parse_quote! { (a, b.c) = [(1, 2)] }].into_iter(),
+            ),
+            Err(TestUrCodeXDMacroError::ParameterAssignmentLeftHandSideIsNotIdentifier(_))
+        ));
+    }
+
+    #[test]
+    fn split_tuple_parameters_mixed() {
+        let parameter_map = split_tuple_parameters(HashMap::from([
+            ("(a, expected)".to_owned(), parse_quote! { (1, 2) }),
+            ("b".to_owned(), parse_quote! { 3 }),
+        ]));
+
+        assert_eq!(parameter_map.len(), 3);
+        assert_eq!(parameter_map["a"].to_token_stream().to_string(), "1");
+        assert_eq!(parameter_map["expected"].to_token_stream().to_string(), "2");
+        assert_eq!(parameter_map["b"].to_token_stream().to_string(), "3");
+    }
+
     #[test]
     fn get_map_of_parameter_vectors_from_expr_assign_iter_empty() {
         let map = get_map_of_parameter_vectors_from_expr_assign_iter(vec![].into_iter()).unwrap();
@@ -728,4 +1720,348 @@ mod tests {
             "# [doc (hidden)]"
         );
     }
+
+    #[test]
+    fn is_parameter_function_attr_cfg_and_lints() {
+        assert!(is_parameter_function_attr(&parse_quote! { #[cfg(unix)] }));
+        assert!(!is_parameter_function_attr(
+            &parse_quote! { #[cfg_attr(unix, ignore)] }
+        ));
+        assert!(is_parameter_function_attr(
+            &parse_quote! { #[allow(clippy::unwrap_used)] }
+        ));
+        assert!(is_parameter_function_attr(
+            &parse_quote! { #[expect(unused_variables)] }
+        ));
+        assert!(!is_parameter_function_attr(&parse_quote! { #[ignore] }));
+        assert!(!is_parameter_function_attr(
+            &parse_quote! { #[should_panic(expected = "x")] }
+        ));
+        assert!(!is_parameter_function_attr(
+            &parse_quote! { #[doc(hidden)] }
+        ));
+    }
+
+    #[test]
+    fn is_permutation_function_attr_test_and_expect() {
+        assert!(!is_permutation_function_attr(&parse_quote! { #[test] }));
+        assert!(!is_permutation_function_attr(
+            &parse_quote! { #[expect(unused_variables)] }
+        ));
+        assert!(is_permutation_function_attr(&parse_quote! { #[ignore] }));
+        assert!(is_permutation_function_attr(
+            &parse_quote! { #[should_panic(expected = "x")] }
+        ));
+        assert!(is_permutation_function_attr(&parse_quote! { #[cfg(unix)] }));
+    }
+
+    #[test]
+    fn get_collection_expr_from_expr_from() {
+        assert_eq!(
+            get_collection_expr_from_expr(&parse_quote! { from(CASES) })
+                .unwrap()
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            "CASES"
+        );
+    }
+
+    #[test]
+    fn get_collection_expr_from_expr_not_from() {
+        assert!(get_collection_expr_from_expr(&parse_quote! { [1, 2, 3] })
+            .unwrap()
+            .is_none());
+        assert!(
+            get_collection_expr_from_expr(&parse_quote! { cases(CASES) })
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn get_collection_expr_from_expr_path() {
+        assert_eq!(
+            get_collection_expr_from_expr(&parse_quote! { my_module::CASES })
+                .unwrap()
+                .unwrap()
+                .to_token_stream()
+                .to_string(),
+            "my_module :: CASES"
+        );
+    }
+
+    #[test]
+    fn get_range_values_from_expr_half_open() {
+        let values: Vec<String> = get_range_values_from_expr(&parse_quote! { 0..3 })
+            .unwrap()
+            .unwrap()
+            .iter()
+            .map(|value| value.to_token_stream().to_string())
+            .collect();
+
+        assert_eq!(values, vec!["0", "1", "2"]);
+    }
+
+    #[test]
+    fn get_range_values_from_expr_closed_negative_suffix() {
+        let values: Vec<String> = get_range_values_from_expr(&parse_quote! { -2i8..=1 })
+            .unwrap()
+            .unwrap()
+            .iter()
+            .map(|value| value.to_token_stream().to_string())
+            .collect();
+
+        assert_eq!(values, vec!["- 2i8", "- 1i8", "0i8", "1i8"]);
+    }
+
+    #[test]
+    fn get_range_values_from_expr_empty() {
+        assert!(get_range_values_from_expr(&parse_quote! { 3..0 })
+            .unwrap()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn get_range_values_from_expr_not_range() {
+        assert!(get_range_values_from_expr(&parse_quote! { [1, 2] })
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn get_range_values_from_expr_bounds_not_integer_literals() {
+        assert!(matches!(
+            get_range_values_from_expr(&parse_quote! { 0.. }),
+            Err(TestUrCodeXDMacroError::RangeBoundsAreNotIntegerLiterals(_))
+        ));
+        assert!(matches!(
+            get_range_values_from_expr(&parse_quote! { 0..N }),
+            Err(TestUrCodeXDMacroError::RangeBoundsAreNotIntegerLiterals(_))
+        ));
+        assert!(matches!(
+            get_range_values_from_expr(&parse_quote! { 0.0..1.0 }),
+            Err(TestUrCodeXDMacroError::RangeBoundsAreNotIntegerLiterals(_))
+        ));
+    }
+
+    #[test]
+    fn get_range_values_from_expr_too_many() {
+        assert!(matches!(
+            get_range_values_from_expr(&parse_quote! { 0..1000000000 }),
+            Err(TestUrCodeXDMacroError::TooManyPermutations { .. })
+        ));
+    }
+
+    #[test]
+    fn get_collection_expr_from_expr_wrong_argument_count() {
+        assert!(get_collection_expr_from_expr(&parse_quote! { from() }).is_err());
+        assert!(get_collection_expr_from_expr(&parse_quote! { from(A, B) }).is_err());
+    }
+
+    #[test]
+    fn partition_collection_parameters_mixed() {
+        let (assignments, collection_parameters) = partition_collection_parameters(
+            vec![
+                parse_quote! { a = [1, 2, 3] },
+                parse_quote! { b = from(CASES) },
+                parse_quote! { c = from(make_cases()) },
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(assignments[0].left.to_token_stream().to_string(), "a");
+        assert_eq!(collection_parameters.len(), 2);
+        assert_eq!(collection_parameters[0].0, "b");
+        assert_eq!(
+            collection_parameters[0].1.to_token_stream().to_string(),
+            "CASES"
+        );
+        assert_eq!(collection_parameters[1].0, "c");
+        assert_eq!(
+            collection_parameters[1].1.to_token_stream().to_string(),
+            "make_cases ()"
+        );
+    }
+
+    #[test]
+    fn partition_collection_parameters_left_hand_side_not_identifier() {
+        assert!(partition_collection_parameters(
+            vec![parse_quote! { (a, b) = from(CASES) }].into_iter()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn partition_options_name_format_present() {
+        let (assignments, options) = partition_options(
+            vec![
+                parse_quote! { a = [1, 2, 3] },
+                parse_quote! { name_format = "a_{a}" },
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(options.name_format.unwrap().value(), "a_{a}");
+    }
+
+    #[test]
+    fn partition_options_absent() {
+        let (assignments, options) =
+            partition_options(vec![parse_quote! { a = [1, 2, 3] }].into_iter()).unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(options.mode, ParameterMode::Permute);
+        assert!(options.name_format.is_none());
+        assert!(options.exclusions.is_empty());
+        assert!(options.filter.is_none());
+    }
+
+    #[test]
+    fn partition_options_name_format_not_string_literal() {
+        assert!(partition_options(vec![parse_quote! { name_format = a }].into_iter()).is_err());
+    }
+
+    #[test]
+    fn partition_options_mode_zip() {
+        let (assignments, options) = partition_options(
+            vec![parse_quote! { a = [1, 2, 3] }, parse_quote! { mode = zip }].into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(options.mode, ParameterMode::Zip);
+    }
+
+    #[test]
+    fn partition_options_mode_not_recognized() {
+        assert!(matches!(
+            partition_options(vec![parse_quote! { mode = diagonal }].into_iter()),
+            Err(TestUrCodeXDMacroError::ModeIsNotRecognized(_))
+        ));
+    }
+
+    #[test]
+    fn partition_options_max_permutations() {
+        let (assignments, options) = partition_options(
+            vec![
+                parse_quote! { a = [1, 2, 3] },
+                parse_quote! { max_permutations = 1_000 },
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(options.max_permutation_count, Some(1_000));
+    }
+
+    #[test]
+    fn partition_options_max_permutations_not_positive_integer_literal() {
+        for expr in [
+            parse_quote! { max_permutations = 0 },
+            parse_quote! { max_permutations = -5 },
+            parse_quote! { max_permutations = "many" },
+            parse_quote! { max_permutations = LIMIT },
+        ] {
+            assert!(matches!(
+                partition_options(vec![expr].into_iter()),
+                Err(TestUrCodeXDMacroError::MaxPermutationsIsNotPositiveIntegerLiteral(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn partition_options_exclude_and_filter() {
+        let (assignments, options) = partition_options(
+            vec![
+                parse_quote! { a = [1, 2, 3] },
+                parse_quote! { exclude = [(a = 1), (a = 2)] },
+                parse_quote! { filter = |a| *a > 0 },
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(assignments.len(), 1);
+        assert_eq!(options.exclusions.len(), 2);
+        assert!(options.filter.is_some());
+    }
+
+    #[test]
+    fn get_generic_parameter_names_all_kinds() {
+        assert_eq!(
+            get_generic_parameter_names(&parse_quote! {
+                fn test<'a, T, const N: usize>(a: &'a T, b: [i32; N]) {}
+            }),
+            HashSet::from(["'a".to_owned(), "T".to_owned(), "N".to_owned()])
+        );
+    }
+
+    #[test]
+    fn type_depends_on_generics_various() {
+        let names = HashSet::from(["T".to_owned(), "'a".to_owned(), "N".to_owned()]);
+
+        assert!(type_depends_on_generics(&parse_quote! { T }, &names));
+        assert!(type_depends_on_generics(&parse_quote! { Vec<T> }, &names));
+        assert!(type_depends_on_generics(&parse_quote! { T::Item }, &names));
+        assert!(type_depends_on_generics(&parse_quote! { &'a str }, &names));
+        assert!(type_depends_on_generics(&parse_quote! { [i32; N] }, &names));
+        assert!(type_depends_on_generics(
+            &parse_quote! { impl Display },
+            &names
+        ));
+        assert!(type_depends_on_generics(
+            &parse_quote! { Vec<impl Display> },
+            &names
+        ));
+        assert!(!type_depends_on_generics(&parse_quote! { i32 }, &names));
+        assert!(!type_depends_on_generics(&parse_quote! { Vec<U> }, &names));
+        assert!(!type_depends_on_generics(
+            &parse_quote! { &'static str },
+            &names
+        ));
+        assert!(!type_depends_on_generics(
+            &parse_quote! { ::T::Item },
+            &names
+        ));
+    }
+
+    #[test]
+    fn ensure_generic_parameters_are_inferable_inferable() {
+        assert!(ensure_generic_parameters_are_inferable(&parse_quote! {
+            fn test<'a, T: Display, const N: usize>(a: &'a T, b: [i32; N]) {}
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn ensure_generic_parameters_are_inferable_from_bounds() {
+        assert!(ensure_generic_parameters_are_inferable(&parse_quote! {
+            fn test<T, I: IntoIterator<Item = T>>(a: I) {}
+        })
+        .is_ok());
+
+        assert!(ensure_generic_parameters_are_inferable(&parse_quote! {
+            fn test<T, I>(a: I) where I: IntoIterator<Item = T> {}
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn ensure_generic_parameters_are_inferable_not_inferable() {
+        match ensure_generic_parameters_are_inferable(&parse_quote! {
+            fn test<T: Default>(a: i32) where T: Clone {}
+        }) {
+            Err(TestUrCodeXDMacroError::GenericParameterCannotBeInferred(ident)) => {
+                assert_eq!(ident.to_string(), "T");
+            }
+            _ => panic!("expected an error"),
+        }
+    }
 }
@@ -0,0 +1,356 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Skipping permutations of a parameterized test with `exclude = [...]` and `filter = |...| ...`.
+//!
+//! Exclusions are applied at compile time, so excluded permutations never generate a test. Filters
+//! are closures that are called at runtime at the start of each permutation, since their result
+//! cannot be known while the macro runs.
+
+use super::extracting::get_identifier_name_from_pat;
+use crate::errors::TestUrCodeXDMacroError;
+use proc_macro2::Ident;
+use quote::{format_ident, quote, ToTokens};
+use std::collections::{HashMap, HashSet};
+use syn::{Expr, ExprClosure, Pat};
+
+/// The name of the attribute argument that excludes permutations.
+pub const EXCLUDE_ARGUMENT_NAME: &str = "exclude";
+
+/// The name of the attribute argument that filters permutations at runtime.
+pub const FILTER_ARGUMENT_NAME: &str = "filter";
+
+/// A set of parameter values that should not be tested together, written like `(x = 5, y = 2)`.
+pub struct Exclusion {
+    /// The exclusion's expression, used to point errors to it.
+    pub expr: Expr,
+
+    /// The names of the parameters and the source text of their excluded values.
+    pub values: Vec<(String, String)>,
+}
+
+/// Gets the exclusions from the value of an `exclude` argument.
+///
+/// # Example
+///
+/// ```ignore
+/// let exclusions = get_exclusions_from_expr(parse_quote! { [(x = 5, y = 2), (x = 7)] })?;
+///
+/// assert_eq!(exclusions.len(), 2);
+/// ```
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments`] if the value is not an
+///   array literal of parenthesized assignments.
+pub fn get_exclusions_from_expr(expr: Expr) -> Result<Vec<Exclusion>, TestUrCodeXDMacroError> {
+    let Expr::Array(array) = expr else {
+        return Err(TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments(expr));
+    };
+
+    array
+        .elems
+        .into_iter()
+        .map(get_exclusion_from_expr)
+        .collect()
+}
+
+/// Gets a single exclusion from an expression like `(x = 5, y = 2)` or `(x = 5)`.
+fn get_exclusion_from_expr(expr: Expr) -> Result<Exclusion, TestUrCodeXDMacroError> {
+    let assignments: Vec<&Expr> = if let Expr::Tuple(tuple) = &expr {
+        tuple.elems.iter().collect()
+    } else if let Expr::Paren(paren) = &expr {
+        vec![&*paren.expr]
+    } else {
+        return Err(TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments(expr));
+    };
+
+    let mut values = Vec::new();
+
+    for assignment in assignments {
+        let Expr::Assign(assign) = assignment else {
+            return Err(TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments(
+                assignment.clone(),
+            ));
+        };
+
+        let Expr::Path(path) = &*assign.left else {
+            return Err(TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments(
+                assignment.clone(),
+            ));
+        };
+
+        let Some(name) = path.path.get_ident() else {
+            return Err(TestUrCodeXDMacroError::ExcludeIsNotListOfAssignments(
+                assignment.clone(),
+            ));
+        };
+
+        values.push((name.to_string(), assign.right.to_token_stream().to_string()));
+    }
+
+    Ok(Exclusion { expr, values })
+}
+
+/// Removes the excluded permutations from a vector of parameter maps.
+///
+/// A permutation is excluded if all of the values of any one exclusion match it. Values are
+/// compared by their source text.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ExclusionReferencesUnknownParameter`] if an exclusion
+///   refers to a parameter that does not have an array literal value.
+/// * Returns [`TestUrCodeXDMacroError::ExclusionMatchesNoPermutation`] if an exclusion does not
+///   match any permutation, which usually means that it has a typo.
+pub fn apply_exclusions(
+    vec_of_parameter_maps: Vec<HashMap<String, Expr>>,
+    exclusions: &[Exclusion],
+) -> Result<Vec<HashMap<String, Expr>>, TestUrCodeXDMacroError> {
+    let parameter_names: HashSet<&String> = vec_of_parameter_maps
+        .iter()
+        .flat_map(HashMap::keys)
+        .collect();
+
+    for exclusion in exclusions {
+        if let Some((name, _)) = exclusion
+            .values
+            .iter()
+            .find(|(name, _)| !parameter_names.contains(name))
+        {
+            return Err(TestUrCodeXDMacroError::ExclusionReferencesUnknownParameter(
+                exclusion.expr.clone(),
+                name.clone(),
+            ));
+        }
+
+        if !vec_of_parameter_maps
+            .iter()
+            .any(|parameter_map| is_excluded(parameter_map, exclusion))
+        {
+            return Err(TestUrCodeXDMacroError::ExclusionMatchesNoPermutation(
+                exclusion.expr.clone(),
+            ));
+        }
+    }
+
+    Ok(vec_of_parameter_maps
+        .into_iter()
+        .filter(|parameter_map| {
+            !exclusions
+                .iter()
+                .any(|exclusion| is_excluded(parameter_map, exclusion))
+        })
+        .collect())
+}
+
+/// Checks whether or not a permutation matches all of the values of an exclusion.
+fn is_excluded(parameter_map: &HashMap<String, Expr>, exclusion: &Exclusion) -> bool {
+    exclusion.values.iter().all(|(name, value)| {
+        parameter_map
+            .get(name)
+            .map_or(false, |expr| expr.to_token_stream().to_string() == *value)
+    })
+}
+
+/// Gets the filter closure from the value of a `filter` argument.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::FilterIsNotClosure`] if the value is not a closure.
+pub fn get_filter_from_expr(expr: Expr) -> Result<ExprClosure, TestUrCodeXDMacroError> {
+    if let Expr::Closure(closure) = expr {
+        Ok(closure)
+    } else {
+        Err(TestUrCodeXDMacroError::FilterIsNotClosure(expr))
+    }
+}
+
+/// Gets the name of a filter closure's argument, which may have a type annotation.
+fn get_filter_argument_name(pat: &Pat) -> Option<String> {
+    if let Pat::Type(pat_type) = pat {
+        get_identifier_name_from_pat(&pat_type.pat)
+    } else {
+        get_identifier_name_from_pat(pat)
+    }
+}
+
+/// Generates the condition that calls the filter closure for a permutation.
+///
+/// The closure's arguments are matched to the test function's arguments by name and are passed by
+/// reference, so `filter = |y, x| x != y` works regardless of the order of the arguments.
+///
+/// # Arguments
+///
+/// * `filter` - The filter closure.
+/// * `argument_names` - The names of the test function's arguments.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::FilterArgumentIsNotParameter`] if a closure argument is not
+///   the name of one of the test function's arguments.
+pub fn generate_filter_condition(
+    filter: &ExprClosure,
+    argument_names: &HashSet<String>,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    let argument_idents = filter
+        .inputs
+        .iter()
+        .map(|input| match get_filter_argument_name(input) {
+            Some(name) if argument_names.contains(&name) => Ok(format_ident!("{}", name)),
+            Some(_) | None => Err(TestUrCodeXDMacroError::FilterArgumentIsNotParameter(
+                input.clone(),
+            )),
+        })
+        .collect::<Result<Vec<Ident>, _>>()?;
+
+    Ok(quote! {
+        (#filter)( #( &#argument_idents ),* )
+    })
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Indexing and slicing allowed to reduce length of test code.
+//
+// Panic allowed to help with match expressions.
+#[allow(clippy::unwrap_used, clippy::indexing_slicing, clippy::panic)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn make_parameter_maps() -> Vec<HashMap<String, Expr>> {
+        vec![
+            HashMap::from([
+                ("x".to_owned(), parse_quote! { 5 }),
+                ("y".to_owned(), parse_quote! { 1 }),
+            ]),
+            HashMap::from([
+                ("x".to_owned(), parse_quote! { 5 }),
+                ("y".to_owned(), parse_quote! { 2 }),
+            ]),
+            HashMap::from([
+                ("x".to_owned(), parse_quote! { 6 }),
+                ("y".to_owned(), parse_quote! { 2 }),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn get_exclusions_from_expr_tuples() {
+        let exclusions =
+            get_exclusions_from_expr(parse_quote! { [(x = 5, y = 2), (x = i32::MAX)] }).unwrap();
+
+        assert_eq!(exclusions.len(), 2);
+        assert_eq!(
+            exclusions[0].values,
+            vec![
+                ("x".to_owned(), "5".to_owned()),
+                ("y".to_owned(), "2".to_owned())
+            ]
+        );
+        assert_eq!(
+            exclusions[1].values,
+            vec![("x".to_owned(), "i32 :: MAX".to_owned())]
+        );
+    }
+
+    #[test]
+    fn get_exclusions_from_expr_invalid() {
+        assert!(get_exclusions_from_expr(parse_quote! { (x = 5) }).is_err());
+        assert!(get_exclusions_from_expr(parse_quote! { [5] }).is_err());
+        assert!(get_exclusions_from_expr(parse_quote! { [(x = 5, 2)] }).is_err());
+        assert!(get_exclusions_from_expr(parse_quote! { [(a::x = 5)] }).is_err());
+    }
+
+    #[test]
+    fn apply_exclusions_full() {
+        let exclusions = get_exclusions_from_expr(parse_quote! { [(x = 5, y = 2)] }).unwrap();
+
+        assert_eq!(
+            apply_exclusions(make_parameter_maps(), &exclusions)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn apply_exclusions_partial() {
+        let exclusions = get_exclusions_from_expr(parse_quote! { [(y = 2)] }).unwrap();
+
+        assert_eq!(
+            apply_exclusions(make_parameter_maps(), &exclusions)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn apply_exclusions_unknown_parameter() {
+        let exclusions = get_exclusions_from_expr(parse_quote! { [(z = 2)] }).unwrap();
+
+        match apply_exclusions(make_parameter_maps(), &exclusions) {
+            Err(TestUrCodeXDMacroError::ExclusionReferencesUnknownParameter(_, name)) => {
+                assert_eq!(name, "z");
+            }
+            _ => panic!("expected an unknown parameter error"),
+        }
+    }
+
+    #[test]
+    fn apply_exclusions_matches_nothing() {
+        let exclusions = get_exclusions_from_expr(parse_quote! { [(x = 6, y = 1)] }).unwrap();
+
+        assert!(matches!(
+            apply_exclusions(make_parameter_maps(), &exclusions),
+            Err(TestUrCodeXDMacroError::ExclusionMatchesNoPermutation(_))
+        ));
+    }
+
+    #[test]
+    fn get_filter_from_expr_closure() {
+        assert!(get_filter_from_expr(parse_quote! { |x| x > 0 }).is_ok());
+        assert!(get_filter_from_expr(parse_quote! { is_valid }).is_err());
+    }
+
+    #[test]
+    fn generate_filter_condition_by_name() {
+        let filter = get_filter_from_expr(parse_quote! { |y, x: &i32| x != y }).unwrap();
+
+        let argument_names = HashSet::from(["x".to_owned(), "y".to_owned()]);
+
+        assert_eq!(
+            generate_filter_condition(&filter, &argument_names)
+                .unwrap()
+                .to_string(),
+            "(| y , x : & i32 | x != y) (& y , & x)"
+        );
+    }
+
+    #[test]
+    fn generate_filter_condition_unknown_argument() {
+        let filter = get_filter_from_expr(parse_quote! { |z| z > 0 }).unwrap();
+
+        let argument_names = HashSet::from(["x".to_owned()]);
+
+        assert!(matches!(
+            generate_filter_condition(&filter, &argument_names),
+            Err(TestUrCodeXDMacroError::FilterArgumentIsNotParameter(_))
+        ));
+    }
+}
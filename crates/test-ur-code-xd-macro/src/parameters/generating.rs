@@ -25,7 +25,9 @@
 //!                               These take no arguments, but have the `#[test]` attribute while
 //!                               the parameter function does not.
 
-use quote::{format_ident, quote};
+use crate::errors::TestUrCodeXDMacroError;
+use quote::{format_ident, quote, quote_spanned};
+use std::collections::HashSet;
 use syn::{Attribute, Expr, Ident, ItemFn, Type};
 
 /// Creates an identifier for the parameter function.
@@ -39,7 +41,57 @@ use syn::{Attribute, Expr, Ident, ItemFn, Type};
 /// The identifier to be used for the parameter function.
 #[must_use]
 pub fn get_parameter_function_ident(item: &ItemFn) -> Ident {
-    format_ident!("_test_ur_code_xd_{}_parameter_function", item.sig.ident)
+    // Use the span of the original function's identifier so that any errors about this identifier
+    // point to the test function instead of into the macro internals
+    format_ident!(
+        "_test_ur_code_xd_{}_parameter_function",
+        item.sig.ident,
+        span = item.sig.ident.span()
+    )
+}
+
+/// Creates an identifier for a permutation function.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `index` - An integer index used to differentiate the permutations.
+///
+/// # Returns
+///
+/// The identifier to be used for the permutation function.
+#[must_use]
+pub fn get_permutation_function_ident(item: &ItemFn, index: usize) -> Ident {
+    // Use the span of the original function's identifier so that if the generated name collides
+    // with another function, the error points to the test function instead of into the macro
+    // internals
+    format_ident!("{}_{}", item.sig.ident, index, span = item.sig.ident.span())
+}
+
+/// Ensures that none of the generated function identifiers are the same.
+///
+/// # Arguments
+///
+/// * `idents` - The identifiers of the generated functions.
+///
+/// # Errors
+///
+/// * Returns a [`TestUrCodeXDMacroError::GeneratedNameCollision`] for the first identifier that
+///   is the same as an earlier one.
+pub fn ensure_generated_idents_are_unique<'ident>(
+    idents: impl IntoIterator<Item = &'ident Ident>,
+) -> Result<(), TestUrCodeXDMacroError> {
+    let mut names = HashSet::new();
+
+    for ident in idents {
+        if !names.insert(ident.to_string()) {
+            return Err(TestUrCodeXDMacroError::GeneratedNameCollision(
+                ident.clone(),
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 /// Generates the parameter function for a given test function.
@@ -58,17 +110,115 @@ pub fn generate_parameter_function(mut item: ItemFn) -> proc_macro2::TokenStream
 ///
 /// * `item` - The test case's original function.
 /// * `parameterization` - The parameterization to use for the permutation function.
-/// * `index` - An integer index used to differentiate the permutations.
+/// * `filter_condition` - The condition that decides whether or not the permutation runs, if any.
+/// * `test_function_ident` - The identifier of the permutation function.
 #[must_use]
 pub fn generate_permutation_function(
     attributes: &[Attribute],
     item: &ItemFn,
     parameterized_fn_inputs: &[(String, Type, Expr)],
-    index: usize,
+    filter_condition: Option<&proc_macro2::TokenStream>,
+    test_function_ident: &Ident,
 ) -> proc_macro2::TokenStream {
-    // Generate test function identifier
-    let test_function_ident = format_ident!("{}_{}", item.sig.ident, index);
+    // Generate the body that calls the parameter function
+    let body = generate_permutation_function_body(item, parameterized_fn_inputs, filter_condition);
 
+    // Generate token stream, spanned so that errors about the function's definition point to the
+    // original function's identifier
+    quote_spanned! { test_function_ident.span() =>
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () {
+            #body
+        }
+    }
+}
+
+/// Generates a permutation function for a given test function and parameterization that also
+/// iterates over the values of collection parameters at runtime.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `parameterization` - The parameterization to use for the permutation function. Collection
+///                        parameters are expected to be bound to their own identifiers.
+/// * `collection_parameters` - The names and collection expressions of the collection parameters.
+/// * `filter_condition` - The condition that decides whether or not each combination of values
+///                        runs, if any.
+/// * `test_function_ident` - The identifier of the permutation function.
+#[must_use]
+pub fn generate_collection_permutation_function(
+    attributes: &[Attribute],
+    item: &ItemFn,
+    parameterized_fn_inputs: &[(String, Type, Expr)],
+    collection_parameters: &[(String, Expr)],
+    filter_condition: Option<&proc_macro2::TokenStream>,
+    test_function_ident: &Ident,
+) -> proc_macro2::TokenStream {
+    // Generate the body that calls the parameter function
+    let mut body =
+        generate_permutation_function_body(item, parameterized_fn_inputs, filter_condition);
+
+    // Wrap the body in a loop for each collection parameter, with the first parameter outermost
+    for (name, collection) in collection_parameters.iter().rev() {
+        let ident = format_ident!("{}", name);
+
+        body = quote! {
+            for #ident in ::std::iter::Iterator::cloned((#collection).iter()) {
+                #body
+            }
+        };
+    }
+
+    // Generate token stream, spanned so that errors about the function's definition point to the
+    // original function's identifier
+    quote_spanned! { test_function_ident.span() =>
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () {
+            #body
+        }
+    }
+}
+
+/// Wraps the permutation functions of a test in a module named after the test.
+///
+/// This is used when the test has a name format, so that the generated tests can be filtered with
+/// `cargo test <test name>::<formatted name>`.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `permutation_functions` - The generated permutation functions.
+#[must_use]
+pub fn generate_permutation_module(
+    item: &ItemFn,
+    permutation_functions: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let module_ident = &item.sig.ident;
+
+    // Formatted names can contain parameter values like `MAX`, which are not snake case
+    quote_spanned! { module_ident.span() =>
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #module_ident {
+            use super::*;
+
+            #permutation_functions
+        }
+    }
+}
+
+/// Generates the body of a permutation function, which binds each parameter and calls the parameter
+/// function.
+///
+/// If there is a filter condition, the parameter function is only called if the condition is true.
+#[must_use]
+fn generate_permutation_function_body(
+    item: &ItemFn,
+    parameterized_fn_inputs: &[(String, Type, Expr)],
+    filter_condition: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
     // Get test function with parameters identifier
     let test_function_with_parameters_ident = get_parameter_function_ident(item);
 
@@ -82,19 +232,30 @@ pub fn generate_permutation_function(
 
     let let_expression_values = parameterized_fn_inputs.iter().map(|(_, _, expr)| expr);
 
+    // Generate the call to the parameter function
+    let mut call = quote! {
+        #test_function_with_parameters_ident ( #( #let_expression_identifiers ),* );
+    };
+
+    if let Some(filter_condition) = filter_condition {
+        call = quote! {
+            if #filter_condition {
+                #call
+            }
+        };
+    }
+
     // Generate token stream
     quote! {
-        #[test]
-        #( #attributes )*
-        fn #test_function_ident () {
-            #(let #let_expression_identifiers: #let_expression_types = #let_expression_values;)*
+        #(let #let_expression_identifiers: #let_expression_types = #let_expression_values;)*
 
-            #test_function_with_parameters_ident ( #( #let_expression_identifiers ),* );
-        }
+        #call
     }
 }
 
 #[cfg(test)]
+// Panic allowed to help with match expressions.
+#[allow(clippy::panic)]
 mod tests {
     use quote::ToTokens;
     use syn::parse_quote;
@@ -140,8 +301,13 @@ mod tests {
             fn asdf() {}
         };
 
-        let permutation_function =
-            generate_permutation_function(&attributes, &item, &Vec::new(), 0);
+        let permutation_function = generate_permutation_function(
+            &attributes,
+            &item,
+            &Vec::new(),
+            None,
+            &get_permutation_function_ident(&item, 0),
+        );
 
         assert_eq!(
             permutation_function.to_token_stream().to_string(),
@@ -166,7 +332,8 @@ mod tests {
                 ("a".to_owned(), parse_quote! { u32 }, parse_quote! { 1 }),
                 ("b".to_owned(), parse_quote! { u32 }, parse_quote! { 2 }),
             ],
-            0,
+            None,
+            &get_permutation_function_ident(&item, 0),
         );
 
         assert_eq!(permutation_function.to_token_stream().to_string(), "# [test] fn asdf_0 () { let a : u32 = 1 ; let b : u32 = 2 ; _test_ur_code_xd_asdf_parameter_function (a , b) ; }");
@@ -189,9 +356,100 @@ mod tests {
                 ("a".to_owned(), parse_quote! { u32 }, parse_quote! { 1 }),
                 ("b".to_owned(), parse_quote! { u32 }, parse_quote! { 2 }),
             ],
-            0,
+            None,
+            &get_permutation_function_ident(&item, 0),
         );
 
         assert_eq!(permutation_function.to_token_stream().to_string(), "# [test] # [doc (hidden)] # [ignore] fn asdf_0 () { let a : u32 = 1 ; let b : u32 = 2 ; _test_ur_code_xd_asdf_parameter_function (a , b) ; }");
     }
+
+    #[test]
+    fn generate_collection_permutation_function_two() {
+        let attributes = Vec::new();
+
+        let item = parse_quote! {
+            fn asdf(a: u32, b: u32) {
+                assert_eq!(a, b);
+            }
+        };
+
+        let permutation_function = generate_collection_permutation_function(
+            &attributes,
+            &item,
+            &vec![
+                ("a".to_owned(), parse_quote! { u32 }, parse_quote! { 1 }),
+                ("b".to_owned(), parse_quote! { u32 }, parse_quote! { b }),
+            ],
+            &vec![("b".to_owned(), parse_quote! { CASES })],
+            None,
+            &get_permutation_function_ident(&item, 0),
+        );
+
+        assert_eq!(permutation_function.to_token_stream().to_string(), "# [test] fn asdf_0 () { for b in :: std :: iter :: Iterator :: cloned ((CASES) . iter ()) { let a : u32 = 1 ; let b : u32 = b ; _test_ur_code_xd_asdf_parameter_function (a , b) ; } }");
+    }
+
+    #[test]
+    fn generate_collection_permutation_function_nested() {
+        let attributes = Vec::new();
+
+        let item = parse_quote! {
+            fn asdf(a: u32, b: u32) {
+                assert_eq!(a, b);
+            }
+        };
+
+        let permutation_function = generate_collection_permutation_function(
+            &attributes,
+            &item,
+            &vec![
+                ("a".to_owned(), parse_quote! { u32 }, parse_quote! { a }),
+                ("b".to_owned(), parse_quote! { u32 }, parse_quote! { b }),
+            ],
+            &vec![
+                ("a".to_owned(), parse_quote! { A }),
+                ("b".to_owned(), parse_quote! { B }),
+            ],
+            None,
+            &get_permutation_function_ident(&item, 0),
+        );
+
+        assert_eq!(permutation_function.to_token_stream().to_string(), "# [test] fn asdf_0 () { for a in :: std :: iter :: Iterator :: cloned ((A) . iter ()) { for b in :: std :: iter :: Iterator :: cloned ((B) . iter ()) { let a : u32 = a ; let b : u32 = b ; _test_ur_code_xd_asdf_parameter_function (a , b) ; } } }");
+    }
+
+    #[test]
+    fn generate_permutation_module_simple() {
+        let item = parse_quote! {
+            fn asdf(a: u32) {}
+        };
+
+        let permutation_module = generate_permutation_module(&item, &quote! { fn a_1() {} });
+
+        assert_eq!(
+            permutation_module.to_string(),
+            "# [cfg (test)] # [allow (non_snake_case)] mod asdf { use super :: * ; fn a_1 () { } }"
+        );
+    }
+
+    #[test]
+    fn ensure_generated_idents_are_unique_unique() {
+        let idents = vec![format_ident!("a_0"), format_ident!("a_1")];
+
+        assert!(ensure_generated_idents_are_unique(&idents).is_ok());
+    }
+
+    #[test]
+    fn ensure_generated_idents_are_unique_collision() {
+        let idents = vec![
+            format_ident!("a_0"),
+            format_ident!("a_1"),
+            format_ident!("a_0"),
+        ];
+
+        match ensure_generated_idents_are_unique(&idents) {
+            Err(TestUrCodeXDMacroError::GeneratedNameCollision(ident)) => {
+                assert_eq!(ident.to_string(), "a_0");
+            }
+            _ => panic!("expected a name collision"),
+        }
+    }
 }
@@ -0,0 +1,331 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Formatting of generated test names from a `name_format` template.
+//!
+//! A name format like `"x_{x}_y_{y}"` has each `{<parameter>}` placeholder replaced with the
+//! parameter's value for a given permutation, so that `x = [5]` and `y = [1]` generate a test named
+//! `x_5_y_1`.
+
+use crate::errors::TestUrCodeXDMacroError;
+use proc_macro2::Ident;
+use quote::ToTokens;
+use std::{collections::HashMap, mem};
+use syn::{Expr, ExprLit, Lit, LitStr};
+
+/// The name of the attribute argument that sets the name format.
+pub const NAME_FORMAT_ARGUMENT_NAME: &str = "name_format";
+
+/// A piece of a parsed name format.
+#[derive(Debug, PartialEq, Eq)]
+enum NameFormatPiece {
+    /// Text that is copied into the name as-is.
+    Text(String),
+
+    /// A placeholder that is replaced with the value of the named parameter.
+    Parameter(String),
+}
+
+/// Parses a name format into text and parameter placeholder pieces.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::NameFormatHasUnclosedPlaceholder`] if a `{` is not followed
+///   by a `}`.
+fn parse_name_format(name_format: &LitStr) -> Result<Vec<NameFormatPiece>, TestUrCodeXDMacroError> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let value = name_format.value();
+    let mut characters = value.chars();
+
+    while let Some(character) = characters.next() {
+        if character == '{' {
+            let mut parameter_name = String::new();
+            let mut is_closed = false;
+
+            for placeholder_character in characters.by_ref() {
+                if placeholder_character == '}' {
+                    is_closed = true;
+                    break;
+                }
+
+                parameter_name.push(placeholder_character);
+            }
+
+            if !is_closed {
+                return Err(TestUrCodeXDMacroError::NameFormatHasUnclosedPlaceholder(
+                    name_format.clone(),
+                ));
+            }
+
+            if !text.is_empty() {
+                pieces.push(NameFormatPiece::Text(mem::take(&mut text)));
+            }
+
+            pieces.push(NameFormatPiece::Parameter(parameter_name.trim().to_owned()));
+        } else {
+            text.push(character);
+        }
+    }
+
+    if !text.is_empty() {
+        pieces.push(NameFormatPiece::Text(text));
+    }
+
+    Ok(pieces)
+}
+
+/// Converts a parameter value into text that can be used within an identifier.
+///
+/// String and character literals use their value, with special characters escaped. Other expressions use their source text. Minus
+/// signs become `neg` and any other characters that cannot appear in an identifier become
+/// underscores.
+///
+/// # Example
+///
+/// ```ignore
+/// assert_eq!(format_parameter_value(&parse_quote! { -1.5 }), "neg_1_5");
+/// assert_eq!(format_parameter_value(&parse_quote! { i32::MAX }), "i32_MAX");
+/// assert_eq!(format_parameter_value(&parse_quote! { "hello world" }), "hello_world");
+/// ```
+#[must_use]
+fn format_parameter_value(expr: &Expr) -> String {
+    let text = if let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit_str),
+        ..
+    }) = expr
+    {
+        lit_str.value().escape_default().to_string()
+    } else if let Expr::Lit(ExprLit {
+        lit: Lit::Char(lit_char),
+        ..
+    }) = expr
+    {
+        lit_char.value().escape_default().to_string()
+    } else {
+        expr.to_token_stream().to_string()
+    };
+
+    let mut formatted = String::new();
+
+    for character in text.chars() {
+        if character.is_ascii_alphanumeric() {
+            formatted.push(character);
+        } else if character == '-' {
+            formatted.push_str("_neg_");
+        } else {
+            formatted.push('_');
+        }
+    }
+
+    // Collapse runs of underscores and trim them from the ends
+    let formatted = formatted
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if formatted.is_empty() {
+        "empty".to_owned()
+    } else {
+        formatted
+    }
+}
+
+/// Formats the name of a permutation function from a name format.
+///
+/// # Arguments
+///
+/// * `name_format` - The name format string literal.
+/// * `parameter_map` - The array literal parameter values for the permutation.
+/// * `item_ident` - The identifier of the test case's original function, whose span is used for
+///                  the generated identifier.
+///
+/// # Returns
+///
+/// The identifier to be used for the permutation function.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::NameFormatHasUnclosedPlaceholder`] if a placeholder is not
+///   closed.
+/// * Returns [`TestUrCodeXDMacroError::NameFormatReferencesUnknownParameter`] if a placeholder
+///   refers to a parameter that does not have an array literal value.
+/// * Returns [`TestUrCodeXDMacroError::NameFormatProducesInvalidIdentifier`] if the formatted name
+///   is not a valid identifier.
+pub fn format_permutation_function_ident(
+    name_format: &LitStr,
+    parameter_map: &HashMap<String, Expr>,
+    item_ident: &Ident,
+) -> Result<Ident, TestUrCodeXDMacroError> {
+    let mut name = String::new();
+
+    for piece in parse_name_format(name_format)? {
+        match piece {
+            NameFormatPiece::Text(text) => name.push_str(&text),
+            NameFormatPiece::Parameter(parameter_name) => {
+                let value = parameter_map.get(&parameter_name).ok_or_else(|| {
+                    TestUrCodeXDMacroError::NameFormatReferencesUnknownParameter(
+                        name_format.clone(),
+                        parameter_name.clone(),
+                    )
+                })?;
+
+                name.push_str(&format_parameter_value(value));
+            }
+        }
+    }
+
+    // Identifiers cannot start with a digit
+    if name.starts_with(|character: char| character.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    let Ok(mut ident) = syn::parse_str::<Ident>(&name) else {
+        return Err(TestUrCodeXDMacroError::NameFormatProducesInvalidIdentifier(
+            name_format.clone(),
+            name,
+        ));
+    };
+
+    ident.set_span(item_ident.span());
+
+    Ok(ident)
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Panic allowed to help with match expressions.
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_name_format_text_only() {
+        assert_eq!(
+            parse_name_format(&parse_quote! { "abc" }).unwrap(),
+            vec![NameFormatPiece::Text("abc".to_owned())]
+        );
+    }
+
+    #[test]
+    fn parse_name_format_parameters() {
+        assert_eq!(
+            parse_name_format(&parse_quote! { "x_{x}_y_{ y }" }).unwrap(),
+            vec![
+                NameFormatPiece::Text("x_".to_owned()),
+                NameFormatPiece::Parameter("x".to_owned()),
+                NameFormatPiece::Text("_y_".to_owned()),
+                NameFormatPiece::Parameter("y".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_name_format_unclosed() {
+        assert!(matches!(
+            parse_name_format(&parse_quote! { "x_{x" }),
+            Err(TestUrCodeXDMacroError::NameFormatHasUnclosedPlaceholder(_))
+        ));
+    }
+
+    #[test]
+    fn format_parameter_value_various() {
+        assert_eq!(format_parameter_value(&parse_quote! { 5 }), "5");
+        assert_eq!(format_parameter_value(&parse_quote! { -1 }), "neg_1");
+        assert_eq!(format_parameter_value(&parse_quote! { -1.5 }), "neg_1_5");
+        assert_eq!(
+            format_parameter_value(&parse_quote! { i32::MAX }),
+            "i32_MAX"
+        );
+        assert_eq!(
+            format_parameter_value(&parse_quote! { "hello world" }),
+            "hello_world"
+        );
+        assert_eq!(format_parameter_value(&parse_quote! { 'a' }), "a");
+        assert_eq!(format_parameter_value(&parse_quote! { '\0' }), "u_0");
+        assert_eq!(format_parameter_value(&parse_quote! { '\u{7f}' }), "u_7f");
+        assert_eq!(format_parameter_value(&parse_quote! { "" }), "empty");
+    }
+
+    #[test]
+    fn format_permutation_function_ident_simple() {
+        let parameter_map = HashMap::from([
+            ("x".to_owned(), parse_quote! { 5 }),
+            ("y".to_owned(), parse_quote! { 1 }),
+        ]);
+
+        assert_eq!(
+            format_permutation_function_ident(
+                &parse_quote! { "x_{x}_y_{y}" },
+                &parameter_map,
+                &format_ident!("example")
+            )
+            .unwrap()
+            .to_string(),
+            "x_5_y_1"
+        );
+    }
+
+    #[test]
+    fn format_permutation_function_ident_leading_digit() {
+        let parameter_map = HashMap::from([("x".to_owned(), parse_quote! { 5 })]);
+
+        assert_eq!(
+            format_permutation_function_ident(
+                &parse_quote! { "{x}" },
+                &parameter_map,
+                &format_ident!("example")
+            )
+            .unwrap()
+            .to_string(),
+            "_5"
+        );
+    }
+
+    #[test]
+    fn format_permutation_function_ident_unknown_parameter() {
+        match format_permutation_function_ident(
+            &parse_quote! { "x_{z}" },
+            &HashMap::new(),
+            &format_ident!("example"),
+        ) {
+            Err(TestUrCodeXDMacroError::NameFormatReferencesUnknownParameter(_, name)) => {
+                assert_eq!(name, "z");
+            }
+            _ => panic!("expected an unknown parameter error"),
+        }
+    }
+
+    #[test]
+    fn format_permutation_function_ident_invalid_identifier() {
+        let parameter_map = HashMap::from([("b".to_owned(), parse_quote! { true })]);
+
+        assert!(matches!(
+            format_permutation_function_ident(
+                &parse_quote! { "{b}" },
+                &parameter_map,
+                &format_ident!("example")
+            ),
+            Err(TestUrCodeXDMacroError::NameFormatProducesInvalidIdentifier(
+                _,
+                _
+            ))
+        ));
+    }
+}
@@ -0,0 +1,381 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Parsing and generation for the `#[test_with_random_values]` attribute.
+//!
+//! A function decorated with the attribute is broken down into two functions:
+//!
+//! * **Parameter function** - The original function, renamed in the same way as for
+//!                            `#[test_with_parameter_values]`.
+//! * **Test function** - A function with the original name and the `#[test]` attribute which
+//!                       generates random values and calls the parameter function for each set of
+//!                       values.
+
+use crate::{
+    errors::TestUrCodeXDMacroError,
+    parameters::{
+        extracting::{
+            ensure_generic_parameters_are_inferable, filter_fn_attrs_without_this_macro,
+            get_generic_parameter_names, iter_parameterized_fn_inputs, take_fn_attrs,
+        },
+        generating::{generate_parameter_function, get_parameter_function_ident},
+        get_binding_type,
+    },
+};
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, Ident, ItemFn, Token, Type,
+};
+
+/// The path to the runtime support module in the main crate.
+const RUNTIME_MODULE_PATH: &str = "test_ur_code_xd::utilities::random_values";
+
+/// A single randomly generated parameter, like `x: i32 = range(0..100)`.
+pub struct RandomValueParameter {
+    /// The name of the parameter.
+    pub ident: Ident,
+
+    /// The optional type annotation of the parameter.
+    pub ty: Option<Type>,
+
+    /// The expression for the strategy used to generate values.
+    pub strategy: Expr,
+}
+
+/// A single argument to the attribute.
+enum RandomValuesArgument {
+    /// A randomly generated parameter.
+    Parameter(Box<RandomValueParameter>),
+
+    /// The `seed = <value>` option.
+    Seed(Expr),
+
+    /// The `cases = <value>` option.
+    Cases(Expr),
+}
+
+impl Parse for RandomValuesArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        let ty = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Some(input.parse::<Type>()?)
+        } else {
+            None
+        };
+
+        input.parse::<Token![=]>()?;
+
+        let value: Expr = input.parse()?;
+
+        Ok(match (ident.to_string().as_str(), ty) {
+            ("seed", None) => Self::Seed(value),
+            ("cases", None) => Self::Cases(value),
+            (_, ty) => Self::Parameter(Box::new(RandomValueParameter {
+                ident,
+                ty,
+                strategy: value,
+            })),
+        })
+    }
+}
+
+/// The parsed arguments of the attribute.
+#[derive(Default)]
+pub struct RandomValuesArguments {
+    /// The randomly generated parameters.
+    pub parameters: Vec<RandomValueParameter>,
+
+    /// The optional fixed seed.
+    pub seed: Option<Expr>,
+
+    /// The optional number of cases to generate.
+    pub cases: Option<Expr>,
+}
+
+/// Parses the token stream taken from the attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// parse_random_values_arguments(
+///     quote! {
+///         x: i32 = range(0..100),
+///         seed = 42,
+///         cases = 256
+///     }
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+/// * Returns [`TestUrCodeXDMacroError::NoParameters`] if there are no parameters.
+pub fn parse_random_values_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<RandomValuesArguments, TestUrCodeXDMacroError> {
+    let span = tokens.span();
+
+    let mut arguments = RandomValuesArguments::default();
+
+    for argument in Parser::parse2(
+        Punctuated::<RandomValuesArgument, Token![,]>::parse_terminated,
+        tokens,
+    )? {
+        match argument {
+            RandomValuesArgument::Parameter(parameter) => arguments.parameters.push(*parameter),
+            RandomValuesArgument::Seed(seed) => arguments.seed = Some(seed),
+            RandomValuesArgument::Cases(cases) => arguments.cases = Some(cases),
+        }
+    }
+
+    if arguments.parameters.is_empty() {
+        return Err(TestUrCodeXDMacroError::NoParameters(span));
+    }
+
+    Ok(arguments)
+}
+
+/// Generates the test function and parameter function for a randomized test. This is the
+/// top-level generation function that gets called by the macro.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `arguments` - The arguments parsed from the attribute.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns any errors from iterating over the function's arguments.
+pub fn generate_random_values_test_function(
+    mut item: ItemFn,
+    arguments: RandomValuesArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    // Take attribute list
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+
+    // Split the parameters into maps by name
+    let mut strategy_map = HashMap::new();
+    let mut type_map = HashMap::new();
+
+    for parameter in arguments.parameters {
+        let name = parameter.ident.to_string();
+
+        if let Some(ty) = parameter.ty {
+            type_map.insert(name.clone(), ty);
+        }
+
+        strategy_map.insert(name, parameter.strategy);
+    }
+
+    // Make sure that the generic parameters can be inferred from the generated values
+    ensure_generic_parameters_are_inferable(&item)?;
+
+    let generic_parameter_names = get_generic_parameter_names(&item);
+
+    // Collect the identifiers, types, and strategies in the order of the function's arguments
+    let mut identifiers = Vec::new();
+    let mut types = Vec::new();
+    let mut strategies = Vec::new();
+
+    for input in iter_parameterized_fn_inputs(&item, &strategy_map) {
+        let (name, ty, strategy) = input?;
+
+        types.push(
+            type_map
+                .get(&name)
+                .cloned()
+                .unwrap_or_else(|| get_binding_type(ty, &generic_parameter_names)),
+        );
+        identifiers.push(format_ident!("{}", name));
+        strategies.push(strategy.clone());
+    }
+
+    // Generate tokens
+    let runtime_module: syn::Path = syn::parse_str(RUNTIME_MODULE_PATH)?;
+
+    let seed = arguments.seed.map_or_else(
+        || quote! { ::std::option::Option::None },
+        |seed| quote! { ::std::option::Option::Some(#seed) },
+    );
+
+    let cases = arguments.cases.map_or_else(
+        || quote! { ::#runtime_module::DEFAULT_CASE_COUNT },
+        |cases| quote! { #cases },
+    );
+
+    let test_function_ident = &item.sig.ident;
+    let parameter_function_ident = get_parameter_function_ident(&item);
+
+    let mut result = quote! {
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () {
+            ::#runtime_module::run_random_value_test(
+                #seed,
+                #cases,
+                &( #( { use ::#runtime_module::*; #strategies }, )* ),
+                |( #( #identifiers, )* ): ( #( #types, )* )| {
+                    #parameter_function_ident ( #( #identifiers ),* );
+                },
+            );
+        }
+    };
+
+    result.extend(generate_parameter_function(item));
+
+    Ok(result)
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Indexing and slicing allowed to reduce length of test code.
+//
+// Panic allowed to help with if-then-else expressions.
+#[allow(clippy::unwrap_used, clippy::indexing_slicing, clippy::panic)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_random_values_arguments_empty() {
+        assert!(matches!(
+            parse_random_values_arguments(quote! {}),
+            Err(TestUrCodeXDMacroError::NoParameters(_))
+        ));
+    }
+
+    #[test]
+    fn parse_random_values_arguments_options_only() {
+        assert!(matches!(
+            parse_random_values_arguments(quote! { seed = 42, cases = 10 }),
+            Err(TestUrCodeXDMacroError::NoParameters(_))
+        ));
+    }
+
+    #[test]
+    fn parse_random_values_arguments_typed() {
+        let arguments = parse_random_values_arguments(quote! { x: i32 = range(0..100) }).unwrap();
+
+        assert_eq!(arguments.parameters.len(), 1);
+        assert_eq!(arguments.parameters[0].ident.to_string(), "x");
+        assert_eq!(
+            arguments.parameters[0].ty.to_token_stream().to_string(),
+            "i32"
+        );
+        assert_eq!(
+            arguments.parameters[0]
+                .strategy
+                .to_token_stream()
+                .to_string(),
+            "range (0 .. 100)"
+        );
+        assert!(arguments.seed.is_none());
+        assert!(arguments.cases.is_none());
+    }
+
+    #[test]
+    fn parse_random_values_arguments_untyped() {
+        let arguments = parse_random_values_arguments(quote! { x = range(0..100) }).unwrap();
+
+        assert_eq!(arguments.parameters.len(), 1);
+        assert!(arguments.parameters[0].ty.is_none());
+    }
+
+    #[test]
+    fn parse_random_values_arguments_options() {
+        let arguments = parse_random_values_arguments(
+            quote! { x: i32 = range(0..100), y: f64 = range(0.0..1.0), seed = 42, cases = 10 },
+        )
+        .unwrap();
+
+        assert_eq!(arguments.parameters.len(), 2);
+        assert_eq!(arguments.seed.to_token_stream().to_string(), "42");
+        assert_eq!(arguments.cases.to_token_stream().to_string(), "10");
+    }
+
+    #[test]
+    fn parse_random_values_arguments_invalid() {
+        assert!(matches!(
+            parse_random_values_arguments(quote! { x: i32 }),
+            Err(TestUrCodeXDMacroError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn generate_random_values_test_function_simple() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(x: i32) {}
+        };
+
+        let generated = generate_random_values_test_function(
+            item,
+            parse_random_values_arguments(quote! { x = range(0..10), seed = 42 }).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] fn asdf () { :: test_ur_code_xd :: utilities :: random_values :: run_random_value_test (:: std :: option :: Option :: Some (42) , :: test_ur_code_xd :: utilities :: random_values :: DEFAULT_CASE_COUNT , & ({ use :: test_ur_code_xd :: utilities :: random_values :: * ; range (0 .. 10) } ,) , | (x ,) : (i32 ,) | { _test_ur_code_xd_asdf_parameter_function (x) ; } ,) ; } fn _test_ur_code_xd_asdf_parameter_function (x : i32) { }"
+        );
+    }
+
+    #[test]
+    fn generate_random_values_test_function_missing_parameter() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(x: i32, y: i32) {}
+        };
+
+        let result = generate_random_values_test_function(
+            item,
+            parse_random_values_arguments(quote! { x = range(0..10) }).unwrap(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(TestUrCodeXDMacroError::ArgumentHasNoParameter(_))
+        ));
+    }
+
+    #[test]
+    fn generate_random_values_test_function_generic() {
+        let item: ItemFn = parse_quote! {
+            fn asdf<T: Display>(x: T, y: impl Display) {}
+        };
+
+        let generated = generate_random_values_test_function(
+            item,
+            parse_random_values_arguments(quote! { x: i32 = range(0..10), y = range(0..10) })
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert!(generated
+            .to_string()
+            .contains("| (x , y ,) : (i32 , _ ,) |"));
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Parsing and generation for the `#[test_with_retries]` attribute.
+//!
+//! A function decorated with the attribute is broken down into two functions:
+//!
+//! * **Retried function** - The original function, renamed to
+//!                          `_test_ur_code_xd_<name>_retried_function`.
+//! * **Test function** - A function with the original name and the `#[test]` attribute which calls
+//!                       the retried function until it passes or runs out of retries.
+
+use crate::{
+    errors::TestUrCodeXDMacroError,
+    parameters::extracting::{filter_fn_attrs_without_this_macro, take_fn_attrs},
+};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, Ident, ItemFn, Token,
+};
+
+/// The path to the runtime support module in the main crate.
+const RUNTIME_MODULE_PATH: &str = "test_ur_code_xd::utilities::retries";
+
+/// A single argument to the attribute.
+enum RetriesArgument {
+    /// The `count = <value>` option.
+    Count(Expr),
+
+    /// The `delay_ms = <value>` option.
+    DelayMs(Expr),
+}
+
+impl Parse for RetriesArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        input.parse::<Token![=]>()?;
+
+        let value: Expr = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "count" => Ok(Self::Count(value)),
+            "delay_ms" => Ok(Self::DelayMs(value)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("unknown argument `{ident}`, expected `count` or `delay_ms`"),
+            )),
+        }
+    }
+}
+
+/// The parsed arguments of the attribute.
+pub struct RetriesArguments {
+    /// The maximum number of times to re-run the test after the first attempt.
+    pub count: Expr,
+
+    /// The optional number of milliseconds to wait before each retry.
+    pub delay_ms: Option<Expr>,
+}
+
+/// Parses the token stream taken from the attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// parse_retries_arguments(
+///     quote! {
+///         count = 3,
+///         delay_ms = 100
+///     }
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+/// * Returns [`TestUrCodeXDMacroError::NoRetryCount`] if there is no `count` argument.
+pub fn parse_retries_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<RetriesArguments, TestUrCodeXDMacroError> {
+    let span = tokens.span();
+
+    let mut count = None;
+    let mut delay_ms = None;
+
+    for argument in Parser::parse2(
+        Punctuated::<RetriesArgument, Token![,]>::parse_terminated,
+        tokens,
+    )? {
+        match argument {
+            RetriesArgument::Count(value) => count = Some(value),
+            RetriesArgument::DelayMs(value) => delay_ms = Some(value),
+        }
+    }
+
+    let Some(count) = count else {
+        return Err(TestUrCodeXDMacroError::NoRetryCount(span));
+    };
+
+    Ok(RetriesArguments { count, delay_ms })
+}
+
+/// Creates an identifier for the retried function.
+#[must_use]
+fn get_retried_function_ident(item: &ItemFn) -> Ident {
+    // Use the span of the original function's identifier so that any errors about this identifier
+    // point to the test function instead of into the macro internals
+    format_ident!(
+        "_test_ur_code_xd_{}_retried_function",
+        item.sig.ident,
+        span = item.sig.ident.span()
+    )
+}
+
+/// Generates the test function and retried function for a test with retries. This is the
+/// top-level generation function that gets called by the macro.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `arguments` - The arguments parsed from the attribute.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ArgumentInRetriedTest`] if the function has arguments.
+pub fn generate_retries_test_function(
+    mut item: ItemFn,
+    arguments: RetriesArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    if let Some(input) = item.sig.inputs.first() {
+        return Err(TestUrCodeXDMacroError::ArgumentInRetriedTest(input.clone()));
+    }
+
+    // Take attribute list
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+
+    // Generate tokens
+    let runtime_module: syn::Path = syn::parse_str(RUNTIME_MODULE_PATH)?;
+
+    let count = arguments.count;
+
+    let delay_ms = arguments
+        .delay_ms
+        .map_or_else(|| quote! { 0 }, |delay_ms| quote! { #delay_ms });
+
+    let test_function_ident = item.sig.ident.clone();
+    let retried_function_ident = get_retried_function_ident(&item);
+    let output = item.sig.output.clone();
+
+    item.sig.ident = retried_function_ident.clone();
+
+    Ok(quote! {
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () #output {
+            ::#runtime_module::run_with_retries(
+                #count,
+                ::std::time::Duration::from_millis(#delay_ms),
+                #retried_function_ident,
+            )
+        }
+
+        #item
+    })
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_retries_arguments_count_only() {
+        let arguments = parse_retries_arguments(quote! { count = 3 }).unwrap();
+
+        assert_eq!(arguments.count.to_token_stream().to_string(), "3");
+        assert!(arguments.delay_ms.is_none());
+    }
+
+    #[test]
+    fn parse_retries_arguments_count_and_delay() {
+        let arguments = parse_retries_arguments(quote! { count = 3, delay_ms = 100 }).unwrap();
+
+        assert_eq!(arguments.count.to_token_stream().to_string(), "3");
+        assert_eq!(arguments.delay_ms.to_token_stream().to_string(), "100");
+    }
+
+    #[test]
+    fn parse_retries_arguments_empty() {
+        assert!(matches!(
+            parse_retries_arguments(quote! {}),
+            Err(TestUrCodeXDMacroError::NoRetryCount(_))
+        ));
+    }
+
+    #[test]
+    fn parse_retries_arguments_delay_only() {
+        assert!(matches!(
+            parse_retries_arguments(quote! { delay_ms = 100 }),
+            Err(TestUrCodeXDMacroError::NoRetryCount(_))
+        ));
+    }
+
+    #[test]
+    fn parse_retries_arguments_unknown() {
+        assert!(matches!(
+            parse_retries_arguments(quote! { count = 3, attempts = 5 }),
+            Err(TestUrCodeXDMacroError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn generate_retries_test_function_simple() {
+        let item: ItemFn = parse_quote! {
+            fn asdf() {}
+        };
+
+        let generated = generate_retries_test_function(
+            item,
+            parse_retries_arguments(quote! { count = 3 }).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] fn asdf () { :: test_ur_code_xd :: utilities :: retries :: run_with_retries (3 , :: std :: time :: Duration :: from_millis (0) , _test_ur_code_xd_asdf_retried_function ,) } fn _test_ur_code_xd_asdf_retried_function () { }"
+        );
+    }
+
+    #[test]
+    fn generate_retries_test_function_attributes_and_output() {
+        let item: ItemFn = parse_quote! {
+            #[ignore]
+            fn asdf() -> Result<(), String> { Ok(()) }
+        };
+
+        let generated = generate_retries_test_function(
+            item,
+            parse_retries_arguments(quote! { count = 3, delay_ms = 100 }).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] # [ignore] fn asdf () -> Result < () , String > { :: test_ur_code_xd :: utilities :: retries :: run_with_retries (3 , :: std :: time :: Duration :: from_millis (100) , _test_ur_code_xd_asdf_retried_function ,) } fn _test_ur_code_xd_asdf_retried_function () -> Result < () , String > { Ok (()) }"
+        );
+    }
+
+    #[test]
+    fn generate_retries_test_function_with_argument() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(x: i32) {}
+        };
+
+        assert!(matches!(
+            generate_retries_test_function(
+                item,
+                parse_retries_arguments(quote! { count = 3 }).unwrap(),
+            ),
+            Err(TestUrCodeXDMacroError::ArgumentInRetriedTest(_))
+        ));
+    }
+}
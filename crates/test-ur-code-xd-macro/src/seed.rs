@@ -0,0 +1,253 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Parsing and generation for the `#[test_with_seed]` attribute.
+//!
+//! A function decorated with the attribute is broken down into two functions:
+//!
+//! * **Seeded function** - The original function, renamed to
+//!                         `_test_ur_code_xd_<name>_seeded_function`.
+//! * **Test function** - A function with the original name and the `#[test]` attribute which calls
+//!                       the seeded function with a random number generator.
+
+use crate::{
+    errors::TestUrCodeXDMacroError,
+    parameters::extracting::{filter_fn_attrs_without_this_macro, take_fn_attrs},
+};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, Ident, ItemFn, Token,
+};
+
+/// The path to the runtime support module in the main crate.
+const RUNTIME_MODULE_PATH: &str = "test_ur_code_xd::utilities::random_values";
+
+/// A single argument to the attribute.
+enum SeedArgument {
+    /// The `seed = <value>` option.
+    Seed(Expr),
+}
+
+impl Parse for SeedArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        input.parse::<Token![=]>()?;
+
+        let value: Expr = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "seed" => Ok(Self::Seed(value)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("unknown argument `{ident}`, expected `seed`"),
+            )),
+        }
+    }
+}
+
+/// The parsed arguments of the attribute.
+pub struct SeedArguments {
+    /// The optional seed to use instead of the one derived from the test's name.
+    pub seed: Option<Expr>,
+}
+
+/// Parses the token stream taken from the attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// parse_seed_arguments(
+///     quote! {
+///         seed = 42
+///     }
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+pub fn parse_seed_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<SeedArguments, TestUrCodeXDMacroError> {
+    let mut seed = None;
+
+    for argument in Parser::parse2(
+        Punctuated::<SeedArgument, Token![,]>::parse_terminated,
+        tokens,
+    )? {
+        match argument {
+            SeedArgument::Seed(value) => seed = Some(value),
+        }
+    }
+
+    Ok(SeedArguments { seed })
+}
+
+/// Creates an identifier for the seeded function.
+#[must_use]
+fn get_seeded_function_ident(item: &ItemFn) -> Ident {
+    // Use the span of the original function's identifier so that any errors about this identifier
+    // point to the test function instead of into the macro internals
+    format_ident!(
+        "_test_ur_code_xd_{}_seeded_function",
+        item.sig.ident,
+        span = item.sig.ident.span()
+    )
+}
+
+/// Generates the test function and seeded function for a test with a seeded random number
+/// generator. This is the top-level generation function that gets called by the macro.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `arguments` - The arguments parsed from the attribute.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::SeededTestArgumentCount`] if the function does not have
+///   exactly one argument.
+pub fn generate_seed_test_function(
+    mut item: ItemFn,
+    arguments: SeedArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    if item.sig.inputs.len() != 1 {
+        return Err(TestUrCodeXDMacroError::SeededTestArgumentCount(
+            item.sig
+                .inputs
+                .iter()
+                .nth(1)
+                .map_or_else(|| item.sig.ident.span(), Spanned::span),
+        ));
+    }
+
+    // Take attribute list
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+
+    // Generate tokens
+    let runtime_module: syn::Path = syn::parse_str(RUNTIME_MODULE_PATH)?;
+
+    let seed = arguments.seed.map_or_else(
+        || quote! { ::std::option::Option::None },
+        |seed| quote! { ::std::option::Option::Some(#seed) },
+    );
+
+    let test_function_ident = item.sig.ident.clone();
+    let seeded_function_ident = get_seeded_function_ident(&item);
+    let output = item.sig.output.clone();
+
+    item.sig.ident = seeded_function_ident.clone();
+
+    Ok(quote! {
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () #output {
+            ::#runtime_module::run_with_seed(
+                ::std::concat!(::std::module_path!(), "::", ::std::stringify!(#test_function_ident)),
+                #seed,
+                #seeded_function_ident,
+            )
+        }
+
+        #item
+    })
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_seed_arguments_seed() {
+        let arguments = parse_seed_arguments(quote! { seed = 42 }).unwrap();
+
+        assert_eq!(arguments.seed.unwrap().to_token_stream().to_string(), "42");
+    }
+
+    #[test]
+    fn parse_seed_arguments_empty() {
+        let arguments = parse_seed_arguments(quote! {}).unwrap();
+
+        assert!(arguments.seed.is_none());
+    }
+
+    #[test]
+    fn parse_seed_arguments_unknown() {
+        assert!(matches!(
+            parse_seed_arguments(quote! { cases = 5 }),
+            Err(TestUrCodeXDMacroError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn generate_seed_test_function_simple() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(rng: &mut Rng) {}
+        };
+
+        let generated =
+            generate_seed_test_function(item, parse_seed_arguments(quote! {}).unwrap()).unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] fn asdf () { :: test_ur_code_xd :: utilities :: random_values :: run_with_seed (:: std :: concat ! (:: std :: module_path ! () , \"::\" , :: std :: stringify ! (asdf)) , :: std :: option :: Option :: None , _test_ur_code_xd_asdf_seeded_function ,) } fn _test_ur_code_xd_asdf_seeded_function (rng : & mut Rng) { }"
+        );
+    }
+
+    #[test]
+    fn generate_seed_test_function_with_seed() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(rng: &mut Rng) {}
+        };
+
+        let generated =
+            generate_seed_test_function(item, parse_seed_arguments(quote! { seed = 42 }).unwrap())
+                .unwrap();
+
+        assert!(generated
+            .to_string()
+            .contains(":: std :: option :: Option :: Some (42)"));
+    }
+
+    #[test]
+    fn generate_seed_test_function_argument_count() {
+        for item in [
+            parse_quote! {
+                fn asdf() {}
+            },
+            parse_quote! {
+                fn asdf(rng: &mut Rng, x: i32) {}
+            },
+        ] {
+            assert!(matches!(
+                generate_seed_test_function(item, parse_seed_arguments(quote! {}).unwrap()),
+                Err(TestUrCodeXDMacroError::SeededTestArgumentCount(_))
+            ));
+        }
+    }
+}
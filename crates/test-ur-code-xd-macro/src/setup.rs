@@ -0,0 +1,419 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Parsing and generation for the `#[test_with_setup]` and `#[test_suite]` attributes.
+//!
+//! The body of each test is wrapped in a closure that is passed to the runtime function along with
+//! the setup and teardown functions:
+//!
+//! ```ignore
+//! #[test]
+//! fn example() {
+//!     ::test_ur_code_xd::utilities::setup::run_with_setup(setup, teardown, move || {
+//!         // Original body
+//!     })
+//! }
+//! ```
+
+use crate::{
+    errors::TestUrCodeXDMacroError,
+    parameters::extracting::{filter_fn_attrs_without_this_macro, take_fn_attrs},
+};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, Ident, Item, ItemFn, ItemMod, Token,
+};
+
+/// The path to the runtime support module in the main crate.
+const RUNTIME_MODULE_PATH: &str = "test_ur_code_xd::utilities::setup";
+
+/// The name of the function in a test suite module that is used for setup by default.
+const DEFAULT_SETUP_FUNCTION_NAME: &str = "setup";
+
+/// The name of the function in a test suite module that is used for teardown by default.
+const DEFAULT_TEARDOWN_FUNCTION_NAME: &str = "teardown";
+
+/// A single argument to the attribute.
+enum SetupArgument {
+    /// The `setup = <value>` option.
+    Setup(Expr),
+
+    /// The `teardown = <value>` option.
+    Teardown(Expr),
+}
+
+impl Parse for SetupArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        input.parse::<Token![=]>()?;
+
+        let value: Expr = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "setup" => Ok(Self::Setup(value)),
+            "teardown" => Ok(Self::Teardown(value)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("unknown argument `{ident}`, expected `setup` or `teardown`"),
+            )),
+        }
+    }
+}
+
+/// The parsed arguments of the attribute.
+#[derive(Default)]
+pub struct SetupArguments {
+    /// The function to call before each test.
+    pub setup: Option<Expr>,
+
+    /// The function to call after each test, even if it panics.
+    pub teardown: Option<Expr>,
+}
+
+/// Parses the token stream taken from the attribute.
+///
+/// Unlike [`parse_test_suite_arguments`], this requires at least one of the arguments.
+///
+/// # Example
+///
+/// ```ignore
+/// parse_setup_arguments(
+///     quote! {
+///         setup = create_database, teardown = drop_database
+///     }
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+/// * Returns [`TestUrCodeXDMacroError::NoSetupOrTeardown`] if there is neither a `setup` or a
+///   `teardown` argument.
+pub fn parse_setup_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<SetupArguments, TestUrCodeXDMacroError> {
+    let span = tokens.span();
+
+    let arguments = parse_test_suite_arguments(tokens)?;
+
+    if arguments.setup.is_none() && arguments.teardown.is_none() {
+        return Err(TestUrCodeXDMacroError::NoSetupOrTeardown(span));
+    }
+
+    Ok(arguments)
+}
+
+/// Parses the token stream taken from the `#[test_suite]` attribute, where every argument is
+/// optional.
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+pub fn parse_test_suite_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<SetupArguments, TestUrCodeXDMacroError> {
+    let mut arguments = SetupArguments::default();
+
+    for argument in Parser::parse2(
+        Punctuated::<SetupArgument, Token![,]>::parse_terminated,
+        tokens,
+    )? {
+        match argument {
+            SetupArgument::Setup(value) => arguments.setup = Some(value),
+            SetupArgument::Teardown(value) => arguments.teardown = Some(value),
+        }
+    }
+
+    Ok(arguments)
+}
+
+/// Wraps the body of a test function so that it runs between setup and teardown.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ArgumentInSetupTest`] if the function has arguments.
+fn wrap_test_function_body(
+    item: &mut ItemFn,
+    arguments: &SetupArguments,
+) -> Result<(), TestUrCodeXDMacroError> {
+    if let Some(input) = item.sig.inputs.first() {
+        return Err(TestUrCodeXDMacroError::ArgumentInSetupTest(input.clone()));
+    }
+
+    let runtime_module: syn::Path = syn::parse_str(RUNTIME_MODULE_PATH)?;
+
+    let empty_function: Expr = parse_quote! { || {} };
+
+    let setup = arguments.setup.as_ref().unwrap_or(&empty_function);
+    let teardown = arguments.teardown.as_ref().unwrap_or(&empty_function);
+    let output = &item.sig.output;
+    let block = &item.block;
+
+    *item.block = parse_quote! {
+        {
+            ::#runtime_module::run_with_setup(#setup, #teardown, move || #output #block)
+        }
+    };
+
+    Ok(())
+}
+
+/// Generates the test function for a test with setup and teardown. This is the top-level
+/// generation function that gets called by the `#[test_with_setup]` macro.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `arguments` - The arguments parsed from the attribute.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ArgumentInSetupTest`] if the function has arguments.
+pub fn generate_setup_test_function(
+    mut item: ItemFn,
+    arguments: &SetupArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    // Take attribute list
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+
+    wrap_test_function_body(&mut item, arguments)?;
+
+    Ok(quote! {
+        #[test]
+        #( #attributes )*
+        #item
+    })
+}
+
+/// Checks whether or not a function has the `#[test]` attribute.
+fn is_test_function(item: &ItemFn) -> bool {
+    item.attrs
+        .iter()
+        .any(|attribute| attribute.path().is_ident("test"))
+}
+
+/// Checks whether or not a module contains a function with the given name.
+fn has_function_named(items: &[Item], name: &str) -> bool {
+    items.iter().any(|item| {
+        // We intentionally ignore any other arms
+        #[allow(clippy::wildcard_enum_match_arm)]
+        match item {
+            Item::Fn(item_fn) => item_fn.sig.ident == name,
+            _ => false,
+        }
+    })
+}
+
+/// Gets the setup or teardown function for a test suite, falling back to a function in the module
+/// with the default name.
+fn get_test_suite_function(explicit: Option<Expr>, items: &[Item], name: &str) -> Option<Expr> {
+    explicit.or_else(|| {
+        has_function_named(items, name).then(|| {
+            let ident = Ident::new(name, proc_macro2::Span::call_site());
+
+            parse_quote! { #ident }
+        })
+    })
+}
+
+/// Generates a test suite module where every `#[test]` function in it runs between setup and
+/// teardown. This is the top-level generation function that gets called by the `#[test_suite]`
+/// macro.
+///
+/// # Arguments
+///
+/// * `item` - The original module.
+/// * `arguments` - The arguments parsed from the attribute. Functions named `setup` and `teardown`
+///                 in the module are used for any that are missing.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::TestSuiteIsNotInline`] if the module's items are in another
+///   file.
+/// * Returns [`TestUrCodeXDMacroError::NoSetupOrTeardown`] if there is no setup or teardown
+///   function.
+/// * Returns [`TestUrCodeXDMacroError::ArgumentInSetupTest`] if a test function has arguments.
+pub fn generate_test_suite_module(
+    mut item: ItemMod,
+    arguments: SetupArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    let Some((_, items)) = item.content.as_mut() else {
+        return Err(TestUrCodeXDMacroError::TestSuiteIsNotInline(
+            item.ident.clone(),
+        ));
+    };
+
+    let arguments = SetupArguments {
+        setup: get_test_suite_function(arguments.setup, items, DEFAULT_SETUP_FUNCTION_NAME),
+        teardown: get_test_suite_function(
+            arguments.teardown,
+            items,
+            DEFAULT_TEARDOWN_FUNCTION_NAME,
+        ),
+    };
+
+    if arguments.setup.is_none() && arguments.teardown.is_none() {
+        return Err(TestUrCodeXDMacroError::NoSetupOrTeardown(item.ident.span()));
+    }
+
+    for module_item in items.iter_mut() {
+        if let Item::Fn(item_fn) = module_item {
+            if is_test_function(item_fn) {
+                wrap_test_function_body(item_fn, &arguments)?;
+            }
+        }
+    }
+
+    Ok(quote! { #item })
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    #[test]
+    fn parse_setup_arguments_both() {
+        let arguments =
+            parse_setup_arguments(quote! { setup = create, teardown = destroy }).unwrap();
+
+        assert_eq!(
+            arguments.setup.unwrap().to_token_stream().to_string(),
+            "create"
+        );
+        assert_eq!(
+            arguments.teardown.unwrap().to_token_stream().to_string(),
+            "destroy"
+        );
+    }
+
+    #[test]
+    fn parse_setup_arguments_empty() {
+        assert!(matches!(
+            parse_setup_arguments(quote! {}),
+            Err(TestUrCodeXDMacroError::NoSetupOrTeardown(_))
+        ));
+    }
+
+    #[test]
+    fn parse_setup_arguments_unknown() {
+        assert!(matches!(
+            parse_setup_arguments(quote! { before = create }),
+            Err(TestUrCodeXDMacroError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn generate_setup_test_function_simple() {
+        let item: ItemFn = parse_quote! {
+            fn asdf() {}
+        };
+
+        let generated = generate_setup_test_function(
+            item,
+            &parse_setup_arguments(quote! { teardown = destroy }).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] fn asdf () { :: test_ur_code_xd :: utilities :: setup :: run_with_setup (| | { } , destroy , move | | { }) }"
+        );
+    }
+
+    #[test]
+    fn generate_setup_test_function_with_argument() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(x: i32) {}
+        };
+
+        assert!(matches!(
+            generate_setup_test_function(
+                item,
+                &parse_setup_arguments(quote! { setup = create }).unwrap()
+            ),
+            Err(TestUrCodeXDMacroError::ArgumentInSetupTest(_))
+        ));
+    }
+
+    #[test]
+    fn generate_test_suite_module_default_functions() {
+        let item: ItemMod = parse_quote! {
+            mod tests {
+                fn setup() {}
+
+                #[test]
+                fn asdf() -> Result<(), ()> { Ok(()) }
+
+                fn helper() {}
+            }
+        };
+
+        let generated =
+            generate_test_suite_module(item, parse_test_suite_arguments(quote! {}).unwrap())
+                .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "mod tests { fn setup () { } # [test] fn asdf () -> Result < () , () > { :: test_ur_code_xd :: utilities :: setup :: run_with_setup (setup , | | { } , move | | -> Result < () , () > { Ok (()) }) } fn helper () { } }"
+        );
+    }
+
+    #[test]
+    fn generate_test_suite_module_no_functions() {
+        let item: ItemMod = parse_quote! {
+            mod tests {
+                #[test]
+                fn asdf() {}
+            }
+        };
+
+        assert!(matches!(
+            generate_test_suite_module(item, parse_test_suite_arguments(quote! {}).unwrap()),
+            Err(TestUrCodeXDMacroError::NoSetupOrTeardown(_))
+        ));
+    }
+
+    #[test]
+    fn generate_test_suite_module_not_inline() {
+        let item: ItemMod = parse_quote! {
+            mod tests;
+        };
+
+        assert!(matches!(
+            generate_test_suite_module(
+                item,
+                parse_test_suite_arguments(quote! { setup = create }).unwrap()
+            ),
+            Err(TestUrCodeXDMacroError::TestSuiteIsNotInline(_))
+        ));
+    }
+}
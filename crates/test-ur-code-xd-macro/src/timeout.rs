@@ -0,0 +1,236 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Parsing and generation for the `#[test_with_timeout]` attribute.
+//!
+//! A function decorated with the attribute is broken down into two functions:
+//!
+//! * **Timed function** - The original function, renamed to
+//!                        `_test_ur_code_xd_<name>_timed_function`.
+//! * **Test function** - A function with the original name and the `#[test]` attribute which runs
+//!                       the timed function on a separate thread and fails if it takes too long.
+
+use crate::{
+    errors::TestUrCodeXDMacroError,
+    parameters::extracting::{filter_fn_attrs_without_this_macro, take_fn_attrs},
+};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, Ident, ItemFn, Token,
+};
+
+/// The path to the runtime support module in the main crate.
+const RUNTIME_MODULE_PATH: &str = "test_ur_code_xd::utilities::timeout";
+
+/// A single argument to the attribute.
+enum TimeoutArgument {
+    /// The `ms = <value>` option.
+    Ms(Expr),
+}
+
+impl Parse for TimeoutArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        input.parse::<Token![=]>()?;
+
+        let value: Expr = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "ms" => Ok(Self::Ms(value)),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!("unknown argument `{ident}`, expected `ms`"),
+            )),
+        }
+    }
+}
+
+/// The parsed arguments of the attribute.
+pub struct TimeoutArguments {
+    /// The maximum number of milliseconds that the test can run for.
+    pub ms: Expr,
+}
+
+/// Parses the token stream taken from the attribute.
+///
+/// # Example
+///
+/// ```ignore
+/// parse_timeout_arguments(
+///     quote! {
+///         ms = 500
+///     }
+/// );
+/// ```
+///
+/// # Errors
+///
+/// * Returns a [`syn::Error`] if the token stream cannot be parsed as expected.
+/// * Returns [`TestUrCodeXDMacroError::NoTimeoutLimit`] if there is no `ms` argument.
+pub fn parse_timeout_arguments(
+    tokens: proc_macro2::TokenStream,
+) -> Result<TimeoutArguments, TestUrCodeXDMacroError> {
+    let span = tokens.span();
+
+    let mut ms = None;
+
+    for argument in Parser::parse2(
+        Punctuated::<TimeoutArgument, Token![,]>::parse_terminated,
+        tokens,
+    )? {
+        match argument {
+            TimeoutArgument::Ms(value) => ms = Some(value),
+        }
+    }
+
+    let Some(ms) = ms else {
+        return Err(TestUrCodeXDMacroError::NoTimeoutLimit(span));
+    };
+
+    Ok(TimeoutArguments { ms })
+}
+
+/// Creates an identifier for the timed function.
+#[must_use]
+fn get_timed_function_ident(item: &ItemFn) -> Ident {
+    // Use the span of the original function's identifier so that any errors about this identifier
+    // point to the test function instead of into the macro internals
+    format_ident!(
+        "_test_ur_code_xd_{}_timed_function",
+        item.sig.ident,
+        span = item.sig.ident.span()
+    )
+}
+
+/// Generates the test function and timed function for a test with a timeout. This is the
+/// top-level generation function that gets called by the macro.
+///
+/// # Arguments
+///
+/// * `item` - The test case's original function.
+/// * `arguments` - The arguments parsed from the attribute.
+///
+/// # Returns
+///
+/// A token stream.
+///
+/// # Errors
+///
+/// * Returns [`TestUrCodeXDMacroError::ArgumentInTimeoutTest`] if the function has arguments.
+pub fn generate_timeout_test_function(
+    mut item: ItemFn,
+    arguments: TimeoutArguments,
+) -> Result<proc_macro2::TokenStream, TestUrCodeXDMacroError> {
+    if let Some(input) = item.sig.inputs.first() {
+        return Err(TestUrCodeXDMacroError::ArgumentInTimeoutTest(input.clone()));
+    }
+
+    // Take attribute list
+    let attributes: Vec<Attribute> =
+        filter_fn_attrs_without_this_macro(take_fn_attrs(&mut item)).collect();
+
+    // Generate tokens
+    let runtime_module: syn::Path = syn::parse_str(RUNTIME_MODULE_PATH)?;
+
+    let ms = arguments.ms;
+
+    let test_function_ident = item.sig.ident.clone();
+    let timed_function_ident = get_timed_function_ident(&item);
+    let output = item.sig.output.clone();
+
+    item.sig.ident = timed_function_ident.clone();
+
+    Ok(quote! {
+        #[test]
+        #( #attributes )*
+        fn #test_function_ident () #output {
+            ::#runtime_module::run_with_timeout(
+                ::std::time::Duration::from_millis(#ms),
+                #timed_function_ident,
+            )
+        }
+
+        #item
+    })
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use syn::parse_quote;
+
+    #[test]
+    fn parse_timeout_arguments_ms() {
+        let arguments = parse_timeout_arguments(quote! { ms = 500 }).unwrap();
+
+        assert_eq!(arguments.ms.to_token_stream().to_string(), "500");
+    }
+
+    #[test]
+    fn parse_timeout_arguments_empty() {
+        assert!(matches!(
+            parse_timeout_arguments(quote! {}),
+            Err(TestUrCodeXDMacroError::NoTimeoutLimit(_))
+        ));
+    }
+
+    #[test]
+    fn parse_timeout_arguments_unknown() {
+        assert!(matches!(
+            parse_timeout_arguments(quote! { seconds = 5 }),
+            Err(TestUrCodeXDMacroError::ParsingError(_))
+        ));
+    }
+
+    #[test]
+    fn generate_timeout_test_function_simple() {
+        let item: ItemFn = parse_quote! {
+            fn asdf() {}
+        };
+
+        let generated = generate_timeout_test_function(
+            item,
+            parse_timeout_arguments(quote! { ms = 500 }).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            generated.to_string(),
+            "# [test] fn asdf () { :: test_ur_code_xd :: utilities :: timeout :: run_with_timeout (:: std :: time :: Duration :: from_millis (500) , _test_ur_code_xd_asdf_timed_function ,) } fn _test_ur_code_xd_asdf_timed_function () { }"
+        );
+    }
+
+    #[test]
+    fn generate_timeout_test_function_with_argument() {
+        let item: ItemFn = parse_quote! {
+            fn asdf(x: i32) {}
+        };
+
+        assert!(matches!(
+            generate_timeout_test_function(
+                item,
+                parse_timeout_arguments(quote! { ms = 500 }).unwrap()
+            ),
+            Err(TestUrCodeXDMacroError::ArgumentInTimeoutTest(_))
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! A pairwise zip implementation.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Zips a hash map of vectors into a vector of hash maps, pairing values by their index.
+///
+/// If there is a hash map of vectors like this:
+///
+/// ```json
+/// {
+///     "a": [1, 2, 3],
+///     "b": [4, 5, 6],
+/// }
+/// ```
+///
+/// It will zip them into:
+///
+/// ```json
+/// [
+///     {"a": 1, "b": 4},
+///     {"a": 2, "b": 5},
+///     {"a": 3, "b": 6},
+/// ]
+/// ```
+///
+/// # Returns
+///
+/// * `Some(vec_of_maps)` if all of the vectors have the same length.
+/// * `None` if the vectors have different lengths.
+#[allow(clippy::module_name_repetitions)]
+#[must_use]
+pub fn zip_map_of_vectors<KeyType: Clone + Eq + Hash, ValueType>(
+    map_of_vectors: HashMap<KeyType, Vec<ValueType>>,
+) -> Option<Vec<HashMap<KeyType, ValueType>>> {
+    let length = map_of_vectors.values().next().map_or(0, Vec::len);
+
+    if map_of_vectors.values().any(|vector| vector.len() != length) {
+        return None;
+    }
+
+    let mut vec_of_maps: Vec<HashMap<KeyType, ValueType>> =
+        (0..length).map(|_| HashMap::new()).collect();
+
+    for (key, vector) in map_of_vectors {
+        for (map, value) in vec_of_maps.iter_mut().zip(vector) {
+            map.insert(key.clone(), value);
+        }
+    }
+
+    Some(vec_of_maps)
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Indexing and slicing allowed to reduce length of test code.
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let map_of_vectors: HashMap<String, Vec<String>> = HashMap::new();
+
+        assert!(zip_map_of_vectors(map_of_vectors).unwrap().is_empty());
+    }
+
+    #[test]
+    fn two_keys_same_length() {
+        let map_of_vectors: HashMap<String, Vec<String>> = HashMap::from([
+            ("k0".to_owned(), vec!["v0".to_owned(), "v1".to_owned()]),
+            ("k1".to_owned(), vec!["v2".to_owned(), "v3".to_owned()]),
+        ]);
+
+        let vec_of_maps = zip_map_of_vectors(map_of_vectors).unwrap();
+
+        assert_eq!(vec_of_maps.len(), 2);
+        assert_eq!(vec_of_maps[0]["k0"], "v0");
+        assert_eq!(vec_of_maps[0]["k1"], "v2");
+        assert_eq!(vec_of_maps[1]["k0"], "v1");
+        assert_eq!(vec_of_maps[1]["k1"], "v3");
+    }
+
+    #[test]
+    fn two_keys_different_lengths() {
+        let map_of_vectors: HashMap<String, Vec<String>> = HashMap::from([
+            ("k0".to_owned(), vec!["v0".to_owned(), "v1".to_owned()]),
+            ("k1".to_owned(), vec!["v2".to_owned()]),
+        ]);
+
+        assert!(zip_map_of_vectors(map_of_vectors).is_none());
+    }
+}
@@ -13,6 +13,13 @@
 // You should have received a copy of the GNU General Public License along with test ur code XD. If
 // not, see <https://www.gnu.org/licenses/>.
 
+#![allow(
+    // Parameterized test values are always passed by value.
+    clippy::needless_pass_by_value,
+    // Explicit lifetimes are used to test generic test functions.
+    clippy::needless_lifetimes
+)]
+
 use test_ur_code_xd_macro::test_with_parameter_values;
 
 // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
@@ -47,3 +54,192 @@ fn failure(x: i32, y: i32) {
 
     assert!(z < 0);
 }
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = ["a", "bb", "ccc"],
+    y = [1, 2, 3],
+    mode = zip
+)]
+fn zip(x: &str, y: usize) {
+    assert_eq!(x.len(), y);
+}
+
+/// Cases for the `from_collection` test.
+const CASES: [i32; 3] = [5, 6, 7];
+
+/// Generates cases for the `from_function` test.
+fn generate_cases() -> Vec<String> {
+    (0..3).map(|i| format!("case {i}")).collect()
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = from(CASES),
+    y = [1, 2]
+)]
+fn from_collection(x: i32, y: i32) {
+    // Allow arithmetic side effects to reduce length of test code.
+    #[allow(clippy::arithmetic_side_effects)]
+    let z = x + y;
+
+    assert!(z > 0);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = from(generate_cases()))]
+fn from_function(x: String) {
+    assert!(x.starts_with("case "));
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = from(CASES))]
+#[should_panic(expected = "assertion failed: x < 6")]
+fn from_collection_failure(x: i32) {
+    assert!(x < 6);
+}
+
+/// Cases for the `from_path` test, shared from a module.
+mod shared_cases {
+    /// Cases that are all positive.
+    pub const POSITIVE: &[i32] = &[1, 2, 3];
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = CASES,
+    y = shared_cases::POSITIVE
+)]
+fn from_path(x: i32, y: i32) {
+    // Allow arithmetic side effects to reduce length of test code.
+    #[allow(clippy::arithmetic_side_effects)]
+    let z = x + y;
+
+    assert!(z > 0);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = 0..3,
+    y = -2..=2
+)]
+fn range(x: u8, y: i8) {
+    assert!(x < 3);
+    assert!(y.abs() <= 2);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = 0..20,
+    y = 0..15,
+    max_permutations = 300
+)]
+fn max_permutations(x: u8, y: u8) {
+    assert!(x < 20);
+    assert!(y < 15);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    (a, b, expected) = [(1, 2, 3), (2, 3, 5)],
+    c = [0, 1]
+)]
+fn tuple(a: i32, b: i32, expected: i32, c: i32) {
+    // Allow arithmetic side effects to reduce length of test code.
+    #[allow(clippy::arithmetic_side_effects)]
+    let (lhs, rhs) = (a + b + c, expected + c);
+
+    assert_eq!(lhs, rhs);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = [5, 6, 7],
+    y = ["a", "b"]
+)]
+fn generic<T: PartialOrd + From<u8>>(x: T, y: impl AsRef<str>) {
+    assert!(x > T::from(0));
+    assert!(!y.as_ref().is_empty());
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = ["hello", "world"])]
+fn generic_lifetime<'text>(x: &'text str) {
+    assert!(!x.is_empty());
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(
+    x = [1, 2],
+    name_format = "x_{x}"
+)]
+#[should_panic(expected = "x is positive")]
+fn should_panic_named(x: i32) {
+    assert!(x <= 0, "x is positive");
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = [1, 2])]
+#[ignore = "checks that ignored permutations are not run"]
+fn ignored(x: i32) {
+    assert!(x <= 0);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = [1, 2])]
+#[cfg(any())]
+fn cfg_disabled(x: i32) {
+    // This function doesn't exist, so this only compiles if the parameter function is disabled too
+    function_that_does_not_exist(x);
+}
+
+// WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+//          Analyzer, not an actual issue with the code.
+//
+//          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+#[test_with_parameter_values(x = [1, 2])]
+#[test]
+// Checks that lint attributes apply to the test function's body.
+#[allow(clippy::arithmetic_side_effects)]
+fn with_test_and_lint_attributes(x: i32) {
+    assert!(x + 1 > x);
+}
@@ -15,7 +15,7 @@
 
 use test_ur_code_xd_macro::test_with_parameter_values;
 
-#[test_with_parameter_values((a, b) = 5)]
+#[test_with_parameter_values(a.b = 5)]
 fn test() {}
 
 fn main() {}
@@ -288,20 +288,56 @@ pub mod arithmetic;
 pub mod bool;
 pub mod config;
 pub mod custom;
+pub mod fields;
+pub mod map;
+pub mod matcher;
+pub mod pattern;
+pub mod polling;
+pub mod result;
+pub mod sequence;
+pub mod set;
+pub mod soft;
 pub mod string;
 
+#[cfg(feature = "allocation")]
+pub mod allocation;
+
+#[cfg(feature = "float")]
+pub mod approx;
+
+#[cfg(feature = "async")]
+pub mod future;
+
 #[cfg(feature = "filesystem")]
 pub mod filesystem;
 
 #[cfg(feature = "float")]
 pub mod float;
 
+#[cfg(feature = "http")]
+pub mod http;
+
+#[cfg(feature = "json")]
+pub mod json;
+
 #[cfg(feature = "output")]
 pub mod output;
 
+#[cfg(feature = "float")]
+pub mod point;
+
 #[cfg(feature = "panic")]
 pub mod panic;
 
+#[cfg(feature = "process")]
+pub mod process;
+
+#[cfg(feature = "semver")]
+pub mod semver;
+
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+
 // These are used for the doc comment above.
 #[allow(unused_imports)]
 #[cfg(feature = "output")]
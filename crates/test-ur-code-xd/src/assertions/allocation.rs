@@ -0,0 +1,210 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on heap allocations.
+//!
+//! These are only available with the `allocation` feature, which installs a counting global
+//! allocator. See [`allocation_counting`](crate::utilities::allocation_counting) for details.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/allocation](https://sophie-katz.github.io/test-ur-code-XD/assertions/allocation/)
+//! for a usage guide.
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::{
+        allocation_counting::AllocationCounts, panic_message_builder::PanicMessageBuilder,
+    },
+};
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub const fn assert_no_alloc_impl(counts: &AllocationCounts) -> bool {
+    counts.count == 0
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub const fn assert_allocates_at_most_impl(counts: &AllocationCounts, max_bytes: usize) -> bool {
+    counts.bytes <= max_bytes
+}
+
+/// Configures a panic message builder for an allocation assertion to show the allocations that
+/// were made.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `counts` - The allocations made by the action
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_allocation_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    counts: &AllocationCounts,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument("allocation count", "--", &counts.count)?
+        .with_argument("allocated bytes", "--", &counts.bytes)
+}
+
+/// Asserts that a function does not make any heap allocations.
+///
+/// Only allocations made by the current thread are counted. Reallocations that shrink an
+/// allocation are not counted.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/allocation](https://sophie-katz.github.io/test-ur-code-XD/assertions/allocation/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `action` - A function with no arguments whose allocations will be counted.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_no_alloc;
+/// #
+/// let values = [3, 1, 2];
+///
+/// assert_no_alloc!(|| {
+///     let _ = values.iter().max();
+/// });
+///
+/// assert_no_alloc!(|| values.to_vec(), negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_no_alloc {
+    ($action:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let (_, counts) = $crate::utilities::allocation_counting::count_allocations($action);
+
+        $crate::assert_custom!(
+            "action does not allocate",
+            $crate::assertions::allocation::assert_no_alloc_impl(&counts),
+            |panic_message_builder| {
+                $crate::assertions::allocation::configure_allocation_panic_message(
+                    panic_message_builder,
+                    &counts,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that a function allocates at most a given number of bytes on the heap.
+///
+/// Only allocations made by the current thread are counted. Reallocations only count the bytes
+/// that they add.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/allocation](https://sophie-katz.github.io/test-ur-code-XD/assertions/allocation/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `action` - A function with no arguments whose allocations will be counted.
+/// * `bytes = <value>` - The maximum number of bytes that `action` can allocate.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_allocates_at_most;
+/// #
+/// assert_allocates_at_most!(|| vec![0_u8; 64], bytes = 64);
+///
+/// assert_allocates_at_most!(|| vec![0_u8; 64], bytes = 32, negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_allocates_at_most {
+    ($action:expr, bytes = $max_bytes:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let max_bytes: usize = $max_bytes;
+
+        let (_, counts) = $crate::utilities::allocation_counting::count_allocations($action);
+
+        $crate::assert_custom!(
+            ::std::format!("action allocates at most {} bytes", max_bytes),
+            $crate::assertions::allocation::assert_allocates_at_most_impl(&counts, max_bytes),
+            |panic_message_builder| {
+                $crate::assertions::allocation::configure_allocation_panic_message(
+                    panic_message_builder,
+                    &counts,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_eq, utilities::assertion_failure::capture_assertion_failure};
+
+    #[test]
+    fn assert_no_alloc_passing() {
+        assert_no_alloc!(|| {});
+
+        let values = [3, 1, 2];
+
+        assert_no_alloc!(|| values.iter().copied().max());
+    }
+
+    #[test]
+    #[should_panic(expected = "action does not allocate")]
+    fn assert_no_alloc_failing() {
+        assert_no_alloc!(|| String::from("hello, world"));
+    }
+
+    #[test]
+    fn assert_no_alloc_passing_negate() {
+        assert_no_alloc!(|| Box::new(5), negate = true);
+    }
+
+    #[test]
+    fn assert_no_alloc_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_no_alloc!(|| (vec![0_u8; 16], vec![0_u8; 8]));
+        })
+        .unwrap();
+
+        assert_eq!(failure.argument("allocation count").unwrap().value, "2");
+        assert_eq!(failure.argument("allocated bytes").unwrap().value, "24");
+    }
+
+    #[test]
+    fn assert_allocates_at_most_passing() {
+        assert_allocates_at_most!(|| vec![0_u8; 64], bytes = 64);
+        assert_allocates_at_most!(|| {}, bytes = 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "action allocates at most 32 bytes")]
+    fn assert_allocates_at_most_failing() {
+        assert_allocates_at_most!(|| vec![0_u8; 64], bytes = 32);
+    }
+
+    #[test]
+    fn assert_allocates_at_most_passing_negate() {
+        assert_allocates_at_most!(|| vec![0_u8; 64], bytes = 32, negate = true);
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that compare values of any type within a tolerance.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+//! for a usage guide.
+
+use std::{fmt::Debug, time::Duration};
+
+/// A value that can be compared to another value of the same type within a tolerance by
+/// [`assert_approx_eq`](crate::assert_approx_eq).
+///
+/// This is implemented for `f32`, `f64`, [`Duration`], arrays of these, and tuples of up to four of
+/// these. Implement it for your own types, like vectors, matrices, or colors, to compare them with
+/// [`assert_approx_eq`](crate::assert_approx_eq).
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_approx_eq, assertions::approx::ApproxEq};
+/// #
+/// #[derive(Debug)]
+/// struct Color {
+///     red: f32,
+///     green: f32,
+///     blue: f32,
+/// }
+///
+/// impl ApproxEq for Color {
+///     type Tolerance = f32;
+///
+///     fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+///         self.red.approx_eq(&other.red, tolerance)
+///             && self.green.approx_eq(&other.green, tolerance)
+///             && self.blue.approx_eq(&other.blue, tolerance)
+///     }
+/// }
+///
+/// assert_approx_eq!(
+///     Color { red: 0.5, green: 0.25, blue: 1.0 },
+///     Color { red: 0.501, green: 0.25, blue: 0.999 },
+///     tolerance = 0.01
+/// );
+/// ```
+pub trait ApproxEq: Debug {
+    /// The type of the tolerance within which two values are considered equal.
+    type Tolerance: Copy + Debug;
+
+    /// Checks whether or not two values are equal within a tolerance.
+    fn approx_eq(&self, other: &Self, tolerance: Self::Tolerance) -> bool;
+}
+
+impl ApproxEq for f32 {
+    type Tolerance = Self;
+
+    /// Checks whether the absolute difference is within the tolerance. Infinities are only equal
+    /// to infinities of the same sign, and NaN is not equal to anything.
+    fn approx_eq(&self, other: &Self, tolerance: Self) -> bool {
+        // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+        #[allow(clippy::arithmetic_side_effects, clippy::float_cmp)]
+        let is_equal = self == other || (self - other).abs() <= tolerance;
+
+        is_equal
+    }
+}
+
+impl ApproxEq for f64 {
+    type Tolerance = Self;
+
+    /// Checks whether the absolute difference is within the tolerance. Infinities are only equal
+    /// to infinities of the same sign, and NaN is not equal to anything.
+    fn approx_eq(&self, other: &Self, tolerance: Self) -> bool {
+        // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+        #[allow(clippy::arithmetic_side_effects, clippy::float_cmp)]
+        let is_equal = self == other || (self - other).abs() <= tolerance;
+
+        is_equal
+    }
+}
+
+impl ApproxEq for Duration {
+    type Tolerance = Self;
+
+    fn approx_eq(&self, other: &Self, tolerance: Self) -> bool {
+        let difference = self
+            .checked_sub(*other)
+            .or_else(|| other.checked_sub(*self))
+            .unwrap_or_default();
+
+        difference <= tolerance
+    }
+}
+
+impl<ItemType: ApproxEq, const LENGTH: usize> ApproxEq for [ItemType; LENGTH] {
+    type Tolerance = ItemType::Tolerance;
+
+    /// Checks whether each pair of corresponding items is equal within the tolerance.
+    fn approx_eq(&self, other: &Self, tolerance: Self::Tolerance) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(lhs, rhs)| lhs.approx_eq(rhs, tolerance))
+    }
+}
+
+/// Implements [`ApproxEq`] for a tuple, with a tuple of tolerances for each of its items.
+macro_rules! impl_approx_eq_for_tuple {
+    ($($type_name:ident $index:tt),+) => {
+        impl<$($type_name: ApproxEq),+> ApproxEq for ($($type_name,)+) {
+            type Tolerance = ($($type_name::Tolerance,)+);
+
+            /// Checks whether each pair of corresponding items is equal within the tolerance at the
+            /// same position.
+            fn approx_eq(&self, other: &Self, tolerance: Self::Tolerance) -> bool {
+                $(self.$index.approx_eq(&other.$index, tolerance.$index))&&+
+            }
+        }
+    };
+}
+
+impl_approx_eq_for_tuple!(Type0 0);
+impl_approx_eq_for_tuple!(Type0 0, Type1 1);
+impl_approx_eq_for_tuple!(Type0 0, Type1 1, Type2 2);
+impl_approx_eq_for_tuple!(Type0 0, Type1 1, Type2 2, Type3 3);
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_approx_eq_impl<ValueType: ApproxEq>(
+    lhs: &ValueType,
+    rhs: &ValueType,
+    tolerance: ValueType::Tolerance,
+) -> bool {
+    lhs.approx_eq(rhs, tolerance)
+}
+
+/// Asserts that two values are equal within a tolerance using the
+/// [`ApproxEq`](crate::assertions::approx::ApproxEq) trait.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The value on the left-hand side.
+/// * `rhs` - The value on the right-hand side, which must be the same type as `lhs`.
+/// * `tolerance = <value>` - The tolerance within which the values are considered equal. Its type
+///                           depends on the type of the values, like `f64` for `f64` values or a
+///                           tuple of tolerances for tuples.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `tolerance` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_approx_eq;
+/// # use std::time::Duration;
+/// #
+/// assert_approx_eq!(0.1 + 0.2, 0.3, tolerance = 1e-9);
+///
+/// assert_approx_eq!(
+///     Duration::from_millis(1002),
+///     Duration::from_secs(1),
+///     tolerance = Duration::from_millis(5)
+/// );
+///
+/// assert_approx_eq!([1.0, 2.0], [1.01, 1.99], tolerance = 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        tolerance = $tolerance:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let tolerance = $tolerance;
+
+        $crate::assert_custom!(
+            format!("lhs == rhs (within {tolerance:?})"),
+            $crate::assertions::approx::assert_approx_eq_impl(&$lhs, &$rhs, tolerance),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("lhs", stringify!($lhs), &$lhs)?
+                    .with_argument("rhs", stringify!($rhs), &$rhs)?
+                    .with_argument("tolerance", stringify!($tolerance), &tolerance)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert;
+
+    #[test]
+    fn approx_eq_f32() {
+        assert!(1.0_f32.approx_eq(&1.05, 0.1));
+        assert!(!1.0_f32.approx_eq(&1.2, 0.1));
+    }
+
+    #[test]
+    fn approx_eq_f64_non_finite() {
+        assert!(f64::INFINITY.approx_eq(&f64::INFINITY, 0.0));
+        assert!(!f64::INFINITY.approx_eq(&f64::NEG_INFINITY, f64::MAX));
+        assert!(!f64::NAN.approx_eq(&f64::NAN, f64::INFINITY));
+    }
+
+    #[test]
+    fn approx_eq_duration() {
+        assert!(Duration::from_millis(10)
+            .approx_eq(&Duration::from_millis(12), Duration::from_millis(2)));
+        assert!(Duration::from_millis(12)
+            .approx_eq(&Duration::from_millis(10), Duration::from_millis(2)));
+        assert!(!Duration::from_millis(10)
+            .approx_eq(&Duration::from_millis(13), Duration::from_millis(2)));
+    }
+
+    #[test]
+    fn approx_eq_array() {
+        assert!([1.0, 2.0, 3.0].approx_eq(&[1.0, 2.05, 3.0], 0.1));
+        assert!(![1.0, 2.0, 3.0].approx_eq(&[1.0, 2.5, 3.0], 0.1));
+    }
+
+    #[test]
+    fn approx_eq_tuple() {
+        assert!((1.0, Duration::from_secs(1)).approx_eq(
+            &(1.05, Duration::from_millis(1001)),
+            (0.1, Duration::from_millis(5))
+        ));
+        assert!(!(1.0, Duration::from_secs(1)).approx_eq(
+            &(1.05, Duration::from_millis(1010)),
+            (0.1, Duration::from_millis(5))
+        ));
+    }
+
+    #[test]
+    fn assert_approx_eq_passing() {
+        assert_approx_eq!(0.1 + 0.2, 0.3, tolerance = 1e-9);
+    }
+
+    #[test]
+    fn assert_approx_eq_passing_negate() {
+        assert_approx_eq!(1.0, 2.0, tolerance = 0.5, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5)")]
+    fn assert_approx_eq_failing() {
+        assert_approx_eq!(1.0, 2.0, tolerance = 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within (0.1, 0.1))")]
+    fn assert_approx_eq_failing_tuple() {
+        assert_approx_eq!((1.0, 2.0), (1.0, 2.5), tolerance = (0.1, 0.1));
+    }
+}
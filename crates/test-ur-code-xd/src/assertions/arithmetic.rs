@@ -19,11 +19,42 @@
 //! [sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic](https://sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic/)
 //! for a usage guide.
 
+use std::{fmt::Debug, ops::RangeBounds};
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// Configures a panic message builder to show the keys that two values are compared by, for
+/// assertions given a `compare_key` closure.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs` - The value on the left-hand side
+/// * `rhs` - The value on the right-hand side
+/// * `compare_key` - A closure that projects a value onto the key that it is compared by
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_compare_key_panic_message<ValueType, KeyType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs: &ValueType,
+    rhs: &ValueType,
+    compare_key: impl Fn(&ValueType) -> KeyType,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument("lhs key", "--", &compare_key(lhs))?
+        .with_argument("rhs key", "--", &compare_key(rhs))
+}
+
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_eq_impl<LhsType: PartialEq<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_eq_impl<LhsType: PartialEq<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     lhs.eq(rhs)
 }
 
@@ -68,7 +99,10 @@ macro_rules! assert_eq {
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_ne_impl<LhsType: PartialEq<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_ne_impl<LhsType: PartialEq<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     !lhs.eq(rhs)
 }
 
@@ -82,8 +116,13 @@ pub fn assert_ne_impl<LhsType: PartialEq<RhsType>, RhsType>(lhs: &LhsType, rhs:
 ///
 /// * `lhs` - The value on the left-hand side.
 /// * `rhs` - The value on the right-hand side.
+/// * `compare_key` - Optionally, a closure that projects each value onto the key that it is
+///                   compared by. Both keys are shown in the panic message if the assertion
+///                   fails.
 /// * Optional keyword arguments for assertions.
 ///
+/// **Note:** `compare_key` must come before any other keyword arguments.
+///
 /// # Example
 ///
 /// ```
@@ -95,6 +134,29 @@ pub fn assert_ne_impl<LhsType: PartialEq<RhsType>, RhsType>(lhs: &LhsType, rhs:
 /// ```
 #[macro_export]
 macro_rules! assert_ne {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        compare_key = $compare_key:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_custom!(
+            "lhs != rhs",
+            $crate::assertions::arithmetic::assert_ne_impl(&$lhs, &$rhs),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_compare_key_panic_message(
+                    panic_message_builder
+                        .with_argument("lhs", stringify!($lhs), &$lhs)?
+                        .with_argument("rhs", stringify!($rhs), &$rhs)?,
+                    &$lhs,
+                    &$rhs,
+                    $compare_key,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs != rhs",
@@ -113,7 +175,10 @@ macro_rules! assert_ne {
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_lt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_lt_impl<LhsType: PartialOrd<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     lhs.lt(rhs)
 }
 
@@ -127,8 +192,13 @@ pub fn assert_lt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 ///
 /// * `lhs` - The value on the left-hand side.
 /// * `rhs` - The value on the right-hand side.
+/// * `compare_key` - Optionally, a closure that projects each value onto the key that it is
+///                   compared by. Both keys are shown in the panic message if the assertion
+///                   fails.
 /// * Optional keyword arguments for assertions.
 ///
+/// **Note:** `compare_key` must come before any other keyword arguments.
+///
 /// # Example
 ///
 /// ```
@@ -140,6 +210,29 @@ pub fn assert_lt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 /// ```
 #[macro_export]
 macro_rules! assert_lt {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        compare_key = $compare_key:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_custom!(
+            "lhs < rhs",
+            $crate::assertions::arithmetic::assert_lt_impl(&$lhs, &$rhs),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_compare_key_panic_message(
+                    panic_message_builder
+                        .with_argument("lhs", stringify!($lhs), &$lhs)?
+                        .with_argument("rhs", stringify!($rhs), &$rhs)?,
+                    &$lhs,
+                    &$rhs,
+                    $compare_key,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs < rhs",
@@ -158,7 +251,10 @@ macro_rules! assert_lt {
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_le_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_le_impl<LhsType: PartialOrd<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     lhs.le(rhs)
 }
 
@@ -172,8 +268,13 @@ pub fn assert_le_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 ///
 /// * `lhs` - The value on the left-hand side.
 /// * `rhs` - The value on the right-hand side.
+/// * `compare_key` - Optionally, a closure that projects each value onto the key that it is
+///                   compared by. Both keys are shown in the panic message if the assertion
+///                   fails.
 /// * Optional keyword arguments for assertions.
 ///
+/// **Note:** `compare_key` must come before any other keyword arguments.
+///
 /// # Example
 ///
 /// ```
@@ -185,6 +286,29 @@ pub fn assert_le_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 /// ```
 #[macro_export]
 macro_rules! assert_le {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        compare_key = $compare_key:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_custom!(
+            "lhs <= rhs",
+            $crate::assertions::arithmetic::assert_le_impl(&$lhs, &$rhs),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_compare_key_panic_message(
+                    panic_message_builder
+                        .with_argument("lhs", stringify!($lhs), &$lhs)?
+                        .with_argument("rhs", stringify!($rhs), &$rhs)?,
+                    &$lhs,
+                    &$rhs,
+                    $compare_key,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs <= rhs",
@@ -203,7 +327,10 @@ macro_rules! assert_le {
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_gt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_gt_impl<LhsType: PartialOrd<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     lhs.gt(rhs)
 }
 
@@ -217,8 +344,13 @@ pub fn assert_gt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 ///
 /// * `lhs` - The value on the left-hand side.
 /// * `rhs` - The value on the right-hand side.
+/// * `compare_key` - Optionally, a closure that projects each value onto the key that it is
+///                   compared by. Both keys are shown in the panic message if the assertion
+///                   fails.
 /// * Optional keyword arguments for assertions.
 ///
+/// **Note:** `compare_key` must come before any other keyword arguments.
+///
 /// # Example
 ///
 /// ```
@@ -230,6 +362,29 @@ pub fn assert_gt_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 /// ```
 #[macro_export]
 macro_rules! assert_gt {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        compare_key = $compare_key:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_custom!(
+            "lhs > rhs",
+            $crate::assertions::arithmetic::assert_gt_impl(&$lhs, &$rhs),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_compare_key_panic_message(
+                    panic_message_builder
+                        .with_argument("lhs", stringify!($lhs), &$lhs)?
+                        .with_argument("rhs", stringify!($rhs), &$rhs)?,
+                    &$lhs,
+                    &$rhs,
+                    $compare_key,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs > rhs",
@@ -248,7 +403,10 @@ macro_rules! assert_gt {
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_ge_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs: &RhsType) -> bool {
+pub fn assert_ge_impl<LhsType: PartialOrd<RhsType> + ?Sized, RhsType: ?Sized>(
+    lhs: &LhsType,
+    rhs: &RhsType,
+) -> bool {
     lhs.ge(rhs)
 }
 
@@ -262,8 +420,13 @@ pub fn assert_ge_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 ///
 /// * `lhs` - The value on the left-hand side.
 /// * `rhs` - The value on the right-hand side.
+/// * `compare_key` - Optionally, a closure that projects each value onto the key that it is
+///                   compared by. Both keys are shown in the panic message if the assertion
+///                   fails.
 /// * Optional keyword arguments for assertions.
 ///
+/// **Note:** `compare_key` must come before any other keyword arguments.
+///
 /// # Example
 ///
 /// ```
@@ -275,6 +438,29 @@ pub fn assert_ge_impl<LhsType: PartialOrd<RhsType>, RhsType>(lhs: &LhsType, rhs:
 /// ```
 #[macro_export]
 macro_rules! assert_ge {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        compare_key = $compare_key:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_custom!(
+            "lhs >= rhs",
+            $crate::assertions::arithmetic::assert_ge_impl(&$lhs, &$rhs),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_compare_key_panic_message(
+                    panic_message_builder
+                        .with_argument("lhs", stringify!($lhs), &$lhs)?
+                        .with_argument("rhs", stringify!($rhs), &$rhs)?,
+                    &$lhs,
+                    &$rhs,
+                    $compare_key,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs >= rhs",
@@ -289,8 +475,473 @@ macro_rules! assert_ge {
     };
 }
 
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_between_impl<ValueType: PartialOrd>(
+    value: &ValueType,
+    low: &ValueType,
+    high: &ValueType,
+    is_exclusive: bool,
+) -> bool {
+    if is_exclusive {
+        low < value && value < high
+    } else {
+        low <= value && value <= high
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub const fn format_between_predicate_description(is_exclusive: bool) -> &'static str {
+    if is_exclusive {
+        "low < value < high"
+    } else {
+        "low <= value <= high"
+    }
+}
+
+/// Asserts that a value is between two bounds using the [`PartialOrd`] trait.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic](https://sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * `low` - The lower bound.
+/// * `high` - The upper bound.
+/// * `exclusive` - Optionally, whether both bounds are excluded. Defaults to `false`, meaning that
+///                 the value may be equal to either bound.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `exclusive` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_between;
+/// #
+/// # let x = 5;
+/// #
+/// assert_between!(x, 1, 10);
+/// assert_between!(x, 4, 6, exclusive = true);
+/// ```
+#[macro_export]
+macro_rules! assert_between {
+    (
+        $value:expr,
+        $low:expr,
+        $high:expr,
+        exclusive = $exclusive:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let is_exclusive: bool = $exclusive;
+
+        $crate::assert_custom!(
+            $crate::assertions::arithmetic::format_between_predicate_description(is_exclusive),
+            $crate::assertions::arithmetic::assert_between_impl(
+                &$value,
+                &$low,
+                &$high,
+                is_exclusive,
+            ),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("value", stringify!($value), &$value)?
+                    .with_argument("low", stringify!($low), &$low)?
+                    .with_argument("high", stringify!($high), &$high)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($value:expr, $low:expr, $high:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_between!($value, $low, $high, exclusive = false $(, $keys = $values)*)
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_in_range_impl<ValueType: PartialOrd, RangeType: RangeBounds<ValueType>>(
+    value: &ValueType,
+    range: &RangeType,
+) -> bool {
+    range.contains(value)
+}
+
+/// Asserts that a value is contained in a range written with Rust range syntax.
+///
+/// Any range type can be used, including `a..b`, `a..=b`, `a..`, and `..b`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic](https://sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * `range` - The range that should contain the value.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_in_range;
+/// #
+/// # let x = 5;
+/// #
+/// assert_in_range!(x, 1..=10);
+/// assert_in_range!(x, 0..);
+/// ```
+#[macro_export]
+macro_rules! assert_in_range {
+    ($value:expr, $range:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let range = $range;
+
+        $crate::assert_custom!(
+            "range contains value",
+            $crate::assertions::arithmetic::assert_in_range_impl(&$value, &range),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("value", stringify!($value), &$value)?
+                    .with_argument("range", stringify!($range), &range)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// One comparison in a chain checked by [`assert_ordered`](crate::assert_ordered).
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedLink {
+    /// The comparison operator, like `"<="`.
+    operator: &'static str,
+
+    /// Whether or not the comparison is true.
+    is_ordered: bool,
+}
+
+impl OrderedLink {
+    /// Creates a new link.
+    ///
+    /// # Arguments
+    ///
+    /// * `operator` - The comparison operator, like `"<="`.
+    /// * `is_ordered` - Whether or not the comparison is true.
+    #[must_use]
+    pub const fn new(operator: &'static str, is_ordered: bool) -> Self {
+        Self {
+            operator,
+            is_ordered,
+        }
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_ordered_impl(links: &[OrderedLink]) -> bool {
+    links.iter().all(|link| link.is_ordered)
+}
+
+/// Configures a panic message builder for [`assert_ordered`](crate::assert_ordered) to show which
+/// comparison in the chain failed along with every value.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `operands` - The source code and value of each operand in the chain
+/// * `links` - The comparisons between each pair of adjacent operands
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_ordered_panic_message(
+    mut panic_message_builder: PanicMessageBuilder,
+    operands: &[(&str, &dyn Debug)],
+    links: &[OrderedLink],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let failed_comparison = links
+        .iter()
+        .zip(operands.iter().zip(operands.iter().skip(1)))
+        .find(|(link, _)| !link.is_ordered)
+        .map(|(link, ((lhs_source, _), (rhs_source, _)))| {
+            format!("{lhs_source} {} {rhs_source}", link.operator)
+        });
+
+    if let Some(failed_comparison) = failed_comparison {
+        panic_message_builder = panic_message_builder.with_argument_formatted(
+            "failed comparison",
+            "--",
+            failed_comparison,
+        )?;
+    }
+
+    for (index, (source, value)) in operands.iter().enumerate() {
+        panic_message_builder = panic_message_builder.with_argument(
+            format!("value {}", index.saturating_add(1)),
+            source,
+            value,
+        )?;
+    }
+
+    Ok(panic_message_builder)
+}
+
+/// Asserts that a chain of comparisons like `a < b <= c` all hold using the [`PartialOrd`] and
+/// [`PartialEq`] traits.
+///
+/// Every operand is evaluated exactly once and every comparison is checked. If any of them fail,
+/// the panic message shows the first comparison that failed along with all of the values.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic](https://sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * A chain of operands separated by `<`, `<=`, `>`, `>=`, or `==`. Operands that contain these
+///   operators themselves, like generic types or other comparisons, need to be wrapped in
+///   parentheses.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_ordered;
+/// #
+/// # let (low, x, high) = (1, 5, 10);
+/// #
+/// assert_ordered!(0 < low <= x < high);
+/// assert_ordered!(high > x >= x, description = "x is below the limit");
+/// ```
+#[macro_export]
+macro_rules! assert_ordered {
+    // Splits the chain into parenthesized operands and comparison operators.
+    (@split [] [$($current:tt)+] $(, $($keys:ident = $values:expr),* $(,)?)?) => {
+        ::std::compile_error!("`assert_ordered!` needs at least one comparison, like `a < b`")
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] < $($rest:tt)+) => {
+        $crate::assert_ordered!(@split [$($done)* ($($current)+) <] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] <= $($rest:tt)+) => {
+        $crate::assert_ordered!(@split [$($done)* ($($current)+) <=] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] > $($rest:tt)+) => {
+        $crate::assert_ordered!(@split [$($done)* ($($current)+) >] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] >= $($rest:tt)+) => {
+        $crate::assert_ordered!(@split [$($done)* ($($current)+) >=] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] == $($rest:tt)+) => {
+        $crate::assert_ordered!(@split [$($done)* ($($current)+) ==] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+] $(, $($keys:ident = $values:expr),* $(,)?)?) => {
+        $crate::assert_ordered!(@bind [] [] $($done)* ($($current)+) ; $($($keys = $values),*)?)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::assert_ordered!(@split [$($done)*] [$($current)* $next] $($rest)*)
+    };
+
+    // Evaluates each operand once, binding it to a variable that is passed along to the next step.
+    (@bind
+        [$(($operand:ident, $source:expr))*]
+        [$(($lhs:ident $operator:tt $rhs:ident))*]
+        $last:ident ; $($keys:ident = $values:expr),*
+    ) => {{
+        let operands: &[(&str, &dyn ::std::fmt::Debug)] = &[$(($source, $operand)),*];
+
+        let links = [
+            $(
+                $crate::assertions::arithmetic::OrderedLink::new(
+                    stringify!($operator),
+                    $crate::assert_ordered!(@compare $lhs $operator $rhs),
+                )
+            ),*
+        ];
+
+        $crate::assert_custom!(
+            "values are ordered",
+            $crate::assertions::arithmetic::assert_ordered_impl(&links),
+            |panic_message_builder| {
+                $crate::assertions::arithmetic::configure_ordered_panic_message(
+                    panic_message_builder,
+                    operands,
+                    &links,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+    (@bind [] [] ($($operand:tt)+) $($rest:tt)*) => {{
+        let value = &($($operand)+);
+
+        $crate::assert_ordered!(@bind [(value, stringify!($($operand)+))] [] value $($rest)*)
+    }};
+    (@bind
+        [$($operands:tt)*]
+        [$($links:tt)*]
+        $previous:ident $operator:tt ($($operand:tt)+) $($rest:tt)*
+    ) => {{
+        let value = &($($operand)+);
+
+        $crate::assert_ordered!(
+            @bind
+            [$($operands)* (value, stringify!($($operand)+))]
+            [$($links)* ($previous $operator value)]
+            value $($rest)*
+        )
+    }};
+
+    // Compares two bound operands.
+    (@compare $lhs:ident < $rhs:ident) => {
+        $crate::assertions::arithmetic::assert_lt_impl($lhs, $rhs)
+    };
+    (@compare $lhs:ident <= $rhs:ident) => {
+        $crate::assertions::arithmetic::assert_le_impl($lhs, $rhs)
+    };
+    (@compare $lhs:ident > $rhs:ident) => {
+        $crate::assertions::arithmetic::assert_gt_impl($lhs, $rhs)
+    };
+    (@compare $lhs:ident >= $rhs:ident) => {
+        $crate::assertions::arithmetic::assert_ge_impl($lhs, $rhs)
+    };
+    (@compare $lhs:ident == $rhs:ident) => {
+        $crate::assertions::arithmetic::assert_eq_impl($lhs, $rhs)
+    };
+
+    ($($tokens:tt)+) => {
+        $crate::assert_ordered!(@split [] [] $($tokens)+)
+    };
+}
+
+/// A trait for integer types whose absolute difference can be computed without overflowing.
+///
+/// This is implemented for all primitive integer types, and is used by
+/// [`assert_abs_diff_le`](crate::assert_abs_diff_le).
+pub trait AbsDiff: Copy + Debug {
+    /// The type of the absolute difference, which is the unsigned type of the same width so that
+    /// every difference can be represented.
+    type Difference: Copy + Debug + PartialOrd;
+
+    /// Computes the absolute difference between two values without overflowing.
+    fn abs_diff(self, other: Self) -> Self::Difference;
+}
+
+/// Implements [`AbsDiff`] for a primitive integer type using its inherent `abs_diff` method.
+macro_rules! impl_abs_diff {
+    ($($type_name:ty => $difference_type_name:ty),+ $(,)?) => {
+        $(
+            impl AbsDiff for $type_name {
+                type Difference = $difference_type_name;
+
+                fn abs_diff(self, other: Self) -> Self::Difference {
+                    <$type_name>::abs_diff(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_abs_diff!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_abs_diff_le_impl<ValueType: AbsDiff>(
+    lhs: ValueType,
+    rhs: ValueType,
+    max_diff: ValueType::Difference,
+) -> bool {
+    lhs.abs_diff(rhs) <= max_diff
+}
+
+/// Asserts that the absolute difference between two integers is less than or equal to a maximum.
+///
+/// The difference is computed without overflowing, even for values at opposite ends of the range of
+/// their type. The actual difference is shown in the panic message if the assertion fails.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic](https://sophie-katz.github.io/test-ur-code-XD/assertions/arithmetic/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The value on the left-hand side.
+/// * `rhs` - The value on the right-hand side.
+/// * `max_diff` - The maximum allowed absolute difference. For signed integers this is the unsigned
+///                type of the same width.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_abs_diff_le;
+/// #
+/// # let elapsed_ticks: i64 = 1_002;
+/// #
+/// assert_abs_diff_le!(elapsed_ticks, 1_000, 5);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_le {
+    ($lhs:expr, $rhs:expr, $max_diff:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        let max_diff = $max_diff;
+
+        $crate::assert_custom!(
+            "|lhs - rhs| <= max_diff",
+            $crate::assertions::arithmetic::assert_abs_diff_le_impl(lhs, rhs, max_diff),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("lhs", stringify!($lhs), &lhs)?
+                    .with_argument("rhs", stringify!($rhs), &rhs)?
+                    .with_argument("max_diff", stringify!($max_diff), &max_diff)?
+                    .with_argument(
+                        "absolute difference",
+                        "--",
+                        &$crate::assertions::arithmetic::AbsDiff::abs_diff(lhs, rhs),
+                    )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
 #[cfg(test)]
 mod tests {
+    use super::AbsDiff;
+    use crate::utilities::{
+        assertion_failure::capture_assertion_failure,
+        color_choice::{set_color_choice, ColorChoice},
+    };
+
     #[derive(Debug, PartialEq, PartialOrd)]
     struct NoDefaultTraitsI32 {
         value: i32,
@@ -406,6 +1057,58 @@ mod tests {
         assert_eq!(Vec::<i32>::new(), Vec::<i32>::new(), negate = true);
     }
 
+    #[derive(Debug)]
+    struct Meters(u32);
+
+    impl PartialEq<u32> for Meters {
+        fn eq(&self, other: &u32) -> bool {
+            self.0 == *other
+        }
+    }
+
+    #[test]
+    fn assert_eq_passing_string_and_str() {
+        let value = "hi".to_owned();
+
+        assert_eq!(value, "hi");
+        assert_eq!("hi", value);
+    }
+
+    #[test]
+    fn assert_eq_passing_unsized() {
+        let value = "hi".to_owned();
+
+        assert_eq!(*value, *"hi");
+        assert_eq!(vec![1, 2][..], [1, 2][..]);
+    }
+
+    #[test]
+    fn assert_eq_passing_vec_and_array() {
+        assert_eq!(vec![1, 2], [1, 2]);
+    }
+
+    #[test]
+    fn assert_eq_passing_newtype() {
+        assert_eq!(Meters(5), 5);
+    }
+
+    #[test]
+    fn assert_eq_failing_different_types() {
+        let failure = capture_assertion_failure(|| {
+            assert_eq!(Meters(5), 6);
+        })
+        .unwrap();
+
+        assert_eq!(failure.argument("lhs").unwrap().value, "Meters(5)");
+        assert_eq!(failure.argument("rhs").unwrap().value, "6");
+    }
+
+    #[test]
+    fn assert_ne_passing_different_types() {
+        assert_ne!("hi".to_owned(), "bye");
+        assert_ne!(Meters(5), 6);
+    }
+
     #[test]
     fn assert_ne_passing_bool() {
         assert_ne!(true, false);
@@ -913,4 +1616,263 @@ mod tests {
             negate = true
         );
     }
+
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version {
+        major: u32,
+        minor: u32,
+        label: &'static str,
+    }
+
+    #[test]
+    fn configure_compare_key_panic_message_shows_keys() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = super::configure_compare_key_panic_message(
+            crate::utilities::panic_message_builder::PanicMessageBuilder::new(
+                crate::utilities::panic_message_builder::MessageType::AssertionFailure,
+                "lhs < rhs",
+                std::panic::Location::caller(),
+            ),
+            &Version {
+                major: 1,
+                minor: 2,
+                label: "a",
+            },
+            &Version {
+                major: 1,
+                minor: 0,
+                label: "b",
+            },
+            |version| (version.major, version.minor),
+        )
+        .map(crate::utilities::panic_message_builder::PanicMessageBuilder::format)
+        .unwrap_or_default();
+
+        assert!(message.contains("lhs key: --"));
+        assert!(message.contains("rhs key: --"));
+        assert!(message.contains("1,\n") && message.contains("2,\n"));
+    }
+
+    #[test]
+    fn assert_lt_passing_compare_key() {
+        assert_lt!(
+            Version {
+                major: 1,
+                minor: 0,
+                label: "b",
+            },
+            Version {
+                major: 1,
+                minor: 2,
+                label: "a",
+            },
+            compare_key = |version| (version.major, version.minor)
+        );
+    }
+
+    #[test]
+    #[should_panic = "lhs < rhs"]
+    fn assert_lt_failing_compare_key() {
+        assert_lt!(
+            Version {
+                major: 1,
+                minor: 2,
+                label: "a",
+            },
+            Version {
+                major: 1,
+                minor: 0,
+                label: "b",
+            },
+            compare_key = |version| (version.major, version.minor)
+        );
+    }
+
+    #[test]
+    fn assert_ge_passing_compare_key_negate() {
+        assert_ge!(
+            Version {
+                major: 0,
+                minor: 9,
+                label: "a",
+            },
+            Version {
+                major: 1,
+                minor: 0,
+                label: "a",
+            },
+            compare_key = |version| version.major,
+            negate = true
+        );
+    }
+
+    #[test]
+    #[should_panic = "lhs != rhs"]
+    fn assert_ne_failing_compare_key() {
+        assert_ne!(
+            Version {
+                major: 1,
+                minor: 0,
+                label: "a",
+            },
+            Version {
+                major: 1,
+                minor: 0,
+                label: "a",
+            },
+            compare_key = |version| version.label
+        );
+    }
+
+    #[test]
+    fn abs_diff_extremes() {
+        assert_eq!(AbsDiff::abs_diff(i32::MIN, i32::MAX), u32::MAX);
+        assert_eq!(AbsDiff::abs_diff(0_u8, u8::MAX), u8::MAX);
+        assert_eq!(AbsDiff::abs_diff(-5_i64, 5), 10);
+    }
+
+    #[test]
+    fn assert_abs_diff_le_passing() {
+        assert_abs_diff_le!(1_002_i64, 1_000, 5);
+        assert_abs_diff_le!(1_000_u32, 1_005, 5);
+    }
+
+    #[test]
+    fn assert_abs_diff_le_passing_extremes() {
+        assert_abs_diff_le!(i8::MIN, i8::MAX, u8::MAX);
+    }
+
+    #[test]
+    #[should_panic = "|lhs - rhs| <= max_diff"]
+    fn assert_abs_diff_le_failing() {
+        assert_abs_diff_le!(10_usize, 0, 9);
+    }
+
+    #[test]
+    #[should_panic = "|lhs - rhs| <= max_diff"]
+    fn assert_abs_diff_le_failing_extremes() {
+        assert_abs_diff_le!(i64::MIN, i64::MAX, u64::MAX - 1);
+    }
+
+    #[test]
+    fn assert_abs_diff_le_passing_negate() {
+        assert_abs_diff_le!(10_i32, 0, 9, negate = true);
+    }
+
+    #[test]
+    fn assert_between_passing() {
+        assert_between!(5, 1, 10);
+        assert_between!(1, 1, 10);
+        assert_between!(10, 1, 10);
+    }
+
+    #[test]
+    fn assert_between_passing_exclusive() {
+        assert_between!(5, 4, 6, exclusive = true);
+    }
+
+    #[test]
+    #[should_panic = "low <= value <= high"]
+    fn assert_between_failing() {
+        assert_between!(11, 1, 10);
+    }
+
+    #[test]
+    #[should_panic = "low < value < high"]
+    fn assert_between_failing_exclusive() {
+        assert_between!(10, 1, 10, exclusive = true);
+    }
+
+    #[test]
+    fn assert_between_passing_negate() {
+        assert_between!(0.5, 1.0, 2.0, negate = true);
+    }
+
+    #[test]
+    fn assert_between_passing_strings() {
+        assert_between!("banana", "apple", "cherry");
+    }
+
+    #[test]
+    fn assert_in_range_passing() {
+        assert_in_range!(5, 1..10);
+        assert_in_range!(10, 1..=10);
+        assert_in_range!(-100, ..0);
+        assert_in_range!(1.5, 1.0..);
+    }
+
+    #[test]
+    #[should_panic = "range contains value"]
+    fn assert_in_range_failing() {
+        assert_in_range!(10, 1..10);
+    }
+
+    #[test]
+    fn assert_in_range_passing_negate() {
+        assert_in_range!(0, 1..10, negate = true);
+    }
+
+    #[test]
+    fn assert_ordered_passing() {
+        assert_ordered!(1 < 2);
+        assert_ordered!(1 < 2 <= 2 < 3);
+        assert_ordered!(3 > 2 >= 2 == 2);
+        assert_ordered!("apple" < "banana" < "cherry");
+    }
+
+    #[test]
+    fn assert_ordered_passing_expressions() {
+        let values = [1, 5, 10];
+
+        assert_ordered!(values[0] < values[1] + 1 < values.len() * 4);
+        assert_ordered!((values[0] < values[1]) == true);
+    }
+
+    #[test]
+    fn assert_ordered_evaluates_each_operand_once() {
+        let mut count = 0;
+
+        assert_ordered!(0 < {
+            count += 1;
+            count
+        } < 2);
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    #[should_panic = "values are ordered"]
+    fn assert_ordered_failing() {
+        assert_ordered!(1 < 2 <= 1 < 3);
+    }
+
+    #[test]
+    fn assert_ordered_passing_negate() {
+        assert_ordered!(1 < 2 < 2, negate = true);
+    }
+
+    #[test]
+    fn assert_ordered_failing_arguments() {
+        let low = 1;
+        let high = 10;
+
+        let failure = capture_assertion_failure(|| {
+            assert_ordered!(0 < low <= high < 5);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "values are ordered");
+        assert_eq!(
+            failure.argument("failed comparison").unwrap().value,
+            "high < 5"
+        );
+        assert_eq!(failure.argument("value 1").unwrap().value, "0");
+        assert_eq!(
+            failure.argument("value 2").unwrap().value_description,
+            "low"
+        );
+        assert_eq!(failure.argument("value 3").unwrap().value, "10");
+        assert_eq!(failure.argument("value 4").unwrap().value, "5");
+    }
 }
@@ -19,6 +19,8 @@
 //! [sophie-katz.github.io/test-ur-code-XD/assertions/boolean](https://sophie-katz.github.io/test-ur-code-XD/assertions/boolean/)
 //! for a usage guide.
 
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
@@ -101,8 +103,172 @@ macro_rules! assert_not {
     };
 }
 
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_all_of_impl(conditions: &[(&str, bool)]) -> bool {
+    conditions.iter().all(|(_, value)| *value)
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_any_of_impl(conditions: &[(&str, bool)]) -> bool {
+    conditions.iter().any(|(_, value)| *value)
+}
+
+/// Configures a panic message builder for [`assert_all_of`](crate::assert_all_of) and
+/// [`assert_any_of`](crate::assert_any_of) to show every condition along with whether it passed.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `conditions` - The source code and value of each condition
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_conditions_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    conditions: &[(&str, bool)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    conditions.iter().try_fold(
+        panic_message_builder,
+        |panic_message_builder, (source, value)| {
+            panic_message_builder.with_argument(if *value { "pass" } else { "fail" }, source, value)
+        },
+    )
+}
+
+/// Asserts that every one of several boolean conditions is `true`.
+///
+/// Unlike chaining the conditions with `&&`, every condition is evaluated and the panic message
+/// lists each one with its value, marked `pass` or `fail`, so it's clear which clause failed.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/boolean](https://sophie-katz.github.io/test-ur-code-XD/assertions/boolean/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * One or more `bool` conditions, separated by commas.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_all_of;
+/// #
+/// let name = "sophie";
+///
+/// assert_all_of!(!name.is_empty(), name.len() < 10, name.is_ascii());
+/// assert_all_of!(name.starts_with('s'), description = "names are lowercase");
+/// ```
+#[macro_export]
+macro_rules! assert_all_of {
+    // Collects the conditions until the keyword arguments or the end of the input.
+    (@collect [$($conditions:expr),*] $key:ident = $value:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_all_of!(@assert [$($conditions),*] ; $key = $value $(, $keys = $values)*)
+    };
+    (@collect [$($conditions:expr),*]) => {
+        $crate::assert_all_of!(@assert [$($conditions),*] ;)
+    };
+    (@collect [$($conditions:expr),*] $condition:expr $(, $($rest:tt)*)?) => {
+        $crate::assert_all_of!(@collect [$($conditions,)* $condition] $($($rest)*)?)
+    };
+
+    (@assert [] ; $($keys:ident = $values:expr),*) => {
+        ::std::compile_error!("`assert_all_of!` needs at least one condition")
+    };
+    (@assert [$($conditions:expr),+] ; $($keys:ident = $values:expr),*) => {{
+        let conditions: &[(&str, bool)] = &[$((stringify!($conditions), $conditions)),+];
+
+        $crate::assert_custom!(
+            "all conditions are true",
+            $crate::assertions::bool::assert_all_of_impl(conditions),
+            |panic_message_builder| {
+                $crate::assertions::bool::configure_conditions_panic_message(
+                    panic_message_builder,
+                    conditions,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($($input:tt)*) => {
+        $crate::assert_all_of!(@collect [] $($input)*)
+    };
+}
+
+/// Asserts that at least one of several boolean conditions is `true`.
+///
+/// Unlike chaining the conditions with `||`, every condition is evaluated and the panic message
+/// lists each one with its value, marked `pass` or `fail`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/boolean](https://sophie-katz.github.io/test-ur-code-XD/assertions/boolean/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * One or more `bool` conditions, separated by commas.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_any_of;
+/// #
+/// let path = "config.toml";
+///
+/// assert_any_of!(path.ends_with(".toml"), path.ends_with(".yaml"));
+/// ```
+#[macro_export]
+macro_rules! assert_any_of {
+    // Collects the conditions until the keyword arguments or the end of the input.
+    (@collect [$($conditions:expr),*] $key:ident = $value:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_any_of!(@assert [$($conditions),*] ; $key = $value $(, $keys = $values)*)
+    };
+    (@collect [$($conditions:expr),*]) => {
+        $crate::assert_any_of!(@assert [$($conditions),*] ;)
+    };
+    (@collect [$($conditions:expr),*] $condition:expr $(, $($rest:tt)*)?) => {
+        $crate::assert_any_of!(@collect [$($conditions,)* $condition] $($($rest)*)?)
+    };
+
+    (@assert [] ; $($keys:ident = $values:expr),*) => {
+        ::std::compile_error!("`assert_any_of!` needs at least one condition")
+    };
+    (@assert [$($conditions:expr),+] ; $($keys:ident = $values:expr),*) => {{
+        let conditions: &[(&str, bool)] = &[$((stringify!($conditions), $conditions)),+];
+
+        $crate::assert_custom!(
+            "any condition is true",
+            $crate::assertions::bool::assert_any_of_impl(conditions),
+            |panic_message_builder| {
+                $crate::assertions::bool::configure_conditions_panic_message(
+                    panic_message_builder,
+                    conditions,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($($input:tt)*) => {
+        $crate::assert_any_of!(@collect [] $($input)*)
+    };
+}
+
 #[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
 mod tests {
+    use crate::{assert_eq, utilities::assertion_failure::capture_assertion_failure};
+
     #[test]
     fn assert_passing() {
         assert!(true);
@@ -146,4 +312,107 @@ mod tests {
     fn assert_not_failing_negate() {
         assert_not!(false, negate = true);
     }
+
+    #[test]
+    fn assert_all_of_passing() {
+        let value = String::from("abc");
+
+        assert_all_of!(true, 1 < 2, !value.is_empty());
+    }
+
+    #[test]
+    fn assert_all_of_passing_single_trailing_comma() {
+        assert_all_of!(true,);
+    }
+
+    #[test]
+    #[should_panic(expected = "all conditions are true")]
+    fn assert_all_of_failing() {
+        assert_all_of!(true, false, true);
+    }
+
+    #[test]
+    fn assert_all_of_passing_negate() {
+        assert_all_of!(true, false, negate = true);
+    }
+
+    #[test]
+    fn assert_all_of_failing_arguments() {
+        let x = 5;
+
+        let failure = capture_assertion_failure(|| {
+            assert_all_of!(x > 0, x < 3, x % 2 == 1, description = "x is small and odd");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "all conditions are true");
+        assert_eq!(failure.description.as_deref(), Some("x is small and odd"));
+
+        let arguments = failure
+            .arguments
+            .iter()
+            .map(|argument| {
+                (
+                    argument.name.as_str(),
+                    argument.value_description.as_str(),
+                    argument.value.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            arguments,
+            vec![
+                ("pass", "x > 0", "true"),
+                ("fail", "x < 3", "false"),
+                ("pass", "x % 2 == 1", "true"),
+            ]
+        );
+    }
+
+    #[test]
+    fn assert_all_of_evaluates_every_condition_once() {
+        let mut count = 0;
+
+        let mut next = || {
+            count += 1;
+            count > 1
+        };
+
+        assert_all_of!(next(), next(), negate = true);
+
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn assert_any_of_passing() {
+        assert_any_of!(false, 1 < 2, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "any condition is true")]
+    fn assert_any_of_failing() {
+        assert_any_of!(false, false);
+    }
+
+    #[test]
+    fn assert_any_of_passing_negate() {
+        assert_any_of!(false, false, negate = true);
+    }
+
+    #[test]
+    fn assert_any_of_failing_arguments() {
+        let name = "sophie";
+
+        let failure = capture_assertion_failure(|| {
+            assert_any_of!(name.is_empty(), name.len() > 10);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "any condition is true");
+        assert_eq!(failure.arguments.len(), 2);
+        assert_eq!(failure.arguments[0].name, "fail");
+        assert_eq!(failure.arguments[0].value_description, "name.is_empty()");
+        assert_eq!(failure.arguments[1].value_description, "name.len() > 10");
+    }
 }
@@ -24,7 +24,11 @@
 
 use crate::{
     errors::TestUrCodeXDError,
-    utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+    utilities::{
+        assertion_failure::set_returned_assertion_failure,
+        failure_behavior::{FailureBehavior, OutputTarget},
+        panic_message_builder::{MessageType, PanicMessageBuilder},
+    },
 };
 use std::{convert, error::Error, fmt::Display, panic::Location};
 
@@ -41,9 +45,11 @@ use std::{convert, error::Error, fmt::Display, panic::Location};
 //   Make sure to put <br /> tags after all field doc comments except for the last one. This is to
 //   work around Rustdoc's formatting with examples for fields. It just makes it more readable.
 //
-// Struct must be exhaustive for `{ ..default::Default() }` syntax to work.
-#[allow(clippy::exhaustive_structs)]
+//   Assertion macros create configurations with `config_from_keyword_arguments!` instead of
+//   struct expressions, so that fields can be added without breaking them.
 #[derive(Clone, Default)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
 pub struct Config {
     /// A flag that negates the assertion.
     ///
@@ -143,10 +149,146 @@ pub struct Config {
     ///                                          // fails
     /// );
     /// ```
+    ///
+    /// <br />
     pub description_owned: String,
+
+    /// A category tag for the assertion, like `"io"` or `"parsing"`.
+    ///
+    /// The category is shown in the panic message and is available to code that inspects the
+    /// [`PanicMessageBuilder`] through [`PanicMessageBuilder::category`]. This allows failures in
+    /// large test suites to be grouped by subsystem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::assert_eq;
+    /// #
+    /// # fn read_config() -> String {
+    /// #     "config".to_owned()
+    /// # }
+    /// #
+    /// assert_eq!(read_config(), "config", category = "io");
+    /// ```
+    ///
+    /// <br />
+    pub category: &'static str,
+
+    /// What happens when the assertion fails, overriding the process-wide behavior set with
+    /// [`set_failure_behavior`](crate::utilities::failure_behavior::set_failure_behavior).
+    ///
+    /// When this is `None`, the process-wide behavior is used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use test_ur_code_xd::{assert_eq, utilities::failure_behavior::FailureBehavior};
+    /// #
+    /// // Prints the failure message and exits with status code 2
+    /// assert_eq!(1 + 1, 3, failure_behavior = Some(FailureBehavior::Exit(2)));
+    /// ```
+    ///
+    /// <br />
+    pub failure_behavior: Option<FailureBehavior>,
+
+    /// Where the failure message is printed when the assertion fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::{assert_eq, utilities::failure_behavior::OutputTarget};
+    /// #
+    /// assert_eq!(1 + 1, 2, output_target = OutputTarget::Stdout);
+    /// ```
+    pub output_target: OutputTarget,
 }
 
 impl Config {
+    /// Creates a configuration with the default settings.
+    ///
+    /// This is the same as [`Config::default`], but reads better at the start of a chain of
+    /// `with_*` methods. It is useful when writing assertion macros that pass a configuration to
+    /// [`assert_custom`](crate::assert_custom) with `config = ...`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::{assert_custom, assertions::config::Config};
+    /// #
+    /// assert_custom!(
+    ///     "value is even",
+    ///     3 % 2 == 0,
+    ///     |panic_message_builder| Ok(panic_message_builder),
+    ///     config = Config::new().with_negate(true)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether or not the assertion is negated. See [`negate`](Self::negate).
+    ///
+    /// # Arguments
+    ///
+    /// * `negate` - Whether or not to negate the assertion.
+    #[must_use]
+    pub const fn with_negate(mut self, negate: bool) -> Self {
+        self.negate = negate;
+
+        self
+    }
+
+    /// Sets the description of what the assertion means. See
+    /// [`description_owned`](Self::description_owned).
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - The description.
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description_owned = description.into();
+
+        self
+    }
+
+    /// Sets the category tag for the assertion. See [`category`](Self::category).
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category.
+    #[must_use]
+    pub const fn with_category(mut self, category: &'static str) -> Self {
+        self.category = category;
+
+        self
+    }
+
+    /// Sets what happens when the assertion fails. See
+    /// [`failure_behavior`](Self::failure_behavior).
+    ///
+    /// # Arguments
+    ///
+    /// * `failure_behavior` - The failure behavior to use for this assertion.
+    #[must_use]
+    pub const fn with_failure_behavior(mut self, failure_behavior: FailureBehavior) -> Self {
+        self.failure_behavior = Some(failure_behavior);
+
+        self
+    }
+
+    /// Sets where the failure message is printed. See [`output_target`](Self::output_target).
+    ///
+    /// # Arguments
+    ///
+    /// * `output_target` - Where to print the failure message.
+    #[must_use]
+    pub const fn with_output_target(mut self, output_target: OutputTarget) -> Self {
+        self.output_target = output_target;
+
+        self
+    }
+
     /// A helper function for executing assertions. This will almost always be wrapped by the
     /// `assert_custom` macro.
     ///
@@ -173,10 +315,7 @@ impl Config {
     /// let lhs = 5;
     /// let rhs = 6;
     ///
-    /// Config {
-    ///     negate: true,
-    ///     ..Config::default()
-    /// }.execute_assertion(
+    /// Config::new().with_negate(true).execute_assertion(
     ///     "lhs == rhs",
     ///     lhs.eq(&rhs),
     ///     Location::caller(),
@@ -195,6 +334,7 @@ impl Config {
         // Errors should be handled here so that assert_custom!(...) doesn't have to.
         clippy::expect_used
     )]
+    #[track_caller]
     pub fn execute_assertion<
         ConfigurePanicMessageType: FnOnce(PanicMessageBuilder) -> Result<PanicMessageBuilder, TestUrCodeXDError>,
     >(
@@ -217,6 +357,12 @@ impl Config {
         //
         // This truth table is the same as `negate == predicate`, which is used as the condition
         // below. It's hard to read, but efficient!
+        if self.failure_behavior == Some(FailureBehavior::Return) {
+            // Clear any failure left over from an earlier assertion so that it isn't mistaken for a
+            // failure of this one
+            set_returned_assertion_failure(None);
+        }
+
         if self.negate == predicate_value {
             // Create panic message builder
             let panic_message_builder = PanicMessageBuilder::unwrap_error_with(
@@ -234,8 +380,8 @@ impl Config {
                 PanicMessageBuilder::no_configuration
             );
 
-            // Trigger the actual panic
-            panic_message_builder.panic();
+            // Trigger the actual panic, unless the failure is returned instead
+            panic_message_builder.fail();
         }
     }
 
@@ -252,16 +398,56 @@ impl Config {
         )
         .with_description(self.description)?;
 
-        let panic_message_builder =
-            panic_message_builder.with_description(self.description_owned)?;
+        let mut panic_message_builder = panic_message_builder
+            .with_description(self.description_owned)?
+            .with_category(self.category)
+            .with_output_target(self.output_target);
+
+        if let Some(failure_behavior) = self.failure_behavior {
+            panic_message_builder = panic_message_builder.with_failure_behavior(failure_behavior);
+        }
 
         Ok(panic_message_builder)
     }
 }
 
+/// Creates a [`Config`] from the `<key> = <value>` keyword arguments of an assertion macro.
+///
+/// The keys are the names of fields of [`Config`]. Giving the same key twice is a compile error.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! config_from_keyword_arguments {
+    ($($keys:ident = $values:expr),* $(,)?) => {{
+        // Destructuring the keys makes giving the same key twice a compile error, like it is in a
+        // struct expression. The function is never called, and the wildcards are the point.
+        #[allow(dead_code, clippy::unneeded_field_pattern)]
+        fn check_keys(config: &$crate::assertions::config::Config) {
+            let $crate::assertions::config::Config { $($keys: _,)* .. } = config;
+        }
+
+        // There may not be any keyword arguments to set
+        #[allow(unused_mut)]
+        let mut config = $crate::assertions::config::Config::new();
+
+        $(config.$keys = $values;)*
+
+        config
+    }};
+}
+
 #[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::{assert_eq, utilities::assertion_failure::capture_assertion_failure};
+    use std::collections::BTreeMap;
+
+    #[cfg(feature = "filesystem")]
+    use crate::utilities::sandbox::TestDir;
 
     #[test]
     fn using_struct_no_panic() {
@@ -299,6 +485,35 @@ mod tests {
         .execute_assertion("value is true", true, Location::caller(), Ok);
     }
 
+    #[test]
+    fn builder_sets_fields() {
+        let config = Config::new()
+            .with_negate(true)
+            .with_description("some description")
+            .with_category("io")
+            .with_failure_behavior(FailureBehavior::Exit(2))
+            .with_output_target(OutputTarget::Stdout);
+
+        assert_eq!(config.negate, true);
+        assert_eq!(config.description, "");
+        assert_eq!(config.description_owned, "some description");
+        assert_eq!(config.category, "io");
+        assert_eq!(config.failure_behavior, Some(FailureBehavior::Exit(2)));
+        assert_eq!(config.output_target, OutputTarget::Stdout);
+    }
+
+    #[test]
+    fn builder_description_in_failure() {
+        let failure = capture_assertion_failure(|| {
+            Config::new()
+                .with_description("some description")
+                .execute_assertion("value is true", false, Location::caller(), Ok);
+        })
+        .unwrap();
+
+        assert_eq!(failure.description.as_deref(), Some("some description"));
+    }
+
     #[test]
     #[should_panic(expected = "predicate description")]
     fn panic_message_no_description() {
@@ -314,4 +529,137 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn create_panic_message_builder_with_category() {
+        let panic_message_builder = Config {
+            category: "io",
+            ..Config::default()
+        }
+        .create_panic_message_builder("value is true", Location::caller())
+        .unwrap();
+
+        assert_eq!(panic_message_builder.category(), Some("io"));
+    }
+
+    /// Asserts that an assertion whose predicate holds fails when it is negated, and that the
+    /// description is included in the failure.
+    macro_rules! assert_negated_with_description {
+        ($assertion:ident!($($arguments:tt)*)) => {{
+            let failure = capture_assertion_failure(|| {
+                $crate::$assertion!($($arguments)*, negate = true, description = "negated");
+            });
+
+            let description = failure.and_then(|failure| failure.description);
+
+            assert_eq!(
+                description.as_deref(),
+                Some("negated"),
+                description = stringify!($assertion)
+            );
+        }};
+    }
+
+    #[test]
+    fn keywords_on_every_assertion() {
+        assert_negated_with_description!(assert!(true));
+        assert_negated_with_description!(assert_not!(false));
+        assert_negated_with_description!(assert_custom!("value is true", true, Ok));
+        assert_negated_with_description!(assert_eq!(1, 1));
+        assert_negated_with_description!(assert_ne!(1, 2));
+        assert_negated_with_description!(assert_ne!(1, -2, compare_key = |value: &i32| *value));
+        assert_negated_with_description!(assert_lt!(1, 2));
+        assert_negated_with_description!(assert_le!(1, 1));
+        assert_negated_with_description!(assert_gt!(2, 1));
+        assert_negated_with_description!(assert_ge!(1, 1));
+        assert_negated_with_description!(assert_str_eq!("a", "a"));
+        assert_negated_with_description!(assert_str_eq!("a", "A", ignore_case = true));
+        assert_negated_with_description!(assert_str_contains!("abc", "b"));
+        assert_negated_with_description!(assert_str_starts_with!("abc", "a"));
+        assert_negated_with_description!(assert_str_ends_with!("abc", "c"));
+        assert_negated_with_description!(assert_matches!(Some(1), Some(_)));
+        assert_negated_with_description!(assert_all_ok!(vec![Ok::<i32, i32>(1)]));
+        assert_negated_with_description!(assert_contains_in_order!(vec![1, 2, 3], vec![1, 3]));
+        assert_negated_with_description!(assert_sorted!(vec![1, 2, 3]));
+        assert_negated_with_description!(assert_sorted!(vec![3, 2, 1], descending = true));
+        assert_negated_with_description!(assert_sorted_by_key!(vec![1, 2], |value: &i32| *value));
+        assert_negated_with_description!(assert_subset_of!(vec![1], vec![1, 2]));
+        assert_negated_with_description!(assert_superset_of!(vec![1, 2], vec![1]));
+        assert_negated_with_description!(assert_disjoint!(vec![1], vec![2]));
+        assert_negated_with_description!(assert_map_eq!(
+            BTreeMap::from([(1, 1)]),
+            BTreeMap::from([(1, 1)])
+        ));
+        assert_negated_with_description!(assert_map_keys_all!(
+            BTreeMap::from([(1, 1)]),
+            |key: &i32| *key == 1
+        ));
+        assert_negated_with_description!(assert_map_values_all!(
+            BTreeMap::from([(1, 1)]),
+            |value: &i32| *value == 1
+        ));
+        assert_negated_with_description!(assert_that!(1, crate::assertions::matcher::eq(1)));
+        assert_negated_with_description!(assert_eventually!(|| true));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn keywords_on_regex_assertions() {
+        assert_negated_with_description!(assert_str_matches!("abc", "a.c"));
+    }
+
+    #[cfg(feature = "float")]
+    #[test]
+    fn keywords_on_float_assertions() {
+        assert_negated_with_description!(assert_approx_eq!(1.0, 1.0, tolerance = 0.1));
+        assert_negated_with_description!(assert_f32_eq!(1.0_f32, 1.0_f32));
+        assert_negated_with_description!(assert_f64_eq!(1.0, 1.0, ulps = 2));
+        assert_negated_with_description!(assert_f64_lt!(1.0, 2.0, relative_epsilon = 0.01));
+        assert_negated_with_description!(assert_f64_slice_eq!([1.0_f64], [1.0_f64]));
+        assert_negated_with_description!(assert_points_close!(
+            [(1.0_f64, 1.0_f64)],
+            [(1.0_f64, 1.0_f64)],
+            epsilon = 0.1
+        ));
+    }
+
+    #[cfg(feature = "filesystem")]
+    #[test]
+    fn keywords_on_filesystem_assertions() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir
+            .write_file("hello_world.txt", "hello, world")
+            .unwrap();
+
+        assert_negated_with_description!(assert_path_exists!(&path));
+        assert_negated_with_description!(assert_path_is_file!(&path));
+        assert_negated_with_description!(assert_path_is_absolute!(&path));
+        assert_negated_with_description!(assert_path_starts_with!("a/b", "a"));
+        assert_negated_with_description!(assert_path_has_extension!(&path, "txt"));
+        assert_negated_with_description!(assert_file_size_eq!(&path, 12));
+        assert_negated_with_description!(assert_file_text_eq!(&path, "hello, world"));
+        assert_negated_with_description!(assert_file_text_eq!(&path, "hello, world", max_len = 64));
+        assert_negated_with_description!(assert_files_eq!(&path, &path));
+        assert_negated_with_description!(assert_file_eventually_contains!(&path, "hello"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn keywords_on_json_assertions() {
+        assert_negated_with_description!(assert_json_eq!(vec![1], vec![1]));
+        assert_negated_with_description!(assert_json_contains!(vec![1, 2], vec![1]));
+    }
+
+    #[cfg(feature = "panic")]
+    #[test]
+    // Panicking is the point of this test
+    #[allow(clippy::panic)]
+    fn keywords_on_panic_assertions() {
+        assert_negated_with_description!(assert_panics!(|| panic!("some panic")));
+        assert_negated_with_description!(assert_panics!(
+            || panic!("some panic"),
+            on_message = |_| {}
+        ));
+        assert_negated_with_description!(assert_panics!(|| panic!("some panic"), with_type = &str));
+    }
 }
@@ -36,8 +36,13 @@
 /// * `configure_panic_message` - A closure that takes a [`PanicMessageBuilder`] and returns an
 ///                               optionally modified [`PanicMessageBuilder`]. This is used to
 ///                               configure the panic message, usually to add arguments to it.
+///                               It is only called if the assertion fails, so formatting
+///                               arguments costs nothing when the assertion passes.
 /// * `key = value` pairs - A variable number of `key = value` pair expressions, separated by
 ///                         commas. These are used to set the fields of the [`Config`] instance.
+/// * `config = value` - Instead of `key = value` pairs, an explicit [`Config`] instance. This is
+///                      the stable way for other crates to write their own assertion macros, since
+///                      it does not depend on the names of the keyword arguments.
 ///
 /// # Example
 ///
@@ -58,19 +63,55 @@
 ///     negate = true,
 /// )
 /// ```
+///
+/// With an explicit configuration:
+///
+/// ```
+/// # use test_ur_code_xd::{assert_custom, assertions::config::Config};
+/// #
+/// let lhs = 5;
+/// let rhs = 6;
+///
+/// assert_custom!(
+///     "lhs == rhs",
+///     lhs.eq(&rhs),
+///     |panic_message_builder| {
+///         panic_message_builder
+///             .with_argument("lhs", "lhs", &lhs)?
+///             .with_argument("rhs", "rhs", &rhs)
+///     },
+///     config = Config::new()
+///         .with_negate(true)
+///         .with_description("the values are expected to differ"),
+/// )
+/// ```
+///
+/// [`Config`]: crate::assertions::config::Config
+/// [`PanicMessageBuilder`]: crate::utilities::panic_message_builder::PanicMessageBuilder
 #[allow(clippy::module_name_repetitions)]
 #[macro_export]
 macro_rules! assert_custom {
+    (
+        $predicate_description:expr,
+        $predicate_value:expr,
+        $configure_panic_message:expr,
+        config = $config:expr $(,)?
+    ) => {
+        $crate::assertions::config::Config::execute_assertion(
+            $config,
+            $predicate_description,
+            $predicate_value,
+            ::std::panic::Location::caller(),
+            $configure_panic_message,
+        )
+    };
     (
         $predicate_description:expr,
         $predicate_value:expr,
         $configure_panic_message:expr
         $(, $keys:ident = $values:expr)* $(,)?
     ) => {
-        $crate::assertions::config::Config {
-            $($keys: $values ,)*
-            ..::std::default::Default::default()
-        }.execute_assertion(
+        $crate::config_from_keyword_arguments!($($keys = $values),*).execute_assertion(
             $predicate_description,
             $predicate_value,
             ::std::panic::Location::caller(),
@@ -80,7 +121,30 @@ macro_rules! assert_custom {
 }
 
 #[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
 mod tests {
+    use crate::{
+        assert, assert_eq, assertions::config::Config,
+        utilities::assertion_failure::capture_assertion_failure,
+    };
+    use std::{
+        cell::Cell,
+        fmt::{self, Debug, Formatter},
+    };
+
+    /// A value that counts how many times it is formatted with [`Debug`].
+    #[derive(PartialEq)]
+    struct CountsDebug<'count>(&'count Cell<usize>);
+
+    impl Debug for CountsDebug<'_> {
+        fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+
+            formatter.write_str("CountsDebug")
+        }
+    }
+
     #[test]
     fn assert_custom_passing() {
         assert_custom!("value is true", true, |panic_message_builder| {
@@ -120,4 +184,74 @@ mod tests {
             negate = true
         );
     }
+
+    #[test]
+    fn assert_custom_passing_explicit_config() {
+        assert_custom!(
+            "value is true",
+            false,
+            |panic_message_builder| {
+                panic_message_builder.with_argument("value", "value", &true)
+            },
+            config = Config::new().with_negate(true),
+        );
+    }
+
+    #[test]
+    fn assert_custom_failing_explicit_config() {
+        let failure = capture_assertion_failure(|| {
+            assert_custom!(
+                "value is true",
+                false,
+                |panic_message_builder| {
+                    panic_message_builder.with_argument("value", "value", &false)
+                },
+                config = Config::new().with_description("value should be true"),
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "value is true");
+        assert_eq!(failure.description.as_deref(), Some("value should be true"));
+    }
+
+    #[test]
+    fn assert_custom_passing_does_not_configure_panic_message() {
+        let is_configured = Cell::new(false);
+
+        assert_custom!("value is true", true, |panic_message_builder| {
+            is_configured.set(true);
+
+            panic_message_builder.with_argument("value", "value", &true)
+        });
+
+        assert!(!is_configured.get());
+    }
+
+    #[test]
+    fn assert_eq_passing_does_not_format_arguments() {
+        let debug_count = Cell::new(0);
+
+        for _ in 0..100 {
+            assert_eq!(CountsDebug(&debug_count), CountsDebug(&debug_count));
+        }
+
+        assert_eq!(debug_count.get(), 0);
+    }
+
+    #[test]
+    fn assert_eq_failing_formats_arguments() {
+        let debug_count = Cell::new(0);
+
+        capture_assertion_failure(|| {
+            assert_eq!(
+                CountsDebug(&debug_count),
+                CountsDebug(&debug_count),
+                negate = true
+            );
+        })
+        .unwrap();
+
+        assert!(debug_count.get() > 0);
+    }
 }
@@ -0,0 +1,300 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that compare selected fields of two values.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/fields](https://sophie-katz.github.io/test-ur-code-XD/assertions/fields/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// A field that has a different value in the actual and expected values.
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct FieldDifference {
+    /// The name of the field.
+    pub name: &'static str,
+
+    /// The debug representation of the field in the actual value.
+    pub actual: String,
+
+    /// The debug representation of the field in the expected value.
+    pub expected: String,
+}
+
+/// Compares a field of the actual and expected values, recording a difference if they are unequal.
+///
+/// # Arguments
+///
+/// * `field_differences` - The differences found so far
+/// * `name` - The name of the field
+/// * `actual` - The field in the actual value
+/// * `expected` - The field in the expected value
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn compare_field<
+    ActualType: PartialEq<ExpectedType> + Debug + ?Sized,
+    ExpectedType: Debug + ?Sized,
+>(
+    field_differences: &mut Vec<FieldDifference>,
+    name: &'static str,
+    actual: &ActualType,
+    expected: &ExpectedType,
+) {
+    if !actual.eq(expected) {
+        field_differences.push(FieldDifference {
+            name,
+            actual: format!("{actual:?}"),
+            expected: format!("{expected:?}"),
+        });
+    }
+}
+
+/// Configures a panic message builder to show each field that differs.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `field_names` - The names of all the fields that were compared
+/// * `field_differences` - The fields that differ
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_fields_eq_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    field_names: &[&str],
+    field_differences: &[FieldDifference],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let mut panic_message_builder =
+        panic_message_builder.with_argument_formatted("fields", "--", field_names.join(", "))?;
+
+    for field_difference in field_differences {
+        panic_message_builder = panic_message_builder.with_argument_formatted(
+            format!("field {}", field_difference.name),
+            "--",
+            format!(
+                "{} (expected {})",
+                field_difference.actual, field_difference.expected
+            ),
+        )?;
+    }
+
+    Ok(panic_message_builder)
+}
+
+/// Asserts that selected fields of two values are equal, ignoring all other fields.
+///
+/// This is useful for comparing structs with fields that are irrelevant to a test, like IDs or
+/// timestamps. Fields are accessed directly, so no derive is needed and the two values can even be
+/// of different types. If any fields differ, each one is shown in the panic message with its actual
+/// and expected values.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/fields](https://sophie-katz.github.io/test-ur-code-XD/assertions/fields/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `actual` - The value to check.
+/// * `expected` - The value that `actual` is expected to match.
+/// * `fields` - The names of the fields to compare, like `fields = [name, age]`. Each field must
+///              implement [`PartialEq`] and [`Debug`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_fields_eq;
+/// #
+/// struct User {
+///     id: u64,
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let actual = User { id: 17, name: "Sophie".to_owned(), age: 30 };
+/// let expected = User { id: 0, name: "Sophie".to_owned(), age: 30 };
+///
+/// assert_fields_eq!(actual, expected, fields = [name, age]);
+/// ```
+#[macro_export]
+macro_rules! assert_fields_eq {
+    (
+        $actual:expr,
+        $expected:expr,
+        fields = [$($fields:ident),+ $(,)?]
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let actual = &$actual;
+        let expected = &$expected;
+
+        let mut field_differences = ::std::vec::Vec::new();
+
+        $(
+            $crate::assertions::fields::compare_field(
+                &mut field_differences,
+                stringify!($fields),
+                &actual.$fields,
+                &expected.$fields,
+            );
+        )+
+
+        $crate::assert_custom!(
+            "selected fields of actual == selected fields of expected",
+            field_differences.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::fields::configure_fields_eq_panic_message(
+                    panic_message_builder,
+                    &[$(stringify!($fields)),+],
+                    &field_differences,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{assert, assert_eq, utilities::assertion_failure::capture_assertion_failure};
+
+    #[derive(Debug)]
+    struct User {
+        id: u64,
+        name: String,
+        age: u32,
+    }
+
+    #[derive(Debug)]
+    struct UserSummary {
+        name: &'static str,
+        age: u32,
+    }
+
+    fn user(id: u64, name: &str, age: u32) -> User {
+        User {
+            id,
+            name: name.to_owned(),
+            age,
+        }
+    }
+
+    #[test]
+    fn passing() {
+        assert_fields_eq!(
+            user(1, "Sophie", 30),
+            user(2, "Sophie", 30),
+            fields = [name, age]
+        );
+    }
+
+    #[test]
+    fn passing_single_field_trailing_comma() {
+        assert_fields_eq!(user(1, "Sophie", 30), user(2, "Alice", 30), fields = [age,],);
+    }
+
+    #[test]
+    fn passing_different_types() {
+        let summary = UserSummary {
+            name: "Sophie",
+            age: 30,
+        };
+
+        assert_fields_eq!(user(1, "Sophie", 30), summary, fields = [name, age]);
+    }
+
+    #[test]
+    fn passing_references() {
+        let actual = user(1, "Sophie", 30);
+        let expected = user(2, "Sophie", 30);
+
+        assert_fields_eq!(&actual, &expected, fields = [name]);
+    }
+
+    #[test]
+    #[should_panic(expected = "selected fields of actual == selected fields of expected")]
+    fn failing() {
+        assert_fields_eq!(
+            user(1, "Sophie", 30),
+            user(1, "Sophie", 31),
+            fields = [id, age]
+        );
+    }
+
+    #[test]
+    fn passing_negate() {
+        assert_fields_eq!(
+            user(1, "Sophie", 30),
+            user(1, "Sophie", 31),
+            fields = [id, age],
+            negate = true
+        );
+    }
+
+    #[test]
+    fn failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_fields_eq!(
+                user(1, "Sophie", 30),
+                user(2, "Alice", 30),
+                fields = [name, age, id]
+            );
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.predicate,
+            "selected fields of actual == selected fields of expected"
+        );
+        assert_eq!(failure.argument("fields").unwrap().value, "name, age, id");
+        assert_eq!(
+            failure.argument("field name").unwrap().value,
+            "\"Sophie\" (expected \"Alice\")"
+        );
+        assert_eq!(
+            failure.argument("field id").unwrap().value,
+            "1 (expected 2)"
+        );
+        assert!(failure.argument("field age").is_none());
+    }
+
+    #[test]
+    fn failing_with_description() {
+        let failure = capture_assertion_failure(|| {
+            assert_fields_eq!(
+                user(1, "Sophie", 30),
+                user(1, "Sophie", 31),
+                fields = [age],
+                description = "ages should match"
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.description.as_deref(), Some("ages should match"));
+        assert_eq!(
+            failure.argument("field age").unwrap().value,
+            "30 (expected 31)"
+        );
+    }
+}
@@ -20,14 +20,28 @@
 //! for a usage guide.
 
 use std::{
+    collections::BTreeSet,
+    env,
     error::Error,
-    fs::File,
-    io::{BufReader, Read},
+    ffi::OsStr,
+    fmt::Debug,
+    fs::{self, File, Metadata},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom},
     panic::Location,
-    path::Path,
+    path::{Component, Path, PathBuf},
+    time::{Duration, SystemTime},
 };
 
-use crate::utilities::panic_message_builder::PanicMessageBuilder;
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+#[cfg(feature = "regex")]
+use crate::assertions::string::assert_str_matches_impl;
+
+#[cfg(feature = "regex")]
+use regex::Regex;
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
@@ -51,13 +65,12 @@ pub fn assert_path_exists_impl(path: impl AsRef<Path>) -> bool {
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_exists;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_exists, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a file within it
 /// # fs::File::create("some_file").unwrap();
@@ -101,13 +114,12 @@ pub fn assert_path_is_file_impl(path: impl AsRef<Path>) -> bool {
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_is_file;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_is_file, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a file within it
 /// # fs::File::create("some_file").unwrap();
@@ -151,9 +163,8 @@ pub fn assert_path_is_symlink_impl(path: impl AsRef<Path>) -> bool {
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_is_symlink;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_is_symlink, utilities::sandbox::TestDir};
 /// #
 /// # #[cfg(target_family = "unix")]
 /// # use std::os::unix::fs::symlink;
@@ -161,9 +172,9 @@ pub fn assert_path_is_symlink_impl(path: impl AsRef<Path>) -> bool {
 /// # #[cfg(target_family = "windows")]
 /// # use std::os::windows::fs::symlink_file as symlink;
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a file and a symlink within it
 /// # fs::File::create("some_file").unwrap();
@@ -209,13 +220,12 @@ pub fn assert_path_is_dir_impl(path: impl AsRef<Path>) -> bool {
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_is_dir;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_is_dir, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a directory within it
 /// # fs::create_dir("some_dir").unwrap();
@@ -347,13 +357,12 @@ pub fn assert_path_starts_with_impl(path: impl AsRef<Path>, base: impl AsRef<Pat
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_starts_with;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_starts_with, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create some nested directories with a file at the end
 /// # fs::create_dir("a").unwrap();
@@ -401,13 +410,12 @@ pub fn assert_path_ends_with_impl(path: impl AsRef<Path>, child: impl AsRef<Path
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::assert_path_ends_with;
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_ends_with, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create some nested directories with a file at the end
 /// # fs::create_dir("a").unwrap();
@@ -432,42 +440,189 @@ macro_rules! assert_path_ends_with {
     };
 }
 
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_path_has_extension_impl(
+    path: impl AsRef<Path>,
+    extension: impl AsRef<OsStr>,
+) -> bool {
+    path.as_ref().extension() == Some(extension.as_ref())
+}
+
+/// Asserts that the path has an extension.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+/// * `extension` - The expected extension, without the leading `.`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_path_has_extension;
+/// #
+/// assert_path_has_extension!("a/b/c.txt", "txt");
+///
+/// assert_path_has_extension!("a/b/c.tar.gz", "gz");
+/// ```
+#[macro_export]
+macro_rules! assert_path_has_extension {
+    ($path:expr, $extension:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_custom!(
+            "path has extension",
+            $crate::assertions::filesystem::assert_path_has_extension_impl(&$path, &$extension),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("extension", stringify!($extension), &::std::convert::AsRef::<::std::ffi::OsStr>::as_ref(&$extension))
+            }
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+// Expect allowed because any failure to create the panic message builder is hard coded in, not
+// dependent on how this function is called.
+#[allow(clippy::expect_used)]
+#[track_caller]
+pub fn read_metadata(path: impl AsRef<Path>) -> Metadata {
+    match fs::metadata(path.as_ref()) {
+        Ok(metadata) => metadata,
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "error reading path metadata",
+            Location::caller(),
+            &error,
+        )
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("path", "--", &path.as_ref())
+        })
+        .expect("error while creating panic message builder")
+        .panic(),
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_path_is_readonly_impl(metadata: &Metadata) -> bool {
+    metadata.permissions().readonly()
+}
+
+/// Asserts that the path exists on the filesystem and is read-only.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_path_is_readonly, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a read-only file within it
+/// # fs::File::create("some_file").unwrap();
+/// # let mut permissions = fs::metadata("some_file").unwrap().permissions();
+/// # permissions.set_readonly(true);
+/// # fs::set_permissions("some_file", permissions).unwrap();
+/// #
+/// assert_path_is_readonly!("some_file");
+/// ```
+#[macro_export]
+macro_rules! assert_path_is_readonly {
+    ($path:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let metadata = $crate::assertions::filesystem::read_metadata(&$path);
+
+        $crate::assert_custom!(
+            "path is read-only",
+            $crate::assertions::filesystem::assert_path_is_readonly_impl(&metadata),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("permissions", "--", &metadata.permissions())
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
 /// Helper method that panics if a path does not exist or is not a file.
+#[track_caller]
 fn ensure_is_file(path: &impl AsRef<Path>) {
     if !path.as_ref().is_file() {
-        PanicMessageBuilder::new("path is file", Location::caller())
-            .with_argument("path", "--", &path.as_ref())
-            .expect("unable to create panic message builder")
-            .panic();
+        PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "path is file",
+            Location::caller(),
+        )
+        .with_argument("path", "--", &path.as_ref())
+        .expect("unable to create panic message builder")
+        .panic();
     }
 }
 
 /// Helper method that tries to read a file and panics if there are any errors.
+#[track_caller]
 fn unwrap_file_read<ValueType, ErrorType: Error>(
     path: &impl AsRef<Path>,
     result: Result<ValueType, ErrorType>,
 ) -> ValueType {
     match result {
         Ok(file_text) => file_text,
-        Err(error) => {
-            PanicMessageBuilder::new_from_error("error reading file", Location::caller(), &error)
-                .and_then(|panic_message_builder| {
-                    panic_message_builder.with_argument("path", "--", &path.as_ref())
-                })
-                .expect("error while creating panic message builder")
-                .panic()
-        }
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "error reading file",
+            Location::caller(),
+            &error,
+        )
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("path", "--", &path.as_ref())
+        })
+        .expect("error while creating panic message builder")
+        .panic(),
     }
 }
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
+#[track_caller]
 pub fn assert_file_text_impl<OnTextType: FnOnce(String)>(
     path: impl AsRef<Path>,
     max_len: u64,
     on_text: OnTextType,
 ) {
+    on_text(read_file_text(path, max_len));
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn read_file_text(path: impl AsRef<Path>, max_len: u64) -> String {
     // Make sure that path points to a file that exists
     ensure_is_file(&path);
 
@@ -486,8 +641,7 @@ pub fn assert_file_text_impl<OnTextType: FnOnce(String)>(
     // Read the file
     unwrap_file_read(&path, buf_reader.read_to_string(&mut string));
 
-    // Call the action
-    on_text(string);
+    string
 }
 
 /// Asserts that the file contains text that matches assertions.
@@ -501,18 +655,16 @@ pub fn assert_file_text_impl<OnTextType: FnOnce(String)>(
 /// * `path` - The path of the file to read.
 /// * `max_len` - The maximum expected size of the file in bytes.
 /// * `on_text` - A closure that takes the file content string as an argument.
-/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::{assert_file_text, assert_eq};
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_text, assert_eq, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a file within it
 /// # fs::write("hello_world_file.txt", "hello, world").unwrap();
@@ -538,6 +690,7 @@ macro_rules! assert_file_text {
 // Expect allowed because any failure to set the description is hard coded in, not dependent on how
 // this function is called.
 #[allow(clippy::expect_used)]
+#[track_caller]
 pub fn assert_file_text_raw_impl<OnTextType: FnOnce(&[u8])>(
     path: impl AsRef<Path>,
     max_len: u64,
@@ -564,6 +717,7 @@ pub fn assert_file_text_raw_impl<OnTextType: FnOnce(&[u8])>(
         }
         Err(error) => {
             PanicMessageBuilder::new(
+                MessageType::ErrorWhileCheckingAssertion,
                 format!(
                     "file size overflows system bit width (file size: {} bytes, maximum value of bit width: {} bytes)",
                     file_len, usize::MAX
@@ -585,6 +739,7 @@ pub fn assert_file_text_raw_impl<OnTextType: FnOnce(&[u8])>(
 }
 
 /// Ensures that the file length is within a limit and panics otherwise
+#[track_caller]
 fn ensure_file_len_within_limit(path: &impl AsRef<Path>, file: &File, max_len: u64) -> u64 {
     // Get the file length
     let file_len = unwrap_file_read(&path, file.metadata()).len();
@@ -592,6 +747,7 @@ fn ensure_file_len_within_limit(path: &impl AsRef<Path>, file: &File, max_len: u
     // Compare the length to the limit
     if file_len > max_len {
         PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
             format!("file is larger than limit (size: {file_len} bytes, limit: {max_len} bytes)"),
             Location::caller(),
         )
@@ -614,18 +770,16 @@ fn ensure_file_len_within_limit(path: &impl AsRef<Path>, file: &File, max_len: u
 /// * `path` - The path of the file to read.
 /// * `max_len` - The maximum expected size of the file in bytes.
 /// * `on_text` - A closure that takes the file content byte array as an argument.
-/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
 /// ```
-/// # use std::{env, fs};
-/// # use tempfile::tempdir;
-/// # use test_ur_code_xd::{assert_file_text_raw, assert_eq};
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_text_raw, assert_eq, utilities::sandbox::TestDir};
 /// #
-/// # // Create a temporary directory and "cd" into it
-/// # let temp_dir = tempdir().unwrap();
-/// # env::set_current_dir(temp_dir.path()).unwrap();
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
 /// #
 /// # // Create a file within it
 /// # fs::write("hello_world_file.txt", "hello, world").unwrap();
@@ -645,123 +799,1281 @@ macro_rules! assert_file_text_raw {
     };
 }
 
-// Unwrap is used to reduce the length of the test code.
-#[allow(clippy::unwrap_used)]
-#[cfg(test)]
-mod tests {
-    use crate::assert_eq;
-    use std::{env, fs, io::Write};
-    use tempfile::tempdir;
-
-    // If on Unix, use the Unix flavor of symlink
-    #[cfg(target_family = "unix")]
-    use std::os::unix::fs::symlink;
+/// The default maximum file size in bytes for [`assert_file_text_eq`],
+/// [`assert_file_text_matches`], and [`assert_write_then_read_eq`].
+pub const DEFAULT_MAX_FILE_TEXT_LEN: u64 = 1024 * 1024;
 
-    // If on Windows, use the Windows flavor of symlink, but alias it to the same name so it is
-    // opaque.
-    #[cfg(target_family = "windows")]
-    use std::os::windows::fs::symlink_file as symlink;
+/// The maximum number of lines of file text to include in panic messages.
+const MAX_FILE_TEXT_EXCERPT_LINE_COUNT: usize = 16;
 
-    #[test]
-    fn assert_path_exists_passing_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn format_file_text_excerpt(text: &str) -> String {
+    let mut lines = text.lines();
 
-        assert_path_exists!("some_file");
-    }
+    let excerpt = lines
+        .by_ref()
+        .take(MAX_FILE_TEXT_EXCERPT_LINE_COUNT)
+        .collect::<Vec<_>>()
+        .join("\n");
 
-    #[test]
-    fn assert_path_exists_passing_symlink() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
-        symlink("some_file", "some_symlink").unwrap();
+    let remaining_line_count = lines.count();
 
-        assert_path_exists!("some_symlink");
+    if remaining_line_count > 0 {
+        format!("{excerpt}\n... ({remaining_line_count} more lines)")
+    } else {
+        excerpt
     }
+}
 
-    #[test]
-    fn assert_path_exists_passing_directory() {
-        let temp_dir = tempdir().unwrap();
-
-        assert_path_exists!(temp_dir.path());
-    }
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_file_text_eq_impl(file_text: impl AsRef<str>, expected: impl AsRef<str>) -> bool {
+    file_text.as_ref() == expected.as_ref()
+}
 
-    #[test]
-    #[should_panic(expected = "path exists")]
-    fn assert_path_exists_failing_bad_name() {
-        assert_path_exists!("a_file_that_does_not_exist");
-    }
+/// Asserts that the text of a file is equal to a string.
+///
+/// If the `UPDATE` environment variable is set to `1`, the file is rewritten with `expected`
+/// instead of failing. See [`update_mode`](crate::utilities::update_mode) for more information.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read.
+/// * `expected` - The string that the file text is expected to equal.
+/// * `max_len` - Optional maximum expected size of the file in bytes. Defaults to
+///               [`DEFAULT_MAX_FILE_TEXT_LEN`](crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN).
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `max_len` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_text_eq, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a file within it
+/// # fs::write("hello_world_file.txt", "hello, world").unwrap();
+/// #
+/// assert_file_text_eq!("hello_world_file.txt", "hello, world");
+///
+/// assert_file_text_eq!("hello_world_file.txt", "hello, world", max_len = 1024);
+///
+/// assert_file_text_eq!("hello_world_file.txt", "hello! world", negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_file_text_eq {
+    ($path:expr, $expected:expr, max_len = $max_len:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let config = $crate::config_from_keyword_arguments!($($keys = $values),*);
+
+        let is_updated = !config.negate
+            && $crate::utilities::update_mode::is_update_mode_enabled()
+            && $crate::utilities::update_mode::update_expected_file(
+                &$path,
+                ::std::convert::AsRef::<str>::as_ref(&$expected),
+            );
+
+        if !is_updated {
+            let file_text = $crate::assertions::filesystem::read_file_text(&$path, $max_len);
+
+            $crate::assert_custom!(
+                "file text == expected",
+                $crate::assertions::filesystem::assert_file_text_eq_impl(&file_text, &$expected),
+                |panic_message_builder| {
+                    panic_message_builder
+                        .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                        .with_argument_formatted("file text", "--", $crate::assertions::filesystem::format_file_text_excerpt(&file_text))?
+                        .with_argument("expected", stringify!($expected), &::std::convert::AsRef::<str>::as_ref(&$expected))
+                },
+                config = config
+            );
+        }
+    }};
 
-    #[test]
-    #[should_panic(expected = "path exists")]
-    fn assert_path_exists_failing_bad_nest() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
+    ($path:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_file_text_eq!(
+            $path,
+            $expected,
+            max_len = $crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN
+            $(, $keys = $values)*
+        )
+    };
+}
 
-        assert_path_exists!("some_file/bad_nesting");
-    }
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn assert_file_text_matches_impl(file_text: impl AsRef<str>, pattern: impl AsRef<str>) -> bool {
+    assert_str_matches_impl(file_text, pattern)
+}
 
-    #[test]
-    fn assert_path_is_file_passing() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
+/// The result of scanning a file line by line for a regular expression.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct FileLineScan {
+    /// Whether or not any line matched the pattern.
+    pub is_match: bool,
+
+    /// The number of lines read before a line matched or the end of the file was reached.
+    pub line_count: usize,
+}
 
-        assert_path_is_file!("some_file");
-    }
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn scan_file_lines_for_pattern(
+    path: impl AsRef<Path>,
+    pattern: impl AsRef<str>,
+) -> FileLineScan {
+    // Compile the pattern before touching the file
+    let pattern = match Regex::new(pattern.as_ref()) {
+        Ok(pattern_value) => pattern_value,
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "invalid regex pattern",
+            Location::caller(),
+            &error,
+        )
+        .expect("unable to build panic message for invalid regex pattern")
+        .panic(),
+    };
 
-    #[test]
-    fn assert_path_is_file_passing_symlink_to_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
-        symlink("some_file", "some_symlink").unwrap();
+    // Make sure that path points to a file that exists
+    ensure_is_file(&path);
 
-        assert_path_is_file!("some_symlink");
-    }
+    // Open the file
+    let file = unwrap_file_read(&path, File::open(path.as_ref()));
 
-    #[test]
-    #[should_panic(expected = "path is file")]
-    fn assert_path_is_file_failing_symlink_to_dir() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::create_dir_all("some_dir").unwrap();
-        symlink("some_dir", "some_symlink").unwrap();
+    // Read one line at a time so that only the current line is held in memory
+    let mut line_count: usize = 0;
 
-        assert_path_is_file!("some_symlink");
-    }
+    for line in BufReader::new(file).lines() {
+        let line = unwrap_file_read(&path, line);
 
-    #[test]
-    #[should_panic(expected = "path is file")]
-    fn assert_path_is_file_failing_directory() {
-        let temp_dir = tempdir().unwrap();
+        line_count = line_count.saturating_add(1);
 
-        assert_path_is_file!(temp_dir.path());
+        if pattern.is_match(&line) {
+            return FileLineScan {
+                is_match: true,
+                line_count,
+            };
+        }
     }
 
-    #[test]
-    #[should_panic(expected = "path is file")]
-    fn assert_path_is_file_failing_bad_name() {
-        assert_path_is_file!("a_file_that_does_not_exist");
+    FileLineScan {
+        is_match: false,
+        line_count,
     }
+}
 
-    #[test]
-    #[should_panic(expected = "path is file")]
-    fn assert_path_is_file_failing_bad_nest() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
-        fs::File::create("some_file").unwrap();
+/// Asserts that the text of a file matches a regular expression.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read.
+/// * `pattern` - The regular expression pattern that the file text is expected to match.
+/// * `max_len` - Optional maximum expected size of the file in bytes. Defaults to
+///               [`DEFAULT_MAX_FILE_TEXT_LEN`](crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN).
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `max_len` must come before any other keyword arguments.
+///
+/// Pass `streaming = true` instead of `max_len` to scan the file one line at a time rather than
+/// reading it into memory all at once. The assertion passes if any line matches the pattern, so
+/// patterns can't match across lines. There is no size limit in this mode, and the panic message
+/// includes the number of lines that were scanned instead of an excerpt of the file text.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_text_matches, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a file within it
+/// # fs::write("hello_world_file.txt", "hello, world").unwrap();
+/// #
+/// assert_file_text_matches!("hello_world_file.txt", "^hello, [a-z]+$");
+///
+/// assert_file_text_matches!("hello_world_file.txt", "[0-9]+", negate = true);
+///
+/// assert_file_text_matches!("hello_world_file.txt", "^hello", streaming = true);
+/// ```
+#[cfg(feature = "regex")]
+#[macro_export]
+macro_rules! assert_file_text_matches {
+    ($path:expr, $pattern:expr, streaming = true $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let scan = $crate::assertions::filesystem::scan_file_lines_for_pattern(&$path, &$pattern);
 
-        assert_path_is_file!("some_file/bad_nesting");
-    }
+        $crate::assert_custom!(
+            "a line of file text matches pattern",
+            scan.is_match,
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("lines scanned", "--", &scan.line_count)?
+                    .with_argument("pattern", stringify!($pattern), &::std::convert::AsRef::<str>::as_ref(&$pattern))
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($path:expr, $pattern:expr, streaming = false $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_file_text_matches!($path, $pattern $(, $keys = $values)*)
+    };
+
+    ($path:expr, $pattern:expr, max_len = $max_len:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let file_text = $crate::assertions::filesystem::read_file_text(&$path, $max_len);
+
+        $crate::assert_custom!(
+            "file text matches pattern",
+            $crate::assertions::filesystem::assert_file_text_matches_impl(&file_text, &$pattern),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument_formatted("file text", "--", $crate::assertions::filesystem::format_file_text_excerpt(&file_text))?
+                    .with_argument("pattern", stringify!($pattern), &::std::convert::AsRef::<str>::as_ref(&$pattern))
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($path:expr, $pattern:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_file_text_matches!(
+            $path,
+            $pattern,
+            max_len = $crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn read_file_bytes(path: impl AsRef<Path>, max_len: u64) -> Vec<u8> {
+    // Make sure that path points to a file that exists
+    ensure_is_file(&path);
+
+    // Open the file
+    let file = unwrap_file_read(&path, File::open(path.as_ref()));
+
+    // Ensure that the file length is within limits
+    ensure_file_len_within_limit(&path, &file, max_len);
+
+    // Create a buffered reader for the file
+    let mut buf_reader = BufReader::new(file);
+
+    // Create the buffer into which to read the file
+    let mut buffer = Vec::new();
+
+    // Read the file
+    unwrap_file_read(&path, buf_reader.read_to_end(&mut buffer));
+
+    buffer
+}
+
+/// The result of writing a file and then reading it back for [`assert_write_then_read_eq`].
+#[doc(hidden)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct WriteThenRead<ValueType, ErrorType> {
+    /// The value returned by the writer.
+    pub write_result: Result<ValueType, ErrorType>,
+
+    /// The contents of the file after writing, or `None` if the writer returned an error.
+    pub read_back: Option<Vec<u8>>,
+}
+
+impl<ValueType, ErrorType> WriteThenRead<ValueType, ErrorType> {
+    /// Checks whether or not the write succeeded and the file contents equal the expected bytes.
+    #[must_use]
+    pub fn is_match(&self, expected: impl AsRef<[u8]>) -> bool {
+        self.write_result.is_ok()
+            && self
+                .read_back
+                .as_ref()
+                .map_or(false, |read_back| read_back.as_slice() == expected.as_ref())
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[track_caller]
+pub fn write_then_read<
+    ValueType,
+    ErrorType,
+    WriterType: FnOnce(&Path) -> Result<ValueType, ErrorType>,
+>(
+    path: impl AsRef<Path>,
+    max_len: u64,
+    writer: WriterType,
+) -> WriteThenRead<ValueType, ErrorType> {
+    let write_result = writer(path.as_ref());
+
+    // There is nothing meaningful to read back if the writer failed
+    let read_back = write_result
+        .is_ok()
+        .then(|| read_file_bytes(&path, max_len));
+
+    WriteThenRead {
+        write_result,
+        read_back,
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_write_then_read_panic_message<ValueType: Debug, ErrorType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    expected_description: &str,
+    expected: impl AsRef<[u8]>,
+    write_then_read: &WriteThenRead<ValueType, ErrorType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let expected_text = String::from_utf8_lossy(expected.as_ref());
+
+    let panic_message_builder =
+        panic_message_builder.with_argument("write result", "--", &write_then_read.write_result)?;
+
+    let Some(read_back) = &write_then_read.read_back else {
+        return panic_message_builder.with_argument_formatted(
+            "expected",
+            expected_description,
+            format_file_text_excerpt(&expected_text),
+        );
+    };
+
+    let read_back_text = String::from_utf8_lossy(read_back);
+
+    let panic_message_builder = panic_message_builder
+        .with_argument_formatted("read back", "--", format_file_text_excerpt(&read_back_text))?
+        .with_argument_formatted(
+            "expected",
+            expected_description,
+            format_file_text_excerpt(&expected_text),
+        )?;
+
+    #[cfg(feature = "string-diff")]
+    let panic_message_builder = panic_message_builder.with_argument_formatted(
+        "diff",
+        "--",
+        crate::utilities::diff::format_diff(&expected_text, &read_back_text),
+    )?;
+
+    Ok(panic_message_builder)
+}
+
+/// Asserts that writing a file and then reading it back gives the expected contents.
+///
+/// The writer is called with the path first. If it returns an error, the assertion fails without
+/// reading the file. Otherwise the file is read back and compared to the expected bytes or text.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to write and read.
+/// * `expected` - The bytes or text that the file is expected to contain after writing.
+/// * `writer` - A closure that takes the path as an argument, writes the file, and returns a
+///              `Result`.
+/// * `max_len` - Optional maximum expected size of the file in bytes. Defaults to
+///               [`DEFAULT_MAX_FILE_TEXT_LEN`](crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN).
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `max_len` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_write_then_read_eq, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// assert_write_then_read_eq!("hello_world_file.txt", "hello, world", |path| {
+///     fs::write(path, "hello, world")
+/// });
+///
+/// assert_write_then_read_eq!("hello_world_file.txt", b"hello, world", |path| {
+///     fs::write(path, b"hello, world")
+/// }, max_len = 1024);
+/// ```
+#[macro_export]
+macro_rules! assert_write_then_read_eq {
+    ($path:expr, $expected:expr, $writer:expr, max_len = $max_len:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let write_then_read =
+            $crate::assertions::filesystem::write_then_read(&$path, $max_len, $writer);
+
+        $crate::assert_custom!(
+            "file read back == expected",
+            write_then_read.is_match(&$expected),
+            |panic_message_builder| {
+                $crate::assertions::filesystem::configure_write_then_read_panic_message(
+                    panic_message_builder
+                        .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?,
+                    stringify!($expected),
+                    &$expected,
+                    &write_then_read,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($path:expr, $expected:expr, $writer:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_write_then_read_eq!(
+            $path,
+            $expected,
+            $writer,
+            max_len = $crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN
+            $(, $keys = $values)*
+        )
+    };
+}
+
+/// The number of bytes on either side of the first difference to include in panic messages for
+/// [`assert_files_eq`].
+const FILE_DIFFERENCE_CONTEXT_LEN: u64 = 8;
+
+/// The first difference between two files for [`assert_files_eq`].
+#[doc(hidden)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct FileDifference {
+    /// The byte offset of the first difference.
+    pub offset: u64,
+
+    /// The byte offset at which the context windows start.
+    pub context_start: u64,
+
+    /// The bytes of the left-hand file around the difference.
+    pub lhs_context: Vec<u8>,
+
+    /// The bytes of the right-hand file around the difference.
+    pub rhs_context: Vec<u8>,
+}
+
+/// Helper method that opens a file for comparison, panicking if it is not a file, cannot be opened,
+/// or is larger than the limit.
+#[track_caller]
+fn open_file_for_comparison(path: &impl AsRef<Path>, max_len: u64) -> File {
+    // Make sure that path points to a file that exists
+    ensure_is_file(path);
+
+    // Open the file
+    let file = unwrap_file_read(path, File::open(path.as_ref()));
+
+    // Ensure that the file length is within limits
+    ensure_file_len_within_limit(path, &file, max_len);
+
+    file
+}
+
+/// Helper method that reads the context window of a file around an offset.
+#[track_caller]
+fn read_file_context(path: &impl AsRef<Path>, context_start: u64) -> Vec<u8> {
+    let mut file = unwrap_file_read(path, File::open(path.as_ref()));
+
+    unwrap_file_read(path, file.seek(SeekFrom::Start(context_start)));
+
+    let mut context = Vec::new();
+
+    unwrap_file_read(
+        path,
+        file.take(FILE_DIFFERENCE_CONTEXT_LEN.saturating_mul(2))
+            .read_to_end(&mut context),
+    );
+
+    context
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn find_first_file_difference(
+    lhs_path: impl AsRef<Path>,
+    rhs_path: impl AsRef<Path>,
+    max_len: u64,
+) -> Option<FileDifference> {
+    let mut lhs_reader = BufReader::new(open_file_for_comparison(&lhs_path, max_len));
+    let mut rhs_reader = BufReader::new(open_file_for_comparison(&rhs_path, max_len));
+
+    let mut offset: u64 = 0;
+
+    // Compare the files one buffer at a time so that neither is loaded into memory all at once
+    let offset = loop {
+        let lhs_buffer = unwrap_file_read(&lhs_path, lhs_reader.fill_buf());
+        let rhs_buffer = unwrap_file_read(&rhs_path, rhs_reader.fill_buf());
+
+        if lhs_buffer.is_empty() && rhs_buffer.is_empty() {
+            return None;
+        }
+
+        let common_len = lhs_buffer.len().min(rhs_buffer.len());
+
+        if let Some(index) = lhs_buffer
+            .iter()
+            .zip(rhs_buffer.iter())
+            .position(|(lhs_byte, rhs_byte)| lhs_byte != rhs_byte)
+        {
+            break offset.saturating_add(index.try_into().unwrap_or(u64::MAX));
+        }
+
+        // If one file has ended but the other has not, the difference is where the shorter one
+        // ends
+        if common_len == 0 {
+            break offset;
+        }
+
+        lhs_reader.consume(common_len);
+        rhs_reader.consume(common_len);
+
+        offset = offset.saturating_add(common_len.try_into().unwrap_or(u64::MAX));
+    };
+
+    let context_start = offset.saturating_sub(FILE_DIFFERENCE_CONTEXT_LEN);
+
+    Some(FileDifference {
+        offset,
+        context_start,
+        lhs_context: read_file_context(&lhs_path, context_start),
+        rhs_context: read_file_context(&rhs_path, context_start),
+    })
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn format_file_context(context_start: u64, context: &[u8]) -> String {
+    let hex = context
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let text = context
+        .iter()
+        .map(|byte| {
+            if byte.is_ascii_graphic() || *byte == b' ' {
+                char::from(*byte)
+            } else {
+                '.'
+            }
+        })
+        .collect::<String>();
+
+    format!("{context_start:08x}: {hex}  |{text}|")
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_files_eq_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    file_difference: &Option<FileDifference>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let Some(file_difference) = file_difference else {
+        return Ok(panic_message_builder);
+    };
+
+    panic_message_builder
+        .with_argument("first difference at byte", "--", &file_difference.offset)?
+        .with_argument_formatted(
+            "lhs context",
+            "--",
+            format_file_context(file_difference.context_start, &file_difference.lhs_context),
+        )?
+        .with_argument_formatted(
+            "rhs context",
+            "--",
+            format_file_context(file_difference.context_start, &file_difference.rhs_context),
+        )
+}
+
+/// Asserts that the contents of two files are equal.
+///
+/// The files are compared a buffer at a time instead of being read into memory all at once. If they
+/// differ, the panic message includes the byte offset of the first difference along with a few bytes
+/// of each file around it, in hex and as text.
+///
+/// If the `UPDATE` environment variable is set to `1`, `rhs` is treated as the expected file and is
+/// rewritten with the contents of `lhs` instead of failing. See
+/// [`update_mode`](crate::utilities::update_mode) for more information.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The path of the file on the left-hand side.
+/// * `rhs` - The path of the file on the right-hand side.
+/// * `max_len` - Optional maximum expected size of each file in bytes. Defaults to
+///               [`DEFAULT_MAX_FILE_TEXT_LEN`](crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN).
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `max_len` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_files_eq, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create some files within it
+/// # fs::write("expected.txt", "hello, world").unwrap();
+/// # fs::write("actual.txt", "hello, world").unwrap();
+/// # fs::write("other.txt", "hello! world").unwrap();
+/// #
+/// assert_files_eq!("actual.txt", "expected.txt");
+///
+/// assert_files_eq!("actual.txt", "expected.txt", max_len = 1024);
+///
+/// assert_files_eq!("actual.txt", "other.txt", negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_files_eq {
+    ($lhs:expr, $rhs:expr, max_len = $max_len:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let config = $crate::config_from_keyword_arguments!($($keys = $values),*);
+
+        let is_updated = !config.negate
+            && $crate::utilities::update_mode::is_update_mode_enabled()
+            && $crate::utilities::update_mode::update_expected_file(
+                &$rhs,
+                $crate::assertions::filesystem::read_file_bytes(&$lhs, $max_len),
+            );
+
+        if !is_updated {
+            let file_difference =
+                $crate::assertions::filesystem::find_first_file_difference(&$lhs, &$rhs, $max_len);
+
+            $crate::assert_custom!(
+                "lhs file == rhs file",
+                file_difference.is_none(),
+                |panic_message_builder| {
+                    $crate::assertions::filesystem::configure_files_eq_panic_message(
+                        panic_message_builder
+                            .with_argument("lhs", stringify!($lhs), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$lhs))?
+                            .with_argument("rhs", stringify!($rhs), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$rhs))?,
+                        &file_difference,
+                    )
+                },
+                config = config
+            );
+        }
+    }};
+
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_files_eq!(
+            $lhs,
+            $rhs,
+            max_len = $crate::assertions::filesystem::DEFAULT_MAX_FILE_TEXT_LEN
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn read_file_len(path: impl AsRef<Path>) -> u64 {
+    // Make sure that path points to a file that exists
+    ensure_is_file(&path);
+
+    read_metadata(path).len()
+}
+
+/// Asserts that the size of a file in bytes is equal to a value.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to check.
+/// * `expected` - The size in bytes that the file is expected to have.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_size_eq, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a file within it
+/// # fs::write("hello_world_file.txt", "hello, world").unwrap();
+/// #
+/// assert_file_size_eq!("hello_world_file.txt", 12);
+/// ```
+#[macro_export]
+macro_rules! assert_file_size_eq {
+    ($path:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let file_size: u64 = $crate::assertions::filesystem::read_file_len(&$path);
+
+        $crate::assert_custom!(
+            "file size == expected",
+            file_size == $expected,
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("file size", "--", &file_size)?
+                    .with_argument("expected", stringify!($expected), &$expected)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that the size of a file in bytes is less than or equal to a value.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to check.
+/// * `max` - The maximum size in bytes that the file is expected to have.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_file_size_le, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a file within it
+/// # fs::write("hello_world_file.txt", "hello, world").unwrap();
+/// #
+/// assert_file_size_le!("hello_world_file.txt", 1024);
+/// ```
+#[macro_export]
+macro_rules! assert_file_size_le {
+    ($path:expr, $max:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let file_size: u64 = $crate::assertions::filesystem::read_file_len(&$path);
+
+        $crate::assert_custom!(
+            "file size <= max",
+            file_size <= $max,
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("file size", "--", &file_size)?
+                    .with_argument("max", stringify!($max), &$max)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+// Expect allowed because any failure to create the panic message builder is hard coded in, not
+// dependent on how this function is called.
+#[allow(clippy::expect_used)]
+#[track_caller]
+pub fn read_time_since_modified(path: impl AsRef<Path>) -> Duration {
+    let modified = match read_metadata(&path).modified() {
+        Ok(modified) => modified,
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "modification time is not supported on this platform",
+            Location::caller(),
+            &error,
+        )
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("path", "--", &path.as_ref())
+        })
+        .expect("error while creating panic message builder")
+        .panic(),
+    };
+
+    // A modification time in the future, like from clock skew on a network filesystem, is
+    // considered to be just now
+    SystemTime::now()
+        .duration_since(modified)
+        .unwrap_or_default()
+}
+
+/// Asserts that a path was modified within a duration of now.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+/// * `duration` - The [`Duration`](std::time::Duration) within which the path is expected to have
+///                been modified.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use std::{fs, time::Duration};
+/// # use test_ur_code_xd::{assert_file_modified_within, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// # // Create a file within it
+/// # fs::write("hello_world_file.txt", "hello, world").unwrap();
+/// #
+/// assert_file_modified_within!("hello_world_file.txt", Duration::from_secs(60));
+/// ```
+#[macro_export]
+macro_rules! assert_file_modified_within {
+    ($path:expr, $duration:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let time_since_modified =
+            $crate::assertions::filesystem::read_time_since_modified(&$path);
+
+        $crate::assert_custom!(
+            "time since modified <= duration",
+            time_since_modified <= $duration,
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("path", stringify!($path), &::std::convert::AsRef::<::std::path::Path>::as_ref(&$path))?
+                    .with_argument("time since modified", "--", &time_since_modified)?
+                    .with_argument("duration", stringify!($duration), &$duration)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// The files that were created and removed in a directory while running an action for
+/// [`assert_creates_files`].
+#[doc(hidden)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct FileChanges {
+    /// The directory that was snapshotted, which is the current directory when the action starts.
+    pub dir: PathBuf,
+
+    /// The paths of files that exist after the action but not before, relative to `dir`.
+    pub created: BTreeSet<PathBuf>,
+
+    /// The paths of files that existed before the action but not after, relative to `dir`.
+    pub removed: BTreeSet<PathBuf>,
+}
+
+impl FileChanges {
+    /// Normalizes a path given to the assertion so that it can be compared with the snapshot.
+    ///
+    /// Absolute paths within the directory are made relative, and `.` components are dropped.
+    #[must_use]
+    fn normalize(&self, path: &Path) -> PathBuf {
+        path.strip_prefix(&self.dir)
+            .unwrap_or(path)
+            .components()
+            .filter(|component| component != &Component::CurDir)
+            .collect()
+    }
+
+    /// Gets the paths that were expected to be created but weren't.
+    #[must_use]
+    pub fn missing(&self, creates: &[PathBuf]) -> Vec<PathBuf> {
+        creates
+            .iter()
+            .map(|path| self.normalize(path))
+            .filter(|path| !self.created.contains(path))
+            .collect()
+    }
+
+    /// Gets the paths that were expected not to be created but were.
+    #[must_use]
+    pub fn unexpected(&self, does_not_create: &[PathBuf]) -> Vec<PathBuf> {
+        does_not_create
+            .iter()
+            .map(|path| self.normalize(path))
+            .filter(|path| self.created.contains(path))
+            .collect()
+    }
+}
+
+/// Helper method that adds the paths of all files under a directory to a set, relative to a root.
+///
+/// Symlinks are recorded as files and are not followed.
+#[track_caller]
+fn collect_dir_files(root: &Path, dir: &Path, files: &mut BTreeSet<PathBuf>) {
+    for entry in unwrap_file_read(&dir, fs::read_dir(dir)) {
+        let entry = unwrap_file_read(&dir, entry);
+        let path = entry.path();
+
+        if unwrap_file_read(&path, entry.file_type()).is_dir() {
+            collect_dir_files(root, &path, files);
+        } else {
+            files.insert(path.strip_prefix(root).unwrap_or(&path).to_owned());
+        }
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn snapshot_dir_files(dir: impl AsRef<Path>) -> BTreeSet<PathBuf> {
+    let mut files = BTreeSet::new();
+
+    collect_dir_files(dir.as_ref(), dir.as_ref(), &mut files);
+
+    files
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+// Expect allowed because any failure to create the panic message builder is hard coded in, not
+// dependent on how this function is called.
+#[allow(clippy::expect_used)]
+#[track_caller]
+pub fn record_file_changes<ActionType: FnOnce()>(action: ActionType) -> FileChanges {
+    let dir = match env::current_dir() {
+        Ok(dir) => dir,
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "error getting current directory",
+            Location::caller(),
+            &error,
+        )
+        .expect("error while creating panic message builder")
+        .panic(),
+    };
+
+    let before = snapshot_dir_files(&dir);
+
+    action();
+
+    let after = snapshot_dir_files(&dir);
+
+    FileChanges {
+        created: after.difference(&before).cloned().collect(),
+        removed: before.difference(&after).cloned().collect(),
+        dir,
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_creates_files_impl(
+    file_changes: &FileChanges,
+    creates: &[PathBuf],
+    does_not_create: &[PathBuf],
+) -> bool {
+    file_changes.missing(creates).is_empty() && file_changes.unexpected(does_not_create).is_empty()
+}
+
+/// Asserts that running an action creates some files in the current directory and not others.
+///
+/// The current directory is snapshotted recursively before and after the action is called, and the
+/// files that appear are compared to the expected paths. Files that the action creates that aren't
+/// listed in either argument are allowed. The panic message lists every file that was created or
+/// removed.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `action` - A closure that takes no arguments and returns nothing.
+/// * `creates` - Optional list of paths that are expected to be created, relative to the current
+///               directory.
+/// * `does_not_create` - Optional list of paths that are expected not to be created, relative to
+///                       the current directory.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `creates` and `does_not_create` must come in that order before any other keyword
+/// arguments.
+///
+/// # Example
+///
+/// ```
+/// # use std::fs;
+/// # use test_ur_code_xd::{assert_creates_files, utilities::sandbox::TestDir};
+/// #
+/// # // Create a test directory and "cd" into it
+/// # let test_dir = TestDir::new().unwrap();
+/// # let _current_dir = test_dir.enter().unwrap();
+/// #
+/// assert_creates_files!(
+///     || {
+///         fs::create_dir("out").unwrap();
+///         fs::write("out/a.txt", "a").unwrap();
+///         fs::write("out/b.txt", "b").unwrap();
+///     },
+///     creates = ["out/a.txt", "out/b.txt"],
+///     does_not_create = ["out/c.txt"]
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_creates_files {
+    (
+        $action:expr,
+        creates = [$($creates:expr),* $(,)?],
+        does_not_create = [$($does_not_create:expr),* $(,)?]
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let file_changes = $crate::assertions::filesystem::record_file_changes($action);
+        let creates: ::std::vec::Vec<::std::path::PathBuf> =
+            vec![$(::std::convert::AsRef::<::std::path::Path>::as_ref(&$creates).to_owned()),*];
+        let does_not_create: ::std::vec::Vec<::std::path::PathBuf> =
+            vec![$(::std::convert::AsRef::<::std::path::Path>::as_ref(&$does_not_create).to_owned()),*];
+
+        $crate::assert_custom!(
+            "action creates files",
+            $crate::assertions::filesystem::assert_creates_files_impl(
+                &file_changes,
+                &creates,
+                &does_not_create,
+            ),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("not created", "--", &file_changes.missing(&creates))?
+                    .with_argument("unexpectedly created", "--", &file_changes.unexpected(&does_not_create))?
+                    .with_argument("created files", "--", &file_changes.created)?
+                    .with_argument("removed files", "--", &file_changes.removed)
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($action:expr, creates = [$($creates:expr),* $(,)?] $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_creates_files!(
+            $action,
+            creates = [$($creates),*],
+            does_not_create = []
+            $(, $keys = $values)*
+        )
+    };
+
+    ($action:expr, does_not_create = [$($does_not_create:expr),* $(,)?] $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_creates_files!(
+            $action,
+            creates = [],
+            does_not_create = [$($does_not_create),*]
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Unwrap is used to reduce the length of the test code.
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod tests {
+    use super::{
+        find_first_file_difference, format_file_context, format_file_text_excerpt,
+        record_file_changes,
+    };
+    use crate::{
+        assert, assert_eq,
+        utilities::{
+            assertion_failure::capture_assertion_failure, sandbox::TestDir,
+            update_mode::UPDATE_ENVIRONMENT_VARIABLE,
+        },
+    };
+    use std::{
+        collections::BTreeSet,
+        env, fs,
+        io::Write,
+        path::{Path, PathBuf},
+        process::{Command, Output},
+        thread,
+        time::Duration,
+    };
+
+    #[cfg(feature = "regex")]
+    use super::{scan_file_lines_for_pattern, FileLineScan};
+
+    // If on Unix, use the Unix flavor of symlink
+    #[cfg(target_family = "unix")]
+    use std::os::unix::fs::symlink;
+
+    // If on Windows, use the Windows flavor of symlink, but alias it to the same name so it is
+    // opaque.
+    #[cfg(target_family = "windows")]
+    use std::os::windows::fs::symlink_file as symlink;
+
+    /// The environment variable that tells the test process that it is the child process in the
+    /// update mode tests, set to the directory in which the test's files are.
+    const UPDATE_CHILD_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_UPDATE_CHILD";
+
+    /// Helper function to run a single test in a child process with update mode enabled.
+    ///
+    /// Update mode is read from the environment, so setting it in this process would affect every
+    /// other test running at the same time.
+    fn run_test_with_update_mode(test_name: &str, dir: &Path) -> Output {
+        Command::new(env::current_exe().unwrap())
+            .args([test_name, "--exact", "--nocapture"])
+            .env(UPDATE_CHILD_ENVIRONMENT_VARIABLE, dir)
+            .env(UPDATE_ENVIRONMENT_VARIABLE, "1")
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn assert_path_exists_passing_file() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+
+        assert_path_exists!("some_file");
+    }
+
+    #[test]
+    fn assert_path_exists_passing_symlink() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+        symlink("some_file", "some_symlink").unwrap();
+
+        assert_path_exists!("some_symlink");
+    }
+
+    #[test]
+    fn assert_path_exists_passing_directory() {
+        let test_dir = TestDir::new().unwrap();
+
+        assert_path_exists!(test_dir.path());
+    }
+
+    #[test]
+    #[should_panic(expected = "path exists")]
+    fn assert_path_exists_failing_bad_name() {
+        assert_path_exists!("a_file_that_does_not_exist");
+    }
+
+    #[test]
+    #[should_panic(expected = "path exists")]
+    fn assert_path_exists_failing_bad_nest() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+
+        assert_path_exists!("some_file/bad_nesting");
+    }
+
+    #[test]
+    fn assert_path_is_file_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+
+        assert_path_is_file!("some_file");
+    }
+
+    #[test]
+    fn assert_path_is_file_passing_symlink_to_file() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+        symlink("some_file", "some_symlink").unwrap();
+
+        assert_path_is_file!("some_symlink");
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_path_is_file_failing_symlink_to_dir() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::create_dir_all("some_dir").unwrap();
+        symlink("some_dir", "some_symlink").unwrap();
+
+        assert_path_is_file!("some_symlink");
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_path_is_file_failing_directory() {
+        let test_dir = TestDir::new().unwrap();
+
+        assert_path_is_file!(test_dir.path());
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_path_is_file_failing_bad_name() {
+        assert_path_is_file!("a_file_that_does_not_exist");
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_path_is_file_failing_bad_nest() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::File::create("some_file").unwrap();
+
+        assert_path_is_file!("some_file/bad_nesting");
+    }
 
     #[test]
     fn assert_path_is_symlink_passing_symlink_to_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
         symlink("some_file", "some_symlink").unwrap();
 
@@ -770,8 +2082,8 @@ mod tests {
 
     #[test]
     fn assert_path_is_symlink_passing_symlink_to_dir() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("some_dir").unwrap();
         symlink("some_dir", "some_symlink").unwrap();
 
@@ -781,8 +2093,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is symlink")]
     fn assert_path_is_symlink_failing_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_symlink!("some_file");
@@ -791,9 +2103,9 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is symlink")]
     fn assert_path_is_symlink_failing_directory() {
-        let temp_dir = tempdir().unwrap();
+        let test_dir = TestDir::new().unwrap();
 
-        assert_path_is_symlink!(temp_dir.path());
+        assert_path_is_symlink!(test_dir.path());
     }
 
     #[test]
@@ -805,8 +2117,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is symlink")]
     fn assert_path_is_symlink_failing_bad_nest() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_symlink!("some_file/bad_nesting");
@@ -814,16 +2126,16 @@ mod tests {
 
     #[test]
     fn assert_path_is_dir_passing() {
-        let temp_dir = tempdir().unwrap();
+        let test_dir = TestDir::new().unwrap();
 
-        assert_path_is_dir!(temp_dir.path());
+        assert_path_is_dir!(test_dir.path());
     }
 
     #[test]
     #[should_panic(expected = "path is dir")]
     fn assert_path_is_dir_failing_symlink_to_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
         symlink("some_file", "some_symlink").unwrap();
 
@@ -832,8 +2144,8 @@ mod tests {
 
     #[test]
     fn assert_path_is_dir_passing_symlink_to_dir() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("some_dir").unwrap();
         symlink("some_dir", "some_symlink").unwrap();
 
@@ -843,8 +2155,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is dir")]
     fn assert_path_is_dir_failing_file() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_dir!("some_file");
@@ -859,8 +2171,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is dir")]
     fn assert_path_is_dir_failing_bad_nest() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_dir!("some_file/bad_nesting");
@@ -868,8 +2180,8 @@ mod tests {
 
     #[test]
     fn assert_path_is_relative_passing() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_relative!("some_file");
@@ -898,8 +2210,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is absolute")]
     fn assert_path_is_absolute_failing() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::File::create("some_file").unwrap();
 
         assert_path_is_absolute!("some_file");
@@ -927,8 +2239,8 @@ mod tests {
 
     #[test]
     fn assert_path_starts_with_passing_flat() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -937,8 +2249,8 @@ mod tests {
 
     #[test]
     fn assert_path_starts_with_passing_nested() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -947,8 +2259,8 @@ mod tests {
 
     #[test]
     fn assert_path_starts_with_passing_equal() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -957,8 +2269,8 @@ mod tests {
 
     #[test]
     fn assert_path_starts_with_passing_empty() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -968,8 +2280,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path starts with base")]
     fn assert_path_starts_with_failing_flat() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -979,8 +2291,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path starts with base")]
     fn assert_path_starts_with_failing_nested() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -990,8 +2302,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path starts with base")]
     fn assert_path_starts_with_failing_full() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1001,8 +2313,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path starts with base")]
     fn assert_path_starts_with_failing_wrong_prefix() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1011,8 +2323,8 @@ mod tests {
 
     #[test]
     fn assert_path_ends_with_passing_flat() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1021,8 +2333,8 @@ mod tests {
 
     #[test]
     fn assert_path_ends_with_passing_nested() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1031,8 +2343,8 @@ mod tests {
 
     #[test]
     fn assert_path_ends_with_passing_equal() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1041,8 +2353,8 @@ mod tests {
 
     #[test]
     fn assert_path_ends_with_passing_empty() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1052,8 +2364,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path ends with child")]
     fn assert_path_ends_with_failing_flat() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1063,8 +2375,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path ends with child")]
     fn assert_path_ends_with_failing_nested() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1074,8 +2386,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path ends with child")]
     fn assert_path_ends_with_failing_full() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1085,8 +2397,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path ends with child")]
     fn assert_path_ends_with_failing_wrong_suffix() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         fs::create_dir_all("a/b").unwrap();
         fs::File::create("a/b/c").unwrap();
 
@@ -1095,8 +2407,8 @@ mod tests {
 
     #[test]
     fn assert_file_text_passing() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1112,8 +2424,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "lhs == rhs")]
     fn assert_file_text_failing_assertion() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1129,8 +2441,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is file")]
     fn assert_file_text_failing_bad_path() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1145,8 +2457,8 @@ mod tests {
 
     #[test]
     fn assert_file_text_raw_passing() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1162,8 +2474,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "lhs == rhs")]
     fn assert_file_text_raw_failing_assertion() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1179,8 +2491,8 @@ mod tests {
     #[test]
     #[should_panic(expected = "path is file")]
     fn assert_file_text_raw_failing_bad_path() {
-        let temp_dir = tempdir().unwrap();
-        env::set_current_dir(temp_dir.path()).unwrap();
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
         let mut file = fs::File::create("some_file").unwrap();
         file.write_all(b"hello, world").unwrap();
 
@@ -1192,4 +2504,643 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn assert_file_text_eq_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_eq!("some_file", "hello, world");
+    }
+
+    #[test]
+    fn assert_file_text_eq_passing_max_len() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_eq!("some_file", "hello, world", max_len = 1024);
+    }
+
+    #[test]
+    fn assert_file_text_eq_passing_negate() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_eq!("some_file", "asdf", negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "file text == expected")]
+    fn assert_file_text_eq_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_eq!("some_file", "asdf");
+    }
+
+    #[test]
+    #[should_panic(expected = "file is larger than limit")]
+    fn assert_file_text_eq_failing_max_len() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_eq!("some_file", "hello, world", max_len = 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_file_text_eq_failing_bad_path() {
+        assert_file_text_eq!("a_file_that_does_not_exist", "hello, world");
+    }
+
+    #[test]
+    fn assert_file_text_eq_update_mode() {
+        if let Some(dir) = env::var_os(UPDATE_CHILD_ENVIRONMENT_VARIABLE) {
+            let dir = PathBuf::from(dir);
+
+            assert_file_text_eq!(dir.join("some_file"), "hello, world");
+            assert_file_text_eq!(dir.join("golden").join("new_file"), "hello, world");
+
+            return;
+        }
+
+        let test_dir = TestDir::new().unwrap();
+        test_dir.write_file("some_file", "asdf").unwrap();
+
+        let output = run_test_with_update_mode(
+            "assertions::filesystem::tests::assert_file_text_eq_update_mode",
+            test_dir.path(),
+        );
+
+        assert!(output.status.success());
+        assert_eq!(
+            fs::read_to_string(test_dir.path_of("some_file")).unwrap(),
+            "hello, world"
+        );
+        assert_eq!(
+            fs::read_to_string(test_dir.path_of("golden/new_file")).unwrap(),
+            "hello, world"
+        );
+        assert!(String::from_utf8_lossy(&output.stderr).contains("2 files updated so far"));
+    }
+
+    #[test]
+    fn assert_file_text_eq_update_mode_negate() {
+        if let Some(dir) = env::var_os(UPDATE_CHILD_ENVIRONMENT_VARIABLE) {
+            let path = PathBuf::from(dir).join("some_file");
+
+            assert_file_text_eq!(path, "hello, world", negate = true);
+
+            return;
+        }
+
+        let test_dir = TestDir::new().unwrap();
+        test_dir.write_file("some_file", "hello, world").unwrap();
+
+        let output = run_test_with_update_mode(
+            "assertions::filesystem::tests::assert_file_text_eq_update_mode_negate",
+            test_dir.path(),
+        );
+
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("file text == expected"));
+    }
+
+    /// Helper assertion that reports failures at its caller.
+    #[track_caller]
+    fn assert_file_is_hello(path: &str) {
+        assert_file_text_eq!(path, "hello");
+    }
+
+    #[test]
+    fn assert_file_text_eq_bad_path_location_is_caller_of_track_caller_helper() {
+        let expected_line = line!() + 3;
+
+        let failure = capture_assertion_failure(|| {
+            assert_file_is_hello("a_file_that_does_not_exist");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "path is file");
+        assert_eq!(failure.location.file(), file!());
+        assert_eq!(failure.location.line(), expected_line);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_file_text_matches_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_matches!("some_file", "^hello, [a-z]+$");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_file_text_matches_passing_negate() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_matches!("some_file", "[0-9]+", negate = true);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[should_panic(expected = "file text matches pattern")]
+    fn assert_file_text_matches_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_matches!("some_file", "[0-9]+");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_file_text_matches_streaming_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "first\nhello, world\nlast\n").unwrap();
+
+        assert_file_text_matches!("some_file", "^hello, [a-z]+$", streaming = true);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_file_text_matches_streaming_passing_negate() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "first\nhello, world\nlast\n").unwrap();
+
+        assert_file_text_matches!("some_file", "[0-9]+", streaming = true, negate = true);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_file_text_matches_streaming_false() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "hello, world").unwrap();
+
+        assert_file_text_matches!("some_file", "^hello", streaming = false);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[should_panic(expected = "a line of file text matches pattern")]
+    fn assert_file_text_matches_streaming_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "first\nhello, world\nlast\n").unwrap();
+
+        assert_file_text_matches!("some_file", "[0-9]+", streaming = true);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn scan_file_lines_for_pattern_line_count() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("some_file", "first\nhello, world\nlast\n").unwrap();
+
+        assert_eq!(
+            scan_file_lines_for_pattern("some_file", "hello"),
+            FileLineScan {
+                is_match: true,
+                line_count: 2
+            }
+        );
+
+        assert_eq!(
+            scan_file_lines_for_pattern("some_file", "[0-9]+"),
+            FileLineScan {
+                is_match: false,
+                line_count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn assert_write_then_read_eq_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_write_then_read_eq!("some_file", "hello, world", |path| {
+            fs::write(path, "hello, world")
+        });
+    }
+
+    #[test]
+    fn assert_write_then_read_eq_passing_bytes() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_write_then_read_eq!("some_file", b"\x00\xff", |path| fs::write(
+            path,
+            b"\x00\xff"
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "file read back == expected")]
+    fn assert_write_then_read_eq_failing_mismatch() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_write_then_read_eq!("some_file", "hello, world", |path| {
+            fs::write(path, "hello! world")
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "file read back == expected")]
+    fn assert_write_then_read_eq_failing_write_error() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_write_then_read_eq!("some_dir/some_file", "hello, world", |path| {
+            fs::write(path, "hello, world")
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "file is larger than limit")]
+    fn assert_write_then_read_eq_failing_max_len() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_write_then_read_eq!(
+            "some_file",
+            "hello, world",
+            |path| fs::write(path, "hello, world"),
+            max_len = 4
+        );
+    }
+
+    #[test]
+    fn assert_path_has_extension_passing() {
+        assert_path_has_extension!("a/b/c.txt", "txt");
+    }
+
+    #[test]
+    fn assert_path_has_extension_passing_multiple() {
+        assert_path_has_extension!("a/b/c.tar.gz", "gz");
+    }
+
+    #[test]
+    #[should_panic(expected = "path has extension")]
+    fn assert_path_has_extension_failing_wrong_extension() {
+        assert_path_has_extension!("a/b/c.txt", "md");
+    }
+
+    #[test]
+    #[should_panic(expected = "path has extension")]
+    fn assert_path_has_extension_failing_no_extension() {
+        assert_path_has_extension!("a/b/c", "txt");
+    }
+
+    #[test]
+    fn assert_path_is_readonly_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "").unwrap();
+        let mut permissions = fs::metadata(&path).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&path, permissions).unwrap();
+
+        assert_path_is_readonly!(path);
+    }
+
+    #[test]
+    #[should_panic(expected = "path is read-only")]
+    fn assert_path_is_readonly_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "").unwrap();
+
+        assert_path_is_readonly!(path);
+    }
+
+    #[test]
+    #[should_panic(expected = "error reading path metadata")]
+    fn assert_path_is_readonly_failing_bad_name() {
+        assert_path_is_readonly!("a_file_that_does_not_exist");
+    }
+
+    #[test]
+    fn assert_file_size_eq_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        assert_file_size_eq!(path, 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "file size == expected")]
+    fn assert_file_size_eq_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        assert_file_size_eq!(path, 13);
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_file_size_eq_failing_directory() {
+        let test_dir = TestDir::new().unwrap();
+
+        assert_file_size_eq!(test_dir.path(), 0);
+    }
+
+    #[test]
+    fn assert_file_size_le_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        assert_file_size_le!(path, 12);
+        assert_file_size_le!(path, 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "file size <= max")]
+    fn assert_file_size_le_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        assert_file_size_le!(path, 11);
+    }
+
+    #[test]
+    fn assert_file_modified_within_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        assert_file_modified_within!(path, Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic(expected = "time since modified <= duration")]
+    fn assert_file_modified_within_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.write_file("some_file", "hello, world").unwrap();
+
+        thread::sleep(Duration::from_millis(50));
+
+        assert_file_modified_within!(path, Duration::from_millis(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "error reading path metadata")]
+    fn assert_file_modified_within_failing_bad_name() {
+        assert_file_modified_within!("a_file_that_does_not_exist", Duration::from_secs(60));
+    }
+
+    #[test]
+    fn assert_creates_files_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_creates_files!(
+            || {
+                fs::create_dir("out").unwrap();
+                fs::write("out/a.txt", "a").unwrap();
+                fs::write("out/b.txt", "b").unwrap();
+            },
+            creates = ["out/a.txt", "./out/b.txt"],
+            does_not_create = ["out/c.txt"]
+        );
+    }
+
+    #[test]
+    fn assert_creates_files_passing_creates_only() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_creates_files!(
+            || fs::write("a.txt", "a").unwrap(),
+            creates = [test_dir.path_of("a.txt")]
+        );
+    }
+
+    #[test]
+    fn assert_creates_files_passing_does_not_create_only() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_creates_files!(
+            || fs::write("a.txt", "a").unwrap(),
+            does_not_create = ["b.txt"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "action creates files")]
+    fn assert_creates_files_failing_not_created() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_creates_files!(|| fs::write("a.txt", "a").unwrap(), creates = ["b.txt"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "action creates files")]
+    fn assert_creates_files_failing_already_exists() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("a.txt", "a").unwrap();
+
+        assert_creates_files!(|| fs::write("a.txt", "b").unwrap(), creates = ["a.txt"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "action creates files")]
+    fn assert_creates_files_failing_unexpected() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+
+        assert_creates_files!(
+            || fs::write("a.txt", "a").unwrap(),
+            does_not_create = ["a.txt"]
+        );
+    }
+
+    #[test]
+    fn record_file_changes_created_and_removed() {
+        let test_dir = TestDir::new().unwrap();
+        let _current_dir = test_dir.enter().unwrap();
+        fs::write("old.txt", "old").unwrap();
+
+        let file_changes = record_file_changes(|| {
+            fs::remove_file("old.txt").unwrap();
+            fs::create_dir_all("new/nested").unwrap();
+            fs::write("new/nested/new.txt", "new").unwrap();
+        });
+
+        assert_eq!(
+            file_changes.created,
+            BTreeSet::from([PathBuf::from("new/nested/new.txt")])
+        );
+        assert_eq!(
+            file_changes.removed,
+            BTreeSet::from([PathBuf::from("old.txt")])
+        );
+    }
+
+    #[test]
+    fn assert_files_eq_passing() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello, world").unwrap();
+        let rhs = test_dir.write_file("rhs", "hello, world").unwrap();
+
+        assert_files_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn assert_files_eq_passing_empty() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "").unwrap();
+        let rhs = test_dir.write_file("rhs", "").unwrap();
+
+        assert_files_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn assert_files_eq_passing_large() {
+        let test_dir = TestDir::new().unwrap();
+        let contents = "hello, world\n".repeat(10_000);
+        let lhs = test_dir.write_file("lhs", &contents).unwrap();
+        let rhs = test_dir.write_file("rhs", &contents).unwrap();
+
+        assert_files_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn assert_files_eq_passing_negate() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello, world").unwrap();
+        let rhs = test_dir.write_file("rhs", "hello! world").unwrap();
+
+        assert_files_eq!(lhs, rhs, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs file == rhs file")]
+    fn assert_files_eq_failing() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello, world").unwrap();
+        let rhs = test_dir.write_file("rhs", "hello! world").unwrap();
+
+        assert_files_eq!(lhs, rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "path is file")]
+    fn assert_files_eq_failing_bad_path() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello, world").unwrap();
+
+        assert_files_eq!(lhs, test_dir.path_of("rhs"));
+    }
+
+    #[test]
+    fn assert_files_eq_update_mode() {
+        if let Some(dir) = env::var_os(UPDATE_CHILD_ENVIRONMENT_VARIABLE) {
+            let dir = PathBuf::from(dir);
+
+            assert_files_eq!(dir.join("lhs"), dir.join("rhs"));
+
+            return;
+        }
+
+        let test_dir = TestDir::new().unwrap();
+        test_dir.write_file("lhs", "hello, world").unwrap();
+        test_dir.write_file("rhs", "hello! world").unwrap();
+
+        let output = run_test_with_update_mode(
+            "assertions::filesystem::tests::assert_files_eq_update_mode",
+            test_dir.path(),
+        );
+
+        assert!(output.status.success());
+        assert_eq!(
+            fs::read_to_string(test_dir.path_of("lhs")).unwrap(),
+            "hello, world"
+        );
+        assert_eq!(
+            fs::read_to_string(test_dir.path_of("rhs")).unwrap(),
+            "hello, world"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "file is larger than limit")]
+    fn assert_files_eq_failing_max_len() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello, world").unwrap();
+        let rhs = test_dir.write_file("rhs", "hello, world").unwrap();
+
+        assert_files_eq!(lhs, rhs, max_len = 4);
+    }
+
+    #[test]
+    fn find_first_file_difference_middle() {
+        let test_dir = TestDir::new().unwrap();
+        let contents = "abcdefghijklmnopqrstuvwxyz".repeat(1_000);
+        let lhs = test_dir.write_file("lhs", &contents).unwrap();
+        let mut rhs_contents = contents.clone().into_bytes();
+        *rhs_contents.get_mut(12_000).unwrap() = b'!';
+        let rhs = test_dir.write_file("rhs", rhs_contents).unwrap();
+
+        let file_difference = find_first_file_difference(lhs, rhs, u64::MAX).unwrap();
+
+        assert_eq!(file_difference.offset, 12_000);
+        assert_eq!(file_difference.context_start, 11_992);
+        assert_eq!(file_difference.lhs_context, b"ghijklmnopqrstuv");
+        assert_eq!(file_difference.rhs_context, b"ghijklmn!pqrstuv");
+    }
+
+    #[test]
+    fn find_first_file_difference_different_lengths() {
+        let test_dir = TestDir::new().unwrap();
+        let lhs = test_dir.write_file("lhs", "hello").unwrap();
+        let rhs = test_dir.write_file("rhs", "hello, world").unwrap();
+
+        let file_difference = find_first_file_difference(lhs, rhs, u64::MAX).unwrap();
+
+        assert_eq!(file_difference.offset, 5);
+        assert_eq!(file_difference.context_start, 0);
+        assert_eq!(file_difference.lhs_context, b"hello");
+        assert_eq!(file_difference.rhs_context, b"hello, world");
+    }
+
+    #[test]
+    fn format_file_context_hex_and_text() {
+        assert_eq!(
+            format_file_context(16, b"hi\n!"),
+            "00000010: 68 69 0a 21  |hi.!|"
+        );
+    }
+
+    #[test]
+    fn format_file_text_excerpt_short() {
+        assert_eq!(format_file_text_excerpt("a\nb\nc"), "a\nb\nc");
+    }
+
+    #[test]
+    fn format_file_text_excerpt_long() {
+        let text = (0..20)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            format_file_text_excerpt(&text),
+            "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n... (4 more lines)"
+        );
+    }
 }
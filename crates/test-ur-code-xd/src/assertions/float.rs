@@ -22,12 +22,93 @@
 //! The assertions in this module are based off of
 //! <a href="https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/">this excellent article</a>.
 
-use float_cmp::{approx_eq, Ulps};
-use num_traits::Float;
-use std::fmt::{Debug, Display};
+use float_cmp::Ulps;
+use num_traits::{Float, One};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    mem,
+    num::FpCategory,
+    ops::Neg,
+};
 
 use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
 
+/// The ULPs tolerance used by `f32` assertions when neither `ulps` nor `relative_epsilon` is given.
+///
+/// This matches the tolerance used by the Google Test Framework.
+pub const DEFAULT_ULPS_F32: i32 = 4;
+
+/// The ULPs tolerance used by `f64` assertions when neither `ulps` nor `relative_epsilon` is given.
+///
+/// This matches the tolerance used by the Google Test Framework.
+pub const DEFAULT_ULPS_F64: i64 = 4;
+
+/// The epsilon near zero used by `f32` assertions when `epsilon_near_zero` is not given.
+pub const DEFAULT_EPSILON_NEAR_ZERO_F32: f32 = f32::EPSILON;
+
+/// The epsilon near zero used by `f64` assertions when `epsilon_near_zero` is not given.
+pub const DEFAULT_EPSILON_NEAR_ZERO_F64: f64 = f64::EPSILON;
+
+/// A float type whose values can be compared with an ULPs tolerance.
+///
+/// This is like [`Ulps`], which can't be implemented for float types from other crates. It is
+/// implemented for `f32` and `f64`, and for `half::f16` with the `half` feature.
+pub trait FloatUlps: Float {
+    /// The integer type used to count ULPs.
+    type U: Copy;
+
+    /// The ULPs tolerance used when neither `ulps` nor `relative_epsilon` is given.
+    const DEFAULT_ULPS: Self::U;
+
+    /// The epsilon near zero used when `epsilon_near_zero` is not given.
+    const DEFAULT_EPSILON_NEAR_ZERO: Self;
+
+    /// Gets the number of ULPs between `self` and `other`, which is zero if they are exactly
+    /// equal.
+    fn ulps(&self, other: &Self) -> Self::U;
+}
+
+impl FloatUlps for f32 {
+    type U = i32;
+
+    const DEFAULT_ULPS: Self::U = DEFAULT_ULPS_F32;
+
+    const DEFAULT_EPSILON_NEAR_ZERO: Self = DEFAULT_EPSILON_NEAR_ZERO_F32;
+
+    fn ulps(&self, other: &Self) -> Self::U {
+        Ulps::ulps(self, other)
+    }
+}
+
+impl FloatUlps for f64 {
+    type U = i64;
+
+    const DEFAULT_ULPS: Self::U = DEFAULT_ULPS_F64;
+
+    const DEFAULT_EPSILON_NEAR_ZERO: Self = DEFAULT_EPSILON_NEAR_ZERO_F64;
+
+    fn ulps(&self, other: &Self) -> Self::U {
+        Ulps::ulps(self, other)
+    }
+}
+
+#[cfg(feature = "half")]
+impl FloatUlps for half::f16 {
+    type U = i16;
+
+    const DEFAULT_ULPS: Self::U = 4;
+
+    const DEFAULT_EPSILON_NEAR_ZERO: Self = Self::EPSILON;
+
+    fn ulps(&self, other: &Self) -> Self::U {
+        // The bits of IEEE 754 floats keep their order when read as integers, so this works the
+        // same way as `float_cmp` does for `f32` and `f64`.
+        i16::from_ne_bytes(self.to_bits().to_ne_bytes())
+            .wrapping_sub(i16::from_ne_bytes(other.to_bits().to_ne_bytes()))
+    }
+}
+
 /// Checks if two numbers are non-finite and equal
 ///
 /// # Arguments
@@ -120,13 +201,24 @@ fn is_float_eq_relative<FloatType: Float>(
 ///                          each other.
 /// * `ulps_tolerance` - The number of ULPs to use for tolerance.
 #[must_use]
-fn is_float_eq_ulps_f32(lhs: f32, rhs: f32, absolute_tolerance: f32, ulps_tolerance: i32) -> bool {
+fn is_float_eq_ulps<
+    UlpsType: Copy + PartialOrd + Neg<Output = UlpsType>,
+    FloatType: FloatUlps<U = UlpsType>,
+>(
+    lhs: FloatType,
+    rhs: FloatType,
+    absolute_tolerance: FloatType,
+    ulps_tolerance: UlpsType,
+) -> bool {
     // Check for non-finite cases
     if let Some(equal) = is_float_eq_non_finite(lhs, rhs) {
         return equal;
     }
 
     // Calculate absolute difference
+    //
+    // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+    #[allow(clippy::arithmetic_side_effects)]
     let diff = (lhs - rhs).abs();
 
     // Check for absolute tolerance first to handle cases close to zero
@@ -140,54 +232,67 @@ fn is_float_eq_ulps_f32(lhs: f32, rhs: f32, absolute_tolerance: f32, ulps_tolera
     }
 
     // Check for ULPS tolerance
-    if approx_eq!(f32, lhs, rhs, ulps = ulps_tolerance) {
-        return true;
-    }
+    //
+    // The difference is compared against both bounds instead of taking its absolute value, which
+    // could overflow for the most negative difference.
+    let ulps = lhs.ulps(&rhs);
 
-    false
+    // ULPs tolerances are never negative, so negating them cannot overflow.
+    #[allow(clippy::arithmetic_side_effects)]
+    let lower_bound = -ulps_tolerance;
+
+    lower_bound <= ulps && ulps <= ulps_tolerance
 }
 
-/// Checks if two numbers are equal using an ULPs tolerance
-///
-/// If the two numbers are both finite, an ULPs tolerance will be used to compare them.
-/// If either are not, [`is_float_eq_non_finite`] will be used instead.
+/// A predicate description for a float assertion using an ULPs tolerance
 ///
-/// # Arguments
-///
-/// * `lhs` - The left-hand side of the comparison.
-/// * `rhs` - The right-hand side of the comparison.
-/// * `absolute_tolerance` - The absolute tolerance to use when `lhs` and `rhs` are very close to
-///                          each other.
-/// * `ulps_tolerance` - The number of ULPs to use for tolerance.
-#[must_use]
-fn is_float_eq_ulps_f64(lhs: f64, rhs: f64, absolute_tolerance: f64, ulps_tolerance: i64) -> bool {
-    // Check for non-finite cases
-    if let Some(equal) = is_float_eq_non_finite(lhs, rhs) {
-        return equal;
-    }
+/// This is only formatted if the assertion fails, so passing assertions don't pay for it.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct FloatPredicateDescriptionUlps<'ulps, UlpsType, FloatType> {
+    /// The comparison operator (for example `==` or `<`)
+    operator: &'static str,
 
-    // Calculate absolute difference
-    let diff = (lhs - rhs).abs();
+    /// The ULPs tolerance
+    ulps_tolerance: &'ulps UlpsType,
 
-    // Check for absolute tolerance first to handle cases close to zero
-    if diff <= absolute_tolerance {
-        return true;
-    }
+    /// The bit width of the float (either 32 or 64)
+    bit_width: usize,
 
-    // Check for differing signs
-    if lhs.is_sign_negative() != rhs.is_sign_negative() {
-        return false;
-    }
+    /// The epsilon to use when comparing values near zero
+    epsilon_near_zero: FloatType,
+}
 
-    // Check for ULPS tolerance
-    if approx_eq!(f64, lhs, rhs, ulps = ulps_tolerance) {
-        return true;
-    }
+impl<UlpsType: Display + One + PartialEq, FloatType: Debug + Float> Display
+    for FloatPredicateDescriptionUlps<'_, UlpsType, FloatType>
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "lhs {} rhs (within {} {}-bit float ulp{}",
+            self.operator,
+            self.ulps_tolerance,
+            self.bit_width,
+            if self.ulps_tolerance.is_one() {
+                ""
+            } else {
+                "s"
+            },
+        )?;
 
-    false
+        if !self.epsilon_near_zero.is_zero() {
+            write!(formatter, " or {:?} near zero", self.epsilon_near_zero)?;
+        }
+
+        write!(formatter, ")")
+    }
 }
 
-/// Formats a predicate description message for a float assertion using an ULPs tolerance
+/// Creates a predicate description for a float assertion using an ULPs tolerance
 ///
 /// # Arguments
 ///
@@ -200,30 +305,57 @@ fn is_float_eq_ulps_f64(lhs: f64, rhs: f64, absolute_tolerance: f64, ulps_tolera
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn format_float_predicate_description_ulps<
-    UlpsType: Display + PartialEq<i32>,
-    FloatType: Debug + Float,
->(
-    operator: &str,
-    ulps_tolerance: &UlpsType,
+pub const fn format_float_predicate_description_ulps<'ulps, UlpsType, FloatType>(
+    operator: &'static str,
+    ulps_tolerance: &'ulps UlpsType,
     bit_width: usize,
     epsilon_near_zero: FloatType,
-) -> String {
-    format!(
-        "lhs {} rhs (within {} {}-bit float ulp{}{})",
+) -> FloatPredicateDescriptionUlps<'ulps, UlpsType, FloatType> {
+    FloatPredicateDescriptionUlps {
         operator,
         ulps_tolerance,
         bit_width,
-        if *ulps_tolerance == 1 { "" } else { "s" },
-        if epsilon_near_zero.is_zero() {
-            String::new()
-        } else {
-            format!(" or {epsilon_near_zero:?} near zero")
+        epsilon_near_zero,
+    }
+}
+
+/// A predicate description for a float assertion using a relative epsilon tolerance
+///
+/// This is only formatted if the assertion fails, so passing assertions don't pay for it.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct FloatPredicateDescriptionRelative<FloatType> {
+    /// The comparison operator (for example `==` or `<`)
+    operator: &'static str,
+
+    /// The relative epsilon tolerance
+    relative_epsilon: FloatType,
+
+    /// The epsilon to use when comparing values near zero
+    epsilon_near_zero: FloatType,
+}
+
+impl<FloatType: Debug + Float> Display for FloatPredicateDescriptionRelative<FloatType> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "lhs {} rhs (within {:?} relative to magnitude",
+            self.operator, self.relative_epsilon,
+        )?;
+
+        if !self.epsilon_near_zero.is_zero() {
+            write!(formatter, " or {:?} near zero", self.epsilon_near_zero)?;
         }
-    )
+
+        write!(formatter, ")")
+    }
 }
 
-/// Formats a predicate description message for a float assertion using a relative epsilon tolerance
+/// Creates a predicate description for a float assertion using a relative epsilon tolerance
 ///
 /// # Arguments
 ///
@@ -235,21 +367,16 @@ pub fn format_float_predicate_description_ulps<
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn format_float_predicate_description_relative<FloatType: Debug + Float>(
-    operator: &str,
+pub const fn format_float_predicate_description_relative<FloatType>(
+    operator: &'static str,
     relative_epsilon: FloatType,
     epsilon_near_zero: FloatType,
-) -> String {
-    format!(
-        "lhs {} rhs (within {:?} relative to magnitude{})",
+) -> FloatPredicateDescriptionRelative<FloatType> {
+    FloatPredicateDescriptionRelative {
         operator,
         relative_epsilon,
-        if epsilon_near_zero.is_zero() {
-            String::new()
-        } else {
-            format!(" or {epsilon_near_zero:?} near zero")
-        }
-    )
+        epsilon_near_zero,
+    }
 }
 
 /// Configures a panic message builder for a float assertion using an ULPs tolerance
@@ -268,27 +395,31 @@ pub fn format_float_predicate_description_relative<FloatType: Debug + Float>(
 #[must_use]
 pub fn configure_float_panic_message_ulps<
     UlpsType: Debug,
-    FloatType: Float + Debug + Ulps<U = UlpsType>,
+    FloatType: Debug + FloatUlps<U = UlpsType>,
 >(
     panic_message_builder: PanicMessageBuilder,
     lhs_description: &str,
-    lhs_value: FloatType,
+    lhs_value: &FloatType,
     rhs_description: &str,
-    rhs_value: FloatType,
+    rhs_value: &FloatType,
 ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
     // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
     #[allow(clippy::arithmetic_side_effects)]
     panic_message_builder
-        .with_argument("lhs", lhs_description, &lhs_value)?
-        .with_argument("rhs", rhs_description, &rhs_value)?
-        .with_argument("absolute difference", "--", &(lhs_value - rhs_value).abs())?
+        .with_argument("lhs", lhs_description, lhs_value)?
+        .with_argument("rhs", rhs_description, rhs_value)?
+        .with_argument(
+            "absolute difference",
+            "--",
+            &(*lhs_value - *rhs_value).abs(),
+        )?
         .with_argument(
             "absolute difference (ulps)",
             "--",
             &if lhs_value < rhs_value {
-                rhs_value.ulps(&lhs_value)
+                rhs_value.ulps(lhs_value)
             } else {
-                lhs_value.ulps(&rhs_value)
+                lhs_value.ulps(rhs_value)
             },
         )
 }
@@ -310,400 +441,609 @@ pub fn configure_float_panic_message_ulps<
 pub fn configure_float_panic_message_relative<FloatType: Float + Debug>(
     panic_message_builder: PanicMessageBuilder,
     lhs_description: &str,
-    lhs_value: FloatType,
+    lhs_value: &FloatType,
     rhs_description: &str,
-    rhs_value: FloatType,
+    rhs_value: &FloatType,
 ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
     // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
     #[allow(clippy::arithmetic_side_effects)]
     panic_message_builder
-        .with_argument("lhs", lhs_description, &lhs_value)?
-        .with_argument("rhs", rhs_description, &rhs_value)?
-        .with_argument("absolute difference", "--", &(lhs_value - rhs_value).abs())
+        .with_argument("lhs", lhs_description, lhs_value)?
+        .with_argument("rhs", rhs_description, rhs_value)?
+        .with_argument(
+            "absolute difference",
+            "--",
+            &(*lhs_value - *rhs_value).abs(),
+        )
 }
 
+/// Checks that neither operand of a float assertion is NaN, unless NaN operands are allowed
+///
+/// # Arguments
+///
+/// * `nan_equal` - Whether NaN operands are allowed, in which case NaN is equal to NaN
+/// * `lhs` - The left-hand side of the comparison
+/// * `rhs` - The right-hand side of the comparison
+///
+/// # Returns
+///
+/// * `false` if `nan_equal` is `false` and either operand is NaN
+/// * Otherwise, `true`
+//
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_f32_eq_impl_ulps(lhs: f32, rhs: f32, epsilon_near_zero: f32, ulps: i32) -> bool {
-    is_float_eq_ulps_f32(lhs, rhs, epsilon_near_zero, ulps)
+pub fn check_nan_operands<FloatType: Float>(
+    nan_equal: bool,
+    lhs: FloatType,
+    rhs: FloatType,
+) -> bool {
+    nan_equal || !(lhs.is_nan() || rhs.is_nan())
 }
 
+/// A float predicate description that says which operands are NaN when NaN operands are not
+/// allowed
+///
+/// This is only formatted if the assertion fails, so passing assertions don't pay for it.
+//
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
-#[must_use]
-pub fn assert_f32_eq_impl_relative(
-    lhs: f32,
-    rhs: f32,
-    epsilon_near_zero: f32,
-    relative_epsilon: f32,
-) -> bool {
-    is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct FloatPredicateDescriptionNan<DescriptionType> {
+    /// The predicate description to add to
+    predicate_description: DescriptionType,
+
+    /// Which operands are NaN, or `None` if there are none to mention
+    nan_operands: Option<&'static str>,
 }
 
-/// Asserts that two `f32` values are equal.
-///
-/// See
-/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
-/// for a usage guide.
+impl<DescriptionType: Display> Display for FloatPredicateDescriptionNan<DescriptionType> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}", self.predicate_description)?;
+
+        if let Some(nan_operands) = self.nan_operands {
+            write!(formatter, " (operand is NaN: {nan_operands})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds which operands are NaN to a float predicate description when NaN operands are not allowed
 ///
 /// # Arguments
 ///
-/// * `lhs` - The left-hand side
-/// * `rhs` - The right-hand side
-/// * Can use one of:
-///     * `ulps = <value>` - The number of ULPs to use for tolerance
-///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
-/// * Optional keyword arguments for assertions
-///
-/// # Example
-///
-/// ```
-/// # use test_ur_code_xd::assert_f32_eq;
-/// #
-/// # let x = 3.0;
-/// #
-/// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f32_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
-///
-/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f32_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
-///
-/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
-/// assert_f32_eq!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
-/// ```
-#[macro_export]
-macro_rules! assert_f32_eq {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "==",
-                &$ulps,
-                32,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_eq_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i32, f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+/// * `predicate_description` - The predicate description to add to
+/// * `nan_equal` - Whether NaN operands are allowed
+/// * `lhs` - The left-hand side of the comparison
+/// * `rhs` - The right-hand side of the comparison
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn format_float_predicate_description_nan<DescriptionType, FloatType: Float>(
+    predicate_description: DescriptionType,
+    nan_equal: bool,
+    lhs: FloatType,
+    rhs: FloatType,
+) -> FloatPredicateDescriptionNan<DescriptionType> {
+    let nan_operands = match (lhs.is_nan(), rhs.is_nan()) {
+        _ if nan_equal => None,
+        (true, true) => Some("lhs and rhs"),
+        (true, false) => Some("lhs"),
+        (false, true) => Some("rhs"),
+        (false, false) => None,
     };
 
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "==",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_eq_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
+    FloatPredicateDescriptionNan {
+        predicate_description,
+        nan_operands,
+    }
 }
 
+/// Fills in the default ULPs tolerance for a float assertion
+///
+/// # Arguments
+///
+/// * `value` - A value of the float type, used only to infer the type
+/// * `ulps` - The ULPs tolerance, if given (defaults to [`FloatUlps::DEFAULT_ULPS`])
+//
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_f32_ne_impl_ulps(lhs: f32, rhs: f32, epsilon_near_zero: f32, ulps: i32) -> bool {
-    !is_float_eq_ulps_f32(lhs, rhs, epsilon_near_zero, ulps)
+pub fn resolve_float_ulps<FloatType: FloatUlps>(
+    _value: &FloatType,
+    ulps: Option<FloatType::U>,
+) -> FloatType::U {
+    ulps.unwrap_or(FloatType::DEFAULT_ULPS)
 }
 
+/// Fills in the default epsilon near zero for a float assertion
+///
+/// # Arguments
+///
+/// * `epsilon_near_zero` - The epsilon near zero, if given (defaults to
+///                         [`FloatUlps::DEFAULT_EPSILON_NEAR_ZERO`])
+//
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_f32_ne_impl_relative(
-    lhs: f32,
-    rhs: f32,
-    epsilon_near_zero: f32,
-    relative_epsilon: f32,
-) -> bool {
-    !is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
+pub fn resolve_float_epsilon_near_zero<FloatType: FloatUlps>(
+    epsilon_near_zero: Option<FloatType>,
+) -> FloatType {
+    epsilon_near_zero.unwrap_or(FloatType::DEFAULT_EPSILON_NEAR_ZERO)
 }
 
-/// Asserts that two `f32` values are unequal.
-///
-/// See
-/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
-/// for a usage guide.
+/// Fills in the default for whether NaN operands are equal for a float assertion
 ///
 /// # Arguments
 ///
-/// * `lhs` - The left-hand side
-/// * `rhs` - The right-hand side
-/// * Can use one of:
-///     * `ulps = <value>` - The number of ULPs to use for tolerance
-///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
-/// * Optional keyword arguments for assertions
-///
-/// # Example
-///
-/// ```
-/// # use test_ur_code_xd::assert_f32_ne;
-/// #
-/// # let x = 4.0;
-/// #
-/// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f32_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
-///
-/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f32_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
-///
-/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
-/// assert_f32_ne!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
-/// ```
-#[macro_export]
-macro_rules! assert_f32_ne {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "!=",
-                &$ulps,
-                32,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_ne_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i32, f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
+/// * `nan_equal` - Whether NaN operands are allowed, if given (defaults to `true`)
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn resolve_float_nan_equal(nan_equal: Option<bool>) -> bool {
+    nan_equal.unwrap_or(true)
+}
 
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "!=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_ne_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
+/// A comparison made by a float assertion
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FloatComparison {
+    /// `lhs == rhs` within the tolerance
+    Eq,
+
+    /// `lhs != rhs` outside of the tolerance
+    Ne,
+
+    /// `lhs <= rhs` or `lhs == rhs` within the tolerance
+    Le,
+
+    /// `lhs >= rhs` or `lhs == rhs` within the tolerance
+    Ge,
+
+    /// `lhs < rhs` and `lhs != rhs` outside of the tolerance
+    Lt,
+
+    /// `lhs > rhs` and `lhs != rhs` outside of the tolerance
+    Gt,
+}
+
+impl FloatComparison {
+    /// Gets the operator to use in predicate descriptions
+    #[must_use]
+    pub const fn operator(self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Le => "<=",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Gt => ">",
+        }
+    }
+
+    /// Checks the comparison
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left-hand side
+    /// * `rhs` - The right-hand side
+    /// * `is_equal` - Checks whether the operands are equal within the tolerance, if needed
+    fn check<FloatType: Float>(
+        self,
+        lhs: FloatType,
+        rhs: FloatType,
+        is_equal: impl FnOnce() -> bool,
+    ) -> bool {
+        match self {
+            Self::Eq => is_equal(),
+            Self::Ne => !is_equal(),
+            Self::Le => lhs <= rhs || is_equal(),
+            Self::Ge => lhs >= rhs || is_equal(),
+            Self::Lt => lhs < rhs && !is_equal(),
+            Self::Gt => lhs > rhs && !is_equal(),
+        }
+    }
 }
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_f32_le_impl_ulps(lhs: f32, rhs: f32, epsilon_near_zero: f32, ulps: i32) -> bool {
-    lhs <= rhs || is_float_eq_ulps_f32(lhs, rhs, epsilon_near_zero, ulps)
+pub fn assert_float_impl_ulps<
+    UlpsType: Copy + PartialOrd + Neg<Output = UlpsType>,
+    FloatType: FloatUlps<U = UlpsType>,
+>(
+    comparison: FloatComparison,
+    lhs: FloatType,
+    rhs: FloatType,
+    epsilon_near_zero: FloatType,
+    ulps: UlpsType,
+) -> bool {
+    comparison.check(lhs, rhs, || {
+        is_float_eq_ulps(lhs, rhs, epsilon_near_zero, ulps)
+    })
 }
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
 #[must_use]
-pub fn assert_f32_le_impl_relative(
-    lhs: f32,
-    rhs: f32,
-    epsilon_near_zero: f32,
-    relative_epsilon: f32,
+pub fn assert_float_impl_relative<FloatType: Float>(
+    comparison: FloatComparison,
+    lhs: FloatType,
+    rhs: FloatType,
+    epsilon_near_zero: FloatType,
+    relative_epsilon: FloatType,
 ) -> bool {
-    lhs <= rhs || is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
+    comparison.check(lhs, rhs, || {
+        is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
+    })
 }
 
-/// Asserts that one `f32` value is less than or equal to the other.
+/// Gets the bit width of a float type for use in predicate descriptions
 ///
-/// See
-/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
-/// for a usage guide.
+/// # Arguments
+///
+/// * `value` - A value of the float type, used only to infer the type
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub const fn float_bit_width<FloatType>(_value: &FloatType) -> usize {
+    mem::size_of::<FloatType>().saturating_mul(8)
+}
+
+/// Fills in the default tolerances for a float assertion
 ///
 /// # Arguments
 ///
-/// * `lhs` - The left-hand side
-/// * `rhs` - The right-hand side
-/// * Can use one of:
-///     * `ulps = <value>` - The number of ULPs to use for tolerance
-///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
-/// * Optional keyword arguments for assertions
+/// * `value` - A value of the float type, used only to infer the type
+/// * `ulps` - The ULPs tolerance, if given (defaults to [`FloatUlps::DEFAULT_ULPS`])
+/// * `epsilon_near_zero` - The epsilon near zero, if given (defaults to
+///                         [`FloatUlps::DEFAULT_EPSILON_NEAR_ZERO`])
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn resolve_float_tolerances<FloatType: FloatUlps>(
+    value: &FloatType,
+    ulps: Option<FloatType::U>,
+    epsilon_near_zero: Option<FloatType>,
+) -> (FloatType::U, FloatType) {
+    (
+        resolve_float_ulps(value, ulps),
+        resolve_float_epsilon_near_zero(epsilon_near_zero),
+    )
+}
+
+/// Implements every float comparison assertion
 ///
-/// # Example
+/// Each assertion such as [`assert_f32_eq`](crate::assert_f32_eq) calls this as
+/// `assert_float_comparison!([f32] Eq, lhs, rhs, keys = values...)`, where `[f32]` is the operand
+/// type (or `[]` to infer it) and `Eq` is a [`FloatComparison`] variant.
 ///
-/// ```
-/// # use test_ur_code_xd::assert_f32_le;
-/// #
-/// # let x = 3.0;
-/// #
-/// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f32_le!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+/// Tolerance keywords can come in any order and can be mixed with the other keyword arguments.
+/// They are sorted into one of these internal forms, where each tolerance that wasn't given is
+/// `[]`:
 ///
-/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f32_le!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+/// * `@ulps context, lhs, rhs, [ulps], [epsilon_near_zero], [nan_equal], keys = values...`
+/// * `@relative context, lhs, rhs, relative_epsilon, [epsilon_near_zero], [nan_equal],
+///   keys = values...`
 ///
-/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
-/// assert_f32_le!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
-/// ```
+/// `ulps` and `relative_epsilon` can't both be given, and each tolerance keyword can only be given
+/// once. Either is a compile error.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! assert_f32_le {
+macro_rules! assert_float_comparison {
+    (@option) => {
+        ::std::option::Option::None
+    };
+
+    (@option $value:expr) => {
+        ::std::option::Option::Some($value)
+    };
+
+    (@value [$float_type:ty] $value:expr) => {
+        *::std::borrow::Borrow::<$float_type>::borrow(&$value)
+    };
+
+    (@value [] $value:expr) => {
+        $value
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [] [] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?] [$($keys:ident = $values:expr),*]
+        ulps = $ulps:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse $context ($lhs, $rhs)
+            [$ulps] [] [$($epsilon_near_zero)?] [$($nan_equal)?] [$($keys = $values),*]
+            $($($rest)*)?
+        )
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [] [] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?] [$($keys:ident = $values:expr),*]
+        relative_epsilon = $relative_epsilon:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse $context ($lhs, $rhs)
+            [] [$relative_epsilon] [$($epsilon_near_zero)?] [$($nan_equal)?] [$($keys = $values),*]
+            $($($rest)*)?
+        )
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [$($relative_epsilon:expr)?] [] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        epsilon_near_zero = $epsilon_near_zero:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse $context ($lhs, $rhs)
+            [$($ulps)?] [$($relative_epsilon)?] [$epsilon_near_zero] [$($nan_equal)?]
+            [$($keys = $values),*]
+            $($($rest)*)?
+        )
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [$($relative_epsilon:expr)?] [$($epsilon_near_zero:expr)?] []
+        [$($keys:ident = $values:expr),*]
+        nan_equal = $nan_equal:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse $context ($lhs, $rhs)
+            [$($ulps)?] [$($relative_epsilon)?] [$($epsilon_near_zero)?] [$nan_equal]
+            [$($keys = $values),*]
+            $($($rest)*)?
+        )
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$ulps:expr] [] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        ulps = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`ulps` can only be given once")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [] [$relative_epsilon:expr] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        relative_epsilon = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`relative_epsilon` can only be given once")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$ulps:expr] [] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        relative_epsilon = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`ulps` and `relative_epsilon` can't be used together")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [] [$relative_epsilon:expr] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        ulps = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`ulps` and `relative_epsilon` can't be used together")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [$($relative_epsilon:expr)?] [$epsilon_near_zero:expr] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+        epsilon_near_zero = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`epsilon_near_zero` can only be given once")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [$($relative_epsilon:expr)?] [$($epsilon_near_zero:expr)?] [$nan_equal:expr]
+        [$($keys:ident = $values:expr),*]
+        nan_equal = $($rest:tt)*
+    ) => {
+        ::std::compile_error!("`nan_equal` can only be given once")
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [$($relative_epsilon:expr)?] [$($epsilon_near_zero:expr)?]
+        [$($nan_equal:expr)?] [$($keys:ident = $values:expr),*]
+        $key:ident = $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse $context ($lhs, $rhs)
+            [$($ulps)?] [$($relative_epsilon)?] [$($epsilon_near_zero)?] [$($nan_equal)?]
+            [$($keys = $values,)* $key = $value]
+            $($($rest)*)?
+        )
+    };
+
+    (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [$($ulps:expr)?] [] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+    ) => {
+        $crate::assert_float_comparison!(
+            @ulps
+            $context,
+            $lhs,
+            $rhs,
+            [$($ulps)?],
+            [$($epsilon_near_zero)?],
+            [$($nan_equal)?]
+            $(, $keys = $values)*
+        )
+    };
+
     (
+        @parse $context:tt ($lhs:expr, $rhs:expr)
+        [] [$relative_epsilon:expr] [$($epsilon_near_zero:expr)?] [$($nan_equal:expr)?]
+        [$($keys:ident = $values:expr),*]
+    ) => {
+        $crate::assert_float_comparison!(
+            @relative
+            $context,
+            $lhs,
+            $rhs,
+            $relative_epsilon,
+            [$($epsilon_near_zero)?],
+            [$($nan_equal)?]
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        @ulps
+        ([$($float_type:ty)?] $comparison:ident),
         $lhs:expr,
         $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
+        [$($ulps:expr)?],
+        [$($epsilon_near_zero:expr)?],
+        [$($nan_equal:expr)?]
+        $(, $keys:ident = $values:expr)*
+    ) => {{
+        let lhs = $crate::assert_float_comparison!(@value [$($float_type)?] $lhs);
+        let rhs = $crate::assert_float_comparison!(@value [$($float_type)?] $rhs);
+        let (ulps, epsilon_near_zero) = $crate::assertions::float::resolve_float_tolerances(
+            &lhs,
+            $crate::assert_float_comparison!(@option $($ulps)?),
+            $crate::assert_float_comparison!(@option $($epsilon_near_zero)?),
+        );
+        let nan_equal: bool = $crate::assertions::float::resolve_float_nan_equal(
+            $crate::assert_float_comparison!(@option $($nan_equal)?),
+        );
+
         $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "<=",
-                &$ulps,
-                32,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_le_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
+            $crate::assertions::float::format_float_predicate_description_nan(
+                $crate::assertions::float::format_float_predicate_description_ulps(
+                    $crate::assertions::float::FloatComparison::$comparison.operator(),
+                    &ulps,
+                    $crate::assertions::float::float_bit_width(&lhs),
+                    epsilon_near_zero,
+                ),
+                nan_equal,
+                lhs,
+                rhs,
             ),
+            $crate::assertions::float::check_nan_operands(nan_equal, lhs, rhs)
+                && $crate::assertions::float::assert_float_impl_ulps(
+                    $crate::assertions::float::FloatComparison::$comparison,
+                    lhs,
+                    rhs,
+                    epsilon_near_zero,
+                    ulps
+                ),
             |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i32, f32>(
+                $crate::assertions::float::configure_float_panic_message_ulps(
                     panic_message_builder,
                     stringify!($lhs),
-                    $lhs,
+                    &lhs,
                     stringify!($rhs),
-                    $rhs,
+                    &rhs,
                 )
             }
             $(, $keys = $values)*
         )
-    };
+    }};
 
     (
+        @relative
+        ([$($float_type:ty)?] $comparison:ident),
         $lhs:expr,
         $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
+        $relative_epsilon:expr,
+        [$($epsilon_near_zero:expr)?],
+        [$($nan_equal:expr)?]
+        $(, $keys:ident = $values:expr)*
+    ) => {{
+        let lhs = $crate::assert_float_comparison!(@value [$($float_type)?] $lhs);
+        let rhs = $crate::assert_float_comparison!(@value [$($float_type)?] $rhs);
+        let relative_epsilon = $relative_epsilon;
+        let epsilon_near_zero = $crate::assertions::float::resolve_float_epsilon_near_zero(
+            $crate::assert_float_comparison!(@option $($epsilon_near_zero)?),
+        );
+        let nan_equal: bool = $crate::assertions::float::resolve_float_nan_equal(
+            $crate::assert_float_comparison!(@option $($nan_equal)?),
+        );
+
         $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "<=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_le_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
+            $crate::assertions::float::format_float_predicate_description_nan(
+                $crate::assertions::float::format_float_predicate_description_relative(
+                    $crate::assertions::float::FloatComparison::$comparison.operator(),
+                    relative_epsilon,
+                    epsilon_near_zero,
+                ),
+                nan_equal,
+                lhs,
+                rhs,
             ),
+            $crate::assertions::float::check_nan_operands(nan_equal, lhs, rhs)
+                && $crate::assertions::float::assert_float_impl_relative(
+                    $crate::assertions::float::FloatComparison::$comparison,
+                    lhs,
+                    rhs,
+                    epsilon_near_zero,
+                    relative_epsilon
+                ),
             |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f32>(
+                $crate::assertions::float::configure_float_panic_message_relative(
                     panic_message_builder,
                     stringify!($lhs),
-                    $lhs,
+                    &lhs,
                     stringify!($rhs),
-                    $rhs,
+                    &rhs,
                 )
             }
             $(, $keys = $values)*
         )
-    };
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f32_ge_impl_ulps(lhs: f32, rhs: f32, epsilon_near_zero: f32, ulps: i32) -> bool {
-    lhs >= rhs || is_float_eq_ulps_f32(lhs, rhs, epsilon_near_zero, ulps)
-}
+    }};
 
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f32_ge_impl_relative(
-    lhs: f32,
-    rhs: f32,
-    epsilon_near_zero: f32,
-    relative_epsilon: f32,
-) -> bool {
-    lhs >= rhs || is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
+    (
+        [$($float_type:ty)?] $comparison:ident,
+        $lhs:expr,
+        $rhs:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_float_comparison!(
+            @parse ([$($float_type)?] $comparison) ($lhs, $rhs) [] [] [] [] []
+            $($keys = $values),*
+        )
+    };
 }
 
-/// Asserts that one `f32` value is greater than or equal to the other.
+/// Asserts that two `f32` values are equal.
 ///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
@@ -713,117 +1053,46 @@ pub fn assert_f32_ge_impl_relative(
 ///
 /// * `lhs` - The left-hand side
 /// * `rhs` - The right-hand side
-/// * Can use one of:
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
 ///     * `ulps = <value>` - The number of ULPs to use for tolerance
 ///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
 /// * Optional keyword arguments for assertions
 ///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
 /// # Example
 ///
 /// ```
-/// # use test_ur_code_xd::assert_f32_ge;
+/// # use test_ur_code_xd::assert_f32_eq;
 /// #
 /// # let x = 3.0;
 /// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f32_eq!(x, 3.0);
+///
 /// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f32_ge!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+/// assert_f32_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
 ///
 /// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f32_ge!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+/// assert_f32_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
 ///
 /// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
-/// assert_f32_ge!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
+/// assert_f32_eq!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
 /// ```
 #[macro_export]
-macro_rules! assert_f32_ge {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                ">=",
-                &$ulps,
-                32,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_ge_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i32, f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+macro_rules! assert_f32_eq {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Eq, $lhs, $rhs $(, $keys = $values)*)
     };
+}
 
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                ">=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f32_ge_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f32>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_eq_impl_ulps(lhs: f64, rhs: f64, epsilon_near_zero: f64, ulps: i64) -> bool {
-    is_float_eq_ulps_f64(lhs, rhs, epsilon_near_zero, ulps)
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_eq_impl_relative(
-    lhs: f64,
-    rhs: f64,
-    epsilon_near_zero: f64,
-    relative_epsilon: f64,
-) -> bool {
-    is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
-}
-
-/// Asserts that two `f64` values are equal.
+/// Asserts that two `f32` values are unequal.
 ///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
@@ -833,117 +1102,46 @@ pub fn assert_f64_eq_impl_relative(
 ///
 /// * `lhs` - The left-hand side
 /// * `rhs` - The right-hand side
-/// * Can use one of:
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
 ///     * `ulps = <value>` - The number of ULPs to use for tolerance
 ///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
 /// * Optional keyword arguments for assertions
 ///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
 /// # Example
 ///
 /// ```
-/// # use test_ur_code_xd::assert_f64_eq;
+/// # use test_ur_code_xd::assert_f32_ne;
 /// #
-/// # let x = 3.0;
+/// # let x = 4.0;
 /// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f32_ne!(x, 3.0);
+///
 /// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f64_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+/// assert_f32_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
 ///
 /// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f64_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+/// assert_f32_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
 ///
-/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
-/// assert_f64_eq!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
+/// assert_f32_ne!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
 /// ```
 #[macro_export]
-macro_rules! assert_f64_eq {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "==",
-                &$ulps,
-                64,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_eq_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i64, f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
-
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "==",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_eq_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+macro_rules! assert_f32_ne {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Ne, $lhs, $rhs $(, $keys = $values)*)
     };
 }
 
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_ne_impl_ulps(lhs: f64, rhs: f64, epsilon_near_zero: f64, ulps: i64) -> bool {
-    !is_float_eq_ulps_f64(lhs, rhs, epsilon_near_zero, ulps)
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_ne_impl_relative(
-    lhs: f64,
-    rhs: f64,
-    epsilon_near_zero: f64,
-    relative_epsilon: f64,
-) -> bool {
-    !is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
-}
-
-/// Asserts that two `f64` values are unequal.
+/// Asserts that one `f32` value is less than or equal to the other.
 ///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
@@ -953,117 +1151,46 @@ pub fn assert_f64_ne_impl_relative(
 ///
 /// * `lhs` - The left-hand side
 /// * `rhs` - The right-hand side
-/// * Can use one of:
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
 ///     * `ulps = <value>` - The number of ULPs to use for tolerance
 ///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
 /// * Optional keyword arguments for assertions
 ///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
 /// # Example
 ///
 /// ```
-/// # use test_ur_code_xd::assert_f64_ne;
+/// # use test_ur_code_xd::assert_f32_le;
 /// #
-/// # let x = 4.0;
+/// # let x = 3.0;
 /// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f32_le!(x, 3.0);
+///
 /// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f64_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+/// assert_f32_le!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
 ///
 /// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f64_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+/// assert_f32_le!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
 ///
-/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
-/// assert_f64_ne!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
+/// assert_f32_le!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
 /// ```
 #[macro_export]
-macro_rules! assert_f64_ne {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "!=",
-                &$ulps,
-                64,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_ne_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i64, f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
-
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "!=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_ne_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+macro_rules! assert_f32_le {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Le, $lhs, $rhs $(, $keys = $values)*)
     };
 }
 
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_le_impl_ulps(lhs: f64, rhs: f64, epsilon_near_zero: f64, ulps: i64) -> bool {
-    lhs <= rhs || is_float_eq_ulps_f64(lhs, rhs, epsilon_near_zero, ulps)
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_le_impl_relative(
-    lhs: f64,
-    rhs: f64,
-    epsilon_near_zero: f64,
-    relative_epsilon: f64,
-) -> bool {
-    lhs <= rhs || is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
-}
-
-/// Asserts that one `f64` value is less than or equal to the other.
+/// Asserts that one `f32` value is greater than or equal to the other.
 ///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
@@ -1073,117 +1200,48 @@ pub fn assert_f64_le_impl_relative(
 ///
 /// * `lhs` - The left-hand side
 /// * `rhs` - The right-hand side
-/// * Can use one of:
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
 ///     * `ulps = <value>` - The number of ULPs to use for tolerance
 ///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
 /// * Optional keyword arguments for assertions
 ///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
 /// # Example
 ///
 /// ```
-/// # use test_ur_code_xd::assert_f64_le;
+/// # use test_ur_code_xd::assert_f32_ge;
 /// #
 /// # let x = 3.0;
 /// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f32_ge!(x, 3.0);
+///
 /// // Compare `x` to 3.0 within 2 ULPs
-/// assert_f64_le!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+/// assert_f32_ge!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
 ///
 /// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
-/// assert_f64_le!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+/// assert_f32_ge!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
 ///
-/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
-/// assert_f64_le!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// // Compare `x` to 3.0 within `f32::EPSILON`, relative to magnitude
+/// assert_f32_ge!(x, 3.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
 /// ```
 #[macro_export]
-macro_rules! assert_f64_le {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                "<=",
-                &$ulps,
-                64,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_le_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i64, f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
-    };
-
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                "<=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_le_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+macro_rules! assert_f32_ge {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Ge, $lhs, $rhs $(, $keys = $values)*)
     };
 }
 
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_ge_impl_ulps(lhs: f64, rhs: f64, epsilon_near_zero: f64, ulps: i64) -> bool {
-    lhs >= rhs || is_float_eq_ulps_f64(lhs, rhs, epsilon_near_zero, ulps)
-}
-
-// Assertion implementations need to be public for the macros to use them, but should not appear in
-// documentation.
-#[doc(hidden)]
-#[must_use]
-pub fn assert_f64_ge_impl_relative(
-    lhs: f64,
-    rhs: f64,
-    epsilon_near_zero: f64,
-    relative_epsilon: f64,
-) -> bool {
-    lhs >= rhs || is_float_eq_relative(lhs, rhs, epsilon_near_zero, relative_epsilon)
-}
-
-/// Asserts that one `f64` value is greater than or equal to the other.
+/// Asserts that one `f32` value is strictly less than the other.
+///
+/// Fails if the values are approximately equal within the tolerance, even if `lhs < rhs`.
 ///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
@@ -1193,19 +1251,277 @@ pub fn assert_f64_ge_impl_relative(
 ///
 /// * `lhs` - The left-hand side
 /// * `rhs` - The right-hand side
-/// * Can use one of:
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
 ///     * `ulps = <value>` - The number of ULPs to use for tolerance
 ///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
 /// * Optional keyword arguments for assertions
 ///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
 /// # Example
 ///
 /// ```
-/// # use test_ur_code_xd::assert_f64_ge;
+/// # use test_ur_code_xd::assert_f32_lt;
 /// #
 /// # let x = 3.0;
 /// #
-/// // Compare `x` to 3.0 within 2 ULPs
+/// // Compare `x` to 4.0 using the default tolerances
+/// assert_f32_lt!(x, 4.0);
+///
+/// // Compare `x` to 4.0 within 2 ULPs
+/// assert_f32_lt!(x, 4.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 4.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f32_lt!(x, 4.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 4.0 within `f32::EPSILON`, relative to magnitude
+/// assert_f32_lt!(x, 4.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f32_lt {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Lt, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that one `f32` value is strictly greater than the other.
+///
+/// Fails if the values are approximately equal within the tolerance, even if `lhs > rhs`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f32_gt;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 2.0 using the default tolerances
+/// assert_f32_gt!(x, 2.0);
+///
+/// // Compare `x` to 2.0 within 2 ULPs
+/// assert_f32_gt!(x, 2.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 2.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f32_gt!(x, 2.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 2.0 within `f32::EPSILON`, relative to magnitude
+/// assert_f32_gt!(x, 2.0, relative_epsilon = f32::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f32_gt {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f32] Gt, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that two `f64` values are equal.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_eq;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f64_eq!(x, 3.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs
+/// assert_f64_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f64_eq!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
+/// assert_f64_eq!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_eq {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Eq, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that two `f64` values are unequal.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_ne;
+/// #
+/// # let x = 4.0;
+/// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f64_ne!(x, 3.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs
+/// assert_f64_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f64_ne!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
+/// assert_f64_ne!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_ne {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Ne, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that one `f64` value is less than or equal to the other.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_le;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f64_le!(x, 3.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs
+/// assert_f64_le!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f64_le!(x, 3.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 3.0 within `f64::EPSILON`, relative to magnitude
+/// assert_f64_le!(x, 3.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_le {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Le, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that one `f64` value is greater than or equal to the other.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_ge;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 3.0 using the default tolerances
+/// assert_f64_ge!(x, 3.0);
+///
+/// // Compare `x` to 3.0 within 2 ULPs
 /// assert_f64_ge!(x, 3.0, ulps = 2, epsilon_near_zero = 0.0);
 ///
 /// // Compare `x` to 3.0 within 2 ULPs or within 1e-7 if they are very close
@@ -1216,2074 +1532,4721 @@ pub fn assert_f64_ge_impl_relative(
 /// ```
 #[macro_export]
 macro_rules! assert_f64_ge {
-    (
-        $lhs:expr,
-        $rhs:expr,
-        ulps = $ulps:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_ulps(
-                ">=",
-                &$ulps,
-                64,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_ge_impl_ulps(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $ulps
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_ulps::<i64, f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Ge, $lhs, $rhs $(, $keys = $values)*)
     };
+}
 
-    (
-        $lhs:expr,
-        $rhs:expr,
-        relative_epsilon = $relative_epsilon:expr,
-        epsilon_near_zero = $epsilon_near_zero:expr
-        $(, $keys:ident = $values:expr)* $(,)?
-    ) => {
-        $crate::assert_custom!(
-            $crate::assertions::float::format_float_predicate_description_relative(
-                ">=",
-                $relative_epsilon,
-                $epsilon_near_zero,
-            ),
-            $crate::assertions::float::assert_f64_ge_impl_relative(
-                $lhs,
-                $rhs,
-                $epsilon_near_zero,
-                $relative_epsilon
-            ),
-            |panic_message_builder| {
-                $crate::assertions::float::configure_float_panic_message_relative::<f64>(
-                    panic_message_builder,
-                    stringify!($lhs),
-                    $lhs,
-                    stringify!($rhs),
-                    $rhs,
-                )
-            }
-            $(, $keys = $values)*
-        )
+/// Asserts that one `f64` value is strictly less than the other.
+///
+/// Fails if the values are approximately equal within the tolerance, even if `lhs < rhs`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_lt;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 4.0 using the default tolerances
+/// assert_f64_lt!(x, 4.0);
+///
+/// // Compare `x` to 4.0 within 2 ULPs
+/// assert_f64_lt!(x, 4.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 4.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f64_lt!(x, 4.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 4.0 within `f64::EPSILON`, relative to magnitude
+/// assert_f64_lt!(x, 4.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_lt {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Lt, $lhs, $rhs $(, $keys = $values)*)
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::assert_eq;
+/// Asserts that one `f64` value is strictly greater than the other.
+///
+/// Fails if the values are approximately equal within the tolerance, even if `lhs > rhs`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_gt;
+/// #
+/// # let x = 3.0;
+/// #
+/// // Compare `x` to 2.0 using the default tolerances
+/// assert_f64_gt!(x, 2.0);
+///
+/// // Compare `x` to 2.0 within 2 ULPs
+/// assert_f64_gt!(x, 2.0, ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to 2.0 within 2 ULPs or within 1e-7 if they are very close
+/// assert_f64_gt!(x, 2.0, ulps = 2, epsilon_near_zero = 1e-7);
+///
+/// // Compare `x` to 2.0 within `f64::EPSILON`, relative to magnitude
+/// assert_f64_gt!(x, 2.0, relative_epsilon = f64::EPSILON, epsilon_near_zero = 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_gt {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([f64] Gt, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that two float values of any type are equal.
+///
+/// This works for any type implementing [`FloatUlps`](crate::assertions::float::FloatUlps), which
+/// includes `f32`, `f64`, and `half::f16` with the `half` feature.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`FloatUlps::DEFAULT_ULPS`](crate::assertions::float::FloatUlps::DEFAULT_ULPS) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`FloatUlps::DEFAULT_EPSILON_NEAR_ZERO`](crate::assertions::float::FloatUlps::DEFAULT_EPSILON_NEAR_ZERO))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_float_eq;
+/// #
+/// // Compare using the default tolerances
+/// assert_float_eq!(0.1_f32 + 0.2_f32, 0.3_f32);
+///
+/// // Compare within 1 ULP and with no tolerance near zero
+/// assert_float_eq!(0.1_f64 + 0.2_f64, 0.3_f64, ulps = 1, epsilon_near_zero = 0.0);
+///
+/// // Compare within `f64::EPSILON`, relative to magnitude
+/// assert_float_eq!(0.1_f64 + 0.2_f64, 0.3_f64, relative_epsilon = f64::EPSILON);
+/// ```
+#[macro_export]
+macro_rules! assert_float_eq {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([] Eq, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that two float values of any type are unequal.
+///
+/// This works for any type implementing [`FloatUlps`](crate::assertions::float::FloatUlps), which
+/// includes `f32`, `f64`, and `half::f16` with the `half` feature.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side
+/// * `rhs` - The right-hand side
+/// * Can optionally use one of (defaults to
+///   [`FloatUlps::DEFAULT_ULPS`](crate::assertions::float::FloatUlps::DEFAULT_ULPS) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`FloatUlps::DEFAULT_EPSILON_NEAR_ZERO`](crate::assertions::float::FloatUlps::DEFAULT_EPSILON_NEAR_ZERO))
+/// * `nan_equal = <value>` - Optional flag for whether NaN operands are equal to each other
+///   (defaults to `true`). When `false`, any NaN operand fails the assertion.
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps` and `relative_epsilon` can't be used together. Keyword arguments can come in
+/// any order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_float_ne;
+/// #
+/// assert_float_ne!(0.1_f32 + 0.2_f32, 0.4_f32);
+/// ```
+#[macro_export]
+macro_rules! assert_float_ne {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_float_comparison!([] Ne, $lhs, $rhs $(, $keys = $values)*)
+    };
+}
+
+/// Describes the classification of a float value
+///
+/// # Arguments
+///
+/// * `value` - The value to classify
+///
+/// # Returns
+///
+/// One of `"NaN"`, `"+inf"`, `"-inf"`, `"zero"`, `"subnormal"`, or `"normal"`.
+#[must_use]
+fn describe_float_classification<FloatType: Float>(value: FloatType) -> &'static str {
+    match value.classify() {
+        FpCategory::Nan => "NaN",
+        FpCategory::Infinite if value.is_sign_negative() => "-inf",
+        FpCategory::Infinite => "+inf",
+        FpCategory::Zero => "zero",
+        FpCategory::Subnormal => "subnormal",
+        FpCategory::Normal => "normal",
+    }
+}
+
+/// Configures a panic message builder for a unary float classification assertion
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the value
+/// * `value` - The value that was classified
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_float_classification_panic_message<FloatType: Float + Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: FloatType,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument("value", value_description, &value)?
+        .with_argument_formatted("classification", "--", describe_float_classification(value))
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_finite_impl<FloatType: Float>(value: FloatType) -> bool {
+    value.is_finite()
+}
+
+/// Asserts that a float value is finite (neither infinite nor NaN).
+///
+/// This works for any type implementing [`Float`](num_traits::Float), which includes `f32` and
+/// `f64`. The classification of the value is shown in the panic message if the assertion fails.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_finite;
+/// #
+/// assert_finite!(1.0 / 3.0);
+/// ```
+#[macro_export]
+macro_rules! assert_finite {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = $value;
+
+        $crate::assert_custom!(
+            "value is finite",
+            $crate::assertions::float::assert_finite_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_classification_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    value,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_nan_impl<FloatType: Float>(value: FloatType) -> bool {
+    value.is_nan()
+}
+
+/// Asserts that a float value is NaN.
+///
+/// This works for any type implementing [`Float`](num_traits::Float), which includes `f32` and
+/// `f64`. The classification of the value is shown in the panic message if the assertion fails.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_nan;
+/// #
+/// assert_nan!(0.0_f64 / 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_nan {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = $value;
+
+        $crate::assert_custom!(
+            "value is NaN",
+            $crate::assertions::float::assert_nan_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_classification_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    value,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_infinite_impl<FloatType: Float>(value: FloatType) -> bool {
+    value.is_infinite()
+}
+
+/// Asserts that a float value is infinite (either positive or negative).
+///
+/// This works for any type implementing [`Float`](num_traits::Float), which includes `f32` and
+/// `f64`. The classification of the value is shown in the panic message if the assertion fails.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_infinite;
+/// #
+/// assert_infinite!(1.0_f64 / 0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_infinite {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = $value;
+
+        $crate::assert_custom!(
+            "value is infinite",
+            $crate::assertions::float::assert_infinite_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_classification_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    value,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_normal_impl<FloatType: Float>(value: FloatType) -> bool {
+    value.is_normal()
+}
+
+/// Asserts that a float value is normal (neither zero, subnormal, infinite, nor NaN).
+///
+/// This works for any type implementing [`Float`](num_traits::Float), which includes `f32` and
+/// `f64`. The classification of the value is shown in the panic message if the assertion fails.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_normal;
+/// #
+/// assert_normal!(1.0_f64 / 3.0);
+/// ```
+#[macro_export]
+macro_rules! assert_normal {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = $value;
+
+        $crate::assert_custom!(
+            "value is normal",
+            $crate::assertions::float::assert_normal_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_classification_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    value,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// The maximum number of mismatching elements to list in the panic message of a float slice
+/// assertion.
+const MAX_REPORTED_SLICE_MISMATCH_COUNT: usize = 10;
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_float_slice_mismatches<FloatType: Copy>(
+    lhs: &[FloatType],
+    rhs: &[FloatType],
+    is_eq: impl Fn(FloatType, FloatType) -> bool,
+) -> Vec<(usize, FloatType, FloatType)> {
+    lhs.iter()
+        .zip(rhs)
+        .enumerate()
+        .filter(|(_, (lhs_element, rhs_element))| !is_eq(**lhs_element, **rhs_element))
+        .map(|(index, (lhs_element, rhs_element))| (index, *lhs_element, *rhs_element))
+        .collect()
+}
+
+/// Helper function to format the mismatching elements of a float slice assertion, one per line.
+///
+/// # Arguments
+///
+/// * `mismatches` - The index and values of each mismatching element.
+/// * `format_difference` - Formats the difference between two mismatching values.
+#[must_use]
+fn format_float_slice_mismatches<FloatType: Copy + Debug>(
+    mismatches: &[(usize, FloatType, FloatType)],
+    format_difference: impl Fn(FloatType, FloatType) -> String,
+) -> String {
+    let mut lines: Vec<String> = mismatches
+        .iter()
+        .take(MAX_REPORTED_SLICE_MISMATCH_COUNT)
+        .map(|(index, lhs_element, rhs_element)| {
+            format!(
+                "[{index}] lhs = {lhs_element:?}, rhs = {rhs_element:?}, {}",
+                format_difference(*lhs_element, *rhs_element)
+            )
+        })
+        .collect();
+
+    let remaining_mismatch_count = mismatches
+        .len()
+        .saturating_sub(MAX_REPORTED_SLICE_MISMATCH_COUNT);
+
+    if remaining_mismatch_count > 0 {
+        lines.push(format!("... ({remaining_mismatch_count} more mismatches)"));
+    }
+
+    lines.join("\n")
+}
+
+/// Helper function to configure the parts of a float slice assertion's panic message that do not
+/// depend on the tolerance.
+fn configure_float_slice_panic_message<FloatType: Copy + Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[FloatType],
+    rhs_description: &str,
+    rhs: &[FloatType],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument("lhs", lhs_description, &lhs)?
+        .with_argument("rhs", rhs_description, &rhs)?;
+
+    if lhs.len() == rhs.len() {
+        Ok(panic_message_builder)
+    } else {
+        panic_message_builder
+            .with_argument("lhs length", "--", &lhs.len())?
+            .with_argument("rhs length", "--", &rhs.len())
+    }
+}
+
+/// Configures a panic message builder for a float slice assertion using an ULPs tolerance
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The right-hand side of the comparison
+/// * `mismatches` - The index and values of each mismatching element
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_float_slice_panic_message_ulps<
+    UlpsType: Debug,
+    FloatType: Debug + FloatUlps<U = UlpsType>,
+>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[FloatType],
+    rhs_description: &str,
+    rhs: &[FloatType],
+    mismatches: &[(usize, FloatType, FloatType)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = configure_float_slice_panic_message(
+        panic_message_builder,
+        lhs_description,
+        lhs,
+        rhs_description,
+        rhs,
+    )?;
+
+    if mismatches.is_empty() {
+        return Ok(panic_message_builder);
+    }
+
+    panic_message_builder.with_argument_formatted(
+        "mismatches",
+        "--",
+        format_float_slice_mismatches(mismatches, |lhs_element, rhs_element| {
+            // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the
+            // warning.
+            #[allow(clippy::arithmetic_side_effects)]
+            let absolute_difference = (lhs_element - rhs_element).abs();
+
+            let ulps_difference = if lhs_element < rhs_element {
+                rhs_element.ulps(&lhs_element)
+            } else {
+                lhs_element.ulps(&rhs_element)
+            };
+
+            format!("absolute difference = {absolute_difference:?}, ulps = {ulps_difference:?}")
+        }),
+    )
+}
+
+/// Configures a panic message builder for a float slice assertion using a relative epsilon
+/// tolerance
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The right-hand side of the comparison
+/// * `mismatches` - The index and values of each mismatching element
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_float_slice_panic_message_relative<FloatType: Float + Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[FloatType],
+    rhs_description: &str,
+    rhs: &[FloatType],
+    mismatches: &[(usize, FloatType, FloatType)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = configure_float_slice_panic_message(
+        panic_message_builder,
+        lhs_description,
+        lhs,
+        rhs_description,
+        rhs,
+    )?;
+
+    if mismatches.is_empty() {
+        return Ok(panic_message_builder);
+    }
+
+    panic_message_builder.with_argument_formatted(
+        "mismatches",
+        "--",
+        format_float_slice_mismatches(mismatches, |lhs_element, rhs_element| {
+            // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the
+            // warning.
+            #[allow(clippy::arithmetic_side_effects)]
+            let absolute_difference = (lhs_element - rhs_element).abs();
+
+            format!("absolute difference = {absolute_difference:?}")
+        }),
+    )
+}
+
+/// Asserts that two slices of `f32` values are equal element-wise.
+///
+/// If the slices are not equal, the panic message lists the index, values, and difference of each
+/// mismatching element.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side, which can be anything that dereferences to a `f32` slice
+/// * `rhs` - The right-hand side, which can be anything that dereferences to a `f32` slice
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps`, `relative_epsilon`, and `epsilon_near_zero` must come before any other keyword
+/// arguments, with `epsilon_near_zero` last.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f32_slice_eq;
+/// #
+/// # let x: Vec<f32> = vec![1.0, 2.0, 3.0];
+/// #
+/// // Compare `x` to a slice using the default tolerances
+/// assert_f32_slice_eq!(x, [1.0, 2.0, 3.0]);
+///
+/// // Compare `x` to a slice within 2 ULPs
+/// assert_f32_slice_eq!(x, [1.0, 2.0, 3.0], ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to a slice within `f32::EPSILON`, relative to magnitude
+/// assert_f32_slice_eq!(x, [1.0, 2.0, 3.0], relative_epsilon = f32::EPSILON);
+/// ```
+#[macro_export]
+macro_rules! assert_f32_slice_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs: &[f32] = &$lhs;
+        let rhs: &[f32] = &$rhs;
+        let ulps: i32 = $ulps;
+        let epsilon_near_zero: f32 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_slice_mismatches(
+            lhs,
+            rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_ulps(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    ulps,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_ulps(
+                "==",
+                &ulps,
+                32,
+                epsilon_near_zero,
+            ),
+            lhs.len() == rhs.len() && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_slice_panic_message_ulps::<i32, f32>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    lhs,
+                    stringify!($rhs),
+                    rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs: &[f32] = &$lhs;
+        let rhs: &[f32] = &$rhs;
+        let relative_epsilon: f32 = $relative_epsilon;
+        let epsilon_near_zero: f32 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_slice_mismatches(
+            lhs,
+            rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_relative(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    relative_epsilon,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_relative(
+                "==",
+                relative_epsilon,
+                epsilon_near_zero,
+            ),
+            lhs.len() == rhs.len() && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_slice_panic_message_relative::<f32>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    lhs,
+                    stringify!($rhs),
+                    rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $ulps,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_slice_eq!(
+            $lhs,
+            $rhs,
+            relative_epsilon = $relative_epsilon,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F32,
+            epsilon_near_zero = $epsilon_near_zero
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F32,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+}
+
+/// Asserts that two slices of `f64` values are equal element-wise.
+///
+/// If the slices are not equal, the panic message lists the index, values, and difference of each
+/// mismatching element.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side, which can be anything that dereferences to a `f64` slice
+/// * `rhs` - The right-hand side, which can be anything that dereferences to a `f64` slice
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps`, `relative_epsilon`, and `epsilon_near_zero` must come before any other keyword
+/// arguments, with `epsilon_near_zero` last.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_slice_eq;
+/// #
+/// # let x: Vec<f64> = vec![1.0, 2.0, 3.0];
+/// #
+/// // Compare `x` to a slice using the default tolerances
+/// assert_f64_slice_eq!(x, [1.0, 2.0, 3.0]);
+///
+/// // Compare `x` to a slice within 2 ULPs
+/// assert_f64_slice_eq!(x, [1.0, 2.0, 3.0], ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to a slice within `f64::EPSILON`, relative to magnitude
+/// assert_f64_slice_eq!(x, [1.0, 2.0, 3.0], relative_epsilon = f64::EPSILON);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_slice_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs: &[f64] = &$lhs;
+        let rhs: &[f64] = &$rhs;
+        let ulps: i64 = $ulps;
+        let epsilon_near_zero: f64 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_slice_mismatches(
+            lhs,
+            rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_ulps(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    ulps,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_ulps(
+                "==",
+                &ulps,
+                64,
+                epsilon_near_zero,
+            ),
+            lhs.len() == rhs.len() && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_slice_panic_message_ulps::<i64, f64>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    lhs,
+                    stringify!($rhs),
+                    rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs: &[f64] = &$lhs;
+        let rhs: &[f64] = &$rhs;
+        let relative_epsilon: f64 = $relative_epsilon;
+        let epsilon_near_zero: f64 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_slice_mismatches(
+            lhs,
+            rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_relative(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    relative_epsilon,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_relative(
+                "==",
+                relative_epsilon,
+                epsilon_near_zero,
+            ),
+            lhs.len() == rhs.len() && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_slice_panic_message_relative::<f64>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    lhs,
+                    stringify!($rhs),
+                    rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $ulps,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_slice_eq!(
+            $lhs,
+            $rhs,
+            relative_epsilon = $relative_epsilon,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F64,
+            epsilon_near_zero = $epsilon_near_zero
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_slice_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F64,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn float_grid_rows<FloatType, Row: AsRef<[FloatType]>>(grid: &[Row]) -> Vec<&[FloatType]> {
+    grid.iter().map(AsRef::as_ref).collect()
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn is_float_grid_shape_eq<FloatType>(lhs: &[&[FloatType]], rhs: &[&[FloatType]]) -> bool {
+    lhs.len() == rhs.len()
+        && lhs
+            .iter()
+            .zip(rhs)
+            .all(|(lhs_row, rhs_row)| lhs_row.len() == rhs_row.len())
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_float_grid_mismatches<FloatType: Copy>(
+    lhs: &[&[FloatType]],
+    rhs: &[&[FloatType]],
+    is_eq: impl Fn(FloatType, FloatType) -> bool,
+) -> Vec<(usize, usize, FloatType, FloatType)> {
+    lhs.iter()
+        .zip(rhs)
+        .enumerate()
+        .flat_map(|(row, (lhs_row, rhs_row))| {
+            find_float_slice_mismatches(lhs_row, rhs_row, &is_eq)
+                .into_iter()
+                .map(move |(column, lhs_element, rhs_element)| {
+                    (row, column, lhs_element, rhs_element)
+                })
+        })
+        .collect()
+}
+
+/// Helper function to describe the shape of a float grid, like `"2 rows × 3 columns"`.
+#[must_use]
+fn describe_float_grid_shape<FloatType>(grid: &[&[FloatType]]) -> String {
+    match grid.first() {
+        Some(first_row) if grid.iter().all(|row| row.len() == first_row.len()) => {
+            format!("{} rows \u{d7} {} columns", grid.len(), first_row.len())
+        }
+        Some(_) => format!(
+            "{} rows with lengths {:?}",
+            grid.len(),
+            grid.iter().map(|row| row.len()).collect::<Vec<_>>()
+        ),
+        None => "0 rows".to_owned(),
+    }
+}
+
+/// Helper function to compute the ULP distance between two float values.
+fn float_ulps_distance<UlpsType, FloatType: FloatUlps<U = UlpsType>>(
+    lhs: FloatType,
+    rhs: FloatType,
+) -> UlpsType {
+    if lhs < rhs {
+        rhs.ulps(&lhs)
+    } else {
+        lhs.ulps(&rhs)
+    }
+}
+
+/// Helper function to configure the parts of a float grid assertion's panic message that do not
+/// depend on the tolerance.
+///
+/// Instead of showing both grids, this shows their shapes, the worst mismatching element, and
+/// statistics about the differences between all elements.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The rows of the left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The rows of the right-hand side of the comparison
+/// * `mismatches` - The row, column, and values of each mismatching element
+/// * `worst_mismatch` - The mismatch with the largest difference, if there are any
+fn configure_float_grid_panic_message<
+    UlpsType: Debug,
+    FloatType: Debug + FloatUlps<U = UlpsType>,
+>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[&[FloatType]],
+    rhs_description: &str,
+    rhs: &[&[FloatType]],
+    mismatches: &[(usize, usize, FloatType, FloatType)],
+    worst_mismatch: Option<&(usize, usize, FloatType, FloatType)>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument_formatted("lhs", lhs_description, describe_float_grid_shape(lhs))?
+        .with_argument_formatted("rhs", rhs_description, describe_float_grid_shape(rhs))?;
+
+    let Some((row, column, worst_lhs, worst_rhs)) = worst_mismatch else {
+        return Ok(panic_message_builder);
+    };
+
+    // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+    #[allow(clippy::arithmetic_side_effects)]
+    let absolute_difference =
+        |lhs_element: FloatType, rhs_element: FloatType| (lhs_element - rhs_element).abs();
+
+    let absolute_differences: Vec<FloatType> = lhs
+        .iter()
+        .zip(rhs)
+        .flat_map(|(lhs_row, rhs_row)| lhs_row.iter().zip(*rhs_row))
+        .map(|(lhs_element, rhs_element)| absolute_difference(*lhs_element, *rhs_element))
+        .collect();
+
+    let max_absolute_difference = absolute_differences
+        .iter()
+        .copied()
+        .fold(FloatType::zero(), FloatType::max);
+
+    // The element count is converted to the float type to compute the mean, so precision loss is
+    // expected for huge grids. Neither `f32` or `f64` implement `num_traits::CheckedAdd` or
+    // `num_traits::CheckedDiv` either.
+    #[allow(clippy::arithmetic_side_effects)]
+    let mean_absolute_difference = absolute_differences
+        .iter()
+        .copied()
+        .fold(FloatType::zero(), |sum, difference| sum + difference)
+        / FloatType::from(absolute_differences.len()).unwrap_or_else(FloatType::nan);
+
+    panic_message_builder
+        .with_argument_formatted(
+            "worst element",
+            "--",
+            format!(
+                "({row}, {column}) lhs = {worst_lhs:?}, rhs = {worst_rhs:?}, \
+                 absolute difference = {:?}, ulps = {:?}",
+                absolute_difference(*worst_lhs, *worst_rhs),
+                float_ulps_distance(*worst_lhs, *worst_rhs)
+            ),
+        )?
+        .with_argument_formatted(
+            "mismatches",
+            "--",
+            format!(
+                "{} of {} elements",
+                mismatches.len(),
+                absolute_differences.len()
+            ),
+        )?
+        .with_argument("max absolute difference", "--", &max_absolute_difference)?
+        .with_argument("mean absolute difference", "--", &mean_absolute_difference)
+}
+
+/// Configures a panic message builder for a float grid assertion using an ULPs tolerance
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The rows of the left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The rows of the right-hand side of the comparison
+/// * `mismatches` - The row, column, and values of each mismatching element
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_float_grid_panic_message_ulps<
+    UlpsType: Debug + Ord,
+    FloatType: Debug + FloatUlps<U = UlpsType>,
+>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[&[FloatType]],
+    rhs_description: &str,
+    rhs: &[&[FloatType]],
+    mismatches: &[(usize, usize, FloatType, FloatType)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let worst_mismatch = mismatches
+        .iter()
+        .max_by_key(|(_, _, lhs_element, rhs_element)| {
+            float_ulps_distance(*lhs_element, *rhs_element)
+        });
+
+    configure_float_grid_panic_message(
+        panic_message_builder,
+        lhs_description,
+        lhs,
+        rhs_description,
+        rhs,
+        mismatches,
+        worst_mismatch,
+    )
+}
+
+/// Configures a panic message builder for a float grid assertion using a relative epsilon
+/// tolerance
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The rows of the left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The rows of the right-hand side of the comparison
+/// * `mismatches` - The row, column, and values of each mismatching element
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_float_grid_panic_message_relative<
+    UlpsType: Debug,
+    FloatType: Debug + FloatUlps<U = UlpsType>,
+>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[&[FloatType]],
+    rhs_description: &str,
+    rhs: &[&[FloatType]],
+    mismatches: &[(usize, usize, FloatType, FloatType)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+    #[allow(clippy::arithmetic_side_effects)]
+    let worst_mismatch = mismatches.iter().max_by(
+        |(_, _, lhs_element_a, rhs_element_a), (_, _, lhs_element_b, rhs_element_b)| {
+            (*lhs_element_a - *rhs_element_a)
+                .abs()
+                .partial_cmp(&(*lhs_element_b - *rhs_element_b).abs())
+                .unwrap_or(Ordering::Equal)
+        },
+    );
+
+    configure_float_grid_panic_message(
+        panic_message_builder,
+        lhs_description,
+        lhs,
+        rhs_description,
+        rhs,
+        mismatches,
+        worst_mismatch,
+    )
+}
+
+/// Asserts that two grids of `f32` values, like `[[f32; N]; M]` or `Vec<Vec<f32>>`, are equal
+/// element-wise.
+///
+/// If the grids are not equal, the panic message shows the row and column of the worst mismatching
+/// element with its ULP distance, along with the maximum and mean absolute difference of all
+/// elements, instead of both grids.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side, which can be an array, slice, or vector of rows that
+///   each implement `AsRef<[f32]>`
+/// * `rhs` - The right-hand side, which can be an array, slice, or vector of rows that
+///   each implement `AsRef<[f32]>`
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F32`](crate::assertions::float::DEFAULT_ULPS_F32) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F32`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32))
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps`, `relative_epsilon`, and `epsilon_near_zero` must come before any other keyword
+/// arguments, with `epsilon_near_zero` last.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f32_grid_eq;
+/// #
+/// # let x: Vec<Vec<f32>> = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+/// #
+/// // Compare `x` to a grid using the default tolerances
+/// assert_f32_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]]);
+///
+/// // Compare `x` to a grid within 2 ULPs
+/// assert_f32_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]], ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to a grid within `f32::EPSILON`, relative to magnitude
+/// assert_f32_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]], relative_epsilon = f32::EPSILON);
+/// ```
+#[macro_export]
+macro_rules! assert_f32_grid_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs_grid = &$lhs;
+        let lhs = $crate::assertions::float::float_grid_rows::<f32, _>(lhs_grid);
+        let rhs_grid = &$rhs;
+        let rhs = $crate::assertions::float::float_grid_rows::<f32, _>(rhs_grid);
+        let ulps: i32 = $ulps;
+        let epsilon_near_zero: f32 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_grid_mismatches(
+            &lhs,
+            &rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_ulps(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    ulps,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_ulps(
+                "==",
+                &ulps,
+                32,
+                epsilon_near_zero,
+            ),
+            $crate::assertions::float::is_float_grid_shape_eq(&lhs, &rhs) && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_grid_panic_message_ulps::<i32, f32>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    &lhs,
+                    stringify!($rhs),
+                    &rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs_grid = &$lhs;
+        let lhs = $crate::assertions::float::float_grid_rows::<f32, _>(lhs_grid);
+        let rhs_grid = &$rhs;
+        let rhs = $crate::assertions::float::float_grid_rows::<f32, _>(rhs_grid);
+        let relative_epsilon: f32 = $relative_epsilon;
+        let epsilon_near_zero: f32 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_grid_mismatches(
+            &lhs,
+            &rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_relative(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    relative_epsilon,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_relative(
+                "==",
+                relative_epsilon,
+                epsilon_near_zero,
+            ),
+            $crate::assertions::float::is_float_grid_shape_eq(&lhs, &rhs) && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_grid_panic_message_relative::<i32, f32>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    &lhs,
+                    stringify!($rhs),
+                    &rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $ulps,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_grid_eq!(
+            $lhs,
+            $rhs,
+            relative_epsilon = $relative_epsilon,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F32,
+            epsilon_near_zero = $epsilon_near_zero
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f32_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F32,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F32
+            $(, $keys = $values)*
+        )
+    };
+}
+
+/// Asserts that two grids of `f64` values, like `[[f64; N]; M]` or `Vec<Vec<f64>>`, are equal
+/// element-wise.
+///
+/// If the grids are not equal, the panic message shows the row and column of the worst mismatching
+/// element with its ULP distance, along with the maximum and mean absolute difference of all
+/// elements, instead of both grids.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side, which can be an array, slice, or vector of rows that
+///   each implement `AsRef<[f64]>`
+/// * `rhs` - The right-hand side, which can be an array, slice, or vector of rows that
+///   each implement `AsRef<[f64]>`
+/// * Can optionally use one of (defaults to
+///   [`DEFAULT_ULPS_F64`](crate::assertions::float::DEFAULT_ULPS_F64) ULPs):
+///     * `ulps = <value>` - The number of ULPs to use for tolerance
+///     * `relative_epsilon = <value>` - The epsilon to use for tolerance relative to the magnitude
+/// * `epsilon_near_zero = <value>` - Optional absolute tolerance to use near zero (defaults to
+///   [`DEFAULT_EPSILON_NEAR_ZERO_F64`](crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64))
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `ulps`, `relative_epsilon`, and `epsilon_near_zero` must come before any other keyword
+/// arguments, with `epsilon_near_zero` last.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_f64_grid_eq;
+/// #
+/// # let x: Vec<Vec<f64>> = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+/// #
+/// // Compare `x` to a grid using the default tolerances
+/// assert_f64_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]]);
+///
+/// // Compare `x` to a grid within 2 ULPs
+/// assert_f64_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]], ulps = 2, epsilon_near_zero = 0.0);
+///
+/// // Compare `x` to a grid within `f64::EPSILON`, relative to magnitude
+/// assert_f64_grid_eq!(x, [[1.0, 2.0], [3.0, 4.0]], relative_epsilon = f64::EPSILON);
+/// ```
+#[macro_export]
+macro_rules! assert_f64_grid_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs_grid = &$lhs;
+        let lhs = $crate::assertions::float::float_grid_rows::<f64, _>(lhs_grid);
+        let rhs_grid = &$rhs;
+        let rhs = $crate::assertions::float::float_grid_rows::<f64, _>(rhs_grid);
+        let ulps: i64 = $ulps;
+        let epsilon_near_zero: f64 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_grid_mismatches(
+            &lhs,
+            &rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_ulps(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    ulps,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_ulps(
+                "==",
+                &ulps,
+                64,
+                epsilon_near_zero,
+            ),
+            $crate::assertions::float::is_float_grid_shape_eq(&lhs, &rhs) && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_grid_panic_message_ulps::<i64, f64>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    &lhs,
+                    stringify!($rhs),
+                    &rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs_grid = &$lhs;
+        let lhs = $crate::assertions::float::float_grid_rows::<f64, _>(lhs_grid);
+        let rhs_grid = &$rhs;
+        let rhs = $crate::assertions::float::float_grid_rows::<f64, _>(rhs_grid);
+        let relative_epsilon: f64 = $relative_epsilon;
+        let epsilon_near_zero: f64 = $epsilon_near_zero;
+
+        let mismatches = $crate::assertions::float::find_float_grid_mismatches(
+            &lhs,
+            &rhs,
+            |lhs_element, rhs_element| {
+                $crate::assertions::float::assert_float_impl_relative(
+                    $crate::assertions::float::FloatComparison::Eq,
+                    lhs_element,
+                    rhs_element,
+                    epsilon_near_zero,
+                    relative_epsilon,
+                )
+            },
+        );
+
+        $crate::assert_custom!(
+            $crate::assertions::float::format_float_predicate_description_relative(
+                "==",
+                relative_epsilon,
+                epsilon_near_zero,
+            ),
+            $crate::assertions::float::is_float_grid_shape_eq(&lhs, &rhs) && mismatches.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::float::configure_float_grid_panic_message_relative::<i64, f64>(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    &lhs,
+                    stringify!($rhs),
+                    &rhs,
+                    &mismatches,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ulps = $ulps:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $ulps,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        relative_epsilon = $relative_epsilon:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_grid_eq!(
+            $lhs,
+            $rhs,
+            relative_epsilon = $relative_epsilon,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        epsilon_near_zero = $epsilon_near_zero:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F64,
+            epsilon_near_zero = $epsilon_near_zero
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_f64_grid_eq!(
+            $lhs,
+            $rhs,
+            ulps = $crate::assertions::float::DEFAULT_ULPS_F64,
+            epsilon_near_zero = $crate::assertions::float::DEFAULT_EPSILON_NEAR_ZERO_F64
+            $(, $keys = $values)*
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_eq, utilities::assertion_failure::capture_assertion_failure};
+    use std::cell::Cell;
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_infinity() {
+        assert_eq!(
+            is_float_eq_non_finite(f32::INFINITY, f32::INFINITY),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_neg_infinity() {
+        assert_eq!(
+            is_float_eq_non_finite(f32::INFINITY, -f32::INFINITY),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_nan() {
+        assert_eq!(is_float_eq_non_finite(f32::INFINITY, f32::NAN), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_five() {
+        assert_eq!(is_float_eq_non_finite(f32::INFINITY, 5.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_zero() {
+        assert_eq!(is_float_eq_non_finite(f32::INFINITY, 0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_infinity_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(f32::INFINITY, -0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_infinity() {
+        assert_eq!(
+            is_float_eq_non_finite(-f32::INFINITY, f32::INFINITY),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_neg_infinity() {
+        assert_eq!(
+            is_float_eq_non_finite(-f32::INFINITY, -f32::INFINITY),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_nan() {
+        assert_eq!(
+            is_float_eq_non_finite(-f32::INFINITY, f32::NAN),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_five() {
+        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, 5.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_zero() {
+        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, 0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_infinity_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, -0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_infinity() {
+        assert_eq!(is_float_eq_non_finite(f32::NAN, f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_neg_infinity() {
+        assert_eq!(
+            is_float_eq_non_finite(f32::NAN, -f32::INFINITY),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_nan() {
+        assert_eq!(is_float_eq_non_finite(f32::NAN, f32::NAN), Some(true));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_five() {
+        assert_eq!(is_float_eq_non_finite(f32::NAN, 5.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_zero() {
+        assert_eq!(is_float_eq_non_finite(f32::NAN, 0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_nan_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(f32::NAN, -0.0), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_infinity() {
+        assert_eq!(is_float_eq_non_finite(5.0, f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_neg_infinity() {
+        assert_eq!(is_float_eq_non_finite(5.0, -f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_nan() {
+        assert_eq!(is_float_eq_non_finite(5.0, f32::NAN), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_five() {
+        assert_eq!(is_float_eq_non_finite(5.0, 5.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_zero() {
+        assert_eq!(is_float_eq_non_finite(5.0, 0.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_five_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(5.0, -0.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_infinity() {
+        assert_eq!(is_float_eq_non_finite(0.0, f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_neg_infinity() {
+        assert_eq!(is_float_eq_non_finite(0.0, -f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_nan() {
+        assert_eq!(is_float_eq_non_finite(0.0, f32::NAN), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_five() {
+        assert_eq!(is_float_eq_non_finite(0.0, 5.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_zero() {
+        assert_eq!(is_float_eq_non_finite(0.0, 0.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_zero_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(0.0, -0.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_infinity() {
+        assert_eq!(is_float_eq_non_finite(-0.0, f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_neg_infinity() {
+        assert_eq!(is_float_eq_non_finite(-0.0, -f32::INFINITY), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_nan() {
+        assert_eq!(is_float_eq_non_finite(-0.0, f32::NAN), Some(false));
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_five() {
+        assert_eq!(is_float_eq_non_finite(-0.0, 5.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_zero() {
+        assert_eq!(is_float_eq_non_finite(-0.0, 0.0), None::<bool>);
+    }
+
+    #[test]
+    fn is_float_eq_non_finite_f32_neg_zero_neg_zero() {
+        assert_eq!(is_float_eq_non_finite(-0.0, -0.0), None::<bool>);
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_simple() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &0, 32, 0.0).to_string(),
+            "lhs == rhs (within 0 32-bit float ulps)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_operator() {
+        assert_eq!(
+            format_float_predicate_description_ulps("!=", &0, 32, 0.0).to_string(),
+            "lhs != rhs (within 0 32-bit float ulps)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_ulps_tolerance_1() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &1, 32, 0.0).to_string(),
+            "lhs == rhs (within 1 32-bit float ulp)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_ulps_tolerance_2() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &2, 32, 0.0).to_string(),
+            "lhs == rhs (within 2 32-bit float ulps)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_bit_width() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &0, 64, 0.0).to_string(),
+            "lhs == rhs (within 0 64-bit float ulps)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_epsilon_near_zero_e_neg_30() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &0, 32, 1e-30).to_string(),
+            "lhs == rhs (within 0 32-bit float ulps or 1e-30 near zero)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_ulps_epsilon_near_zero_1() {
+        assert_eq!(
+            format_float_predicate_description_ulps("==", &0, 32, 1.0).to_string(),
+            "lhs == rhs (within 0 32-bit float ulps or 1.0 near zero)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_simple() {
+        assert_eq!(
+            format_float_predicate_description_relative("==", 0.0, 0.0).to_string(),
+            "lhs == rhs (within 0.0 relative to magnitude)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_operator() {
+        assert_eq!(
+            format_float_predicate_description_relative("!=", 0.0, 0.0).to_string(),
+            "lhs != rhs (within 0.0 relative to magnitude)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_relative_epsilon_e_neg_30() {
+        assert_eq!(
+            format_float_predicate_description_relative("==", 1e-30, 0.0).to_string(),
+            "lhs == rhs (within 1e-30 relative to magnitude)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_relative_epsilon_1() {
+        assert_eq!(
+            format_float_predicate_description_relative("==", 1.0, 0.0).to_string(),
+            "lhs == rhs (within 1.0 relative to magnitude)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_epsilon_near_zero_e_neg_30() {
+        assert_eq!(
+            format_float_predicate_description_relative("==", 0.0, 1e-30).to_string(),
+            "lhs == rhs (within 0.0 relative to magnitude or 1e-30 near zero)"
+        );
+    }
+
+    #[test]
+    fn format_float_predicate_description_relative_epsilon_near_zero_1() {
+        assert_eq!(
+            format_float_predicate_description_relative("==", 0.0, 1.0).to_string(),
+            "lhs == rhs (within 0.0 relative to magnitude or 1.0 near zero)"
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps() {
+        assert_f32_eq!(1.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_1_ulps() {
+        assert_f32_eq!(1.0, 1.000_000_1, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps() {
+        assert_f32_eq!(1.0, 1.000_000_2, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_2_ulps() {
+        assert_f32_eq!(1.0, 1.000_000_2, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps() {
+        assert_f32_eq!(1.0, 1.000_000_3, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact() {
+        assert_f32_eq!(1.0, 1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact() {
+        assert_f32_eq!(
+            1.0,
+            1.000_000_1,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon() {
+        assert_f32_eq!(
+            0.5,
+            0.999_999_94,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon() {
+        assert_f32_eq!(0.5, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon() {
+        assert_f32_eq!(
+            0.5,
+            1.000_000_1,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_000.0,
+            ulps = 0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_1_ulps_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_060.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_100.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_2_ulps_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_100.0,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_200.0,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_000.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact_large() {
+        assert_f32_eq!(
+            1_000_000_000.0,
+            1_000_000_060.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_large() {
+        assert_f32_eq!(
+            500_000_000.0,
+            999_999_940.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon_large() {
+        assert_f32_eq!(
+            500_000_000.0,
+            1_000_000_000.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_large() {
+        assert_f32_eq!(
+            500_000_000.0,
+            1_000_000_060.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_near_zero() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_1_ulps_near_zero() {
+        assert_f32_eq!(0.0, 1e-45, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps_near_zero() {
+        assert_f32_eq!(0.0, 3e-45, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_2_ulps_near_zero() {
+        assert_f32_eq!(0.0, 3e-45, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps_near_zero() {
+        assert_f32_eq!(0.0, 4e-45, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact_near_zero() {
+        assert_f32_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact_near_zero() {
+        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_near_zero() {
+        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon_near_zero() {
+        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_near_zero() {
+        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_exact() {
+        assert_f32_eq!(0.0, 1e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps or 1e-45 near zero)")]
+    fn assert_f32_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above() {
+        assert_f32_eq!(0.0, 3e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_absolute_epsilon_looser() {
+        assert_f32_eq!(0.0, 3e-45, ulps = 1, epsilon_near_zero = 3e-45);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_negative() {
+        assert_f32_eq!(-1.0, -1.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_1_ulps_negative() {
+        assert_f32_eq!(-1.0, -1.000_000_1, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps_negative() {
+        assert_f32_eq!(-1.0, -1.000_000_2, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_2_ulps_negative() {
+        assert_f32_eq!(-1.0, -1.000_000_2, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps_negative() {
+        assert_f32_eq!(-1.0, -1.000_000_3, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact_negative() {
+        assert_f32_eq!(-1.0, -1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact_negative() {
+        assert_f32_eq!(
+            -1.0,
+            -1.000_000_1,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_negative() {
+        assert_f32_eq!(
+            -0.5,
+            -0.999_999_94,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon_negative() {
+        assert_f32_eq!(-0.5, -1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_negative() {
+        assert_f32_eq!(
+            -0.5,
+            -1.000_000_1,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.0,
+            ulps = 0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_1_ulps_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_060.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_100.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_2_ulps_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_100.0,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_200.0,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact_large_negative() {
+        assert_f32_eq!(
+            -1_000_000_000.0,
+            -1_000_000_060.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_large_negative() {
+        assert_f32_eq!(
+            -500_000_000.0,
+            -999_999_940.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon_large_negative() {
+        assert_f32_eq!(
+            -500_000_000.0,
+            -1_000_000_000.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_large_negative() {
+        assert_f32_eq!(
+            -500_000_000.0,
+            -1_000_000_060.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_near_zero_negative() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_ulps_1_ulps_near_zero_negative() {
+        assert_f32_eq!(0.0, -1e-45, ulps = 1, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_2_ulps_near_zero_negative() {
+        assert_f32_eq!(0.0, -3e-45, ulps = 2, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_exact_near_zero_negative() {
+        assert_f32_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_exact_near_zero_negative() {
+        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_near_zero_negative() {
+        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_relative_at_epsilon_near_zero_negative() {
+        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_near_zero_negative() {
+        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below_negative() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_exact_negative() {
+        assert_f32_eq!(0.0, -1e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps or 1e-45 near zero)")]
+    fn assert_f32_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above_negative() {
+        assert_f32_eq!(0.0, -3e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    }
+
+    #[test]
+    fn assert_f32_eq_passing_absolute_epsilon_looser_negative() {
+        assert_f32_eq!(0.0, -3e-45, ulps = 1, epsilon_near_zero = 3e-45);
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_ulps_0_ulps() {
+        assert_f64_eq!(1.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_ulps_1_ulps() {
+        assert_f64_eq!(
+            1.0,
+            1.000_000_000_000_000_2,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps() {
+        assert_f64_eq!(
+            1.0,
+            1.000_000_000_000_000_4,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_ulps_2_ulps() {
+        assert_f64_eq!(
+            1.0,
+            1.000_000_000_000_000_4,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps() {
+        assert_f64_eq!(
+            1.0,
+            1.000_000_000_000_000_7,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_relative_exact() {
+        assert_f64_eq!(1.0, 1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact() {
+        assert_f64_eq!(
+            1.0,
+            1.000_000_000_000_000_2,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon() {
+        assert_f64_eq!(
+            0.5,
+            0.999_999_999_999_999_9,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_relative_at_epsilon() {
+        assert_f64_eq!(0.5, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon() {
+        assert_f64_eq!(
+            0.5,
+            1.000_000_000_000_000_2,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_ulps_0_ulps_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.0,
+            ulps = 0,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    fn assert_f64_eq_passing_ulps_1_ulps_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.000_000_1,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.000_000_2,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
+    }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_infinity() {
-        assert_eq!(
-            is_float_eq_non_finite(f32::INFINITY, f32::INFINITY),
-            Some(true)
+    fn assert_f64_eq_passing_ulps_2_ulps_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.000_000_2,
+            ulps = 2,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_neg_infinity() {
-        assert_eq!(
-            is_float_eq_non_finite(f32::INFINITY, -f32::INFINITY),
-            Some(false)
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.000_000_4,
+            ulps = 2,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_nan() {
-        assert_eq!(is_float_eq_non_finite(f32::INFINITY, f32::NAN), Some(false));
+    fn assert_f64_eq_passing_relative_exact_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_five() {
-        assert_eq!(is_float_eq_non_finite(f32::INFINITY, 5.0), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact_large() {
+        assert_f64_eq!(
+            1_000_000_000.0,
+            1_000_000_000.000_000_1,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_zero() {
-        assert_eq!(is_float_eq_non_finite(f32::INFINITY, 0.0), Some(false));
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_large() {
+        assert_f64_eq!(
+            500_000_000.0,
+            999_999_999.999_999_9,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_infinity_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(f32::INFINITY, -0.0), Some(false));
+    fn assert_f64_eq_passing_relative_at_epsilon_large() {
+        assert_f64_eq!(
+            500_000_000.0,
+            1_000_000_000.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_infinity() {
-        assert_eq!(
-            is_float_eq_non_finite(-f32::INFINITY, f32::INFINITY),
-            Some(false)
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_large() {
+        assert_f64_eq!(
+            500_000_000.0,
+            1_000_000_000.000_000_1,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_neg_infinity() {
-        assert_eq!(
-            is_float_eq_non_finite(-f32::INFINITY, -f32::INFINITY),
-            Some(true)
-        );
+    fn assert_f64_eq_passing_ulps_0_ulps_near_zero() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_nan() {
-        assert_eq!(
-            is_float_eq_non_finite(-f32::INFINITY, f32::NAN),
-            Some(false)
-        );
+    fn assert_f64_eq_passing_ulps_1_ulps_near_zero() {
+        assert_f64_eq!(0.0, 5e-324, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_five() {
-        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, 5.0), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps_near_zero() {
+        assert_f64_eq!(0.0, 1e-323, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_zero() {
-        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, 0.0), Some(false));
+    fn assert_f64_eq_passing_ulps_2_ulps_near_zero() {
+        assert_f64_eq!(0.0, 1e-323, ulps = 2, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_infinity_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(-f32::INFINITY, -0.0), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps_near_zero() {
+        assert_f64_eq!(0.0, 1.5e-323, ulps = 2, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_infinity() {
-        assert_eq!(is_float_eq_non_finite(f32::NAN, f32::INFINITY), Some(false));
+    fn assert_f64_eq_passing_relative_exact_near_zero() {
+        assert_f64_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_neg_infinity() {
-        assert_eq!(
-            is_float_eq_non_finite(f32::NAN, -f32::INFINITY),
-            Some(false)
-        );
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact_near_zero() {
+        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_nan() {
-        assert_eq!(is_float_eq_non_finite(f32::NAN, f32::NAN), Some(true));
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_near_zero() {
+        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_five() {
-        assert_eq!(is_float_eq_non_finite(f32::NAN, 5.0), Some(false));
+    fn assert_f64_eq_passing_relative_at_epsilon_near_zero() {
+        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_zero() {
-        assert_eq!(is_float_eq_non_finite(f32::NAN, 0.0), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_near_zero() {
+        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_nan_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(f32::NAN, -0.0), Some(false));
+    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_infinity() {
-        assert_eq!(is_float_eq_non_finite(5.0, f32::INFINITY), Some(false));
+    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_exact() {
+        assert_f64_eq!(0.0, 5e-324, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_neg_infinity() {
-        assert_eq!(is_float_eq_non_finite(5.0, -f32::INFINITY), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps or 5e-324 near zero)")]
+    fn assert_f64_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above() {
+        assert_f64_eq!(0.0, 1e-323, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_nan() {
-        assert_eq!(is_float_eq_non_finite(5.0, f32::NAN), Some(false));
+    fn assert_f64_eq_passing_absolute_epsilon_looser() {
+        assert_f64_eq!(0.0, 1e-323, ulps = 1, epsilon_near_zero = 1e-323);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_five() {
-        assert_eq!(is_float_eq_non_finite(5.0, 5.0), None::<bool>);
+    fn assert_f64_eq_passing_ulps_0_ulps_negative() {
+        assert_f64_eq!(-1.0, -1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_zero() {
-        assert_eq!(is_float_eq_non_finite(5.0, 0.0), None::<bool>);
+    fn assert_f64_eq_passing_ulps_1_ulps_negative() {
+        assert_f64_eq!(
+            -1.0,
+            -1.000_000_000_000_000_2,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_five_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(5.0, -0.0), None::<bool>);
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps_negative() {
+        assert_f64_eq!(
+            -1.0,
+            -1.000_000_000_000_000_4,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_infinity() {
-        assert_eq!(is_float_eq_non_finite(0.0, f32::INFINITY), Some(false));
+    fn assert_f64_eq_passing_ulps_2_ulps_negative() {
+        assert_f64_eq!(
+            -1.0,
+            -1.000_000_000_000_000_4,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_neg_infinity() {
-        assert_eq!(is_float_eq_non_finite(0.0, -f32::INFINITY), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps_negative() {
+        assert_f64_eq!(
+            -1.0,
+            -1.000_000_000_000_000_7,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_nan() {
-        assert_eq!(is_float_eq_non_finite(0.0, f32::NAN), Some(false));
+    fn assert_f64_eq_passing_relative_exact_negative() {
+        assert_f64_eq!(-1.0, -1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_five() {
-        assert_eq!(is_float_eq_non_finite(0.0, 5.0), None::<bool>);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact_negative() {
+        assert_f64_eq!(
+            -1.0,
+            -1.000_000_000_000_000_2,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_zero() {
-        assert_eq!(is_float_eq_non_finite(0.0, 0.0), None::<bool>);
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_negative() {
+        assert_f64_eq!(
+            -0.5,
+            -0.999_999_999_999_999_9,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_zero_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(0.0, -0.0), None::<bool>);
+    fn assert_f64_eq_passing_relative_at_epsilon_negative() {
+        assert_f64_eq!(-0.5, -1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_infinity() {
-        assert_eq!(is_float_eq_non_finite(-0.0, f32::INFINITY), Some(false));
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_negative() {
+        assert_f64_eq!(
+            -0.5,
+            -1.000_000_000_000_000_2,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_neg_infinity() {
-        assert_eq!(is_float_eq_non_finite(-0.0, -f32::INFINITY), Some(false));
+    fn assert_f64_eq_passing_ulps_0_ulps_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.0,
+            ulps = 0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_nan() {
-        assert_eq!(is_float_eq_non_finite(-0.0, f32::NAN), Some(false));
+    fn assert_f64_eq_passing_ulps_1_ulps_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.000_000_1,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_five() {
-        assert_eq!(is_float_eq_non_finite(-0.0, 5.0), None::<bool>);
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.000_000_2,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_zero() {
-        assert_eq!(is_float_eq_non_finite(-0.0, 0.0), None::<bool>);
+    fn assert_f64_eq_passing_ulps_2_ulps_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.000_000_2,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn is_float_eq_non_finite_f32_neg_zero_neg_zero() {
-        assert_eq!(is_float_eq_non_finite(-0.0, -0.0), None::<bool>);
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.000_000_4,
+            ulps = 2,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_simple() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &0, 32, 0.0),
-            "lhs == rhs (within 0 32-bit float ulps)"
+    fn assert_f64_eq_passing_relative_exact_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_operator() {
-        assert_eq!(
-            format_float_predicate_description_ulps("!=", &0, 32, 0.0),
-            "lhs != rhs (within 0 32-bit float ulps)"
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact_large_negative() {
+        assert_f64_eq!(
+            -1_000_000_000.0,
+            -1_000_000_000.000_000_1,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_ulps_tolerance_1() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &1, 32, 0.0),
-            "lhs == rhs (within 1 32-bit float ulp)"
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_large_negative() {
+        assert_f64_eq!(
+            -500_000_000.0,
+            -999_999_999.999_999_9,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_ulps_tolerance_2() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &2, 32, 0.0),
-            "lhs == rhs (within 2 32-bit float ulps)"
+    fn assert_f64_eq_passing_relative_at_epsilon_large_negative() {
+        assert_f64_eq!(
+            -500_000_000.0,
+            -1_000_000_000.0,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_bit_width() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &0, 64, 0.0),
-            "lhs == rhs (within 0 64-bit float ulps)"
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_large_negative() {
+        assert_f64_eq!(
+            -500_000_000.0,
+            -1_000_000_000.000_000_1,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_epsilon_near_zero_e_neg_30() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &0, 32, 1e-30),
-            "lhs == rhs (within 0 32-bit float ulps or 1e-30 near zero)"
-        );
+    fn assert_f64_eq_passing_ulps_0_ulps_near_zero_negative() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn format_float_predicate_description_ulps_epsilon_near_zero_1() {
-        assert_eq!(
-            format_float_predicate_description_ulps("==", &0, 32, 1.0),
-            "lhs == rhs (within 0 32-bit float ulps or 1.0 near zero)"
-        );
+    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_eq_failing_ulps_1_ulps_near_zero_negative() {
+        assert_f64_eq!(0.0, -5e-324, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn format_float_predicate_description_relative_simple() {
-        assert_eq!(
-            format_float_predicate_description_relative("==", 0.0, 0.0),
-            "lhs == rhs (within 0.0 relative to magnitude)"
-        );
+    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_2_ulps_near_zero_negative() {
+        assert_f64_eq!(0.0, -1e-323, ulps = 2, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn format_float_predicate_description_relative_operator() {
-        assert_eq!(
-            format_float_predicate_description_relative("!=", 0.0, 0.0),
-            "lhs != rhs (within 0.0 relative to magnitude)"
-        );
+    fn assert_f64_eq_passing_relative_exact_near_zero_negative() {
+        assert_f64_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn format_float_predicate_description_relative_relative_epsilon_e_neg_30() {
-        assert_eq!(
-            format_float_predicate_description_relative("==", 1e-30, 0.0),
-            "lhs == rhs (within 1e-30 relative to magnitude)"
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_exact_near_zero_negative() {
+        assert_f64_eq!(
+            0.0,
+            -5e-324,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_relative_relative_epsilon_1() {
-        assert_eq!(
-            format_float_predicate_description_relative("==", 1.0, 0.0),
-            "lhs == rhs (within 1.0 relative to magnitude)"
+    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_near_zero_negative() {
+        assert_f64_eq!(
+            0.0,
+            -5e-324,
+            relative_epsilon = 2.0,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_relative_epsilon_near_zero_e_neg_30() {
-        assert_eq!(
-            format_float_predicate_description_relative("==", 0.0, 1e-30),
-            "lhs == rhs (within 0.0 relative to magnitude or 1e-30 near zero)"
+    fn assert_f64_eq_passing_relative_at_epsilon_near_zero_negative() {
+        assert_f64_eq!(
+            0.0,
+            -5e-324,
+            relative_epsilon = 1.0,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn format_float_predicate_description_relative_epsilon_near_zero_1() {
-        assert_eq!(
-            format_float_predicate_description_relative("==", 0.0, 1.0),
-            "lhs == rhs (within 0.0 relative to magnitude or 1.0 near zero)"
+    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_near_zero_negative() {
+        assert_f64_eq!(
+            0.0,
+            -5e-324,
+            relative_epsilon = 0.5,
+            epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps() {
-        assert_f32_eq!(1.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below_negative() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_1_ulps() {
-        assert_f32_eq!(1.0, 1.000_000_1, ulps = 1, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_exact_negative() {
+        assert_f64_eq!(0.0, -5e-324, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps() {
-        assert_f32_eq!(1.0, 1.000_000_2, ulps = 1, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps or 5e-324 near zero)")]
+    fn assert_f64_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above_negative() {
+        assert_f64_eq!(0.0, -1e-323, ulps = 0, epsilon_near_zero = 5e-324);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_2_ulps() {
-        assert_f32_eq!(1.0, 1.000_000_2, ulps = 2, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_absolute_epsilon_looser_negative() {
+        assert_f64_eq!(0.0, -1e-323, ulps = 1, epsilon_near_zero = 1e-323);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps() {
-        assert_f32_eq!(1.0, 1.000_000_3, ulps = 2, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_passing_simple() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact() {
-        assert_f32_eq!(1.0, 1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_simple() {
+        assert_f32_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact() {
-        assert_f32_eq!(
-            1.0,
-            1.000_000_1,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ne_passing_simple() {
+        assert_f32_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon() {
-        assert_f32_eq!(
-            0.5,
-            0.999_999_94,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs != rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_ne_failing_simple() {
+        assert_f32_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon() {
-        assert_f32_eq!(0.5, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    fn assert_f32_le_passing_simple_lt() {
+        assert_f32_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon() {
-        assert_f32_eq!(
-            0.5,
-            1.000_000_1,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_le_passing_simple_eq() {
+        assert_f32_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_000.0,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_le_failing_simple() {
+        assert_f32_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_1_ulps_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_060.0,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ge_passing_simple_gt() {
+        assert_f32_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_100.0,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ge_passing_simple_eq() {
+        assert_f32_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_2_ulps_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_100.0,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_ge_failing_simple() {
+        assert_f32_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_200.0,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_eq_passing_simple_negate() {
+        assert_f32_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_000.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_simple_negate() {
+        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact_large() {
-        assert_f32_eq!(
-            1_000_000_000.0,
-            1_000_000_060.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ne_passing_simple_negate() {
+        assert_f32_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs != rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_ne_failing_simple_negate() {
+        assert_f32_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_large() {
-        assert_f32_eq!(
-            500_000_000.0,
-            999_999_940.0,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_le_failing_simple_lt_negate() {
+        assert_f32_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon_large() {
-        assert_f32_eq!(
-            500_000_000.0,
-            1_000_000_000.0,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_le_failing_simple_eq_negate() {
+        assert_f32_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_large() {
-        assert_f32_eq!(
-            500_000_000.0,
-            1_000_000_060.0,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_le_passing_simple_negate() {
+        assert_f32_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_near_zero() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_ge_failing_simple_gt_negate() {
+        assert_f32_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_1_ulps_near_zero() {
-        assert_f32_eq!(0.0, 1e-45, ulps = 1, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_ge_failing_simple_eq_negate() {
+        assert_f32_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps_near_zero() {
-        assert_f32_eq!(0.0, 3e-45, ulps = 1, epsilon_near_zero = 0.0);
+    fn assert_f32_ge_passing_simple_negate() {
+        assert_f32_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_2_ulps_near_zero() {
-        assert_f32_eq!(0.0, 3e-45, ulps = 2, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_simple() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps_near_zero() {
-        assert_f32_eq!(0.0, 4e-45, ulps = 2, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_simple() {
+        assert_f64_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact_near_zero() {
-        assert_f32_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    fn assert_f64_ne_passing_simple() {
+        assert_f64_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact_near_zero() {
-        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs != rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_ne_failing_simple() {
+        assert_f64_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_near_zero() {
-        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
+    fn assert_f64_le_passing_simple_lt() {
+        assert_f64_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon_near_zero() {
-        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
+    fn assert_f64_le_passing_simple_eq() {
+        assert_f64_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_near_zero() {
-        assert_f32_eq!(0.0, 1e-45, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_le_failing_simple() {
+        assert_f64_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 1e-45);
+    fn assert_f64_ge_passing_simple_gt() {
+        assert_f64_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_exact() {
-        assert_f32_eq!(0.0, 1e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    fn assert_f64_ge_passing_simple_eq() {
+        assert_f64_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps or 1e-45 near zero)")]
-    fn assert_f32_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above() {
-        assert_f32_eq!(0.0, 3e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_ge_failing_simple() {
+        assert_f64_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_absolute_epsilon_looser() {
-        assert_f32_eq!(0.0, 3e-45, ulps = 1, epsilon_near_zero = 3e-45);
+    fn assert_f64_eq_passing_simple_negate() {
+        assert_f64_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_negative() {
-        assert_f32_eq!(-1.0, -1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_simple_negate() {
+        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_1_ulps_negative() {
-        assert_f32_eq!(-1.0, -1.000_000_1, ulps = 1, epsilon_near_zero = 0.0);
+    fn assert_f64_ne_passing_simple_negate() {
+        assert_f64_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps_negative() {
-        assert_f32_eq!(-1.0, -1.000_000_2, ulps = 1, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs != rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_ne_failing_simple_negate() {
+        assert_f64_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_2_ulps_negative() {
-        assert_f32_eq!(-1.0, -1.000_000_2, ulps = 2, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_le_failing_simple_lt_negate() {
+        assert_f64_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps_negative() {
-        assert_f32_eq!(-1.0, -1.000_000_3, ulps = 2, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_le_failing_simple_eq_negate() {
+        assert_f64_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact_negative() {
-        assert_f32_eq!(-1.0, -1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    fn assert_f64_le_passing_simple_negate() {
+        assert_f64_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact_negative() {
-        assert_f32_eq!(
-            -1.0,
-            -1.000_000_1,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_ge_failing_simple_gt_negate() {
+        assert_f64_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_negative() {
-        assert_f32_eq!(
-            -0.5,
-            -0.999_999_94,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_ge_failing_simple_eq_negate() {
+        assert_f64_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon_negative() {
-        assert_f32_eq!(-0.5, -1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    fn assert_f64_ge_passing_simple_negate() {
+        assert_f64_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_negative() {
+    fn assert_f32_eq_passing_ulps_infinity_infinity() {
         assert_f32_eq!(
-            -0.5,
-            -1.000_000_1,
-            relative_epsilon = 0.5,
+            f32::INFINITY,
+            f32::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_large_negative() {
+    fn assert_f32_eq_passing_relative_infinity_infinity() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.0,
-            ulps = 0,
+            f32::INFINITY,
+            f32::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_1_ulps_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_infinity_neg_infinity() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_060.0,
-            ulps = 1,
+            f32::INFINITY,
+            -f32::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_infinity_neg_infinity() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_100.0,
-            ulps = 1,
+            f32::INFINITY,
+            -f32::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_2_ulps_large_negative() {
-        assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_100.0,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_infinity_nan() {
+        assert_f32_eq!(f32::INFINITY, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_infinity_nan() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_200.0,
-            ulps = 2,
+            f32::INFINITY,
+            f32::NAN,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_infinity_five() {
+        assert_f32_eq!(f32::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_infinity_five() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.0,
+            f32::INFINITY,
+            5.0,
             relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_neg_infinity_infinity() {
         assert_f32_eq!(
-            -1_000_000_000.0,
-            -1_000_000_060.0,
-            relative_epsilon = 0.0,
+            -f32::INFINITY,
+            f32::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_large_negative() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_neg_infinity_infinity() {
         assert_f32_eq!(
-            -500_000_000.0,
-            -999_999_940.0,
-            relative_epsilon = 0.5,
+            -f32::INFINITY,
+            f32::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon_large_negative() {
+    fn assert_f32_eq_passing_ulps_neg_infinity_neg_infinity() {
         assert_f32_eq!(
-            -500_000_000.0,
-            -1_000_000_000.0,
-            relative_epsilon = 0.5,
+            -f32::INFINITY,
+            -f32::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_large_negative() {
+    fn assert_f32_eq_passing_relative_neg_infinity_neg_infinity() {
         assert_f32_eq!(
-            -500_000_000.0,
-            -1_000_000_060.0,
-            relative_epsilon = 0.5,
+            -f32::INFINITY,
+            -f32::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_near_zero_negative() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
-    }
-
-    #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
-    fn assert_f32_eq_failing_ulps_1_ulps_near_zero_negative() {
-        assert_f32_eq!(0.0, -1e-45, ulps = 1, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_neg_infinity_nan() {
+        assert_f32_eq!(-f32::INFINITY, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_2_ulps_near_zero_negative() {
-        assert_f32_eq!(0.0, -3e-45, ulps = 2, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_neg_infinity_nan() {
+        assert_f32_eq!(
+            -f32::INFINITY,
+            f32::NAN,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_exact_near_zero_negative() {
-        assert_f32_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_neg_infinity_five() {
+        assert_f32_eq!(-f32::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
     #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_exact_near_zero_negative() {
-        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_failing_relative_neg_infinity_five() {
+        assert_f32_eq!(
+            -f32::INFINITY,
+            5.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_1_ulp_below_epsilon_near_zero_negative() {
-        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_nan_infinity() {
+        assert_f32_eq!(
+            -f32::INFINITY,
+            f32::INFINITY,
+            ulps = 0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_at_epsilon_near_zero_negative() {
-        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_nan_infinity() {
+        assert_f32_eq!(
+            f32::NAN,
+            f32::INFINITY,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_1_ulp_above_epsilon_near_zero_negative() {
-        assert_f32_eq!(0.0, -1e-45, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_nan_neg_infinity() {
+        assert_f32_eq!(f32::NAN, -f32::INFINITY, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below_negative() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 1e-45);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_nan_neg_infinity() {
+        assert_f32_eq!(
+            f32::NAN,
+            -f32::INFINITY,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_0_ulps_absolute_epsilon_exact_negative() {
-        assert_f32_eq!(0.0, -1e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    fn assert_f32_eq_passing_ulps_nan_nan() {
+        assert_f32_eq!(f32::NAN, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps or 1e-45 near zero)")]
-    fn assert_f32_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above_negative() {
-        assert_f32_eq!(0.0, -3e-45, ulps = 0, epsilon_near_zero = 1e-45);
+    fn assert_f32_eq_passing_relative_nan_nan() {
+        assert_f32_eq!(
+            f32::NAN,
+            f32::NAN,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_eq_passing_absolute_epsilon_looser_negative() {
-        assert_f32_eq!(0.0, -3e-45, ulps = 1, epsilon_near_zero = 3e-45);
+    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_eq_failing_ulps_nan_five() {
+        assert_f32_eq!(f32::NAN, 5.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps() {
-        assert_f64_eq!(1.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f32_eq_failing_relative_nan_five() {
+        assert_f32_eq!(
+            f32::NAN,
+            5.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_1_ulps() {
+    fn assert_f64_eq_passing_ulps_infinity_infinity() {
         assert_f64_eq!(
-            1.0,
-            1.000_000_000_000_000_2,
-            ulps = 1,
+            f64::INFINITY,
+            f64::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps() {
+    fn assert_f64_eq_passing_relative_infinity_infinity() {
         assert_f64_eq!(
-            1.0,
-            1.000_000_000_000_000_4,
-            ulps = 1,
+            f64::INFINITY,
+            f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_2_ulps() {
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_infinity_neg_infinity() {
         assert_f64_eq!(
-            1.0,
-            1.000_000_000_000_000_4,
-            ulps = 2,
+            f64::INFINITY,
+            -f64::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_infinity_neg_infinity() {
         assert_f64_eq!(
-            1.0,
-            1.000_000_000_000_000_7,
-            ulps = 2,
+            f64::INFINITY,
+            -f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact() {
-        assert_f64_eq!(1.0, 1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_infinity_nan() {
+        assert_f64_eq!(f64::INFINITY, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
     #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact() {
+    fn assert_f64_eq_failing_relative_infinity_nan() {
         assert_f64_eq!(
-            1.0,
-            1.000_000_000_000_000_2,
+            f64::INFINITY,
+            f64::NAN,
             relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon() {
-        assert_f64_eq!(
-            0.5,
-            0.999_999_999_999_999_9,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
-    }
-
-    #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon() {
-        assert_f64_eq!(0.5, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_infinity_five() {
+        assert_f64_eq!(f64::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_infinity_five() {
         assert_f64_eq!(
-            0.5,
-            1.000_000_000_000_000_2,
-            relative_epsilon = 0.5,
+            f64::INFINITY,
+            5.0,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_large() {
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_neg_infinity_infinity() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.0,
+            -f64::INFINITY,
+            f64::INFINITY,
             ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_1_ulps_large() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_neg_infinity_infinity() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.000_000_1,
-            ulps = 1,
+            -f64::INFINITY,
+            f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps_large() {
+    fn assert_f64_eq_passing_ulps_neg_infinity_neg_infinity() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.000_000_2,
-            ulps = 1,
+            -f64::INFINITY,
+            -f64::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_2_ulps_large() {
+    fn assert_f64_eq_passing_relative_neg_infinity_neg_infinity() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.000_000_2,
-            ulps = 2,
+            -f64::INFINITY,
+            -f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps_large() {
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_neg_infinity_nan() {
+        assert_f64_eq!(-f64::INFINITY, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_neg_infinity_nan() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.000_000_4,
-            ulps = 2,
+            -f64::INFINITY,
+            f64::NAN,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact_large() {
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_neg_infinity_five() {
+        assert_f64_eq!(-f64::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_neg_infinity_five() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.0,
+            -f64::INFINITY,
+            5.0,
             relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact_large() {
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_nan_infinity() {
         assert_f64_eq!(
-            1_000_000_000.0,
-            1_000_000_000.000_000_1,
-            relative_epsilon = 0.0,
+            -f64::INFINITY,
+            f64::INFINITY,
+            ulps = 0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_large() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_nan_infinity() {
         assert_f64_eq!(
-            500_000_000.0,
-            999_999_999.999_999_9,
-            relative_epsilon = 0.5,
+            f64::NAN,
+            f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon_large() {
-        assert_f64_eq!(
-            500_000_000.0,
-            1_000_000_000.0,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_nan_neg_infinity() {
+        assert_f64_eq!(f64::NAN, -f64::INFINITY, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_large() {
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_nan_neg_infinity() {
         assert_f64_eq!(
-            500_000_000.0,
-            1_000_000_000.000_000_1,
-            relative_epsilon = 0.5,
+            f64::NAN,
+            -f64::INFINITY,
+            relative_epsilon = 0.0,
             epsilon_near_zero = 0.0
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_near_zero() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_ulps_nan_nan() {
+        assert_f64_eq!(f64::NAN, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_1_ulps_near_zero() {
-        assert_f64_eq!(0.0, 5e-324, ulps = 1, epsilon_near_zero = 0.0);
+    fn assert_f64_eq_passing_relative_nan_nan() {
+        assert_f64_eq!(
+            f64::NAN,
+            f64::NAN,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps_near_zero() {
-        assert_f64_eq!(0.0, 1e-323, ulps = 1, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_eq_failing_ulps_nan_five() {
+        assert_f64_eq!(f64::NAN, 5.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_2_ulps_near_zero() {
-        assert_f64_eq!(0.0, 1e-323, ulps = 2, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_eq_failing_relative_nan_five() {
+        assert_f64_eq!(
+            f64::NAN,
+            5.0,
+            relative_epsilon = 0.0,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps_near_zero() {
-        assert_f64_eq!(0.0, 1.5e-323, ulps = 2, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_passing_default() {
+        assert_f32_eq!(1.0, 1.000_000_4);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact_near_zero() {
-        assert_f64_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 4 32-bit float ulps or 1.1920929e-7 near zero)")]
+    fn assert_f32_eq_failing_default() {
+        assert_f32_eq!(1.0, 1.000_000_6);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact_near_zero() {
-        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_passing_default_near_zero() {
+        assert_f32_eq!(0.0, 1e-8);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_near_zero() {
-        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 2.0, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_passing_default_negate() {
+        assert_f32_eq!(1.0, 2.0, negate = true);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon_near_zero() {
-        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp or 1.1920929e-7 near zero)")]
+    fn assert_f32_eq_failing_ulps_default_epsilon_near_zero() {
+        assert_f32_eq!(1.0, 1.000_000_2, ulps = 1);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_near_zero() {
-        assert_f64_eq!(0.0, 5e-324, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    #[should_panic(
+        expected = "lhs == rhs (within 0.0 relative to magnitude or 1.1920929e-7 near zero)"
+    )]
+    fn assert_f32_eq_failing_relative_default_epsilon_near_zero() {
+        assert_f32_eq!(10.0, 10.000_001, relative_epsilon = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 5e-324);
+    #[should_panic(expected = "lhs == rhs (within 4 32-bit float ulps)")]
+    fn assert_f32_eq_failing_epsilon_near_zero_default_ulps() {
+        assert_f32_eq!(1.0, 1.000_000_6, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_exact() {
-        assert_f64_eq!(0.0, 5e-324, ulps = 0, epsilon_near_zero = 5e-324);
+    fn resolve_float_ulps_default() {
+        assert_eq!(resolve_float_ulps(&1.0_f32, None), DEFAULT_ULPS_F32);
+        assert_eq!(resolve_float_ulps(&1.0_f64, None), DEFAULT_ULPS_F64);
+        assert_eq!(resolve_float_ulps(&1.0_f32, Some(1)), 1);
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps or 5e-324 near zero)")]
-    fn assert_f64_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above() {
-        assert_f64_eq!(0.0, 1e-323, ulps = 0, epsilon_near_zero = 5e-324);
+    fn resolve_float_tolerances_default_f16() {
+        assert_eq!(
+            resolve_float_tolerances(&half::f16::ONE, None, None),
+            (4, half::f16::EPSILON)
+        );
     }
 
     #[test]
-    fn assert_f64_eq_passing_absolute_epsilon_looser() {
-        assert_f64_eq!(0.0, 1e-323, ulps = 1, epsilon_near_zero = 1e-323);
+    fn resolve_float_epsilon_near_zero_default() {
+        assert_eq!(
+            resolve_float_epsilon_near_zero::<f32>(None),
+            DEFAULT_EPSILON_NEAR_ZERO_F32
+        );
+        assert_eq!(
+            resolve_float_epsilon_near_zero::<f64>(None),
+            DEFAULT_EPSILON_NEAR_ZERO_F64
+        );
+        assert_eq!(resolve_float_epsilon_near_zero(Some(0.0_f32)), 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_negative() {
-        assert_f64_eq!(-1.0, -1.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn resolve_float_nan_equal_default() {
+        assert_eq!(resolve_float_nan_equal(None), true);
+        assert_eq!(resolve_float_nan_equal(Some(false)), false);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_1_ulps_negative() {
-        assert_f64_eq!(
-            -1.0,
-            -1.000_000_000_000_000_2,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_eq_failing_keywords_any_order() {
+        assert_f32_eq!(1.0, 1.000_000_2, epsilon_near_zero = 0.0, ulps = 1);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps_negative() {
-        assert_f64_eq!(
-            -1.0,
-            -1.000_000_000_000_000_4,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_eq_passing_keywords_mixed_with_config() {
+        assert_f32_eq!(1.0, 2.0, negate = true, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_2_ulps_negative() {
-        assert_f64_eq!(
-            -1.0,
-            -1.000_000_000_000_000_4,
-            ulps = 2,
-            epsilon_near_zero = 0.0
+    #[should_panic(expected = "lhs < rhs (within 0.0 relative to magnitude)")]
+    fn assert_f64_lt_failing_keywords_any_order() {
+        assert_f64_lt!(
+            1.0,
+            1.0,
+            description = "strictly less",
+            epsilon_near_zero = 0.0,
+            relative_epsilon = 0.0
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps_negative() {
-        assert_f64_eq!(
-            -1.0,
-            -1.000_000_000_000_000_7,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ne_passing_default() {
+        assert_f32_ne!(1.0, 1.000_000_6);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact_negative() {
-        assert_f64_eq!(-1.0, -1.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    fn assert_f32_le_passing_default() {
+        assert_f32_le!(1.000_000_4, 1.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact_negative() {
-        assert_f64_eq!(
-            -1.0,
-            -1.000_000_000_000_000_2,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_ge_passing_default() {
+        assert_f32_ge!(1.0, 1.000_000_4);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_negative() {
-        assert_f64_eq!(
-            -0.5,
-            -0.999_999_999_999_999_9,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f64_eq_passing_default() {
+        assert_f64_eq!(1.0, 1.000_000_000_000_000_8);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon_negative() {
-        assert_f64_eq!(-0.5, -1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
+    #[should_panic(
+        expected = "lhs == rhs (within 4 64-bit float ulps or 2.220446049250313e-16 near zero)"
+    )]
+    fn assert_f64_eq_failing_default() {
+        assert_f64_eq!(1.0, 1.000_000_000_000_001);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_negative() {
-        assert_f64_eq!(
-            -0.5,
-            -1.000_000_000_000_000_2,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(
+        expected = "lhs == rhs (within 1 64-bit float ulp or 2.220446049250313e-16 near zero)"
+    )]
+    fn assert_f64_eq_failing_ulps_default_epsilon_near_zero() {
+        assert_f64_eq!(1.0, 1.000_000_000_000_000_4, ulps = 1);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.0,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f64_ne_passing_default() {
+        assert_f64_ne!(1.0, 1.000_000_000_000_001);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_1_ulps_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.000_000_1,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f64_le_passing_default() {
+        assert_f64_le!(1.000_000_000_000_000_8, 1.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.000_000_2,
-            ulps = 1,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f64_ge_passing_default() {
+        assert_f64_ge!(1.0, 1.000_000_000_000_000_8);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_2_ulps_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.000_000_2,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_lt_passing_simple() {
+        assert_f32_lt!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.000_000_4,
-            ulps = 2,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs < rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_lt_failing_simple_eq() {
+        assert_f32_lt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs < rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_lt_failing_simple_wrong_order() {
+        assert_f32_lt!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact_large_negative() {
-        assert_f64_eq!(
-            -1_000_000_000.0,
-            -1_000_000_000.000_000_1,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs < rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_lt_failing_ulps_within_tolerance() {
+        assert_f32_lt!(1.0, 1.000_000_1, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_large_negative() {
-        assert_f64_eq!(
-            -500_000_000.0,
-            -999_999_999.999_999_9,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_lt_passing_ulps_outside_tolerance() {
+        assert_f32_lt!(1.0, 1.000_001_2, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon_large_negative() {
-        assert_f64_eq!(
-            -500_000_000.0,
-            -1_000_000_000.0,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs < rhs (within 1.0 relative to magnitude)")]
+    fn assert_f32_lt_failing_relative_within_tolerance() {
+        assert_f32_lt!(0.0, 1.0, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_large_negative() {
-        assert_f64_eq!(
-            -500_000_000.0,
-            -1_000_000_000.000_000_1,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_lt_passing_relative_outside_tolerance() {
+        assert_f32_lt!(0.0, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_near_zero_negative() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f32_lt_passing_default() {
+        assert_f32_lt!(0.0, 1.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 1 64-bit float ulp)")]
-    fn assert_f64_eq_failing_ulps_1_ulps_near_zero_negative() {
-        assert_f64_eq!(0.0, -5e-324, ulps = 1, epsilon_near_zero = 0.0);
+    fn assert_f32_lt_passing_simple_negate() {
+        assert_f32_lt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 2 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_2_ulps_near_zero_negative() {
-        assert_f64_eq!(0.0, -1e-323, ulps = 2, epsilon_near_zero = 0.0);
+    fn assert_f32_gt_passing_simple() {
+        assert_f32_gt!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_exact_near_zero_negative() {
-        assert_f64_eq!(0.0, 0.0, relative_epsilon = 0.0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs > rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_gt_failing_simple_eq() {
+        assert_f32_gt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_exact_near_zero_negative() {
-        assert_f64_eq!(
-            0.0,
-            -5e-324,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs > rhs (within 0 32-bit float ulps)")]
+    fn assert_f32_gt_failing_simple_wrong_order() {
+        assert_f32_gt!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_1_ulp_below_epsilon_near_zero_negative() {
-        assert_f64_eq!(
-            0.0,
-            -5e-324,
-            relative_epsilon = 2.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs > rhs (within 1 32-bit float ulp)")]
+    fn assert_f32_gt_failing_ulps_within_tolerance() {
+        assert_f32_gt!(1.000_000_1, 1.0, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_at_epsilon_near_zero_negative() {
-        assert_f64_eq!(
-            0.0,
-            -5e-324,
-            relative_epsilon = 1.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_gt_passing_ulps_outside_tolerance() {
+        assert_f32_gt!(1.000_001_2, 1.0, ulps = 1, epsilon_near_zero = 0.0);
     }
 
-    #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.5 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_1_ulp_above_epsilon_near_zero_negative() {
-        assert_f64_eq!(
-            0.0,
-            -5e-324,
-            relative_epsilon = 0.5,
-            epsilon_near_zero = 0.0
-        );
+    #[test]
+    #[should_panic(expected = "lhs > rhs (within 1.0 relative to magnitude)")]
+    fn assert_f32_gt_failing_relative_within_tolerance() {
+        assert_f32_gt!(1.0, 0.0, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_1_ulp_below_negative() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 5e-324);
+    fn assert_f32_gt_passing_relative_outside_tolerance() {
+        assert_f32_gt!(1.0, 0.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_0_ulps_absolute_epsilon_exact_negative() {
-        assert_f64_eq!(0.0, -5e-324, ulps = 0, epsilon_near_zero = 5e-324);
+    fn assert_f32_gt_passing_default() {
+        assert_f32_gt!(1.0, 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps or 5e-324 near zero)")]
-    fn assert_f64_eq_failing_ulps_0_ulps_absolute_epsilon_1_ulp_above_negative() {
-        assert_f64_eq!(0.0, -1e-323, ulps = 0, epsilon_near_zero = 5e-324);
+    fn assert_f32_gt_passing_simple_negate() {
+        assert_f32_gt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f64_eq_passing_absolute_epsilon_looser_negative() {
-        assert_f64_eq!(0.0, -1e-323, ulps = 1, epsilon_near_zero = 1e-323);
+    fn assert_f64_lt_passing_simple() {
+        assert_f64_lt!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_simple() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs < rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_lt_failing_simple_eq() {
+        assert_f64_lt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_simple() {
-        assert_f32_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs < rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_lt_failing_simple_wrong_order() {
+        assert_f64_lt!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_ne_passing_simple() {
-        assert_f32_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs < rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_lt_failing_ulps_within_tolerance() {
+        assert_f64_lt!(
+            1.0,
+            1.000_000_000_000_000_2,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs != rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_ne_failing_simple() {
-        assert_f32_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_lt_passing_ulps_outside_tolerance() {
+        assert_f64_lt!(
+            1.0,
+            1.000_000_000_000_002,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_le_passing_simple_lt() {
-        assert_f32_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs < rhs (within 1.0 relative to magnitude)")]
+    fn assert_f64_lt_failing_relative_within_tolerance() {
+        assert_f64_lt!(0.0, 1.0, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_le_passing_simple_eq() {
-        assert_f32_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_lt_passing_relative_outside_tolerance() {
+        assert_f64_lt!(0.0, 1.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_le_failing_simple() {
-        assert_f32_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_lt_passing_default() {
+        assert_f64_lt!(0.0, 1.0);
     }
 
     #[test]
-    fn assert_f32_ge_passing_simple_gt() {
-        assert_f32_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_lt_passing_simple_negate() {
+        assert_f64_lt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    fn assert_f32_ge_passing_simple_eq() {
-        assert_f32_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_gt_passing_simple() {
+        assert_f64_gt!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_ge_failing_simple() {
-        assert_f32_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs > rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_gt_failing_simple_eq() {
+        assert_f64_gt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    fn assert_f32_eq_passing_simple_negate() {
-        assert_f32_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs > rhs (within 0 64-bit float ulps)")]
+    fn assert_f64_gt_failing_simple_wrong_order() {
+        assert_f64_gt!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_simple_negate() {
-        assert_f32_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs > rhs (within 1 64-bit float ulp)")]
+    fn assert_f64_gt_failing_ulps_within_tolerance() {
+        assert_f64_gt!(
+            1.000_000_000_000_000_2,
+            1.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    fn assert_f32_ne_passing_simple_negate() {
-        assert_f32_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_gt_passing_ulps_outside_tolerance() {
+        assert_f64_gt!(
+            1.000_000_000_000_002,
+            1.0,
+            ulps = 1,
+            epsilon_near_zero = 0.0
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs != rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_ne_failing_simple_negate() {
-        assert_f32_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs > rhs (within 1.0 relative to magnitude)")]
+    fn assert_f64_gt_failing_relative_within_tolerance() {
+        assert_f64_gt!(1.0, 0.0, relative_epsilon = 1.0, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_le_failing_simple_lt_negate() {
-        assert_f32_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_gt_passing_relative_outside_tolerance() {
+        assert_f64_gt!(1.0, 0.0, relative_epsilon = 0.5, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_le_failing_simple_eq_negate() {
-        assert_f32_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_gt_passing_default() {
+        assert_f64_gt!(1.0, 0.0);
     }
 
     #[test]
-    fn assert_f32_le_passing_simple_negate() {
-        assert_f32_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_gt_passing_simple_negate() {
+        assert_f64_gt!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_ge_failing_simple_gt_negate() {
-        assert_f32_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    // Strict float comparison allowed because the values in these tests are exactly representable.
+    #[allow(clippy::float_cmp)]
+    fn find_float_slice_mismatches_none() {
+        assert!(
+            find_float_slice_mismatches(&[1.0_f32, 2.0], &[1.0, 2.0], |lhs, rhs| { lhs == rhs })
+                .is_empty()
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_ge_failing_simple_eq_negate() {
-        assert_f32_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    // Strict float comparison allowed because the values in these tests are exactly representable.
+    #[allow(clippy::float_cmp)]
+    fn find_float_slice_mismatches_some() {
+        assert_eq!(
+            find_float_slice_mismatches(&[1.0_f64, 2.0, 3.0], &[1.0, 2.5, 3.5], |lhs, rhs| {
+                lhs == rhs
+            }),
+            vec![(1, 2.0, 2.5), (2, 3.0, 3.5)]
+        );
     }
 
     #[test]
-    fn assert_f32_ge_passing_simple_negate() {
-        assert_f32_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn format_float_slice_mismatches_truncated() {
+        let mismatches: Vec<(usize, f32, f32)> = (0..12).map(|index| (index, 1.0, 2.0)).collect();
+
+        let formatted = format_float_slice_mismatches(&mismatches, |_, _| "difference".to_owned());
+
+        assert_eq!(
+            formatted.lines().count(),
+            MAX_REPORTED_SLICE_MISMATCH_COUNT + 1
+        );
+        assert_eq!(
+            formatted.lines().next(),
+            Some("[0] lhs = 1.0, rhs = 2.0, difference")
+        );
+        assert_eq!(formatted.lines().last(), Some("... (2 more mismatches)"));
     }
 
     #[test]
-    fn assert_f64_eq_passing_simple() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f32_slice_eq_passing() {
+        assert_f32_slice_eq!([0.1_f32 + 0.2, 1.0], [0.3, 1.0]);
+        assert_f32_slice_eq!(vec![1.0, 2.0], [1.0, 2.0], ulps = 0);
+        assert_f32_slice_eq!([1.0, 2.0], vec![1.0, 2.000_01], relative_epsilon = 1e-5);
+        assert_f32_slice_eq!([0.0_f32; 0], [0.0_f32; 0]);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_simple() {
-        assert_f64_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f32_slice_eq_passing_negate() {
+        assert_f32_slice_eq!([1.0, 2.0], [1.0, 2.1], negate = true);
+        assert_f32_slice_eq!([1.0, 2.0], [1.0], negate = true);
     }
 
     #[test]
-    fn assert_f64_ne_passing_simple() {
-        assert_f64_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 4 32-bit float ulps")]
+    fn assert_f32_slice_eq_failing_element() {
+        assert_f32_slice_eq!([1.0, 2.0, 3.0], [1.0, 2.5, 3.0]);
     }
 
     #[test]
-    #[should_panic(expected = "lhs != rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_ne_failing_simple() {
-        assert_f64_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_f32_slice_eq_failing_length() {
+        assert_f32_slice_eq!([1.0, 2.0, 3.0], [1.0, 2.0]);
     }
 
     #[test]
-    fn assert_f64_le_passing_simple_lt() {
-        assert_f64_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f64_slice_eq_passing() {
+        assert_f64_slice_eq!([0.1_f64 + 0.2, 1.0], [0.3, 1.0]);
+        assert_f64_slice_eq!(vec![1.0, 2.0], [1.0, 2.0], ulps = 0);
+        assert_f64_slice_eq!(
+            [1.0, 2.0],
+            vec![1.0, 2.000_000_001],
+            relative_epsilon = 1e-9
+        );
     }
 
     #[test]
-    fn assert_f64_le_passing_simple_eq() {
-        assert_f64_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 1e-9 relative to magnitude")]
+    fn assert_f64_slice_eq_failing_element() {
+        assert_f64_slice_eq!([1.0, 2.0], [1.0, 2.1], relative_epsilon = 1e-9);
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_le_failing_simple() {
-        assert_f64_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_f64_slice_eq_failing_length() {
+        assert_f64_slice_eq!([1.0], [1.0, 2.0]);
     }
 
     #[test]
-    fn assert_f64_ge_passing_simple_gt() {
-        assert_f64_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn is_float_grid_shape_eq_ragged() {
+        assert!(is_float_grid_shape_eq::<f32>(
+            &[&[1.0], &[]],
+            &[&[2.0], &[]]
+        ));
+        assert!(!is_float_grid_shape_eq::<f32>(&[&[1.0], &[]], &[&[2.0]]));
+        assert!(!is_float_grid_shape_eq::<f32>(
+            &[&[1.0], &[]],
+            &[&[], &[2.0]]
+        ));
     }
 
     #[test]
-    fn assert_f64_ge_passing_simple_eq() {
-        assert_f64_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0);
+    // Strict float comparison allowed because the values in these tests are exactly representable.
+    #[allow(clippy::float_cmp)]
+    fn find_float_grid_mismatches_some() {
+        assert_eq!(
+            find_float_grid_mismatches(
+                &[&[1.0_f64, 2.0], &[3.0, 4.0]],
+                &[&[1.0, 2.5], &[3.5, 4.0]],
+                |lhs, rhs| lhs == rhs
+            ),
+            vec![(0, 1, 2.0, 2.5), (1, 0, 3.0, 3.5)]
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_ge_failing_simple() {
-        assert_f64_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn describe_float_grid_shape_all() {
+        assert_eq!(
+            describe_float_grid_shape::<f32>(&[&[1.0, 2.0], &[3.0, 4.0]]),
+            "2 rows \u{d7} 2 columns"
+        );
+        assert_eq!(
+            describe_float_grid_shape::<f32>(&[&[1.0, 2.0], &[3.0]]),
+            "2 rows with lengths [2, 1]"
+        );
+        assert_eq!(describe_float_grid_shape::<f32>(&[]), "0 rows");
     }
 
     #[test]
-    fn assert_f64_eq_passing_simple_negate() {
-        assert_f64_eq!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f32_grid_eq_passing() {
+        assert_f32_grid_eq!([[0.1_f32 + 0.2, 1.0], [2.0, 3.0]], [[0.3, 1.0], [2.0, 3.0]]);
+        assert_f32_grid_eq!(vec![vec![1.0, 2.0]], [[1.0, 2.0]], ulps = 0);
+        assert_f32_grid_eq!(
+            [[1.0, 2.0]],
+            vec![vec![1.0, 2.000_01]],
+            relative_epsilon = 1e-5
+        );
+        assert_f32_grid_eq!([[0.0_f32; 0]; 0], [[0.0_f32; 0]; 0]);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_simple_negate() {
-        assert_f64_eq!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f32_grid_eq_passing_negate() {
+        assert_f32_grid_eq!([[1.0, 2.0]], [[1.0, 2.1]], negate = true);
+        assert_f32_grid_eq!([[1.0, 2.0]], [[1.0], [2.0]], negate = true);
     }
 
     #[test]
-    fn assert_f64_ne_passing_simple_negate() {
-        assert_f64_ne!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs == rhs (within 4 32-bit float ulps")]
+    fn assert_f32_grid_eq_failing_element() {
+        assert_f32_grid_eq!([[1.0, 2.0], [3.0, 4.0]], [[1.0, 2.0], [3.5, 4.0]]);
     }
 
     #[test]
-    #[should_panic(expected = "lhs != rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_ne_failing_simple_negate() {
-        assert_f64_ne!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_f32_grid_eq_failing_shape() {
+        assert_f32_grid_eq!(vec![vec![1.0, 2.0], vec![3.0]], [[1.0, 2.0], [3.0, 4.0]]);
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_le_failing_simple_lt_negate() {
-        assert_f64_le!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_grid_eq_passing() {
+        let grid: Vec<Vec<f64>> = vec![vec![0.1 + 0.2, 1.0], vec![2.0, 3.0]];
+
+        assert_f64_grid_eq!(grid, [[0.3, 1.0], [2.0, 3.0]]);
+        assert_f64_grid_eq!(&grid, grid.as_slice(), ulps = 0);
+        assert_f64_grid_eq!(
+            [[1.0, 2.0]],
+            [[1.0, 2.000_000_001]],
+            relative_epsilon = 1e-9
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs <= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_le_failing_simple_eq_negate() {
-        assert_f64_le!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    #[should_panic(expected = "lhs == rhs (within 1e-9 relative to magnitude")]
+    fn assert_f64_grid_eq_failing_element() {
+        assert_f64_grid_eq!([[1.0, 2.0]], [[1.0, 2.1]], relative_epsilon = 1e-9);
     }
 
     #[test]
-    fn assert_f64_le_passing_simple_negate() {
-        assert_f64_le!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_grid_eq_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_f64_grid_eq!(
+                [[1.0, 2.0], [3.0, 4.0]],
+                [[1.0, 2.5], [3.0, 5.0]],
+                epsilon_near_zero = 0.0
+            );
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("lhs").unwrap().value,
+            "2 rows \u{d7} 2 columns"
+        );
+        assert!(failure
+            .argument("worst element")
+            .unwrap()
+            .value
+            .starts_with("(1, 1) lhs = 4.0, rhs = 5.0, absolute difference = 1.0, ulps = "));
+        assert_eq!(
+            failure.argument("mismatches").unwrap().value,
+            "2 of 4 elements"
+        );
+        assert_eq!(
+            failure.argument("max absolute difference").unwrap().value,
+            "1.0"
+        );
+        assert_eq!(
+            failure.argument("mean absolute difference").unwrap().value,
+            "0.375"
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_ge_failing_simple_gt_negate() {
-        assert_f64_ge!(1.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f32_eq_evaluates_operands_once() {
+        let evaluation_count = Cell::new(0);
+
+        let next_value = || {
+            evaluation_count.set(evaluation_count.get() + 1);
+
+            1.0_f32
+        };
+
+        assert_f32_eq!(next_value(), next_value());
+        assert_f32_eq!(next_value(), 1.0, relative_epsilon = f32::EPSILON);
+
+        assert_eq!(evaluation_count.get(), 3);
     }
 
     #[test]
-    #[should_panic(expected = "lhs >= rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_ge_failing_simple_eq_negate() {
-        assert_f64_ge!(0.0, 0.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f64_lt_evaluates_operands_once() {
+        let evaluation_count = Cell::new(0);
+
+        let next_value = || {
+            evaluation_count.set(evaluation_count.get() + 1);
+
+            f64::from(evaluation_count.get())
+        };
+
+        assert_f64_lt!(next_value(), next_value());
+
+        assert_eq!(evaluation_count.get(), 2);
     }
 
     #[test]
-    fn assert_f64_ge_passing_simple_negate() {
-        assert_f64_ge!(0.0, 1.0, ulps = 0, epsilon_near_zero = 0.0, negate = true);
+    fn assert_f32_eq_reference_operands() {
+        let [first, second] = [1.0_f32, 2.0];
+
+        assert_f32_eq!(&first, 1.0);
+        assert_f32_ne!(&first, &second);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_infinity_infinity() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            f32::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f64_ge_reference_operands() {
+        let values = vec![2.0_f64, 1.0];
+
+        for value in &values {
+            assert_f64_ge!(value, 1.0, relative_epsilon = f64::EPSILON);
+        }
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_infinity_infinity() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn is_float_eq_ulps_matches_f32_and_f64() {
+        assert!(is_float_eq_ulps(1.0_f32, 1.000_000_1, 0.0, 1));
+        assert!(!is_float_eq_ulps(1.0_f32, 1.000_000_2, 0.0, 1));
+        assert!(is_float_eq_ulps(-1.0_f64, -1.000_000_000_000_000_2, 0.0, 1));
+        assert!(!is_float_eq_ulps(
+            -1.0_f64,
+            -1.000_000_000_000_000_4,
+            0.0,
+            1
+        ));
+        assert!(!is_float_eq_ulps(f64::MIN, f64::MAX, 0.0, i64::MAX));
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_infinity_neg_infinity() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            -f32::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_eq_passing_f32() {
+        assert_float_eq!(1.0_f32, 1.000_000_1_f32, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_infinity_neg_infinity() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            -f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_eq_passing_f64_default() {
+        assert_float_eq!(0.1_f64 + 0.2_f64, 0.3_f64);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_infinity_nan() {
-        assert_f32_eq!(f32::INFINITY, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_float_eq_passing_epsilon_near_zero() {
+        assert_float_eq!(0.0_f32, 1e-10_f32, epsilon_near_zero = 1e-9);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_infinity_nan() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            f32::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 1 32-bit float ulp)")]
+    fn assert_float_eq_failing_f32() {
+        assert_float_eq!(1.0_f32, 1.000_000_2_f32, ulps = 1, epsilon_near_zero = 0.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_infinity_five() {
-        assert_f32_eq!(f32::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(
+        expected = "lhs == rhs (within 4 64-bit float ulps or 2.220446049250313e-16 near zero)"
+    )]
+    fn assert_float_eq_failing_f64_default() {
+        assert_float_eq!(1.0_f64, 1.1_f64);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_infinity_five() {
-        assert_f32_eq!(
-            f32::INFINITY,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_ne_passing() {
+        assert_float_ne!(1.0_f64, 1.1_f64, ulps = 2);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_neg_infinity_infinity() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            f32::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs != rhs (within 4 32-bit float ulps")]
+    fn assert_float_ne_failing() {
+        assert_float_ne!(0.1_f32 + 0.2_f32, 0.3_f32);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_neg_infinity_infinity() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_eq_negate() {
+        assert_float_eq!(1.0_f32, 2.0_f32, negate = true);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_neg_infinity_neg_infinity() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            -f32::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    fn float_ulps_f32_f64() {
+        assert_eq!(FloatUlps::ulps(&1.000_000_2_f32, &1.0_f32), 2);
+        assert_eq!(FloatUlps::ulps(&1.0_f64, &1.0_f64), 0);
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    fn assert_f32_eq_passing_relative_neg_infinity_neg_infinity() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            -f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
+    fn float_ulps_f16() {
+        assert_eq!(
+            FloatUlps::ulps(&half::f16::from_bits(0x3c05), &half::f16::ONE),
+            5
+        );
+        assert_eq!(
+            FloatUlps::ulps(&half::f16::ONE, &half::f16::from_bits(0x3c05)),
+            -5
         );
     }
 
+    #[cfg(feature = "half")]
+    #[test]
+    fn assert_float_eq_passing_f16() {
+        assert_float_eq!(half::f16::ONE, half::f16::from_bits(0x3c04));
+    }
+
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_neg_infinity_nan() {
-        assert_f32_eq!(-f32::INFINITY, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "lhs == rhs (within 4 16-bit float ulps")]
+    fn assert_float_eq_failing_f16() {
+        assert_float_eq!(half::f16::ONE, half::f16::from_bits(0x3c05));
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_neg_infinity_nan() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            f32::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
+    #[should_panic(expected = "lhs == rhs (within 1 16-bit float ulp)")]
+    fn assert_float_eq_failing_f16_ulps() {
+        assert_float_eq!(
+            half::f16::ONE,
+            half::f16::from_bits(0x3c02),
+            ulps = 1,
+            epsilon_near_zero = half::f16::ZERO
         );
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_neg_infinity_five() {
-        assert_f32_eq!(-f32::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "operand is NaN")]
+    fn assert_float_eq_failing_f16_nan_equal_false() {
+        assert_float_eq!(half::f16::NAN, half::f16::NAN, nan_equal = false);
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_neg_infinity_five() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_ne_passing_f16() {
+        assert_float_ne!(half::f16::ONE, half::f16::from_f32(1.1));
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_nan_infinity() {
-        assert_f32_eq!(
-            -f32::INFINITY,
-            f32::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
+    fn assert_float_eq_passing_f16_relative() {
+        assert_float_eq!(
+            half::f16::ONE,
+            half::f16::from_bits(0x3c05),
+            relative_epsilon = half::f16::from_f32(0.01)
         );
     }
 
+    #[cfg(feature = "half")]
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_nan_infinity() {
-        assert_f32_eq!(
-            f32::NAN,
-            f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs != rhs (within 4 16-bit float ulps")]
+    fn assert_float_ne_failing_f16() {
+        assert_float_ne!(half::f16::ONE, half::f16::from_bits(0x3c01));
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_nan_neg_infinity() {
-        assert_f32_eq!(f32::NAN, -f32::INFINITY, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_float_eq_passing_relative() {
+        assert_float_eq!(1.0_f64, 1.01_f64, relative_epsilon = 0.02);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_nan_neg_infinity() {
-        assert_f32_eq!(
-            f32::NAN,
-            -f32::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "lhs == rhs (within 0.01 relative to magnitude")]
+    fn assert_float_eq_failing_relative() {
+        assert_float_eq!(1.0_f32, 1.1_f32, relative_epsilon = 0.01);
     }
 
     #[test]
-    fn assert_f32_eq_passing_ulps_nan_nan() {
-        assert_f32_eq!(f32::NAN, f32::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    fn float_comparison_operator() {
+        assert_eq!(FloatComparison::Eq.operator(), "==");
+        assert_eq!(FloatComparison::Ne.operator(), "!=");
+        assert_eq!(FloatComparison::Le.operator(), "<=");
+        assert_eq!(FloatComparison::Ge.operator(), ">=");
+        assert_eq!(FloatComparison::Lt.operator(), "<");
+        assert_eq!(FloatComparison::Gt.operator(), ">");
     }
 
     #[test]
-    fn assert_f32_eq_passing_relative_nan_nan() {
-        assert_f32_eq!(
-            f32::NAN,
-            f32::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_impl_ulps_comparisons() {
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Eq,
+            1.0_f32,
+            1.0,
+            0.0,
+            0
+        ));
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Ne,
+            1.0_f32,
+            2.0,
+            0.0,
+            4
+        ));
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Le,
+            1.0_f64,
+            2.0,
+            0.0,
+            4
+        ));
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Ge,
+            2.0_f64,
+            1.0,
+            0.0,
+            4
+        ));
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Lt,
+            1.0_f32,
+            2.0,
+            0.0,
+            4
+        ));
+        assert!(!assert_float_impl_ulps(
+            FloatComparison::Lt,
+            1.0_f32,
+            1.000_000_1,
+            0.0,
+            4
+        ));
+        assert!(assert_float_impl_ulps(
+            FloatComparison::Gt,
+            2.0_f64,
+            1.0,
+            0.0,
+            4
+        ));
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 32-bit float ulps)")]
-    fn assert_f32_eq_failing_ulps_nan_five() {
-        assert_f32_eq!(f32::NAN, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_float_impl_relative_comparisons() {
+        assert!(assert_float_impl_relative(
+            FloatComparison::Eq,
+            1.0_f32,
+            1.0,
+            0.0,
+            0.0
+        ));
+        assert!(assert_float_impl_relative(
+            FloatComparison::Le,
+            1.01_f64,
+            1.0,
+            0.0,
+            0.1
+        ));
+        assert!(!assert_float_impl_relative(
+            FloatComparison::Gt,
+            1.01_f64,
+            1.0,
+            0.0,
+            0.1
+        ));
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f32_eq_failing_relative_nan_five() {
-        assert_f32_eq!(
-            f32::NAN,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn check_nan_operands_allowed() {
+        assert!(check_nan_operands(true, f32::NAN, f32::NAN));
+        assert!(check_nan_operands(false, 1.0_f32, 2.0));
+        assert!(!check_nan_operands(false, f32::NAN, 1.0));
+        assert!(!check_nan_operands(false, 1.0_f64, f64::NAN));
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_infinity_infinity() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            f64::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
+    fn format_float_predicate_description_nan_operands() {
+        assert_eq!(
+            format_float_predicate_description_nan("lhs == rhs", false, f32::NAN, 1.0).to_string(),
+            "lhs == rhs (operand is NaN: lhs)"
         );
-    }
 
-    #[test]
-    fn assert_f64_eq_passing_relative_infinity_infinity() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
+        assert_eq!(
+            format_float_predicate_description_nan("lhs == rhs", false, 1.0, f64::NAN).to_string(),
+            "lhs == rhs (operand is NaN: rhs)"
         );
-    }
 
-    #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_infinity_neg_infinity() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            -f64::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
+        assert_eq!(
+            format_float_predicate_description_nan("lhs == rhs", false, f32::NAN, f32::NAN)
+                .to_string(),
+            "lhs == rhs (operand is NaN: lhs and rhs)"
+        );
+
+        assert_eq!(
+            format_float_predicate_description_nan("lhs == rhs", true, f32::NAN, 1.0).to_string(),
+            "lhs == rhs"
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_infinity_neg_infinity() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            -f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn format_float_predicate_description_nan_is_lazy() {
+        /// A description that counts how many times it is formatted with [`Display`].
+        struct CountsDisplay<'count>(&'count Cell<usize>);
+
+        impl Display for CountsDisplay<'_> {
+            fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+                self.0.set(self.0.get() + 1);
+
+                formatter.write_str("lhs == rhs")
+            }
+        }
+
+        let count = Cell::new(0);
+
+        let description =
+            format_float_predicate_description_nan(CountsDisplay(&count), false, f32::NAN, 1.0);
+
+        assert_eq!(count.get(), 0);
+        assert_eq!(description.to_string(), "lhs == rhs (operand is NaN: lhs)");
+        assert_eq!(count.get(), 1);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_infinity_nan() {
-        assert_f64_eq!(f64::INFINITY, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_f32_eq_passing_nan_equal_default() {
+        assert_f32_eq!(f32::NAN, f32::NAN);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_infinity_nan() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            f64::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_eq_passing_nan_equal_false_finite() {
+        assert_f32_eq!(1.0, 1.0, nan_equal = false);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_infinity_five() {
-        assert_f64_eq!(f64::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "operand is NaN")]
+    fn assert_f32_eq_failing_nan_equal_false() {
+        assert_f32_eq!(f32::NAN, f32::NAN, nan_equal = false);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_infinity_five() {
-        assert_f64_eq!(
-            f64::INFINITY,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "operand is NaN")]
+    fn assert_f64_ne_failing_nan_equal_false() {
+        assert_f64_ne!(f64::NAN, 1.0, ulps = 1, nan_equal = false);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_neg_infinity_infinity() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            f64::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
+    #[should_panic(expected = "operand is NaN")]
+    fn assert_f64_le_failing_nan_equal_false_relative() {
+        assert_f64_le!(
+            f64::NAN,
+            f64::NAN,
+            relative_epsilon = f64::EPSILON,
+            epsilon_near_zero = 0.0,
+            nan_equal = false
         );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_neg_infinity_infinity() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_f32_gt_passing_nan_equal_false_negate() {
+        assert_f32_gt!(f32::NAN, 1.0, nan_equal = false, negate = true);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_neg_infinity_neg_infinity() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            -f64::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
+    #[should_panic(expected = "operand is NaN")]
+    fn assert_float_eq_failing_nan_equal_false() {
+        assert_float_eq!(
+            f64::NAN,
+            f64::NAN,
+            epsilon_near_zero = 0.0,
+            nan_equal = false
         );
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_neg_infinity_neg_infinity() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            -f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_float_ne_passing_nan_equal_default() {
+        assert_float_ne!(f32::NAN, 1.0_f32);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_neg_infinity_nan() {
-        assert_f64_eq!(-f64::INFINITY, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_float_eq_passing_nan_equal_false_keywords_any_order() {
+        assert_float_eq!(
+            f32::NAN,
+            1.0_f32,
+            nan_equal = false,
+            negate = true,
+            ulps = 2
+        );
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_neg_infinity_nan() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            f64::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
+    fn describe_float_classification_all() {
+        assert_eq!(describe_float_classification(f32::NAN), "NaN");
+        assert_eq!(describe_float_classification(f64::INFINITY), "+inf");
+        assert_eq!(describe_float_classification(f64::NEG_INFINITY), "-inf");
+        assert_eq!(describe_float_classification(-0.0_f32), "zero");
+        assert_eq!(
+            describe_float_classification(f64::MIN_POSITIVE / 2.0),
+            "subnormal"
         );
+        assert_eq!(describe_float_classification(1.0_f32), "normal");
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_neg_infinity_five() {
-        assert_f64_eq!(-f64::INFINITY, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_finite_passing() {
+        assert_finite!(1.0_f32);
+        assert_finite!(0.0_f64);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_neg_infinity_five() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "value is finite")]
+    fn assert_finite_failing() {
+        assert_finite!(f64::NEG_INFINITY);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_nan_infinity() {
-        assert_f64_eq!(
-            -f64::INFINITY,
-            f64::INFINITY,
-            ulps = 0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_nan_passing() {
+        assert_nan!(f32::NAN);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_nan_infinity() {
-        assert_f64_eq!(
-            f64::NAN,
-            f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "value is NaN")]
+    fn assert_nan_failing() {
+        assert_nan!(1.0_f64);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_nan_neg_infinity() {
-        assert_f64_eq!(f64::NAN, -f64::INFINITY, ulps = 0, epsilon_near_zero = 0.0);
+    fn assert_nan_passing_negate() {
+        assert_nan!(1.0_f64, negate = true);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_nan_neg_infinity() {
-        assert_f64_eq!(
-            f64::NAN,
-            -f64::INFINITY,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_infinite_passing() {
+        assert_infinite!(f64::INFINITY);
+        assert_infinite!(f32::NEG_INFINITY);
     }
 
     #[test]
-    fn assert_f64_eq_passing_ulps_nan_nan() {
-        assert_f64_eq!(f64::NAN, f64::NAN, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "value is infinite")]
+    fn assert_infinite_failing() {
+        assert_infinite!(f32::NAN);
     }
 
     #[test]
-    fn assert_f64_eq_passing_relative_nan_nan() {
-        assert_f64_eq!(
-            f64::NAN,
-            f64::NAN,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    fn assert_normal_passing() {
+        assert_normal!(1.0_f64);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0 64-bit float ulps)")]
-    fn assert_f64_eq_failing_ulps_nan_five() {
-        assert_f64_eq!(f64::NAN, 5.0, ulps = 0, epsilon_near_zero = 0.0);
+    #[should_panic(expected = "value is normal")]
+    fn assert_normal_failing_subnormal() {
+        assert_normal!(f32::MIN_POSITIVE / 2.0);
     }
 
     #[test]
-    #[should_panic(expected = "lhs == rhs (within 0.0 relative to magnitude)")]
-    fn assert_f64_eq_failing_relative_nan_five() {
-        assert_f64_eq!(
-            f64::NAN,
-            5.0,
-            relative_epsilon = 0.0,
-            epsilon_near_zero = 0.0
-        );
+    #[should_panic(expected = "value is normal")]
+    fn assert_normal_failing_zero() {
+        assert_normal!(0.0_f64);
     }
 }
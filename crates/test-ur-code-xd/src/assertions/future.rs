@@ -0,0 +1,687 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on futures.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/future](https://sophie-katz.github.io/test-ur-code-XD/assertions/future/)
+//! for a usage guide.
+
+use std::{
+    any,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    assertions::polling::PollingOutcome, errors::TestUrCodeXDError,
+    utilities::panic_message_builder::PanicMessageBuilder,
+};
+
+/// A waker that unparks the thread that created it.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Helper function to create a waker that unparks the current thread.
+#[must_use]
+fn current_thread_waker() -> Waker {
+    Waker::from(Arc::new(ThreadWaker(thread::current())))
+}
+
+/// A probe used to check whether or not a value's type implements [`Send`] without requiring it
+/// at compile time.
+///
+/// Calling `(&SendProbe(&value)).is_send()` with both [`SendProbeIsSend`] and
+/// [`SendProbeIsNotSend`] in scope resolves to [`SendProbeIsSend::is_send`] if the value is
+/// [`Send`] and to [`SendProbeIsNotSend::is_send`] otherwise. This only works when the value's
+/// type is concrete, which is why it is used from within macros.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[allow(clippy::exhaustive_structs)]
+pub struct SendProbe<'value, ValueType>(pub &'value ValueType);
+
+/// The probe implementation chosen when the value is [`Send`].
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub trait SendProbeIsSend {
+    /// Returns `true`, since the value is [`Send`].
+    fn is_send(&self) -> bool {
+        true
+    }
+}
+
+// The default method is the implementation.
+#[allow(clippy::missing_trait_methods)]
+impl<ValueType: Send> SendProbeIsSend for SendProbe<'_, ValueType> {}
+
+/// The probe implementation chosen when the value is not [`Send`].
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub trait SendProbeIsNotSend {
+    /// Returns `false`, since the value is not [`Send`].
+    fn is_send(&self) -> bool {
+        false
+    }
+}
+
+// The default method is the implementation.
+#[allow(clippy::missing_trait_methods)]
+impl<ValueType> SendProbeIsNotSend for &SendProbe<'_, ValueType> {}
+
+/// Requires that a value is a future at compile time and returns it unchanged.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn require_future<FutureType: Future>(future: FutureType) -> FutureType {
+    future
+}
+
+/// Gets the name of a value's type.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn type_name_of<ValueType>(_value: &ValueType) -> &'static str {
+    any::type_name::<ValueType>()
+}
+
+/// Configures a panic message builder for
+/// [`assert_send_across_await`](crate::assert_send_across_await).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `future_description` - The description of the future expression
+/// * `type_name` - The name of the future's type
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_send_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    future_description: &str,
+    type_name: &str,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder.with_argument_formatted(
+        "future",
+        future_description,
+        format!("type {type_name}"),
+    )
+}
+
+/// Asserts that a future is [`Send`].
+///
+/// A future is only [`Send`] if every value that it holds across an `.await` is [`Send`]. Holding
+/// something like an [`Rc`](std::rc::Rc) or a [`MutexGuard`](std::sync::MutexGuard) across an
+/// `.await` silently makes the future `!Send`, which breaks callers that spawn it onto a
+/// multi-threaded executor. This assertion catches that in a test of the library instead of in a
+/// downstream crate.
+///
+/// The check happens at runtime so that the test fails with the future's type name instead of
+/// failing to compile.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/future](https://sophie-katz.github.io/test-ur-code-XD/assertions/future/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `future` - The future to check. It must be a concrete type, not a generic parameter.
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// The future, so that it can still be awaited or polled.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_send_across_await;
+/// #
+/// async fn fetch() -> i32 {
+///     42
+/// }
+///
+/// let future = assert_send_across_await!(fetch());
+/// ```
+#[macro_export]
+macro_rules! assert_send_across_await {
+    ($future:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let future = $crate::assertions::future::require_future($future);
+
+        let is_send = {
+            #[allow(unused_imports)]
+            use $crate::assertions::future::{SendProbeIsNotSend, SendProbeIsSend};
+
+            (&$crate::assertions::future::SendProbe(&future)).is_send()
+        };
+
+        $crate::assert_custom!(
+            "future is Send",
+            is_send,
+            |panic_message_builder| {
+                $crate::assertions::future::configure_send_panic_message(
+                    panic_message_builder,
+                    stringify!($future),
+                    $crate::assertions::future::type_name_of(&future),
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        future
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn poll_once<FutureType: Future + Unpin + ?Sized>(
+    future: &mut FutureType,
+) -> Poll<FutureType::Output> {
+    let waker = current_thread_waker();
+    let mut context = Context::from_waker(&waker);
+
+    Pin::new(future).poll(&mut context)
+}
+
+/// Runs a future on the current thread until it resolves or the timeout is reached.
+///
+/// This is a minimal executor: the current thread is parked until the future's waker is called.
+/// Futures that depend on a runtime like Tokio for I/O or timers will never be woken.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn block_on_with_timeout<FutureType: Future>(
+    future: FutureType,
+    timeout: Duration,
+) -> (Option<FutureType::Output>, PollingOutcome) {
+    let start = Instant::now();
+    let waker = current_thread_waker();
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    let mut attempt_count: usize = 0;
+
+    loop {
+        attempt_count = attempt_count.saturating_add(1);
+
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return (
+                Some(output),
+                PollingOutcome {
+                    is_satisfied: true,
+                    attempt_count,
+                    elapsed: start.elapsed(),
+                },
+            );
+        }
+
+        let elapsed = start.elapsed();
+
+        match timeout.checked_sub(elapsed) {
+            Some(remaining) if !remaining.is_zero() => thread::park_timeout(remaining),
+            _ => {
+                return (
+                    None,
+                    PollingOutcome {
+                        is_satisfied: false,
+                        attempt_count,
+                        elapsed,
+                    },
+                )
+            }
+        }
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_poll_panic_message<OutputType>(
+    panic_message_builder: PanicMessageBuilder,
+    future_description: &str,
+    poll: &Poll<OutputType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder.with_argument_formatted(
+        "future",
+        future_description,
+        if poll.is_ready() {
+            format!("ready with {}", any::type_name::<OutputType>())
+        } else {
+            "pending".to_owned()
+        },
+    )
+}
+
+/// Asserts that a future is ready after being polled once.
+///
+/// The future is polled a single time on the current thread without an async runtime, which is
+/// useful for testing hand-written futures and async state machines one step at a time.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/future](https://sophie-katz.github.io/test-ur-code-XD/assertions/future/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `future` - The future to poll. It must be [`Unpin`], so pin futures from `async` blocks and
+///              functions with [`Box::pin`] first.
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// The future's output, or `None` if it was pending. This can only be `None` if the assertion
+/// doesn't panic, like when it is negated.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_ready;
+/// #
+/// let mut future = Box::pin(async { 42 });
+///
+/// assert_eq!(assert_ready!(future), Some(42));
+/// ```
+#[macro_export]
+macro_rules! assert_ready {
+    ($future:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let poll = $crate::assertions::future::poll_once(&mut $future);
+
+        $crate::assert_custom!(
+            "future is ready",
+            poll.is_ready(),
+            |panic_message_builder| {
+                $crate::assertions::future::configure_poll_panic_message(
+                    panic_message_builder,
+                    stringify!($future),
+                    &poll,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        match poll {
+            ::std::task::Poll::Ready(output) => ::std::option::Option::Some(output),
+            ::std::task::Poll::Pending => ::std::option::Option::None,
+        }
+    }};
+}
+
+/// Asserts that a future is pending after being polled once.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/future](https://sophie-katz.github.io/test-ur-code-XD/assertions/future/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `future` - The future to poll. It must be [`Unpin`], so pin futures from `async` blocks and
+///              functions with [`Box::pin`] first.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_pending;
+/// #
+/// let mut future = std::future::pending::<i32>();
+///
+/// assert_pending!(future);
+/// ```
+#[macro_export]
+macro_rules! assert_pending {
+    ($future:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let poll = $crate::assertions::future::poll_once(&mut $future);
+
+        $crate::assert_custom!(
+            "future is pending",
+            poll.is_pending(),
+            |panic_message_builder| {
+                $crate::assertions::future::configure_poll_panic_message(
+                    panic_message_builder,
+                    stringify!($future),
+                    &poll,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that a future resolves within a timeout.
+///
+/// The future is run on the current thread by a minimal executor that sleeps until the future's
+/// waker is called, so no async runtime is needed. Futures that depend on a runtime like Tokio for
+/// I/O or timers will never be woken and will fail once the timeout is reached.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/future](https://sophie-katz.github.io/test-ur-code-XD/assertions/future/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `future` - The future to run.
+/// * `timeout` - The maximum [`Duration`](std::time::Duration) to wait.
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// The future's output, or `None` if it didn't resolve. This can only be `None` if the assertion
+/// doesn't panic, like when it is negated.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_resolves_within;
+/// use std::time::Duration;
+///
+/// async fn fetch() -> i32 {
+///     42
+/// }
+///
+/// assert_eq!(
+///     assert_resolves_within!(fetch(), Duration::from_secs(1)),
+///     Some(42)
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_resolves_within {
+    ($future:expr, $timeout:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let timeout = $timeout;
+
+        let (output, outcome) =
+            $crate::assertions::future::block_on_with_timeout($future, timeout);
+
+        $crate::assert_custom!(
+            "future resolves within timeout",
+            outcome.is_satisfied,
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_polling_panic_message(
+                    panic_message_builder.with_argument_formatted(
+                        "future",
+                        stringify!($future),
+                        "still pending",
+                    )?,
+                    &outcome,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        output
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, utilities::assertion_failure::capture_assertion_failure};
+    use std::{future, panic::Location, rc::Rc, sync::Mutex};
+
+    use crate::utilities::{
+        color_choice::{set_color_choice, ColorChoice},
+        panic_message_builder::MessageType,
+    };
+
+    async fn send_future() -> i32 {
+        let value = Box::new(1);
+
+        future::ready(()).await;
+
+        *value
+    }
+
+    async fn not_send_future() -> i32 {
+        let value = Rc::new(1);
+
+        future::ready(()).await;
+
+        *value
+    }
+
+    /// A future that resolves after a delay, woken by a background thread.
+    struct Delay {
+        /// How long to wait before resolving.
+        duration: Duration,
+
+        /// Whether or not the delay has finished, shared with the background thread.
+        is_finished: Arc<Mutex<bool>>,
+
+        /// Whether or not the background thread has been started.
+        is_started: bool,
+    }
+
+    impl Delay {
+        fn new(duration: Duration) -> Self {
+            Self {
+                duration,
+                is_finished: Arc::new(Mutex::new(false)),
+                is_started: false,
+            }
+        }
+    }
+
+    impl Future for Delay {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+            if *self.is_finished.lock().unwrap() {
+                return Poll::Ready(());
+            }
+
+            if !self.is_started {
+                self.is_started = true;
+
+                let duration = self.duration;
+                let is_finished = Arc::clone(&self.is_finished);
+                let waker = context.waker().clone();
+
+                thread::spawn(move || {
+                    thread::sleep(duration);
+                    *is_finished.lock().unwrap() = true;
+                    waker.wake();
+                });
+            }
+
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn send_probe_send() {
+        let value = 1;
+
+        assert_eq!(SendProbe(&value).is_send(), true);
+    }
+
+    #[test]
+    fn send_probe_not_send() {
+        let value = Rc::new(1);
+
+        assert_eq!((&SendProbe(&value)).is_send(), false);
+    }
+
+    #[test]
+    fn type_name_of_simple() {
+        assert_eq!(type_name_of(&1_i32), "i32");
+    }
+
+    #[test]
+    fn configure_send_panic_message_includes_type() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = configure_send_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "future is Send",
+                Location::caller(),
+            ),
+            "fetch()",
+            "Fetch",
+        )
+        .map(PanicMessageBuilder::format)
+        .unwrap_or_default();
+
+        assert!(message.contains("type Fetch"));
+    }
+
+    #[test]
+    fn assert_send_across_await_passing() {
+        let _future = assert_send_across_await!(send_future());
+    }
+
+    #[test]
+    fn assert_send_across_await_passing_negate() {
+        let _future = assert_send_across_await!(not_send_future(), negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "future is Send")]
+    fn assert_send_across_await_failing() {
+        let _future = assert_send_across_await!(not_send_future());
+    }
+
+    #[test]
+    fn assert_ready_passing() {
+        let mut future = future::ready(5);
+
+        assert_eq!(assert_ready!(future), Some(5));
+    }
+
+    #[test]
+    fn assert_ready_passing_async_block() {
+        let mut future = Box::pin(async { 5 });
+
+        assert_eq!(assert_ready!(future), Some(5));
+    }
+
+    #[test]
+    fn assert_ready_failing() {
+        let failure = capture_assertion_failure(|| {
+            let mut future = future::pending::<i32>();
+
+            assert_ready!(future);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "future is ready");
+        assert_eq!(failure.argument("future").unwrap().value, "pending");
+    }
+
+    #[test]
+    fn assert_ready_passing_negate() {
+        let mut future = future::pending::<i32>();
+
+        assert!(assert_ready!(future, negate = true).is_none());
+    }
+
+    #[test]
+    fn assert_pending_passing() {
+        let mut future = Box::pin(Delay::new(Duration::from_secs(5)));
+
+        assert_pending!(future);
+    }
+
+    #[test]
+    fn assert_pending_failing() {
+        let failure = capture_assertion_failure(|| {
+            let mut future = future::ready(5_i32);
+
+            assert_pending!(future);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "future is pending");
+        assert_eq!(failure.argument("future").unwrap().value, "ready with i32");
+    }
+
+    #[test]
+    fn assert_pending_then_ready() {
+        let mut future = Box::pin(Delay::new(Duration::from_millis(10)));
+
+        assert_pending!(future);
+
+        thread::sleep(Duration::from_millis(50));
+
+        assert_ready!(future);
+    }
+
+    #[test]
+    fn assert_resolves_within_passing() {
+        assert_eq!(
+            assert_resolves_within!(async { 5 }, Duration::from_secs(5)),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn assert_resolves_within_passing_woken() {
+        assert!(assert_resolves_within!(
+            Delay::new(Duration::from_millis(10)),
+            Duration::from_secs(5)
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn assert_resolves_within_failing() {
+        let failure = capture_assertion_failure(|| {
+            assert_resolves_within!(future::pending::<i32>(), Duration::from_millis(10));
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "future resolves within timeout");
+        assert_eq!(failure.argument("future").unwrap().value, "still pending");
+        assert!(failure
+            .argument("elapsed")
+            .unwrap()
+            .value
+            .contains("budget 10 ms"));
+    }
+
+    #[test]
+    fn assert_resolves_within_passing_negate() {
+        assert!(assert_resolves_within!(
+            future::pending::<i32>(),
+            Duration::from_millis(10),
+            negate = true
+        )
+        .is_none());
+    }
+}
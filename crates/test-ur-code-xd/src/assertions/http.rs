@@ -0,0 +1,573 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on HTTP responses from the [`http`](https://crates.io/crates/http)
+//! crate.
+//!
+//! Every assertion accepts an [`http::Response`] with any body that implements `AsRef<[u8]>`, like
+//! `Bytes`, `Vec<u8>`, or `String`. Failure messages show the status, headers, and a pretty-printed
+//! and truncated body.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/http](https://sophie-katz.github.io/test-ur-code-XD/assertions/http/)
+//! for a usage guide.
+
+use std::str;
+
+use ::http::{Response, StatusCode};
+use serde_json::Value;
+
+use crate::{
+    assertions::json::{
+        find_json_differences, format_json_differences, JsonComparisonMode, JsonDifference,
+    },
+    errors::TestUrCodeXDError,
+    utilities::panic_message_builder::PanicMessageBuilder,
+};
+
+/// The maximum number of body lines to show in failure messages.
+const MAX_BODY_LINE_COUNT: usize = 20;
+
+/// Helper function to format a response body for a failure message.
+///
+/// JSON bodies are pretty-printed, other text bodies are shown as is, and binary bodies are only
+/// described by their length. Bodies with more than [`MAX_BODY_LINE_COUNT`] lines are truncated.
+#[must_use]
+fn format_http_body(body: &[u8]) -> String {
+    if body.is_empty() {
+        return "(empty body)".to_owned();
+    }
+
+    let text = if let Ok(value) = serde_json::from_slice::<Value>(body) {
+        serde_json::to_string_pretty(&value).unwrap_or_else(|error| error.to_string())
+    } else if let Ok(text) = str::from_utf8(body) {
+        text.to_owned()
+    } else {
+        return format!("({} bytes of binary data)", body.len());
+    };
+
+    let line_count = text.lines().count();
+
+    if line_count <= MAX_BODY_LINE_COUNT {
+        return text;
+    }
+
+    let mut lines: Vec<String> = text
+        .lines()
+        .take(MAX_BODY_LINE_COUNT)
+        .map(ToOwned::to_owned)
+        .collect();
+
+    lines.push(format!(
+        "... ({} more lines)",
+        line_count.saturating_sub(MAX_BODY_LINE_COUNT)
+    ));
+
+    lines.join("\n")
+}
+
+/// Formats an HTTP response for a failure message, with the status line, headers, and body.
+///
+/// # Arguments
+///
+/// * `response` - The response to format.
+///
+/// # Returns
+///
+/// The formatted response.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::http::format_http_response;
+/// #
+/// let response = http::Response::builder()
+///     .status(404)
+///     .header("content-type", "text/plain")
+///     .body("not found")
+///     .unwrap();
+///
+/// assert_eq!(
+///     format_http_response(&response),
+///     "HTTP/1.1 404 Not Found\ncontent-type: text/plain\n\nnot found"
+/// );
+/// ```
+#[must_use]
+pub fn format_http_response<BodyType: AsRef<[u8]>>(response: &Response<BodyType>) -> String {
+    let mut lines = vec![format!("{:?} {}", response.version(), response.status())];
+
+    for (name, value) in response.headers() {
+        lines.push(format!(
+            "{name}: {}",
+            value.to_str().unwrap_or("(binary value)")
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format_http_body(response.body().as_ref()));
+
+    lines.join("\n")
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn header_values<BodyType>(response: &Response<BodyType>, name: &str) -> Vec<String> {
+    response
+        .headers()
+        .get_all(name)
+        .iter()
+        .map(|value| {
+            value
+                .to_str()
+                .map_or_else(|_| "(binary value)".to_owned(), ToOwned::to_owned)
+        })
+        .collect()
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn find_body_json_differences<BodyType: AsRef<[u8]>>(
+    response: &Response<BodyType>,
+    expected: &Value,
+) -> Result<Vec<JsonDifference>, serde_json::Error> {
+    serde_json::from_slice::<Value>(response.body().as_ref())
+        .map(|actual| find_json_differences(&actual, expected, JsonComparisonMode::Contains))
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_http_response_panic_message<BodyType: AsRef<[u8]>>(
+    panic_message_builder: PanicMessageBuilder,
+    response_description: &str,
+    response: &Response<BodyType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder.with_argument_formatted(
+        "response",
+        response_description,
+        format_http_response(response),
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_header_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    name: &str,
+    values: &[String],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder.with_argument_formatted(
+        "header",
+        name,
+        if values.is_empty() {
+            "(missing)".to_owned()
+        } else {
+            values.join("\n")
+        },
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_body_json_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    differences: &Result<Vec<JsonDifference>, serde_json::Error>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    match differences {
+        Ok(differences) => panic_message_builder.with_argument_formatted(
+            "differences",
+            "--",
+            format_json_differences(differences),
+        ),
+        Err(error) => panic_message_builder.with_argument_formatted(
+            "body",
+            "--",
+            format!("not valid JSON: {error}"),
+        ),
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn status_of<BodyType>(response: &Response<BodyType>) -> StatusCode {
+    response.status()
+}
+
+/// Asserts that an HTTP response has a status code.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/http](https://sophie-katz.github.io/test-ur-code-XD/assertions/http/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `response` - The [`http::Response`]. Its body can be anything that implements `AsRef<[u8]>`.
+/// * `expected` - The expected status code, either as a [`StatusCode`] or a `u16`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_status_eq;
+/// use http::{Response, StatusCode};
+///
+/// let response = Response::builder().status(201).body(Vec::new()).unwrap();
+///
+/// assert_status_eq!(response, StatusCode::CREATED);
+/// assert_status_eq!(response, 201);
+/// ```
+#[macro_export]
+macro_rules! assert_status_eq {
+    ($response:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let response = &$response;
+        let status = $crate::assertions::http::status_of(response);
+        let expected = $expected;
+
+        $crate::assert_custom!(
+            "response status == expected",
+            status == expected,
+            |panic_message_builder| {
+                $crate::assertions::http::configure_http_response_panic_message(
+                    panic_message_builder
+                        .with_argument_formatted("status", "--", status.to_string())?
+                        .with_argument("expected", stringify!($expected), &expected)?,
+                    stringify!($response),
+                    response,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that an HTTP response has a header with a value.
+///
+/// Header names are case-insensitive. The assertion fails if the header is missing or if it has
+/// any other values.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/http](https://sophie-katz.github.io/test-ur-code-XD/assertions/http/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `response` - The [`http::Response`]. Its body can be anything that implements `AsRef<[u8]>`.
+/// * `name` - The name of the header.
+/// * `expected` - The expected value of the header.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_header_eq;
+/// use http::Response;
+///
+/// let response = Response::builder()
+///     .header("Content-Type", "application/json")
+///     .body(Vec::new())
+///     .unwrap();
+///
+/// assert_header_eq!(response, "content-type", "application/json");
+/// ```
+#[macro_export]
+macro_rules! assert_header_eq {
+    ($response:expr, $name:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let response = &$response;
+        let name = $name;
+        let expected = $expected;
+
+        let values = $crate::assertions::http::header_values(
+            response,
+            ::std::convert::AsRef::<str>::as_ref(&name),
+        );
+
+        $crate::assert_custom!(
+            "response header == expected",
+            values.len() == 1
+                && values.first().map(::std::string::String::as_str)
+                    == ::std::option::Option::Some(::std::convert::AsRef::<str>::as_ref(&expected)),
+            |panic_message_builder| {
+                $crate::assertions::http::configure_http_response_panic_message(
+                    $crate::assertions::http::configure_header_panic_message(
+                        panic_message_builder,
+                        ::std::convert::AsRef::<str>::as_ref(&name),
+                        &values,
+                    )?
+                    .with_argument(
+                        "expected",
+                        stringify!($expected),
+                        &::std::convert::AsRef::<str>::as_ref(&expected),
+                    )?,
+                    stringify!($response),
+                    response,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that the JSON body of an HTTP response matches an expected value.
+///
+/// The body matches if it contains the expected value, like with
+/// [`assert_json_contains`](crate::assert_json_contains). Objects in the body may have keys that
+/// the expected value does not, so fields like IDs and timestamps can be left out. The assertion
+/// fails if the body is not valid JSON.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/http](https://sophie-katz.github.io/test-ur-code-XD/assertions/http/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `response` - The [`http::Response`]. Its body can be anything that implements `AsRef<[u8]>`.
+/// * `expected` - The subset that the body must contain. This can be a [`serde_json::Value`] or
+///                anything that implements [`serde::Serialize`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::json;
+/// # use test_ur_code_xd::assert_body_json_matches;
+/// use http::Response;
+///
+/// let response = Response::builder()
+///     .body(r#"{ "id": 5, "name": "Sophie" }"#)
+///     .unwrap();
+///
+/// assert_body_json_matches!(response, json!({ "name": "Sophie" }));
+/// ```
+#[macro_export]
+macro_rules! assert_body_json_matches {
+    ($response:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let response = &$response;
+
+        let differences = $crate::assertions::http::find_body_json_differences(
+            response,
+            &$crate::assertions::json::to_json_value(&$expected),
+        );
+
+        $crate::assert_custom!(
+            "response body matches expected JSON",
+            differences.as_ref().map_or(false, ::std::vec::Vec::is_empty),
+            |panic_message_builder| {
+                $crate::assertions::http::configure_http_response_panic_message(
+                    $crate::assertions::http::configure_body_json_panic_message(
+                        panic_message_builder,
+                        &differences,
+                    )?,
+                    stringify!($response),
+                    response,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, utilities::assertion_failure::capture_assertion_failure};
+    use serde_json::json;
+
+    fn json_response(status: u16, body: &str) -> Response<Vec<u8>> {
+        Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body.as_bytes().to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn format_body_empty() {
+        assert_eq!(format_http_body(b""), "(empty body)");
+    }
+
+    #[test]
+    fn format_body_json_pretty() {
+        assert_eq!(
+            format_http_body(br#"{"a":[1,2]}"#),
+            "{\n  \"a\": [\n    1,\n    2\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn format_body_text() {
+        assert_eq!(format_http_body(b"not found"), "not found");
+    }
+
+    #[test]
+    fn format_body_binary() {
+        assert_eq!(
+            format_http_body(&[0xff, 0xfe, 0x00]),
+            "(3 bytes of binary data)"
+        );
+    }
+
+    #[test]
+    fn format_body_truncated() {
+        let body = (0..25)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let formatted = format_http_body(body.as_bytes());
+
+        assert_eq!(formatted.lines().count(), MAX_BODY_LINE_COUNT + 1);
+        assert!(formatted.ends_with("\n... (5 more lines)"));
+    }
+
+    #[test]
+    fn format_response_with_headers() {
+        assert_eq!(
+            format_http_response(&json_response(200, r#"{"ok":true}"#)),
+            "HTTP/1.1 200 OK\ncontent-type: application/json\n\n{\n  \"ok\": true\n}"
+        );
+    }
+
+    #[test]
+    fn header_values_multiple() {
+        let response = Response::builder()
+            .header("set-cookie", "a=1")
+            .header("Set-Cookie", "b=2")
+            .body(())
+            .unwrap();
+
+        assert_eq!(header_values(&response, "set-cookie"), vec!["a=1", "b=2"]);
+        assert!(header_values(&response, "content-type").is_empty());
+    }
+
+    #[test]
+    fn assert_status_eq_passing() {
+        let response = json_response(201, "{}");
+
+        assert_status_eq!(response, StatusCode::CREATED);
+        assert_status_eq!(response, 201);
+    }
+
+    #[test]
+    fn assert_status_eq_passing_negate() {
+        assert_status_eq!(json_response(500, "{}"), 200, negate = true);
+    }
+
+    #[test]
+    fn assert_status_eq_failing() {
+        let failure = capture_assertion_failure(|| {
+            assert_status_eq!(json_response(404, r#"{"error":"not found"}"#), 200);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "response status == expected");
+        assert_eq!(failure.argument("status").unwrap().value, "404 Not Found");
+        assert_eq!(failure.argument("expected").unwrap().value, "200");
+        assert!(failure
+            .argument("response")
+            .unwrap()
+            .value
+            .contains("\"error\": \"not found\""));
+    }
+
+    #[test]
+    fn assert_header_eq_passing() {
+        assert_header_eq!(json_response(200, "{}"), "Content-Type", "application/json");
+    }
+
+    #[test]
+    fn assert_header_eq_failing_missing() {
+        let failure = capture_assertion_failure(|| {
+            assert_header_eq!(json_response(200, "{}"), "x-request-id", "abc");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "response header == expected");
+        assert_eq!(failure.argument("header").unwrap().value, "(missing)");
+        assert_eq!(
+            failure.argument("header").unwrap().value_description,
+            "x-request-id"
+        );
+    }
+
+    #[test]
+    fn assert_header_eq_failing_multiple_values() {
+        let response = Response::builder()
+            .header("cache-control", "no-cache")
+            .header("cache-control", "no-store")
+            .body(Vec::new())
+            .unwrap();
+
+        let failure = capture_assertion_failure(|| {
+            assert_header_eq!(response, "cache-control", "no-cache");
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("header").unwrap().value,
+            "no-cache\nno-store"
+        );
+    }
+
+    #[test]
+    fn assert_body_json_matches_passing() {
+        assert_body_json_matches!(
+            json_response(200, r#"{"id":5,"name":"Sophie","tags":["a","b"]}"#),
+            json!({ "name": "Sophie", "tags": ["a"] })
+        );
+    }
+
+    #[test]
+    fn assert_body_json_matches_failing() {
+        let failure = capture_assertion_failure(|| {
+            assert_body_json_matches!(
+                json_response(200, r#"{"name":"Sophie"}"#),
+                json!({ "name": "Katz" })
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "response body matches expected JSON");
+        assert_eq!(
+            failure.argument("differences").unwrap().value,
+            r#"$.name: expected "Katz", found "Sophie""#
+        );
+    }
+
+    #[test]
+    fn assert_body_json_matches_failing_invalid_json() {
+        let failure = capture_assertion_failure(|| {
+            assert_body_json_matches!(
+                json_response(500, "internal error"),
+                json!({ "name": "Sophie" })
+            );
+        })
+        .unwrap();
+
+        assert!(failure
+            .argument("body")
+            .unwrap()
+            .value
+            .starts_with("not valid JSON: "));
+    }
+}
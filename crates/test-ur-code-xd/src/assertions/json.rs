@@ -0,0 +1,529 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that compare JSON values structurally.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/json](https://sophie-katz.github.io/test-ur-code-XD/assertions/json/)
+//! for a usage guide.
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::utilities::panic_message_builder::{MessageType, PanicMessageBuilder};
+
+/// The path of the root JSON value.
+const ROOT_PATH: &str = "$";
+
+/// How to compare two JSON values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum JsonComparisonMode {
+    /// The values must be exactly equal.
+    Equal,
+
+    /// The actual value must contain the expected value.
+    ///
+    /// Objects in the actual value may have keys that are not in the expected value, and arrays in
+    /// the actual value may have extra elements after the ones in the expected value.
+    Contains,
+}
+
+/// A single difference between two JSON values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum JsonDifference {
+    /// The values at the path are different.
+    Mismatch {
+        /// The path to the value, like `$.items[3].name`.
+        path: String,
+
+        /// The actual value at the path.
+        actual: Value,
+
+        /// The expected value at the path.
+        expected: Value,
+    },
+
+    /// The expected value has a value at the path, but the actual value does not.
+    Missing {
+        /// The path to the value, like `$.items[3].name`.
+        path: String,
+
+        /// The expected value at the path.
+        expected: Value,
+    },
+
+    /// The actual value has a value at the path, but the expected value does not.
+    Unexpected {
+        /// The path to the value, like `$.items[3].name`.
+        path: String,
+
+        /// The actual value at the path.
+        actual: Value,
+    },
+}
+
+impl Display for JsonDifference {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mismatch {
+                path,
+                actual,
+                expected,
+            } => write!(formatter, "{path}: expected {expected}, found {actual}"),
+            Self::Missing { path, expected } => {
+                write!(formatter, "{path}: missing, expected {expected}")
+            }
+            Self::Unexpected { path, actual } => write!(formatter, "{path}: unexpected {actual}"),
+        }
+    }
+}
+
+/// Helper function to append an object key to a path.
+///
+/// Keys that are valid identifiers use dot notation like `$.name`, and all other keys use bracket
+/// notation like `$["first name"]`.
+#[must_use]
+fn append_key_to_path(path: &str, key: &str) -> String {
+    let is_identifier = key
+        .chars()
+        .next()
+        .map_or(false, |first| first.is_ascii_alphabetic() || first == '_')
+        && key
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '_');
+
+    if is_identifier {
+        format!("{path}.{key}")
+    } else {
+        format!("{path}[{}]", Value::String(key.to_owned()))
+    }
+}
+
+/// Helper function to recursively collect the differences between two JSON values.
+fn collect_json_differences(
+    path: &str,
+    actual: &Value,
+    expected: &Value,
+    mode: JsonComparisonMode,
+    differences: &mut Vec<JsonDifference>,
+) {
+    match (actual, expected) {
+        (Value::Object(actual_map), Value::Object(expected_map)) => {
+            for (key, expected_value) in expected_map {
+                let key_path = append_key_to_path(path, key);
+
+                match actual_map.get(key) {
+                    Some(actual_value) => collect_json_differences(
+                        &key_path,
+                        actual_value,
+                        expected_value,
+                        mode,
+                        differences,
+                    ),
+                    None => differences.push(JsonDifference::Missing {
+                        path: key_path,
+                        expected: expected_value.clone(),
+                    }),
+                }
+            }
+
+            if mode == JsonComparisonMode::Equal {
+                for (key, actual_value) in actual_map {
+                    if !expected_map.contains_key(key) {
+                        differences.push(JsonDifference::Unexpected {
+                            path: append_key_to_path(path, key),
+                            actual: actual_value.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        (Value::Array(actual_array), Value::Array(expected_array)) => {
+            for (index, expected_value) in expected_array.iter().enumerate() {
+                let index_path = format!("{path}[{index}]");
+
+                match actual_array.get(index) {
+                    Some(actual_value) => collect_json_differences(
+                        &index_path,
+                        actual_value,
+                        expected_value,
+                        mode,
+                        differences,
+                    ),
+                    None => differences.push(JsonDifference::Missing {
+                        path: index_path,
+                        expected: expected_value.clone(),
+                    }),
+                }
+            }
+
+            if mode == JsonComparisonMode::Equal {
+                for (index, actual_value) in
+                    actual_array.iter().enumerate().skip(expected_array.len())
+                {
+                    differences.push(JsonDifference::Unexpected {
+                        path: format!("{path}[{index}]"),
+                        actual: actual_value.clone(),
+                    });
+                }
+            }
+        }
+        _ => {
+            if actual != expected {
+                differences.push(JsonDifference::Mismatch {
+                    path: path.to_owned(),
+                    actual: actual.clone(),
+                    expected: expected.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Finds the structural differences between two JSON values.
+///
+/// # Arguments
+///
+/// * `actual` - The actual value.
+/// * `expected` - The expected value.
+/// * `mode` - How to compare the values.
+///
+/// # Returns
+///
+/// The differences in the order they were found. This is empty if the values match.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::json;
+/// # use test_ur_code_xd::assertions::json::{find_json_differences, JsonComparisonMode};
+/// #
+/// let differences = find_json_differences(
+///     &json!({ "items": [{ "name": "a" }, { "name": "b" }] }),
+///     &json!({ "items": [{ "name": "a" }, { "name": "c" }] }),
+///     JsonComparisonMode::Equal,
+/// );
+///
+/// assert_eq!(differences[0].to_string(), r#"$.items[1].name: expected "c", found "b""#);
+/// ```
+#[must_use]
+pub fn find_json_differences(
+    actual: &Value,
+    expected: &Value,
+    mode: JsonComparisonMode,
+) -> Vec<JsonDifference> {
+    let mut differences = Vec::new();
+
+    collect_json_differences(ROOT_PATH, actual, expected, mode, &mut differences);
+
+    differences
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn to_json_value(value: &impl Serialize) -> Value {
+    PanicMessageBuilder::unwrap_error_with(
+        serde_json::to_value(value),
+        MessageType::ErrorWhileCheckingAssertion,
+        "unable to serialize value as JSON",
+        PanicMessageBuilder::no_configuration,
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn format_json_differences(differences: &[JsonDifference]) -> String {
+    if differences.is_empty() {
+        "(none)".to_owned()
+    } else {
+        differences
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Asserts that two values are equal when serialized as JSON.
+///
+/// Instead of printing both values in full, the failure message lists each difference along with
+/// its path, like `$.items[3].name`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/json](https://sophie-katz.github.io/test-ur-code-XD/assertions/json/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `actual` - The actual value. This can be a [`serde_json::Value`] or anything that implements
+///              [`serde::Serialize`].
+/// * `expected` - The expected value. This can be a [`serde_json::Value`] or anything that
+///                implements [`serde::Serialize`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::json;
+/// # use test_ur_code_xd::assert_json_eq;
+/// #
+/// assert_json_eq!(
+///     json!({ "name": "Sophie", "languages": ["Rust", "C++"] }),
+///     json!({ "languages": ["Rust", "C++"], "name": "Sophie" })
+/// );
+///
+/// assert_json_eq!(json!([1, 2, 3]), json!([1, 2]), negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_json_eq {
+    ($actual:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let differences = $crate::assertions::json::find_json_differences(
+            &$crate::assertions::json::to_json_value(&$actual),
+            &$crate::assertions::json::to_json_value(&$expected),
+            $crate::assertions::json::JsonComparisonMode::Equal,
+        );
+
+        $crate::assert_custom!(
+            "actual == expected",
+            differences.is_empty(),
+            |panic_message_builder| {
+                panic_message_builder.with_argument_formatted(
+                    "differences",
+                    "--",
+                    $crate::assertions::json::format_json_differences(&differences),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that a value contains another value when both are serialized as JSON.
+///
+/// Objects in the actual value may have keys that the expected value does not, and arrays in the
+/// actual value may have extra elements after the ones in the expected value. Everything else must
+/// be equal.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/json](https://sophie-katz.github.io/test-ur-code-XD/assertions/json/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `actual` - The actual value. This can be a [`serde_json::Value`] or anything that implements
+///              [`serde::Serialize`].
+/// * `expected` - The subset that the actual value must contain. This can be a
+///                [`serde_json::Value`] or anything that implements [`serde::Serialize`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use serde_json::json;
+/// # use test_ur_code_xd::assert_json_contains;
+/// #
+/// assert_json_contains!(
+///     json!({ "id": 5, "name": "Sophie", "tags": ["a", "b"] }),
+///     json!({ "name": "Sophie", "tags": ["a"] })
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_json_contains {
+    ($actual:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let differences = $crate::assertions::json::find_json_differences(
+            &$crate::assertions::json::to_json_value(&$actual),
+            &$crate::assertions::json::to_json_value(&$expected),
+            $crate::assertions::json::JsonComparisonMode::Contains,
+        );
+
+        $crate::assert_custom!(
+            "actual contains expected",
+            differences.is_empty(),
+            |panic_message_builder| {
+                panic_message_builder.with_argument_formatted(
+                    "differences",
+                    "--",
+                    $crate::assertions::json::format_json_differences(&differences),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn append_key_identifier() {
+        assert_eq!(append_key_to_path("$", "name"), "$.name");
+        assert_eq!(append_key_to_path("$", "_name2"), "$._name2");
+    }
+
+    #[test]
+    fn append_key_not_identifier() {
+        assert_eq!(append_key_to_path("$", "first name"), r#"$["first name"]"#);
+        assert_eq!(append_key_to_path("$", "2"), r#"$["2"]"#);
+        assert_eq!(append_key_to_path("$", ""), r#"$[""]"#);
+        assert_eq!(append_key_to_path("$", "a\"b"), r#"$["a\"b"]"#);
+    }
+
+    #[test]
+    fn differences_equal() {
+        assert!(find_json_differences(
+            &json!({ "a": [1, { "b": null }] }),
+            &json!({ "a": [1, { "b": null }] }),
+            JsonComparisonMode::Equal
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn differences_nested_mismatch() {
+        assert_eq!(
+            find_json_differences(
+                &json!({ "items": [{ "name": "a" }, { "name": "b" }] }),
+                &json!({ "items": [{ "name": "a" }, { "name": "c" }] }),
+                JsonComparisonMode::Equal
+            ),
+            vec![JsonDifference::Mismatch {
+                path: "$.items[1].name".to_owned(),
+                actual: json!("b"),
+                expected: json!("c"),
+            }]
+        );
+    }
+
+    #[test]
+    fn differences_type_mismatch() {
+        assert_eq!(
+            format_json_differences(&find_json_differences(
+                &json!({ "a": [1] }),
+                &json!({ "a": { "b": 1 } }),
+                JsonComparisonMode::Equal
+            )),
+            r#"$.a: expected {"b":1}, found [1]"#
+        );
+    }
+
+    #[test]
+    fn differences_missing_and_unexpected_keys() {
+        assert_eq!(
+            format_json_differences(&find_json_differences(
+                &json!({ "a": 1, "c": 3 }),
+                &json!({ "a": 1, "b": 2 }),
+                JsonComparisonMode::Equal
+            )),
+            "$.b: missing, expected 2\n$.c: unexpected 3"
+        );
+    }
+
+    #[test]
+    fn differences_array_lengths() {
+        assert_eq!(
+            format_json_differences(&find_json_differences(
+                &json!([1, 2, 3]),
+                &json!([1, 2]),
+                JsonComparisonMode::Equal
+            )),
+            "$[2]: unexpected 3"
+        );
+
+        assert_eq!(
+            format_json_differences(&find_json_differences(
+                &json!([1]),
+                &json!([1, 2]),
+                JsonComparisonMode::Equal
+            )),
+            "$[1]: missing, expected 2"
+        );
+    }
+
+    #[test]
+    fn differences_contains() {
+        assert!(find_json_differences(
+            &json!({ "a": 1, "b": [1, 2, 3], "c": { "d": 4, "e": 5 } }),
+            &json!({ "b": [1, 2], "c": { "e": 5 } }),
+            JsonComparisonMode::Contains
+        )
+        .is_empty());
+
+        assert_eq!(
+            format_json_differences(&find_json_differences(
+                &json!({ "a": 1, "b": [1] }),
+                &json!({ "a": 2, "b": [1, 2] }),
+                JsonComparisonMode::Contains
+            )),
+            "$.a: expected 2, found 1\n$.b[1]: missing, expected 2"
+        );
+    }
+
+    #[test]
+    fn format_no_differences() {
+        assert_eq!(format_json_differences(&[]), "(none)");
+    }
+
+    #[test]
+    fn assert_json_eq_passing() {
+        assert_json_eq!(
+            json!({ "a": 1, "b": [true, null] }),
+            json!({ "b": [true, null], "a": 1 })
+        );
+    }
+
+    #[test]
+    fn assert_json_eq_passing_serialize() {
+        let map = BTreeMap::from([("a", vec![1, 2]), ("b", vec![])]);
+
+        assert_json_eq!(map, json!({ "a": [1, 2], "b": [] }));
+    }
+
+    #[test]
+    #[should_panic(expected = "actual == expected")]
+    fn assert_json_eq_failing() {
+        assert_json_eq!(json!({ "a": 1 }), json!({ "a": 2 }));
+    }
+
+    #[test]
+    fn assert_json_eq_passing_negate() {
+        assert_json_eq!(json!({ "a": 1 }), json!({ "a": 2 }), negate = true);
+    }
+
+    #[test]
+    fn assert_json_contains_passing() {
+        assert_json_contains!(json!({ "a": 1, "b": 2 }), json!({ "b": 2 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "actual contains expected")]
+    fn assert_json_contains_failing() {
+        assert_json_contains!(json!({ "a": 1 }), json!({ "b": 2 }));
+    }
+}
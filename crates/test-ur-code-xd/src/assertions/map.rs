@@ -0,0 +1,678 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on maps like [`HashMap`](std::collections::HashMap) and
+//! [`BTreeMap`](std::collections::BTreeMap).
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/map](https://sophie-katz.github.io/test-ur-code-XD/assertions/map/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// The entries of a map that fail a predicate, along with the total number of entries.
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct FailingEntries<'map, KeyType, ValueType> {
+    /// The total number of entries in the map.
+    pub entry_count: usize,
+
+    /// The entries for which the predicate returned `false`.
+    pub entries: Vec<(&'map KeyType, &'map ValueType)>,
+}
+
+/// Finds the entries of a map for which a predicate returns `false`.
+///
+/// # Arguments
+///
+/// * `map` - Anything that can be iterated over to get references to keys and values, like
+///           `&HashMap<K, V>` or `&BTreeMap<K, V>`.
+/// * `predicate` - The predicate to check each entry against.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_failing_entries<'map, KeyType: 'map, ValueType: 'map>(
+    map: impl IntoIterator<Item = (&'map KeyType, &'map ValueType)>,
+    mut predicate: impl FnMut(&'map KeyType, &'map ValueType) -> bool,
+) -> FailingEntries<'map, KeyType, ValueType> {
+    let all_entries: Vec<(&KeyType, &ValueType)> = map.into_iter().collect();
+
+    FailingEntries {
+        entry_count: all_entries.len(),
+        entries: all_entries
+            .into_iter()
+            .filter(|(key, value)| !predicate(key, value))
+            .collect(),
+    }
+}
+
+/// Finds the entries of a map whose values fail a predicate.
+///
+/// See [`find_failing_entries`].
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_failing_values<'map, KeyType: 'map, ValueType: 'map>(
+    map: impl IntoIterator<Item = (&'map KeyType, &'map ValueType)>,
+    mut predicate: impl FnMut(&ValueType) -> bool,
+) -> FailingEntries<'map, KeyType, ValueType> {
+    find_failing_entries(map, |_, value| predicate(value))
+}
+
+/// Finds the entries of a map whose keys fail a predicate.
+///
+/// See [`find_failing_entries`].
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_failing_keys<'map, KeyType: 'map, ValueType: 'map>(
+    map: impl IntoIterator<Item = (&'map KeyType, &'map ValueType)>,
+    mut predicate: impl FnMut(&KeyType) -> bool,
+) -> FailingEntries<'map, KeyType, ValueType> {
+    find_failing_entries(map, |key, _| predicate(key))
+}
+
+/// Configures a panic message builder for [`assert_map_values_all`](crate::assert_map_values_all)
+/// and [`assert_map_keys_all`](crate::assert_map_keys_all).
+///
+/// The failing entries are sorted by their formatted text so that the message is the same between
+/// runs, even for maps with an unspecified iteration order.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `map_description` - The description of the map expression
+/// * `predicate_description` - The description of the predicate expression
+/// * `failing_entries` - The entries that failed the predicate
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_map_all_panic_message<KeyType: Debug, ValueType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    map_description: &str,
+    predicate_description: &str,
+    failing_entries: &FailingEntries<'_, KeyType, ValueType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument_formatted(
+            "map",
+            map_description,
+            format!("{} entries", failing_entries.entry_count),
+        )?
+        .with_argument_formatted(
+            "predicate",
+            predicate_description,
+            format!("{} failing", failing_entries.entries.len()),
+        )?;
+
+    if failing_entries.entries.is_empty() {
+        return Ok(panic_message_builder);
+    }
+
+    let mut lines: Vec<String> = failing_entries
+        .entries
+        .iter()
+        .map(|(key, value)| format!("{key:?}: {value:?}"))
+        .collect();
+
+    lines.sort();
+
+    panic_message_builder.with_argument_formatted("failing entries", "--", lines.join("\n"))
+}
+
+/// Asserts that every value in a map satisfies a predicate.
+///
+/// Unlike asserting on each value in a loop, every failing entry is listed in the panic message,
+/// not just the first one.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/map](https://sophie-katz.github.io/test-ur-code-XD/assertions/map/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `map` - The map to check. Anything whose reference can be iterated over to get key and value
+///           references works, like [`HashMap`](std::collections::HashMap) and
+///           [`BTreeMap`](std::collections::BTreeMap). Keys and values must implement [`Debug`].
+/// * `predicate` - A closure that takes a reference to a value and returns `true` if it is valid.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_map_values_all;
+/// # use std::collections::HashMap;
+/// #
+/// let ports = HashMap::from([("http", 80), ("https", 443)]);
+///
+/// assert_map_values_all!(ports, |port| *port < 1024);
+/// ```
+#[macro_export]
+macro_rules! assert_map_values_all {
+    ($map:expr, $predicate:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let map = &$map;
+
+        let failing_entries = $crate::assertions::map::find_failing_values(map, $predicate);
+
+        $crate::assert_custom!(
+            "all map values satisfy predicate",
+            failing_entries.entries.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::map::configure_map_all_panic_message(
+                    panic_message_builder,
+                    stringify!($map),
+                    stringify!($predicate),
+                    &failing_entries,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that every key in a map satisfies a predicate.
+///
+/// Unlike asserting on each key in a loop, every failing entry is listed in the panic message, not
+/// just the first one.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/map](https://sophie-katz.github.io/test-ur-code-XD/assertions/map/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `map` - The map to check. Anything whose reference can be iterated over to get key and value
+///           references works, like [`HashMap`](std::collections::HashMap) and
+///           [`BTreeMap`](std::collections::BTreeMap). Keys and values must implement [`Debug`].
+/// * `predicate` - A closure that takes a reference to a key and returns `true` if it is valid.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_map_keys_all;
+/// # use std::collections::HashMap;
+/// #
+/// let ports = HashMap::from([("http", 80), ("https", 443)]);
+///
+/// assert_map_keys_all!(ports, |name| name.starts_with("http"));
+/// ```
+#[macro_export]
+macro_rules! assert_map_keys_all {
+    ($map:expr, $predicate:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let map = &$map;
+
+        let failing_entries = $crate::assertions::map::find_failing_keys(map, $predicate);
+
+        $crate::assert_custom!(
+            "all map keys satisfy predicate",
+            failing_entries.entries.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::map::configure_map_all_panic_message(
+                    panic_message_builder,
+                    stringify!($map),
+                    stringify!($predicate),
+                    &failing_entries,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// The differences between two maps, broken down by key.
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct MapDifference<'map, KeyType, ValueType> {
+    /// The total number of entries in the actual map.
+    pub actual_entry_count: usize,
+
+    /// The total number of entries in the expected map.
+    pub expected_entry_count: usize,
+
+    /// The entries of the expected map whose keys are not in the actual map.
+    pub missing_entries: Vec<(&'map KeyType, &'map ValueType)>,
+
+    /// The entries of the actual map whose keys are not in the expected map.
+    pub unexpected_entries: Vec<(&'map KeyType, &'map ValueType)>,
+
+    /// The keys that are in both maps with different values, along with the actual and expected
+    /// values.
+    pub differing_entries: Vec<(&'map KeyType, &'map ValueType, &'map ValueType)>,
+}
+
+impl<KeyType, ValueType> MapDifference<'_, KeyType, ValueType> {
+    /// Checks whether or not the maps are equal.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing_entries.is_empty()
+            && self.unexpected_entries.is_empty()
+            && self.differing_entries.is_empty()
+    }
+}
+
+/// Finds the differences between two maps.
+///
+/// Keys are only required to implement [`PartialEq`] so that this works for any kind of map, so
+/// the comparison takes quadratic time. This is fine for the size of maps usually found in tests.
+///
+/// # Arguments
+///
+/// * `actual` - Anything that can be iterated over to get references to keys and values, like
+///              `&HashMap<K, V>` or `&BTreeMap<K, V>`.
+/// * `expected` - The map that `actual` is expected to equal.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_map_difference<'map, KeyType: PartialEq + 'map, ValueType: PartialEq + 'map>(
+    actual: impl IntoIterator<Item = (&'map KeyType, &'map ValueType)>,
+    expected: impl IntoIterator<Item = (&'map KeyType, &'map ValueType)>,
+) -> MapDifference<'map, KeyType, ValueType> {
+    let actual_entries: Vec<(&KeyType, &ValueType)> = actual.into_iter().collect();
+    let expected_entries: Vec<(&KeyType, &ValueType)> = expected.into_iter().collect();
+
+    let mut missing_entries = Vec::new();
+    let mut differing_entries = Vec::new();
+
+    for (expected_key, expected_value) in &expected_entries {
+        match actual_entries
+            .iter()
+            .find(|(actual_key, _)| actual_key == expected_key)
+        {
+            None => missing_entries.push((*expected_key, *expected_value)),
+            Some((_, actual_value)) if actual_value != expected_value => {
+                differing_entries.push((*expected_key, *actual_value, *expected_value));
+            }
+            Some(_) => {}
+        }
+    }
+
+    let unexpected_entries = actual_entries
+        .iter()
+        .filter(|(actual_key, _)| {
+            !expected_entries
+                .iter()
+                .any(|(expected_key, _)| expected_key == actual_key)
+        })
+        .copied()
+        .collect();
+
+    MapDifference {
+        actual_entry_count: actual_entries.len(),
+        expected_entry_count: expected_entries.len(),
+        missing_entries,
+        unexpected_entries,
+        differing_entries,
+    }
+}
+
+/// Configures a panic message builder for [`assert_map_eq`](crate::assert_map_eq).
+///
+/// Each kind of difference is listed separately, sorted by its formatted text so that the message is
+/// the same between runs, even for maps with an unspecified iteration order.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `actual_description` - The description of the actual map expression
+/// * `expected_description` - The description of the expected map expression
+/// * `map_difference` - The differences between the maps
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_map_eq_panic_message<KeyType: Debug, ValueType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    actual_description: &str,
+    expected_description: &str,
+    map_difference: &MapDifference<'_, KeyType, ValueType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let mut panic_message_builder = panic_message_builder
+        .with_argument_formatted(
+            "actual",
+            actual_description,
+            format!("{} entries", map_difference.actual_entry_count),
+        )?
+        .with_argument_formatted(
+            "expected",
+            expected_description,
+            format!("{} entries", map_difference.expected_entry_count),
+        )?;
+
+    let sections = [
+        (
+            "missing keys",
+            map_difference
+                .missing_entries
+                .iter()
+                .map(|(key, value)| format!("{key:?}: {value:?}"))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "unexpected keys",
+            map_difference
+                .unexpected_entries
+                .iter()
+                .map(|(key, value)| format!("{key:?}: {value:?}"))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "differing values",
+            map_difference
+                .differing_entries
+                .iter()
+                .map(|(key, actual_value, expected_value)| {
+                    format!("{key:?}: {actual_value:?} (expected {expected_value:?})")
+                })
+                .collect::<Vec<_>>(),
+        ),
+    ];
+
+    for (name, mut lines) in sections {
+        if lines.is_empty() {
+            continue;
+        }
+
+        lines.sort();
+
+        panic_message_builder =
+            panic_message_builder.with_argument_formatted(name, "--", lines.join("\n"))?;
+    }
+
+    Ok(panic_message_builder)
+}
+
+/// Asserts that two maps have the same keys, and the same value for each key.
+///
+/// Unlike comparing the maps with [`assert_eq`](crate::assert_eq), the panic message lists missing
+/// keys, unexpected keys, and keys with differing values separately instead of showing both maps in
+/// full.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/map](https://sophie-katz.github.io/test-ur-code-XD/assertions/map/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `actual` - The map to check. Anything whose reference can be iterated over to get key and
+///              value references works, like [`HashMap`](std::collections::HashMap) and
+///              [`BTreeMap`](std::collections::BTreeMap). Keys and values must implement
+///              [`PartialEq`] and [`Debug`].
+/// * `expected` - The map that `actual` is expected to equal. It does not have to be the same kind
+///                of map as `actual`, but must have the same key and value types.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_map_eq;
+/// # use std::collections::{BTreeMap, HashMap};
+/// #
+/// let ports = HashMap::from([("http", 80), ("https", 443)]);
+///
+/// assert_map_eq!(ports, HashMap::from([("https", 443), ("http", 80)]));
+///
+/// assert_map_eq!(ports, BTreeMap::from([("http", 80), ("https", 443)]));
+/// ```
+#[macro_export]
+macro_rules! assert_map_eq {
+    ($actual:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let actual = &$actual;
+        let expected = &$expected;
+
+        let map_difference = $crate::assertions::map::find_map_difference(actual, expected);
+
+        $crate::assert_custom!(
+            "actual map == expected map",
+            map_difference.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::map::configure_map_eq_panic_message(
+                    panic_message_builder,
+                    stringify!($actual),
+                    stringify!($expected),
+                    &map_difference,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        panic::Location,
+    };
+
+    use crate::utilities::{
+        color_choice::{set_color_choice, ColorChoice},
+        panic_message_builder::MessageType,
+    };
+
+    #[test]
+    fn find_failing_entries_empty() {
+        let map = HashMap::<&str, i32>::new();
+
+        let failing_entries = find_failing_entries(&map, |_, _| false);
+
+        assert_eq!(failing_entries.entry_count, 0);
+        assert!(failing_entries.entries.is_empty());
+    }
+
+    #[test]
+    fn find_failing_entries_mixed() {
+        let map = BTreeMap::from([("a", 1), ("b", -2), ("c", 3), ("d", -4)]);
+
+        let failing_entries = find_failing_entries(&map, |_, value| *value > 0);
+
+        assert_eq!(failing_entries.entry_count, 4);
+        assert_eq!(failing_entries.entries, vec![(&"b", &-2), (&"d", &-4)]);
+    }
+
+    #[test]
+    fn configure_map_all_panic_message_lists_entries() {
+        set_color_choice(ColorChoice::Never);
+
+        let map = HashMap::from([("a", 1), ("d", -4), ("b", -2)]);
+
+        let message = configure_map_all_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "all map values satisfy predicate",
+                Location::caller(),
+            ),
+            "map",
+            "|value| *value > 0",
+            &find_failing_entries(&map, |_, value| *value > 0),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("3 entries"));
+        assert!(message.contains("2 failing"));
+        assert!(message.contains("\"b\": -2\n"));
+        assert!(message.contains("\"d\": -4"));
+        assert!(!message.contains("\"a\": 1"));
+    }
+
+    #[test]
+    fn find_map_difference_equal() {
+        let actual = HashMap::from([("a", 1), ("b", 2)]);
+        let expected = BTreeMap::from([("b", 2), ("a", 1)]);
+
+        let map_difference = find_map_difference(&actual, &expected);
+
+        assert_eq!(map_difference.actual_entry_count, 2);
+        assert_eq!(map_difference.expected_entry_count, 2);
+        assert!(map_difference.is_empty());
+    }
+
+    #[test]
+    fn find_map_difference_mixed() {
+        let actual = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        let expected = BTreeMap::from([("a", 1), ("b", -2), ("d", 4)]);
+
+        let map_difference = find_map_difference(&actual, &expected);
+
+        assert!(!map_difference.is_empty());
+        assert_eq!(map_difference.missing_entries, vec![(&"d", &4)]);
+        assert_eq!(map_difference.unexpected_entries, vec![(&"c", &3)]);
+        assert_eq!(map_difference.differing_entries, vec![(&"b", &2, &-2)]);
+    }
+
+    #[test]
+    fn configure_map_eq_panic_message_lists_differences() {
+        set_color_choice(ColorChoice::Never);
+
+        let actual = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        let expected = HashMap::from([("a", 1), ("b", -2), ("d", 4)]);
+
+        let message = configure_map_eq_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "actual map == expected map",
+                Location::caller(),
+            ),
+            "actual",
+            "expected",
+            &find_map_difference(&actual, &expected),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("3 entries"));
+        assert!(message.contains("missing keys: --\n"));
+        assert!(message.contains("\"d\": 4"));
+        assert!(message.contains("unexpected keys: --\n"));
+        assert!(message.contains("\"c\": 3"));
+        assert!(message.contains("differing values: --\n"));
+        assert!(message.contains("\"b\": 2 (expected -2)"));
+        assert!(!message.contains("\"a\": 1"));
+    }
+
+    #[test]
+    fn configure_map_eq_panic_message_omits_empty_sections() {
+        set_color_choice(ColorChoice::Never);
+
+        let actual = BTreeMap::from([("a", 1)]);
+        let expected = BTreeMap::from([("a", 2)]);
+
+        let message = configure_map_eq_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "actual map == expected map",
+                Location::caller(),
+            ),
+            "actual",
+            "expected",
+            &find_map_difference(&actual, &expected),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(!message.contains("missing keys"));
+        assert!(!message.contains("unexpected keys"));
+        assert!(message.contains("differing values"));
+    }
+
+    #[test]
+    fn assert_map_eq_passing() {
+        assert_map_eq!(
+            HashMap::from([("a", 1), ("b", 2)]),
+            HashMap::from([("b", 2), ("a", 1)])
+        );
+    }
+
+    #[test]
+    fn assert_map_eq_passing_different_map_types() {
+        assert_map_eq!(
+            HashMap::from([("a", 1), ("b", 2)]),
+            BTreeMap::from([("a", 1), ("b", 2)])
+        );
+    }
+
+    #[test]
+    fn assert_map_eq_passing_negate() {
+        assert_map_eq!(
+            BTreeMap::from([("a", 1)]),
+            BTreeMap::from([("a", 2)]),
+            negate = true
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "actual map == expected map")]
+    fn assert_map_eq_failing() {
+        assert_map_eq!(
+            HashMap::from([("a", 1), ("b", 2)]),
+            HashMap::from([("a", 1), ("c", 2)])
+        );
+    }
+
+    #[test]
+    fn assert_map_values_all_passing() {
+        assert_map_values_all!(HashMap::from([("a", 1), ("b", 2)]), |value| *value > 0);
+    }
+
+    #[test]
+    fn assert_map_values_all_passing_empty() {
+        assert_map_values_all!(HashMap::<&str, i32>::new(), |_| false);
+    }
+
+    #[test]
+    fn assert_map_values_all_passing_negate() {
+        assert_map_values_all!(
+            BTreeMap::from([("a", 1), ("b", -2)]),
+            |value| *value > 0,
+            negate = true
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "all map values satisfy predicate")]
+    fn assert_map_values_all_failing() {
+        assert_map_values_all!(HashMap::from([("a", 1), ("b", -2)]), |value| *value > 0);
+    }
+
+    #[test]
+    fn assert_map_keys_all_passing() {
+        assert_map_keys_all!(BTreeMap::from([("a", 1), ("b", 2)]), |key| key.len() == 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "all map keys satisfy predicate")]
+    fn assert_map_keys_all_failing() {
+        assert_map_keys_all!(BTreeMap::from([("a", 1), ("bb", 2)]), |key| key.len() == 1);
+    }
+}
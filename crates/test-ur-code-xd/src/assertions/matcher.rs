@@ -0,0 +1,586 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Matchers that can be plugged into [`assert_that`](crate::assert_that).
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/matchers](https://sophie-katz.github.io/test-ur-code-XD/assertions/matchers/)
+//! for a usage guide.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt::Debug,
+    ops::Sub,
+};
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// A reusable predicate for [`assert_that`](crate::assert_that).
+///
+/// Implement this to write matchers that can be shipped in other crates and still use this crate's
+/// panic message formatting.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{
+/// #     assert_that,
+/// #     assertions::matcher::Matcher,
+/// #     errors::TestUrCodeXDError,
+/// #     utilities::panic_message_builder::PanicMessageBuilder,
+/// # };
+/// #
+/// struct IsEven;
+///
+/// impl Matcher<i32> for IsEven {
+///     fn matches(&self, value: &i32) -> bool {
+///         value % 2 == 0
+///     }
+///
+///     fn describe(
+///         &self,
+///         panic_message_builder: PanicMessageBuilder,
+///     ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+///         panic_message_builder.with_argument_formatted("expected", "--", "an even number")
+///     }
+/// }
+///
+/// assert_that!(4, IsEven);
+/// ```
+pub trait Matcher<ValueType: ?Sized> {
+    /// Checks whether or not the value matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value matches, `false` otherwise.
+    fn matches(&self, value: &ValueType) -> bool;
+
+    /// Describes what the matcher expects by adding arguments to the panic message.
+    ///
+    /// This is only called when the assertion fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `panic_message_builder` - The panic message builder for the failed assertion. The value
+    ///                             being matched has already been added to it.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors with formatting.
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError>;
+}
+
+/// A matcher created by [`eq`].
+#[derive(Clone, Debug)]
+pub struct EqMatcher<ExpectedType> {
+    /// The expected value.
+    expected: ExpectedType,
+}
+
+impl<ValueType: PartialEq<ExpectedType> + ?Sized, ExpectedType: Debug> Matcher<ValueType>
+    for EqMatcher<ExpectedType>
+{
+    fn matches(&self, value: &ValueType) -> bool {
+        value.eq(&self.expected)
+    }
+
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        panic_message_builder.with_argument("expected", "--", &self.expected)
+    }
+}
+
+/// Matches values that are equal to the expected value.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_that, assertions::matcher::eq};
+/// #
+/// assert_that!(2 + 3, eq(5));
+/// ```
+#[must_use]
+pub const fn eq<ExpectedType>(expected: ExpectedType) -> EqMatcher<ExpectedType> {
+    EqMatcher { expected }
+}
+
+/// A collection whose elements can be matched with [`contains`].
+pub trait Collection {
+    /// The type of the elements in the collection.
+    type Element;
+
+    /// Checks whether or not any element in the collection satisfies a predicate.
+    fn any_element(&self, predicate: impl FnMut(&Self::Element) -> bool) -> bool;
+}
+
+impl<ElementType> Collection for [ElementType] {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ElementType, const LENGTH: usize> Collection for [ElementType; LENGTH] {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ElementType> Collection for Vec<ElementType> {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ElementType> Collection for VecDeque<ElementType> {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ElementType, StateType> Collection for HashSet<ElementType, StateType> {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ElementType> Collection for BTreeSet<ElementType> {
+    type Element = ElementType;
+
+    fn any_element(&self, predicate: impl FnMut(&ElementType) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+impl<ValueType: Collection + ?Sized> Collection for &ValueType {
+    type Element = ValueType::Element;
+
+    fn any_element(&self, predicate: impl FnMut(&Self::Element) -> bool) -> bool {
+        (**self).any_element(predicate)
+    }
+}
+
+/// A matcher created by [`contains`].
+#[derive(Clone, Debug)]
+pub struct ContainsMatcher<ItemType> {
+    /// The item that the collection must contain.
+    item: ItemType,
+}
+
+impl<ValueType: Collection + ?Sized, ItemType: Debug> Matcher<ValueType>
+    for ContainsMatcher<ItemType>
+where
+    ValueType::Element: PartialEq<ItemType>,
+{
+    fn matches(&self, value: &ValueType) -> bool {
+        value.any_element(|element| element.eq(&self.item))
+    }
+
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        panic_message_builder.with_argument("expected item", "--", &self.item)
+    }
+}
+
+/// Matches collections that contain the item.
+///
+/// Any type that implements [`Collection`] can be matched, like slices, arrays, vectors, and sets.
+/// Use [`contains_substring`] for strings.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_that, assertions::matcher::contains};
+/// #
+/// assert_that!(vec![1, 2, 3], contains(2));
+/// ```
+#[must_use]
+pub const fn contains<ItemType>(item: ItemType) -> ContainsMatcher<ItemType> {
+    ContainsMatcher { item }
+}
+
+/// A matcher created by [`contains_substring`].
+#[derive(Clone, Debug)]
+pub struct ContainsSubstringMatcher<SubstringType> {
+    /// The substring that the string must contain.
+    substring: SubstringType,
+}
+
+impl<ValueType: AsRef<str> + ?Sized, SubstringType: AsRef<str>> Matcher<ValueType>
+    for ContainsSubstringMatcher<SubstringType>
+{
+    fn matches(&self, value: &ValueType) -> bool {
+        value.as_ref().contains(self.substring.as_ref())
+    }
+
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        panic_message_builder.with_argument("expected substring", "--", &self.substring.as_ref())
+    }
+}
+
+/// Matches strings that contain the substring.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_that, assertions::matcher::contains_substring};
+/// #
+/// assert_that!("hello, world", contains_substring("world"));
+/// ```
+#[must_use]
+pub const fn contains_substring<SubstringType>(
+    substring: SubstringType,
+) -> ContainsSubstringMatcher<SubstringType> {
+    ContainsSubstringMatcher { substring }
+}
+
+/// A value with a length that can be matched with [`len`].
+pub trait Length {
+    /// Gets the length of the value.
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    /// Gets the length of the string in bytes.
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for String {
+    /// Gets the length of the string in bytes.
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ElementType> Length for [ElementType] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ElementType, const LENGTH: usize> Length for [ElementType; LENGTH] {
+    fn length(&self) -> usize {
+        LENGTH
+    }
+}
+
+impl<ElementType> Length for Vec<ElementType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ElementType> Length for VecDeque<ElementType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ElementType, StateType> Length for HashSet<ElementType, StateType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<KeyType, ValueType, StateType> Length for HashMap<KeyType, ValueType, StateType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ElementType> Length for BTreeSet<ElementType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<KeyType, ValueType> Length for BTreeMap<KeyType, ValueType> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<ValueType: Length + ?Sized> Length for &ValueType {
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// A matcher created by [`len`].
+#[derive(Clone, Copy, Debug)]
+pub struct LenMatcher {
+    /// The expected length.
+    expected: usize,
+}
+
+impl<ValueType: Length + ?Sized> Matcher<ValueType> for LenMatcher {
+    fn matches(&self, value: &ValueType) -> bool {
+        value.length() == self.expected
+    }
+
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        panic_message_builder.with_argument("expected length", "--", &self.expected)
+    }
+}
+
+/// Matches values with the expected length.
+///
+/// Strings are measured in bytes, like [`str::len`].
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_that, assertions::matcher::len};
+/// #
+/// assert_that!(vec![1, 2, 3], len(3));
+/// ```
+#[must_use]
+pub const fn len(expected: usize) -> LenMatcher {
+    LenMatcher { expected }
+}
+
+/// A matcher created by [`within`].
+#[derive(Clone, Copy, Debug)]
+pub struct WithinMatcher<ValueType> {
+    /// The expected value.
+    expected: ValueType,
+
+    /// The maximum allowed distance from the expected value.
+    tolerance: ValueType,
+}
+
+impl<ValueType: PartialOrd + Sub<Output = ValueType> + Copy + Debug> Matcher<ValueType>
+    for WithinMatcher<ValueType>
+{
+    // The larger value is always the minuend, so the subtraction only overflows if the distance
+    // between the values cannot be represented, in which case no tolerance would make sense anyway.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn matches(&self, value: &ValueType) -> bool {
+        let distance = if *value > self.expected {
+            *value - self.expected
+        } else {
+            self.expected - *value
+        };
+
+        distance <= self.tolerance
+    }
+
+    fn describe(
+        &self,
+        panic_message_builder: PanicMessageBuilder,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        panic_message_builder
+            .with_argument("expected", "--", &self.expected)?
+            .with_argument("tolerance", "--", &self.tolerance)
+    }
+}
+
+/// Matches values that are within a tolerance of the expected value, inclusive.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_that, assertions::matcher::within};
+/// #
+/// assert_that!(0.1 + 0.2, within(0.3, 1e-9));
+/// assert_that!(98, within(100, 2));
+/// ```
+#[must_use]
+pub const fn within<ValueType>(
+    expected: ValueType,
+    tolerance: ValueType,
+) -> WithinMatcher<ValueType> {
+    WithinMatcher {
+        expected,
+        tolerance,
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_matcher_panic_message<
+    ValueType: Debug + ?Sized,
+    MatcherType: Matcher<ValueType>,
+>(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: &ValueType,
+    matcher: &MatcherType,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    matcher.describe(panic_message_builder.with_argument("value", value_description, &value)?)
+}
+
+/// Asserts that a value matches a [`Matcher`](crate::assertions::matcher::Matcher).
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/matchers](https://sophie-katz.github.io/test-ur-code-XD/assertions/matchers/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to match. It is borrowed, not moved.
+/// * `matcher` - The matcher to use.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{
+/// #     assert_that,
+/// #     assertions::matcher::{contains, eq, len},
+/// # };
+/// #
+/// let numbers = vec![1, 2, 3];
+///
+/// assert_that!(numbers, contains(2));
+/// assert_that!(numbers, len(3));
+/// assert_that!(numbers[0], eq(2), negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_that {
+    ($value:expr, $matcher:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+        let matcher = $matcher;
+
+        $crate::assert_custom!(
+            "value matches matcher",
+            $crate::assertions::matcher::Matcher::matches(&matcher, value),
+            |panic_message_builder| {
+                $crate::assertions::matcher::configure_matcher_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    value,
+                    &matcher,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_not};
+
+    #[test]
+    fn eq_matches() {
+        assert!(eq(5).matches(&5));
+        assert_not!(eq(5).matches(&6));
+        assert!(eq("hello").matches(&"hello"));
+        assert!(eq("hello").matches(&String::from("hello")));
+    }
+
+    #[test]
+    fn contains_matches() {
+        assert!(contains(2).matches(&vec![1, 2, 3]));
+        assert!(contains(2).matches(&[1, 2, 3]));
+        assert!(contains(2).matches(&[1, 2, 3][..]));
+        assert!(contains("b").matches(&HashSet::from(["a", "b"])));
+        assert_not!(contains(4).matches(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn contains_substring_matches() {
+        assert!(contains_substring("world").matches(&"hello, world"));
+        assert!(contains_substring("world").matches(&String::from("hello, world")));
+        assert_not!(contains_substring("goodbye").matches(&"hello, world"));
+    }
+
+    #[test]
+    fn len_matches() {
+        assert!(len(3).matches(&vec![1, 2, 3]));
+        assert!(len(3).matches(&[1, 2, 3]));
+        assert!(len(5).matches(&"hello"));
+        assert!(len(1).matches(&HashMap::from([(1, 2)])));
+        assert!(len(0).matches(&BTreeSet::<i32>::new()));
+        assert_not!(len(2).matches(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn within_matches() {
+        assert!(within(100, 2).matches(&98));
+        assert!(within(100, 2).matches(&102));
+        assert_not!(within(100, 2).matches(&97));
+        assert!(within(0.3, 1e-9).matches(&(0.1 + 0.2)));
+        assert_not!(within(0.3, 1e-9).matches(&0.31));
+        assert!(within(5_u32, 2).matches(&3));
+    }
+
+    #[test]
+    fn assert_that_passing() {
+        assert_that!(2 + 3, eq(5));
+        assert_that!(vec![1, 2, 3], contains(2));
+        assert_that!("hello, world", contains_substring("world"));
+        assert_that!("hello", len(5));
+        assert_that!(98, within(100, 2));
+    }
+
+    #[test]
+    fn assert_that_does_not_move_value() {
+        let values = vec![String::from("a")];
+
+        assert_that!(values, len(1));
+        assert_that!(values, contains(String::from("a")));
+
+        drop(values);
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches matcher")]
+    fn assert_that_failing() {
+        assert_that!(vec![1, 2, 3], contains(4));
+    }
+
+    #[test]
+    fn assert_that_passing_negate() {
+        assert_that!(vec![1, 2, 3], contains(4), negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches matcher")]
+    fn assert_that_failing_negate() {
+        assert_that!(5, eq(5), negate = true);
+    }
+}
@@ -19,11 +19,17 @@
 //! [sophie-katz.github.io/test-ur-code-XD/assertions/output](https://sophie-katz.github.io/test-ur-code-XD/assertions/output/)
 //! for a usage guide.
 
-use std::panic::Location;
+use std::{
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+};
+
+use console::strip_ansi_codes;
 
 use crate::utilities::{
-    capture_output::{capture_output, capture_output_raw, CapturedOutputs, OutputCapturingError},
-    panic_message_builder::PanicMessageBuilder,
+    capture_output::{capture_output, capture_output_raw},
+    color_choice::style,
+    panic_message_builder::is_panic_output_suppressed,
 };
 
 // /// Helper function to unwrap captured output wrapped in an error and panic.
@@ -42,6 +48,49 @@ use crate::utilities::{
 //     }
 // }
 
+/// Helper function to forward captured output to the real `stdout` and `stderr`.
+///
+/// Errors are ignored since there is nowhere to report them, and the output is only forwarded to
+/// help with debugging.
+fn tee_outputs(stdout: &[u8], stderr: &[u8]) {
+    let _stdout_result: io::Result<()> = io::stdout()
+        .write_all(stdout)
+        .and_then(|()| io::stdout().flush());
+
+    let _stderr_result: io::Result<()> = io::stderr()
+        .write_all(stderr)
+        .and_then(|()| io::stderr().flush());
+}
+
+/// Helper function to print the raw output after the panic message of a failed assertion on output
+/// with ANSI escape codes stripped.
+// Stderr printing allowed because the panic message is also printed to stderr.
+#[allow(clippy::print_stderr)]
+fn report_raw_outputs(stdout: &str, stderr: &str) {
+    if is_panic_output_suppressed() {
+        return;
+    }
+
+    eprintln!("  {} {stdout:?}", style("raw stdout:").dim());
+    eprintln!("  {} {stderr:?}", style("raw stderr:").dim());
+}
+
+/// Helper function to pass captured output to the `on_stdout` and `on_stderr` closures.
+fn call_output_callbacks(
+    on_stdout: Option<Box<dyn FnOnce(String)>>,
+    on_stderr: Option<Box<dyn FnOnce(String)>>,
+    stdout: String,
+    stderr: String,
+) {
+    if let Some(on_stdout) = on_stdout {
+        on_stdout(stdout);
+    }
+
+    if let Some(on_stderr) = on_stderr {
+        on_stderr(stderr);
+    }
+}
+
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
@@ -49,20 +98,46 @@ pub fn assert_outputs_impl<ActionType: FnOnce()>(
     action: ActionType,
     on_stdout: Option<Box<dyn FnOnce(String)>>,
     on_stderr: Option<Box<dyn FnOnce(String)>>,
+    strip_ansi: bool,
+    tee: bool,
 ) {
     let captured_outputs = capture_output(action).expect("unable to capture output");
 
-    if let Some(on_stdout) = on_stdout {
-        on_stdout(captured_outputs.stdout);
+    if tee {
+        tee_outputs(
+            captured_outputs.stdout.as_bytes(),
+            captured_outputs.stderr.as_bytes(),
+        );
     }
 
-    if let Some(on_stderr) = on_stderr {
-        on_stderr(captured_outputs.stderr);
+    if !strip_ansi {
+        call_output_callbacks(
+            on_stdout,
+            on_stderr,
+            captured_outputs.stdout,
+            captured_outputs.stderr,
+        );
+
+        return;
+    }
+
+    let stdout = strip_ansi_codes(&captured_outputs.stdout).into_owned();
+    let stderr = strip_ansi_codes(&captured_outputs.stderr).into_owned();
+
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| {
+        call_output_callbacks(on_stdout, on_stderr, stdout, stderr);
+    })) {
+        report_raw_outputs(&captured_outputs.stdout, &captured_outputs.stderr);
+
+        panic::resume_unwind(payload);
     }
 }
 
 /// Assertion wrapper for capturing `stdout` and `stderr` output.
 ///
+/// Output from child processes and C libraries that write to `stdout` and `stderr` is captured as
+/// well.
+///
 /// See
 /// [sophie-katz.github.io/test-ur-code-XD/assertions/output](https://sophie-katz.github.io/test-ur-code-XD/assertions/output/)
 /// for a usage guide.
@@ -77,8 +152,16 @@ pub fn assert_outputs_impl<ActionType: FnOnce()>(
 ///                                     nothing. The `String` is the content of `stderr` that was
 ///                                     outputted by `action`.
 ///
-/// **Note:** At least one of `on_stdout` and `on_stderr` must be passed. `on_stdout` must always
-/// come before `on_stderr`.
+/// * Optional: `strip_ansi = <value>` - Whether to strip ANSI escape codes, like colors, from the
+///                                      output before passing it to `on_stdout` and `on_stderr`. If
+///                                      either of them fails, the raw output is printed after the
+///                                      failure message. Defaults to `false`.
+/// * Optional: `tee = <value>` - Whether to also forward the captured output to the real `stdout`
+///                               and `stderr` once `action` finishes. This is useful for debugging
+///                               failing output assertions. Defaults to `false`.
+///
+/// **Note:** At least one of `on_stdout` and `on_stderr` must be passed. The keyword arguments
+/// must come in the order above.
 ///
 /// # Example
 ///
@@ -96,27 +179,33 @@ pub fn assert_outputs_impl<ActionType: FnOnce()>(
 /// ```
 #[macro_export]
 macro_rules! assert_outputs {
-    ($action:expr, on_stdout = $on_stdout:expr $(,)?) => {
+    ($action:expr, on_stdout = $on_stdout:expr $(, strip_ansi = $strip_ansi:expr)? $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_impl(
             $action,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
             ::std::option::Option::None,
+            false $(|| $strip_ansi)?,
+            false $(|| $tee)?,
         )
     };
 
-    ($action:expr, on_stderr = $on_stderr:expr $(,)?) => {
+    ($action:expr, on_stderr = $on_stderr:expr $(, strip_ansi = $strip_ansi:expr)? $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_impl(
             $action,
             ::std::option::Option::None,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            false $(|| $strip_ansi)?,
+            false $(|| $tee)?,
         )
     };
 
-    ($action:expr, on_stdout = $on_stdout:expr, on_stderr = $on_stderr:expr $(,)?) => {
+    ($action:expr, on_stdout = $on_stdout:expr, on_stderr = $on_stderr:expr $(, strip_ansi = $strip_ansi:expr)? $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_impl(
             $action,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
             ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            false $(|| $strip_ansi)?,
+            false $(|| $tee)?,
         )
     };
 }
@@ -131,9 +220,14 @@ pub fn assert_outputs_raw_impl<ActionType: FnOnce()>(
     action: ActionType,
     on_stdout: Option<Box<dyn FnOnce(&[u8])>>,
     on_stderr: Option<Box<dyn FnOnce(&[u8])>>,
+    tee: bool,
 ) {
     let captured_outputs = capture_output_raw(action).expect("unable to capture output");
 
+    if tee {
+        tee_outputs(&captured_outputs.stdout, &captured_outputs.stderr);
+    }
+
     if let Some(on_stdout) = on_stdout {
         on_stdout(&captured_outputs.stdout);
     }
@@ -159,8 +253,12 @@ pub fn assert_outputs_raw_impl<ActionType: FnOnce()>(
 ///                                     returns nothing. The `u8` array is the content of `stderr`
 ///                                     that was outputted by `action`.
 ///
+/// * Optional: `tee = <value>` - Whether to also forward the captured output to the real `stdout`
+///                               and `stderr` once `action` finishes. This is useful for debugging
+///                               failing output assertions. Defaults to `false`.
+///
 /// **Note:** At least one of `on_stdout` and `on_stderr` must be passed. `on_stdout` must always
-/// come before `on_stderr`.
+/// come before `on_stderr`, and `tee` must come last.
 ///
 /// # Example
 ///
@@ -178,36 +276,47 @@ pub fn assert_outputs_raw_impl<ActionType: FnOnce()>(
 /// ```
 #[macro_export]
 macro_rules! assert_outputs_raw {
-    ($action:expr, on_stdout = $on_stdout:expr $(,)?) => {
+    ($action:expr, on_stdout = $on_stdout:expr $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_raw_impl(
             $action,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
             ::std::option::Option::None,
+            false $(|| $tee)?,
         )
     };
 
-    ($action:expr, on_stderr = $on_stderr:expr $(,)?) => {
+    ($action:expr, on_stderr = $on_stderr:expr $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_raw_impl(
             $action,
             ::std::option::Option::None,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            false $(|| $tee)?,
         )
     };
 
-    ($action:expr, on_stdout = $on_stdout:expr, on_stderr = $on_stderr:expr $(,)?) => {
+    ($action:expr, on_stdout = $on_stdout:expr, on_stderr = $on_stderr:expr $(, tee = $tee:expr)? $(,)?) => {
         $crate::assertions::output::assert_outputs_raw_impl(
             $action,
             ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
             ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            false $(|| $tee)?,
         )
     };
 }
 
 #[cfg(test)]
 // Stdout and stderr printing are allowed in order to generate output for tests.
-#[allow(clippy::print_stdout, clippy::print_stderr)]
+//
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::print_stdout, clippy::print_stderr, clippy::unwrap_used)]
 mod tests {
-    use crate::assert_eq;
+    use crate::{assert, assert_eq, assert_str_contains};
+    use console::style;
+    use std::{env, process::Command};
+
+    /// The environment variable that tells the test process that it is the child process in
+    /// [`assert_outputs_tee`].
+    const CHILD_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_OUTPUT_TEE_CHILD";
 
     #[test]
     fn assert_outputs_passing_empty_stdout_only() {
@@ -346,6 +455,107 @@ mod tests {
         );
     }
 
+    #[test]
+    fn assert_outputs_tee() {
+        // The forwarded output goes to the real streams, so the assertion runs in a child process
+        // whose streams can be checked
+        if env::var_os(CHILD_ENVIRONMENT_VARIABLE).is_some() {
+            assert_outputs!(
+                || {
+                    println!("hello, world (stdout)");
+                    eprintln!("hello, world (stderr)");
+                },
+                on_stdout = |stdout| {
+                    assert_eq!(stdout, "hello, world (stdout)\n");
+                },
+                on_stderr = |stderr| {
+                    assert_eq!(stderr, "hello, world (stderr)\n");
+                },
+                tee = true
+            );
+
+            return;
+        }
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "assertions::output::tests::assert_outputs_tee",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(CHILD_ENVIRONMENT_VARIABLE, "1")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        assert_str_contains!(
+            String::from_utf8_lossy(&output.stdout),
+            "hello, world (stdout)"
+        );
+        assert_str_contains!(
+            String::from_utf8_lossy(&output.stderr),
+            "hello, world (stderr)"
+        );
+    }
+
+    #[test]
+    fn assert_outputs_strip_ansi() {
+        assert_outputs!(
+            || {
+                println!("{}", style("hello, world").red().force_styling(true));
+                eprintln!("{}", style("goodbye").bold().force_styling(true));
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "hello, world\n");
+            },
+            on_stderr = |stderr| {
+                assert_eq!(stderr, "goodbye\n");
+            },
+            strip_ansi = true
+        );
+    }
+
+    #[test]
+    fn assert_outputs_no_strip_ansi() {
+        assert_outputs!(
+            || {
+                println!("{}", style("hello, world").red().force_styling(true));
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "\u{1b}[31mhello, world\u{1b}[0m\n");
+            },
+            strip_ansi = false,
+            tee = false
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_outputs_strip_ansi_failing() {
+        assert_outputs!(
+            || {
+                println!("{}", style("hello, world").red().force_styling(true));
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "asdf");
+            },
+            strip_ansi = true
+        );
+    }
+
+    #[test]
+    fn assert_outputs_raw_tee_false() {
+        assert_outputs_raw!(
+            || {
+                println!("hello, world");
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, b"hello, world\n");
+            },
+            tee = false,
+        );
+    }
+
     // TODO: Get this to work
     // #[test]
     // #[should_panic(expected = "explicit panic")]
@@ -19,9 +19,22 @@
 //! [sophie-katz.github.io/test-ur-code-XD/assertions/panic](https://sophie-katz.github.io/test-ur-code-XD/assertions/panic/)
 //! for a usage guide.
 
-use std::panic::{self, AssertUnwindSafe, Location, UnwindSafe};
+use std::{
+    any::{self, Any},
+    panic::{self, AssertUnwindSafe, Location, UnwindSafe},
+    thread,
+};
 
-use crate::utilities::panic_message_builder::PanicMessageBuilder;
+use crate::{
+    assertions::config::Config, errors::TestUrCodeXDError,
+    utilities::panic_message_builder::PanicMessageBuilder,
+};
+
+#[cfg(feature = "output")]
+use crate::utilities::{
+    capture_output::capture_output,
+    panic_message_builder::{catch_unwind_silently, MessageType},
+};
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
@@ -33,13 +46,154 @@ pub fn assert_panics_impl<
     action: ActionType,
     location: &'static Location<'static>,
     on_message: Option<MessageCallbackType>,
+    config: Config,
 ) {
-    if let Err(error) = panic::catch_unwind(AssertUnwindSafe(action)) {
-        if let Some(on_message) = on_message {
-            on_message(panic_message::panic_message(&error).to_owned());
-        }
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    config.execute_assertion(
+        "action panics",
+        result.is_err(),
+        location,
+        |panic_message_builder| configure_panics_panic_message(panic_message_builder, &result),
+    );
+
+    if let (Err(payload), Some(on_message)) = (result, on_message) {
+        on_message(panic_message::panic_message(&payload).to_owned());
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_panics_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    result: &thread::Result<()>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    // If the assertion was negated, show what the action panicked with
+    match result {
+        Ok(()) => Ok(panic_message_builder),
+        Err(payload) => panic_message_builder.with_argument_formatted(
+            "panic message",
+            "--",
+            panic_message::panic_message(payload),
+        ),
+    }
+}
+
+/// Describes the type of a panic payload for a panic message.
+///
+/// Only the [`TypeId`](std::any::TypeId) of a payload is available at runtime, so the names of
+/// `&str` and `String` payloads, which are what [`panic!`] produces, are known but other types are
+/// described by their type ID.
+#[must_use]
+pub fn describe_panic_payload_type(payload: &(dyn Any + Send)) -> String {
+    if payload.is::<&str>() {
+        any::type_name::<&str>().to_owned()
+    } else if payload.is::<String>() {
+        any::type_name::<String>().to_owned()
     } else {
-        PanicMessageBuilder::new("action panics", location).panic();
+        format!("unknown ({:?})", payload.type_id())
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn assert_panics_with_type_impl<
+    PayloadType: Any,
+    ActionType: FnOnce() + UnwindSafe,
+    MessageCallbackType: FnOnce(String),
+>(
+    action: ActionType,
+    location: &'static Location<'static>,
+    on_message: Option<MessageCallbackType>,
+    config: Config,
+) {
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    config.clone().execute_assertion(
+        "action panics",
+        result.is_err(),
+        location,
+        |panic_message_builder| configure_panics_panic_message(panic_message_builder, &result),
+    );
+
+    // If the assertion was negated, the action did not panic and there is nothing else to check
+    let Err(payload) = result else {
+        return;
+    };
+
+    // Negation only applies to whether or not the action panics, so the payload type is always
+    // checked normally
+    Config {
+        negate: false,
+        ..config
+    }
+    .execute_assertion(
+        "action panics with payload type",
+        payload.is::<PayloadType>(),
+        location,
+        |panic_message_builder| {
+            panic_message_builder
+                .with_argument_formatted("expected type", "--", any::type_name::<PayloadType>())?
+                .with_argument_formatted(
+                    "actual type",
+                    "--",
+                    describe_panic_payload_type(&*payload),
+                )
+        },
+    );
+
+    if let Some(on_message) = on_message {
+        on_message(panic_message::panic_message(&payload).to_owned());
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[cfg(feature = "output")]
+#[track_caller]
+pub fn capture_panic_output(action: impl FnOnce()) -> (thread::Result<()>, String, String) {
+    let mut result = Ok(());
+
+    // The panic hook is silenced so that the panic message doesn't end up in the captured stderr
+    let captured_outputs = PanicMessageBuilder::unwrap_error_with(
+        capture_output(|| result = catch_unwind_silently(action)),
+        MessageType::ErrorWhileCheckingAssertion,
+        "unable to capture output",
+        PanicMessageBuilder::no_configuration,
+    );
+
+    (result, captured_outputs.stdout, captured_outputs.stderr)
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn replay_panic(result: thread::Result<()>) -> impl FnOnce() + UnwindSafe {
+    AssertUnwindSafe(move || {
+        if let Err(payload) = result {
+            panic::resume_unwind(payload);
+        }
+    })
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn call_panic_output_callbacks(
+    on_stdout: Option<Box<dyn FnOnce(String)>>,
+    on_stderr: Option<Box<dyn FnOnce(String)>>,
+    stdout: String,
+    stderr: String,
+) {
+    if let Some(on_stdout) = on_stdout {
+        on_stdout(stdout);
+    }
+
+    if let Some(on_stderr) = on_stderr {
+        on_stderr(stderr);
     }
 }
 
@@ -52,9 +206,25 @@ pub fn assert_panics_impl<
 /// # Arguments
 ///
 /// * `action` - A function with no arguments or returns whose panic will be captured.
+/// * Optional: `with_type = <type>` - The type that the panic payload must have, like a custom
+///                                     error type passed to
+///                                     [`panic_any`](std::panic::panic_any). This must come before
+///                                     `on_message`.
 /// * Optional: `on_message = <value>` - A closure that accepts a `String` as an argument and
 ///                                      returns nothing. The `String` is the content of the panic
 ///                                      message that was raised by `action`.
+/// * Optional: `on_stdout = <value>` - A closure that accepts a `String` as an argument and
+///                                     returns nothing. The `String` is what `action` printed to
+///                                     `stdout` before it panicked. Requires the `output` feature.
+/// * Optional: `on_stderr = <value>` - A closure that accepts a `String` as an argument and
+///                                     returns nothing. The `String` is what `action` printed to
+///                                     `stderr` before it panicked, without the panic message.
+///                                     Requires the `output` feature.
+/// * Optional keyword arguments for assertions. If `negate = true`, the assertion is that `action`
+///   does not panic and `with_type` and `on_message` are ignored.
+///
+/// **Note:** `with_type`, `on_message`, `on_stdout`, and `on_stderr` must come in the order above
+/// and before any other keyword arguments. The output closures are called after `on_message`.
 ///
 /// # Example
 ///
@@ -69,22 +239,209 @@ pub fn assert_panics_impl<
 ///         assert_eq!(message, "hello, world");
 ///     }
 /// );
+///
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// assert_panics!(
+///     || {
+///         std::panic::panic_any(MyError);
+///     },
+///     with_type = MyError
+/// );
+///
+/// assert_panics!(
+///     || {
+///         println!("connecting");
+///         panic!("connection refused");
+///     },
+///     on_stdout = |stdout| {
+///         assert_eq!(stdout, "connecting\n");
+///     }
+/// );
 /// ```
 #[macro_export]
 macro_rules! assert_panics {
-    ($action:expr, on_message = $on_message:expr) => {
+    (
+        $action:expr
+        $(, with_type = $payload_type:ty)?
+        $(, on_message = $on_message:expr)?,
+        on_stdout = $on_stdout:expr,
+        on_stderr = $on_stderr:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let (result, stdout, stderr) = $crate::assertions::panic::capture_panic_output($action);
+
+        $crate::assert_panics!(
+            $crate::assertions::panic::replay_panic(result)
+            $(, with_type = $payload_type)?
+            $(, on_message = $on_message)?
+            $(, $keys = $values)*
+        );
+
+        $crate::assertions::panic::call_panic_output_callbacks(
+            ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
+            ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            stdout,
+            stderr,
+        );
+    }};
+
+    (
+        $action:expr
+        $(, with_type = $payload_type:ty)?
+        $(, on_message = $on_message:expr)?,
+        on_stdout = $on_stdout:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let (result, stdout, stderr) = $crate::assertions::panic::capture_panic_output($action);
+
+        $crate::assert_panics!(
+            $crate::assertions::panic::replay_panic(result)
+            $(, with_type = $payload_type)?
+            $(, on_message = $on_message)?
+            $(, $keys = $values)*
+        );
+
+        $crate::assertions::panic::call_panic_output_callbacks(
+            ::std::option::Option::Some(::std::boxed::Box::new($on_stdout)),
+            ::std::option::Option::None,
+            stdout,
+            stderr,
+        );
+    }};
+
+    (
+        $action:expr
+        $(, with_type = $payload_type:ty)?
+        $(, on_message = $on_message:expr)?,
+        on_stderr = $on_stderr:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let (result, stdout, stderr) = $crate::assertions::panic::capture_panic_output($action);
+
+        $crate::assert_panics!(
+            $crate::assertions::panic::replay_panic(result)
+            $(, with_type = $payload_type)?
+            $(, on_message = $on_message)?
+            $(, $keys = $values)*
+        );
+
+        $crate::assertions::panic::call_panic_output_callbacks(
+            ::std::option::Option::None,
+            ::std::option::Option::Some(::std::boxed::Box::new($on_stderr)),
+            stdout,
+            stderr,
+        );
+    }};
+
+    (
+        $action:expr,
+        with_type = $payload_type:ty,
+        on_message = $on_message:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assertions::panic::assert_panics_with_type_impl::<$payload_type, _, _>(
+            $action,
+            ::std::panic::Location::caller(),
+            ::std::option::Option::Some($on_message),
+            $crate::config_from_keyword_arguments!($($keys = $values),*),
+        )
+    };
+
+    ($action:expr, with_type = $payload_type:ty $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assertions::panic::assert_panics_with_type_impl::<$payload_type, _, _>(
+            $action,
+            ::std::panic::Location::caller(),
+            ::std::option::Option::<fn(String)>::None,
+            $crate::config_from_keyword_arguments!($($keys = $values),*),
+        )
+    };
+
+    ($action:expr, on_message = $on_message:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assertions::panic::assert_panics_impl(
             $action,
             ::std::panic::Location::caller(),
             ::std::option::Option::Some($on_message),
+            $crate::config_from_keyword_arguments!($($keys = $values),*),
         )
     };
 
-    ($action:expr) => {
+    ($action:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assertions::panic::assert_panics_impl(
             $action,
             ::std::panic::Location::caller(),
             ::std::option::Option::<fn(String)>::None,
+            $crate::config_from_keyword_arguments!($($keys = $values),*),
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn assert_no_panic_on_drop_impl<ValueType>(
+    value: ValueType,
+    location: &'static Location<'static>,
+    config: Config,
+) {
+    let result = panic::catch_unwind(AssertUnwindSafe(move || drop(value)));
+
+    config.execute_assertion(
+        "value does not panic when dropped",
+        result.is_ok(),
+        location,
+        |panic_message_builder| {
+            configure_panics_panic_message(
+                panic_message_builder.with_argument_formatted(
+                    "value type",
+                    "--",
+                    any::type_name::<ValueType>(),
+                )?,
+                &result,
+            )
+        },
+    );
+}
+
+/// Asserts that dropping a value does not panic.
+///
+/// The value is dropped inside of [`catch_unwind`](std::panic::catch_unwind), so a panicking
+/// destructor fails the assertion with the panic message instead of unwinding through the test.
+/// This is useful for types that do cleanup or check invariants in their [`Drop`] implementation.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/panic](https://sophie-katz.github.io/test-ur-code-XD/assertions/panic/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to drop. It is moved into the assertion.
+/// * Optional keyword arguments for assertions. If `negate = true`, the assertion is that dropping
+///   `value` panics.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_no_panic_on_drop;
+/// #
+/// struct Connection;
+///
+/// impl Drop for Connection {
+///     fn drop(&mut self) {
+///         // Close the connection
+///     }
+/// }
+///
+/// assert_no_panic_on_drop!(Connection);
+/// ```
+#[macro_export]
+macro_rules! assert_no_panic_on_drop {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assertions::panic::assert_no_panic_on_drop_impl(
+            $value,
+            ::std::panic::Location::caller(),
+            $crate::config_from_keyword_arguments!($($keys = $values),*),
         )
     };
 }
@@ -95,8 +452,34 @@ macro_rules! assert_panics {
 // Panics are allowed to generate panics for testing.
 #[allow(clippy::print_stdout, clippy::print_stderr, clippy::panic)]
 mod tests {
-    use crate::assert_eq;
-    use std::panic;
+    use super::*;
+    use crate::{assert, assert_eq, utilities::assertion_failure::capture_assertion_failure};
+    use std::sync::Arc;
+
+    /// Runs an action with a panic hook that calls `print` for panics on the current thread, then
+    /// restores the previous hook.
+    ///
+    /// Panics on other threads are passed on to the previous hook so that tests running in parallel
+    /// aren't affected.
+    fn with_printing_panic_hook(print: fn(), action: impl FnOnce()) {
+        let thread_id = thread::current().id();
+        let previous_hook = Arc::new(panic::take_hook());
+        let hook_previous_hook = Arc::clone(&previous_hook);
+
+        panic::set_hook(Box::new(move |panic_info| {
+            if thread::current().id() == thread_id {
+                print();
+            } else {
+                hook_previous_hook(panic_info);
+            }
+        }));
+
+        action();
+
+        drop(panic::take_hook());
+
+        panic::set_hook(Box::new(move |panic_info| previous_hook(panic_info)));
+    }
 
     #[test]
     fn assert_panics_passing_no_message_text_no_message_assertions() {
@@ -170,65 +553,69 @@ mod tests {
 
     #[test]
     fn assert_panics_passing_hook_stdout_no_message_text() {
-        assert_panics!(
+        with_printing_panic_hook(
+            || println!("hello, world"),
             || {
-                panic::set_hook(Box::new(move |_| {
-                    println!("hello, world");
-                }));
-
-                panic!();
+                assert_panics!(
+                    || {
+                        panic!();
+                    },
+                    on_message = |message| {
+                        assert_eq!(message, "explicit panic");
+                    }
+                );
             },
-            on_message = |message| {
-                assert_eq!(message, "explicit panic");
-            }
         );
     }
 
     #[test]
     fn assert_panics_passing_hook_stderr_no_message_text() {
-        assert_panics!(
+        with_printing_panic_hook(
+            || eprintln!("hello, world"),
             || {
-                panic::set_hook(Box::new(move |_| {
-                    eprintln!("hello, world");
-                }));
-
-                panic!();
+                assert_panics!(
+                    || {
+                        panic!();
+                    },
+                    on_message = |message| {
+                        assert_eq!(message, "explicit panic");
+                    }
+                );
             },
-            on_message = |message| {
-                assert_eq!(message, "explicit panic");
-            }
         );
     }
 
     #[test]
     fn assert_panics_passing_hook_stdout_with_message_text() {
-        assert_panics!(
+        with_printing_panic_hook(
+            || println!("hello, world"),
             || {
-                panic::set_hook(Box::new(move |_| {
-                    println!("hello, world");
-                }));
-
-                panic!("some panic message");
+                assert_panics!(
+                    || {
+                        panic!("some panic message");
+                    },
+                    on_message = |message| {
+                        assert_eq!(message, "some panic message");
+                    }
+                );
             },
-            on_message = |message| {
-                assert_eq!(message, "some panic message");
-            }
         );
     }
 
     #[test]
     fn assert_panics_passing_hook_stderr_with_message_text() {
-        assert_panics!(
+        with_printing_panic_hook(
+            || eprintln!("hello, world"),
             || {
-                panic::set_hook(Box::new(move |_| {
-                    eprintln!("hello, world");
-                }));
-
-                panic!("some panic message");
+                assert_panics!(
+                    || {
+                        panic!("some panic message");
+                    },
+                    on_message = |message| {
+                        assert_eq!(message, "some panic message");
+                    }
+                );
             },
-            on_message = |message| {
-                assert_eq!(message, "some panic message");
-            }
         );
     }
 
@@ -268,6 +655,72 @@ mod tests {
         });
     }
 
+    #[derive(Debug)]
+    struct CustomPayload;
+
+    #[test]
+    fn describe_panic_payload_type_known() {
+        let payload: Box<dyn Any + Send> = Box::new("hello");
+
+        assert_eq!(describe_panic_payload_type(&*payload), "&str");
+
+        let payload: Box<dyn Any + Send> = Box::new("hello".to_owned());
+
+        assert_eq!(
+            describe_panic_payload_type(&*payload),
+            "alloc::string::String"
+        );
+    }
+
+    #[test]
+    fn describe_panic_payload_type_unknown() {
+        let payload: Box<dyn Any + Send> = Box::new(CustomPayload);
+
+        assert!(describe_panic_payload_type(&*payload).starts_with("unknown (TypeId("));
+    }
+
+    #[test]
+    fn assert_panics_with_type_passing() {
+        assert_panics!(
+            || {
+                panic::panic_any(CustomPayload);
+            },
+            with_type = CustomPayload
+        );
+    }
+
+    #[test]
+    fn assert_panics_with_type_passing_string() {
+        let name = "world".to_owned();
+
+        assert_panics!(
+            move || {
+                panic!("hello, {name}");
+            },
+            with_type = String,
+            on_message = |message| {
+                assert_eq!(message, "hello, world");
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "action panics with payload type")]
+    fn assert_panics_with_type_failing_wrong_type() {
+        assert_panics!(
+            || {
+                panic!("hello, world");
+            },
+            with_type = CustomPayload
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "action panics")]
+    fn assert_panics_with_type_failing_no_panic() {
+        assert_panics!(|| {}, with_type = CustomPayload);
+    }
+
     #[test]
     #[should_panic(expected = "action panics")]
     fn assert_panics_failing_nested() {
@@ -277,4 +730,202 @@ mod tests {
             });
         });
     }
+
+    #[test]
+    fn assert_panics_passing_negate() {
+        assert_panics!(|| {}, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "action panics")]
+    fn assert_panics_failing_negate() {
+        assert_panics!(
+            || {
+                panic!("hello, world");
+            },
+            negate = true
+        );
+    }
+
+    #[test]
+    fn assert_panics_failing_negate_shows_panic_message() {
+        let failure = capture_assertion_failure(|| {
+            assert_panics!(
+                || {
+                    panic!("hello, world");
+                },
+                negate = true
+            );
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("panic message").unwrap().value,
+            "hello, world"
+        );
+    }
+
+    #[test]
+    fn assert_panics_with_type_passing_negate() {
+        assert_panics!(|| {}, with_type = CustomPayload, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "action panics with payload type")]
+    fn assert_panics_with_type_failing_wrong_type_description() {
+        assert_panics!(
+            || {
+                panic!("hello, world");
+            },
+            with_type = CustomPayload,
+            description = "custom payloads are used"
+        );
+    }
+
+    /// A value that panics when it is dropped.
+    struct PanicsOnDrop;
+
+    impl Drop for PanicsOnDrop {
+        fn drop(&mut self) {
+            panic!("unable to flush buffer");
+        }
+    }
+
+    #[test]
+    fn assert_no_panic_on_drop_passing() {
+        assert_no_panic_on_drop!(vec![1, 2, 3]);
+        assert_no_panic_on_drop!(String::from("hello, world"));
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not panic when dropped")]
+    fn assert_no_panic_on_drop_failing() {
+        assert_no_panic_on_drop!(PanicsOnDrop);
+    }
+
+    #[test]
+    fn assert_no_panic_on_drop_passing_negate() {
+        assert_no_panic_on_drop!(PanicsOnDrop, negate = true);
+    }
+
+    #[test]
+    fn assert_no_panic_on_drop_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_no_panic_on_drop!(PanicsOnDrop);
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("value type").unwrap().value,
+            any::type_name::<PanicsOnDrop>()
+        );
+        assert_eq!(
+            failure.argument("panic message").unwrap().value,
+            "unable to flush buffer"
+        );
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_passing_on_stdout() {
+        assert_panics!(
+            || {
+                println!("before panic");
+                panic!("hello, world");
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "before panic\n");
+            }
+        );
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_passing_on_stderr_without_panic_message() {
+        assert_panics!(
+            || {
+                eprintln!("before panic");
+                panic!("hello, world");
+            },
+            on_stderr = |stderr| {
+                assert_eq!(stderr, "before panic\n");
+            }
+        );
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_passing_on_message_on_stdout_on_stderr() {
+        let mut calls = Vec::new();
+
+        assert_panics!(
+            || {
+                println!("out");
+                eprintln!("err");
+                panic!("hello, world");
+            },
+            on_message = |message| {
+                assert_eq!(message, "hello, world");
+                calls.push("message");
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "out\n");
+            },
+            on_stderr = |stderr| {
+                assert_eq!(stderr, "err\n");
+            }
+        );
+
+        assert_eq!(calls, vec!["message"]);
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_passing_with_type_on_stdout() {
+        #[derive(Debug)]
+        struct MyError;
+
+        assert_panics!(
+            || {
+                print!("about to fail");
+                panic::panic_any(MyError);
+            },
+            with_type = MyError,
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "about to fail");
+            }
+        );
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_passing_on_stdout_negate() {
+        assert_panics!(
+            || {
+                println!("no panic");
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "no panic\n");
+            },
+            negate = true
+        );
+    }
+
+    #[cfg(feature = "output")]
+    #[test]
+    fn assert_panics_failing_on_stdout() {
+        let failure = capture_assertion_failure(|| {
+            assert_panics!(
+                || {
+                    println!("no panic");
+                },
+                on_stdout = |_stdout| {
+                    panic!("on_stdout should not be called");
+                }
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "action panics");
+    }
 }
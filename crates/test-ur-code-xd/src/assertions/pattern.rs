@@ -0,0 +1,292 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that check values against patterns.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/pattern](https://sophie-katz.github.io/test-ur-code-XD/assertions/pattern/)
+//! for a usage guide.
+
+use std::{fmt::Debug, panic::Location};
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+/// Configures a panic message builder for [`assert_matches`](crate::assert_matches) and
+/// [`assert_let`](crate::assert_let).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the value
+/// * `value` - The value, if it is still available. It is not available when the pattern matched
+///             and moved out of it.
+/// * `pattern_description` - The stringified pattern, including any guard
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_pattern_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: Option<&dyn Debug>,
+    pattern_description: &str,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = match value {
+        Some(value) => panic_message_builder.with_argument("value", value_description, &value)?,
+        None => panic_message_builder.with_argument_formatted(
+            "value",
+            value_description,
+            "<moved by pattern>",
+        )?,
+    };
+
+    panic_message_builder.with_argument_formatted("pattern", "--", pattern_description)
+}
+
+/// Panics when [`assert_let`](crate::assert_let) is negated and the pattern does not match.
+///
+/// The pattern's variables cannot be bound when it does not match, so there is no way for the code
+/// after the assertion to continue.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[track_caller]
+pub fn panic_let_pattern_not_matched() -> ! {
+    PanicMessageBuilder::new(
+        MessageType::ErrorWhileCheckingAssertion,
+        "pattern did not match, so its variables cannot be bound (assert_let! cannot be negated)",
+        Location::caller(),
+    )
+    .panic()
+}
+
+/// Asserts that a value matches a pattern, with an optional guard.
+///
+/// This mirrors the unstable `std::assert_matches!` macro. On failure the panic message shows the
+/// [`Debug`] representation of the value along with the pattern.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/pattern](https://sophie-katz.github.io/test-ur-code-XD/assertions/pattern/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The value to match. It must implement [`Debug`].
+/// * `pattern` - The pattern to match against, optionally followed by `if <guard>`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_matches;
+/// #
+/// let value: Result<i32, String> = Ok(5);
+///
+/// assert_matches!(value, Ok(_));
+/// assert_matches!(value, Ok(x) if x > 3);
+/// assert_matches!(value, Err(_), negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_matches {
+    ($value:expr, $pattern:pat $(if $guard:expr)? $(, $keys:ident = $values:expr)* $(,)?) => {
+        match $value {
+            $pattern $(if $guard)? => $crate::assert_custom!(
+                "value matches pattern",
+                true,
+                |panic_message_builder| {
+                    $crate::assertions::pattern::configure_pattern_panic_message(
+                        panic_message_builder,
+                        stringify!($value),
+                        ::std::option::Option::None,
+                        stringify!($pattern $(if $guard)?),
+                    )
+                }
+                $(, $keys = $values)*
+            ),
+            // The pattern may be irrefutable, in which case this arm is never reached
+            #[allow(unreachable_patterns)]
+            ref value => $crate::assert_custom!(
+                "value matches pattern",
+                false,
+                |panic_message_builder| {
+                    $crate::assertions::pattern::configure_pattern_panic_message(
+                        panic_message_builder,
+                        stringify!($value),
+                        ::std::option::Option::Some(value),
+                        stringify!($pattern $(if $guard)?),
+                    )
+                }
+                $(, $keys = $values)*
+            ),
+        }
+    };
+}
+
+/// Asserts that a value matches a pattern, then binds the pattern's variables for use after the
+/// assertion.
+///
+/// This is like a `let ... else` statement that panics with a formatted message when the pattern
+/// does not match. It cannot be negated, since the variables cannot be bound if the pattern does not
+/// match.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/pattern](https://sophie-katz.github.io/test-ur-code-XD/assertions/pattern/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `pattern` - The pattern to match against.
+/// * `value` - The value to match. It must implement [`Debug`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, assert_let};
+/// #
+/// let value: Result<i32, String> = Ok(5);
+///
+/// assert_let!(Ok(x) = value);
+///
+/// assert_eq!(x, 5);
+/// ```
+#[macro_export]
+macro_rules! assert_let {
+    ($pattern:pat = $value:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        let value = $value;
+
+        // The pattern's variables are only bound for this check, and are bound again below
+        #[allow(unused_variables)]
+        let is_match = matches!(&value, $pattern);
+
+        $crate::assert_custom!(
+            "value matches pattern",
+            is_match,
+            |panic_message_builder| {
+                $crate::assertions::pattern::configure_pattern_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::option::Option::Some(&value),
+                    stringify!($pattern),
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        let $pattern = value else {
+            $crate::assertions::pattern::panic_let_pattern_not_matched()
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_eq;
+
+    #[derive(Debug)]
+    enum Shape {
+        Circle { radius: f64 },
+        Square(f64),
+    }
+
+    #[test]
+    fn assert_matches_passing() {
+        assert_matches!(Some(5), Some(_));
+        assert_matches!(Some(5), Some(1..=9));
+        assert_matches!(Shape::Square(2.0), Shape::Square(side) if side > 1.0);
+        assert_matches!(
+            Shape::Circle { radius: 2.0 },
+            Shape::Square(_) | Shape::Circle { .. }
+        );
+    }
+
+    #[test]
+    fn assert_matches_passing_guard() {
+        let value: Result<i32, String> = Ok(5);
+
+        assert_matches!(value, Ok(x) if x > 3);
+    }
+
+    #[test]
+    fn assert_matches_passing_does_not_move_value() {
+        let value = Some("hello".to_owned());
+
+        assert_matches!(value, Some(_));
+
+        assert_eq!(value, Some("hello".to_owned()));
+    }
+
+    #[test]
+    fn assert_matches_passing_negate() {
+        assert_matches!(None::<i32>, Some(_), negate = true);
+        assert_matches!(Some(1), Some(x) if x > 3, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches pattern")]
+    fn assert_matches_failing() {
+        assert_matches!(Shape::Circle { radius: 1.0 }, Shape::Square(_));
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches pattern")]
+    fn assert_matches_failing_guard() {
+        assert_matches!(Some(1), Some(x) if x > 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches pattern")]
+    fn assert_matches_failing_negate() {
+        assert_matches!(Some(5), Some(_), negate = true);
+    }
+
+    #[test]
+    fn assert_let_passing() {
+        let shape = Shape::Circle { radius: 2.0 };
+
+        assert_let!(Shape::Circle { radius } = shape);
+
+        crate::assert_f64_eq!(radius, 2.0);
+    }
+
+    #[test]
+    fn assert_let_passing_owned() {
+        let value: Result<String, String> = Ok("hello".to_owned());
+
+        assert_let!(Ok(text) = value, description = "value should be ok");
+
+        assert_eq!(text, "hello");
+    }
+
+    #[test]
+    #[should_panic(expected = "value matches pattern")]
+    fn assert_let_failing() {
+        let value: Result<i32, String> = Err("failure".to_owned());
+
+        assert_let!(Ok(_x) = value);
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern did not match")]
+    fn assert_let_failing_negate() {
+        let value: Option<i32> = None;
+
+        assert_let!(Some(_x) = value, negate = true);
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that compare sequences of points with a floating-point tolerance.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// A point whose coordinates can be compared by
+/// [`assert_points_close`](crate::assert_points_close).
+///
+/// This is implemented for 2D and 3D tuples of `f32` and `f64`.
+pub trait FloatPoint: Copy + Debug {
+    /// Gets the coordinates of the point as `f64` values, one per axis.
+    fn coordinates(self) -> Vec<f64>;
+}
+
+impl FloatPoint for (f32, f32) {
+    fn coordinates(self) -> Vec<f64> {
+        vec![f64::from(self.0), f64::from(self.1)]
+    }
+}
+
+impl FloatPoint for (f32, f32, f32) {
+    fn coordinates(self) -> Vec<f64> {
+        vec![f64::from(self.0), f64::from(self.1), f64::from(self.2)]
+    }
+}
+
+impl FloatPoint for (f64, f64) {
+    fn coordinates(self) -> Vec<f64> {
+        vec![self.0, self.1]
+    }
+}
+
+impl FloatPoint for (f64, f64, f64) {
+    fn coordinates(self) -> Vec<f64> {
+        vec![self.0, self.1, self.2]
+    }
+}
+
+/// How far apart two corresponding points are.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct PointDeviation<PointType> {
+    /// The index of the points within their slices.
+    pub index: usize,
+
+    /// The point from the left-hand side.
+    pub lhs: PointType,
+
+    /// The point from the right-hand side.
+    pub rhs: PointType,
+
+    /// The difference between the points along each axis, `rhs - lhs`.
+    pub deltas: Vec<f64>,
+
+    /// The euclidean distance between the points.
+    pub distance: f64,
+}
+
+/// Helper function to measure how far apart two points are.
+#[must_use]
+fn measure_point_deviation<PointType: FloatPoint>(
+    index: usize,
+    lhs: PointType,
+    rhs: PointType,
+) -> PointDeviation<PointType> {
+    // Neither `f32` or `f64` implement `num_traits::CheckedSub`, so we just ignore the warning.
+    #[allow(clippy::arithmetic_side_effects)]
+    let deltas: Vec<f64> = lhs
+        .coordinates()
+        .into_iter()
+        .zip(rhs.coordinates())
+        .map(|(lhs_coordinate, rhs_coordinate)| rhs_coordinate - lhs_coordinate)
+        .collect();
+
+    let distance = deltas
+        .iter()
+        .fold(0.0, |sum, delta| delta.mul_add(*delta, sum))
+        .sqrt();
+
+    PointDeviation {
+        index,
+        lhs,
+        rhs,
+        deltas,
+        distance,
+    }
+}
+
+/// Measures how far apart each pair of corresponding points is.
+///
+/// # Returns
+///
+/// A deviation for each index at which both slices have a point. NaN coordinates result in a NaN
+/// distance.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn measure_point_deviations<PointType: FloatPoint>(
+    lhs: &[PointType],
+    rhs: &[PointType],
+) -> Vec<PointDeviation<PointType>> {
+    lhs.iter()
+        .zip(rhs)
+        .enumerate()
+        .map(|(index, (lhs_point, rhs_point))| {
+            measure_point_deviation(index, *lhs_point, *rhs_point)
+        })
+        .collect()
+}
+
+/// Checks whether a deviation is outside of the tolerance. NaN distances are always outside.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn is_point_deviation_outside_epsilon<PointType>(
+    deviation: &PointDeviation<PointType>,
+    epsilon: f64,
+) -> bool {
+    deviation.distance.is_nan() || deviation.distance > epsilon
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_points_close_impl<PointType>(
+    lhs_len: usize,
+    rhs_len: usize,
+    deviations: &[PointDeviation<PointType>],
+    epsilon: f64,
+) -> bool {
+    lhs_len == rhs_len
+        && !deviations
+            .iter()
+            .any(|deviation| is_point_deviation_outside_epsilon(deviation, epsilon))
+}
+
+/// Configures a panic message builder for [`assert_points_close`](crate::assert_points_close).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_description` - The description of the left-hand side of the comparison
+/// * `lhs` - The left-hand side of the comparison
+/// * `rhs_description` - The description of the right-hand side of the comparison
+/// * `rhs` - The right-hand side of the comparison
+/// * `deviations` - The deviation of each pair of corresponding points
+/// * `epsilon` - The maximum euclidean distance allowed between corresponding points
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_points_close_panic_message<PointType: FloatPoint>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_description: &str,
+    lhs: &[PointType],
+    rhs_description: &str,
+    rhs: &[PointType],
+    deviations: &[PointDeviation<PointType>],
+    epsilon: f64,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let mut panic_message_builder = panic_message_builder
+        .with_argument("lhs", lhs_description, &lhs)?
+        .with_argument("rhs", rhs_description, &rhs)?;
+
+    if lhs.len() != rhs.len() {
+        panic_message_builder = panic_message_builder
+            .with_argument("lhs length", "--", &lhs.len())?
+            .with_argument("rhs length", "--", &rhs.len())?;
+    }
+
+    let outside_count = deviations
+        .iter()
+        .filter(|deviation| is_point_deviation_outside_epsilon(deviation, epsilon))
+        .count();
+
+    // NaN distances sort as the worst so that they are always reported
+    let worst = deviations.iter().max_by(|lhs_deviation, rhs_deviation| {
+        lhs_deviation
+            .distance
+            .is_nan()
+            .cmp(&rhs_deviation.distance.is_nan())
+            .then_with(|| lhs_deviation.distance.total_cmp(&rhs_deviation.distance))
+    });
+
+    match worst {
+        Some(worst) if outside_count > 0 => panic_message_builder
+            .with_argument("points outside epsilon", "--", &outside_count)?
+            .with_argument("worst index", "--", &worst.index)?
+            .with_argument("worst lhs point", "--", &worst.lhs)?
+            .with_argument("worst rhs point", "--", &worst.rhs)?
+            .with_argument("axis deltas", "--", &worst.deltas)?
+            .with_argument("euclidean distance", "--", &worst.distance),
+        _ => Ok(panic_message_builder),
+    }
+}
+
+/// Asserts that two slices of points are close to each other, point by point.
+///
+/// Each pair of corresponding points must be within `epsilon` of each other by euclidean distance.
+/// If not, the panic message reports the index, per-axis deltas, and euclidean distance of the
+/// worst point.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/float](https://sophie-katz.github.io/test-ur-code-XD/assertions/float/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The left-hand side, which can be anything that dereferences to a slice of
+///           [`FloatPoint`](crate::assertions::point::FloatPoint) values, like `(x, y)` or
+///           `(x, y, z)` tuples
+/// * `rhs` - The right-hand side, which must have the same point type as `lhs`
+/// * `epsilon = <value>` - The maximum euclidean distance allowed between corresponding points
+/// * Optional keyword arguments for assertions
+///
+/// **Note:** `epsilon` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_points_close;
+/// #
+/// let path = vec![(0.0, 0.0), (1.0, 0.5), (2.0, 1.0)];
+///
+/// assert_points_close!(path, [(0.0, 0.0), (1.0, 0.500_000_1), (2.0, 1.0)], epsilon = 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_points_close {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        epsilon = $epsilon:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let lhs: &[_] = &$lhs;
+        let rhs: &[_] = &$rhs;
+        let epsilon: f64 = $epsilon;
+
+        let deviations = $crate::assertions::point::measure_point_deviations(lhs, rhs);
+
+        $crate::assert_custom!(
+            format!("lhs points == rhs points (within {epsilon:?} euclidean distance)"),
+            $crate::assertions::point::assert_points_close_impl(
+                lhs.len(),
+                rhs.len(),
+                &deviations,
+                epsilon,
+            ),
+            |panic_message_builder| {
+                $crate::assertions::point::configure_points_close_panic_message(
+                    panic_message_builder,
+                    stringify!($lhs),
+                    lhs,
+                    stringify!($rhs),
+                    rhs,
+                    &deviations,
+                    epsilon,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn coordinates_f32() {
+        assert_eq!((1.0_f32, 2.0_f32).coordinates(), vec![1.0, 2.0]);
+        assert_eq!(
+            (1.0_f32, 2.0_f32, 3.0_f32).coordinates(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn measure_point_deviations_2d() {
+        let deviations =
+            measure_point_deviations(&[(0.0, 0.0), (1.0, 1.0)], &[(0.0, 0.0), (4.0, 5.0)]);
+
+        assert_eq!(deviations.len(), 2);
+        assert_eq!(
+            deviations.last().map(|deviation| (
+                deviation.index,
+                deviation.deltas.clone(),
+                deviation.distance
+            )),
+            Some((1, vec![3.0, 4.0], 5.0))
+        );
+    }
+
+    #[test]
+    fn measure_point_deviations_different_lengths() {
+        assert_eq!(
+            measure_point_deviations(&[(0.0, 0.0, 0.0)], &[(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)]).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn is_point_deviation_outside_epsilon_nan() {
+        let deviations = measure_point_deviations(&[(f64::NAN, 0.0)], &[(0.0, 0.0)]);
+
+        assert!(deviations
+            .iter()
+            .all(|deviation| is_point_deviation_outside_epsilon(deviation, 1.0)));
+    }
+
+    #[test]
+    fn assert_points_close_passing() {
+        assert_points_close!(
+            [(0.0, 0.0), (1.0, 1.0)],
+            [(0.0, 0.0), (1.0, 1.0)],
+            epsilon = 0.0
+        );
+        assert_points_close!(
+            vec![(0.0_f32, 0.0_f32, 0.0_f32)],
+            [(0.0, 0.000_000_1, 0.0)],
+            epsilon = 1e-6
+        );
+        assert_points_close!(Vec::<(f64, f64)>::new(), [], epsilon = 1e-6);
+    }
+
+    #[test]
+    fn assert_points_close_passing_negate() {
+        assert_points_close!([(0.0, 0.0)], [(0.0, 1.0)], epsilon = 0.5, negate = true);
+        assert_points_close!([(0.0, 0.0)], [], epsilon = 0.5, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs points == rhs points (within 0.5 euclidean distance)")]
+    fn assert_points_close_failing() {
+        assert_points_close!(
+            [(0.0, 0.0), (1.0, 1.0)],
+            [(0.0, 0.0), (1.0, 2.0)],
+            epsilon = 0.5
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs points == rhs points")]
+    fn assert_points_close_failing_length() {
+        assert_points_close!([(0.0, 0.0, 0.0)], [], epsilon = 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs points == rhs points")]
+    fn assert_points_close_failing_nan() {
+        assert_points_close!([(f64::NAN, 0.0)], [(0.0, 0.0)], epsilon = 0.5);
+    }
+}
@@ -0,0 +1,1140 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that poll a condition until it holds or a timeout is reached.
+//!
+//! While waiting, a dim progress line is printed to `stderr` about once per second when tests are
+//! run with `--nocapture`, so that long waits don't look like hangs.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+//! for a usage guide.
+
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Mutex, MutexGuard, TryLockError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::{
+        assertion_failure::{last_assertion_failure, set_last_assertion_failure},
+        color_choice::style,
+        format::{format_duration, format_duration_with_budget},
+        panic_message_builder::{
+            is_panic_output_suppressed, set_panic_output_suppressed, PanicMessageBuilder,
+        },
+    },
+};
+
+/// The default maximum amount of time to wait for a condition to hold.
+pub const DEFAULT_POLLING_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The default amount of time to wait between checks of the condition.
+pub const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The minimum amount of time between progress lines.
+const PROGRESS_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The result of polling a condition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PollingOutcome {
+    /// Whether or not the condition held before the timeout was reached.
+    pub is_satisfied: bool,
+
+    /// The number of times that the condition was checked.
+    pub attempt_count: usize,
+
+    /// The amount of time spent polling.
+    pub elapsed: Duration,
+}
+
+/// Helper function to check whether or not the test harness is capturing output.
+///
+/// Output is not captured when tests are run with `--nocapture` or with the `RUST_TEST_NOCAPTURE`
+/// environment variable set to anything other than `0`.
+#[must_use]
+fn is_output_captured() -> bool {
+    let is_nocapture_env_set =
+        env::var_os("RUST_TEST_NOCAPTURE").map_or(false, |value| value != "0");
+
+    let is_nocapture_arg_passed =
+        env::args().any(|argument| argument == "--nocapture" || argument == "--no-capture");
+
+    !is_nocapture_env_set && !is_nocapture_arg_passed
+}
+
+/// Helper function to format a progress line for a condition that is still being polled.
+#[must_use]
+fn format_progress_line(attempt_count: usize, elapsed: Duration) -> String {
+    format!(
+        "  still waiting for condition (attempt {attempt_count}, {} elapsed)",
+        format_duration(elapsed)
+    )
+}
+
+/// Helper function to print a progress line to `stderr`.
+// Stderr printing allowed because this is only done when the user has asked to see output.
+#[allow(clippy::print_stderr)]
+fn print_progress_line(attempt_count: usize, elapsed: Duration) {
+    eprintln!(
+        "{}",
+        style(format_progress_line(attempt_count, elapsed)).dim()
+    );
+}
+
+/// Helper function to poll a condition, calling a progress callback about once per
+/// [`PROGRESS_REPORT_INTERVAL`].
+fn poll_until_with_progress(
+    mut condition: impl FnMut() -> bool,
+    timeout: Duration,
+    interval: Duration,
+    progress_report_interval: Duration,
+    mut report_progress: impl FnMut(usize, Duration),
+) -> PollingOutcome {
+    let start = Instant::now();
+    let mut last_progress_report = start;
+    let mut attempt_count: usize = 0;
+
+    loop {
+        attempt_count = attempt_count.saturating_add(1);
+
+        if condition() {
+            return PollingOutcome {
+                is_satisfied: true,
+                attempt_count,
+                elapsed: start.elapsed(),
+            };
+        }
+
+        let elapsed = start.elapsed();
+
+        if elapsed >= timeout {
+            return PollingOutcome {
+                is_satisfied: false,
+                attempt_count,
+                elapsed,
+            };
+        }
+
+        if last_progress_report.elapsed() >= progress_report_interval {
+            report_progress(attempt_count, elapsed);
+            last_progress_report = Instant::now();
+        }
+
+        thread::sleep(interval.min(timeout.saturating_sub(elapsed)));
+    }
+}
+
+/// Polls a condition until it returns `true` or the timeout is reached.
+///
+/// The condition is always checked at least once. When tests are run with `--nocapture`, a dim
+/// progress line with the attempt count and elapsed time is printed to `stderr` about once per
+/// second.
+///
+/// # Arguments
+///
+/// * `condition` - The condition to poll.
+/// * `timeout` - The maximum amount of time to wait for the condition to hold.
+/// * `interval` - The amount of time to wait between checks of the condition.
+///
+/// # Returns
+///
+/// The outcome of polling.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::polling::poll_until;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+///
+/// let outcome = poll_until(
+///     || start.elapsed() >= Duration::from_millis(20),
+///     Duration::from_secs(1),
+///     Duration::from_millis(5),
+/// );
+///
+/// assert!(outcome.is_satisfied);
+/// ```
+pub fn poll_until(
+    condition: impl FnMut() -> bool,
+    timeout: Duration,
+    interval: Duration,
+) -> PollingOutcome {
+    let should_report_progress = !is_output_captured();
+
+    poll_until_with_progress(
+        condition,
+        timeout,
+        interval,
+        PROGRESS_REPORT_INTERVAL,
+        |attempt_count, elapsed| {
+            if should_report_progress {
+                print_progress_line(attempt_count, elapsed);
+            }
+        },
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn file_contains(path: impl AsRef<Path>, substring: impl AsRef<str>) -> bool {
+    fs::read_to_string(path).map_or(false, |text| text.contains(substring.as_ref()))
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_polling_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    outcome: &PollingOutcome,
+    timeout: Duration,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument("attempts", "--", &outcome.attempt_count)?
+        .with_argument_formatted(
+            "elapsed",
+            "--",
+            format_duration_with_budget(outcome.elapsed, timeout),
+        )
+}
+
+/// Asserts that a condition eventually holds.
+///
+/// The condition is polled until it returns `true` or the timeout is reached. When tests are run
+/// with `--nocapture`, a dim progress line is printed about once per second while waiting.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `condition` - A closure with no arguments that returns a `bool`.
+/// * Optional: `timeout = <value>` - The maximum [`Duration`](std::time::Duration) to wait.
+///                                   Defaults to [`DEFAULT_POLLING_TIMEOUT`].
+/// * Optional: `interval = <value>` - The [`Duration`](std::time::Duration) to wait between
+///                                    checks. Defaults to [`DEFAULT_POLLING_INTERVAL`].
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `timeout` must always come before `interval`, and both must come before any other
+/// keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_eventually;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+///
+/// assert_eventually!(
+///     || start.elapsed() >= Duration::from_millis(20),
+///     timeout = Duration::from_secs(1),
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_eventually {
+    (
+        $condition:expr,
+        timeout = $timeout:expr,
+        interval = $interval:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let timeout = $timeout;
+
+        let outcome = $crate::assertions::polling::poll_until($condition, timeout, $interval);
+
+        $crate::assert_custom!(
+            "condition eventually holds",
+            outcome.is_satisfied,
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_polling_panic_message(
+                    panic_message_builder.with_argument(
+                        "condition",
+                        stringify!($condition),
+                        &outcome.is_satisfied,
+                    )?,
+                    &outcome,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($condition:expr, timeout = $timeout:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_eventually!(
+            $condition,
+            timeout = $timeout,
+            interval = $crate::assertions::polling::DEFAULT_POLLING_INTERVAL
+            $(, $keys = $values)*
+        )
+    };
+
+    ($condition:expr, interval = $interval:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_eventually!(
+            $condition,
+            timeout = $crate::assertions::polling::DEFAULT_POLLING_TIMEOUT,
+            interval = $interval
+            $(, $keys = $values)*
+        )
+    };
+
+    ($condition:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_eventually!(
+            $condition,
+            timeout = $crate::assertions::polling::DEFAULT_POLLING_TIMEOUT,
+            interval = $crate::assertions::polling::DEFAULT_POLLING_INTERVAL
+            $(, $keys = $values)*
+        )
+    };
+}
+
+/// Asserts that a file eventually contains a substring.
+///
+/// The file is read until it contains the substring or the timeout is reached. It is fine for the
+/// file to not exist yet. When tests are run with `--nocapture`, a dim progress line is printed
+/// about once per second while waiting.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to read.
+/// * `substring` - The substring for which to check.
+/// * Optional: `timeout = <value>` - The maximum [`Duration`](std::time::Duration) to wait.
+///                                   Defaults to [`DEFAULT_POLLING_TIMEOUT`].
+/// * Optional: `interval = <value>` - The [`Duration`](std::time::Duration) to wait between
+///                                    reads. Defaults to [`DEFAULT_POLLING_INTERVAL`].
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `timeout` must always come before `interval`, and both must come before any other
+/// keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_file_eventually_contains;
+/// # use std::{fs, thread, time::Duration};
+/// # use tempfile::tempdir;
+/// #
+/// # let temp_dir = tempdir().unwrap();
+/// let path = temp_dir.path().join("server.log");
+///
+/// let writer_path = path.clone();
+/// thread::spawn(move || fs::write(writer_path, "server started"));
+///
+/// assert_file_eventually_contains!(&path, "started", timeout = Duration::from_secs(5));
+/// ```
+#[macro_export]
+macro_rules! assert_file_eventually_contains {
+    (
+        $path:expr,
+        $substring:expr,
+        timeout = $timeout:expr,
+        interval = $interval:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let path = $path;
+        let substring = $substring;
+        let timeout = $timeout;
+
+        let outcome = $crate::assertions::polling::poll_until(
+            || $crate::assertions::polling::file_contains(&path, &substring),
+            timeout,
+            $interval,
+        );
+
+        $crate::assert_custom!(
+            "file eventually contains substring",
+            outcome.is_satisfied,
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_polling_panic_message(
+                    panic_message_builder
+                        .with_argument(
+                            "path",
+                            stringify!($path),
+                            &::std::convert::AsRef::<::std::path::Path>::as_ref(&path),
+                        )?
+                        .with_argument(
+                            "substring",
+                            stringify!($substring),
+                            &::std::convert::AsRef::<str>::as_ref(&substring),
+                        )?,
+                    &outcome,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $path:expr,
+        $substring:expr,
+        timeout = $timeout:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_file_eventually_contains!(
+            $path,
+            $substring,
+            timeout = $timeout,
+            interval = $crate::assertions::polling::DEFAULT_POLLING_INTERVAL
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $path:expr,
+        $substring:expr,
+        interval = $interval:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_file_eventually_contains!(
+            $path,
+            $substring,
+            timeout = $crate::assertions::polling::DEFAULT_POLLING_TIMEOUT,
+            interval = $interval
+            $(, $keys = $values)*
+        )
+    };
+
+    ($path:expr, $substring:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_file_eventually_contains!(
+            $path,
+            $substring,
+            timeout = $crate::assertions::polling::DEFAULT_POLLING_TIMEOUT,
+            interval = $crate::assertions::polling::DEFAULT_POLLING_INTERVAL
+            $(, $keys = $values)*
+        )
+    };
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn recv_within<ValueType>(
+    receiver: &Receiver<ValueType>,
+    timeout: Duration,
+) -> (Result<ValueType, RecvTimeoutError>, Duration) {
+    let start = Instant::now();
+
+    let result = receiver.recv_timeout(timeout);
+
+    (result, start.elapsed())
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_recv_within_panic_message<ValueType>(
+    panic_message_builder: PanicMessageBuilder,
+    receiver_description: &str,
+    result: &Result<ValueType, RecvTimeoutError>,
+    elapsed: Duration,
+    timeout: Duration,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument_formatted(
+            "receiver",
+            receiver_description,
+            result
+                .as_ref()
+                .map_or_else(ToString::to_string, |_| "received a value".to_owned()),
+        )?
+        .with_argument_formatted(
+            "elapsed",
+            "--",
+            format_duration_with_budget(elapsed, timeout),
+        )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn lock_within<ValueType: ?Sized>(
+    mutex: &Mutex<ValueType>,
+    timeout: Duration,
+    interval: Duration,
+) -> (Option<MutexGuard<'_, ValueType>>, PollingOutcome) {
+    let mut guard = None;
+
+    let outcome = poll_until(
+        || match mutex.try_lock() {
+            Ok(acquired) => {
+                guard = Some(acquired);
+                true
+            }
+            Err(TryLockError::Poisoned(error)) => {
+                guard = Some(error.into_inner());
+                true
+            }
+            Err(TryLockError::WouldBlock) => false,
+        },
+        timeout,
+        interval,
+    );
+
+    (guard, outcome)
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn complete_within<ResultType: Send + 'static>(
+    action: impl FnOnce() -> ResultType + Send + 'static,
+    timeout: Duration,
+) -> (Option<ResultType>, Duration) {
+    let (sender, receiver) = mpsc::channel();
+
+    let is_suppressed = is_panic_output_suppressed();
+
+    let start = Instant::now();
+
+    thread::spawn(move || {
+        set_panic_output_suppressed(is_suppressed);
+
+        // Unwind safety is asserted because the panic is passed on to the caller, which is where it
+        // would have happened if the action had been called directly.
+        let result = panic::catch_unwind(AssertUnwindSafe(action))
+            .map_err(|payload| (payload, last_assertion_failure()));
+
+        // The receiver is dropped if the timeout is reached first, in which case the result is
+        // discarded.
+        let _send_result = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok(result)) => (Some(result), start.elapsed()),
+        Ok(Err((payload, failure))) => {
+            // Assertion failures are recorded per thread, so the failure is copied over for
+            // anything on the caller's thread that is capturing it.
+            if let Some(failure) = failure {
+                set_last_assertion_failure(failure);
+            }
+
+            panic::resume_unwind(payload)
+        }
+        Err(_) => (None, start.elapsed()),
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_complete_within_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    action_description: &str,
+    is_completed: bool,
+    elapsed: Duration,
+    timeout: Duration,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder
+        .with_argument_formatted(
+            "action",
+            action_description,
+            if is_completed {
+                "completed"
+            } else {
+                "still running"
+            },
+        )?
+        .with_argument_formatted(
+            "elapsed",
+            "--",
+            format_duration_with_budget(elapsed, timeout),
+        )
+}
+
+/// Asserts that a value is received from a channel within a timeout.
+///
+/// Unlike [`Receiver::recv`](std::sync::mpsc::Receiver::recv), this fails with the time spent
+/// waiting instead of hanging the whole test run if the value is never sent.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `receiver` - The [`Receiver`](std::sync::mpsc::Receiver) from which to receive.
+/// * `timeout` - The maximum [`Duration`](std::time::Duration) to wait.
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// The received value, or `None` if nothing was received. This can only be `None` if the assertion
+/// doesn't panic, like when it is negated.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_recv_within;
+/// use std::{sync::mpsc, thread, time::Duration};
+///
+/// let (sender, receiver) = mpsc::channel();
+///
+/// thread::spawn(move || sender.send(5));
+///
+/// assert_eq!(assert_recv_within!(receiver, Duration::from_secs(5)), Some(5));
+/// ```
+#[macro_export]
+macro_rules! assert_recv_within {
+    ($receiver:expr, $timeout:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let timeout = $timeout;
+
+        let (result, elapsed) = $crate::assertions::polling::recv_within(&$receiver, timeout);
+
+        $crate::assert_custom!(
+            "value is received within timeout",
+            result.is_ok(),
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_recv_within_panic_message(
+                    panic_message_builder,
+                    stringify!($receiver),
+                    &result,
+                    elapsed,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        result.ok()
+    }};
+}
+
+/// Asserts that a mutex can be locked within a timeout.
+///
+/// The mutex is polled with [`Mutex::try_lock`](std::sync::Mutex::try_lock) until it is acquired
+/// or the timeout is reached, so a deadlock fails with the time spent waiting instead of hanging
+/// the whole test run. A poisoned mutex still counts as acquired.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `mutex` - The [`Mutex`](std::sync::Mutex) to lock.
+/// * `timeout` - The maximum [`Duration`](std::time::Duration) to wait.
+/// * Optional: `interval = <value>` - The [`Duration`](std::time::Duration) to wait between
+///                                    attempts. Defaults to [`DEFAULT_POLLING_INTERVAL`].
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `interval` must come before any other keyword arguments.
+///
+/// # Returns
+///
+/// The guard for the locked mutex, or `None` if it wasn't acquired. This can only be `None` if the
+/// assertion doesn't panic, like when it is negated.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_lock_acquired_within;
+/// use std::{sync::Mutex, time::Duration};
+///
+/// let counter = Mutex::new(0);
+///
+/// if let Some(mut guard) = assert_lock_acquired_within!(counter, Duration::from_secs(5)) {
+///     *guard += 1;
+/// }
+///
+/// assert_eq!(*counter.lock().unwrap(), 1);
+/// ```
+#[macro_export]
+macro_rules! assert_lock_acquired_within {
+    (
+        $mutex:expr,
+        $timeout:expr,
+        interval = $interval:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let timeout = $timeout;
+
+        let (guard, outcome) =
+            $crate::assertions::polling::lock_within(&$mutex, timeout, $interval);
+
+        $crate::assert_custom!(
+            "lock is acquired within timeout",
+            outcome.is_satisfied,
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_polling_panic_message(
+                    panic_message_builder.with_argument_formatted(
+                        "mutex",
+                        stringify!($mutex),
+                        "still locked",
+                    )?,
+                    &outcome,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        guard
+    }};
+
+    ($mutex:expr, $timeout:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_lock_acquired_within!(
+            $mutex,
+            $timeout,
+            interval = $crate::assertions::polling::DEFAULT_POLLING_INTERVAL
+            $(, $keys = $values)*
+        )
+    };
+}
+
+/// Asserts that an action completes within a timeout.
+///
+/// The action is run on its own thread so that an action that hangs fails with the time spent
+/// waiting instead of hanging the whole test run. If the timeout is reached, the thread is left to
+/// finish in the background. If the action panics, like when an assertion in it fails, the panic is
+/// passed on to the caller.
+///
+/// This pairs well with [`SlowReader`](crate::utilities::slow_reader::SlowReader) for testing
+/// timeout handling in code that reads from files, pipes, or network streams.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/polling](https://sophie-katz.github.io/test-ur-code-XD/assertions/polling/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `action` - The closure to run. It must be [`Send`] and `'static` since it runs on another
+///              thread.
+/// * `timeout` - The maximum [`Duration`](std::time::Duration) to wait.
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// The action's return value, or `None` if it didn't complete. This can only be `None` if the
+/// assertion doesn't panic, like when it is negated.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_completes_within, utilities::slow_reader::SlowReader};
+/// use std::{io::Read, time::Duration};
+///
+/// let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::from_millis(10));
+///
+/// let text = assert_completes_within!(
+///     move || {
+///         let mut text = String::new();
+///         reader.read_to_string(&mut text).unwrap();
+///         text
+///     },
+///     Duration::from_secs(5)
+/// );
+///
+/// assert_eq!(text.as_deref(), Some("hello, world"));
+/// ```
+#[macro_export]
+macro_rules! assert_completes_within {
+    ($action:expr, $timeout:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let timeout = $timeout;
+
+        let (output, elapsed) = $crate::assertions::polling::complete_within($action, timeout);
+
+        let is_completed = output.is_some();
+
+        $crate::assert_custom!(
+            "action completes within timeout",
+            is_completed,
+            |panic_message_builder| {
+                $crate::assertions::polling::configure_complete_within_panic_message(
+                    panic_message_builder,
+                    stringify!($action),
+                    is_completed,
+                    elapsed,
+                    timeout,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        output
+    }};
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert, assert_eq, assert_not, utilities::assertion_failure::capture_assertion_failure,
+    };
+    use std::{cell::Cell, sync::mpsc};
+
+    #[test]
+    fn format_progress_line_attempts_and_elapsed() {
+        assert_eq!(
+            format_progress_line(12, Duration::from_millis(3_040)),
+            "  still waiting for condition (attempt 12, 3.04 s elapsed)"
+        );
+    }
+
+    #[test]
+    fn poll_satisfied_first_attempt() {
+        let outcome = poll_until(|| true, Duration::ZERO, Duration::ZERO);
+
+        assert!(outcome.is_satisfied);
+        assert_eq!(outcome.attempt_count, 1);
+    }
+
+    #[test]
+    fn poll_satisfied_after_attempts() {
+        let attempt_count = Cell::new(0);
+
+        let outcome = poll_until(
+            || {
+                attempt_count.set(attempt_count.get() + 1);
+                attempt_count.get() == 3
+            },
+            Duration::from_secs(5),
+            Duration::ZERO,
+        );
+
+        assert!(outcome.is_satisfied);
+        assert_eq!(outcome.attempt_count, 3);
+    }
+
+    #[test]
+    fn poll_timeout() {
+        let outcome = poll_until(
+            || false,
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+        );
+
+        assert_not!(outcome.is_satisfied);
+        assert!(outcome.attempt_count > 1);
+        assert!(outcome.elapsed >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn poll_reports_progress() {
+        let mut reports = Vec::new();
+
+        let outcome = poll_until_with_progress(
+            || false,
+            Duration::from_millis(30),
+            Duration::from_millis(2),
+            Duration::from_millis(5),
+            |attempt_count, elapsed| reports.push((attempt_count, elapsed)),
+        );
+
+        assert_not!(outcome.is_satisfied);
+        assert_not!(reports.is_empty());
+        assert!(reports
+            .iter()
+            .zip(reports.iter().skip(1))
+            .all(|(previous, next)| previous.0 < next.0 && previous.1 < next.1));
+    }
+
+    #[test]
+    fn poll_does_not_report_progress_when_fast() {
+        let mut report_count = 0;
+
+        poll_until_with_progress(
+            || true,
+            Duration::from_secs(5),
+            Duration::ZERO,
+            Duration::from_millis(5),
+            |_, _| report_count += 1,
+        );
+
+        assert_eq!(report_count, 0);
+    }
+
+    #[test]
+    fn assert_eventually_passing() {
+        let attempt_count = Cell::new(0);
+
+        assert_eventually!(
+            || {
+                attempt_count.set(attempt_count.get() + 1);
+                attempt_count.get() == 3
+            },
+            interval = Duration::ZERO
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "condition eventually holds")]
+    fn assert_eventually_failing() {
+        assert_eventually!(
+            || false,
+            timeout = Duration::from_millis(10),
+            interval = Duration::from_millis(1)
+        );
+    }
+
+    #[test]
+    fn assert_eventually_passing_negate() {
+        assert_eventually!(|| false, timeout = Duration::from_millis(10), negate = true);
+    }
+
+    #[test]
+    fn assert_file_eventually_contains_passing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("file.txt");
+
+        let writer_path = path.clone();
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            fs::write(writer_path, "hello, world").unwrap();
+        });
+
+        assert_file_eventually_contains!(&path, "world", interval = Duration::from_millis(1));
+
+        writer.join().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "file eventually contains substring")]
+    fn assert_file_eventually_contains_failing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("file.txt");
+
+        fs::write(&path, "hello").unwrap();
+
+        assert_file_eventually_contains!(
+            &path,
+            "world",
+            timeout = Duration::from_millis(10),
+            interval = Duration::from_millis(1)
+        );
+    }
+
+    #[test]
+    fn assert_recv_within_passing() {
+        let (sender, receiver) = mpsc::channel();
+
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            sender.send(5).unwrap();
+        });
+
+        assert_eq!(
+            assert_recv_within!(receiver, Duration::from_secs(5)),
+            Some(5)
+        );
+
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn assert_recv_within_failing_timeout() {
+        let (_sender, receiver) = mpsc::channel::<i32>();
+
+        let failure = capture_assertion_failure(|| {
+            assert_recv_within!(receiver, Duration::from_millis(10));
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "value is received within timeout");
+        assert_eq!(
+            failure.argument("receiver").unwrap().value,
+            "timed out waiting on channel"
+        );
+        assert!(failure
+            .argument("elapsed")
+            .unwrap()
+            .value
+            .contains("budget 10 ms"));
+    }
+
+    #[test]
+    fn assert_recv_within_failing_disconnected() {
+        let (sender, receiver) = mpsc::channel::<i32>();
+
+        drop(sender);
+
+        let failure = capture_assertion_failure(|| {
+            assert_recv_within!(receiver, Duration::from_secs(5));
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("receiver").unwrap().value,
+            "channel is empty and sending half is closed"
+        );
+    }
+
+    #[test]
+    fn assert_recv_within_passing_negate() {
+        let (_sender, receiver) = mpsc::channel::<i32>();
+
+        assert!(assert_recv_within!(receiver, Duration::from_millis(10), negate = true).is_none());
+    }
+
+    #[test]
+    fn assert_completes_within_passing() {
+        assert_eq!(
+            assert_completes_within!(|| 5, Duration::from_secs(5)),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn assert_completes_within_failing() {
+        let (_sender, receiver) = mpsc::channel::<()>();
+
+        let failure = capture_assertion_failure(|| {
+            assert_completes_within!(
+                move || receiver.recv_timeout(Duration::from_secs(5)),
+                Duration::from_millis(10)
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "action completes within timeout");
+        assert_eq!(failure.argument("action").unwrap().value, "still running");
+        assert!(failure
+            .argument("elapsed")
+            .unwrap()
+            .value
+            .contains("budget 10 ms"));
+    }
+
+    #[test]
+    fn assert_completes_within_passes_on_panics() {
+        let failure = capture_assertion_failure(|| {
+            assert_completes_within!(|| crate::assert_eq!(1, 2), Duration::from_secs(5));
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+    }
+
+    #[test]
+    fn assert_completes_within_passing_negate() {
+        let (_sender, receiver) = mpsc::channel::<()>();
+
+        let output = assert_completes_within!(
+            move || receiver.recv_timeout(Duration::from_secs(5)),
+            Duration::from_millis(10),
+            negate = true
+        );
+
+        assert!(output.is_none());
+    }
+
+    #[test]
+    fn assert_lock_acquired_within_passing() {
+        let mutex = Mutex::new(5);
+
+        let guard = assert_lock_acquired_within!(mutex, Duration::from_secs(5)).unwrap();
+
+        assert_eq!(*guard, 5);
+    }
+
+    #[test]
+    fn assert_lock_acquired_within_passing_after_release() {
+        let mutex = Mutex::new(5);
+
+        let (locked_sender, locked_receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            let mutex = &mutex;
+
+            scope.spawn(move || {
+                let guard = mutex.lock().unwrap();
+                locked_sender.send(()).unwrap();
+                thread::sleep(Duration::from_millis(10));
+                drop(guard);
+            });
+
+            locked_receiver.recv().unwrap();
+
+            assert_lock_acquired_within!(
+                mutex,
+                Duration::from_secs(5),
+                interval = Duration::from_millis(1)
+            );
+        });
+    }
+
+    #[test]
+    // Panicking is the point of this test
+    #[allow(clippy::panic)]
+    fn assert_lock_acquired_within_passing_poisoned() {
+        let mutex = Mutex::new(5);
+
+        thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _guard = mutex.lock().unwrap();
+                    panic!("poison the mutex");
+                })
+                .join()
+                .unwrap_err();
+        });
+
+        assert_lock_acquired_within!(mutex, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn assert_lock_acquired_within_failing() {
+        let mutex = Mutex::new(5);
+        let _guard = mutex.lock().unwrap();
+
+        let failure = capture_assertion_failure(|| {
+            assert_lock_acquired_within!(
+                mutex,
+                Duration::from_millis(10),
+                interval = Duration::from_millis(1)
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "lock is acquired within timeout");
+        assert_eq!(failure.argument("mutex").unwrap().value, "still locked");
+        assert!(failure
+            .argument("elapsed")
+            .unwrap()
+            .value
+            .contains("budget 10 ms"));
+    }
+
+    #[test]
+    fn assert_lock_acquired_within_passing_negate() {
+        let mutex = Mutex::new(5);
+        let _guard = mutex.lock().unwrap();
+
+        assert!(
+            assert_lock_acquired_within!(mutex, Duration::from_millis(10), negate = true).is_none()
+        );
+    }
+}
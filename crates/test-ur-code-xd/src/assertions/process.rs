@@ -0,0 +1,536 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that run processes and check their exit status and output.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/process](https://sophie-katz.github.io/test-ur-code-XD/assertions/process/)
+//! for a usage guide.
+
+use std::{
+    borrow::BorrowMut,
+    panic::{self, AssertUnwindSafe, Location},
+    process::{Command, ExitStatus, Output},
+};
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+/// Helper function to format a command line for a panic message.
+///
+/// The program and each of its arguments are quoted so that arguments containing whitespace are
+/// unambiguous.
+#[must_use]
+fn format_command_line(command: &Command) -> String {
+    format!("{command:?}")
+}
+
+/// Helper function to create the panic message for a failed output callback.
+///
+/// # Errors
+///
+/// * Returns any errors with formatting.
+fn new_output_failure_panic_message_builder(
+    command_line: &str,
+    output: &Output,
+    failure_message: &str,
+    location: &'static Location<'static>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    PanicMessageBuilder::new(MessageType::AssertionFailure, failure_message, location)
+        .with_argument_formatted("command", "--", command_line)
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument_formatted("status", "--", output.status.to_string())
+        })
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument_formatted(
+                "stdout",
+                "--",
+                String::from_utf8_lossy(&output.stdout),
+            )
+        })
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument_formatted(
+                "stderr",
+                "--",
+                String::from_utf8_lossy(&output.stderr),
+            )
+        })
+}
+
+/// Helper function to call an assertion callback on some part of the command's output.
+///
+/// If the callback panics, the panic is re-raised with the command line and all of the captured
+/// output added to the panic message.
+// Expect allowed because any failure to format the panic message is hard coded in, not dependent on
+// how this function is called.
+#[allow(clippy::expect_used)]
+fn call_output_callback<ValueType>(
+    command_line: &str,
+    output: &Output,
+    location: &'static Location<'static>,
+    callback: Option<Box<dyn FnOnce(ValueType)>>,
+    value: ValueType,
+) {
+    let Some(callback) = callback else {
+        return;
+    };
+
+    if let Err(error) = panic::catch_unwind(AssertUnwindSafe(|| callback(value))) {
+        new_output_failure_panic_message_builder(
+            command_line,
+            output,
+            panic_message::panic_message(&error),
+            location,
+        )
+        .expect("error while creating panic message builder")
+        .panic();
+    }
+}
+
+/// Helper function to run a command to completion, panicking if it cannot be run.
+// Expect allowed because any failure to format the panic message is hard coded in, not dependent on
+// how this function is called.
+#[allow(clippy::expect_used)]
+fn run_command(command: &mut Command, location: &'static Location<'static>) -> Output {
+    match command.output() {
+        Ok(output) => output,
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "unable to run command",
+            location,
+            &error,
+        )
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument_formatted(
+                "command",
+                "--",
+                format_command_line(command),
+            )
+        })
+        .expect("error while creating panic message builder")
+        .panic(),
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+// The callback types are warned to be too complex, but it would be less readable to break them up.
+#[allow(clippy::type_complexity)]
+pub fn assert_command_impl(
+    mut command: impl BorrowMut<Command>,
+    location: &'static Location<'static>,
+    on_status: Option<Box<dyn FnOnce(ExitStatus)>>,
+    on_stdout: Option<Box<dyn FnOnce(String)>>,
+    on_stderr: Option<Box<dyn FnOnce(String)>>,
+) {
+    let command = command.borrow_mut();
+
+    let command_line = format_command_line(command);
+
+    // Run the command to completion while capturing its output
+    let output = run_command(command, location);
+
+    call_output_callback(&command_line, &output, location, on_status, output.status);
+
+    call_output_callback(
+        &command_line,
+        &output,
+        location,
+        on_stdout,
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    );
+
+    call_output_callback(
+        &command_line,
+        &output,
+        location,
+        on_stderr,
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    );
+}
+
+/// Formats the exit code, `stdout`, and `stderr` of a command as the text of a snapshot.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::process::format_command_snapshot;
+/// # #[cfg(unix)]
+/// # use std::{os::unix::process::ExitStatusExt, process::{ExitStatus, Output}};
+/// #
+/// # #[cfg(unix)]
+/// assert_eq!(
+///     format_command_snapshot(&Output {
+///         status: ExitStatus::from_raw(0),
+///         stdout: b"hello\n".to_vec(),
+///         stderr: Vec::new(),
+///     }),
+///     "exit code: 0\n----- stdout -----\nhello\n----- stderr -----\n"
+/// );
+/// ```
+#[must_use]
+pub fn format_command_snapshot(output: &Output) -> String {
+    let exit_code = output.status.code().map_or_else(
+        || format!("none ({})", output.status),
+        |exit_code| exit_code.to_string(),
+    );
+
+    format!(
+        "exit code: {exit_code}\n----- stdout -----\n{}----- stderr -----\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn run_command_for_snapshot(
+    mut command: impl BorrowMut<Command>,
+    location: &'static Location<'static>,
+) -> String {
+    format_command_snapshot(&run_command(command.borrow_mut(), location))
+}
+
+/// Assertion wrapper for running a command and checking its exit status and output.
+///
+/// The command is run to completion with `stdout` and `stderr` captured. If any of the callbacks
+/// panic, the failure message includes the full command line, its exit status, and everything that
+/// it wrote to `stdout` and `stderr`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/process](https://sophie-katz.github.io/test-ur-code-XD/assertions/process/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `command` - The [`Command`](std::process::Command) to run. Either an owned command or a
+///               mutable reference to one can be passed.
+/// * Optional: `on_status = <value>` - A closure that accepts an
+///                                     [`ExitStatus`](std::process::ExitStatus) as an argument and
+///                                     returns nothing.
+/// * Optional: `on_stdout = <value>` - A closure that accepts a `String` as an argument and returns
+///                                     nothing. The `String` is the content of `stdout` that was
+///                                     outputted by the command.
+/// * Optional: `on_stderr = <value>` - A closure that accepts a `String` as an argument and returns
+///                                     nothing. The `String` is the content of `stderr` that was
+///                                     outputted by the command.
+///
+/// **Note:** The keyword arguments must always be in the order `on_status`, `on_stdout`,
+/// `on_stderr`.
+///
+/// # Example
+///
+/// ```
+/// # use std::process::Command;
+/// # use test_ur_code_xd::{assert, assert_command, assert_eq};
+/// #
+/// # #[cfg(unix)]
+/// assert_command!(
+///     Command::new("echo").arg("hello, world"),
+///     on_status = |status| {
+///         assert!(status.success());
+///     },
+///     on_stdout = |stdout| {
+///         assert_eq!(stdout, "hello, world\n");
+///     }
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_command {
+    (@callback) => {
+        ::std::option::Option::None
+    };
+
+    (@callback $callback:expr) => {
+        ::std::option::Option::Some(::std::boxed::Box::new($callback))
+    };
+
+    (
+        $command:expr
+        $(, on_status = $on_status:expr)?
+        $(, on_stdout = $on_stdout:expr)?
+        $(, on_stderr = $on_stderr:expr)?
+        $(,)?
+    ) => {
+        $crate::assertions::process::assert_command_impl(
+            $command,
+            ::std::panic::Location::caller(),
+            $crate::assert_command!(@callback $($on_status)?),
+            $crate::assert_command!(@callback $($on_stdout)?),
+            $crate::assert_command!(@callback $($on_stderr)?),
+        )
+    };
+}
+
+/// Asserts that a command's exit code, `stdout`, and `stderr` match a snapshot.
+///
+/// The command is run to completion and its output is recorded into a single snapshot, which makes
+/// for end-to-end golden tests of command line programs. See
+/// [`assert_snapshot`](crate::assert_snapshot) for how snapshots are stored and accepted.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/process](https://sophie-katz.github.io/test-ur-code-XD/assertions/process/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `name` - The name of the snapshot. It must be unique within the crate.
+/// * `command` - The [`Command`](std::process::Command) to run. Either an owned command or a
+///               mutable reference to one can be passed.
+/// * Optional: `redact = <value>` - Pairs of patterns and placeholders with which to replace them
+///                                  before comparing, like `[(temp_path, "[TEMP]")]`.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `redact` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::process::Command;
+/// # use test_ur_code_xd::assert_command_snapshot;
+/// #
+/// assert_command_snapshot!("echo_hello", Command::new("echo").arg("hello"));
+/// ```
+#[cfg(feature = "snapshot")]
+#[macro_export]
+macro_rules! assert_command_snapshot {
+    (
+        $name:expr,
+        $command:expr,
+        redact = $redactions:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_snapshot!(
+            $name,
+            $crate::assertions::process::run_command_for_snapshot(
+                $command,
+                ::std::panic::Location::caller(),
+            ),
+            redact = $redactions
+            $(, $keys = $values)*
+        )
+    };
+
+    ($name:expr, $command:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_command_snapshot!(
+            $name,
+            $command,
+            redact = ::std::iter::empty::<(&str, &str)>()
+            $(, $keys = $values)*
+        )
+    };
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{env, os::unix::process::ExitStatusExt};
+
+    #[cfg(feature = "regex")]
+    use crate::{
+        assert_str_matches,
+        utilities::color_choice::{set_color_choice, ColorChoice},
+    };
+
+    /// Creates a command that runs a shell script.
+    ///
+    /// Other tests may change the working directory to one that gets deleted, which makes the shell
+    /// print warnings to `stderr`, so the script is always run from the temporary directory.
+    fn new_shell_command(script: &str) -> Command {
+        let mut command = Command::new("sh");
+
+        command.args(["-c", script]).current_dir(env::temp_dir());
+
+        command
+    }
+
+    #[test]
+    fn format_command_line_quotes_arguments() {
+        let mut command = Command::new("echo");
+
+        command.arg("hello, world");
+
+        assert_eq!(format_command_line(&command), r#""echo" "hello, world""#);
+    }
+
+    #[test]
+    fn assert_command_passing_no_callbacks() {
+        assert_command!(Command::new("true"));
+    }
+
+    #[test]
+    fn assert_command_passing_owned_command() {
+        let mut command = Command::new("echo");
+
+        command.arg("hello, world");
+
+        assert_command!(
+            command,
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "hello, world\n");
+            }
+        );
+    }
+
+    #[test]
+    fn assert_command_passing_all_callbacks() {
+        assert_command!(
+            new_shell_command("echo out; echo err >&2; exit 3"),
+            on_status = |status| {
+                assert_eq!(status.code(), Some(3));
+            },
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "out\n");
+            },
+            on_stderr = |stderr| {
+                assert_eq!(stderr, "err\n");
+            },
+        );
+    }
+
+    #[test]
+    fn assert_command_passing_stderr_only() {
+        assert_command!(
+            new_shell_command("echo err >&2"),
+            on_stderr = |stderr| {
+                assert_eq!(stderr, "err\n");
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value is true")]
+    fn assert_command_failing_status() {
+        assert_command!(
+            Command::new("false"),
+            on_status = |status| {
+                assert!(status.success());
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_command_failing_stdout() {
+        assert_command!(
+            Command::new("echo").arg("hello"),
+            on_stdout = |stdout| {
+                assert_eq!(stdout, "goodbye\n");
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unable to run command")]
+    fn assert_command_failing_missing_program() {
+        assert_command!(Command::new("test-ur-code-xd-program-that-does-not-exist"));
+    }
+
+    #[test]
+    fn format_command_snapshot_all_output() {
+        let output = Output {
+            status: ExitStatus::from_raw(3 << 8),
+            stdout: b"out\n".to_vec(),
+            stderr: b"err\n".to_vec(),
+        };
+
+        assert_eq!(
+            format_command_snapshot(&output),
+            "exit code: 3\n----- stdout -----\nout\n----- stderr -----\nerr\n"
+        );
+    }
+
+    #[test]
+    fn format_command_snapshot_signal() {
+        let output = Output {
+            status: ExitStatus::from_raw(9),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        };
+
+        assert_eq!(
+            format_command_snapshot(&output),
+            "exit code: none (signal: 9 (SIGKILL))\n----- stdout -----\n----- stderr -----\n"
+        );
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    fn assert_command_snapshot_passing() {
+        let directory = env::temp_dir();
+        let directory = directory.to_string_lossy();
+
+        assert_command_snapshot!(
+            "assert_command_snapshot_passing",
+            new_shell_command(&format!("echo out {directory}; echo err >&2; exit 3")),
+            redact = [(directory.as_ref(), "[TEMP]")]
+        );
+    }
+
+    #[cfg(feature = "snapshot")]
+    #[test]
+    #[should_panic(expected = "unable to run command")]
+    fn assert_command_snapshot_failing_missing_program() {
+        assert_command_snapshot!(
+            "assert_command_snapshot_failing_missing_program",
+            Command::new("test-ur-code-xd-program-that-does-not-exist")
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn output_failure_message_includes_command_and_output() {
+        set_color_choice(ColorChoice::Never);
+
+        let output = Output {
+            status: ExitStatus::from_raw(0),
+            stdout: b"out\n".to_vec(),
+            stderr: b"err\n".to_vec(),
+        };
+
+        let message = new_output_failure_panic_message_builder(
+            r#""sh" "-c" "echo out; echo err >&2""#,
+            &output,
+            "lhs == rhs",
+            Location::caller(),
+        )
+        .unwrap()
+        .format();
+
+        assert_str_matches!(
+            message,
+            r#"(?s)lhs == rhs
+(?:.*
+)?  command: --
+           == "sh" "-c" "echo out; echo err >&2"
+  status: --
+          == exit status: 0
+  stdout: --
+          == out
+
+  stderr: --
+          == err
+"#
+        );
+    }
+}
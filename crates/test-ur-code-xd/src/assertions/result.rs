@@ -0,0 +1,215 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on [`Result`] values.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/result](https://sophie-katz.github.io/test-ur-code-XD/assertions/result/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// Splits results into the values inside of `Ok` and the errors inside of `Err`, along with the
+/// index at which each error occurred.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn partition_results<ValueType, ErrorType>(
+    results: impl IntoIterator<Item = Result<ValueType, ErrorType>>,
+) -> (Vec<ValueType>, Vec<(usize, ErrorType)>) {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push((index, error)),
+        }
+    }
+
+    (values, errors)
+}
+
+/// Configures a panic message builder for [`assert_all_ok`](crate::assert_all_ok).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `results_description` - The description of the results expression
+/// * `ok_count` - The number of results that were `Ok`
+/// * `errors` - The index and error of each result that was `Err`
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_all_ok_panic_message<ErrorType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    results_description: &str,
+    ok_count: usize,
+    errors: &[(usize, ErrorType)],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder.with_argument_formatted(
+        "results",
+        results_description,
+        format!("{ok_count} ok, {} err", errors.len()),
+    )?;
+
+    if errors.is_empty() {
+        return Ok(panic_message_builder);
+    }
+
+    panic_message_builder.with_argument_formatted(
+        "errors",
+        "--",
+        errors
+            .iter()
+            .map(|(index, error)| format!("[{index}] {error:?}"))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+/// Asserts that every result in an iterable is `Ok`.
+///
+/// Unlike calling `unwrap` on each result, every error is listed in the panic message along with
+/// its index, not just the first one.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/result](https://sophie-katz.github.io/test-ur-code-XD/assertions/result/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `results` - Anything that can be iterated over to get [`Result`] values. Errors must
+///               implement [`Debug`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Returns
+///
+/// A [`Vec`] of the values inside of each `Ok`, in order, for further assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_all_ok, assert_eq};
+/// #
+/// let values = assert_all_ok!(["1", "2", "3"].iter().map(|text| text.parse::<i32>()));
+///
+/// assert_eq!(values, vec![1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! assert_all_ok {
+    ($results:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let (ok_values, errors) = $crate::assertions::result::partition_results($results);
+
+        $crate::assert_custom!(
+            "all results are ok",
+            errors.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::result::configure_all_ok_panic_message(
+                    panic_message_builder,
+                    stringify!($results),
+                    ok_values.len(),
+                    &errors,
+                )
+            }
+            $(, $keys = $values)*
+        );
+
+        ok_values
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::panic::Location;
+
+    use crate::utilities::{
+        color_choice::{set_color_choice, ColorChoice},
+        panic_message_builder::MessageType,
+    };
+
+    #[test]
+    fn partition_results_empty() {
+        let (values, errors) = partition_results(Vec::<Result<i32, String>>::new());
+
+        assert!(values.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn partition_results_mixed() {
+        let (values, errors) = partition_results(vec![Ok(1), Err("a"), Ok(2), Err("b")]);
+
+        assert_eq!(values, vec![1, 2]);
+        assert_eq!(errors, vec![(1, "a"), (3, "b")]);
+    }
+
+    #[test]
+    fn configure_all_ok_panic_message_lists_errors() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = configure_all_ok_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "all results are ok",
+                Location::caller(),
+            ),
+            "results",
+            1,
+            &[(1, "a"), (3, "b")],
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("1 ok, 2 err"));
+        assert!(message.contains("[1] \"a\""));
+        assert!(message.contains("[3] \"b\""));
+    }
+
+    #[test]
+    fn assert_all_ok_passing() {
+        let values = assert_all_ok!(vec![Ok::<i32, String>(1), Ok(2)]);
+
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn assert_all_ok_passing_empty() {
+        let values = assert_all_ok!(Vec::<Result<i32, String>>::new());
+
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn assert_all_ok_passing_negate() {
+        let values = assert_all_ok!(vec![Ok(1), Err("a")], negate = true);
+
+        assert_eq!(values, vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "all results are ok")]
+    fn assert_all_ok_failing() {
+        assert_all_ok!(["1", "x", "3", "y"].iter().map(|text| text.parse::<i32>()));
+    }
+}
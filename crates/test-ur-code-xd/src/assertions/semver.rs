@@ -0,0 +1,378 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on semantic versions from the
+//! [`semver`](https://crates.io/crates/semver) crate.
+//!
+//! Versions and requirements can be given as strings or as already-parsed values. Failure messages
+//! show the parsed components of each version.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/semver](https://sophie-katz.github.io/test-ur-code-XD/assertions/semver/)
+//! for a usage guide.
+
+use std::fmt::Display;
+
+use ::semver::{Version, VersionReq};
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+/// Formats a version along with its parsed components for failure messages.
+///
+/// # Arguments
+///
+/// * `version` - The version to format.
+///
+/// # Returns
+///
+/// The version followed by its components, like
+/// `1.2.3-alpha.1 (major: 1, minor: 2, patch: 3, pre-release: alpha.1)`. The pre-release and build
+/// metadata are only included if they are present.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, assertions::semver::format_version_components};
+/// use semver::Version;
+///
+/// assert_eq!(
+///     format_version_components(&Version::new(1, 2, 3)),
+///     "1.2.3 (major: 1, minor: 2, patch: 3)"
+/// );
+/// ```
+#[must_use]
+pub fn format_version_components(version: &Version) -> String {
+    let mut components = vec![
+        format!("major: {}", version.major),
+        format!("minor: {}", version.minor),
+        format!("patch: {}", version.patch),
+    ];
+
+    if !version.pre.is_empty() {
+        components.push(format!("pre-release: {}", version.pre));
+    }
+
+    if !version.build.is_empty() {
+        components.push(format!("build: {}", version.build));
+    }
+
+    format!("{version} ({})", components.join(", "))
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn parse_version(value: &impl Display) -> Version {
+    let text = value.to_string();
+
+    PanicMessageBuilder::unwrap_error_with(
+        Version::parse(&text),
+        MessageType::ErrorWhileCheckingAssertion,
+        "invalid semantic version",
+        |panic_message_builder| panic_message_builder.with_argument("version", "--", &text),
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+#[track_caller]
+pub fn parse_version_requirement(value: &impl Display) -> VersionReq {
+    let text = value.to_string();
+
+    PanicMessageBuilder::unwrap_error_with(
+        VersionReq::parse(&text),
+        MessageType::ErrorWhileCheckingAssertion,
+        "invalid semantic version requirement",
+        |panic_message_builder| panic_message_builder.with_argument("requirement", "--", &text),
+    )
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_semver_matches_impl(version: &Version, requirement: &VersionReq) -> bool {
+    requirement.matches(version)
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_semver_lt_impl(lhs: &Version, rhs: &Version) -> bool {
+    lhs < rhs
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_version_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    argument_description: &str,
+    value_description: &str,
+    version: &Version,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    panic_message_builder.with_argument_formatted(
+        argument_description,
+        value_description,
+        format_version_components(version),
+    )
+}
+
+/// Asserts that a semantic version matches a version requirement, like `"^1.2"`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/semver](https://sophie-katz.github.io/test-ur-code-XD/assertions/semver/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `version` - The version, either as a [`semver::Version`] or anything that formats as one, like
+///               `"1.2.3"`.
+/// * `requirement` - The requirement, either as a [`semver::VersionReq`] or anything that formats
+///                   as one, like `">=1.2, <2"`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Panics
+///
+/// * If the version or the requirement can't be parsed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_semver_matches;
+/// #
+/// assert_semver_matches!("1.4.0", "^1.2");
+/// assert_semver_matches!(env!("CARGO_PKG_VERSION"), ">=0.1, <1");
+/// ```
+#[macro_export]
+macro_rules! assert_semver_matches {
+    ($version:expr, $requirement:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let version = $crate::assertions::semver::parse_version(&$version);
+        let requirement = $crate::assertions::semver::parse_version_requirement(&$requirement);
+
+        $crate::assert_custom!(
+            "version matches requirement",
+            $crate::assertions::semver::assert_semver_matches_impl(&version, &requirement),
+            |panic_message_builder| {
+                $crate::assertions::semver::configure_version_panic_message(
+                    panic_message_builder,
+                    "version",
+                    stringify!($version),
+                    &version,
+                )?
+                .with_argument_formatted(
+                    "requirement",
+                    stringify!($requirement),
+                    requirement.to_string(),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that one semantic version is lower than another.
+///
+/// Versions are compared by their precedence, so pre-releases like `1.0.0-alpha` are lower than
+/// the release `1.0.0`.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/semver](https://sophie-katz.github.io/test-ur-code-XD/assertions/semver/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The version that should be lower, either as a [`semver::Version`] or anything that
+///           formats as one, like `"1.2.3"`.
+/// * `rhs` - The version that should be higher, in the same forms as `lhs`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Panics
+///
+/// * If either version can't be parsed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_semver_lt;
+/// #
+/// assert_semver_lt!("1.9.0", "1.10.0");
+/// assert_semver_lt!("2.0.0-rc.1", "2.0.0");
+/// ```
+#[macro_export]
+macro_rules! assert_semver_lt {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let lhs = $crate::assertions::semver::parse_version(&$lhs);
+        let rhs = $crate::assertions::semver::parse_version(&$rhs);
+
+        $crate::assert_custom!(
+            "lhs < rhs",
+            $crate::assertions::semver::assert_semver_lt_impl(&lhs, &rhs),
+            |panic_message_builder| {
+                $crate::assertions::semver::configure_version_panic_message(
+                    $crate::assertions::semver::configure_version_panic_message(
+                        panic_message_builder,
+                        "lhs",
+                        stringify!($lhs),
+                        &lhs,
+                    )?,
+                    "rhs",
+                    stringify!($rhs),
+                    &rhs,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, utilities::assertion_failure::capture_assertion_failure};
+
+    #[test]
+    fn format_version_components_release() {
+        assert_eq!(
+            format_version_components(&Version::new(1, 2, 3)),
+            "1.2.3 (major: 1, minor: 2, patch: 3)"
+        );
+    }
+
+    #[test]
+    fn format_version_components_pre_release_and_build() {
+        assert_eq!(
+            format_version_components(&Version::parse("1.2.3-alpha.1+build.5").unwrap()),
+            "1.2.3-alpha.1+build.5 (major: 1, minor: 2, patch: 3, pre-release: alpha.1, build: \
+             build.5)"
+        );
+    }
+
+    #[test]
+    fn assert_semver_matches_passing() {
+        assert_semver_matches!("1.4.0", "^1.2");
+    }
+
+    #[test]
+    fn assert_semver_matches_passing_parsed_values() {
+        assert_semver_matches!(
+            Version::new(1, 4, 0),
+            VersionReq::parse(">=1.2, <2").unwrap()
+        );
+    }
+
+    #[test]
+    fn assert_semver_matches_passing_owned_strings() {
+        let version = String::from("0.3.1");
+
+        assert_semver_matches!(version, format!("~{}", "0.3"));
+    }
+
+    #[test]
+    #[should_panic(expected = "version matches requirement")]
+    fn assert_semver_matches_failing() {
+        assert_semver_matches!("2.0.0", "^1.2");
+    }
+
+    #[test]
+    fn assert_semver_matches_passing_negate() {
+        assert_semver_matches!("2.0.0", "^1.2", negate = true);
+    }
+
+    #[test]
+    fn assert_semver_matches_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_semver_matches!("2.0.0-beta", "^1.2");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "version matches requirement");
+        assert_eq!(
+            failure.argument("version").unwrap().value,
+            "2.0.0-beta (major: 2, minor: 0, patch: 0, pre-release: beta)"
+        );
+        assert_eq!(failure.argument("requirement").unwrap().value, "^1.2");
+    }
+
+    #[test]
+    fn assert_semver_matches_invalid_version() {
+        let failure = capture_assertion_failure(|| {
+            assert_semver_matches!("1.2", "^1.2");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "invalid semantic version");
+        assert_eq!(failure.argument("version").unwrap().value, "\"1.2\"");
+    }
+
+    #[test]
+    fn assert_semver_matches_invalid_requirement() {
+        let failure = capture_assertion_failure(|| {
+            assert_semver_matches!("1.2.0", "not a requirement");
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "invalid semantic version requirement");
+    }
+
+    #[test]
+    fn assert_semver_lt_passing() {
+        assert_semver_lt!("1.9.0", "1.10.0");
+    }
+
+    #[test]
+    fn assert_semver_lt_passing_pre_release() {
+        assert_semver_lt!("2.0.0-rc.1", "2.0.0");
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs < rhs")]
+    fn assert_semver_lt_failing_equal() {
+        assert_semver_lt!("1.0.0", Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn assert_semver_lt_passing_negate() {
+        assert_semver_lt!("1.10.0", "1.9.0", negate = true);
+    }
+
+    #[test]
+    fn assert_semver_lt_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_semver_lt!("1.10.0", "1.9.0");
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.argument("lhs").unwrap().value,
+            "1.10.0 (major: 1, minor: 10, patch: 0)"
+        );
+        assert_eq!(
+            failure.argument("rhs").unwrap().value,
+            "1.9.0 (major: 1, minor: 9, patch: 0)"
+        );
+        assert!(failure.argument("rhs").unwrap().value_description == "\"1.9.0\"");
+    }
+}
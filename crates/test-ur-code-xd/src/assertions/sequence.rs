@@ -0,0 +1,709 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that operate on sequences like iterators, vectors, and the lines of a log.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/sequence](https://sophie-katz.github.io/test-ur-code-XD/assertions/sequence/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// How far matching progressed for [`assert_contains_in_order`](crate::assert_contains_in_order).
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct InOrderMatch<ItemType> {
+    /// The number of items in the haystack.
+    pub haystack_len: usize,
+
+    /// The items that were expected to appear in order.
+    pub expected: Vec<ItemType>,
+
+    /// The number of expected items that were found in order.
+    pub matched_count: usize,
+
+    /// The index in the haystack of the last expected item that was found, if any were.
+    pub last_match_index: Option<usize>,
+}
+
+impl<ItemType> InOrderMatch<ItemType> {
+    /// Checks whether or not every expected item was found in order.
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.matched_count == self.expected.len()
+    }
+}
+
+/// Finds how many of the expected items appear in the haystack in order, not necessarily adjacent
+/// to each other.
+///
+/// Each expected item is matched against the earliest item in the haystack after the previous
+/// match, which finds a match whenever one exists.
+///
+/// # Arguments
+///
+/// * `haystack` - The sequence to search.
+/// * `expected` - The items expected to appear in `haystack`, in order.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn match_in_order<HaystackItemType: PartialEq<ItemType>, ItemType>(
+    haystack: impl IntoIterator<Item = HaystackItemType>,
+    expected: impl IntoIterator<Item = ItemType>,
+) -> InOrderMatch<ItemType> {
+    let expected: Vec<ItemType> = expected.into_iter().collect();
+
+    let mut haystack_len: usize = 0;
+    let mut matched_count: usize = 0;
+    let mut last_match_index = None;
+
+    for (index, item) in haystack.into_iter().enumerate() {
+        haystack_len = index.saturating_add(1);
+
+        if expected
+            .get(matched_count)
+            .map_or(false, |expected_item| item == *expected_item)
+        {
+            matched_count = matched_count.saturating_add(1);
+            last_match_index = Some(index);
+        }
+    }
+
+    InOrderMatch {
+        haystack_len,
+        expected,
+        matched_count,
+        last_match_index,
+    }
+}
+
+/// Configures a panic message builder for
+/// [`assert_contains_in_order`](crate::assert_contains_in_order).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `haystack_description` - The description of the haystack expression
+/// * `expected_description` - The description of the expected items expression
+/// * `in_order_match` - How far matching progressed
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_contains_in_order_panic_message<ItemType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    haystack_description: &str,
+    expected_description: &str,
+    in_order_match: &InOrderMatch<ItemType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument_formatted(
+            "haystack",
+            haystack_description,
+            format!("{} items", in_order_match.haystack_len),
+        )?
+        .with_argument("expected", expected_description, &in_order_match.expected)?
+        .with_argument_formatted(
+            "matched",
+            "--",
+            format!(
+                "{} of {}",
+                in_order_match.matched_count,
+                in_order_match.expected.len()
+            ),
+        )?;
+
+    let panic_message_builder = match in_order_match.last_match_index {
+        Some(last_match_index) => {
+            panic_message_builder.with_argument("last match at index", "--", &last_match_index)?
+        }
+        None => panic_message_builder,
+    };
+
+    match in_order_match.expected.get(in_order_match.matched_count) {
+        Some(next_item) => panic_message_builder.with_argument("not found", "--", next_item),
+        None => Ok(panic_message_builder),
+    }
+}
+
+/// Asserts that items appear in a sequence in order, but not necessarily adjacent to each other.
+///
+/// If they do not, the panic message includes how many of the items were found and the first one
+/// that was not.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/sequence](https://sophie-katz.github.io/test-ur-code-XD/assertions/sequence/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `haystack` - The sequence to search. Anything that can be iterated over works, like an
+///                iterator, a vector, or a reference to a vector. Its items must be comparable to
+///                the expected items with `==`.
+/// * `expected` - The items expected to appear in `haystack`, in order. Anything that can be
+///                iterated over works, but this is usually an array. Its items must implement
+///                [`Debug`].
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_contains_in_order;
+/// #
+/// let log = "starting\nloading config\nlistening on port 80\nshutting down";
+///
+/// assert_contains_in_order!(log.lines(), ["starting", "listening on port 80", "shutting down"]);
+///
+/// let events = vec![1, 2, 3, 4, 5];
+///
+/// assert_contains_in_order!(events, [1, 3, 5]);
+/// ```
+#[macro_export]
+macro_rules! assert_contains_in_order {
+    ($haystack:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let in_order_match = $crate::assertions::sequence::match_in_order($haystack, $expected);
+
+        $crate::assert_custom!(
+            "haystack contains expected items in order",
+            in_order_match.is_match(),
+            |panic_message_builder| {
+                $crate::assertions::sequence::configure_contains_in_order_panic_message(
+                    panic_message_builder,
+                    stringify!($haystack),
+                    stringify!($expected),
+                    &in_order_match,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// The first adjacent pair of items that are out of order, for
+/// [`assert_sorted`](crate::assert_sorted) and [`assert_sorted_by_key`](crate::assert_sorted_by_key).
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct UnsortedPair<ItemType> {
+    /// The index of the first item in the pair.
+    pub index: usize,
+
+    /// The first item in the pair.
+    pub item: ItemType,
+
+    /// The item right after it, which should not come after it.
+    pub next_item: ItemType,
+}
+
+/// Checks whether or not two adjacent values are in order.
+///
+/// Equal values are considered in order. Values that cannot be compared, like `f64::NAN`, are not.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn is_in_order<ValueType: PartialOrd + ?Sized>(
+    value: &ValueType,
+    next_value: &ValueType,
+    descending: bool,
+) -> bool {
+    if descending {
+        value >= next_value
+    } else {
+        value <= next_value
+    }
+}
+
+/// Finds the first adjacent pair of items that are out of order.
+///
+/// # Arguments
+///
+/// * `items` - The items to check.
+/// * `is_in_order` - Returns `true` if an item and the item right after it are in order.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_unsorted_pair<ItemType>(
+    items: impl IntoIterator<Item = ItemType>,
+    mut is_in_order: impl FnMut(&ItemType, &ItemType) -> bool,
+) -> Option<UnsortedPair<ItemType>> {
+    let mut items = items.into_iter();
+
+    let mut item = items.next()?;
+
+    for (index, next_item) in items.enumerate() {
+        if !is_in_order(&item, &next_item) {
+            return Some(UnsortedPair {
+                index,
+                item,
+                next_item,
+            });
+        }
+
+        item = next_item;
+    }
+
+    None
+}
+
+/// Finds the first adjacent pair of items whose keys are out of order.
+///
+/// # Arguments
+///
+/// * `items` - The items to check.
+/// * `key` - Returns the key of an item.
+/// * `descending` - Whether or not the keys are expected in descending order.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_unsorted_pair_by_key<ItemType, KeyType: PartialOrd>(
+    items: impl IntoIterator<Item = ItemType>,
+    mut key: impl FnMut(&ItemType) -> KeyType,
+    descending: bool,
+) -> Option<UnsortedPair<ItemType>> {
+    find_unsorted_pair(items, |item, next_item| {
+        is_in_order(&key(item), &key(next_item), descending)
+    })
+}
+
+/// Configures a panic message builder for [`assert_sorted`](crate::assert_sorted) and
+/// [`assert_sorted_by_key`](crate::assert_sorted_by_key).
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `items_description` - The description of the items expression
+/// * `descending` - Whether or not the items were expected in descending order
+/// * `unsorted_pair` - The first adjacent pair of items that are out of order, if any
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_sorted_panic_message<ItemType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    items_description: &str,
+    descending: bool,
+    unsorted_pair: &Option<UnsortedPair<ItemType>>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder.with_argument_formatted(
+        "order",
+        "--",
+        if descending {
+            "descending"
+        } else {
+            "ascending"
+        },
+    )?;
+
+    let Some(unsorted_pair) = unsorted_pair else {
+        return panic_message_builder.with_argument_formatted("items", items_description, "sorted");
+    };
+
+    panic_message_builder
+        .with_argument_formatted(
+            "items",
+            items_description,
+            format!("out of order at index {}", unsorted_pair.index),
+        )?
+        .with_argument(
+            format!("item {}", unsorted_pair.index),
+            "--",
+            &unsorted_pair.item,
+        )?
+        .with_argument(
+            format!("item {}", unsorted_pair.index.saturating_add(1)),
+            "--",
+            &unsorted_pair.next_item,
+        )
+}
+
+/// Asserts that items are sorted.
+///
+/// Equal items next to each other are considered sorted. If the items are not sorted, the panic
+/// message includes the first adjacent pair that is out of order and its index.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/sequence](https://sophie-katz.github.io/test-ur-code-XD/assertions/sequence/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `items` - The items to check. Anything that can be iterated over works, like an iterator, a
+///             vector, or a reference to a vector. Its items must implement [`PartialOrd`] and
+///             [`Debug`].
+/// * `descending = <bool>` - Optional argument to check for descending order instead of ascending
+///                           order. It must come before any other keyword arguments.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_sorted;
+/// #
+/// assert_sorted!(vec![1, 2, 2, 3]);
+///
+/// assert_sorted!(["c", "b", "a"], descending = true);
+/// ```
+#[macro_export]
+macro_rules! assert_sorted {
+    ($items:expr, descending = $descending:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let descending: bool = $descending;
+
+        let unsorted_pair = $crate::assertions::sequence::find_unsorted_pair(
+            $items,
+            |item, next_item| $crate::assertions::sequence::is_in_order(item, next_item, descending),
+        );
+
+        $crate::assert_custom!(
+            "items are sorted",
+            unsorted_pair.is_none(),
+            |panic_message_builder| {
+                $crate::assertions::sequence::configure_sorted_panic_message(
+                    panic_message_builder,
+                    stringify!($items),
+                    descending,
+                    &unsorted_pair,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($items:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_sorted!($items, descending = false $(, $keys = $values)*)
+    };
+}
+
+/// Asserts that items are sorted by a key.
+///
+/// Items with equal keys next to each other are considered sorted. If the items are not sorted, the
+/// panic message includes the first adjacent pair that is out of order and its index.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/sequence](https://sophie-katz.github.io/test-ur-code-XD/assertions/sequence/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `items` - The items to check. Anything that can be iterated over works, like an iterator, a
+///             vector, or a reference to a vector. Its items must implement [`Debug`].
+/// * `key` - A closure that takes a reference to an item and returns its key, like for
+///           [`slice::sort_by_key`]. Keys must implement [`PartialOrd`].
+/// * `descending = <bool>` - Optional argument to check for descending order instead of ascending
+///                           order. It must come before any other keyword arguments.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_sorted_by_key;
+/// #
+/// assert_sorted_by_key!(["a", "bb", "ccc"], |name| name.len());
+///
+/// assert_sorted_by_key!(vec![(1, "c"), (2, "b")], |(_, name)| *name, descending = true);
+/// ```
+#[macro_export]
+macro_rules! assert_sorted_by_key {
+    ($items:expr, $key:expr, descending = $descending:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let descending: bool = $descending;
+
+        let unsorted_pair = $crate::assertions::sequence::find_unsorted_pair_by_key(
+            $items,
+            $key,
+            descending,
+        );
+
+        $crate::assert_custom!(
+            "items are sorted by key",
+            unsorted_pair.is_none(),
+            |panic_message_builder| {
+                $crate::assertions::sequence::configure_sorted_panic_message(
+                    panic_message_builder,
+                    stringify!($items),
+                    descending,
+                    &unsorted_pair,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($items:expr, $key:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_sorted_by_key!($items, $key, descending = false $(, $keys = $values)*)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::panic::Location;
+
+    use crate::utilities::{
+        color_choice::{set_color_choice, ColorChoice},
+        panic_message_builder::MessageType,
+    };
+
+    #[test]
+    fn match_in_order_empty_expected() {
+        let in_order_match = match_in_order([1, 2, 3], Vec::<i32>::new());
+
+        assert!(in_order_match.is_match());
+        assert_eq!(in_order_match.haystack_len, 3);
+        assert!(in_order_match.last_match_index.is_none());
+    }
+
+    #[test]
+    fn match_in_order_not_adjacent() {
+        let in_order_match = match_in_order([1, 2, 3, 4, 5], [2, 4]);
+
+        assert!(in_order_match.is_match());
+        assert_eq!(in_order_match.matched_count, 2);
+        assert_eq!(in_order_match.last_match_index, Some(3));
+    }
+
+    #[test]
+    fn match_in_order_wrong_order() {
+        let in_order_match = match_in_order([1, 2, 3, 4, 5], [4, 2]);
+
+        assert!(!in_order_match.is_match());
+        assert_eq!(in_order_match.matched_count, 1);
+        assert_eq!(in_order_match.last_match_index, Some(3));
+    }
+
+    #[test]
+    fn match_in_order_repeated_items() {
+        assert!(match_in_order([1, 2, 1], [1, 1]).is_match());
+        assert!(!match_in_order([1, 2], [1, 1]).is_match());
+    }
+
+    #[test]
+    fn configure_contains_in_order_panic_message_reports_progress() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = configure_contains_in_order_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "haystack contains expected items in order",
+                Location::caller(),
+            ),
+            "log.lines()",
+            "[\"a\", \"b\", \"c\"]",
+            &match_in_order(["a", "x", "b", "y"], ["a", "b", "c"]),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("4 items"));
+        assert!(message.contains("2 of 3"));
+        assert!(message.contains("last match at index: --\n"));
+        assert!(message.contains("== 2\n"));
+        assert!(message.contains("not found: --\n"));
+        assert!(message.contains("== \"c\""));
+    }
+
+    #[test]
+    fn configure_contains_in_order_panic_message_no_matches() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = configure_contains_in_order_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "haystack contains expected items in order",
+                Location::caller(),
+            ),
+            "haystack",
+            "expected",
+            &match_in_order(["x", "y"], ["a"]),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("0 of 1"));
+        assert!(!message.contains("last match at index"));
+    }
+
+    #[test]
+    fn assert_contains_in_order_passing() {
+        assert_contains_in_order!(vec![1, 2, 3, 4, 5], [1, 3, 5]);
+    }
+
+    #[test]
+    fn assert_contains_in_order_passing_lines() {
+        let log = "starting\nloading config\nlistening\nshutting down";
+
+        assert_contains_in_order!(log.lines(), ["starting", "shutting down"]);
+    }
+
+    #[test]
+    fn assert_contains_in_order_passing_strings() {
+        let log = vec![String::from("a"), String::from("b")];
+
+        assert_contains_in_order!(&log, ["a", "b"]);
+    }
+
+    #[test]
+    fn assert_contains_in_order_passing_negate() {
+        assert_contains_in_order!(vec![1, 2, 3], [3, 1], negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "haystack contains expected items in order")]
+    fn assert_contains_in_order_failing() {
+        assert_contains_in_order!(vec![1, 2, 3], [3, 1]);
+    }
+
+    #[test]
+    fn is_in_order_ascending() {
+        assert!(is_in_order(&1, &2, false));
+        assert!(is_in_order(&1, &1, false));
+        assert!(!is_in_order(&2, &1, false));
+    }
+
+    #[test]
+    fn is_in_order_descending() {
+        assert!(is_in_order(&2, &1, true));
+        assert!(is_in_order(&1, &1, true));
+        assert!(!is_in_order(&1, &2, true));
+    }
+
+    #[test]
+    fn is_in_order_nan() {
+        assert!(!is_in_order(&f64::NAN, &1.0, false));
+        assert!(!is_in_order(&1.0, &f64::NAN, true));
+    }
+
+    #[test]
+    fn find_unsorted_pair_empty() {
+        assert!(find_unsorted_pair(Vec::<i32>::new(), |_, _| false).is_none());
+    }
+
+    #[test]
+    fn find_unsorted_pair_single() {
+        assert!(find_unsorted_pair([1], |_, _| false).is_none());
+    }
+
+    #[test]
+    fn find_unsorted_pair_first_violation() {
+        let unsorted_pair =
+            find_unsorted_pair([1, 2, 5, 3, 0], |item, next_item| item <= next_item).unwrap();
+
+        assert_eq!(unsorted_pair.index, 2);
+        assert_eq!(unsorted_pair.item, 5);
+        assert_eq!(unsorted_pair.next_item, 3);
+    }
+
+    #[test]
+    fn configure_sorted_panic_message_reports_pair() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = configure_sorted_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "items are sorted",
+                Location::caller(),
+            ),
+            "items",
+            false,
+            &find_unsorted_pair([1, 2, 5, 3], |item, next_item| item <= next_item),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("ascending"));
+        assert!(message.contains("out of order at index 2"));
+        assert!(message.contains("item 2: --\n"));
+        assert!(message.contains("== 5\n"));
+        assert!(message.contains("item 3: --\n"));
+        assert!(message.contains("== 3"));
+    }
+
+    #[test]
+    fn assert_sorted_passing() {
+        assert_sorted!(vec![1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn assert_sorted_passing_empty() {
+        assert_sorted!(Vec::<i32>::new());
+    }
+
+    #[test]
+    fn assert_sorted_passing_reference() {
+        let items = vec![String::from("a"), String::from("b")];
+
+        assert_sorted!(&items);
+    }
+
+    #[test]
+    fn assert_sorted_passing_descending() {
+        assert_sorted!([3, 2, 2, 1], descending = true);
+    }
+
+    #[test]
+    fn assert_sorted_passing_negate() {
+        assert_sorted!([1, 3, 2], negate = true);
+    }
+
+    #[test]
+    fn assert_sorted_passing_descending_negate() {
+        assert_sorted!([1, 2, 3], descending = true, negate = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "items are sorted")]
+    fn assert_sorted_failing() {
+        assert_sorted!([1, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "items are sorted")]
+    fn assert_sorted_failing_descending() {
+        assert_sorted!([3, 1, 2], descending = true);
+    }
+
+    #[test]
+    fn assert_sorted_by_key_passing() {
+        assert_sorted_by_key!(["a", "bb", "cc", "ddd"], |name| name.len());
+    }
+
+    #[test]
+    fn assert_sorted_by_key_passing_descending() {
+        assert_sorted_by_key!(
+            vec![(1, "c"), (2, "b"), (3, "a")],
+            |(_, name)| *name,
+            descending = true
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "items are sorted by key")]
+    fn assert_sorted_by_key_failing() {
+        assert_sorted_by_key!(["bb", "a"], |name| name.len());
+    }
+}
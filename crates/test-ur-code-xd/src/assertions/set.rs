@@ -0,0 +1,440 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that check the relationship between sets like [`HashSet`](std::collections::HashSet)
+//! and [`BTreeSet`](std::collections::BTreeSet), or any other iterables.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/set](https://sophie-katz.github.io/test-ur-code-XD/assertions/set/)
+//! for a usage guide.
+
+use std::fmt::Debug;
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
+
+/// The elements that violate a relation between two sets, along with the sizes of the sets.
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct ViolatingElements<'set, ElementType> {
+    /// The number of elements in the left-hand set.
+    pub lhs_len: usize,
+
+    /// The number of elements in the right-hand set.
+    pub rhs_len: usize,
+
+    /// The elements of the left-hand set that violate the relation, without duplicates.
+    pub elements: Vec<&'set ElementType>,
+}
+
+/// Finds the elements of the left-hand set for which a predicate returns `false`, given whether or
+/// not they are in the right-hand set.
+///
+/// Elements are only required to implement [`PartialEq`] so that this works for any kind of set, so
+/// the comparison takes quadratic time. This is fine for the size of sets usually found in tests.
+///
+/// # Arguments
+///
+/// * `lhs` - Anything that can be iterated over to get references to elements, like
+///           `&HashSet<T>`, `&BTreeSet<T>`, or `&Vec<T>`.
+/// * `rhs` - The set to check the elements of `lhs` against.
+/// * `predicate` - Takes whether or not an element of `lhs` is in `rhs` and returns `true` if it
+///                 satisfies the relation.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_violating_elements<'set, ElementType: PartialEq + 'set>(
+    lhs: impl IntoIterator<Item = &'set ElementType>,
+    rhs: impl IntoIterator<Item = &'set ElementType>,
+    predicate: impl Fn(bool) -> bool,
+) -> ViolatingElements<'set, ElementType> {
+    let lhs_elements: Vec<&ElementType> = lhs.into_iter().collect();
+    let rhs_elements: Vec<&ElementType> = rhs.into_iter().collect();
+
+    let mut elements: Vec<&ElementType> = Vec::new();
+
+    for lhs_element in &lhs_elements {
+        if !predicate(rhs_elements.contains(lhs_element)) && !elements.contains(lhs_element) {
+            elements.push(lhs_element);
+        }
+    }
+
+    ViolatingElements {
+        lhs_len: lhs_elements.len(),
+        rhs_len: rhs_elements.len(),
+        elements,
+    }
+}
+
+/// Configures a panic message builder for the set assertions.
+///
+/// The violating elements are sorted by their formatted text so that the message is the same
+/// between runs, even for sets with an unspecified iteration order.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `lhs_name` - The name of the left-hand set argument
+/// * `lhs_description` - The description of the left-hand set expression
+/// * `rhs_name` - The name of the right-hand set argument
+/// * `rhs_description` - The description of the right-hand set expression
+/// * `elements_name` - The name to give the list of violating elements
+/// * `violating_elements` - The elements that violate the relation
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_set_panic_message<ElementType: Debug>(
+    panic_message_builder: PanicMessageBuilder,
+    lhs_name: &str,
+    lhs_description: &str,
+    rhs_name: &str,
+    rhs_description: &str,
+    elements_name: &str,
+    violating_elements: &ViolatingElements<'_, ElementType>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument_formatted(
+            lhs_name,
+            lhs_description,
+            format!("{} elements", violating_elements.lhs_len),
+        )?
+        .with_argument_formatted(
+            rhs_name,
+            rhs_description,
+            format!("{} elements", violating_elements.rhs_len),
+        )?;
+
+    if violating_elements.elements.is_empty() {
+        return Ok(panic_message_builder);
+    }
+
+    let mut lines: Vec<String> = violating_elements
+        .elements
+        .iter()
+        .map(|element| format!("{element:?}"))
+        .collect();
+
+    lines.sort();
+
+    panic_message_builder.with_argument_formatted(elements_name, "--", lines.join("\n"))
+}
+
+/// Asserts that every element of a set is also in another set.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/set](https://sophie-katz.github.io/test-ur-code-XD/assertions/set/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `subset` - The set to check. Anything whose reference can be iterated over to get element
+///              references works, like [`HashSet`](std::collections::HashSet),
+///              [`BTreeSet`](std::collections::BTreeSet), and [`Vec`]. Elements must implement
+///              [`PartialEq`] and [`Debug`].
+/// * `superset` - The set that is expected to contain every element of `subset`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_subset_of;
+/// # use std::collections::HashSet;
+/// #
+/// let granted = HashSet::from(["read", "write"]);
+///
+/// assert_subset_of!(granted, HashSet::from(["read", "write", "delete"]));
+/// ```
+#[macro_export]
+macro_rules! assert_subset_of {
+    ($subset:expr, $superset:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let subset = &$subset;
+        let superset = &$superset;
+
+        let violating_elements = $crate::assertions::set::find_violating_elements(
+            subset,
+            superset,
+            |is_in_superset| is_in_superset,
+        );
+
+        $crate::assert_custom!(
+            "subset is subset of superset",
+            violating_elements.elements.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::set::configure_set_panic_message(
+                    panic_message_builder,
+                    "subset",
+                    stringify!($subset),
+                    "superset",
+                    stringify!($superset),
+                    "elements not in superset",
+                    &violating_elements,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that a set contains every element of another set.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/set](https://sophie-katz.github.io/test-ur-code-XD/assertions/set/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `superset` - The set to check. Anything whose reference can be iterated over to get element
+///                references works, like [`HashSet`](std::collections::HashSet),
+///                [`BTreeSet`](std::collections::BTreeSet), and [`Vec`]. Elements must implement
+///                [`PartialEq`] and [`Debug`].
+/// * `subset` - The set whose elements are all expected to be in `superset`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_superset_of;
+/// # use std::collections::BTreeSet;
+/// #
+/// let enabled_features = BTreeSet::from(["logging", "metrics", "tracing"]);
+///
+/// assert_superset_of!(enabled_features, ["logging", "metrics"]);
+/// ```
+#[macro_export]
+macro_rules! assert_superset_of {
+    ($superset:expr, $subset:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let superset = &$superset;
+        let subset = &$subset;
+
+        let violating_elements = $crate::assertions::set::find_violating_elements(
+            subset,
+            superset,
+            |is_in_superset| is_in_superset,
+        );
+
+        $crate::assert_custom!(
+            "superset is superset of subset",
+            violating_elements.elements.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::set::configure_set_panic_message(
+                    panic_message_builder,
+                    "subset",
+                    stringify!($subset),
+                    "superset",
+                    stringify!($superset),
+                    "elements missing from superset",
+                    &violating_elements,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that two sets have no elements in common.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/set](https://sophie-katz.github.io/test-ur-code-XD/assertions/set/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `lhs` - The set on the left-hand side. Anything whose reference can be iterated over to get
+///           element references works, like [`HashSet`](std::collections::HashSet),
+///           [`BTreeSet`](std::collections::BTreeSet), and [`Vec`]. Elements must implement
+///           [`PartialEq`] and [`Debug`].
+/// * `rhs` - The set on the right-hand side.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_disjoint;
+/// # use std::collections::HashSet;
+/// #
+/// let public_routes = HashSet::from(["/", "/login"]);
+/// let admin_routes = HashSet::from(["/admin", "/admin/users"]);
+///
+/// assert_disjoint!(public_routes, admin_routes);
+/// ```
+#[macro_export]
+macro_rules! assert_disjoint {
+    ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let lhs = &$lhs;
+        let rhs = &$rhs;
+
+        let violating_elements = $crate::assertions::set::find_violating_elements(
+            lhs,
+            rhs,
+            |is_in_rhs| !is_in_rhs,
+        );
+
+        $crate::assert_custom!(
+            "lhs and rhs are disjoint",
+            violating_elements.elements.is_empty(),
+            |panic_message_builder| {
+                $crate::assertions::set::configure_set_panic_message(
+                    panic_message_builder,
+                    "lhs",
+                    stringify!($lhs),
+                    "rhs",
+                    stringify!($rhs),
+                    "common elements",
+                    &violating_elements,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{
+        collections::{BTreeSet, HashSet},
+        panic::Location,
+    };
+
+    use crate::utilities::{
+        color_choice::{set_color_choice, ColorChoice},
+        panic_message_builder::MessageType,
+    };
+
+    #[test]
+    fn find_violating_elements_empty() {
+        let lhs = HashSet::<i32>::new();
+        let rhs = HashSet::from([1, 2]);
+
+        let violating_elements = find_violating_elements(&lhs, &rhs, |_| false);
+
+        assert_eq!(violating_elements.lhs_len, 0);
+        assert_eq!(violating_elements.rhs_len, 2);
+        assert!(violating_elements.elements.is_empty());
+    }
+
+    #[test]
+    fn find_violating_elements_not_in_rhs() {
+        let lhs = BTreeSet::from([1, 2, 3, 4]);
+        let rhs = BTreeSet::from([2, 4]);
+
+        let violating_elements = find_violating_elements(&lhs, &rhs, |is_in_rhs| is_in_rhs);
+
+        assert_eq!(violating_elements.elements, vec![&1, &3]);
+    }
+
+    #[test]
+    fn find_violating_elements_without_duplicates() {
+        let lhs = vec![1, 1, 2, 2];
+        let rhs = vec![2];
+
+        let violating_elements = find_violating_elements(&lhs, &rhs, |is_in_rhs| is_in_rhs);
+
+        assert_eq!(violating_elements.lhs_len, 4);
+        assert_eq!(violating_elements.elements, vec![&1]);
+    }
+
+    #[test]
+    fn configure_set_panic_message_lists_elements() {
+        set_color_choice(ColorChoice::Never);
+
+        let lhs = HashSet::from(["c", "a", "b"]);
+        let rhs = HashSet::from(["b"]);
+
+        let message = configure_set_panic_message(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "subset is subset of superset",
+                Location::caller(),
+            ),
+            "subset",
+            "lhs",
+            "superset",
+            "rhs",
+            "elements not in superset",
+            &find_violating_elements(&lhs, &rhs, |is_in_rhs| is_in_rhs),
+        )
+        .map(PanicMessageBuilder::format);
+
+        let message = message.unwrap_or_default();
+
+        assert!(message.contains("3 elements"));
+        assert!(message.contains("1 elements"));
+        assert!(message.contains("elements not in superset: --\n"));
+        assert!(message.contains("\"a\"\n"));
+        assert!(message.contains("\"c\""));
+    }
+
+    #[test]
+    fn assert_subset_of_passing() {
+        assert_subset_of!(HashSet::from([1, 2]), HashSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn assert_subset_of_passing_equal() {
+        assert_subset_of!(BTreeSet::from([1, 2]), BTreeSet::from([2, 1]));
+    }
+
+    #[test]
+    fn assert_subset_of_passing_vec() {
+        assert_subset_of!(vec![1, 1, 2], [1, 2, 3]);
+    }
+
+    #[test]
+    fn assert_subset_of_passing_negate() {
+        assert_subset_of!(
+            HashSet::from([1, 4]),
+            HashSet::from([1, 2, 3]),
+            negate = true
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "subset is subset of superset")]
+    fn assert_subset_of_failing() {
+        assert_subset_of!(HashSet::from([1, 4]), HashSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn assert_superset_of_passing() {
+        assert_superset_of!(HashSet::from([1, 2, 3]), HashSet::from([1, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "superset is superset of subset")]
+    fn assert_superset_of_failing() {
+        assert_superset_of!(HashSet::from([1, 2, 3]), HashSet::from([1, 4]));
+    }
+
+    #[test]
+    fn assert_disjoint_passing() {
+        assert_disjoint!(HashSet::from([1, 2]), HashSet::from([3, 4]));
+    }
+
+    #[test]
+    fn assert_disjoint_passing_empty() {
+        assert_disjoint!(HashSet::<i32>::new(), HashSet::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs and rhs are disjoint")]
+    fn assert_disjoint_failing() {
+        assert_disjoint!(BTreeSet::from([1, 2, 3]), BTreeSet::from([3, 4]));
+    }
+}
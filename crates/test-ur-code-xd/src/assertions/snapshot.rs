@@ -0,0 +1,842 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Assertions that compare text against snapshot files stored alongside the tests.
+//!
+//! Snapshots are stored in `tests/snapshots/<name>.snap` relative to the crate being tested. When a
+//! snapshot is missing or does not match, the actual text is written to
+//! `tests/snapshots/<name>.snap.new` so that it can be reviewed and accepted by renaming it, or with
+//! [`review_pending_snapshots`]. Snapshots can also be rewritten without review by setting the
+//! `UPDATE` environment variable to `1`.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/snapshot](https://sophie-katz.github.io/test-ur-code-XD/assertions/snapshot/)
+//! for a usage guide.
+
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    panic::Location,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    errors::TestUrCodeXDError,
+    utilities::{
+        panic_message_builder::{MessageType, PanicMessageBuilder},
+        update_mode::update_expected_file,
+    },
+};
+
+/// The directory in which snapshots are stored, relative to the crate being tested.
+pub const SNAPSHOT_DIRECTORY: &str = "tests/snapshots";
+
+/// The file extension for accepted snapshots.
+pub const SNAPSHOT_EXTENSION: &str = "snap";
+
+/// The file extension for pending snapshots that have not been accepted yet.
+pub const PENDING_SNAPSHOT_EXTENSION: &str = "snap.new";
+
+/// The result of comparing text against a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum SnapshotOutcome {
+    /// The text matches the snapshot.
+    Matches,
+
+    /// There is no snapshot yet.
+    Missing,
+
+    /// The text does not match the snapshot.
+    Mismatch {
+        /// The text stored in the snapshot.
+        expected: String,
+    },
+}
+
+impl SnapshotOutcome {
+    /// Checks whether or not the text matched the snapshot.
+    #[must_use]
+    pub const fn is_match(&self) -> bool {
+        matches!(self, Self::Matches)
+    }
+}
+
+/// Gets the path of the accepted snapshot with the given name.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+#[must_use]
+pub fn get_snapshot_path(directory: impl AsRef<Path>, name: &str) -> PathBuf {
+    directory
+        .as_ref()
+        .join(format!("{name}.{SNAPSHOT_EXTENSION}"))
+}
+
+/// Gets the path of the pending snapshot with the given name.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+#[must_use]
+pub fn get_pending_snapshot_path(directory: impl AsRef<Path>, name: &str) -> PathBuf {
+    directory
+        .as_ref()
+        .join(format!("{name}.{PENDING_SNAPSHOT_EXTENSION}"))
+}
+
+/// Replaces every occurrence of each pattern in some text with a placeholder.
+///
+/// This is used to remove values that change between test runs, like temporary paths, from
+/// snapshots.
+///
+/// # Arguments
+///
+/// * `text` - The text to redact.
+/// * `redactions` - Pairs of patterns and the placeholders with which to replace them. They are
+///                  applied in order.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::snapshot::redact;
+/// #
+/// assert_eq!(
+///     redact("wrote /tmp/.tmpA1b2C3/out.txt", [("/tmp/.tmpA1b2C3", "[TEMP]")]),
+///     "wrote [TEMP]/out.txt"
+/// );
+/// ```
+#[must_use]
+pub fn redact(
+    text: impl AsRef<str>,
+    redactions: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+) -> String {
+    redactions
+        .into_iter()
+        .filter(|(pattern, _)| !pattern.as_ref().is_empty())
+        .fold(text.as_ref().to_owned(), |text, (pattern, placeholder)| {
+            text.replace(pattern.as_ref(), placeholder.as_ref())
+        })
+}
+
+/// Compares text against a snapshot.
+///
+/// If the snapshot is missing or does not match, the text is written to a pending snapshot. If the
+/// snapshot matches, any stale pending snapshot is removed.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+/// * `actual` - The text to compare against the snapshot.
+///
+/// # Errors
+///
+/// * Returns any errors from reading the snapshot or writing the pending snapshot.
+pub fn check_snapshot(
+    directory: impl AsRef<Path>,
+    name: &str,
+    actual: &str,
+) -> io::Result<SnapshotOutcome> {
+    let pending_snapshot_path = get_pending_snapshot_path(&directory, name);
+
+    let outcome = match fs::read_to_string(get_snapshot_path(&directory, name)) {
+        Ok(expected) if expected == actual => SnapshotOutcome::Matches,
+        Ok(expected) => SnapshotOutcome::Mismatch { expected },
+        Err(error) if error.kind() == io::ErrorKind::NotFound => SnapshotOutcome::Missing,
+        Err(error) => return Err(error),
+    };
+
+    if outcome.is_match() {
+        match fs::remove_file(&pending_snapshot_path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+            _ => {}
+        }
+    } else {
+        fs::create_dir_all(directory.as_ref())?;
+        fs::write(&pending_snapshot_path, actual)?;
+    }
+
+    Ok(outcome)
+}
+
+/// A decision made about a pending snapshot while reviewing it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum ReviewDecision {
+    /// Replace the accepted snapshot with the pending one.
+    Accept,
+
+    /// Delete the pending snapshot.
+    Reject,
+
+    /// Leave the pending snapshot for later.
+    Skip,
+}
+
+impl ReviewDecision {
+    /// Parses a decision from a line of user input.
+    ///
+    /// The input is case-insensitive and can be the full word or its first letter, like `a` or
+    /// `accept`.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "a" | "accept" => Some(Self::Accept),
+            "r" | "reject" => Some(Self::Reject),
+            "s" | "skip" => Some(Self::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// The names of the pending snapshots for each decision made during a review.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct SnapshotReview {
+    /// The snapshots that were accepted.
+    pub accepted: Vec<String>,
+
+    /// The snapshots that were rejected.
+    pub rejected: Vec<String>,
+
+    /// The snapshots that were skipped, including any left over if the input ended early.
+    pub skipped: Vec<String>,
+}
+
+/// Finds the names of the pending snapshots in a directory, in sorted order.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+///
+/// # Errors
+///
+/// * Returns any errors from reading the directory. A directory that does not exist has no pending
+///   snapshots.
+pub fn find_pending_snapshots(directory: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let suffix = format!(".{PENDING_SNAPSHOT_EXTENSION}");
+
+    let mut names = Vec::new();
+
+    for entry in entries {
+        if let Some(name) = entry?
+            .file_name()
+            .to_str()
+            .and_then(|file_name| file_name.strip_suffix(&suffix))
+        {
+            names.push(name.to_owned());
+        }
+    }
+
+    names.sort();
+
+    Ok(names)
+}
+
+/// Accepts a pending snapshot by moving it over the accepted snapshot.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+///
+/// # Errors
+///
+/// * Returns any errors from renaming the pending snapshot.
+pub fn accept_pending_snapshot(directory: impl AsRef<Path>, name: &str) -> io::Result<()> {
+    fs::rename(
+        get_pending_snapshot_path(&directory, name),
+        get_snapshot_path(&directory, name),
+    )
+}
+
+/// Rejects a pending snapshot by deleting it, leaving the accepted snapshot as it is.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+///
+/// # Errors
+///
+/// * Returns any errors from deleting the pending snapshot.
+pub fn reject_pending_snapshot(directory: impl AsRef<Path>, name: &str) -> io::Result<()> {
+    fs::remove_file(get_pending_snapshot_path(directory, name))
+}
+
+/// Writes a description of the changes in a pending snapshot to be reviewed.
+fn write_pending_snapshot_changes(
+    directory: impl AsRef<Path>,
+    name: &str,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    let actual = fs::read_to_string(get_pending_snapshot_path(&directory, name))?;
+
+    match fs::read_to_string(get_snapshot_path(&directory, name)) {
+        Ok(expected) => {
+            #[cfg(feature = "string-diff")]
+            writeln!(
+                output,
+                "{}",
+                crate::utilities::diff::format_diff(&expected, &actual)
+            )?;
+
+            #[cfg(not(feature = "string-diff"))]
+            writeln!(output, "expected:\n{expected}\nactual:\n{actual}")?;
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            writeln!(output, "new snapshot:\n{actual}")?;
+        }
+        Err(error) => return Err(error),
+    }
+
+    Ok(())
+}
+
+/// Reviews the pending snapshots in a directory interactively, accepting or rejecting each one.
+///
+/// For each pending snapshot, the changes are written to `output` and a decision is read from
+/// `input`: `a` to accept, `r` to reject, or `s` to skip. If `input` ends, the remaining snapshots
+/// are skipped.
+///
+/// This is what the `review-snapshots` binary runs with standard input and output.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `input` - Where to read decisions from.
+/// * `output` - Where to write the changes and prompts to.
+///
+/// # Errors
+///
+/// * Returns any errors from reading or writing snapshots, or from reading `input` or writing
+///   `output`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use test_ur_code_xd::assertions::snapshot::{review_pending_snapshots, SNAPSHOT_DIRECTORY};
+/// # use std::io;
+/// #
+/// let review = review_pending_snapshots(SNAPSHOT_DIRECTORY, io::stdin().lock(), io::stdout())?;
+///
+/// println!("accepted {} snapshots", review.accepted.len());
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn review_pending_snapshots(
+    directory: impl AsRef<Path>,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<SnapshotReview> {
+    let mut review = SnapshotReview::default();
+    let mut is_input_finished = false;
+
+    for name in find_pending_snapshots(&directory)? {
+        if is_input_finished {
+            review.skipped.push(name);
+            continue;
+        }
+
+        writeln!(output, "pending snapshot: {name}")?;
+        write_pending_snapshot_changes(&directory, &name, &mut output)?;
+
+        let decision = loop {
+            write!(output, "accept (a), reject (r), or skip (s)? ")?;
+            output.flush()?;
+
+            let mut line = String::new();
+
+            if input.read_line(&mut line)? == 0 {
+                is_input_finished = true;
+                break ReviewDecision::Skip;
+            }
+
+            if let Some(decision) = ReviewDecision::parse(&line) {
+                break decision;
+            }
+        };
+
+        writeln!(output)?;
+
+        match decision {
+            ReviewDecision::Accept => {
+                accept_pending_snapshot(&directory, &name)?;
+                review.accepted.push(name);
+            }
+            ReviewDecision::Reject => {
+                reject_pending_snapshot(&directory, &name)?;
+                review.rejected.push(name);
+            }
+            ReviewDecision::Skip => review.skipped.push(name),
+        }
+    }
+
+    Ok(review)
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[track_caller]
+pub fn check_snapshot_or_panic(
+    directory: impl AsRef<Path>,
+    name: &str,
+    actual: &str,
+) -> SnapshotOutcome {
+    match check_snapshot(&directory, name, actual) {
+        Ok(outcome) => outcome,
+        Err(error) => panic_with_snapshot_error(directory, name, &error),
+    }
+}
+
+/// Helper function to panic with an error that happened while checking or updating a snapshot.
+// Expect allowed because any failure to format the panic message is hard coded in, not dependent on
+// how this function is called.
+#[allow(clippy::expect_used)]
+#[track_caller]
+fn panic_with_snapshot_error(directory: impl AsRef<Path>, name: &str, error: &io::Error) -> ! {
+    PanicMessageBuilder::new_from_error(
+        MessageType::ErrorWhileCheckingAssertion,
+        "error checking snapshot",
+        Location::caller(),
+        error,
+    )
+    .and_then(|panic_message_builder| {
+        panic_message_builder.with_argument("snapshot", "--", &get_snapshot_path(&directory, name))
+    })
+    .expect("error while creating panic message builder")
+    .panic()
+}
+
+/// Replaces a snapshot with some text and removes any pending snapshot for it.
+///
+/// This is what [`assert_snapshot`](crate::assert_snapshot) does instead of failing when update mode
+/// is enabled. See [`update_mode`](crate::utilities::update_mode) for more information.
+///
+/// # Arguments
+///
+/// * `directory` - The directory in which snapshots are stored.
+/// * `name` - The name of the snapshot.
+/// * `actual` - The text with which to replace the snapshot.
+///
+/// # Returns
+///
+/// `true` if the snapshot was rewritten, or `false` if it already had the text.
+///
+/// # Panics
+///
+/// * If the snapshot can't be written or the pending snapshot can't be removed.
+#[track_caller]
+pub fn update_snapshot(directory: impl AsRef<Path>, name: &str, actual: &str) -> bool {
+    let is_updated = update_expected_file(get_snapshot_path(&directory, name), actual);
+
+    match fs::remove_file(get_pending_snapshot_path(&directory, name)) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => {
+            panic_with_snapshot_error(directory, name, &error)
+        }
+        _ => is_updated,
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_snapshot_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    directory: impl AsRef<Path>,
+    name: &str,
+    actual: &str,
+    outcome: &SnapshotOutcome,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument("snapshot", "--", &get_snapshot_path(&directory, name))?
+        .with_argument_formatted("actual", "--", actual)?;
+
+    let panic_message_builder = match outcome {
+        SnapshotOutcome::Matches => panic_message_builder,
+        SnapshotOutcome::Missing => {
+            panic_message_builder.with_argument_formatted("expected", "--", "(no snapshot)")?
+        }
+        SnapshotOutcome::Mismatch { expected } => {
+            let panic_message_builder =
+                panic_message_builder.with_argument_formatted("expected", "--", expected)?;
+
+            #[cfg(feature = "string-diff")]
+            let panic_message_builder = panic_message_builder.with_argument_formatted(
+                "diff",
+                "--",
+                crate::utilities::diff::format_diff(expected, actual),
+            )?;
+
+            panic_message_builder
+        }
+    };
+
+    panic_message_builder.with_argument(
+        "pending snapshot",
+        "--",
+        &get_pending_snapshot_path(&directory, name),
+    )
+}
+
+/// Asserts that some text matches a snapshot stored alongside the tests.
+///
+/// Snapshots are stored in `tests/snapshots/<name>.snap` relative to the crate being tested. If the
+/// snapshot is missing or does not match, the assertion fails and the actual text is written to
+/// `tests/snapshots/<name>.snap.new`. Rename it to `<name>.snap` to accept it.
+///
+/// If the `UPDATE` environment variable is set to `1`, the snapshot is rewritten with the actual
+/// text instead of failing. See [`update_mode`](crate::utilities::update_mode) for more information.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/snapshot](https://sophie-katz.github.io/test-ur-code-XD/assertions/snapshot/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `name` - The name of the snapshot. It must be unique within the crate.
+/// * `actual` - The text to compare against the snapshot.
+/// * Optional: `redact = <value>` - Pairs of patterns and placeholders with which to replace them
+///                                  before comparing, like `[(temp_path, "[TEMP]")]`.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `redact` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```no_run
+/// # use test_ur_code_xd::assert_snapshot;
+/// #
+/// assert_snapshot!("greeting", format!("hello, {}", "world"));
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    (
+        $name:expr,
+        $actual:expr,
+        redact = $redactions:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let directory = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join($crate::assertions::snapshot::SNAPSHOT_DIRECTORY);
+        let name: &str = ::std::convert::AsRef::as_ref(&$name);
+        let actual = $crate::assertions::snapshot::redact(&$actual, $redactions);
+
+        let config = $crate::config_from_keyword_arguments!($($keys = $values),*);
+
+        let is_updated = !config.negate
+            && $crate::utilities::update_mode::is_update_mode_enabled()
+            && $crate::assertions::snapshot::update_snapshot(&directory, name, &actual);
+
+        if !is_updated {
+            let outcome =
+                $crate::assertions::snapshot::check_snapshot_or_panic(&directory, name, &actual);
+
+            $crate::assert_custom!(
+                "text matches snapshot",
+                outcome.is_match(),
+                |panic_message_builder| {
+                    $crate::assertions::snapshot::configure_snapshot_panic_message(
+                        panic_message_builder,
+                        &directory,
+                        name,
+                        &actual,
+                        &outcome,
+                    )
+                },
+                config = config
+            );
+        }
+    }};
+
+    ($name:expr, $actual:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_snapshot!(
+            $name,
+            $actual,
+            redact = ::std::iter::empty::<(&str, &str)>()
+            $(, $keys = $values)*
+        )
+    };
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use tempfile::tempdir;
+
+    #[test]
+    fn redact_none() {
+        assert_eq!(redact("hello", Vec::<(&str, &str)>::new()), "hello");
+    }
+
+    #[test]
+    fn redact_multiple() {
+        assert_eq!(
+            redact(
+                "hello, world, hello",
+                [("hello", "[GREETING]"), ("world", "[NAME]")]
+            ),
+            "[GREETING], [NAME], [GREETING]"
+        );
+    }
+
+    #[test]
+    fn redact_empty_pattern() {
+        assert_eq!(redact("hello", [("", "[EMPTY]")]), "hello");
+    }
+
+    #[test]
+    fn check_snapshot_missing() {
+        let directory = tempdir().unwrap();
+        let snapshot_directory = directory.path().join("snapshots");
+
+        assert_eq!(
+            check_snapshot(&snapshot_directory, "example", "hello").unwrap(),
+            SnapshotOutcome::Missing
+        );
+
+        assert_eq!(
+            fs::read_to_string(get_pending_snapshot_path(&snapshot_directory, "example")).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn check_snapshot_matches() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+        fs::write(
+            get_pending_snapshot_path(directory.path(), "example"),
+            "stale",
+        )
+        .unwrap();
+
+        assert_eq!(
+            check_snapshot(directory.path(), "example", "hello").unwrap(),
+            SnapshotOutcome::Matches
+        );
+
+        assert!(!get_pending_snapshot_path(directory.path(), "example").exists());
+    }
+
+    #[test]
+    fn check_snapshot_mismatch() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+
+        assert_eq!(
+            check_snapshot(directory.path(), "example", "goodbye").unwrap(),
+            SnapshotOutcome::Mismatch {
+                expected: "hello".to_owned()
+            }
+        );
+
+        assert_eq!(
+            fs::read_to_string(get_pending_snapshot_path(directory.path(), "example")).unwrap(),
+            "goodbye"
+        );
+    }
+
+    #[test]
+    fn update_snapshot_missing() {
+        let directory = tempdir().unwrap();
+        let snapshot_directory = directory.path().join("snapshots");
+
+        assert!(update_snapshot(&snapshot_directory, "example", "hello"));
+
+        assert_eq!(
+            fs::read_to_string(get_snapshot_path(&snapshot_directory, "example")).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn update_snapshot_mismatch() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+        fs::write(
+            get_pending_snapshot_path(directory.path(), "example"),
+            "stale",
+        )
+        .unwrap();
+
+        assert!(update_snapshot(directory.path(), "example", "goodbye"));
+
+        assert_eq!(
+            fs::read_to_string(get_snapshot_path(directory.path(), "example")).unwrap(),
+            "goodbye"
+        );
+        assert!(!get_pending_snapshot_path(directory.path(), "example").exists());
+    }
+
+    #[test]
+    fn update_snapshot_matches() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+
+        assert!(!update_snapshot(directory.path(), "example", "hello"));
+    }
+
+    #[test]
+    fn review_decision_parse() {
+        assert_eq!(ReviewDecision::parse("a\n"), Some(ReviewDecision::Accept));
+        assert_eq!(
+            ReviewDecision::parse("Reject"),
+            Some(ReviewDecision::Reject)
+        );
+        assert_eq!(ReviewDecision::parse(" s "), Some(ReviewDecision::Skip));
+        assert_eq!(ReviewDecision::parse("x"), None::<ReviewDecision>);
+    }
+
+    #[test]
+    fn find_pending_snapshots_missing_directory() {
+        let directory = tempdir().unwrap();
+
+        assert!(find_pending_snapshots(directory.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn find_pending_snapshots_sorted() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_pending_snapshot_path(directory.path(), "b"), "").unwrap();
+        fs::write(get_pending_snapshot_path(directory.path(), "a"), "").unwrap();
+        fs::write(get_snapshot_path(directory.path(), "c"), "").unwrap();
+
+        assert_eq!(
+            find_pending_snapshots(directory.path()).unwrap(),
+            vec!["a".to_owned(), "b".to_owned()]
+        );
+    }
+
+    #[test]
+    fn accept_pending_snapshot_replaces_snapshot() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+        fs::write(
+            get_pending_snapshot_path(directory.path(), "example"),
+            "goodbye",
+        )
+        .unwrap();
+
+        accept_pending_snapshot(directory.path(), "example").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(get_snapshot_path(directory.path(), "example")).unwrap(),
+            "goodbye"
+        );
+        assert!(!get_pending_snapshot_path(directory.path(), "example").exists());
+    }
+
+    #[test]
+    fn reject_pending_snapshot_keeps_snapshot() {
+        let directory = tempdir().unwrap();
+
+        fs::write(get_snapshot_path(directory.path(), "example"), "hello").unwrap();
+        fs::write(
+            get_pending_snapshot_path(directory.path(), "example"),
+            "goodbye",
+        )
+        .unwrap();
+
+        reject_pending_snapshot(directory.path(), "example").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(get_snapshot_path(directory.path(), "example")).unwrap(),
+            "hello"
+        );
+        assert!(!get_pending_snapshot_path(directory.path(), "example").exists());
+    }
+
+    #[test]
+    fn review_pending_snapshots_decisions() {
+        let directory = tempdir().unwrap();
+
+        for name in ["a", "b", "c", "d"] {
+            fs::write(get_pending_snapshot_path(directory.path(), name), name).unwrap();
+        }
+
+        let mut output = Vec::new();
+
+        let review =
+            review_pending_snapshots(directory.path(), "a\nwhat\nr\ns\n".as_bytes(), &mut output)
+                .unwrap();
+
+        assert_eq!(review.accepted, vec!["a".to_owned()]);
+        assert_eq!(review.rejected, vec!["b".to_owned()]);
+        assert_eq!(review.skipped, vec!["c".to_owned(), "d".to_owned()]);
+
+        assert!(get_snapshot_path(directory.path(), "a").exists());
+        assert!(!get_pending_snapshot_path(directory.path(), "b").exists());
+        assert!(!get_snapshot_path(directory.path(), "b").exists());
+        assert!(get_pending_snapshot_path(directory.path(), "c").exists());
+        assert!(get_pending_snapshot_path(directory.path(), "d").exists());
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("pending snapshot: a\nnew snapshot:\na\n"));
+        assert_eq!(
+            output
+                .matches("accept (a), reject (r), or skip (s)?")
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn assert_snapshot_passing() {
+        assert_snapshot!("assert_snapshot_passing", "hello, world\n");
+    }
+
+    #[test]
+    fn assert_snapshot_passing_redact() {
+        let name = "Sophie";
+
+        assert_snapshot!(
+            "assert_snapshot_passing_redact",
+            format!("hello, {name}\n"),
+            redact = [(name, "[NAME]")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "text matches snapshot")]
+    fn assert_snapshot_failing() {
+        assert_snapshot!("assert_snapshot_failing", "goodbye, world\n");
+    }
+}
@@ -0,0 +1,373 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Soft assertions that collect failures instead of panicking right away.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/soft](https://sophie-katz.github.io/test-ur-code-XD/assertions/soft/)
+//! for a usage guide.
+
+use std::{
+    fmt::{Debug, Display},
+    panic::Location,
+    thread,
+};
+
+use crate::utilities::panic_message_builder::{MessageType, PanicMessageBuilder};
+
+/// A single failed check recorded by [`SoftAssertions`].
+#[derive(Clone, Debug)]
+struct SoftAssertionFailure {
+    /// The location of the check.
+    location: &'static Location<'static>,
+
+    /// A description of the predicate that failed, including the values involved.
+    description: String,
+}
+
+impl Display for SoftAssertionFailure {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            formatter,
+            "at {}:{}:{}: {}",
+            self.location.file(),
+            self.location.line(),
+            self.location.column(),
+            self.description
+        )
+    }
+}
+
+/// Collects failed checks during a test and panics once at the end with every failure listed.
+///
+/// Each check records a failure instead of panicking. Call [`verify`](Self::verify) at the end of
+/// the test to panic if any check failed. If the checks are dropped without being verified, they
+/// are verified automatically.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/soft](https://sophie-katz.github.io/test-ur-code-XD/assertions/soft/)
+/// for a usage guide.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::soft::SoftAssertions;
+/// #
+/// let mut check = SoftAssertions::new();
+///
+/// for (input, expected) in [(1, 2), (2, 4), (3, 6)] {
+///     check.eq(input * 2, expected);
+/// }
+///
+/// check.is_true(true).is_false(false);
+///
+/// check.verify();
+/// ```
+#[derive(Debug)]
+pub struct SoftAssertions {
+    /// The location where the checks were created, used when they are verified on drop.
+    location: &'static Location<'static>,
+
+    /// The failures recorded so far.
+    failures: Vec<SoftAssertionFailure>,
+
+    /// The number of checks run so far.
+    check_count: usize,
+}
+
+impl SoftAssertions {
+    /// Creates an empty set of checks.
+    #[must_use]
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            location: Location::caller(),
+            failures: Vec::new(),
+            check_count: 0,
+        }
+    }
+
+    /// Records a check.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate_value` - Whether or not the check passed.
+    /// * `describe` - A closure that describes the failure. It is only called if the check failed.
+    /// * `location` - The location of the check.
+    fn record(
+        &mut self,
+        predicate_value: bool,
+        describe: impl FnOnce() -> String,
+        location: &'static Location<'static>,
+    ) -> &mut Self {
+        self.check_count = self.check_count.saturating_add(1);
+
+        if !predicate_value {
+            self.failures.push(SoftAssertionFailure {
+                location,
+                description: describe(),
+            });
+        }
+
+        self
+    }
+
+    /// Checks a custom predicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate_description` - A description of the predicate, used if the check fails.
+    /// * `predicate_value` - Whether or not the check passed.
+    #[track_caller]
+    pub fn check(
+        &mut self,
+        predicate_description: impl Display,
+        predicate_value: bool,
+    ) -> &mut Self {
+        self.record(
+            predicate_value,
+            || predicate_description.to_string(),
+            Location::caller(),
+        )
+    }
+
+    /// Checks that a value is true.
+    #[track_caller]
+    pub fn is_true(&mut self, value: bool) -> &mut Self {
+        self.record(value, || "value is true".to_owned(), Location::caller())
+    }
+
+    /// Checks that a value is false.
+    #[track_caller]
+    pub fn is_false(&mut self, value: bool) -> &mut Self {
+        self.record(!value, || "value is false".to_owned(), Location::caller())
+    }
+
+    /// Checks that two values are equal to each other using the [`PartialEq`] trait.
+    //
+    // Values are taken by value so that checks read like `check.eq(a, b)`, the same as the assertion
+    // macros.
+    #[allow(clippy::needless_pass_by_value)]
+    #[track_caller]
+    pub fn eq<LhsType: PartialEq<RhsType> + Debug, RhsType: Debug>(
+        &mut self,
+        lhs: LhsType,
+        rhs: RhsType,
+    ) -> &mut Self {
+        self.record(
+            lhs.eq(&rhs),
+            || format!("lhs == rhs (lhs = {lhs:?}, rhs = {rhs:?})"),
+            Location::caller(),
+        )
+    }
+
+    /// Checks that two values are unequal to each other using the [`PartialEq`] trait.
+    //
+    // Values are taken by value so that checks read like `check.ne(a, b)`, the same as the assertion
+    // macros.
+    #[allow(clippy::needless_pass_by_value)]
+    #[track_caller]
+    pub fn ne<LhsType: PartialEq<RhsType> + Debug, RhsType: Debug>(
+        &mut self,
+        lhs: LhsType,
+        rhs: RhsType,
+    ) -> &mut Self {
+        self.record(
+            lhs.ne(&rhs),
+            || format!("lhs != rhs (lhs = {lhs:?}, rhs = {rhs:?})"),
+            Location::caller(),
+        )
+    }
+
+    /// Gets the number of checks that failed so far.
+    #[must_use]
+    pub fn failure_count(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Panics if any checks failed, listing every failure.
+    ///
+    /// # Panics
+    ///
+    /// * If any checks failed.
+    #[track_caller]
+    pub fn verify(mut self) {
+        self.verify_at(Location::caller());
+    }
+
+    /// Helper method to format the failures as a list, one per line.
+    #[must_use]
+    fn format_failures(&self) -> String {
+        format!(
+            "{} of {} checks failed\n{}",
+            self.failures.len(),
+            self.check_count,
+            self.failures
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+
+    /// Helper method to panic with every failure if any checks failed.
+    fn verify_at(&mut self, location: &'static Location<'static>) {
+        if self.failures.is_empty() {
+            return;
+        }
+
+        let formatted_failures = self.format_failures();
+
+        // Clear the failures so that they are not reported again when dropped
+        self.failures.clear();
+
+        PanicMessageBuilder::unwrap_error_with(
+            PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "all soft assertions pass",
+                location,
+            )
+            .with_argument_formatted("failures", "--", formatted_failures),
+            MessageType::InternalError,
+            "unable to create panic message builder for soft assertions",
+            PanicMessageBuilder::no_configuration,
+        )
+        .panic();
+    }
+}
+
+impl Default for SoftAssertions {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        // Panicking while already panicking would abort the test process
+        if !thread::panicking() {
+            self.verify_at(self.location);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::panic_message_builder::catch_unwind_silently;
+    use crate::{assert, assert_eq};
+    use std::panic::AssertUnwindSafe;
+
+    #[test]
+    fn passing() {
+        let mut check = SoftAssertions::new();
+
+        check
+            .eq(1, 1)
+            .ne(1, 2)
+            .is_true(true)
+            .is_false(false)
+            .check("custom", true);
+
+        assert_eq!(check.failure_count(), 0);
+
+        check.verify();
+    }
+
+    #[test]
+    fn failure_count() {
+        let mut check = SoftAssertions::new();
+
+        check.eq(1, 2).eq(3, 3).is_true(false);
+
+        assert_eq!(check.failure_count(), 2);
+
+        // Clear the failures so that dropping does not panic
+        check.failures.clear();
+    }
+
+    #[test]
+    fn format_failures_lists_every_failure() {
+        let mut check = SoftAssertions::new();
+
+        check.eq(1, 2);
+        check.eq("a", "a");
+        check.is_true(false);
+        check.check("custom predicate", false);
+
+        let formatted = check.format_failures();
+
+        check.failures.clear();
+
+        let lines: Vec<&str> = formatted.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines.first(), Some(&"3 of 4 checks failed"));
+        assert!(formatted.contains("lhs == rhs (lhs = 1, rhs = 2)"));
+        assert!(formatted.contains("value is true"));
+        assert!(formatted.contains("custom predicate"));
+    }
+
+    #[test]
+    fn verify_clears_failures() {
+        let mut check = SoftAssertions::new();
+
+        check.is_false(true);
+
+        let result = catch_unwind_silently(AssertUnwindSafe(|| {
+            check.verify_at(Location::caller());
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(check.failure_count(), 0);
+    }
+
+    #[test]
+    fn failure_display() {
+        let failure = SoftAssertionFailure {
+            location: Location::caller(),
+            description: "lhs == rhs (lhs = 1, rhs = 2)".to_owned(),
+        };
+
+        assert_eq!(
+            failure.to_string(),
+            format!(
+                "at {}:{}:{}: lhs == rhs (lhs = 1, rhs = 2)",
+                failure.location.file(),
+                failure.location.line(),
+                failure.location.column()
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "all soft assertions pass")]
+    fn failing_verify() {
+        let mut check = SoftAssertions::new();
+
+        check.eq(1, 2);
+
+        check.verify();
+    }
+
+    #[test]
+    #[should_panic(expected = "all soft assertions pass")]
+    fn failing_on_drop() {
+        let mut check = SoftAssertions::new();
+
+        check.ne(1, 1);
+    }
+}
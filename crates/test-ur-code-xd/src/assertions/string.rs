@@ -21,6 +21,14 @@
 
 #[cfg(feature = "regex")]
 use regex::Regex;
+use std::fmt::Debug;
+#[cfg(feature = "unicode-normalization")]
+use std::fmt::{self, Display};
+use std::str::{self, Utf8Error};
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::{is_nfc, is_nfd, UnicodeNormalization};
+
+use crate::{errors::TestUrCodeXDError, utilities::panic_message_builder::PanicMessageBuilder};
 
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
@@ -30,6 +38,45 @@ pub fn assert_str_eq_impl(lhs: impl AsRef<str>, rhs: impl AsRef<str>) -> bool {
     lhs.as_ref().eq(rhs.as_ref())
 }
 
+/// Normalizes a string before comparing it.
+///
+/// # Arguments
+///
+/// * `value` - The string to normalize.
+/// * `ignore_case` - Whether or not to convert the string to lowercase.
+/// * `normalize_whitespace` - Whether or not to trim the ends of the string and collapse each run
+///                            of whitespace within it into a single space.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::string::normalize_str;
+/// #
+/// assert_eq!(normalize_str("  Hello,\n\tWorld ", true, true), "hello, world");
+/// ```
+#[must_use]
+pub fn normalize_str(
+    value: impl AsRef<str>,
+    ignore_case: bool,
+    normalize_whitespace: bool,
+) -> String {
+    let value = if normalize_whitespace {
+        value
+            .as_ref()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    } else {
+        value.as_ref().to_owned()
+    };
+
+    if ignore_case {
+        value.to_lowercase()
+    } else {
+        value
+    }
+}
+
 /// Asserts that one string is equal to another and prints a diff if they are not.
 ///
 /// See
@@ -40,6 +87,13 @@ pub fn assert_str_eq_impl(lhs: impl AsRef<str>, rhs: impl AsRef<str>) -> bool {
 ///
 /// * `lhs` - The left-hand side string.
 /// * `rhs` - The right-hand side string.
+/// * Optional: `ignore_case = <bool>` - Whether or not to compare the strings case-insensitively.
+/// * Optional: `normalize_whitespace = <bool>` - Whether or not to trim the ends of the strings and
+///                                               collapse runs of whitespace before comparing.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `ignore_case` and `normalize_whitespace` must come before any other keyword
+/// arguments. When either is given, the panic message shows both the raw and normalized strings.
 ///
 /// # Example
 ///
@@ -49,10 +103,88 @@ pub fn assert_str_eq_impl(lhs: impl AsRef<str>, rhs: impl AsRef<str>) -> bool {
 /// assert_str_eq!("hello, world", "hello, world");
 ///
 /// assert_str_eq!("hello, world", "hello! world", negate = true);
+///
+/// assert_str_eq!("Hello,\n  World", "hello, world", ignore_case = true, normalize_whitespace = true);
 /// ```
 #[cfg(feature = "string-diff")]
 #[macro_export]
 macro_rules! assert_str_eq {
+    (
+        $lhs:expr,
+        $rhs:expr,
+        ignore_case = $ignore_case:expr,
+        normalize_whitespace = $normalize_whitespace:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let ignore_case: bool = $ignore_case;
+        let normalize_whitespace: bool = $normalize_whitespace;
+
+        let lhs_normalized =
+            $crate::assertions::string::normalize_str(&$lhs, ignore_case, normalize_whitespace);
+        let rhs_normalized =
+            $crate::assertions::string::normalize_str(&$rhs, ignore_case, normalize_whitespace);
+
+        $crate::assert_custom!(
+            "lhs == rhs",
+            $crate::assertions::string::assert_str_eq_impl(&lhs_normalized, &rhs_normalized),
+            |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("lhs", stringify!($lhs), &::std::convert::AsRef::<str>::as_ref(&$lhs))?
+                    .with_argument("rhs", stringify!($rhs), &::std::convert::AsRef::<str>::as_ref(&$rhs))?
+                    .with_argument("normalized lhs", "--", &lhs_normalized)?
+                    .with_argument("normalized rhs", "--", &rhs_normalized)?
+                    .with_argument_formatted("diff", "--",
+                        $crate::utilities::diff::format_diff(
+                            &lhs_normalized,
+                            &rhs_normalized
+                        )
+                    )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        normalize_whitespace = $normalize_whitespace:expr,
+        ignore_case = $ignore_case:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_str_eq!(
+            $lhs,
+            $rhs,
+            ignore_case = $ignore_case,
+            normalize_whitespace = $normalize_whitespace
+            $(, $keys = $values)*
+        )
+    };
+
+    ($lhs:expr, $rhs:expr, ignore_case = $ignore_case:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_str_eq!(
+            $lhs,
+            $rhs,
+            ignore_case = $ignore_case,
+            normalize_whitespace = false
+            $(, $keys = $values)*
+        )
+    };
+
+    (
+        $lhs:expr,
+        $rhs:expr,
+        normalize_whitespace = $normalize_whitespace:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {
+        $crate::assert_str_eq!(
+            $lhs,
+            $rhs,
+            ignore_case = false,
+            normalize_whitespace = $normalize_whitespace
+            $(, $keys = $values)*
+        )
+    };
+
     ($lhs:expr, $rhs:expr $(, $keys:ident = $values:expr)* $(,)?) => {
         $crate::assert_custom!(
             "lhs == rhs",
@@ -73,6 +205,248 @@ macro_rules! assert_str_eq {
     };
 }
 
+/// Splits a string into lines, treating `\n`, `\r\n`, and `\r` all as line endings.
+///
+/// Like [`str::lines`], a line ending at the end of the string does not start another line.
+///
+/// # Arguments
+///
+/// * `value` - The string to split.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assertions::string::split_lines;
+/// #
+/// assert_eq!(split_lines("a\r\nb\rc\n"), ["a", "b", "c"]);
+/// ```
+#[must_use]
+pub fn split_lines(value: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut rest = value;
+
+    while let Some(line_ending_offset) = rest.find(['\r', '\n']) {
+        let (line, line_ending_and_rest) = rest.split_at(line_ending_offset);
+
+        lines.push(line);
+
+        rest = line_ending_and_rest
+            .strip_prefix("\r\n")
+            .or_else(|| line_ending_and_rest.get(1..))
+            .unwrap_or_default();
+    }
+
+    if !rest.is_empty() {
+        lines.push(rest);
+    }
+
+    lines
+}
+
+/// The first line that differs between two strings compared by
+/// [`assert_lines_eq`](crate::assert_lines_eq).
+#[derive(Debug)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct LinesDifference<'value> {
+    /// The 1-based number of the line.
+    pub line_number: usize,
+
+    /// The line in the actual string, or `None` if the actual string has fewer lines.
+    pub actual_line: Option<&'value str>,
+
+    /// The line in the expected string, or `None` if the expected string has fewer lines.
+    pub expected_line: Option<&'value str>,
+
+    /// The number of lines in the actual string.
+    pub actual_line_count: usize,
+
+    /// The number of lines in the expected string.
+    pub expected_line_count: usize,
+}
+
+/// Finds the first line that differs between two strings.
+///
+/// # Arguments
+///
+/// * `actual` - The actual string.
+/// * `expected` - The expected string.
+/// * `trim_trailing` - Whether or not to ignore whitespace at the end of each line.
+///
+/// # Returns
+///
+/// The first differing line, or `None` if all lines are equal.
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn find_lines_difference<'value>(
+    actual: &'value str,
+    expected: &'value str,
+    trim_trailing: bool,
+) -> Option<LinesDifference<'value>> {
+    let actual_lines = split_lines(actual);
+    let expected_lines = split_lines(expected);
+
+    let normalize = |line: &'value str| {
+        if trim_trailing {
+            line.trim_end()
+        } else {
+            line
+        }
+    };
+
+    let line_index = (0..actual_lines.len().max(expected_lines.len())).find(|line_index| {
+        actual_lines.get(*line_index).copied().map(normalize)
+            != expected_lines.get(*line_index).copied().map(normalize)
+    })?;
+
+    Some(LinesDifference {
+        line_number: line_index.saturating_add(1),
+        actual_line: actual_lines.get(line_index).copied(),
+        expected_line: expected_lines.get(line_index).copied(),
+        actual_line_count: actual_lines.len(),
+        expected_line_count: expected_lines.len(),
+    })
+}
+
+/// Configures a panic message builder for [`assert_lines_eq`](crate::assert_lines_eq) to show the
+/// first line that differs.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `actual_description` - The description of the actual string expression
+/// * `actual` - The actual string
+/// * `expected_description` - The description of the expected string expression
+/// * `expected` - The expected string
+/// * `lines_difference` - The first line that differs, if any
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_lines_eq_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    actual_description: &str,
+    actual: &str,
+    expected_description: &str,
+    expected: &str,
+    lines_difference: Option<&LinesDifference<'_>>,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder
+        .with_argument("actual", actual_description, &actual)?
+        .with_argument("expected", expected_description, &expected)?;
+
+    let Some(lines_difference) = lines_difference else {
+        return Ok(panic_message_builder);
+    };
+
+    let format_line = |line: Option<&str>, line_count: usize| match line {
+        Some(line) => format!("{line:?}"),
+        None if line_count == 1 => "none (only 1 line)".to_owned(),
+        None => format!("none (only {line_count} lines)"),
+    };
+
+    panic_message_builder
+        .with_argument_formatted(
+            "first differing line",
+            "--",
+            lines_difference.line_number.to_string(),
+        )?
+        .with_argument_formatted(
+            "actual line",
+            "--",
+            format_line(
+                lines_difference.actual_line,
+                lines_difference.actual_line_count,
+            ),
+        )?
+        .with_argument_formatted(
+            "expected line",
+            "--",
+            format_line(
+                lines_difference.expected_line,
+                lines_difference.expected_line_count,
+            ),
+        )
+}
+
+/// Asserts that two strings have the same lines, regardless of which line endings they use.
+///
+/// Lines can end with `\n`, `\r\n`, or `\r`, and a line ending at the end of either string is
+/// ignored. This avoids spurious failures when comparing against golden files that were checked
+/// out on a different platform. If the assertion fails, the panic message shows the first line that
+/// differs.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `actual` - The string to check.
+/// * `expected` - The string that `actual` is expected to equal.
+/// * Optional: `trim_trailing = <bool>` - Whether or not to ignore whitespace at the end of each
+///                                        line.
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `trim_trailing` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_lines_eq;
+/// #
+/// assert_lines_eq!("hello\r\nworld\r\n", "hello\nworld");
+///
+/// assert_lines_eq!("hello  \nworld\t\n", "hello\nworld\n", trim_trailing = true);
+/// ```
+#[macro_export]
+macro_rules! assert_lines_eq {
+    (
+        $actual:expr,
+        $expected:expr,
+        trim_trailing = $trim_trailing:expr
+        $(, $keys:ident = $values:expr)* $(,)?
+    ) => {{
+        let actual = &$actual;
+        let expected = &$expected;
+        let trim_trailing: bool = $trim_trailing;
+
+        let lines_difference = $crate::assertions::string::find_lines_difference(
+            ::std::convert::AsRef::<str>::as_ref(actual),
+            ::std::convert::AsRef::<str>::as_ref(expected),
+            trim_trailing,
+        );
+
+        $crate::assert_custom!(
+            "actual lines == expected lines",
+            lines_difference.is_none(),
+            |panic_message_builder| {
+                $crate::assertions::string::configure_lines_eq_panic_message(
+                    panic_message_builder,
+                    stringify!($actual),
+                    ::std::convert::AsRef::<str>::as_ref(actual),
+                    stringify!($expected),
+                    ::std::convert::AsRef::<str>::as_ref(expected),
+                    lines_difference.as_ref(),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($actual:expr, $expected:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_lines_eq!(
+            $actual,
+            $expected,
+            trim_trailing = false
+            $(, $keys = $values)*
+        )
+    };
+}
+
 // Assertion implementations need to be public for the macros to use them, but should not appear in
 // documentation.
 #[doc(hidden)]
@@ -91,6 +465,7 @@ pub fn assert_str_contains_impl(value: impl AsRef<str>, substring: impl AsRef<st
 ///
 /// * `value` - The string to check.
 /// * `substring` - The substring for which to check.
+/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
@@ -135,6 +510,7 @@ pub fn assert_str_starts_with_impl(value: impl AsRef<str>, prefix: impl AsRef<st
 ///
 /// * `value` - The string to check.
 /// * `prefix` - The prefix for which to check.
+/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
@@ -179,6 +555,7 @@ pub fn assert_str_ends_with_impl(value: impl AsRef<str>, suffix: impl AsRef<str>
 ///
 /// * `value` - The string to check.
 /// * `suffix` - The suffix for which to check.
+/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
@@ -215,18 +592,21 @@ macro_rules! assert_str_ends_with {
     // not with the input
     clippy::expect_used
 )]
+#[track_caller]
 pub fn assert_str_matches_impl(value: impl AsRef<str>, pattern: impl AsRef<str>) -> bool {
+    use crate::utilities::panic_message_builder::MessageType;
     use std::panic::Location;
 
-    use crate::utilities::panic_message_builder::PanicMessageBuilder;
-
     let pattern = match Regex::new(pattern.as_ref()) {
         Ok(pattern_value) => pattern_value,
-        Err(error) => {
-            PanicMessageBuilder::new_from_error("invalid regex pattern", Location::caller(), &error)
-                .expect("unable to build panic message for invalid regex pattern")
-                .panic()
-        }
+        Err(error) => PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "invalid regex pattern",
+            Location::caller(),
+            &error,
+        )
+        .expect("unable to build panic message for invalid regex pattern")
+        .panic(),
     };
 
     pattern.is_match(value.as_ref())
@@ -242,6 +622,7 @@ pub fn assert_str_matches_impl(value: impl AsRef<str>, pattern: impl AsRef<str>)
 ///
 /// * `value` - The string to check.
 /// * `pattern` - The pattern for which to check.
+/// * Optional keyword arguments for assertions.
 ///
 /// # Example
 ///
@@ -269,90 +650,839 @@ macro_rules! assert_str_matches {
     };
 }
 
-#[cfg(test)]
-mod tests {
-    #[cfg(feature = "string-diff")]
-    #[test]
-    fn assert_str_eq_passing() {
-        assert_str_eq!("hello, world", "hello, world");
-    }
+/// Something that can be searched for in a string by
+/// [`assert_str_count_eq`](crate::assert_str_count_eq) and related assertions.
+///
+/// This is implemented for substrings and characters, and for [`Regex`](regex::Regex) with the
+/// `regex` feature.
+pub trait StrNeedle: Debug {
+    /// Finds the byte offsets of each non-overlapping occurrence of the needle in a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack` - The string to search.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the start of each occurrence, in order.
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize>;
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    fn assert_str_eq_passing_empty() {
-        assert_str_eq!("", "");
+impl StrNeedle for str {
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize> {
+        haystack
+            .match_indices(self)
+            .map(|(offset, _)| offset)
+            .collect()
     }
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_empty_some() {
-        assert_str_eq!("", "asdf");
+impl StrNeedle for String {
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize> {
+        self.as_str().find_occurrence_offsets(haystack)
     }
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_some_empty() {
-        assert_str_eq!("asdf", "");
+impl StrNeedle for char {
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize> {
+        haystack
+            .match_indices(*self)
+            .map(|(offset, _)| offset)
+            .collect()
     }
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_totally_different() {
-        assert_str_eq!("hello, world", "asdf");
+#[cfg(feature = "regex")]
+impl StrNeedle for Regex {
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize> {
+        self.find_iter(haystack)
+            .map(|occurrence| occurrence.start())
+            .collect()
     }
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_slightly_different() {
-        assert_str_eq!("hello, world", "hello! world");
+impl<NeedleType: StrNeedle + ?Sized> StrNeedle for &NeedleType {
+    fn find_occurrence_offsets(&self, haystack: &str) -> Vec<usize> {
+        (**self).find_occurrence_offsets(haystack)
     }
+}
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_long() {
-        assert_str_eq!("a".repeat(100), "b".repeat(100));
-    }
+/// Configures a panic message builder for [`assert_str_count_eq`](crate::assert_str_count_eq) and
+/// related assertions to show where the needle was found.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the string that was searched
+/// * `value` - The string that was searched
+/// * `needle_description` - The description of the needle
+/// * `needle` - The needle
+/// * `count_description` - The description of the count that the occurrences are compared to
+/// * `count` - The count that the occurrences are compared to
+/// * `occurrence_offsets` - The byte offsets of the occurrences that were found
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+// This mirrors the arguments of the assertion, which are all needed in the panic message.
+#[allow(clippy::too_many_arguments)]
+pub fn configure_count_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: &str,
+    needle_description: &str,
+    needle: &(impl StrNeedle + ?Sized),
+    count_description: &str,
+    count: usize,
+    occurrence_offsets: &[usize],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let occurrences = match occurrence_offsets {
+        [] => "none".to_owned(),
+        [offset] => format!("1 at byte offset {offset}"),
+        _ => format!(
+            "{} at byte offsets {}",
+            occurrence_offsets.len(),
+            occurrence_offsets
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
 
-    #[cfg(feature = "string-diff")]
-    #[test]
-    #[should_panic(expected = "lhs == rhs")]
-    fn assert_str_eq_failing_multiline() {
-        assert_str_eq!("asdf\nasdf", "asdf\nfdsa");
-    }
+    panic_message_builder
+        .with_argument("value", value_description, &value)?
+        .with_argument("needle", needle_description, needle)?
+        .with_argument("count", count_description, &count)?
+        .with_argument_formatted("occurrences", "--", occurrences)
+}
 
-    #[test]
-    fn assert_str_contains_passing() {
-        assert_str_contains!("hello, world", "hello");
-    }
+/// Asserts that a substring or pattern occurs exactly a number of times in a string.
+///
+/// Occurrences are not allowed to overlap. If the assertion fails, the byte offsets of the
+/// occurrences that were found are shown in the panic message.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The string to search.
+/// * `needle` - What to search for. This can be a substring, a character, or a
+///              [`Regex`](regex::Regex) with the `regex` feature. See
+///              [`StrNeedle`](crate::assertions::string::StrNeedle).
+/// * `count` - The number of times that `needle` should occur.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_str_count_eq;
+/// #
+/// assert_str_count_eq!("a, b, c", ", ", 2);
+/// assert_str_count_eq!("hello, world", 'l', 3);
+/// ```
+#[macro_export]
+macro_rules! assert_str_count_eq {
+    ($value:expr, $needle:expr, $count:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+        let needle = &$needle;
+        let count: usize = $count;
 
-    #[test]
-    fn assert_str_contains_passing_empty() {
-        assert_str_contains!("hello, world", "");
-    }
+        let occurrence_offsets = $crate::assertions::string::StrNeedle::find_occurrence_offsets(
+            needle,
+            ::std::convert::AsRef::<str>::as_ref(value),
+        );
 
-    #[test]
-    fn assert_str_contains_passing_empty_both_empty() {
-        assert_str_contains!("", "");
-    }
+        $crate::assert_custom!(
+            "occurrence count == count",
+            occurrence_offsets.len() == count,
+            |panic_message_builder| {
+                $crate::assertions::string::configure_count_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<str>::as_ref(value),
+                    stringify!($needle),
+                    needle,
+                    stringify!($count),
+                    count,
+                    &occurrence_offsets,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
 
-    #[test]
-    #[should_panic(expected = "value contains substring")]
-    fn assert_str_contains_failing() {
-        assert_str_contains!("hello, world", "asdf");
-    }
+/// Asserts that a substring or pattern occurs at least a number of times in a string.
+///
+/// Occurrences are not allowed to overlap. If the assertion fails, the byte offsets of the
+/// occurrences that were found are shown in the panic message.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The string to search.
+/// * `needle` - What to search for. This can be a substring, a character, or a
+///              [`Regex`](regex::Regex) with the `regex` feature. See
+///              [`StrNeedle`](crate::assertions::string::StrNeedle).
+/// * `count` - The minimum number of times that `needle` should occur.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_str_count_ge;
+/// #
+/// assert_str_count_ge!("a, b, c", ", ", 1);
+/// assert_str_count_ge!("a, b, c", ", ", 2);
+/// ```
+#[macro_export]
+macro_rules! assert_str_count_ge {
+    ($value:expr, $needle:expr, $count:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+        let needle = &$needle;
+        let count: usize = $count;
 
-    #[test]
-    #[should_panic(expected = "value contains substring")]
-    fn assert_str_contains_failing_empty() {
-        assert_str_contains!("", "asdf");
-    }
+        let occurrence_offsets = $crate::assertions::string::StrNeedle::find_occurrence_offsets(
+            needle,
+            ::std::convert::AsRef::<str>::as_ref(value),
+        );
 
-    #[test]
+        $crate::assert_custom!(
+            "occurrence count >= count",
+            occurrence_offsets.len() >= count,
+            |panic_message_builder| {
+                $crate::assertions::string::configure_count_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<str>::as_ref(value),
+                    stringify!($needle),
+                    needle,
+                    stringify!($count),
+                    count,
+                    &occurrence_offsets,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// Asserts that a substring or pattern occurs at most a number of times in a string.
+///
+/// Occurrences are not allowed to overlap. If the assertion fails, the byte offsets of the
+/// occurrences that were found are shown in the panic message.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The string to search.
+/// * `needle` - What to search for. This can be a substring, a character, or a
+///              [`Regex`](regex::Regex) with the `regex` feature. See
+///              [`StrNeedle`](crate::assertions::string::StrNeedle).
+/// * `count` - The maximum number of times that `needle` should occur.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_str_count_le;
+/// #
+/// assert_str_count_le!("a, b, c", ", ", 2);
+/// assert_str_count_le!("a, b, c", ';', 0);
+/// ```
+#[macro_export]
+macro_rules! assert_str_count_le {
+    ($value:expr, $needle:expr, $count:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+        let needle = &$needle;
+        let count: usize = $count;
+
+        let occurrence_offsets = $crate::assertions::string::StrNeedle::find_occurrence_offsets(
+            needle,
+            ::std::convert::AsRef::<str>::as_ref(value),
+        );
+
+        $crate::assert_custom!(
+            "occurrence count <= count",
+            occurrence_offsets.len() <= count,
+            |panic_message_builder| {
+                $crate::assertions::string::configure_count_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<str>::as_ref(value),
+                    stringify!($needle),
+                    needle,
+                    stringify!($count),
+                    count,
+                    &occurrence_offsets,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// The number of bytes to show on each side of an invalid UTF-8 sequence in the panic message of
+/// [`assert_is_utf8`](crate::assert_is_utf8).
+const UTF8_ERROR_CONTEXT_BYTE_COUNT: usize = 8;
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_is_utf8_impl(value: impl AsRef<[u8]>) -> bool {
+    str::from_utf8(value.as_ref()).is_ok()
+}
+
+/// Helper function to format the bytes around an invalid UTF-8 sequence as hex, with the invalid
+/// sequence in brackets.
+///
+/// # Arguments
+///
+/// * `value` - The bytes that are not valid UTF-8.
+/// * `error` - The error from decoding `value`.
+#[must_use]
+fn format_utf8_error_context(value: &[u8], error: &Utf8Error) -> String {
+    let invalid_start = error.valid_up_to();
+    let invalid_end = error.error_len().map_or(value.len(), |error_len| {
+        invalid_start.saturating_add(error_len)
+    });
+
+    let context_start = invalid_start.saturating_sub(UTF8_ERROR_CONTEXT_BYTE_COUNT);
+    let context_end = invalid_end
+        .saturating_add(UTF8_ERROR_CONTEXT_BYTE_COUNT)
+        .min(value.len());
+
+    let format_bytes = |start: usize, end: usize| {
+        value
+            .get(start..end)
+            .unwrap_or_default()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<String>>()
+    };
+
+    let mut parts = format_bytes(context_start, invalid_start);
+
+    parts.push(format!(
+        "[{}]",
+        format_bytes(invalid_start, invalid_end).join(" ")
+    ));
+
+    parts.extend(format_bytes(invalid_end, context_end));
+
+    if context_start > 0 {
+        parts.insert(0, "...".to_owned());
+    }
+
+    if context_end < value.len() {
+        parts.push("...".to_owned());
+    }
+
+    parts.join(" ")
+}
+
+/// Configures a panic message builder for [`assert_is_utf8`](crate::assert_is_utf8) to show where
+/// the first invalid sequence is and the bytes around it.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the value being checked
+/// * `value` - The value being checked
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_utf8_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: &[u8],
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder = panic_message_builder.with_argument(
+        "value",
+        value_description,
+        &String::from_utf8_lossy(value),
+    )?;
+
+    match str::from_utf8(value) {
+        Ok(_) => Ok(panic_message_builder),
+        Err(error) => panic_message_builder
+            .with_argument_formatted("error", "--", error.to_string())?
+            .with_argument_formatted("bytes", "--", format_utf8_error_context(value, &error)),
+    }
+}
+
+/// Asserts that a sequence of bytes is valid UTF-8.
+///
+/// If it is not, the panic message shows the byte offset of the first invalid sequence and the
+/// surrounding bytes in hex.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The bytes to check, which can be anything that implements `AsRef<[u8]>`.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_is_utf8;
+/// #
+/// assert_is_utf8!(b"hello, world");
+///
+/// assert_is_utf8!(vec![0x68, 0x69, 0xff], negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_is_utf8 {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+
+        $crate::assert_custom!(
+            "value is valid UTF-8",
+            $crate::assertions::string::assert_is_utf8_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::string::configure_utf8_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<[u8]>::as_ref(value),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[must_use]
+pub fn assert_str_is_ascii_impl(value: impl AsRef<str>) -> bool {
+    value.as_ref().is_ascii()
+}
+
+/// Configures a panic message builder for [`assert_str_is_ascii`](crate::assert_str_is_ascii) to
+/// show the first character that is not ASCII.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the value being checked
+/// * `value` - The value being checked
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+pub fn configure_ascii_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: &str,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let panic_message_builder =
+        panic_message_builder.with_argument("value", value_description, &value)?;
+
+    match value
+        .char_indices()
+        .find(|(_, character)| !character.is_ascii())
+    {
+        Some((offset, character)) => panic_message_builder.with_argument_formatted(
+            "first non-ASCII character",
+            "--",
+            format!(
+                "{character:?} (U+{:04X}) at byte offset {offset}",
+                u32::from(character)
+            ),
+        ),
+        None => Ok(panic_message_builder),
+    }
+}
+
+/// Asserts that a string only contains ASCII characters.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The string to check.
+/// * Optional keyword arguments for assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::assert_str_is_ascii;
+/// #
+/// assert_str_is_ascii!("hello, world");
+///
+/// assert_str_is_ascii!("héllo, world", negate = true);
+/// ```
+#[macro_export]
+macro_rules! assert_str_is_ascii {
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+
+        $crate::assert_custom!(
+            "value is ASCII",
+            $crate::assertions::string::assert_str_is_ascii_impl(value),
+            |panic_message_builder| {
+                $crate::assertions::string::configure_ascii_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<str>::as_ref(value),
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+}
+
+/// A Unicode normalization form for [`assert_str_normalized`](crate::assert_str_normalized).
+///
+/// See [Unicode Standard Annex #15](https://www.unicode.org/reports/tr15/) for what each form
+/// means.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition.
+    Nfc,
+
+    /// Canonical decomposition.
+    Nfd,
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl NormalizationForm {
+    /// Normalizes a string into this form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::assertions::string::NormalizationForm;
+    /// #
+    /// assert_eq!(NormalizationForm::Nfc.normalize("e\u{301}"), "\u{e9}");
+    /// assert_eq!(NormalizationForm::Nfd.normalize("\u{e9}"), "e\u{301}");
+    /// ```
+    #[must_use]
+    pub fn normalize(self, value: &str) -> String {
+        match self {
+            Self::Nfc => value.nfc().collect(),
+            Self::Nfd => value.nfd().collect(),
+        }
+    }
+
+    /// Checks whether a string is already in this form.
+    #[must_use]
+    pub fn is_normalized(self, value: &str) -> bool {
+        match self {
+            Self::Nfc => is_nfc(value),
+            Self::Nfd => is_nfd(value),
+        }
+    }
+}
+
+#[cfg(feature = "unicode-normalization")]
+impl Display for NormalizationForm {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nfc => write!(formatter, "NFC"),
+            Self::Nfd => write!(formatter, "NFD"),
+        }
+    }
+}
+
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[cfg(feature = "unicode-normalization")]
+#[must_use]
+pub fn assert_str_normalized_impl(value: impl AsRef<str>, form: NormalizationForm) -> bool {
+    form.is_normalized(value.as_ref())
+}
+
+/// Configures a panic message builder for [`assert_str_normalized`](crate::assert_str_normalized)
+/// to show the normalized string and where it first differs from the value.
+///
+/// # Arguments
+///
+/// * `panic_message_builder` - The panic message builder to configure
+/// * `value_description` - The description of the value being checked
+/// * `value` - The value being checked
+/// * `form` - The normalization form the value is expected to be in
+//
+// Assertion implementations need to be public for the macros to use them, but should not appear in
+// documentation.
+#[doc(hidden)]
+#[cfg(feature = "unicode-normalization")]
+pub fn configure_normalized_panic_message(
+    panic_message_builder: PanicMessageBuilder,
+    value_description: &str,
+    value: &str,
+    form: NormalizationForm,
+) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+    let normalized = form.normalize(value);
+
+    let panic_message_builder = panic_message_builder
+        .with_argument("value", value_description, &value)?
+        .with_argument("normalized value", "--", &normalized)?;
+
+    if normalized == value {
+        return Ok(panic_message_builder);
+    }
+
+    let first_difference_offset = value
+        .char_indices()
+        .zip(normalized.chars())
+        .find(|((_, value_character), normalized_character)| {
+            value_character != normalized_character
+        })
+        .map_or(value.len(), |((offset, _), _)| offset);
+
+    panic_message_builder.with_argument_formatted(
+        "first difference",
+        "--",
+        format!("byte offset {first_difference_offset}"),
+    )
+}
+
+/// Asserts that a string is in a Unicode normalization form.
+///
+/// The panic message shows the normalized string with non-printable characters like combining
+/// marks escaped, along with the byte offset where it first differs from the value.
+///
+/// See
+/// [sophie-katz.github.io/test-ur-code-XD/assertions/string](https://sophie-katz.github.io/test-ur-code-XD/assertions/string/)
+/// for a usage guide.
+///
+/// # Arguments
+///
+/// * `value` - The string to check.
+/// * Optional: `form = <NormalizationForm>` - The
+///   [`NormalizationForm`](crate::assertions::string::NormalizationForm) to check for (defaults to
+///   NFC).
+/// * Optional keyword arguments for assertions.
+///
+/// **Note:** `form` must come before any other keyword arguments.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_str_normalized, assertions::string::NormalizationForm};
+/// #
+/// assert_str_normalized!("caf\u{e9}");
+///
+/// assert_str_normalized!("cafe\u{301}", form = NormalizationForm::Nfd);
+///
+/// assert_str_normalized!("cafe\u{301}", negate = true);
+/// ```
+#[cfg(feature = "unicode-normalization")]
+#[macro_export]
+macro_rules! assert_str_normalized {
+    ($value:expr, form = $form:expr $(, $keys:ident = $values:expr)* $(,)?) => {{
+        let value = &$value;
+        let form: $crate::assertions::string::NormalizationForm = $form;
+
+        $crate::assert_custom!(
+            ::std::format!("value is {} normalized", form),
+            $crate::assertions::string::assert_str_normalized_impl(value, form),
+            |panic_message_builder| {
+                $crate::assertions::string::configure_normalized_panic_message(
+                    panic_message_builder,
+                    stringify!($value),
+                    ::std::convert::AsRef::<str>::as_ref(value),
+                    form,
+                )
+            }
+            $(, $keys = $values)*
+        )
+    }};
+
+    ($value:expr $(, $keys:ident = $values:expr)* $(,)?) => {
+        $crate::assert_str_normalized!(
+            $value,
+            form = $crate::assertions::string::NormalizationForm::Nfc
+            $(, $keys = $values)*
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str;
+
+    use super::split_lines;
+    #[cfg(feature = "unicode-normalization")]
+    use super::NormalizationForm;
+    use crate::utilities::assertion_failure::capture_assertion_failure;
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing() {
+        assert_str_eq!("hello, world", "hello, world");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing_empty() {
+        assert_str_eq!("", "");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_empty_some() {
+        assert_str_eq!("", "asdf");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_some_empty() {
+        assert_str_eq!("asdf", "");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_totally_different() {
+        assert_str_eq!("hello, world", "asdf");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_slightly_different() {
+        assert_str_eq!("hello, world", "hello! world");
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_long() {
+        assert_str_eq!("a".repeat(100), "b".repeat(100));
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_multiline() {
+        assert_str_eq!("asdf\nasdf", "asdf\nfdsa");
+    }
+
+    #[test]
+    fn normalize_str_unchanged() {
+        crate::assert_eq!(
+            super::normalize_str(" Hello,\n World ", false, false),
+            " Hello,\n World "
+        );
+    }
+
+    #[test]
+    fn normalize_str_ignore_case() {
+        crate::assert_eq!(super::normalize_str("HeLLo", true, false), "hello");
+    }
+
+    #[test]
+    fn normalize_str_normalize_whitespace() {
+        crate::assert_eq!(
+            super::normalize_str("\t Hello,\n\n   World \n", false, true),
+            "Hello, World"
+        );
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing_ignore_case() {
+        assert_str_eq!("Hello, World", "hello, world", ignore_case = true);
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn assert_str_eq_failing_ignore_case() {
+        assert_str_eq!("Hello, World", "hello,  world", ignore_case = true);
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing_normalize_whitespace() {
+        assert_str_eq!(
+            "hello,\n  world\n",
+            "hello, world",
+            normalize_whitespace = true
+        );
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing_both_either_order() {
+        assert_str_eq!(
+            " Hello,\tWorld",
+            "hello, world",
+            ignore_case = true,
+            normalize_whitespace = true
+        );
+
+        assert_str_eq!(
+            " Hello,\tWorld",
+            "hello, world",
+            normalize_whitespace = true,
+            ignore_case = true
+        );
+    }
+
+    #[cfg(feature = "string-diff")]
+    #[test]
+    fn assert_str_eq_passing_normalize_whitespace_negate() {
+        assert_str_eq!(
+            "hello, world",
+            "hello,world",
+            normalize_whitespace = true,
+            negate = true
+        );
+    }
+
+    #[test]
+    fn assert_str_contains_passing() {
+        assert_str_contains!("hello, world", "hello");
+    }
+
+    #[test]
+    fn assert_str_contains_passing_empty() {
+        assert_str_contains!("hello, world", "");
+    }
+
+    #[test]
+    fn assert_str_contains_passing_empty_both_empty() {
+        assert_str_contains!("", "");
+    }
+
+    #[test]
+    #[should_panic(expected = "value contains substring")]
+    fn assert_str_contains_failing() {
+        assert_str_contains!("hello, world", "asdf");
+    }
+
+    #[test]
+    #[should_panic(expected = "value contains substring")]
+    fn assert_str_contains_failing_empty() {
+        assert_str_contains!("", "asdf");
+    }
+
+    #[test]
     fn assert_str_starts_with_passing() {
         assert_str_starts_with!("hello, world", "hello");
     }
@@ -432,4 +1562,350 @@ mod tests {
     fn assert_str_matches_failing_bad_regex() {
         assert_str_matches!("hello, world", "[a-z, ");
     }
+
+    #[test]
+    fn assert_is_utf8_passing() {
+        assert_is_utf8!(b"hello, world");
+        assert_is_utf8!("h\u{e9}llo".as_bytes().to_vec());
+        assert_is_utf8!([0_u8; 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "value is valid UTF-8")]
+    fn assert_is_utf8_failing() {
+        assert_is_utf8!(vec![0x68, 0x69, 0xff]);
+    }
+
+    #[test]
+    fn assert_is_utf8_passing_negate() {
+        assert_is_utf8!([0xc3], negate = true);
+    }
+
+    #[test]
+    fn format_utf8_error_context_middle() {
+        let value: Vec<u8> = b"0123456789\xffabcdefghij".to_vec();
+        let error = str::from_utf8(&value).unwrap_err();
+
+        crate::assert_eq!(
+            super::format_utf8_error_context(&value, &error),
+            "... 32 33 34 35 36 37 38 39 [ff] 61 62 63 64 65 66 67 68 ..."
+        );
+    }
+
+    #[test]
+    fn format_utf8_error_context_incomplete_at_end() {
+        let value: Vec<u8> = b"hi\xe2\x82".to_vec();
+        let error = str::from_utf8(&value).unwrap_err();
+
+        crate::assert_eq!(
+            super::format_utf8_error_context(&value, &error),
+            "68 69 [e2 82]"
+        );
+    }
+
+    #[test]
+    fn assert_is_utf8_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_is_utf8!(b"hi\xffthere");
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("error").unwrap().value,
+            "invalid utf-8 sequence of 1 bytes from index 2"
+        );
+        crate::assert_eq!(
+            failure.argument("bytes").unwrap().value,
+            "68 69 [ff] 74 68 65 72 65"
+        );
+    }
+
+    #[test]
+    fn assert_str_is_ascii_passing() {
+        assert_str_is_ascii!("hello, world");
+        assert_str_is_ascii!(String::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "value is ASCII")]
+    fn assert_str_is_ascii_failing() {
+        assert_str_is_ascii!("caf\u{e9}");
+    }
+
+    #[test]
+    fn assert_str_is_ascii_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_is_ascii!("na\u{ef}ve");
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("first non-ASCII character").unwrap().value,
+            "'\u{ef}' (U+00EF) at byte offset 2"
+        );
+    }
+
+    #[test]
+    fn split_lines_mixed_line_endings() {
+        crate::assert_eq!(split_lines("a\nb\r\nc\rd"), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn split_lines_trailing_line_ending() {
+        crate::assert_eq!(split_lines("a\r\n"), ["a"]);
+        crate::assert_eq!(split_lines("a\r"), ["a"]);
+        crate::assert_eq!(split_lines("a\n\n"), ["a", ""]);
+    }
+
+    #[test]
+    fn split_lines_empty() {
+        crate::assert!(split_lines("").is_empty());
+        crate::assert_eq!(split_lines("\n"), [""]);
+    }
+
+    #[test]
+    fn split_lines_carriage_return_then_line_feed_are_one_line_ending() {
+        crate::assert_eq!(split_lines("a\r\n\r\nb"), ["a", "", "b"]);
+        crate::assert_eq!(split_lines("a\n\rb"), ["a", "", "b"]);
+    }
+
+    #[test]
+    fn assert_lines_eq_passing() {
+        assert_lines_eq!("hello\nworld", "hello\nworld");
+        assert_lines_eq!("hello\r\nworld\r\n", "hello\nworld");
+        assert_lines_eq!(String::from("hello\rworld"), "hello\r\nworld\n");
+        assert_lines_eq!("", "");
+    }
+
+    #[test]
+    fn assert_lines_eq_passing_trim_trailing() {
+        assert_lines_eq!("hello  \r\nworld\t\n", "hello\nworld", trim_trailing = true);
+    }
+
+    #[test]
+    #[should_panic(expected = "actual lines == expected lines")]
+    fn assert_lines_eq_failing_trailing_whitespace() {
+        assert_lines_eq!("hello  \nworld", "hello\nworld");
+    }
+
+    #[test]
+    #[should_panic(expected = "actual lines == expected lines")]
+    fn assert_lines_eq_failing_leading_whitespace_with_trim_trailing() {
+        assert_lines_eq!("  hello\nworld", "hello\nworld", trim_trailing = true);
+    }
+
+    #[test]
+    fn assert_lines_eq_passing_negate() {
+        assert_lines_eq!("hello\nworld", "hello\nthere", negate = true);
+    }
+
+    #[test]
+    fn assert_lines_eq_passing_trim_trailing_negate() {
+        assert_lines_eq!(
+            "hello\nworld",
+            "hello\nthere",
+            trim_trailing = true,
+            negate = true
+        );
+    }
+
+    #[test]
+    fn assert_lines_eq_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_lines_eq!("a\r\nb\r\nc  \r\n", "a\nb\nc\n");
+        })
+        .unwrap();
+
+        crate::assert_eq!(failure.predicate, "actual lines == expected lines");
+        crate::assert_eq!(failure.argument("first differing line").unwrap().value, "3");
+        crate::assert_eq!(failure.argument("actual line").unwrap().value, "\"c  \"");
+        crate::assert_eq!(failure.argument("expected line").unwrap().value, "\"c\"");
+    }
+
+    #[test]
+    fn assert_lines_eq_failing_arguments_missing_line() {
+        let failure = capture_assertion_failure(|| {
+            assert_lines_eq!("a\nb\nc", "a\nb");
+        })
+        .unwrap();
+
+        crate::assert_eq!(failure.argument("first differing line").unwrap().value, "3");
+        crate::assert_eq!(failure.argument("actual line").unwrap().value, "\"c\"");
+        crate::assert_eq!(
+            failure.argument("expected line").unwrap().value,
+            "none (only 2 lines)"
+        );
+    }
+
+    #[test]
+    fn assert_lines_eq_failing_arguments_missing_single_line() {
+        let failure = capture_assertion_failure(|| {
+            assert_lines_eq!("a", "a\nb");
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("actual line").unwrap().value,
+            "none (only 1 line)"
+        );
+    }
+
+    #[test]
+    fn assert_str_count_eq_passing() {
+        assert_str_count_eq!("a, b, c", ", ", 2);
+        assert_str_count_eq!(String::from("hello, world"), 'l', 3);
+        assert_str_count_eq!("hello", String::from("x"), 0);
+    }
+
+    #[test]
+    fn assert_str_count_eq_passing_non_overlapping() {
+        assert_str_count_eq!("aaaa", "aa", 2);
+    }
+
+    #[test]
+    fn assert_str_count_eq_passing_temporary() {
+        assert_str_count_eq!(format!("{}-{}", 1, 2), '-', 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "occurrence count == count")]
+    fn assert_str_count_eq_failing() {
+        assert_str_count_eq!("a, b, c", ", ", 3);
+    }
+
+    #[test]
+    fn assert_str_count_eq_passing_negate() {
+        assert_str_count_eq!("a, b, c", ", ", 3, negate = true);
+    }
+
+    #[test]
+    fn assert_str_count_eq_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_count_eq!("error: a\nok\nerror: b\n", "error", 1);
+        })
+        .unwrap();
+
+        crate::assert_eq!(failure.predicate, "occurrence count == count");
+        crate::assert_eq!(failure.argument("needle").unwrap().value, "\"error\"");
+        crate::assert_eq!(failure.argument("count").unwrap().value, "1");
+        crate::assert_eq!(
+            failure.argument("occurrences").unwrap().value,
+            "2 at byte offsets 0, 12"
+        );
+    }
+
+    #[test]
+    fn assert_str_count_eq_failing_arguments_single_occurrence() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_count_eq!("hello", 'e', 2);
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("occurrences").unwrap().value,
+            "1 at byte offset 1"
+        );
+    }
+
+    #[test]
+    fn assert_str_count_eq_failing_arguments_no_occurrences() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_count_eq!("hello", 'x', 1);
+        })
+        .unwrap();
+
+        crate::assert_eq!(failure.argument("occurrences").unwrap().value, "none");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_str_count_eq_passing_regex() {
+        let pattern = regex::Regex::new(r"\d+").unwrap();
+
+        assert_str_count_eq!("1 + 22 = 23", pattern, 3);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn assert_str_count_eq_failing_arguments_regex() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_count_eq!("1 + 22 = 23", regex::Regex::new(r"\d{2}").unwrap(), 1);
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("occurrences").unwrap().value,
+            "2 at byte offsets 4, 9"
+        );
+    }
+
+    #[test]
+    fn assert_str_count_ge_passing() {
+        assert_str_count_ge!("a, b, c", ", ", 1);
+        assert_str_count_ge!("a, b, c", ", ", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "occurrence count >= count")]
+    fn assert_str_count_ge_failing() {
+        assert_str_count_ge!("a, b, c", ", ", 3);
+    }
+
+    #[test]
+    fn assert_str_count_le_passing() {
+        assert_str_count_le!("a, b, c", ", ", 2);
+        assert_str_count_le!("a, b, c", ", ", 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "occurrence count <= count")]
+    fn assert_str_count_le_failing() {
+        assert_str_count_le!("a, b, c", ", ", 1);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn assert_str_normalized_passing() {
+        assert_str_normalized!("caf\u{e9}");
+        assert_str_normalized!("cafe\u{301}", form = NormalizationForm::Nfd);
+        assert_str_normalized!("plain ascii".to_owned(), form = NormalizationForm::Nfd);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    #[should_panic(expected = "value is NFC normalized")]
+    fn assert_str_normalized_failing_nfc() {
+        assert_str_normalized!("cafe\u{301}");
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    #[should_panic(expected = "value is NFD normalized")]
+    fn assert_str_normalized_failing_nfd() {
+        assert_str_normalized!("caf\u{e9}", form = NormalizationForm::Nfd);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn assert_str_normalized_passing_negate() {
+        assert_str_normalized!("cafe\u{301}", negate = true);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn assert_str_normalized_failing_arguments() {
+        let failure = capture_assertion_failure(|| {
+            assert_str_normalized!("cafe\u{301}!");
+        })
+        .unwrap();
+
+        crate::assert_eq!(
+            failure.argument("normalized value").unwrap().value,
+            "\"caf\u{e9}!\""
+        );
+        crate::assert_eq!(
+            failure.argument("first difference").unwrap().value,
+            "byte offset 3"
+        );
+    }
 }
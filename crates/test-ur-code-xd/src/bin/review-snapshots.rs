@@ -0,0 +1,63 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Reviews pending snapshots interactively, accepting or rejecting each one.
+//!
+//! # How to run
+//!
+//! From the crate whose snapshots should be reviewed:
+//!
+//! ```shell
+//! cargo run -p test-ur-code-xd --bin review-snapshots
+//! ```
+//!
+//! The snapshot directory defaults to `tests/snapshots` and can be given as an argument instead.
+
+use std::{
+    env,
+    io::{self, Write},
+    process::ExitCode,
+};
+
+use test_ur_code_xd::assertions::snapshot::{review_pending_snapshots, SNAPSHOT_DIRECTORY};
+
+fn main() -> ExitCode {
+    let directory = env::args_os()
+        .nth(1)
+        .unwrap_or_else(|| SNAPSHOT_DIRECTORY.into());
+
+    let mut stdout = io::stdout();
+
+    let result =
+        review_pending_snapshots(&directory, io::stdin().lock(), &mut stdout).and_then(|review| {
+            writeln!(
+                stdout,
+                "{} accepted, {} rejected, {} skipped",
+                review.accepted.len(),
+                review.rejected.len(),
+                review.skipped.len()
+            )
+        });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            // There is nowhere else to report the error
+            let _ignored = writeln!(io::stderr(), "error reviewing snapshots: {error}");
+
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -27,19 +27,52 @@
 //!
 //! * [`assert`] - Asserts that a boolean is true.
 //! * [`assert_not`] - Asserts that a boolean is false.
+//! * [`assert_all_of`] - Asserts that every one of several booleans is true.
+//! * [`assert_any_of`] - Asserts that at least one of several booleans is true.
 //! * [`assert_eq`] - Asserts that two expressions are equal.
 //! * [`assert_ne`] - Asserts that two expressions are unequal.
 //! * [`assert_lt`] - Asserts that the first expression is less than the second expression.
 //! * [`assert_le`] - Asserts that the first expression is less than or equal to the second.
 //! * [`assert_gt`] - Asserts that the first expression is greater than the second expression.
 //! * [`assert_ge`] - Asserts that the first expression is greater than or equal to the second.
+//! * [`assert_between`] - Asserts that a value is between two bounds.
+//! * [`assert_in_range`] - Asserts that a value is contained in a range.
+//! * [`assert_ordered`] - Asserts that a chain of comparisons like `a < b <= c` all hold.
+//! * [`assert_abs_diff_le`] - Asserts that two integers are within a maximum absolute difference.
+//!
+//! ## Matchers
+//!
+//! [`assert_that`] checks a value against a [`Matcher`](assertions::matcher::Matcher). There is a
+//! starter set of matchers in [`assertions::matcher`], and other crates can implement their own.
+//!
+//! ## Soft assertions
+//!
+//! [`SoftAssertions`](assertions::soft::SoftAssertions) collects failed checks during a test and
+//! panics once at the end with every failure listed.
+//!
+//! ## Pattern assertions
+//!
+//! * [`assert_matches`] - Asserts that a value matches a pattern.
+//! * [`assert_let`] - Asserts that a value matches a pattern and binds its variables.
 //!
 //! ## String assertions
 //!
+//! * [`assert_lines_eq`] - Asserts that two strings have the same lines, ignoring differences in
+//!                         line endings.
 //! * [`assert_str_contains`] - Asserts that a string contains a substring.
 //! * [`assert_str_starts_with`] - Asserts that a string starts with a substring.
 //! * [`assert_str_ends_with`] - Asserts that a string ends with a substring.
 //! * [`assert_str_matches`] - Asserts that a string matches a regular expression.
+//! * [`assert_str_count_eq`] - Asserts that a substring occurs in a string a number of times.
+//! * [`assert_str_count_ge`] - Asserts that a substring occurs in a string at least a number of
+//!                             times.
+//! * [`assert_str_count_le`] - Asserts that a substring occurs in a string at most a number of
+//!                             times.
+//! * [`assert_str_is_ascii`] - Asserts that a string only contains ASCII characters.
+//! * [`assert_is_utf8`] - Asserts that a sequence of bytes is valid UTF-8.
+//!
+//! With the `unicode-normalization` feature enabled, `assert_str_normalized` asserts that a string
+//! is in a Unicode normalization form.
 //!
 //! ## Panic assertions
 //!
@@ -61,6 +94,8 @@
 //! // This code also runs normally
 //! ```
 //!
+//! [`assert_no_panic_on_drop`] checks that a value's destructor does not panic.
+//!
 //! ## Output assertions for `stdout` and `stderr`
 //!
 //! This assertion allows you to write custom assertions for `stdout` and `stderr`:
@@ -80,6 +115,83 @@
 //! });
 //! ```
 //!
+//! ## Process assertions
+//!
+//! [`assert_command`] runs a command and allows you to write custom assertions for its exit status,
+//! `stdout`, and `stderr`. [`assert_command_snapshot`] records all three into a snapshot instead.
+//!
+//! ## Snapshot assertions
+//!
+//! [`assert_snapshot`] compares text against a snapshot file stored alongside the tests. Set the
+//! `UPDATE` environment variable to `1` to rewrite mismatched snapshots and expected files instead of
+//! failing.
+//!
+//! ## Polling assertions
+//!
+//! * [`assert_eventually`] - Asserts that a condition eventually holds.
+//! * [`assert_file_eventually_contains`] - Asserts that a file eventually contains a substring.
+//! * [`assert_recv_within`] - Asserts that a value is received from a channel within a timeout.
+//! * [`assert_lock_acquired_within`] - Asserts that a mutex can be locked within a timeout.
+//! * [`assert_completes_within`] - Asserts that a closure completes within a timeout.
+//!
+//! ## Result assertions
+//!
+//! [`assert_all_ok`] asserts that every result in an iterable is `Ok`, listing every error if not.
+//!
+//! ## Field assertions
+//!
+//! [`assert_fields_eq`] asserts that selected fields of two values are equal, ignoring all other
+//! fields. Each differing field is shown in the panic message.
+//!
+//! ## Map assertions
+//!
+//! * [`assert_map_values_all`] - Asserts that every value in a map satisfies a predicate.
+//! * [`assert_map_keys_all`] - Asserts that every key in a map satisfies a predicate.
+//! * [`assert_map_eq`] - Asserts that two maps are equal, listing the differences key by key.
+//!
+//! Every failing entry is listed in the panic message.
+//!
+//! ## Set assertions
+//!
+//! * [`assert_subset_of`] - Asserts that every element of a set is in another set.
+//! * [`assert_superset_of`] - Asserts that a set contains every element of another set.
+//! * [`assert_disjoint`] - Asserts that two sets have no elements in common.
+//!
+//! Every element that violates the relation is listed in the panic message.
+//!
+//! ## Sequence assertions
+//!
+//! * [`assert_contains_in_order`] - Asserts that items appear in a sequence in order, but not
+//!                                  necessarily adjacent to each other.
+//! * [`assert_sorted`] - Asserts that items are sorted.
+//! * [`assert_sorted_by_key`] - Asserts that items are sorted by a key.
+//!
+//! ## Future assertions
+//!
+//! * [`assert_send_across_await`] - Asserts that a future is `Send`, which catches values like an
+//!                                  `Rc` being held across an `.await` by accident.
+//! * [`assert_ready`] - Asserts that a future is ready after being polled once.
+//! * [`assert_pending`] - Asserts that a future is pending after being polled once.
+//! * [`assert_resolves_within`] - Asserts that a future resolves within a timeout, without needing
+//!                                an async runtime.
+//!
+//! ## Allocation assertions
+//!
+//! With the `allocation` feature enabled, `assert_no_alloc` asserts that a closure makes no heap
+//! allocations and `assert_allocates_at_most` asserts that it allocates at most a number of bytes.
+//!
+//! ## HTTP assertions
+//!
+//! With the `http` feature enabled, `assert_status_eq`, `assert_header_eq`, and
+//! `assert_body_json_matches` check responses from the [`http`](https://crates.io/crates/http)
+//! crate. Failure messages show the status, headers, and body of the response.
+//!
+//! ## Semantic version assertions
+//!
+//! With the `semver` feature enabled, `assert_semver_matches` asserts that a version matches a
+//! requirement like `"^1.2"` and `assert_semver_lt` asserts that one version is lower than another.
+//! Failure messages show the parsed components of each version.
+//!
 //! ## Filesystem assertions
 //!
 //! There are some assertions for simple filesystem checks:
@@ -93,11 +205,32 @@
 //! * [`assert_path_is_dir`] - Asserts that a path is a directory.
 //! * [`assert_path_starts_with`] - Asserts that a path starts with a prefix.
 //! * [`assert_path_ends_with`] - Asserts that a path ends with a suffix.
+//! * [`assert_path_has_extension`] - Asserts that a path has an extension.
+//!
+//! There are assertions about file metadata:
+//!
+//! * [`assert_path_is_readonly`] - Asserts that a path is read-only.
+//! * [`assert_file_size_eq`] - Asserts that the size of a file is equal to a value.
+//! * [`assert_file_size_le`] - Asserts that the size of a file is at most a value.
+//! * [`assert_file_modified_within`] - Asserts that a file was modified recently.
 //!
 //! And there are also assertions about file contents:
 //!
 //! * [`assert_file_text_eq`] - Asserts that the contents of a file are equal to a string.
 //! * [`assert_file_text_matches`] - Asserts that the contents of a file match a regular expression.
+//! * [`assert_write_then_read_eq`] - Asserts that a file contains the expected contents after
+//!                                   writing it.
+//! * [`assert_files_eq`] - Asserts that the contents of two files are equal.
+//!
+//! And there are assertions about what files code creates:
+//!
+//! * [`assert_creates_files`] - Asserts that running a closure creates some files and not others.
+//!
+//! ## JSON assertions
+//!
+//! With the `json` feature enabled, `assert_json_eq` asserts that two values are equal when
+//! serialized as JSON and `assert_json_contains` asserts that a value contains another when
+//! serialized as JSON.
 //!
 //! ## Floating-point assertions
 //!
@@ -121,10 +254,33 @@
 //! * [`assert_f32_ne`] - Asserts that two `f32` values are unequal.
 //! * [`assert_f32_le`] - Asserts that the first `f32` value is less than or equal to the second.
 //! * [`assert_f32_ge`] - Asserts that the first `f32` value is greater than or equal to the second.
+//! * [`assert_f32_lt`] - Asserts that the first `f32` value is strictly less than the second.
+//! * [`assert_f32_gt`] - Asserts that the first `f32` value is strictly greater than the second.
 //! * [`assert_f64_eq`] - Asserts that two `f64` values are equal.
 //! * [`assert_f64_ne`] - Asserts that two `f64` values are unequal.
 //! * [`assert_f64_le`] - Asserts that the first `f64` value is less than or equal to the second.
 //! * [`assert_f64_ge`] - Asserts that the first `f64` value is greater than or equal to the second.
+//! * [`assert_f64_lt`] - Asserts that the first `f64` value is strictly less than the second.
+//! * [`assert_f64_gt`] - Asserts that the first `f64` value is strictly greater than the second.
+//! * [`assert_float_eq`] - Asserts that two values of any float type implementing `FloatUlps`
+//!                         are equal.
+//! * [`assert_float_ne`] - Asserts that two values of any float type implementing `FloatUlps`
+//!                         are unequal.
+//! * [`assert_finite`] - Asserts that a float value is neither infinite nor NaN.
+//! * [`assert_nan`] - Asserts that a float value is NaN.
+//! * [`assert_infinite`] - Asserts that a float value is positive or negative infinity.
+//! * [`assert_normal`] - Asserts that a float value is neither zero, subnormal, infinite, nor NaN.
+//! * [`assert_f32_slice_eq`] - Asserts that two `f32` slices are equal element-wise.
+//! * [`assert_f64_slice_eq`] - Asserts that two `f64` slices are equal element-wise.
+//! * [`assert_f32_grid_eq`] - Asserts that two grids of `f32` values are equal element-wise.
+//! * [`assert_f64_grid_eq`] - Asserts that two grids of `f64` values are equal element-wise.
+//! * [`assert_points_close`] - Asserts that two slices of points are close to each other.
+//! * [`assert_approx_eq`] - Asserts that two values of any type implementing
+//!                          [`ApproxEq`](assertions::approx::ApproxEq) are equal within a
+//!                          tolerance.
+//!
+//! With the `half` feature enabled, `assert_float_eq` and `assert_float_ne` also work with
+//! `half::f16` values.
 //!
 //! # Parameterized tests
 //!
@@ -147,9 +303,55 @@
 //!   //   x == 7, y == 2
 //! }
 //! ```
+//!
+//! # Retries
+//!
+//! [`test_with_retries`] re-runs a failing test a number of times before giving up, which is useful
+//! for tests that touch real networks or timing-sensitive code.
+//!
+//! # Timeouts
+//!
+//! [`test_with_timeout`] fails a test if it runs for longer than a time limit.
+//!
+//! # Setup and teardown
+//!
+//! [`test_with_setup`] runs a test between a setup function and a teardown function, and
+//! [`test_suite`] does the same for every test in a module. Teardown runs even if the test panics.
+//!
+//! # Subtests
+//!
+//! [`subtest`] breaks a single test up into named sections whose names are reported when an
+//! assertion within them fails.
+//!
+//! # Assertion context
+//!
+//! [`assertion_context`] attaches key/value context, like the ID of the record being checked, to
+//! any assertion that fails within a block. [`assert_for_each`] runs assertions for each item of an
+//! iterable and attaches the index and item to any failure.
+//!
+//! # Colors
+//!
+//! [`output::set_color_choice`] sets whether failure messages are colored for the whole process,
+//! without changing the colors of other crates that use `console`.
+//!
+//! # Test runner
+//!
+//! With the `harness` feature enabled, `runner::run` runs tests that are built at runtime in a
+//! test target with `harness = false`. Each test can have a timeout and retries, and the results
+//! can be printed as JSON.
 
 pub mod assertions;
 pub mod errors;
+pub mod output;
 pub mod utilities;
 
+#[cfg(feature = "harness")]
+pub mod runner;
+
+pub use test_ur_code_xd_macro::test_suite;
 pub use test_ur_code_xd_macro::test_with_parameter_values;
+pub use test_ur_code_xd_macro::test_with_random_values;
+pub use test_ur_code_xd_macro::test_with_retries;
+pub use test_ur_code_xd_macro::test_with_seed;
+pub use test_ur_code_xd_macro::test_with_setup;
+pub use test_ur_code_xd_macro::test_with_timeout;
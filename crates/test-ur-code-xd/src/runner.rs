@@ -0,0 +1,705 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! A test runner for test targets with `harness = false`.
+//!
+//! The default test harness only knows about `#[test]` functions. This runner takes a list of
+//! [`Test`] values instead, which can be generated at runtime and can each have a timeout, retries,
+//! or be ignored. It accepts the most commonly used arguments of the default test harness, and can
+//! print its results as JSON with `--format json`.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/tests/runner](https://sophie-katz.github.io/test-ur-code-XD/tests/runner/)
+//! for a usage guide.
+
+mod arguments;
+mod errors;
+
+use std::{
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::utilities::{
+    color_choice::style, format::format_duration, retries::run_with_retries,
+    timeout::run_with_timeout,
+};
+
+#[cfg(feature = "report")]
+use crate::utilities::report::{record_report_entry, ReportEntry, ReportOutcome};
+
+pub use arguments::{Arguments, OutputFormat};
+pub use errors::ArgumentsError;
+
+/// The status code to exit with when any tests fail, which is the same as the default test harness.
+pub const FAILURE_EXIT_CODE: i32 = 101;
+
+/// A test body that can be shared between the runner and the thread that runs it.
+type TestAction = Arc<dyn Fn() + Send + Sync + 'static>;
+
+/// A test for the runner.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, runner::Test};
+/// use std::time::Duration;
+///
+/// let test = Test::new("addition", || {
+///     assert_eq!(1 + 1, 2);
+/// })
+/// .with_timeout(Duration::from_secs(5))
+/// .with_retries(2, Duration::ZERO);
+/// ```
+pub struct Test {
+    /// The name of the test.
+    name: String,
+
+    /// The test body.
+    action: TestAction,
+
+    /// The maximum amount of time that the test body can run for.
+    timeout: Option<Duration>,
+
+    /// The maximum number of times to re-run the test body after the first attempt.
+    retry_count: usize,
+
+    /// The amount of time to wait before each retry.
+    retry_delay: Duration,
+
+    /// Whether the test is ignored unless `--ignored` or `--include-ignored` is passed.
+    is_ignored: bool,
+}
+
+impl Test {
+    /// Creates a new test.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the test. This is what filters are matched against.
+    /// * `action` - The test body. The test fails if it panics.
+    pub fn new(name: impl Into<String>, action: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            name: name.into(),
+            action: Arc::new(action),
+            timeout: None,
+            retry_count: 0,
+            retry_delay: Duration::ZERO,
+            is_ignored: false,
+        }
+    }
+
+    /// Creates one test for each value, named like the tests generated by
+    /// `#[test_with_parameter_values]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The base name of the tests. Each test is named `<name>_<index>`.
+    /// * `values` - The values to run the test body with.
+    /// * `action` - The test body, which takes a reference to the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::{assert, runner::Test};
+    /// #
+    /// let tests = Test::parameterized("is_positive", [1, 2, 3], |value| {
+    ///     assert!(*value > 0);
+    /// });
+    ///
+    /// assert_eq!(tests[1].name(), "is_positive_1");
+    /// ```
+    pub fn parameterized<ValueType: Send + Sync + 'static>(
+        name: &str,
+        values: impl IntoIterator<Item = ValueType>,
+        action: impl Fn(&ValueType) + Send + Sync + 'static,
+    ) -> Vec<Self> {
+        let action = Arc::new(action);
+
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let action = Arc::clone(&action);
+
+                Self::new(format!("{name}_{index}"), move || action(&value))
+            })
+            .collect()
+    }
+
+    /// Fails the test if the test body does not finish within a time limit. See
+    /// [`run_with_timeout`].
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - The maximum amount of time that the test body can run for.
+    #[must_use]
+    pub const fn with_timeout(mut self, limit: Duration) -> Self {
+        self.timeout = Some(limit);
+
+        self
+    }
+
+    /// Re-runs the test body if it fails. See [`run_with_retries`].
+    ///
+    /// When combined with a timeout, each attempt has its own time limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_count` - The maximum number of times to re-run the test body after the first
+    ///                   attempt.
+    /// * `delay` - The amount of time to wait before each retry.
+    #[must_use]
+    pub const fn with_retries(mut self, retry_count: usize, delay: Duration) -> Self {
+        self.retry_count = retry_count;
+        self.retry_delay = delay;
+
+        self
+    }
+
+    /// Sets whether the test is ignored unless `--ignored` or `--include-ignored` is passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_ignored` - Whether the test is ignored.
+    #[must_use]
+    pub const fn with_ignored(mut self, is_ignored: bool) -> Self {
+        self.is_ignored = is_ignored;
+
+        self
+    }
+
+    /// Gets the name of the test.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Runs the test body on a thread named after the test.
+    ///
+    /// # Returns
+    ///
+    /// Whether the test passed.
+    //
+    // Stderr printing is allowed because there is nowhere else to report the error.
+    #[allow(clippy::print_stderr)]
+    fn run(&self) -> bool {
+        let action = Arc::clone(&self.action);
+        let timeout = self.timeout;
+        let retry_count = self.retry_count;
+        let retry_delay = self.retry_delay;
+
+        let handle = thread::Builder::new()
+            .name(self.name.clone())
+            .spawn(move || {
+                run_with_retries(retry_count, retry_delay, || match timeout {
+                    Some(limit) => {
+                        let action = Arc::clone(&action);
+
+                        run_with_timeout(limit, move || action());
+                    }
+                    None => action(),
+                });
+            });
+
+        match handle {
+            Ok(handle) => handle.join().is_ok(),
+            Err(error) => {
+                eprintln!(
+                    "error: unable to spawn thread for test {}: {error}",
+                    self.name
+                );
+
+                false
+            }
+        }
+    }
+}
+
+/// What happened to a single test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TestOutcome {
+    /// The test body finished without panicking.
+    Passed,
+
+    /// The test body panicked.
+    Failed,
+
+    /// The test was not run because it is ignored.
+    Ignored,
+}
+
+/// The results of a test run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct Conclusion {
+    /// The number of tests that passed.
+    pub passed: usize,
+
+    /// The number of tests that failed.
+    pub failed: usize,
+
+    /// The number of tests that were ignored.
+    pub ignored: usize,
+
+    /// The number of tests that were not selected by the filters.
+    pub filtered_out: usize,
+
+    /// The names of the tests that failed, in the order they were given to the runner.
+    pub failures: Vec<String>,
+}
+
+impl Conclusion {
+    /// Checks whether any tests failed.
+    #[must_use]
+    pub const fn has_failed(&self) -> bool {
+        self.failed > 0
+    }
+
+    /// Gets the status code to exit the process with.
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        if self.has_failed() {
+            FAILURE_EXIT_CODE
+        } else {
+            0
+        }
+    }
+
+    /// Exits the process with the status code for the results.
+    //
+    // Exiting is the point of this function.
+    #[allow(clippy::exit)]
+    pub fn exit(&self) -> ! {
+        process::exit(self.exit_code())
+    }
+}
+
+/// Helper struct to print the progress and results of a test run.
+struct Printer {
+    /// How to print the results.
+    format: OutputFormat,
+
+    /// Whether to only print failed tests and the summary.
+    is_quiet: bool,
+}
+
+// Stdout printing is allowed because this is the output of the test runner.
+#[allow(clippy::print_stdout)]
+impl Printer {
+    /// Prints the name of a test when listing tests.
+    fn print_listed_test(&self, name: &str) {
+        match self.format {
+            OutputFormat::Pretty => println!("{name}: test"),
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::json!({ "type": "test", "event": "discovered", "name": name })
+            ),
+        }
+    }
+
+    /// Prints that the test run is starting.
+    fn print_started(&self, test_count: usize) {
+        match self.format {
+            OutputFormat::Pretty => {
+                let plural = if test_count == 1 { "" } else { "s" };
+
+                println!("\nrunning {test_count} test{plural}");
+            }
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::json!({ "type": "suite", "event": "started", "test_count": test_count })
+            ),
+        }
+    }
+
+    /// Prints the outcome of a single test.
+    fn print_test_outcome(&self, name: &str, outcome: TestOutcome, duration: Duration) {
+        match self.format {
+            OutputFormat::Pretty => {
+                if self.is_quiet && outcome != TestOutcome::Failed {
+                    return;
+                }
+
+                let outcome = match outcome {
+                    TestOutcome::Passed => style("ok").green(),
+                    TestOutcome::Failed => style("FAILED").red(),
+                    TestOutcome::Ignored => style("ignored").yellow(),
+                };
+
+                println!("test {name} ... {outcome}");
+            }
+            OutputFormat::Json => {
+                let event = match outcome {
+                    TestOutcome::Passed => "ok",
+                    TestOutcome::Failed => "failed",
+                    TestOutcome::Ignored => "ignored",
+                };
+
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "type": "test",
+                        "event": event,
+                        "name": name,
+                        "exec_time": duration.as_secs_f64(),
+                    })
+                );
+            }
+        }
+    }
+
+    /// Prints the summary of the test run.
+    fn print_conclusion(&self, conclusion: &Conclusion, duration: Duration) {
+        match self.format {
+            OutputFormat::Pretty => {
+                if conclusion.has_failed() {
+                    println!("\nfailures:");
+
+                    for name in &conclusion.failures {
+                        println!("    {name}");
+                    }
+                }
+
+                let result = if conclusion.has_failed() {
+                    style("FAILED").red()
+                } else {
+                    style("ok").green()
+                };
+
+                println!(
+                    "\ntest result: {result}. {} passed; {} failed; {} ignored; {} filtered out; finished in {}\n",
+                    conclusion.passed,
+                    conclusion.failed,
+                    conclusion.ignored,
+                    conclusion.filtered_out,
+                    format_duration(duration)
+                );
+            }
+            OutputFormat::Json => println!(
+                "{}",
+                serde_json::json!({
+                    "type": "suite",
+                    "event": if conclusion.has_failed() { "failed" } else { "ok" },
+                    "passed": conclusion.passed,
+                    "failed": conclusion.failed,
+                    "ignored": conclusion.ignored,
+                    "filtered_out": conclusion.filtered_out,
+                    "exec_time": duration.as_secs_f64(),
+                })
+            ),
+        }
+    }
+}
+
+/// Helper function to record the outcome of a test in the report file, if reporting is enabled.
+#[cfg(feature = "report")]
+fn report_outcome(name: &str, outcome: TestOutcome) {
+    match outcome {
+        TestOutcome::Passed => record_report_entry(ReportEntry::new(name, ReportOutcome::Passed)),
+        TestOutcome::Failed => record_report_entry(ReportEntry::new(name, ReportOutcome::Failed)),
+        TestOutcome::Ignored => {}
+    }
+}
+
+/// Runs tests with the arguments of the current process, then exits the process.
+///
+/// This is meant to be called from the `main` function of a test target with `harness = false`.
+/// If the arguments are invalid, the error is printed and the process exits.
+///
+/// # Arguments
+///
+/// * `tests` - The tests to run.
+///
+/// # Example
+///
+/// ```no_run
+/// use test_ur_code_xd::{assert_eq, runner::{self, Test}};
+///
+/// fn main() {
+///     runner::run(vec![Test::new("addition", || {
+///         assert_eq!(1 + 1, 2);
+///     })]);
+/// }
+/// ```
+//
+// Stderr printing is allowed because there is nowhere else to report invalid arguments.
+//
+// Exiting is the point of this function.
+#[allow(clippy::print_stderr, clippy::exit)]
+pub fn run(tests: Vec<Test>) -> ! {
+    let arguments = match Arguments::from_env() {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            eprintln!("error: {error}");
+
+            process::exit(FAILURE_EXIT_CODE);
+        }
+    };
+
+    run_with_arguments(&arguments, tests).exit()
+}
+
+/// Runs tests with the given arguments.
+///
+/// Tests are run in parallel, each on a thread named after the test. Their output is not captured.
+///
+/// # Arguments
+///
+/// * `arguments` - The arguments, usually from [`Arguments::from_env`].
+/// * `tests` - The tests to run.
+///
+/// # Returns
+///
+/// The results of the test run.
+pub fn run_with_arguments(arguments: &Arguments, tests: Vec<Test>) -> Conclusion {
+    let start = Instant::now();
+
+    let printer = Printer {
+        format: arguments.format,
+        is_quiet: arguments.is_quiet,
+    };
+
+    let test_count = tests.len();
+
+    let tests: Vec<Test> = tests
+        .into_iter()
+        .filter(|test| {
+            arguments.is_selected(&test.name) && (test.is_ignored || !arguments.is_ignored_only)
+        })
+        .collect();
+
+    let mut conclusion = Conclusion {
+        filtered_out: test_count.saturating_sub(tests.len()),
+        ..Conclusion::default()
+    };
+
+    if arguments.is_listing {
+        for test in &tests {
+            printer.print_listed_test(&test.name);
+        }
+
+        return conclusion;
+    }
+
+    printer.print_started(tests.len());
+
+    let next_index = AtomicUsize::new(0);
+    let outcomes: Mutex<Vec<(usize, TestOutcome)>> = Mutex::new(Vec::with_capacity(tests.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..arguments.test_thread_count().min(tests.len()) {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+
+                let Some(test) = tests.get(index) else {
+                    break;
+                };
+
+                let test_start = Instant::now();
+
+                let outcome = if test.is_ignored
+                    && !arguments.is_ignored_only
+                    && !arguments.is_ignored_included
+                {
+                    TestOutcome::Ignored
+                } else if test.run() {
+                    TestOutcome::Passed
+                } else {
+                    TestOutcome::Failed
+                };
+
+                printer.print_test_outcome(&test.name, outcome, test_start.elapsed());
+
+                #[cfg(feature = "report")]
+                report_outcome(&test.name, outcome);
+
+                outcomes
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push((index, outcome));
+            });
+        }
+    });
+
+    let mut outcomes = outcomes
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    outcomes.sort_unstable_by_key(|(index, _)| *index);
+
+    for (index, outcome) in outcomes {
+        match outcome {
+            TestOutcome::Passed => conclusion.passed = conclusion.passed.saturating_add(1),
+            TestOutcome::Failed => {
+                conclusion.failed = conclusion.failed.saturating_add(1);
+
+                if let Some(test) = tests.get(index) {
+                    conclusion.failures.push(test.name.clone());
+                }
+            }
+            TestOutcome::Ignored => conclusion.ignored = conclusion.ignored.saturating_add(1),
+        }
+    }
+
+    printer.print_conclusion(&conclusion, start.elapsed());
+
+    conclusion
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Panics allowed to generate panics for testing.
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, assert_not};
+    use std::sync::atomic::AtomicBool;
+
+    /// Helper function to parse arguments for tests.
+    fn arguments(arguments: &[&str]) -> Arguments {
+        Arguments::parse(arguments.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn run_passing_and_failing() {
+        let conclusion = run_with_arguments(
+            &arguments(&["--quiet"]),
+            vec![
+                Test::new("passing", || {}),
+                Test::new("failing", || panic!("some failure")),
+                Test::new("ignored", || panic!("should not run")).with_ignored(true),
+            ],
+        );
+
+        assert_eq!(conclusion.passed, 1);
+        assert_eq!(conclusion.failed, 1);
+        assert_eq!(conclusion.ignored, 1);
+        assert_eq!(conclusion.failures, vec!["failing".to_owned()]);
+        assert_eq!(conclusion.exit_code(), FAILURE_EXIT_CODE);
+    }
+
+    #[test]
+    fn run_filtered() {
+        let conclusion = run_with_arguments(
+            &arguments(&["parse", "--quiet"]),
+            vec![
+                Test::new("parse_number", || {}),
+                Test::new("format_number", || panic!("should not run")),
+            ],
+        );
+
+        assert_eq!(conclusion.passed, 1);
+        assert_eq!(conclusion.filtered_out, 1);
+        assert_eq!(conclusion.exit_code(), 0);
+    }
+
+    #[test]
+    fn run_ignored_only() {
+        let has_run = Arc::new(AtomicBool::new(false));
+        let has_run_in_test = Arc::clone(&has_run);
+
+        let conclusion = run_with_arguments(
+            &arguments(&["--ignored", "--quiet"]),
+            vec![
+                Test::new("not_ignored", || panic!("should not run")),
+                Test::new("ignored", move || {
+                    has_run_in_test.store(true, Ordering::Relaxed)
+                })
+                .with_ignored(true),
+            ],
+        );
+
+        assert_eq!(conclusion.passed, 1);
+        assert_eq!(conclusion.filtered_out, 1);
+        assert!(has_run.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn run_retries() {
+        let attempt_count = Arc::new(AtomicUsize::new(0));
+        let attempt_count_in_test = Arc::clone(&attempt_count);
+
+        let conclusion = run_with_arguments(
+            &arguments(&["--quiet"]),
+            vec![Test::new("flaky", move || {
+                let attempt_number = attempt_count_in_test.fetch_add(1, Ordering::Relaxed);
+
+                assert!(attempt_number >= 2);
+            })
+            .with_retries(3, Duration::ZERO)],
+        );
+
+        assert_eq!(conclusion.passed, 1);
+        assert_eq!(attempt_count.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn run_timeout() {
+        let conclusion = run_with_arguments(
+            &arguments(&["--quiet"]),
+            vec![Test::new("slow", || thread::sleep(Duration::from_secs(1)))
+                .with_timeout(Duration::from_millis(10))],
+        );
+
+        assert_eq!(conclusion.failed, 1);
+    }
+
+    #[test]
+    fn run_thread_named_after_test() {
+        let conclusion = run_with_arguments(
+            &arguments(&["--quiet"]),
+            vec![Test::new("named", || {
+                assert_eq!(thread::current().name(), Some("named"));
+            })],
+        );
+
+        assert_eq!(conclusion.passed, 1);
+    }
+
+    #[test]
+    fn run_listing() {
+        let conclusion = run_with_arguments(
+            &arguments(&["--list"]),
+            vec![Test::new("listed", || panic!("should not run"))],
+        );
+
+        assert_not!(conclusion.has_failed());
+        assert_eq!(conclusion.passed, 0);
+    }
+
+    #[test]
+    fn parameterized_names() {
+        let tests = Test::parameterized("is_positive", [1, -1], |value| {
+            assert!(*value > 0);
+        });
+
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].name(), "is_positive_0");
+        assert_eq!(tests[1].name(), "is_positive_1");
+
+        let conclusion = run_with_arguments(&arguments(&["--quiet"]), tests);
+
+        assert_eq!(conclusion.failures, vec!["is_positive_1".to_owned()]);
+    }
+}
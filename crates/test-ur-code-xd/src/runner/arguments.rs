@@ -0,0 +1,291 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Command line arguments for the test runner.
+
+use std::{env, num::NonZeroUsize, thread};
+
+use super::errors::ArgumentsError;
+
+/// How the test runner prints its results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    /// One human-readable line per test, like the default test harness. This is the default.
+    #[default]
+    Pretty,
+
+    /// One JSON object per line for each event, for CI systems and other tools.
+    Json,
+}
+
+/// The command line arguments for the test runner.
+///
+/// These mirror the most commonly used arguments of the default test harness, so that
+/// `cargo test -- <arguments>` works the same way.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+// Each flag of the default test harness is independent, so they are kept as bools.
+#[allow(clippy::struct_excessive_bools)]
+pub struct Arguments {
+    /// Only tests whose names contain one of these are run. All tests are run when this is empty.
+    pub filters: Vec<String>,
+
+    /// Tests whose names contain one of these are not run.
+    pub skip: Vec<String>,
+
+    /// Whether filters must match test names exactly instead of being contained in them.
+    pub is_exact: bool,
+
+    /// Whether to run only ignored tests.
+    pub is_ignored_only: bool,
+
+    /// Whether to run ignored tests along with the others.
+    pub is_ignored_included: bool,
+
+    /// Whether to list the tests instead of running them.
+    pub is_listing: bool,
+
+    /// Whether to only print failed tests and the summary.
+    pub is_quiet: bool,
+
+    /// How to print the results.
+    pub format: OutputFormat,
+
+    /// How many tests to run at once, or `None` to use the available parallelism.
+    pub test_threads: Option<NonZeroUsize>,
+}
+
+impl Arguments {
+    /// Parses the arguments of the current process.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if there are any unknown flags or invalid values.
+    pub fn from_env() -> Result<Self, ArgumentsError> {
+        Self::parse(env::args().skip(1))
+    }
+
+    /// Parses arguments, not including the name of the executable.
+    ///
+    /// Flags with values can be given as either `--flag value` or `--flag=value`. `--nocapture` and
+    /// `--show-output` are accepted for compatibility with the default test harness, but have no
+    /// effect since output is never captured.
+    ///
+    /// # Arguments
+    ///
+    /// * `arguments` - The arguments to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if there are any unknown flags or invalid values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::runner::{Arguments, OutputFormat};
+    /// #
+    /// let arguments = Arguments::parse(["parsing", "--format=json"]).unwrap();
+    ///
+    /// assert_eq!(arguments.filters, vec!["parsing".to_owned()]);
+    /// assert_eq!(arguments.format, OutputFormat::Json);
+    /// ```
+    pub fn parse(
+        arguments: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, ArgumentsError> {
+        let mut parsed = Self::default();
+        let mut arguments = arguments.into_iter().map(Into::into);
+
+        while let Some(argument) = arguments.next() {
+            if !argument.starts_with('-') {
+                parsed.filters.push(argument);
+                continue;
+            }
+
+            let (flag, inline_value) = match argument.split_once('=') {
+                Some((flag, value)) => (flag.to_owned(), Some(value.to_owned())),
+                None => (argument.clone(), None),
+            };
+
+            match flag.as_str() {
+                "--exact" => parsed.is_exact = true,
+                "--ignored" => parsed.is_ignored_only = true,
+                "--include-ignored" => parsed.is_ignored_included = true,
+                "--list" => parsed.is_listing = true,
+                "-q" | "--quiet" => parsed.is_quiet = true,
+                "--nocapture" | "--show-output" => {}
+                "--skip" => parsed
+                    .skip
+                    .push(take_value(&flag, inline_value, &mut arguments)?),
+                "--format" => {
+                    let value = take_value(&flag, inline_value, &mut arguments)?;
+
+                    parsed.format = match value.as_str() {
+                        "pretty" => OutputFormat::Pretty,
+                        "json" => OutputFormat::Json,
+                        _ => return Err(ArgumentsError::InvalidValue { flag, value }),
+                    };
+                }
+                "--test-threads" => {
+                    let value = take_value(&flag, inline_value, &mut arguments)?;
+
+                    parsed.test_threads = Some(
+                        value
+                            .parse()
+                            .map_err(|_error| ArgumentsError::InvalidValue { flag, value })?,
+                    );
+                }
+                _ => return Err(ArgumentsError::UnknownFlag(flag)),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Gets the number of tests to run at once.
+    #[must_use]
+    pub fn test_thread_count(&self) -> usize {
+        self.test_threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get)
+    }
+
+    /// Checks whether a test with the given name is selected by the filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the test.
+    #[must_use]
+    pub fn is_selected(&self, name: &str) -> bool {
+        let matches = |filter: &String| {
+            if self.is_exact {
+                name == filter
+            } else {
+                name.contains(filter.as_str())
+            }
+        };
+
+        (self.filters.is_empty() || self.filters.iter().any(matches))
+            && !self.skip.iter().any(matches)
+    }
+}
+
+/// Helper function to get the value of a flag, either from after the `=` or from the next argument.
+fn take_value(
+    flag: &str,
+    inline_value: Option<String>,
+    arguments: &mut impl Iterator<Item = String>,
+) -> Result<String, ArgumentsError> {
+    inline_value
+        .or_else(|| arguments.next())
+        .ok_or_else(|| ArgumentsError::MissingValue(flag.to_owned()))
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, assert_not};
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(
+            Arguments::parse(Vec::<String>::new()).unwrap(),
+            Arguments::default()
+        );
+    }
+
+    #[test]
+    fn parse_filters_and_flags() {
+        let arguments = Arguments::parse([
+            "parsing",
+            "io",
+            "--exact",
+            "--include-ignored",
+            "--skip",
+            "slow",
+            "--test-threads=2",
+            "--nocapture",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            arguments.filters,
+            vec!["parsing".to_owned(), "io".to_owned()]
+        );
+        assert_eq!(arguments.skip, vec!["slow".to_owned()]);
+        assert!(arguments.is_exact);
+        assert!(arguments.is_ignored_included);
+        assert_eq!(arguments.test_thread_count(), 2);
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!(
+            Arguments::parse(["--format", "json"]).unwrap().format,
+            OutputFormat::Json
+        );
+
+        assert_eq!(
+            Arguments::parse(["--format=pretty"]).unwrap().format,
+            OutputFormat::Pretty
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Arguments::parse(["--bench"]).unwrap_err(),
+            ArgumentsError::UnknownFlag("--bench".to_owned())
+        );
+
+        assert_eq!(
+            Arguments::parse(["--skip"]).unwrap_err(),
+            ArgumentsError::MissingValue("--skip".to_owned())
+        );
+
+        assert_eq!(
+            Arguments::parse(["--format", "xml"]).unwrap_err(),
+            ArgumentsError::InvalidValue {
+                flag: "--format".to_owned(),
+                value: "xml".to_owned()
+            }
+        );
+
+        assert_eq!(
+            Arguments::parse(["--test-threads=0"]).unwrap_err(),
+            ArgumentsError::InvalidValue {
+                flag: "--test-threads".to_owned(),
+                value: "0".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn is_selected() {
+        let arguments = Arguments::parse(["parse", "--skip", "slow"]).unwrap();
+
+        assert!(arguments.is_selected("parse_number"));
+        assert_not!(arguments.is_selected("format_number"));
+        assert_not!(arguments.is_selected("parse_slow"));
+
+        let arguments = Arguments::parse(["parse", "--exact"]).unwrap();
+
+        assert!(arguments.is_selected("parse"));
+        assert_not!(arguments.is_selected("parse_number"));
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Error types specific to the test runner.
+
+use thiserror::Error;
+
+/// An error that can occur when parsing the test runner's command line arguments.
+#[derive(Error, Debug, PartialEq, Eq)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum ArgumentsError {
+    #[error("unknown flag: {0}")]
+    UnknownFlag(String),
+
+    #[error("missing value for {0}")]
+    MissingValue(String),
+
+    #[error("invalid value for {flag}: {value}")]
+    InvalidValue { flag: String, value: String },
+}
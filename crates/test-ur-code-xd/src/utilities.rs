@@ -15,13 +15,47 @@
 
 //! Utilities for the crate.
 
+pub mod assertion_context;
+pub mod assertion_failure;
+pub mod color_choice;
+pub mod failure_behavior;
+pub mod failure_reporter;
+pub mod format;
+pub mod lazy_fixture;
+pub mod normalize;
 pub mod panic_message_builder;
+pub mod random_values;
+pub mod retries;
+pub mod setup;
+pub mod slow_reader;
+pub mod subtest;
+pub mod theme;
+pub mod timeout;
+pub mod verbosity;
+
+#[cfg(feature = "allocation")]
+pub mod allocation_counting;
 
 #[cfg(feature = "output")]
 pub mod capture_output;
 
+#[cfg(feature = "filesystem")]
+pub mod sandbox;
+
+#[cfg(feature = "input")]
+pub mod stdin;
+
+#[cfg(feature = "report")]
+pub mod report;
+
+#[cfg(feature = "source-snippet")]
+pub mod source_snippet;
+
 #[cfg(feature = "string-diff")]
 pub mod diff;
 
 #[cfg(feature = "string-diff")]
 pub mod truncate;
+
+#[cfg(any(feature = "filesystem", feature = "snapshot"))]
+pub mod update_mode;
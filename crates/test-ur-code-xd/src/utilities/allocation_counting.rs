@@ -0,0 +1,213 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Counting of heap allocations for allocation assertions.
+//!
+//! With the `allocation` feature enabled, this module installs a global allocator that forwards to
+//! [`System`] and counts how many allocations each thread makes. This means that any binary linking
+//! test ur code XD with the feature enabled cannot declare its own `#[global_allocator]`, so the
+//! feature should only be enabled for tests, like in `[dev-dependencies]`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The number and total size of heap allocations made while running some code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AllocationCounts {
+    /// The number of allocations, including reallocations that grow an allocation.
+    pub count: usize,
+
+    /// The total number of bytes allocated. Reallocations only count the bytes that they add.
+    pub bytes: usize,
+}
+
+impl AllocationCounts {
+    /// Computes the allocations made between two snapshots of the counts.
+    #[must_use]
+    const fn since(self, start: Self) -> Self {
+        Self {
+            count: self.count.saturating_sub(start.count),
+            bytes: self.bytes.saturating_sub(start.bytes),
+        }
+    }
+}
+
+thread_local! {
+    /// The allocations made by the current thread since it started.
+    static THREAD_ALLOCATION_COUNTS: Cell<AllocationCounts> =
+        const { Cell::new(AllocationCounts { count: 0, bytes: 0 }) };
+}
+
+/// Records an allocation made by the current thread.
+///
+/// The thread-local counts are not available while the thread is being torn down, in which case the
+/// allocation is not counted.
+fn record_allocation(bytes: usize) {
+    THREAD_ALLOCATION_COUNTS
+        .try_with(|counts| {
+            let current = counts.get();
+
+            counts.set(AllocationCounts {
+                count: current.count.saturating_add(1),
+                bytes: current.bytes.saturating_add(bytes),
+            });
+        })
+        .unwrap_or_default();
+}
+
+/// A global allocator that forwards to [`System`] and counts the allocations made by each thread.
+struct CountingAllocator;
+
+// SAFETY: Every method forwards to `System` with the same arguments, so the contract of
+//         `GlobalAlloc` is upheld by `System`.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation(layout.size());
+
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc_zeroed`.
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::dealloc`, and `ptr` was
+        //         allocated by `System` since every allocation is forwarded to it.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    /// Reallocations that grow an allocation count as one allocation of the bytes that they add.
+    /// Reallocations that shrink an allocation or keep its size don't allocate anything new, so
+    /// they aren't counted.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            record_allocation(new_size.saturating_sub(layout.size()));
+        }
+
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::realloc`, and `ptr` was
+        //         allocated by `System` since every allocation is forwarded to it.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// The global allocator installed by the `allocation` feature.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs a function and counts the heap allocations that it makes.
+///
+/// Only allocations made by the current thread are counted, so allocations from other tests running
+/// in parallel or threads spawned by `action` are not included.
+///
+/// # Arguments
+///
+/// * `action` - The function to run.
+///
+/// # Returns
+///
+/// The return value of `action` along with the allocations it made.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::allocation_counting::count_allocations;
+/// #
+/// let (values, counts) = count_allocations(|| vec![1_u8, 2, 3]);
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// assert_eq!(counts.count, 1);
+/// assert_eq!(counts.bytes, 3);
+/// ```
+pub fn count_allocations<ReturnType>(
+    action: impl FnOnce() -> ReturnType,
+) -> (ReturnType, AllocationCounts) {
+    let start = THREAD_ALLOCATION_COUNTS.with(Cell::get);
+
+    let result = action();
+
+    let counts = THREAD_ALLOCATION_COUNTS.with(Cell::get).since(start);
+
+    (result, counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn count_allocations_none() {
+        let (result, counts) = count_allocations(|| 1_i32.saturating_add(2));
+
+        assert_eq!(result, 3);
+        assert_eq!(counts, AllocationCounts::default());
+    }
+
+    #[test]
+    fn count_allocations_box() {
+        let (_, counts) = count_allocations(|| Box::new(5_u64));
+
+        assert_eq!(counts.count, 1);
+        assert_eq!(counts.bytes, 8);
+    }
+
+    #[test]
+    fn count_allocations_realloc() {
+        let (_, counts) = count_allocations(|| {
+            let mut values: Vec<u8> = Vec::with_capacity(4);
+
+            // Grows the allocation from 4 bytes to 12 bytes
+            values.reserve_exact(12);
+
+            values
+        });
+
+        assert_eq!(counts.count, 2);
+        assert_eq!(counts.bytes, 12);
+    }
+
+    #[test]
+    fn count_allocations_realloc_shrink() {
+        let mut values: Vec<u8> = Vec::with_capacity(12);
+
+        values.push(1);
+
+        let ((), counts) = count_allocations(|| {
+            // Shrinks the allocation from 12 bytes to 1 byte
+            values.shrink_to_fit();
+        });
+
+        assert_eq!(counts, AllocationCounts::default());
+    }
+
+    #[test]
+    fn count_allocations_ignores_other_threads() {
+        let (handle, counts) = count_allocations(|| thread::spawn(|| vec![0_u8; 4096]));
+
+        handle.join().unwrap();
+
+        assert!(counts.bytes < 4096);
+    }
+}
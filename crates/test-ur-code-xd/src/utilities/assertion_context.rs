@@ -0,0 +1,332 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Key/value context that is attached to any assertion failure within a scope.
+//!
+//! See [`assertion_context`](crate::assertion_context) for more information.
+
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    panic::{self, AssertUnwindSafe},
+};
+
+thread_local! {
+    /// The context entries that are currently in scope on this thread, from outermost to
+    /// innermost.
+    static CONTEXT_ENTRIES: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Gets the context entries that are currently in scope on this thread.
+///
+/// # Returns
+///
+/// The keys and formatted values from outermost to innermost. This is empty if no context is in
+/// scope.
+#[must_use]
+pub fn current_assertion_context() -> Vec<(String, String)> {
+    CONTEXT_ENTRIES.with(|entries| entries.borrow().clone())
+}
+
+/// Runs an action with context entries attached to any assertion that fails within it.
+///
+/// This is usually called with the [`assertion_context`](crate::assertion_context) macro.
+///
+/// # Arguments
+///
+/// * `entries` - The keys and formatted values to attach.
+/// * `action` - The action to run.
+///
+/// # Returns
+///
+/// The value returned by the action.
+///
+/// # Panics
+///
+/// * If the action panics, the panic is resumed after the entries are removed.
+pub fn run_with_assertion_context<ResultType>(
+    entries: Vec<(String, String)>,
+    action: impl FnOnce() -> ResultType,
+) -> ResultType {
+    let previous_len = CONTEXT_ENTRIES.with(|current_entries| {
+        let mut current_entries = current_entries.borrow_mut();
+        let previous_len = current_entries.len();
+
+        current_entries.extend(entries);
+
+        previous_len
+    });
+
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    CONTEXT_ENTRIES.with(|current_entries| current_entries.borrow_mut().truncate(previous_len));
+
+    match result {
+        Ok(value) => value,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Attaches key/value context to any assertion that fails within a block.
+///
+/// The context is printed at the end of the failure message and is available in
+/// [`AssertionFailure::context`](crate::utilities::assertion_failure::AssertionFailure::context).
+/// Context can be nested, in which case the outer entries are listed first. It only applies to
+/// assertions on the current thread.
+///
+/// # Arguments
+///
+/// * `key => value` - One or more string literal keys and values to attach. Values are formatted
+///                    with [`Debug`](std::fmt::Debug) when the block is entered.
+/// * `body` - The block to run. Its value is returned from the macro.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, assertion_context};
+/// #
+/// for customer_id in [1, 2, 3] {
+///     assertion_context!("customer_id" => customer_id, {
+///         assert_eq!(customer_id * 2 / 2, customer_id);
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! assertion_context {
+    ($($key:literal => $value:expr),+ , $body:block $(,)?) => {
+        $crate::utilities::assertion_context::run_with_assertion_context(
+            ::std::vec![
+                $((::std::string::String::from($key), ::std::format!("{:?}", $value))),+
+            ],
+            || $body,
+        )
+    };
+}
+
+/// Runs an action for each item of an iterable with the index and item attached as context.
+///
+/// This is usually called with the [`assert_for_each`](crate::assert_for_each) macro.
+///
+/// # Arguments
+///
+/// * `iterable` - The items to run the action for.
+/// * `action` - The action to run, which is passed each item and its index.
+///
+/// # Panics
+///
+/// * If the action panics, the panic is resumed and no more items are visited.
+pub fn for_each_with_assertion_context<IterableType: IntoIterator>(
+    iterable: IterableType,
+    mut action: impl FnMut(IterableType::Item, usize),
+) where
+    IterableType::Item: Debug,
+{
+    for (index, item) in iterable.into_iter().enumerate() {
+        let entries = vec![
+            ("index".to_owned(), format!("{index:?}")),
+            ("item".to_owned(), format!("{item:?}")),
+        ];
+
+        run_with_assertion_context(entries, || action(item, index));
+    }
+}
+
+/// Runs assertions for each item of an iterable, attaching the index and item to any failure.
+///
+/// Without this, a failure inside a loop doesn't say which element it happened on. Any assertion
+/// that fails inside the closure has `index` and `item` context entries, like with
+/// [`assertion_context`](crate::assertion_context).
+///
+/// # Arguments
+///
+/// * `iterable` - The items to check. Anything that implements [`IntoIterator`] with items that
+///                implement [`Debug`](std::fmt::Debug) can be used.
+/// * `action` - A closure that takes an item and its index and contains the assertions.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_for_each, assert_gt};
+/// #
+/// assert_for_each!([1, 2, 3], |item, index| {
+///     assert_gt!(item, index);
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_for_each {
+    ($iterable:expr, $action:expr $(,)?) => {
+        $crate::utilities::assertion_context::for_each_with_assertion_context($iterable, $action)
+    };
+}
+
+#[cfg(test)]
+// Panics allowed to generate panics for testing.
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert, assert_eq,
+        utilities::{
+            assertion_failure::capture_assertion_failure,
+            color_choice::{set_color_choice, ColorChoice},
+            panic_message_builder::{MessageType, PanicMessageBuilder},
+        },
+    };
+    use std::panic::Location;
+
+    #[test]
+    fn no_context() {
+        assert!(current_assertion_context().is_empty());
+    }
+
+    #[test]
+    fn returns_value() {
+        let value = assertion_context!("key" => 1, { 5 });
+
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn nested_entries() {
+        assertion_context!("outer" => "a", {
+            assertion_context!("inner" => 2, "other" => 3, {
+                assert_eq!(
+                    current_assertion_context(),
+                    vec![
+                        ("outer".to_owned(), "\"a\"".to_owned()),
+                        ("inner".to_owned(), "2".to_owned()),
+                        ("other".to_owned(), "3".to_owned()),
+                    ]
+                );
+            });
+
+            assert_eq!(
+                current_assertion_context(),
+                vec![("outer".to_owned(), "\"a\"".to_owned())]
+            );
+        });
+
+        assert!(current_assertion_context().is_empty());
+    }
+
+    #[test]
+    fn entries_are_popped_after_panic() {
+        let result = panic::catch_unwind(|| {
+            assertion_context!("outer" => 1, {
+                assertion_context!("inner" => 2, {
+                    panic!("context failure");
+                });
+            });
+        });
+
+        assert!(result.is_err());
+        assert!(current_assertion_context().is_empty());
+    }
+
+    #[test]
+    fn failure_has_context() {
+        let customer_id = 42;
+
+        let failure = capture_assertion_failure(|| {
+            assertion_context!("customer_id" => customer_id, {
+                assert_eq!(1, 2);
+            });
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.context,
+            vec![("customer_id".to_owned(), "42".to_owned())]
+        );
+    }
+
+    #[test]
+    fn for_each_passing() {
+        let mut visited = Vec::new();
+
+        assert_for_each!(vec!["a", "b"], |item, index| {
+            visited.push((index, item));
+        });
+
+        assert_eq!(visited, vec![(0, "a"), (1, "b")]);
+        assert!(current_assertion_context().is_empty());
+    }
+
+    #[test]
+    fn for_each_failure_has_index_and_item() {
+        let failure = capture_assertion_failure(|| {
+            assert_for_each!([2, 4, 5, 6], |item, _index| {
+                assert_eq!(item % 2, 0);
+            });
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.context,
+            vec![
+                ("index".to_owned(), "2".to_owned()),
+                ("item".to_owned(), "5".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn for_each_stops_after_failure() {
+        let mut visited = Vec::new();
+
+        let failure = capture_assertion_failure(|| {
+            assert_for_each!(0..5, |item, _index| {
+                visited.push(item);
+                assert!(item < 2);
+            });
+        });
+
+        assert!(failure.is_some());
+        assert_eq!(visited, vec![0, 1, 2]);
+        assert!(current_assertion_context().is_empty());
+    }
+
+    #[test]
+    fn for_each_nested_in_context() {
+        let failure = capture_assertion_failure(|| {
+            assertion_context!("customer_id" => 7, {
+                assert_for_each!(["x"], |item, _index| {
+                    assert_eq!(item, "y");
+                });
+            });
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.context,
+            vec![
+                ("customer_id".to_owned(), "7".to_owned()),
+                ("index".to_owned(), "0".to_owned()),
+                ("item".to_owned(), "\"x\"".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn format_has_context() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = assertion_context!("customer_id" => 42, {
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller()).format()
+        });
+
+        assert!(message.contains("\n  context: customer_id = 42\n"));
+    }
+}
@@ -0,0 +1,690 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Structured information about assertion failures, for testing custom assertions and for running
+//! assertions without panicking.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/custom](https://sophie-katz.github.io/test-ur-code-XD/assertions/custom/)
+//! for a usage guide.
+
+use std::{
+    cell::RefCell,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    panic::{self, Location},
+};
+
+use super::{
+    assertion_context::current_assertion_context, panic_message_builder::catch_unwind_silently,
+};
+
+thread_local! {
+    /// The last assertion failure on this thread, which is set right before the assertion panics.
+    static LAST_ASSERTION_FAILURE: RefCell<Option<AssertionFailure>> = const { RefCell::new(None) };
+
+    /// The last assertion failure on this thread that was returned instead of panicking, with
+    /// [`FailureBehavior::Return`](super::failure_behavior::FailureBehavior::Return).
+    static RETURNED_ASSERTION_FAILURE: RefCell<Option<AssertionFailure>> =
+        const { RefCell::new(None) };
+}
+
+/// An argument of a failed assertion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct AssertionArgument {
+    /// The name of the argument, like `"lhs"`.
+    pub name: String,
+
+    /// The stringified expression of the argument, like `"x + y"`.
+    pub value_description: String,
+
+    /// The compact debug representation of the argument's value, or the pre-formatted value if it
+    /// was added with
+    /// [`PanicMessageBuilder::with_argument_formatted`](super::panic_message_builder::PanicMessageBuilder::with_argument_formatted).
+    pub value: String,
+}
+
+/// Structured information about a failed assertion.
+///
+/// Get one with [`capture_assertion_failure`], [`try_assertion`], or [`last_assertion_failure`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct AssertionFailure {
+    /// A description of the predicate that failed, like `"lhs == rhs"`.
+    pub predicate: String,
+
+    /// The arguments of the assertion, in the order they were added to the panic message.
+    pub arguments: Vec<AssertionArgument>,
+
+    /// The user-defined description of the assertion, if one was given.
+    pub description: Option<String>,
+
+    /// The location of the assertion.
+    pub location: &'static Location<'static>,
+
+    /// The context attached to the failure, as keys and formatted values, from
+    /// [`assertion_context`](crate::assertion_context) scopes and
+    /// [`PanicMessageBuilder::with_context`](super::panic_message_builder::PanicMessageBuilder::with_context).
+    pub context: Vec<(String, String)>,
+}
+
+impl AssertionFailure {
+    /// Creates a new assertion failure without any arguments or description.
+    pub(crate) fn new(predicate: String, location: &'static Location<'static>) -> Self {
+        Self {
+            predicate,
+            arguments: Vec::new(),
+            description: None,
+            location,
+            context: current_assertion_context(),
+        }
+    }
+
+    /// Gets an argument by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the argument, like `"lhs"`.
+    ///
+    /// # Returns
+    ///
+    /// The first argument with the name, or `None` if there is none.
+    #[must_use]
+    pub fn argument(&self, name: &str) -> Option<&AssertionArgument> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+}
+
+impl Display for AssertionFailure {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "assertion failure at {}:{}:{}: {}",
+            self.location.file(),
+            self.location.line(),
+            self.location.column(),
+            self.predicate
+        )?;
+
+        if let Some(description) = &self.description {
+            write!(formatter, " ({description})")?;
+        }
+
+        Ok(())
+    }
+}
+
+// An assertion failure has no underlying error, so the default methods are what we want.
+#[allow(clippy::missing_trait_methods)]
+impl Error for AssertionFailure {}
+
+/// Records the assertion failure that is about to panic on this thread.
+pub(crate) fn set_last_assertion_failure(failure: AssertionFailure) {
+    LAST_ASSERTION_FAILURE.with(|last_failure| *last_failure.borrow_mut() = Some(failure));
+}
+
+/// Forgets the last assertion failure on this thread, because something other than an assertion
+/// panicked after it.
+pub(crate) fn clear_last_assertion_failure() {
+    // The thread local may have been destroyed already if this is called while the thread is
+    // exiting, in which case there is nothing to clear.
+    if let Ok(last_failure) = LAST_ASSERTION_FAILURE.try_with(RefCell::take) {
+        drop(last_failure);
+    }
+}
+
+/// Stores an assertion failure that is returned instead of panicking on this thread.
+pub(crate) fn set_returned_assertion_failure(failure: Option<AssertionFailure>) {
+    RETURNED_ASSERTION_FAILURE.with(|returned_failure| *returned_failure.borrow_mut() = failure);
+}
+
+/// Takes the assertion failure that was returned instead of panicking on this thread.
+///
+/// Assertions with the failure behavior
+/// [`FailureBehavior::Return`](super::failure_behavior::FailureBehavior::Return) store their failure
+/// here instead of panicking. An assertion with `failure_behavior = Some(FailureBehavior::Return)`
+/// that passes clears the stored failure, so this only returns a failure from the most recent one.
+///
+/// # Returns
+///
+/// The failure, or `None` if there is none. The failure is removed, so the next call returns
+/// `None`.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{
+/// #     assert_eq,
+/// #     utilities::{
+/// #         assertion_failure::take_returned_assertion_failure, failure_behavior::FailureBehavior,
+/// #     },
+/// # };
+/// #
+/// assert_eq!(1 + 1, 3, failure_behavior = Some(FailureBehavior::Return));
+///
+/// let failure = take_returned_assertion_failure().unwrap();
+///
+/// assert_eq!(failure.predicate, "lhs == rhs");
+/// assert!(take_returned_assertion_failure().is_none());
+/// ```
+#[must_use]
+pub fn take_returned_assertion_failure() -> Option<AssertionFailure> {
+    RETURNED_ASSERTION_FAILURE.with(RefCell::take)
+}
+
+/// Gets the assertion failure that caused the most recent panic on this thread.
+///
+/// This is meant for custom test harnesses and test runners that catch panics themselves. After
+/// catching a panic from a test, they can get structured information about the failure instead of
+/// parsing the panic message. The failure is recorded right before the assertion panics, and is
+/// forgotten if something other than an assertion panics afterwards.
+///
+/// Each thread has its own last assertion failure, so the test has to run on the same thread that
+/// calls this function.
+///
+/// # Returns
+///
+/// The assertion failure, or `None` if no assertion has failed on this thread or the most recent
+/// panic was not from an assertion.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, utilities::assertion_failure::last_assertion_failure};
+/// # use std::panic;
+/// #
+/// let result = panic::catch_unwind(|| {
+///     assert_eq!(1 + 1, 3);
+/// });
+///
+/// assert!(result.is_err());
+///
+/// let failure = last_assertion_failure().unwrap();
+///
+/// assert_eq!(failure.predicate, "lhs == rhs");
+/// assert_eq!(failure.argument("lhs").unwrap().value, "2");
+/// ```
+#[must_use]
+pub fn last_assertion_failure() -> Option<AssertionFailure> {
+    LAST_ASSERTION_FAILURE.with(|last_failure| last_failure.borrow().clone())
+}
+
+/// Runs an action and captures the assertion failure if it fails.
+///
+/// This is useful for testing custom assertions built with
+/// [`assert_custom`](crate::assert_custom), without matching against the formatted panic message.
+/// The failure message is not printed.
+///
+/// # Arguments
+///
+/// * `action` - The action to run.
+///
+/// # Returns
+///
+/// The assertion failure, or `None` if the action did not panic.
+///
+/// # Panics
+///
+/// * If the action panics with something other than an assertion from this crate, the panic is
+///   resumed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, utilities::assertion_failure::capture_assertion_failure};
+/// #
+/// let failure = capture_assertion_failure(|| {
+///     assert_eq!(1 + 1, 3, description = "math is broken");
+/// })
+/// .unwrap();
+///
+/// assert_eq!(failure.predicate, "lhs == rhs");
+/// assert_eq!(failure.argument("lhs").unwrap().value_description, "1 + 1");
+/// assert_eq!(failure.argument("lhs").unwrap().value, "2");
+/// assert_eq!(failure.description.as_deref(), Some("math is broken"));
+/// ```
+pub fn capture_assertion_failure(action: impl FnOnce()) -> Option<AssertionFailure> {
+    LAST_ASSERTION_FAILURE.with(|last_failure| last_failure.borrow_mut().take());
+
+    let payload = catch_unwind_silently(action).err()?;
+
+    let failure = LAST_ASSERTION_FAILURE.with(|last_failure| last_failure.borrow_mut().take());
+
+    if failure.is_none() {
+        panic::resume_unwind(payload);
+    }
+
+    failure
+}
+
+/// Runs an action and returns the assertion failure as an error instead of panicking.
+///
+/// This allows assertions to be used where a panic is not wanted, like in helper functions that
+/// return a [`Result`] or in custom test harnesses. Any assertion from this crate can be used inside
+/// the action. The failure message is not printed.
+///
+/// The assertion still panics and the panic is caught, so this doesn't work with `panic = "abort"`.
+/// Use [`try_assert!`](crate::try_assert) there, or in async code.
+///
+/// # Arguments
+///
+/// * `action` - The action to run.
+///
+/// # Returns
+///
+/// `Ok(())` if the action did not panic, or the assertion failure if it did.
+///
+/// # Errors
+///
+/// * Returns the assertion failure if an assertion in the action failed.
+///
+/// # Panics
+///
+/// * If the action panics with something other than an assertion from this crate, the panic is
+///   resumed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{
+/// #     assert_eq,
+/// #     utilities::assertion_failure::{try_assertion, AssertionFailure},
+/// # };
+/// #
+/// fn check_sum(values: &[i32], expected: i32) -> Result<(), AssertionFailure> {
+///     try_assertion(|| {
+///         assert_eq!(values.iter().sum::<i32>(), expected);
+///     })
+/// }
+///
+/// assert!(check_sum(&[1, 2], 3).is_ok());
+///
+/// let failure = check_sum(&[1, 2], 4).unwrap_err();
+///
+/// assert_eq!(failure.predicate, "lhs == rhs");
+/// ```
+pub fn try_assertion(action: impl FnOnce()) -> Result<(), AssertionFailure> {
+    capture_assertion_failure(action).map_or(Ok(()), Err)
+}
+
+/// Runs an assertion and returns its failure as an error instead of panicking.
+///
+/// The assertion is run with `failure_behavior = Some(FailureBehavior::Return)`, so nothing
+/// panics. This works with `panic = "abort"` and in async code, since the failure is taken back
+/// right after the assertion is checked without an `.await` in between. Any assertion that accepts
+/// the keyword arguments from
+/// [`Config`](crate::assertions::config::Config) can be used. The failure message is not printed.
+///
+/// # Arguments
+///
+/// * The assertion macro call, like `assert_eq!(x, y)`.
+///
+/// # Returns
+///
+/// `Ok(())` if the assertion passed, or the assertion failure if it failed.
+///
+/// # Panics
+///
+/// * If there is an error while checking the assertion, like a file that can't be read.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{
+/// #     assert_eq, try_assert, utilities::assertion_failure::AssertionFailure,
+/// # };
+/// #
+/// fn check_sum(values: &[i32], expected: i32) -> Result<(), AssertionFailure> {
+///     try_assert!(assert_eq!(values.iter().sum::<i32>(), expected))
+/// }
+///
+/// assert!(check_sum(&[1, 2], 3).is_ok());
+///
+/// let failure = check_sum(&[1, 2], 4).unwrap_err();
+///
+/// assert_eq!(failure.predicate, "lhs == rhs");
+/// ```
+#[macro_export]
+macro_rules! try_assert {
+    (@call [$($name:ident)::+] [$($arguments:tt)*] $(,)?) => {{
+        $($name)::+!(
+            $($arguments)*,
+            failure_behavior = ::std::option::Option::Some(
+                $crate::utilities::failure_behavior::FailureBehavior::Return
+            )
+        );
+
+        $crate::utilities::assertion_failure::take_returned_assertion_failure()
+            .map_or(::std::result::Result::Ok(()), ::std::result::Result::Err)
+    }};
+
+    // Moves the arguments over one token at a time so that a trailing comma can be dropped
+    (@call $name:tt [$($arguments:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::try_assert!(@call $name [$($arguments)* $next] $($rest)*)
+    };
+
+    ($($name:ident)::+ ! ($($arguments:tt)*) $(,)?) => {
+        $crate::try_assert!(@call [$($name)::+] [] $($arguments)*)
+    };
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_custom, assert_eq, utilities::failure_behavior::FailureBehavior};
+    use std::thread;
+
+    #[test]
+    fn passing_action() {
+        assert!(capture_assertion_failure(|| {
+            assert!(true);
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn failing_assertion() {
+        let failure = capture_assertion_failure(|| {
+            assert_eq!(1 + 1, 3);
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+        assert_eq!(failure.arguments.len(), 2);
+        assert_eq!(failure.arguments[0].name, "lhs");
+        assert_eq!(failure.arguments[0].value_description, "1 + 1");
+        assert_eq!(failure.arguments[0].value, "2");
+        assert_eq!(failure.arguments[1].name, "rhs");
+        assert_eq!(failure.arguments[1].value, "3");
+        assert!(failure.description.is_none());
+        assert_eq!(failure.location.file(), file!());
+    }
+
+    #[test]
+    fn custom_assertion_with_description() {
+        let failure = capture_assertion_failure(|| {
+            assert_custom!(
+                "value is even",
+                false,
+                |panic_message_builder| { panic_message_builder.with_argument("value", "x", &3) },
+                description = "odd numbers are not allowed"
+            );
+        })
+        .unwrap();
+
+        assert_eq!(failure.predicate, "value is even");
+        assert_eq!(failure.argument("value").unwrap().value, "3");
+        assert!(failure.argument("other").is_none());
+        assert_eq!(
+            failure.description.as_deref(),
+            Some("odd numbers are not allowed")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "some other panic")]
+    fn other_panic_is_resumed() {
+        capture_assertion_failure(|| {
+            // Panicking is the point of this test
+            #[allow(clippy::panic)]
+            {
+                panic!("some other panic");
+            }
+        });
+    }
+
+    #[test]
+    fn try_assertion_passing() {
+        assert!(try_assertion(|| {
+            assert_eq!(1 + 1, 2);
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn try_assertion_failing() {
+        let failure = try_assertion(|| {
+            assert_eq!(1 + 1, 3);
+        })
+        .unwrap_err();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+        assert_eq!(failure.argument("lhs").unwrap().value, "2");
+    }
+
+    #[test]
+    fn try_assertion_question_mark() {
+        fn check(value: i32) -> Result<i32, Box<dyn Error>> {
+            try_assertion(|| {
+                assert!(value > 0, description = "value must be positive");
+            })?;
+
+            Ok(value)
+        }
+
+        assert_eq!(check(1).unwrap(), 1);
+        assert!(check(-1).is_err());
+    }
+
+    #[test]
+    fn display() {
+        let failure = try_assertion(|| {
+            assert!(false, description = "some description");
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            failure.to_string(),
+            format!(
+                "assertion failure at {}:{}:{}: value is true (some description)",
+                failure.location.file(),
+                failure.location.line(),
+                failure.location.column()
+            )
+        );
+    }
+
+    #[test]
+    fn display_without_description() {
+        let failure = try_assertion(|| {
+            assert!(false);
+        })
+        .unwrap_err();
+
+        assert!(failure.to_string().ends_with(": value is true"));
+    }
+
+    /// Helper assertion that reports failures at its caller.
+    #[track_caller]
+    fn assert_is_even(value: i32) {
+        assert_eq!(value % 2, 0);
+    }
+
+    #[test]
+    fn location_is_caller_of_track_caller_helper() {
+        let expected_line = line!() + 3;
+
+        let failure = capture_assertion_failure(|| {
+            assert_is_even(3);
+        })
+        .unwrap();
+
+        assert_eq!(failure.location.file(), file!());
+        assert_eq!(failure.location.line(), expected_line);
+        assert_eq!(failure.location.column(), 13);
+    }
+
+    #[test]
+    fn display_has_column() {
+        let failure = try_assertion(|| {
+            assert!(false);
+        })
+        .unwrap_err();
+
+        assert!(failure.to_string().starts_with(
+            format!(
+                "assertion failure at {}:{}:{}: ",
+                failure.location.file(),
+                failure.location.line(),
+                failure.location.column()
+            )
+            .as_str()
+        ));
+    }
+
+    #[test]
+    fn last_assertion_failure_after_caught_panic() {
+        assert!(panic::catch_unwind(|| {
+            assert_eq!(1 + 1, 3, description = "math is broken");
+        })
+        .is_err());
+
+        let failure = last_assertion_failure().unwrap();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+        assert_eq!(failure.argument("rhs").unwrap().value, "3");
+        assert_eq!(failure.description.as_deref(), Some("math is broken"));
+    }
+
+    #[test]
+    fn last_assertion_failure_is_not_consumed() {
+        assert!(panic::catch_unwind(|| {
+            assert!(false);
+        })
+        .is_err());
+
+        assert_eq!(last_assertion_failure(), last_assertion_failure());
+        assert!(last_assertion_failure().is_some());
+    }
+
+    #[test]
+    fn last_assertion_failure_is_per_thread() {
+        assert!(panic::catch_unwind(|| {
+            assert!(false);
+        })
+        .is_err());
+
+        assert!(thread::spawn(last_assertion_failure)
+            .join()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn last_assertion_failure_none_after_capture() {
+        assert!(capture_assertion_failure(|| {
+            assert!(false);
+        })
+        .is_some());
+
+        assert!(last_assertion_failure().is_none());
+    }
+
+    #[test]
+    fn take_returned_assertion_failure_failing() {
+        assert_eq!(1 + 1, 3, failure_behavior = Some(FailureBehavior::Return));
+
+        let failure = take_returned_assertion_failure().unwrap();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+        assert_eq!(failure.argument("lhs").unwrap().value, "2");
+        assert!(take_returned_assertion_failure().is_none());
+    }
+
+    #[test]
+    fn take_returned_assertion_failure_passing_clears() {
+        assert!(false, failure_behavior = Some(FailureBehavior::Return));
+        assert!(true, failure_behavior = Some(FailureBehavior::Return));
+
+        assert!(take_returned_assertion_failure().is_none());
+    }
+
+    #[test]
+    fn try_assert_passing() {
+        assert!(try_assert!(assert_eq!(1 + 1, 2)).is_ok());
+    }
+
+    #[test]
+    fn try_assert_failing() {
+        let failure = try_assert!(assert_eq!(1 + 1, 3)).unwrap_err();
+
+        assert_eq!(failure.predicate, "lhs == rhs");
+        assert_eq!(failure.argument("lhs").unwrap().value, "2");
+        assert!(take_returned_assertion_failure().is_none());
+    }
+
+    #[test]
+    fn try_assert_keyword_arguments() {
+        let failure = try_assert!(assert!(false, description = "value must be true",)).unwrap_err();
+
+        assert_eq!(failure.description.as_deref(), Some("value must be true"));
+    }
+
+    #[test]
+    fn try_assert_path() {
+        assert!(try_assert!(crate::assert_eq!(1, 2)).is_err());
+    }
+
+    #[test]
+    fn try_assert_question_mark() {
+        fn check(value: i32) -> Result<i32, Box<dyn Error>> {
+            try_assert!(assert!(value > 0, description = "value must be positive"))?;
+
+            Ok(value)
+        }
+
+        assert_eq!(check(1).unwrap(), 1);
+        assert!(check(-1).is_err());
+    }
+
+    #[test]
+    fn try_assert_does_not_record_last_failure() {
+        assert!(try_assert!(assert!(false)).is_err());
+
+        assert!(last_assertion_failure().is_none());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn try_assert_across_await() {
+        use crate::assertions::future::block_on_with_timeout;
+        use std::{future, time::Duration};
+
+        let (output, _) = block_on_with_timeout(
+            async {
+                let value = future::ready(1 + 1).await;
+
+                try_assert!(assert_eq!(value, 3))?;
+
+                Ok::<(), AssertionFailure>(())
+            },
+            Duration::from_secs(1),
+        );
+
+        let failure = output.unwrap().unwrap_err();
+
+        assert_eq!(failure.argument("lhs").unwrap().value, "2");
+    }
+
+    #[test]
+    fn take_returned_assertion_failure_none_by_default() {
+        assert!(take_returned_assertion_failure().is_none());
+    }
+}
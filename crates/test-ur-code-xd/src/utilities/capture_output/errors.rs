@@ -42,6 +42,9 @@ pub enum OutputCapturingError {
     #[error("error while flushing {0:?}: {1}")]
     OutputStreamFlushError(OutputStream, io::Error),
 
+    #[error("error while flushing the C standard library's output streams: {0}")]
+    CStdioFlushError(io::Error),
+
     #[error("error while redirecting {0:?}: {1}")]
     OutputStreamRedirectError(OutputStream, io::Error),
 
@@ -16,7 +16,11 @@
 //! Defines a struct to help with capturing output.
 
 use gag::BufferRedirect;
-use std::io::{self, Read, Write};
+use std::{
+    io::{self, Read, Write},
+    os::raw::{c_int, c_void},
+    ptr,
+};
 
 // Used for doc comments below.
 #[allow(unused_imports)]
@@ -24,7 +28,16 @@ use std::io::{stderr, stdout};
 
 use super::{captured_output::CapturedOutputs, errors::OutputStream, OutputCapturingError};
 
+extern "C" {
+    /// The C standard library's `fflush`. Passing a null pointer flushes every open C output
+    /// stream.
+    fn fflush(stream: *mut c_void) -> c_int;
+}
+
 /// A struct to help with capturing output.
+///
+/// The redirects are done at the file descriptor level, so output written to `stdout` and `stderr`
+/// by child processes and C libraries is captured along with Rust's.
 #[derive(Default)]
 pub struct OutputCapturer {
     /// A buffer redirect for [`stdout`]
@@ -133,10 +146,22 @@ impl OutputCapturer {
 
     /// Flush both `stdout` and `stderr`.
     ///
+    /// The C standard library's output streams are flushed too, since C libraries buffer their
+    /// output separately from Rust and would otherwise write it after the capture is over.
+    ///
     /// # Errors
     ///
-    /// * If there is an IO error while flushing either stream, this function will return an error.
+    /// * If there is an IO error while flushing either stream or the C standard library's output
+    ///   streams, this function will return an error.
     fn flush_streams() -> Result<(), OutputCapturingError> {
+        // SAFETY: `fflush` accepts a null pointer, in which case it flushes all open output
+        //         streams.
+        if unsafe { fflush(ptr::null_mut()) } != 0 {
+            return Err(OutputCapturingError::CStdioFlushError(
+                io::Error::last_os_error(),
+            ));
+        }
+
         stdout().flush().map_err(|error| {
             OutputCapturingError::OutputStreamFlushError(OutputStream::Stdout, error)
         })?;
@@ -193,6 +218,7 @@ impl OutputCapturer {
 mod tests {
     use super::*;
     use crate::{assert, assert_eq};
+    use std::process::Command;
 
     #[test]
     fn capture_none() {
@@ -294,6 +320,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn capture_child_process() {
+        let mut output_capturer = OutputCapturer::default();
+
+        output_capturer.start().unwrap();
+
+        std::process::Command::new("echo")
+            .arg("hello from a child process")
+            .status()
+            .unwrap();
+
+        let captured_output = output_capturer.stop().unwrap();
+
+        assert_eq!(captured_output.stdout, "hello from a child process\n");
+    }
+
+    #[test]
+    fn capture_child_process_stderr() {
+        let mut output_capturer = OutputCapturer::default();
+
+        output_capturer.start().unwrap();
+
+        Command::new("sh")
+            .args(["-c", "echo hello from a child process >&2"])
+            .status()
+            .unwrap();
+
+        let captured_output = output_capturer.stop().unwrap();
+
+        assert_eq!(captured_output.stdout, "");
+        assert_eq!(captured_output.stderr, "hello from a child process\n");
+    }
+
+    #[test]
+    fn capture_c_library() {
+        extern "C" {
+            fn puts(string: *const std::os::raw::c_char) -> c_int;
+        }
+
+        let mut output_capturer = OutputCapturer::default();
+
+        output_capturer.start().unwrap();
+
+        // SAFETY: The string is null-terminated.
+        unsafe {
+            puts(b"this IS captured (C)\0".as_ptr().cast());
+        }
+
+        let captured_output = output_capturer.stop().unwrap();
+
+        assert_eq!(captured_output.stdout, "this IS captured (C)\n");
+    }
+
     #[test]
     fn multiple_captures() {
         let mut output_capturer_0 = OutputCapturer::default();
@@ -0,0 +1,182 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Configuration for whether or not failure messages are colored.
+//!
+//! Failure messages are styled with the `console` crate, but its setting for colors is shared by
+//! every crate in the process that uses it. The color choice here only applies to this crate's
+//! output, so other crates can't change it and it doesn't change them.
+
+use std::sync::{Mutex, PoisonError};
+
+use console::StyledObject;
+
+use crate::utilities::verbosity::is_color_disabled;
+
+/// Whether or not failure messages are colored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColorChoice {
+    /// Use colors if the output is a terminal that supports them and the `NO_COLOR` environment
+    /// variable isn't set. This is the default.
+    #[default]
+    Auto,
+
+    /// Always use colors, even if the output isn't a terminal or `NO_COLOR` is set.
+    Always,
+
+    /// Never use colors.
+    Never,
+}
+
+impl ColorChoice {
+    /// Gets whether styling is forced on or off, or `None` if it is detected by `console`.
+    ///
+    /// `console` only checks whether the output is a terminal, so `NO_COLOR` forces styling off.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_no_color_set` - Whether or not the `NO_COLOR` environment variable is set.
+    const fn forced_styling(self, is_no_color_set: bool) -> Option<bool> {
+        match self {
+            Self::Auto if !is_no_color_set => None,
+            Self::Auto | Self::Always | Self::Never => Some(self.is_enabled(is_no_color_set)),
+        }
+    }
+
+    /// Checks whether or not colors are enabled by this choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_no_color_set` - Whether or not the `NO_COLOR` environment variable is set, which only
+    ///                       matters for [`ColorChoice::Auto`].
+    #[must_use]
+    pub const fn is_enabled(self, is_no_color_set: bool) -> bool {
+        match self {
+            Self::Auto => !is_no_color_set,
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
+/// The current color choice for the process.
+static COLOR_CHOICE: Mutex<ColorChoice> = Mutex::new(ColorChoice::Auto);
+
+/// Sets whether or not failure messages are colored for the whole process.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::output::{set_color_choice, ColorChoice};
+/// #
+/// // Make failure messages easy to compare in tests of custom assertions
+/// set_color_choice(ColorChoice::Never);
+/// ```
+pub fn set_color_choice(choice: ColorChoice) {
+    *COLOR_CHOICE.lock().unwrap_or_else(PoisonError::into_inner) = choice;
+}
+
+/// Gets whether or not failure messages are colored.
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    *COLOR_CHOICE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Checks whether or not colors are enabled for the process by the color choice and the
+/// environment.
+#[must_use]
+pub fn is_color_enabled() -> bool {
+    color_choice().is_enabled(is_color_disabled())
+}
+
+/// Helper function to style a value for the given color choice and `NO_COLOR` setting.
+fn style_with_choice<ValueType>(
+    value: ValueType,
+    choice: ColorChoice,
+    is_no_color_set: bool,
+) -> StyledObject<ValueType> {
+    let styled_object = console::style(value);
+
+    match choice.forced_styling(is_no_color_set) {
+        Some(is_forced) => styled_object.force_styling(is_forced),
+        None => styled_object,
+    }
+}
+
+/// Styles a value for output, respecting the process's color choice and the `NO_COLOR` environment
+/// variable like [`is_color_enabled`].
+///
+/// This is used instead of `console::style` everywhere in the crate.
+///
+/// # Arguments
+///
+/// * `value` - The value to style.
+#[must_use]
+pub(crate) fn style<ValueType>(value: ValueType) -> StyledObject<ValueType> {
+    style_with_choice(value, color_choice(), is_color_disabled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn is_enabled() {
+        assert!(ColorChoice::Auto.is_enabled(false));
+        assert!(!ColorChoice::Auto.is_enabled(true));
+        assert!(ColorChoice::Always.is_enabled(true));
+        assert!(!ColorChoice::Never.is_enabled(false));
+    }
+
+    #[test]
+    fn style_with_choice_always() {
+        assert_eq!(
+            style_with_choice("hello", ColorChoice::Always, true)
+                .red()
+                .to_string(),
+            "\u{1b}[31mhello\u{1b}[0m"
+        );
+    }
+
+    #[test]
+    fn style_with_choice_never() {
+        assert_eq!(
+            style_with_choice("hello", ColorChoice::Never, false)
+                .red()
+                .to_string(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn style_with_choice_auto_no_color() {
+        assert_eq!(
+            style_with_choice("hello", ColorChoice::Auto, true)
+                .red()
+                .to_string(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn forced_styling() {
+        assert!(ColorChoice::Auto.forced_styling(false).is_none());
+        assert_eq!(ColorChoice::Auto.forced_styling(true), Some(false));
+        assert_eq!(ColorChoice::Always.forced_styling(true), Some(true));
+        assert_eq!(ColorChoice::Never.forced_styling(false), Some(false));
+    }
+}
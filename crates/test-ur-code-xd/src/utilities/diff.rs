@@ -19,8 +19,8 @@
 
 use std::fmt::{self, Write};
 
-use crate::utilities::truncate::Truncate;
-use console::{style, Color};
+use crate::utilities::{color_choice::style, truncate::Truncate};
+use console::Color;
 use unicode_segmentation::UnicodeSegmentation;
 
 use super::truncate::TruncationMode;
@@ -277,10 +277,11 @@ fn merge_char_diffs(diffs: &[diff::Result<char>]) -> Vec<diff::Result<String>> {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
+    use crate::utilities::color_choice::{set_color_choice, ColorChoice};
 
     #[test]
     fn format_diff_text_line_all_both() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -294,7 +295,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_all_left() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -305,7 +306,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_all_right() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -316,7 +317,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_long_both() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -333,7 +334,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_long_left() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -347,7 +348,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_long_right() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -361,7 +362,7 @@ mod tests {
 
     #[test]
     fn format_diff_text_line_mixed() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -379,7 +380,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_all_both() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -393,7 +394,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_all_left() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -404,7 +405,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_all_right() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -415,7 +416,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_long_both() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -432,7 +433,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_long_left() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -446,7 +447,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_long_right() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -460,7 +461,7 @@ mod tests {
 
     #[test]
     fn format_diff_marker_line_mixed() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let mut formatted = String::new();
 
@@ -478,7 +479,7 @@ mod tests {
 
     #[test]
     fn format_diff_simple() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let formatted = format_diff("hello, ", "helloworld");
 
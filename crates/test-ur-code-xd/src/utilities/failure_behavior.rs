@@ -0,0 +1,198 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Configuration for what happens when an assertion fails.
+//!
+//! By default, failed assertions panic, which is what test harnesses expect. Outside of tests, like
+//! in examples or demo binaries, it can be nicer to print the failure message and exit the process
+//! with a status code instead. Where neither is wanted, the failure can be returned as an error.
+
+use std::sync::{Mutex, PoisonError};
+
+/// What happens when an assertion fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FailureBehavior {
+    /// Print the failure message and panic. This is the default.
+    Panic,
+
+    /// Print the failure message and exit the process with the given status code.
+    ///
+    /// This is meant for binaries, not tests. The test harness cannot report an exit as a failed
+    /// test, and it would stop every other test in the same process.
+    Exit(i32),
+
+    /// Don't print the failure message or panic. The failure is stored instead, so that it can be
+    /// returned as an error with
+    /// [`take_returned_assertion_failure`](crate::utilities::assertion_failure::take_returned_assertion_failure).
+    ///
+    /// Nothing unwinds, so this works under `panic = "abort"` and in async code. The assertion
+    /// returns normally, so the code after it keeps running. Errors while checking an assertion
+    /// still panic.
+    Return,
+}
+
+/// Where the failure message is printed when an assertion fails.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OutputTarget {
+    /// Print the failure message to `stderr`. This is the default.
+    #[default]
+    Stderr,
+
+    /// Print the failure message to `stdout`.
+    Stdout,
+}
+
+/// The current failure behavior for the process.
+static FAILURE_BEHAVIOR: Mutex<FailureBehavior> = Mutex::new(FailureBehavior::Panic);
+
+/// Sets what happens when an assertion fails for the whole process.
+///
+/// # Example
+///
+/// ```no_run
+/// # use test_ur_code_xd::{assert_eq, utilities::failure_behavior::{set_failure_behavior, FailureBehavior}};
+/// #
+/// set_failure_behavior(FailureBehavior::Exit(1));
+///
+/// // Prints the failure message and exits with status code 1
+/// assert_eq!(1 + 1, 3);
+/// ```
+pub fn set_failure_behavior(behavior: FailureBehavior) {
+    *FAILURE_BEHAVIOR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = behavior;
+}
+
+/// Gets what happens when an assertion fails.
+#[must_use]
+pub fn failure_behavior() -> FailureBehavior {
+    *FAILURE_BEHAVIOR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Gets the status code to exit with for a failed assertion, if the process should exit.
+///
+/// Failures whose output is suppressed are always expected to be caught by whatever suppressed
+/// them, like [`assert_eventually`](crate::assert_eventually) retrying its condition, so they
+/// always panic.
+///
+/// # Arguments
+///
+/// * `behavior` - The current failure behavior.
+/// * `is_output_suppressed` - Whether or not panic output is suppressed for the current thread.
+#[must_use]
+pub(crate) const fn get_exit_code(
+    behavior: FailureBehavior,
+    is_output_suppressed: bool,
+) -> Option<i32> {
+    match behavior {
+        FailureBehavior::Exit(code) if !is_output_suppressed => Some(code),
+        FailureBehavior::Exit(_) | FailureBehavior::Panic | FailureBehavior::Return => None,
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{env, process::Command};
+
+    /// The environment variable that tells the test process that it is the child process in
+    /// [`exit_behavior_exits_with_code`] and [`exit_behavior_per_assertion`].
+    const CHILD_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_FAILURE_BEHAVIOR_CHILD";
+
+    #[test]
+    fn get_exit_code_panic() {
+        assert!(get_exit_code(FailureBehavior::Panic, false).is_none());
+        assert!(get_exit_code(FailureBehavior::Panic, true).is_none());
+    }
+
+    #[test]
+    fn get_exit_code_return() {
+        assert!(get_exit_code(FailureBehavior::Return, false).is_none());
+    }
+
+    #[test]
+    fn get_exit_code_exit() {
+        assert_eq!(get_exit_code(FailureBehavior::Exit(3), false), Some(3));
+        assert!(get_exit_code(FailureBehavior::Exit(3), true).is_none());
+    }
+
+    #[test]
+    fn failure_behavior_default() {
+        assert_eq!(failure_behavior(), FailureBehavior::Panic);
+    }
+
+    #[test]
+    fn exit_behavior_exits_with_code() {
+        // Exiting would stop every other test, so the failure happens in a child process that
+        // only runs this test
+        if env::var_os(CHILD_ENVIRONMENT_VARIABLE).is_some() {
+            set_failure_behavior(FailureBehavior::Exit(3));
+
+            assert_eq!(1 + 1, 3);
+
+            return;
+        }
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "utilities::failure_behavior::tests::exit_behavior_exits_with_code",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(CHILD_ENVIRONMENT_VARIABLE, "1")
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(3));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("lhs == rhs"));
+    }
+
+    #[test]
+    fn exit_behavior_per_assertion() {
+        // Exiting would stop every other test, so the failure happens in a child process that
+        // only runs this test
+        if env::var_os(CHILD_ENVIRONMENT_VARIABLE).is_some() {
+            assert_eq!(
+                1 + 1,
+                3,
+                failure_behavior = Some(FailureBehavior::Exit(4)),
+                output_target = OutputTarget::Stdout
+            );
+
+            return;
+        }
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "utilities::failure_behavior::tests::exit_behavior_per_assertion",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(CHILD_ENVIRONMENT_VARIABLE, "1")
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(4));
+        assert!(String::from_utf8_lossy(&output.stdout).contains("lhs == rhs"));
+        assert!(!String::from_utf8_lossy(&output.stderr).contains("lhs == rhs"));
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! An integration point for reporting failures that don't come from assertions.
+//!
+//! Other crates, like mocking libraries with violated expectations, can report their failures with
+//! [`report_failure`] so that they are formatted like every other failure in the test suite. By
+//! default, failures are formatted with
+//! [`PanicMessageBuilder`](super::panic_message_builder::PanicMessageBuilder), but this can be
+//! replaced for the whole process with [`set_failure_reporter`].
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/extending/writing-your-own-assertions](https://sophie-katz.github.io/test-ur-code-XD/extending/writing-your-own-assertions/)
+//! for a usage guide.
+
+use std::{
+    panic::Location,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use super::panic_message_builder::{MessageType, PanicMessageBuilder};
+use crate::errors::TestUrCodeXDError;
+
+/// A failure that doesn't come from an assertion, like a violated mock expectation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct ExternalFailure {
+    /// A description of what went wrong, like `"expected MockDatabase::save to be called once"`.
+    pub description: String,
+
+    /// Where the failure happened.
+    pub location: &'static Location<'static>,
+
+    /// Pre-formatted arguments to show in the failure message, as pairs of names and values.
+    pub arguments: Vec<(String, String)>,
+
+    /// A free-form tag for the failure, like `"mock"`.
+    pub category: Option<String>,
+}
+
+impl ExternalFailure {
+    /// Creates a new failure at the caller's location.
+    ///
+    /// # Arguments
+    ///
+    /// * `description` - A description of what went wrong.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::utilities::failure_reporter::ExternalFailure;
+    /// #
+    /// let failure = ExternalFailure::new("expected MockDatabase::save to be called once")
+    ///     .with_argument("calls", "0")
+    ///     .with_category("mock");
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            location: Location::caller(),
+            arguments: Vec::new(),
+            category: None,
+        }
+    }
+
+    /// Sets where the failure happened, if it isn't where the failure was created.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Where the failure happened.
+    #[must_use]
+    pub const fn with_location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = location;
+
+        self
+    }
+
+    /// Adds a pre-formatted argument to show in the failure message.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the argument, like `"calls"`.
+    /// * `value` - The formatted value of the argument.
+    #[must_use]
+    pub fn with_argument(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.arguments.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// Sets a free-form tag for the failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the failure, like `"mock"`.
+    #[must_use]
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+
+        self
+    }
+}
+
+/// Reports failures that don't come from assertions.
+///
+/// Implement this to change how [`report_failure`] reports failures for the whole process, then
+/// install it with [`set_failure_reporter`].
+pub trait FailureReporter: Send + Sync {
+    /// Reports a failure. This must never return, usually by panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `failure` - The failure to report.
+    fn report(&self, failure: ExternalFailure) -> !;
+}
+
+/// The failure reporter used when none has been set, which formats failures with
+/// [`PanicMessageBuilder`].
+///
+/// Failures are reported just like failed assertions, so they can be captured with
+/// [`capture_assertion_failure`](super::assertion_failure::capture_assertion_failure).
+#[derive(Clone, Copy, Debug, Default)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct DefaultFailureReporter;
+
+impl DefaultFailureReporter {
+    /// Helper method to create a panic message builder for a failure.
+    fn create_panic_message_builder(
+        failure: &ExternalFailure,
+    ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+        let mut panic_message_builder = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            &failure.description,
+            failure.location,
+        );
+
+        for (name, value) in &failure.arguments {
+            panic_message_builder =
+                panic_message_builder.with_argument_formatted(name, "--", value)?;
+        }
+
+        if let Some(category) = &failure.category {
+            panic_message_builder = panic_message_builder.with_category(category);
+        }
+
+        Ok(panic_message_builder)
+    }
+}
+
+impl FailureReporter for DefaultFailureReporter {
+    fn report(&self, failure: ExternalFailure) -> ! {
+        PanicMessageBuilder::unwrap_error_with(
+            Self::create_panic_message_builder(&failure),
+            MessageType::InternalError,
+            "unable to create panic message builder for reported failure",
+            PanicMessageBuilder::no_configuration,
+        )
+        .panic()
+    }
+}
+
+/// The failure reporter for the process, or `None` to use [`DefaultFailureReporter`].
+static FAILURE_REPORTER: Mutex<Option<Arc<dyn FailureReporter>>> = Mutex::new(None);
+
+/// Sets how failures reported with [`report_failure`] are reported for the whole process.
+///
+/// # Arguments
+///
+/// * `reporter` - The failure reporter to use.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::failure_reporter::{set_failure_reporter, ExternalFailure, FailureReporter};
+/// #
+/// struct PlainReporter;
+///
+/// impl FailureReporter for PlainReporter {
+///     fn report(&self, failure: ExternalFailure) -> ! {
+///         panic!("{}", failure.description);
+///     }
+/// }
+///
+/// set_failure_reporter(PlainReporter);
+/// ```
+pub fn set_failure_reporter(reporter: impl FailureReporter + 'static) {
+    *FAILURE_REPORTER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(reporter));
+}
+
+/// Goes back to reporting failures with [`DefaultFailureReporter`].
+pub fn reset_failure_reporter() {
+    *FAILURE_REPORTER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Reports a failure that doesn't come from an assertion with the current failure reporter.
+///
+/// This is the function for other crates to call. Unless the reporter has been changed with
+/// [`set_failure_reporter`], the failure is formatted like a failed assertion and panics.
+///
+/// # Arguments
+///
+/// * `failure` - The failure to report.
+///
+/// # Returns
+///
+/// This function never returns.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use test_ur_code_xd::utilities::failure_reporter::{report_failure, ExternalFailure};
+/// #
+/// report_failure(
+///     ExternalFailure::new("expected MockDatabase::save to be called once")
+///         .with_argument("calls", "0")
+///         .with_category("mock"),
+/// );
+/// ```
+pub fn report_failure(failure: ExternalFailure) -> ! {
+    // The reporter is cloned out so that the lock isn't held while the reporter panics.
+    let reporter = FAILURE_REPORTER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    match reporter {
+        Some(reporter) => reporter.report(failure),
+        None => DefaultFailureReporter.report(failure),
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_eq,
+        utilities::{
+            assertion_failure::capture_assertion_failure,
+            panic_message_builder::catch_unwind_silently,
+        },
+    };
+
+    /// A failure reporter that panics with a plain message.
+    struct PlainReporter;
+
+    impl FailureReporter for PlainReporter {
+        // Panics are how failures are reported.
+        #[allow(clippy::panic)]
+        fn report(&self, failure: ExternalFailure) -> ! {
+            panic!("plain: {}", failure.description);
+        }
+    }
+
+    #[test]
+    fn external_failure_location_is_caller() {
+        let failure = ExternalFailure::new("expectation violated");
+
+        assert_eq!(failure.location.file(), file!());
+    }
+
+    #[test]
+    fn default_reporter_formats_like_assertion() {
+        let failure = capture_assertion_failure(|| {
+            DefaultFailureReporter.report(
+                ExternalFailure::new("expected MockDatabase::save to be called once")
+                    .with_argument("calls", "0")
+                    .with_category("mock"),
+            )
+        })
+        .unwrap();
+
+        assert_eq!(
+            failure.predicate,
+            "expected MockDatabase::save to be called once"
+        );
+        assert_eq!(failure.argument("calls").unwrap().value, "0");
+        assert_eq!(failure.location.file(), file!());
+    }
+
+    #[test]
+    fn custom_reporter_is_used_until_reset() {
+        set_failure_reporter(PlainReporter);
+
+        let payload =
+            catch_unwind_silently(|| report_failure(ExternalFailure::new("expectation violated")))
+                .unwrap_err();
+
+        reset_failure_reporter();
+
+        assert_eq!(
+            payload.downcast_ref::<String>().map(String::as_str),
+            Some("plain: expectation violated")
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Human-readable formatting for values that appear in panic messages.
+
+use std::time::Duration;
+
+/// The maximum number of decimal places to show for a duration.
+const DURATION_DECIMAL_PLACES: usize = 3;
+
+/// Formats a duration using the largest unit in which it is at least 1, like `1.203 s` or
+/// `500 ms`.
+///
+/// Up to three decimal places are shown and trailing zeros are removed.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format.
+///
+/// # Returns
+///
+/// The formatted duration.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::format::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_millis(1_203)), "1.203 s");
+/// assert_eq!(format_duration(Duration::from_millis(500)), "500 ms");
+/// assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.5 µs");
+/// ```
+#[must_use]
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+
+    let (value, unit) = if seconds >= 1.0 {
+        (seconds, "s")
+    } else if seconds >= 1e-3 {
+        (seconds * 1e3, "ms")
+    } else if seconds >= 1e-6 {
+        (seconds * 1e6, "\u{b5}s")
+    } else {
+        (seconds * 1e9, "ns")
+    };
+
+    let formatted = format!("{value:.DURATION_DECIMAL_PLACES$}");
+
+    format!(
+        "{} {unit}",
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    )
+}
+
+/// Formats a duration along with the budget that it was expected to stay within, like
+/// `1.203 s (budget 500 ms, exceeded by 703 ms)`.
+///
+/// # Arguments
+///
+/// * `duration` - The duration that was measured.
+/// * `budget` - The maximum duration that was expected.
+///
+/// # Returns
+///
+/// The formatted duration and budget.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::format::format_duration_with_budget;
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     format_duration_with_budget(Duration::from_millis(1_203), Duration::from_millis(500)),
+///     "1.203 s (budget 500 ms, exceeded by 703 ms)"
+/// );
+///
+/// assert_eq!(
+///     format_duration_with_budget(Duration::from_millis(200), Duration::from_millis(500)),
+///     "200 ms (budget 500 ms)"
+/// );
+/// ```
+#[must_use]
+pub fn format_duration_with_budget(duration: Duration, budget: Duration) -> String {
+    match duration.checked_sub(budget) {
+        Some(excess) if !excess.is_zero() => format!(
+            "{} (budget {}, exceeded by {})",
+            format_duration(duration),
+            format_duration(budget),
+            format_duration(excess)
+        ),
+        _ => format!(
+            "{} (budget {})",
+            format_duration(duration),
+            format_duration(budget)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_eq;
+
+    #[test]
+    fn format_duration_zero() {
+        assert_eq!(format_duration(Duration::ZERO), "0 ns");
+    }
+
+    #[test]
+    fn format_duration_nanoseconds() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+    }
+
+    #[test]
+    fn format_duration_microseconds() {
+        assert_eq!(format_duration(Duration::from_micros(1)), "1 \u{b5}s");
+        assert_eq!(
+            format_duration(Duration::from_nanos(12_345)),
+            "12.345 \u{b5}s"
+        );
+    }
+
+    #[test]
+    fn format_duration_milliseconds() {
+        assert_eq!(format_duration(Duration::from_millis(50)), "50 ms");
+        assert_eq!(format_duration(Duration::from_micros(1_250)), "1.25 ms");
+    }
+
+    #[test]
+    fn format_duration_seconds() {
+        assert_eq!(format_duration(Duration::from_secs(5)), "5 s");
+        assert_eq!(format_duration(Duration::from_millis(1_203)), "1.203 s");
+        assert_eq!(format_duration(Duration::from_secs(90)), "90 s");
+    }
+
+    #[test]
+    fn format_duration_rounds_to_three_decimal_places() {
+        assert_eq!(format_duration(Duration::from_micros(1_203_400)), "1.203 s");
+    }
+
+    #[test]
+    fn format_duration_with_budget_exceeded() {
+        assert_eq!(
+            format_duration_with_budget(Duration::from_millis(1_203), Duration::from_millis(500)),
+            "1.203 s (budget 500 ms, exceeded by 703 ms)"
+        );
+    }
+
+    #[test]
+    fn format_duration_with_budget_within() {
+        assert_eq!(
+            format_duration_with_budget(Duration::from_millis(200), Duration::from_secs(1)),
+            "200 ms (budget 1 s)"
+        );
+    }
+
+    #[test]
+    fn format_duration_with_budget_equal() {
+        assert_eq!(
+            format_duration_with_budget(Duration::from_secs(1), Duration::from_secs(1)),
+            "1 s (budget 1 s)"
+        );
+    }
+}
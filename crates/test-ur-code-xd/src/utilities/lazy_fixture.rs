@@ -0,0 +1,298 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Process-wide fixtures that are shared between tests.
+//!
+//! Fixtures that are expensive to create, like a compiled set of regular expressions or a started
+//! container, can be created once with [`lazy_fixture`](crate::lazy_fixture) and shared between
+//! every test in the process.
+
+use std::{
+    mem,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+/// A fixture that can be torn down.
+trait Teardown: Sync {
+    /// Releases the fixture's reference to its value.
+    fn release(&self);
+}
+
+/// The fixtures that have been initialized, in the order that they were initialized.
+static INITIALIZED_FIXTURES: Mutex<Vec<&'static dyn Teardown>> = Mutex::new(Vec::new());
+
+/// A lazily-initialized, reference-counted fixture shared across the process.
+///
+/// This is usually created with the [`lazy_fixture`](crate::lazy_fixture) macro.
+pub struct LazyFixture<ValueType> {
+    /// The value, if it has been initialized.
+    value: Mutex<Option<Arc<ValueType>>>,
+
+    /// The function that creates the value.
+    initialize: fn() -> ValueType,
+}
+
+impl<ValueType: Send + Sync + 'static> LazyFixture<ValueType> {
+    /// Creates a new fixture that will be initialized on first use.
+    ///
+    /// # Arguments
+    ///
+    /// * `initialize` - The function that creates the value.
+    #[must_use]
+    pub const fn new(initialize: fn() -> ValueType) -> Self {
+        Self {
+            value: Mutex::new(None),
+            initialize,
+        }
+    }
+
+    /// Gets a reference to the fixture's value, initializing it if needed.
+    ///
+    /// The value is created at most once until [`LazyFixture::teardown`] or
+    /// [`teardown_lazy_fixtures`] is called, even if multiple threads call this at the same time.
+    ///
+    /// # Panics
+    ///
+    /// * If the initialization function panics. The fixture can still be initialized again later.
+    pub fn get(&'static self) -> Arc<ValueType> {
+        // A poisoned mutex only means that initialization panicked, in which case the value is
+        // still `None`, so it is safe to continue
+        let mut value = self.value.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(value) = value.as_ref() {
+            return Arc::clone(value);
+        }
+
+        let initialized = Arc::new((self.initialize)());
+
+        *value = Some(Arc::clone(&initialized));
+
+        INITIALIZED_FIXTURES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(self);
+
+        initialized
+    }
+
+    /// Checks whether or not the fixture's value is currently initialized.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.value
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_some()
+    }
+
+    /// Releases the fixture's reference to its value.
+    ///
+    /// The value is dropped once every [`Arc`] returned by [`LazyFixture::get`] has also been
+    /// dropped. Calling [`LazyFixture::get`] afterwards initializes a new value.
+    pub fn teardown(&self) {
+        // Take the value out first so that it is dropped after the lock is released
+        let value = self
+            .value
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+
+        drop(value);
+    }
+}
+
+impl<ValueType: Send + Sync + 'static> Teardown for LazyFixture<ValueType> {
+    fn release(&self) {
+        self.teardown();
+    }
+}
+
+/// Tears down every fixture that has been initialized, in the reverse order of initialization.
+///
+/// Rust does not run destructors for statics when the process exits, so a custom test harness or
+/// `main` function should call this after all tests have run.
+pub fn teardown_lazy_fixtures() {
+    let fixtures = mem::take(
+        &mut *INITIALIZED_FIXTURES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+
+    for fixture in fixtures.into_iter().rev() {
+        fixture.release();
+    }
+}
+
+/// Declares a process-wide fixture that is lazily initialized and shared between tests.
+///
+/// The fixture is a static [`LazyFixture`](crate::utilities::lazy_fixture::LazyFixture). Call
+/// `.get()` on it to get an [`Arc`](std::sync::Arc) to its value, which is created on first use.
+///
+/// # Arguments
+///
+/// * Any attributes and visibility for the static.
+/// * `static <name>: <type> = <expression>;` - The name and type of the fixture and an expression
+///                                              to create its value.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::lazy_fixture;
+/// #
+/// # fn load_expensive_model() -> Vec<f32> {
+/// #     vec![0.0; 1024]
+/// # }
+/// #
+/// lazy_fixture! {
+///     static MODEL: Vec<f32> = load_expensive_model();
+/// }
+///
+/// // This is only loaded once, no matter how many tests use it
+/// let model = MODEL.get();
+///
+/// assert_eq!(model.len(), 1024);
+/// ```
+#[macro_export]
+macro_rules! lazy_fixture {
+    ($(#[$attributes:meta])* $visibility:vis static $name:ident: $type:ty = $initialize:expr;) => {
+        $(#[$attributes])*
+        $visibility static $name: $crate::utilities::lazy_fixture::LazyFixture<$type> =
+            $crate::utilities::lazy_fixture::LazyFixture::new(|| $initialize);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq, assert_not};
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    };
+
+    #[test]
+    fn initialized_once() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        lazy_fixture! {
+            static FIXTURE: usize = COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let first = FIXTURE.get();
+        let second = FIXTURE.get();
+
+        assert_eq!(*first, 0);
+        assert_eq!(*second, 0);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn not_initialized_until_used() {
+        lazy_fixture! {
+            static FIXTURE: String = "hello".to_owned();
+        }
+
+        assert_not!(FIXTURE.is_initialized());
+
+        let _value = FIXTURE.get();
+
+        assert!(FIXTURE.is_initialized());
+    }
+
+    #[test]
+    fn teardown_reinitializes() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        lazy_fixture! {
+            static FIXTURE: usize = COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let first = FIXTURE.get();
+
+        FIXTURE.teardown();
+
+        assert_not!(FIXTURE.is_initialized());
+
+        let second = FIXTURE.get();
+
+        assert_eq!(*first, 0);
+        assert_eq!(*second, 1);
+    }
+
+    #[test]
+    fn teardown_drops_value() {
+        static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        struct Droppable;
+
+        impl Drop for Droppable {
+            fn drop(&mut self) {
+                DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        lazy_fixture! {
+            static FIXTURE: Droppable = Droppable;
+        }
+
+        let value = FIXTURE.get();
+
+        FIXTURE.teardown();
+
+        // Still referenced by `value`
+        assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 0);
+
+        drop(value);
+
+        assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn teardown_all() {
+        lazy_fixture! {
+            static FIXTURE_A: usize = 1;
+        }
+
+        lazy_fixture! {
+            static FIXTURE_B: usize = 2;
+        }
+
+        let _a = FIXTURE_A.get();
+        let _b = FIXTURE_B.get();
+
+        teardown_lazy_fixtures();
+
+        assert_not!(FIXTURE_A.is_initialized());
+        assert_not!(FIXTURE_B.is_initialized());
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        lazy_fixture! {
+            static FIXTURE: Vec<usize> = (0..100).collect();
+        }
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| thread::spawn(|| FIXTURE.get().len()))
+            .collect();
+
+        for handle in handles {
+            // Unwrap allowed to reduce length of test code.
+            #[allow(clippy::unwrap_used)]
+            let len = handle.join().unwrap();
+
+            assert_eq!(len, 100);
+        }
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Normalization of formatted panic messages so that they can be compared across refactors.
+//!
+//! Formatted panic messages contain parts that change whenever unrelated code moves around: the
+//! file and line of the assertion, terminal styling, the source code snippet, and the backtrace.
+//! Library authors who write their own assertions can use [`normalize_panic_message`] to replace or
+//! remove these parts before asserting on or snapshotting the output.
+
+use std::{iter, path::Path};
+
+/// The placeholder that replaces source file paths.
+pub const FILE_PLACEHOLDER: &str = "<file>";
+
+/// The placeholder that replaces line numbers.
+pub const LINE_PLACEHOLDER: &str = "<line>";
+
+/// The placeholder that replaces column numbers.
+pub const COLUMN_PLACEHOLDER: &str = "<column>";
+
+/// The placeholder that replaces the backtrace section.
+pub const BACKTRACE_PLACEHOLDER: &str = "<backtrace>";
+
+/// The message printed in place of a backtrace when backtrace capturing is disabled.
+const BACKTRACE_DISABLED_MESSAGE_PREFIX: &str = "note: run with `RUST_BACKTRACE=1`";
+
+/// Normalizes the volatile parts of a formatted panic message into placeholders.
+///
+/// The following replacements are made:
+///
+/// * Terminal styling (ANSI escape codes) is removed.
+/// * Source locations like `src/lib.rs:42` or `src/lib.rs:42:9` become `<file>:<line>` or
+///   `<file>:<line>:<column>`.
+/// * The snippet of source code under the first line, which is shown with the `source-snippet`
+///   feature, is removed. This way the result is the same whether or not the feature is enabled.
+/// * The backtrace section at the end of the message, or the note about enabling backtraces,
+///   becomes `<backtrace>`.
+///
+/// Everything else is left untouched, so the result still contains the assertion description,
+/// argument values, and any other details produced by the assertion.
+///
+/// # Arguments
+///
+/// * `message` - The formatted panic message, usually from [`PanicMessageBuilder::format`].
+///
+/// # Returns
+///
+/// The normalized panic message.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::normalize::normalize_panic_message;
+/// let message = "assertion failure at src/lib.rs:42:5: x == y\n\n\
+///                note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace";
+///
+/// assert_eq!(
+///     normalize_panic_message(message),
+///     "assertion failure at <file>:<line>:<column>: x == y\n\n<backtrace>"
+/// );
+/// ```
+///
+/// [`PanicMessageBuilder::format`]: crate::utilities::panic_message_builder::PanicMessageBuilder::format
+#[must_use]
+pub fn normalize_panic_message(message: &str) -> String {
+    let message = strip_source_snippet(&console::strip_ansi_codes(message));
+
+    let (body, has_backtrace) = split_backtrace(&message);
+
+    let mut normalized = body
+        .split('\n')
+        .map(normalize_line)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if has_backtrace {
+        normalized.push_str("\n\n");
+        normalized.push_str(BACKTRACE_PLACEHOLDER);
+    }
+
+    normalized
+}
+
+/// Removes the snippet of source code under the first line of a panic message, if there is one.
+///
+/// The snippet is a line of source code with its line number in a gutter, like `  42 | code`,
+/// followed by a line of carets with an empty gutter.
+fn strip_source_snippet(message: &str) -> String {
+    let lines: Vec<&str> = message.split('\n').collect();
+
+    match lines.as_slice() {
+        [first_line, code_line, caret_line, rest @ ..]
+            if is_snippet_line(code_line, true)
+                && is_snippet_line(caret_line, false)
+                && caret_line.contains('^') =>
+        {
+            iter::once(first_line)
+                .chain(rest)
+                .copied()
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        _ => message.to_owned(),
+    }
+}
+
+/// Checks whether or not a line starts with the gutter of a source code snippet.
+///
+/// # Arguments
+///
+/// * `line` - The line to check.
+/// * `has_line_number` - Whether the gutter should contain a line number, or only spaces.
+fn is_snippet_line(line: &str, has_line_number: bool) -> bool {
+    let Some((gutter, _)) = line
+        .strip_prefix("  ")
+        .and_then(|rest| rest.split_once(" |"))
+    else {
+        return false;
+    };
+
+    !gutter.is_empty()
+        && gutter.bytes().all(|byte| {
+            if has_line_number {
+                byte.is_ascii_digit()
+            } else {
+                byte == b' '
+            }
+        })
+}
+
+/// Splits the backtrace section off of the end of a panic message.
+///
+/// # Returns
+///
+/// The message without the backtrace section and whether or not a backtrace section was found.
+fn split_backtrace(message: &str) -> (&str, bool) {
+    let mut search_start = 0;
+
+    while let Some(offset) = message
+        .get(search_start..)
+        .and_then(|rest| rest.find("\n\n"))
+    {
+        let separator_start = search_start.saturating_add(offset);
+        let section_start = separator_start.saturating_add(2);
+        let section = message.get(section_start..).unwrap_or_default();
+
+        if is_backtrace_section(section) {
+            return (message.get(..separator_start).unwrap_or(message), true);
+        }
+
+        search_start = section_start;
+    }
+
+    (message, false)
+}
+
+/// Checks whether or not some text is the start of a backtrace section.
+fn is_backtrace_section(section: &str) -> bool {
+    if section.starts_with(BACKTRACE_DISABLED_MESSAGE_PREFIX) {
+        return true;
+    }
+
+    // Captured backtraces start with the first frame, which looks like "   0: symbol".
+    section
+        .trim_start_matches(' ')
+        .strip_prefix('0')
+        .map_or(false, |rest| rest.starts_with(':'))
+}
+
+/// Normalizes all source locations within a single line.
+fn normalize_line(line: &str) -> String {
+    line.split(' ')
+        .map(normalize_word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Normalizes a single word if it is a source location, otherwise returns it unchanged.
+fn normalize_word(word: &str) -> String {
+    let trimmed = word.trim_end_matches([':', ',']);
+    let suffix = word.get(trimmed.len()..).unwrap_or_default();
+
+    let mut parts = trimmed.split(':');
+
+    let Some(file) = parts.next() else {
+        return word.to_owned();
+    };
+
+    let numbers: Vec<&str> = parts.collect();
+
+    let is_location = Path::new(file)
+        .extension()
+        .map_or(false, |extension| extension.eq_ignore_ascii_case("rs"))
+        && (1..=2).contains(&numbers.len())
+        && numbers
+            .iter()
+            .all(|number| !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit()));
+
+    if !is_location {
+        return word.to_owned();
+    }
+
+    if numbers.len() == 1 {
+        format!("{FILE_PLACEHOLDER}:{LINE_PLACEHOLDER}{suffix}")
+    } else {
+        format!("{FILE_PLACEHOLDER}:{LINE_PLACEHOLDER}:{COLUMN_PLACEHOLDER}{suffix}")
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_eq,
+        utilities::panic_message_builder::{MessageType, PanicMessageBuilder},
+    };
+    use std::panic::Location;
+
+    #[test]
+    fn normalize_plain_text() {
+        assert_eq!(normalize_panic_message("hello, world"), "hello, world");
+    }
+
+    #[test]
+    fn normalize_location() {
+        assert_eq!(
+            normalize_panic_message("failure at src/lib.rs:42: description"),
+            "failure at <file>:<line>: description"
+        );
+    }
+
+    #[test]
+    fn normalize_location_with_column() {
+        assert_eq!(
+            normalize_panic_message("called from tests/example.rs:1:23, then"),
+            "called from <file>:<line>:<column>, then"
+        );
+    }
+
+    #[test]
+    fn normalize_location_like_words_unchanged() {
+        assert_eq!(
+            normalize_panic_message("lib.rs:: lib.rs:x main.c:3 rs:1 lib.rs:1:2:3"),
+            "lib.rs:: lib.rs:x main.c:3 rs:1 lib.rs:1:2:3"
+        );
+    }
+
+    #[test]
+    fn normalize_backtrace_disabled_message() {
+        assert_eq!(
+            normalize_panic_message(
+                "a\n\nb\n\nnote: run with `RUST_BACKTRACE=1` environment variable to display a \
+                 backtrace"
+            ),
+            "a\n\nb\n\n<backtrace>"
+        );
+    }
+
+    #[test]
+    fn normalize_backtrace_captured() {
+        assert_eq!(
+            normalize_panic_message(
+                "a\n\n   0: std::backtrace::Backtrace::capture\n             at src/backtrace.rs:1"
+            ),
+            "a\n\n<backtrace>"
+        );
+    }
+
+    #[test]
+    fn normalize_source_snippet() {
+        assert_eq!(
+            normalize_panic_message(
+                "failure at src/lib.rs:42:5: x == y\n  42 |     assert_eq!(x, y);\n     |     \
+                 ^^^^^^^^^^^^^^^^^\n  x: 1"
+            ),
+            "failure at <file>:<line>:<column>: x == y\n  x: 1"
+        );
+    }
+
+    #[test]
+    fn normalize_source_snippet_like_lines_unchanged() {
+        assert_eq!(
+            normalize_panic_message("a\n  x | y\n    | ^\n  1 | 2\n    | 3"),
+            "a\n  x | y\n    | ^\n  1 | 2\n    | 3"
+        );
+    }
+
+    #[test]
+    fn normalize_ansi_codes() {
+        assert_eq!(
+            normalize_panic_message("\u{1b}[2mat src/lib.rs:1\u{1b}[0m"),
+            "at <file>:<line>"
+        );
+    }
+
+    #[test]
+    fn normalize_formatted_panic_message() {
+        let message =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "x == y", Location::caller())
+                .with_argument_formatted("x", "1", "== 1")
+                .unwrap()
+                .format();
+
+        let normalized = normalize_panic_message(message.as_str());
+
+        assert_eq!(
+            normalized.lines().next().unwrap(),
+            "\u{26CC} assertion failure at <file>:<line>:<column>: x == y"
+        );
+        assert!(normalized.ends_with("\n\n<backtrace>"));
+    }
+
+    #[cfg(feature = "source-snippet")]
+    #[test]
+    fn normalize_formatted_panic_message_with_source_snippet() {
+        let message =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "x == y", Location::caller())
+                .format();
+
+        // The snippet is read from this file, so it is in the message before normalization
+        assert!(console::strip_ansi_codes(&message).contains(" | "));
+
+        assert_eq!(
+            normalize_panic_message(message.as_str()),
+            "\u{26CC} assertion failure at <file>:<line>:<column>: x == y\n\n<backtrace>"
+        );
+    }
+}
@@ -14,14 +14,32 @@
 // not, see <https://www.gnu.org/licenses/>.
 
 use crate::errors::TestUrCodeXDError;
-use crate::utilities::truncate::Truncate;
-use console::{style, Color};
+use crate::utilities::assertion_failure::{
+    clear_last_assertion_failure, set_last_assertion_failure, set_returned_assertion_failure,
+    AssertionArgument, AssertionFailure,
+};
+use crate::utilities::color_choice::{is_color_enabled, style};
+use crate::utilities::failure_behavior::{
+    failure_behavior, get_exit_code, FailureBehavior, OutputTarget,
+};
+#[cfg(feature = "source-snippet")]
+use crate::utilities::source_snippet::{format_source_snippet, read_source_line};
+use crate::utilities::theme::{set_theme, theme, Theme};
+use crate::utilities::truncate::{truncate_middle_bytes, Truncate};
+use crate::utilities::verbosity::{
+    max_value_bytes, verbosity, Verbosity, MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE,
+};
+use console::Color;
 use indent_write::fmt::IndentWriter;
 use std::{
     backtrace::{Backtrace, BacktraceStatus},
+    cell::{Cell, RefCell},
     error::Error,
     fmt::{Debug, Display},
-    panic::{self, Location},
+    panic::{self, AssertUnwindSafe, Location},
+    process,
+    sync::Once,
+    thread,
 };
 use std::{fmt::Write, mem};
 use unicode_segmentation::UnicodeSegmentation;
@@ -37,6 +55,124 @@ const VALUE_DESCRIPTION_MAX_GRAPHEME_LEN: usize = 50;
 /// The prefix to use before a debug representation of a value
 pub const DEBUGGED_VALUE_PREFIX: &str = "== ";
 
+/// The minimum number of lines in a debug representation before identical lines are collapsed.
+const COLLAPSE_MIN_LINE_COUNT: usize = 20;
+
+/// The number of identical lines to keep around each differing line when collapsing.
+const COLLAPSE_CONTEXT_LINE_COUNT: usize = 2;
+
+thread_local! {
+    /// Whether or not panic messages should be printed to stderr for the current thread.
+    static IS_PANIC_OUTPUT_SUPPRESSED: Cell<bool> = const { Cell::new(false) };
+
+    /// The formatted assertion failure for the panic hook to print for the next panic on the
+    /// current thread.
+    static PENDING_PANIC_OUTPUT: RefCell<Option<(String, OutputTarget)>> =
+        const { RefCell::new(None) };
+}
+
+/// Prints an assertion failure to the given output target.
+//
+// Stderr and stdout printing is allowed because this is how assertion failures are shown.
+#[allow(clippy::print_stderr, clippy::print_stdout)]
+fn print_panic_output(message: &str, output_target: OutputTarget) {
+    match output_target {
+        OutputTarget::Stderr => eprintln!("{message}"),
+        OutputTarget::Stdout => println!("{message}"),
+    }
+}
+
+/// Installs the panic hook that prints assertion failures, if it has not been installed yet.
+///
+/// The hook is installed once per process and wraps whichever hook was installed before it. Panics
+/// from assertion failures print the formatted failure instead of the usual panic message, and all
+/// other panics are passed on to the previous hook. This means that hooks installed by the user
+/// before the first assertion failure keep working.
+///
+/// Assertion failures are not passed on to the previous hook. There is no way to tell a hook
+/// installed by the user apart from the standard library's default hook, which would print a
+/// second, unformatted message for every failure. Code that needs to know about assertion failures
+/// can catch the panic and call
+/// [`last_assertion_failure`](crate::utilities::assertion_failure::last_assertion_failure) instead.
+///
+/// Other panics also clear the last assertion failure for the thread, so that
+/// [`last_assertion_failure`](crate::utilities::assertion_failure::last_assertion_failure) only
+/// returns failures that caused the most recent panic.
+///
+/// Nothing is printed for panics on threads where panic output is suppressed. The hook is never
+/// swapped out to suppress output, since that would also silence panics on other threads.
+pub(crate) fn install_panic_hook() {
+    static INSTALL_PANIC_HOOK: Once = Once::new();
+
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |panic_info| {
+            // The thread local may have been destroyed already if the panic happens while the
+            // thread is exiting, in which case there can't be a pending assertion failure.
+            let pending_panic_output = PENDING_PANIC_OUTPUT.try_with(RefCell::take).ok().flatten();
+
+            let is_suppressed = IS_PANIC_OUTPUT_SUPPRESSED
+                .try_with(Cell::get)
+                .unwrap_or(false);
+
+            if let Some((message, output_target)) = pending_panic_output {
+                if !is_suppressed {
+                    print_panic_output(&message, output_target);
+                }
+            } else {
+                clear_last_assertion_failure();
+
+                if !is_suppressed {
+                    previous_hook(panic_info);
+                }
+            }
+        }));
+    });
+}
+
+/// Calls an action and catches any panics without printing their messages to stderr.
+///
+/// This is useful for running an action that is expected to panic repeatedly, where only the
+/// final result should be shown to the user.
+///
+/// # Arguments
+///
+/// * `action` - The action to call.
+///
+/// # Returns
+///
+/// The result of [`panic::catch_unwind`].
+pub(crate) fn catch_unwind_silently<ResultType>(
+    action: impl FnOnce() -> ResultType,
+) -> thread::Result<ResultType> {
+    // The hook checks the thread-local flag, so panics on other threads are still printed.
+    install_panic_hook();
+
+    let was_suppressed =
+        IS_PANIC_OUTPUT_SUPPRESSED.with(|is_suppressed| is_suppressed.replace(true));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    IS_PANIC_OUTPUT_SUPPRESSED.with(|is_suppressed| is_suppressed.set(was_suppressed));
+
+    result
+}
+
+/// Gets whether or not panic messages are currently suppressed for this thread.
+pub(crate) fn is_panic_output_suppressed() -> bool {
+    IS_PANIC_OUTPUT_SUPPRESSED.with(Cell::get)
+}
+
+/// Sets whether or not panic messages are suppressed for this thread.
+///
+/// This is used to carry the setting over to threads that run actions on behalf of the caller.
+pub(crate) fn set_panic_output_suppressed(is_suppressed: bool) {
+    IS_PANIC_OUTPUT_SUPPRESSED.with(|is_panic_output_suppressed| {
+        is_panic_output_suppressed.set(is_suppressed);
+    });
+}
+
 /// Gets the number of graphemes in the debugged value prefix.
 #[must_use]
 pub fn get_debugged_value_prefix_grapheme_len() -> usize {
@@ -52,11 +188,7 @@ pub enum MessageType {
 
 impl MessageType {
     pub fn symbol_color(self) -> Color {
-        match self {
-            Self::AssertionFailure => Color::Red,
-            Self::ErrorWhileCheckingAssertion => Color::Magenta,
-            Self::InternalError => Color::Cyan,
-        }
+        theme().symbol_color(self)
     }
 
     pub fn message_prefix(self) -> &'static str {
@@ -109,9 +241,90 @@ pub struct PanicMessageBuilder {
 
     /// A flag that is set when the first assertion description is set.
     has_assertion_description: bool,
+
+    /// The category of the assertion, if one was set.
+    category: Option<String>,
+
+    /// How much detail to include in the message.
+    verbosity: Verbosity,
+
+    /// The location of the assertion, used for reports and source snippets.
+    #[cfg_attr(
+        not(any(feature = "report", feature = "source-snippet")),
+        allow(dead_code)
+    )]
+    location: &'static Location<'static>,
+
+    /// The type of message, used for the color of source snippets.
+    #[cfg_attr(not(feature = "source-snippet"), allow(dead_code))]
+    message_type: MessageType,
+
+    /// Whether or not identical lines are collapsed in large values.
+    is_collapsing_identical_lines: bool,
+
+    /// The maximum size of each value in bytes before its middle is removed, or `None` if values
+    /// are never truncated.
+    max_value_bytes: Option<usize>,
+
+    /// A flag that is set when any value is truncated, so that a hint can be shown.
+    has_truncated_values: bool,
+
+    /// The debug representation of the last argument, if it was the last thing added to the
+    /// buffer.
+    previous_value: Option<PreviousValue>,
+
+    /// Structured information about the failure, which can be captured with
+    /// [`capture_assertion_failure`](crate::utilities::assertion_failure::capture_assertion_failure).
+    failure: AssertionFailure,
+
+    /// What happens when the message is panicked with, if it overrides the process-wide failure
+    /// behavior.
+    failure_behavior: Option<FailureBehavior>,
+
+    /// Where the message is printed when it is panicked with.
+    output_target: OutputTarget,
+
+    /// The colors used to format the message, copied from the global theme when the builder is
+    /// created.
+    theme: Theme,
+}
+
+/// The debug representation of an argument that has already been added to the buffer.
+///
+/// This is kept so that it can be rewritten with identical lines collapsed once the next argument
+/// is added.
+struct PreviousValue {
+    /// The offset in the buffer where the value starts.
+    start: usize,
+
+    /// The offset in the buffer where the value ends.
+    end: usize,
+
+    /// The indentation used for the value.
+    indent: String,
+
+    /// The uncolored pretty debug representation of the value.
+    debug_string: String,
+}
+
+/// A line of a debug representation to print.
+#[derive(Debug, PartialEq, Eq)]
+enum ValueLine<'value> {
+    /// A line that is printed as-is.
+    Shown(&'value str),
+
+    /// A run of lines that were identical to the other value and are collapsed into one line.
+    Collapsed {
+        /// The indentation of the first collapsed line.
+        indent: &'value str,
+
+        /// The number of collapsed lines.
+        count: usize,
+    },
 }
 
 impl PanicMessageBuilder {
+    #[track_caller]
     pub fn unwrap_error_with<
         ValueType,
         ErrorType: Error,
@@ -122,18 +335,17 @@ impl PanicMessageBuilder {
         error_description: &str,
         configure_panic_message: ConfigurePanicMessageType,
     ) -> ValueType {
+        // Closures can't track their caller, so the location has to be read out here
+        let location = Location::caller();
+
         match result {
             // If the result is Ok(...) do nothing and just return the value
             Ok(value) => value,
             // If the result is an Err(...), handle it:
             Err(error) => {
                 // Try to create the panic message builder for the error
-                let panic_message_builder = Self::new_from_error(
-                    message_type,
-                    error_description,
-                    Location::caller(),
-                    &error,
-                );
+                let panic_message_builder =
+                    Self::new_from_error(message_type, error_description, location, &error);
 
                 // Try to configure the panic message builder
                 let panic_message_builder = panic_message_builder.and_then(configure_panic_message);
@@ -143,7 +355,7 @@ impl PanicMessageBuilder {
                     Self::new_from_error(
                         MessageType::InternalError,
                         "unable to format unwrapped error",
-                        Location::caller(),
+                        location,
                         &error,
                     )
                     // If even the internal error can't be formatted, use .expect(...) to panic as a fallback
@@ -185,22 +397,42 @@ impl PanicMessageBuilder {
         predicate_description: impl Display,
         location: &'static Location<'static>,
     ) -> Self {
+        let predicate_description = predicate_description.to_string();
+        let theme = theme();
+
         Self {
-            panic_message: format!("{predicate_description}"),
+            panic_message: predicate_description.clone(),
             buffer: format!(
                 "{} {} {}: {}",
                 style("\u{26CC}")
-                    .fg(message_type.symbol_color())
+                    .fg(theme.symbol_color(message_type))
                     .bright()
                     .bold(),
                 message_type.message_prefix(),
-                style(format!("at {}:{}", location.file(), location.line(),)).dim(),
-                style(predicate_description)
-                    .fg(Color::White)
+                theme.dim(format!(
+                    "at {}:{}:{}",
+                    location.file(),
+                    location.line(),
+                    location.column()
+                )),
+                style(predicate_description.as_str())
+                    .fg(theme.predicate_color)
                     .bright()
                     .bold(),
             ),
             has_assertion_description: false,
+            category: None,
+            verbosity: verbosity(),
+            location,
+            message_type,
+            is_collapsing_identical_lines: true,
+            max_value_bytes: max_value_bytes(),
+            has_truncated_values: false,
+            previous_value: None,
+            failure: AssertionFailure::new(predicate_description, location),
+            failure_behavior: None,
+            output_target: OutputTarget::default(),
+            theme,
         }
     }
 
@@ -285,52 +517,162 @@ impl PanicMessageBuilder {
         mut self,
         argument_description: impl Display,
         value_description: impl Display,
-        value: &impl Debug,
+        value: &(impl Debug + ?Sized),
     ) -> Result<Self, TestUrCodeXDError> {
         // Format the components
-        let value_description_string =
-            PanicMessageBuilder::format_value_description(value_description);
+        let value_description = value_description.to_string();
+
+        let value_description_string = self.format_value_description(&value_description);
 
         let value_string = format!("{value:?}");
 
         let argument_description_string = format!("{argument_description}:");
 
-        // Format and push the components to the buffer
-        let indent_argument_description = " ".repeat(2);
+        self.failure.arguments.push(AssertionArgument {
+            name: argument_description.to_string(),
+            value_description,
+            value: value_string.clone(),
+        });
+
+        // The previous value can only be rewritten if nothing else was added after it
+        let buffer_len = self.buffer.len();
+
+        let previous_value = self
+            .previous_value
+            .take()
+            .filter(|previous_value| previous_value.end == buffer_len);
+
+        // In compact mode, the value goes on the same line as the argument
+        if self.verbosity == Verbosity::Compact {
+            write!(
+                self.buffer,
+                "\n  {argument_description_string} {value_description_string}"
+            )?;
+
+            if value_description_string != value_string {
+                let value_string = self.truncate_value(value_string);
+
+                write!(
+                    self.buffer,
+                    " {}{}",
+                    self.theme.dim(DEBUGGED_VALUE_PREFIX),
+                    style(value_string).fg(self.theme.value_color)
+                )?;
+            }
 
-        let mut indented_argument_description =
-            IndentWriter::new(indent_argument_description.as_str(), String::new());
+            return Ok(self);
+        }
 
+        // Format and push the components to the buffer
         write!(
-            indented_argument_description,
+            self.buffer,
             "\n  {} {}",
             style(argument_description_string.as_str()),
             style(&value_description_string).fg(if value_description_string == value_string {
-                Color::Cyan
+                self.theme.value_color
             } else {
-                Color::White
+                self.theme.argument_color
             }),
         )?;
 
         // If the value description is different from the value, format and push the value
-        if value_description_string != value_string {
-            let indent = " ".repeat(3 + argument_description_string.graphemes(true).count());
+        if value_description_string == value_string {
+            return Ok(self);
+        }
+
+        let indent = " ".repeat(3 + argument_description_string.graphemes(true).count());
 
-            let mut indented = IndentWriter::new(indent.as_str(), String::new());
+        let debug_string = self.truncate_value(format!("{value:#?}"));
 
-            write!(
-                indented,
-                "\n{}{:#?}",
-                style(DEBUGGED_VALUE_PREFIX).dim(),
-                style(value).fg(Color::Cyan)
-            )?;
+        // If the previous argument was large and shares lines with this one, print only the lines
+        // that differ for both of them
+        if let Some(previous_value) = previous_value
+            .filter(|previous_value| self.is_collapsible(previous_value, &debug_string))
+        {
+            let previous_lines: Vec<&str> = previous_value.debug_string.lines().collect();
+            let lines: Vec<&str> = debug_string.lines().collect();
+
+            let (previous_identical, identical) = find_identical_lines(&previous_lines, &lines);
+
+            if identical.contains(&true) && identical.contains(&false) {
+                let collapsed_previous = format_value_lines(
+                    &collapse_lines(&previous_lines, &previous_identical),
+                    &self.theme,
+                )?;
+
+                let collapsed =
+                    format_value_lines(&collapse_lines(&lines, &identical), &self.theme)?;
+
+                self.buffer.replace_range(
+                    previous_value.start..previous_value.end,
+                    &indent_value(&previous_value.indent, &collapsed_previous, &self.theme)?,
+                );
 
-            self.buffer.push_str(indented.get_ref());
+                self.push_value(indent, debug_string, &collapsed)?;
+
+                return Ok(self);
+            }
         }
 
+        let formatted = format_value_lines(
+            &debug_string
+                .lines()
+                .map(ValueLine::Shown)
+                .collect::<Vec<ValueLine<'_>>>(),
+            &self.theme,
+        )?;
+
+        self.push_value(indent, debug_string, &formatted)?;
+
         Ok(self)
     }
 
+    /// Pushes the formatted debug representation of an argument to the buffer and remembers it as
+    /// the previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `indent` - The indentation to use for the value.
+    /// * `debug_string` - The uncolored pretty debug representation of the value.
+    /// * `formatted` - The formatted lines to push.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors with formatting.
+    fn push_value(
+        &mut self,
+        indent: String,
+        debug_string: String,
+        formatted: &str,
+    ) -> Result<(), TestUrCodeXDError> {
+        let start = self.buffer.len();
+
+        self.buffer
+            .push_str(&indent_value(&indent, formatted, &self.theme)?);
+
+        self.previous_value = Some(PreviousValue {
+            start,
+            end: self.buffer.len(),
+            indent,
+            debug_string,
+        });
+
+        Ok(())
+    }
+
+    /// Gets whether or not identical lines can be collapsed between the previous value and the
+    /// next one.
+    ///
+    /// This is only possible if collapsing is enabled, the verbosity is not
+    /// [`Verbosity::Verbose`], and one of the values is large.
+    #[must_use]
+    fn is_collapsible(&self, previous_value: &PreviousValue, debug_string: &str) -> bool {
+        self.is_collapsing_identical_lines
+            && self.verbosity != Verbosity::Verbose
+            && (previous_value.debug_string.lines().count() >= COLLAPSE_MIN_LINE_COUNT
+                || debug_string.lines().count() >= COLLAPSE_MIN_LINE_COUNT)
+    }
+
     /// Adds a pre-formatted argument to the panic message.
     ///
     /// This will print the argument's expression and the formatted string for its value.
@@ -380,29 +722,43 @@ impl PanicMessageBuilder {
         // Format the components
         let argument_description_string = format!("{argument_description}:");
 
+        let value_description = value_description.to_string();
+
+        let value_description_string = self.format_value_description(&value_description);
+
+        self.failure.arguments.push(AssertionArgument {
+            name: argument_description.to_string(),
+            value_description,
+            value: value.as_ref().to_owned(),
+        });
+
         // Format and push the components to the buffer
         self.buffer.push_str(
             format!(
                 "\n  {} {}",
                 style(argument_description_string.as_str()),
-                style(PanicMessageBuilder::format_value_description(
-                    value_description
-                ))
-                .fg(Color::White),
+                style(value_description_string).fg(self.theme.argument_color),
             )
             .as_str(),
         );
 
-        // Format and push the value itself
+        // Format and push the value itself, which goes on the same line in compact mode
         let indent = " ".repeat(3 + argument_description_string.graphemes(true).count());
 
+        let value_string = self.truncate_value(value.as_ref().to_owned());
+
         let mut indented = IndentWriter::new(indent.as_str(), String::new());
 
         write!(
             indented,
-            "\n{}{}",
-            style(DEBUGGED_VALUE_PREFIX).dim(),
-            style(value.as_ref()).fg(Color::Cyan)
+            "{}{}{}",
+            if self.verbosity == Verbosity::Compact {
+                " "
+            } else {
+                "\n"
+            },
+            self.theme.dim(DEBUGGED_VALUE_PREFIX),
+            style(value_string).fg(self.theme.value_color)
         )?;
 
         self.buffer.push_str(indented.get_ref());
@@ -410,14 +766,41 @@ impl PanicMessageBuilder {
         Ok(self)
     }
 
-    /// Formats a value description
+    /// Truncates a formatted value if it is larger than the maximum value size, unless the
+    /// verbosity is [`Verbosity::Verbose`].
+    fn truncate_value(&mut self, value: String) -> String {
+        if self.verbosity == Verbosity::Verbose {
+            return value;
+        }
+
+        match self
+            .max_value_bytes
+            .and_then(|max_value_bytes| truncate_middle_bytes(&value, max_value_bytes))
+        {
+            Some(truncated) => {
+                self.has_truncated_values = true;
+
+                truncated
+            }
+            None => value,
+        }
+    }
+
+    /// Formats a value description, which is only truncated if the verbosity is not
+    /// [`Verbosity::Verbose`].
     #[must_use]
-    fn format_value_description(value_description: impl Display) -> String {
-        format!("{value_description}").to_truncated(
-            VALUE_DESCRIPTION_SEPARATOR,
-            TruncationMode::End,
-            VALUE_DESCRIPTION_MAX_GRAPHEME_LEN,
-        )
+    fn format_value_description(&self, value_description: impl Display) -> String {
+        let value_description = format!("{value_description}");
+
+        if self.verbosity == Verbosity::Verbose {
+            value_description
+        } else {
+            value_description.to_truncated(
+                VALUE_DESCRIPTION_SEPARATOR,
+                TruncationMode::End,
+                VALUE_DESCRIPTION_MAX_GRAPHEME_LEN,
+            )
+        }
     }
 
     /// Adds an assertion description to the panic message.
@@ -451,97 +834,551 @@ impl PanicMessageBuilder {
                 .push_str(format!("\n  info: {assertion_description}").as_str());
 
             self.has_assertion_description = true;
+
+            self.failure.description = Some(assertion_description.to_owned());
         }
 
         Ok(self)
     }
 
+    /// Adds a category to the panic message.
+    ///
+    /// Categories are free-form tags like `"io"` or `"parsing"` that group assertions by subsystem.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the assertion. If this is an empty string, none is added. If a
+    ///                category is already set, it is replaced.
+    #[must_use]
+    pub fn with_category(mut self, category: impl AsRef<str>) -> Self {
+        let category = category.as_ref();
+
+        if !category.is_empty() {
+            self.category = Some(category.to_owned());
+        }
+
+        self
+    }
+
+    /// Adds a key/value pair of context to the panic message.
+    ///
+    /// Context is printed at the end of the panic message, after any context from enclosing
+    /// [`assertion_context`](crate::assertion_context) scopes.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the context, like `"customer_id"`.
+    /// * `value` - The value of the context, which is formatted with [`Debug`].
+    #[must_use]
+    pub fn with_context(mut self, key: impl AsRef<str>, value: &(impl Debug + ?Sized)) -> Self {
+        self.failure
+            .context
+            .push((key.as_ref().to_owned(), format!("{value:?}")));
+
+        self
+    }
+
+    /// Sets how much detail to include in the message.
+    ///
+    /// By default, this is read from the `TEST_UR_CODE_XD_VERBOSITY` environment variable when the
+    /// builder is created. It should be set before any arguments are added since they are formatted
+    /// as they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbosity` - The verbosity to use.
+    #[must_use]
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+
+        self
+    }
+
+    /// Sets the maximum size of each value in the message, in bytes.
+    ///
+    /// Values that are larger have their middle removed, and a hint for how to see them in full is
+    /// added to the message. By default, this is read from the `TEST_UR_CODE_XD_MAX_VALUE_BYTES`
+    /// environment variable when the builder is created. Values are never truncated if the
+    /// verbosity is [`Verbosity::Verbose`]. It should be set before any arguments are added since
+    /// they are formatted as they are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_value_bytes` - The maximum size in bytes, or `None` to never truncate values.
+    #[must_use]
+    pub fn with_max_value_bytes(mut self, max_value_bytes: Option<usize>) -> Self {
+        self.max_value_bytes = max_value_bytes;
+
+        self
+    }
+
+    /// Sets whether or not identical lines are collapsed in large values.
+    ///
+    /// When this is enabled, which is the default, and an argument with a large debug
+    /// representation is added right after another one, only the lines that differ between them
+    /// are printed. Runs of identical lines are collapsed into a single `… N identical lines …`
+    /// line. Values are always printed in full if the verbosity is [`Verbosity::Verbose`].
+    ///
+    /// # Arguments
+    ///
+    /// * `is_collapsing_identical_lines` - Whether or not to collapse identical lines.
+    #[must_use]
+    pub fn with_collapsed_identical_lines(mut self, is_collapsing_identical_lines: bool) -> Self {
+        self.is_collapsing_identical_lines = is_collapsing_identical_lines;
+
+        self
+    }
+
+    /// Sets what happens when the message is panicked with, overriding the process-wide failure
+    /// behavior set with
+    /// [`set_failure_behavior`](crate::utilities::failure_behavior::set_failure_behavior).
+    ///
+    /// # Arguments
+    ///
+    /// * `failure_behavior` - The failure behavior to use.
+    #[must_use]
+    pub fn with_failure_behavior(mut self, failure_behavior: FailureBehavior) -> Self {
+        self.failure_behavior = Some(failure_behavior);
+
+        self
+    }
+
+    /// Sets where the message is printed when it is panicked with.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_target` - Where to print the message.
+    #[must_use]
+    pub fn with_output_target(mut self, output_target: OutputTarget) -> Self {
+        self.output_target = output_target;
+
+        self
+    }
+
+    /// Sets the colors used to format failure messages for the whole process.
+    ///
+    /// Builders that have already been created keep the theme that was set when they were created.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::utilities::{panic_message_builder::PanicMessageBuilder, theme::Theme};
+    /// #
+    /// PanicMessageBuilder::set_global_theme(Theme::light());
+    /// ```
+    pub fn set_global_theme(theme: Theme) {
+        set_theme(theme);
+    }
+
+    /// Gets the category of the assertion, if one was set with
+    /// [`with_category`](Self::with_category).
+    #[must_use]
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
     /// Formats the panic message but does not panic.
     ///
     /// This is the termination of the builder chain.
     ///
+    /// If colors are disabled by [`set_color_choice`](crate::output::set_color_choice)
+    /// or the `NO_COLOR` environment variable, the message has no colors or styles.
+    ///
     /// # Returns
     ///
     /// The formatted panic message.
     #[must_use]
     pub fn format(mut self) -> String {
-        // Format backtrace onto the end of the buffer
-        self.buffer
-            .push_str(format!("\n\n{}", PanicMessageBuilder::format_backtrace()).as_str());
+        // Format the line of source code containing the assertion under the first line of the
+        // buffer, unless in compact mode
+        #[cfg(feature = "source-snippet")]
+        if self.verbosity != Verbosity::Compact {
+            if let Some(line) = read_source_line(self.location) {
+                let first_line_end = self.buffer.find('\n').unwrap_or(self.buffer.len());
+
+                self.buffer.insert_str(
+                    first_line_end,
+                    &format_source_snippet(
+                        self.location.line(),
+                        self.location.column(),
+                        &line,
+                        self.theme.symbol_color(self.message_type),
+                        &self.theme,
+                    ),
+                );
+            }
+        }
+
+        // Format context onto the end of the buffer
+        for (key, value) in &self.failure.context {
+            self.buffer
+                .push_str(format!("\n  context: {key} = {value}").as_str());
+        }
+
+        // Format category onto the end of the buffer
+        if let Some(category) = &self.category {
+            self.buffer
+                .push_str(format!("\n  category: {category}").as_str());
+        }
+
+        // Format a hint for how to see truncated values in full onto the end of the buffer
+        if let Some(max_value_bytes) = self.max_value_bytes.filter(|_| self.has_truncated_values) {
+            self.buffer.push_str(
+                format!(
+                    "\n  {}",
+                    self.theme.dim(format!(
+                        "note: values larger than {max_value_bytes} bytes were truncated, set \
+                         `{MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE}=0` to see them in full"
+                    ))
+                )
+                .as_str(),
+            );
+        }
+
+        // Format backtrace onto the end of the buffer, unless in compact mode
+        if self.verbosity != Verbosity::Compact {
+            self.buffer.push_str(
+                format!(
+                    "\n\n{}",
+                    PanicMessageBuilder::format_backtrace(self.verbosity, &self.theme)
+                )
+                .as_str(),
+            );
+        }
 
         // Return the buffer
-        self.buffer
+        if is_color_enabled() {
+            self.buffer
+        } else {
+            console::strip_ansi_codes(&self.buffer).into_owned()
+        }
     }
 
     /// Format a backtrace
     ///
     /// # Returns
     ///
-    /// * If the backtrace was captured, the formatted backtrace.
+    /// * If the backtrace was captured, the formatted backtrace. It is always captured if the
+    ///   verbosity is [`Verbosity::Verbose`].
     /// * Otherwise, a message telling the user how to enable backtrace capturing.
     #[must_use]
-    fn format_backtrace() -> String {
-        let backtrace = Backtrace::capture();
+    fn format_backtrace(verbosity: Verbosity, theme: &Theme) -> String {
+        let backtrace = if verbosity == Verbosity::Verbose {
+            Backtrace::force_capture()
+        } else {
+            Backtrace::capture()
+        };
 
         if backtrace.status() == BacktraceStatus::Captured {
-            PanicMessageBuilder::format_backtrace_captured(backtrace)
+            PanicMessageBuilder::format_backtrace_captured(backtrace, theme)
         } else {
-            PanicMessageBuilder::format_backtrace_message()
+            PanicMessageBuilder::format_backtrace_message(theme)
         }
     }
 
     /// Format a captured backtrace
     #[must_use]
-    fn format_backtrace_captured(backtrace: Backtrace) -> String {
-        style(backtrace).dim().to_string()
+    fn format_backtrace_captured(backtrace: Backtrace, theme: &Theme) -> String {
+        theme.dim(backtrace).to_string()
     }
 
     /// Format a message telling the user how to enable backtrace capturing
     #[must_use]
-    fn format_backtrace_message() -> String {
-        style("note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace")
-            .dim()
+    fn format_backtrace_message(theme: &Theme) -> String {
+        theme
+            .dim("note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace")
             .to_string()
     }
 
+    /// Fails with the built message according to the failure behavior.
+    ///
+    /// This is the same as [`panic`](Self::panic), except that with
+    /// [`FailureBehavior::Return`] the failure is stored for
+    /// [`take_returned_assertion_failure`](crate::utilities::assertion_failure::take_returned_assertion_failure)
+    /// and this returns without printing anything.
+    pub(crate) fn fail(self) {
+        if self.failure_behavior.unwrap_or_else(failure_behavior) == FailureBehavior::Return {
+            set_returned_assertion_failure(Some(self.failure));
+
+            return;
+        }
+
+        self.panic();
+    }
+
     /// Triggers the panic with the built message.
     ///
     /// This is the termination of the builder chain.
     ///
+    /// If the failure behavior has been set to
+    /// [`FailureBehavior::Exit`](crate::utilities::failure_behavior::FailureBehavior::Exit), the
+    /// message is printed and the process exits instead.
+    ///
+    /// With the `report` feature, the failure is also recorded in the report file if one is set. See
+    /// [`report`](crate::utilities::report).
+    ///
+    /// The message is printed by a panic hook that is installed the first time an assertion fails.
+    /// It wraps the hook that was installed before it, which still runs for all other panics but not
+    /// for assertion failures.
+    ///
     /// # Returns
     ///
-    /// This function never returns. It always panics.
+    /// This function never returns. It always panics or exits.
     //
     // We do not need to document the panic in a function called `panic`.
     //
-    // Stderr printing is allowed for use in the panic hook.
-    //
     // Panics being allowed is obvious.
-    #[allow(clippy::missing_panics_doc, clippy::print_stderr, clippy::panic)]
+    //
+    // Exiting is allowed when the user has opted into it.
+    #[allow(clippy::missing_panics_doc, clippy::panic, clippy::exit)]
     pub fn panic(mut self) -> ! {
         let panic_message = mem::take(&mut self.panic_message);
 
+        #[cfg(feature = "report")]
+        let location = self.location;
+
+        let failure = self.failure.clone();
+
+        let behavior = self.failure_behavior.unwrap_or_else(failure_behavior);
+        let output_target = self.output_target;
+
         let buffer = self.format();
 
-        panic::set_hook(Box::new(move |_| {
-            eprintln!("{buffer}");
-        }));
+        #[cfg(feature = "report")]
+        if !IS_PANIC_OUTPUT_SUPPRESSED.with(Cell::get) {
+            crate::utilities::report::record_assertion_failure(&panic_message, location, &buffer);
+        }
+
+        if let Some(code) = get_exit_code(behavior, IS_PANIC_OUTPUT_SUPPRESSED.with(Cell::get)) {
+            print_panic_output(&buffer, output_target);
+
+            process::exit(code);
+        }
+
+        set_last_assertion_failure(failure);
+
+        install_panic_hook();
+
+        PENDING_PANIC_OUTPUT.with(|pending_panic_output| {
+            pending_panic_output.replace(Some((buffer, output_target)));
+        });
 
         panic!("{panic_message}");
     }
 }
 
-#[cfg(test)]
-// Unwrap allowed to reduce length of test code.
-#[allow(clippy::unwrap_used)]
-mod tests {
-    use super::*;
-    use crate::assert;
+/// Finds which lines are identical between two debug representations.
+///
+/// Lines are identical if they are part of the common prefix or suffix of both representations. If
+/// the lines between the prefix and suffix have the same count, they are also compared line by
+/// line so that several separate differences can be found.
+///
+/// # Arguments
+///
+/// * `lhs` - The lines of the first representation.
+/// * `rhs` - The lines of the second representation.
+///
+/// # Returns
+///
+/// Whether or not each line is identical, for each representation.
+#[allow(
+    // The indices are always within bounds since they're less than the lengths of the slices
+    clippy::indexing_slicing,
+    clippy::arithmetic_side_effects
+)]
+fn find_identical_lines(lhs: &[&str], rhs: &[&str]) -> (Vec<bool>, Vec<bool>) {
+    let min_len = lhs.len().min(rhs.len());
+
+    let prefix_len = lhs
+        .iter()
+        .zip(rhs)
+        .take_while(|(lhs_line, rhs_line)| lhs_line == rhs_line)
+        .count();
+
+    let suffix_len = lhs
+        .iter()
+        .rev()
+        .zip(rhs.iter().rev())
+        .take(min_len - prefix_len)
+        .take_while(|(lhs_line, rhs_line)| lhs_line == rhs_line)
+        .count();
+
+    let mut lhs_identical = vec![false; lhs.len()];
+    let mut rhs_identical = vec![false; rhs.len()];
+
+    for index in 0..prefix_len {
+        lhs_identical[index] = true;
+        rhs_identical[index] = true;
+    }
 
-    #[cfg(feature = "regex")]
-    use crate::assert_str_matches;
+    for index in 0..suffix_len {
+        lhs_identical[lhs.len() - 1 - index] = true;
+        rhs_identical[rhs.len() - 1 - index] = true;
+    }
 
-    #[derive(Debug)]
+    if lhs.len() == rhs.len() {
+        for index in prefix_len..(lhs.len() - suffix_len) {
+            let is_identical = lhs[index] == rhs[index];
+
+            lhs_identical[index] = is_identical;
+            rhs_identical[index] = is_identical;
+        }
+    }
+
+    (lhs_identical, rhs_identical)
+}
+
+/// Collapses runs of identical lines, keeping a few lines of context around each differing line.
+///
+/// # Arguments
+///
+/// * `lines` - The lines of the debug representation.
+/// * `is_identical` - Whether or not each line is identical to the other representation.
+///
+/// # Returns
+///
+/// The lines to print.
+#[allow(
+    // The indices are always within bounds since they're less than the lengths of the slices
+    clippy::indexing_slicing,
+    clippy::arithmetic_side_effects
+)]
+fn collapse_lines<'value>(lines: &[&'value str], is_identical: &[bool]) -> Vec<ValueLine<'value>> {
+    let mut result = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        if !is_identical[index] {
+            result.push(ValueLine::Shown(lines[index]));
+            index += 1;
+            continue;
+        }
+
+        // Find the end of the run of identical lines
+        let run_start = index;
+
+        while index < lines.len() && is_identical[index] {
+            index += 1;
+        }
+
+        // Keep context next to the differing lines on either side of the run
+        let leading_context = if run_start == 0 {
+            0
+        } else {
+            COLLAPSE_CONTEXT_LINE_COUNT
+        };
+
+        let trailing_context = if index == lines.len() {
+            0
+        } else {
+            COLLAPSE_CONTEXT_LINE_COUNT
+        };
+
+        let run_len = index - run_start;
+
+        // Collapsing a single line would not make the message any shorter
+        if run_len < leading_context + trailing_context + 2 {
+            result.extend(
+                lines[run_start..index]
+                    .iter()
+                    .copied()
+                    .map(ValueLine::Shown),
+            );
+            continue;
+        }
+
+        let collapsed_start = run_start + leading_context;
+        let collapsed_end = index - trailing_context;
+
+        result.extend(
+            lines[run_start..collapsed_start]
+                .iter()
+                .copied()
+                .map(ValueLine::Shown),
+        );
+
+        let first_collapsed_line = lines[collapsed_start];
+
+        result.push(ValueLine::Collapsed {
+            indent: first_collapsed_line
+                .strip_suffix(first_collapsed_line.trim_start())
+                .unwrap_or_default(),
+            count: collapsed_end - collapsed_start,
+        });
+
+        result.extend(
+            lines[collapsed_end..index]
+                .iter()
+                .copied()
+                .map(ValueLine::Shown),
+        );
+    }
+
+    result
+}
+
+/// Formats the lines of a debug representation with colors.
+///
+/// # Errors
+///
+/// * Returns any errors with formatting.
+fn format_value_lines(lines: &[ValueLine<'_>], theme: &Theme) -> Result<String, TestUrCodeXDError> {
+    let mut result = String::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+            result.push('\n');
+        }
+
+        match line {
+            ValueLine::Shown(text) => write!(result, "{}", style(text).fg(theme.value_color))?,
+            ValueLine::Collapsed { indent, count } => write!(
+                result,
+                "{indent}{}",
+                theme.dim(format!("\u{2026} {count} identical lines \u{2026}"))
+            )?,
+        }
+    }
+
+    Ok(result)
+}
+
+/// Indents a formatted debug representation and prefixes it with [`DEBUGGED_VALUE_PREFIX`] on a
+/// new line.
+///
+/// # Errors
+///
+/// * Returns any errors with formatting.
+fn indent_value(indent: &str, formatted: &str, theme: &Theme) -> Result<String, TestUrCodeXDError> {
+    let mut indented = IndentWriter::new(indent, String::new());
+
+    write!(
+        indented,
+        "\n{}{formatted}",
+        theme.dim(DEBUGGED_VALUE_PREFIX)
+    )?;
+
+    Ok(indented.into_inner())
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert,
+        utilities::color_choice::{set_color_choice, ColorChoice},
+    };
+
+    #[cfg(feature = "regex")]
+    use crate::assert_str_matches;
+
+    #[derive(Debug)]
     // Not sure why Rustc detects this as dead code since it's used below, but yeah we can ignore.
     #[allow(dead_code)]
     struct SomeStruct {
@@ -564,7 +1401,7 @@ mod tests {
     #[cfg(feature = "regex")]
     #[test]
     fn format_minimal() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message = PanicMessageBuilder::new(
             MessageType::AssertionFailure,
@@ -575,7 +1412,7 @@ mod tests {
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: lhs == rhs
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: lhs == rhs
 
 note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
         );
@@ -584,7 +1421,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_one_argument_description_matches() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message =
             PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
@@ -594,7 +1431,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: 
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: 
   lhs: 5
 
 note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
@@ -604,7 +1441,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_one_argument_description_does_not_match() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message =
             PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
@@ -614,7 +1451,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: 
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: 
   lhs: x
        == 5
 
@@ -625,7 +1462,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_two_arguments() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message =
             PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
@@ -637,7 +1474,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: 
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: 
   lhs: x
        == 5
   rhs: y
@@ -650,7 +1487,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_assertion_description_str() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message =
             PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
@@ -660,7 +1497,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: 
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: 
   info: assertion description
 
 note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
@@ -670,7 +1507,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_assertion_description_string() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         // The whole point of this test is to pass in a `String`
         #[allow(clippy::unnecessary_to_owned)]
@@ -682,13 +1519,65 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: 
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: 
   info: assertion description
 
 note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
         );
     }
 
+    #[test]
+    fn format_category() {
+        set_color_choice(ColorChoice::Never);
+
+        let message =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
+                .with_category("io")
+                .format();
+
+        assert!(message.contains("\n  category: io\n"));
+    }
+
+    #[test]
+    fn format_category_empty() {
+        set_color_choice(ColorChoice::Never);
+
+        let message =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
+                .with_category("")
+                .format();
+
+        assert!(!message.contains("  category: "));
+    }
+
+    #[test]
+    fn format_context() {
+        set_color_choice(ColorChoice::Never);
+
+        let message =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
+                .with_context("customer_id", &42)
+                .with_context("region", "eu")
+                .with_category("io")
+                .format();
+
+        assert!(message.contains(
+            "\n  context: customer_id = 42\n  context: region = \"eu\"\n  category: io\n"
+        ));
+    }
+
+    #[test]
+    fn category_getter() {
+        let panic_message_builder =
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller());
+
+        assert!(panic_message_builder.category().is_none());
+
+        let panic_message_builder = panic_message_builder.with_category("io");
+
+        assert_eq!(panic_message_builder.category(), Some("io"));
+    }
+
     #[test]
     fn two_assertion_descriptions() {
         assert!(
@@ -703,7 +1592,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_pretty_debug_argument() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message = PanicMessageBuilder::new(
             MessageType::AssertionFailure,
@@ -722,7 +1611,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
         .unwrap()
         .format();
 
-        assert_str_matches!(message, "\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\\.rs:[0-9]+: predicate description
+        assert_str_matches!(message, "\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\\.rs:[0-9]+:[0-9]+: predicate description
   argument: value
             == SomeStruct \\{
                 a: 1,
@@ -736,7 +1625,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace");
     #[cfg(feature = "regex")]
     #[test]
     fn format_no_multiline_argument_descriptions() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message = PanicMessageBuilder::new(
             MessageType::AssertionFailure,
@@ -749,7 +1638,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace");
 
         assert_str_matches!(
             message,
-            "\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\\.rs:[0-9]+: predicate description
+            "\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\\.rs:[0-9]+:[0-9]+: predicate description
   argument: a ...
             == 1
 
@@ -760,7 +1649,7 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
     #[cfg(feature = "regex")]
     #[test]
     fn format_truncate_argument_descriptions() {
-        console::set_colors_enabled(false);
+        set_color_choice(ColorChoice::Never);
 
         let message = PanicMessageBuilder::new(
             MessageType::AssertionFailure,
@@ -777,11 +1666,380 @@ note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
 
         assert_str_matches!(
             message,
-            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+: predicate description
+            r"\u{26cc} assertion failed at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: predicate description
   argument: aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa ...
             == 1
 
 note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace"
         );
     }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[cfg(feature = "source-snippet")]
+    fn format_source_snippet() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "lhs == rhs",
+            Location::caller(),
+        )
+        .with_argument("lhs", "x", &5)
+        .unwrap()
+        .format();
+
+        assert_str_matches!(
+            message,
+            r#"^\u{26cc} assertion failure at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: lhs == rhs
+  [0-9]+ \|             Location::caller\(\),
+  +\|             \^{19}
+  lhs: x"#
+        );
+    }
+
+    #[test]
+    fn format_compact() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "lhs == rhs",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Compact)
+        .with_argument(
+            "lhs",
+            "x",
+            &SomeStruct {
+                a: 1,
+                b: 2,
+                c: "3".to_owned(),
+            },
+        )
+        .unwrap()
+        .with_argument("rhs", "5", &5)
+        .unwrap()
+        .format();
+
+        assert_str_matches!(
+            message,
+            r#"^\u{26cc} assertion failure at crates/test-ur-code-xd/src/utilities/panic_message_builder\.rs:[0-9]+:[0-9]+: lhs == rhs
+  lhs: x == SomeStruct \{ a: 1, b: 2, c: "3" \}
+  rhs: 5$"#
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn format_truncates_large_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let value = format!("{}{}", "a".repeat(100), "b".repeat(100));
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_max_value_bytes(Some(20))
+        .with_argument("argument", "value", &value)
+        .unwrap()
+        .format();
+
+        assert!(message.contains(&format!(
+            "== \"{} \u{2026} 182 bytes omitted \u{2026} {}\"\n",
+            "a".repeat(9),
+            "b".repeat(9)
+        )));
+        assert!(message.contains(
+            "note: values larger than 20 bytes were truncated, set \
+             `TEST_UR_CODE_XD_MAX_VALUE_BYTES=0` to see them in full"
+        ));
+    }
+
+    #[test]
+    fn format_truncates_large_formatted_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_max_value_bytes(Some(4))
+        .with_argument_formatted("argument", "--", "abcdefghij")
+        .unwrap()
+        .format();
+
+        assert!(message.contains("== ab \u{2026} 6 bytes omitted \u{2026} ij\n"));
+        assert!(message.contains("TEST_UR_CODE_XD_MAX_VALUE_BYTES"));
+    }
+
+    #[test]
+    fn format_compact_truncates_large_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Compact)
+        .with_max_value_bytes(Some(4))
+        .with_argument("argument", "value", &"abcdefghij")
+        .unwrap()
+        .format();
+
+        assert!(
+            message.contains("  argument: value == \"a \u{2026} 8 bytes omitted \u{2026} j\"\n")
+        );
+    }
+
+    #[test]
+    fn format_does_not_truncate_small_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_max_value_bytes(Some(20))
+        .with_argument("argument", "value", &"abc")
+        .unwrap()
+        .format();
+
+        assert!(message.contains("== \"abc\"\n"));
+        assert!(!message.contains("TEST_UR_CODE_XD_MAX_VALUE_BYTES"));
+    }
+
+    #[test]
+    fn format_unlimited_value_size() {
+        set_color_choice(ColorChoice::Never);
+
+        let value = "a".repeat(100_000);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_max_value_bytes(None)
+        .with_argument("argument", "value", &value)
+        .unwrap()
+        .format();
+
+        assert!(message.contains(&value));
+        assert!(!message.contains("bytes omitted"));
+    }
+
+    #[test]
+    fn format_verbose_does_not_truncate_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let value = "a".repeat(100);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Verbose)
+        .with_max_value_bytes(Some(20))
+        .with_argument("argument", "value", &value)
+        .unwrap()
+        .format();
+
+        assert!(message.contains(&value));
+        assert!(!message.contains("TEST_UR_CODE_XD_MAX_VALUE_BYTES"));
+    }
+
+    #[test]
+    fn format_truncated_values_are_complete_in_failure() {
+        let value = "a".repeat(100);
+
+        let failure = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_max_value_bytes(Some(20))
+        .with_argument("argument", "value", &value)
+        .unwrap()
+        .failure;
+
+        assert_eq!(
+            failure.argument("argument").unwrap().value,
+            format!("{value:?}")
+        );
+    }
+
+    #[test]
+    fn format_verbose_does_not_truncate_argument_descriptions() {
+        set_color_choice(ColorChoice::Never);
+
+        let value_description = "a".repeat(VALUE_DESCRIPTION_MAX_GRAPHEME_LEN + 100);
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "predicate description",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Verbose)
+        .with_argument("argument", &value_description, &1)
+        .unwrap()
+        .format();
+
+        assert!(message.contains(&format!("  argument: {value_description}\n")));
+        assert!(!message.contains("RUST_BACKTRACE"));
+    }
+
+    #[test]
+    fn find_identical_lines_prefix_and_suffix() {
+        let (lhs, rhs) = find_identical_lines(&["a", "b", "c", "d"], &["a", "x", "y", "z", "d"]);
+
+        assert_eq!(lhs, vec![true, false, false, true]);
+        assert_eq!(rhs, vec![true, false, false, false, true]);
+    }
+
+    #[test]
+    fn find_identical_lines_same_len() {
+        let (lhs, rhs) =
+            find_identical_lines(&["a", "b", "c", "d", "e"], &["a", "x", "c", "y", "e"]);
+
+        assert_eq!(lhs, vec![true, false, true, false, true]);
+        assert_eq!(rhs, lhs);
+    }
+
+    #[test]
+    fn collapse_lines_keeps_context() {
+        let lines = ["0", "1", "2", "3", "  4", "5", "6", "7", "8", "9"];
+        let is_identical = [true, true, true, true, true, false, true, true, true, true];
+
+        assert_eq!(
+            collapse_lines(&lines, &is_identical),
+            vec![
+                ValueLine::Collapsed {
+                    indent: "",
+                    count: 3
+                },
+                ValueLine::Shown("3"),
+                ValueLine::Shown("  4"),
+                ValueLine::Shown("5"),
+                ValueLine::Shown("6"),
+                ValueLine::Shown("7"),
+                ValueLine::Collapsed {
+                    indent: "",
+                    count: 2
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn collapse_lines_short_run() {
+        let lines = ["0", "1", "2", "3", "4", "5", "6"];
+        let is_identical = [false, true, true, true, true, true, false];
+
+        assert_eq!(
+            collapse_lines(&lines, &is_identical),
+            lines
+                .iter()
+                .copied()
+                .map(ValueLine::Shown)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn format_collapses_identical_lines() {
+        set_color_choice(ColorChoice::Never);
+
+        let lhs: Vec<i32> = (0..30).collect();
+        let mut rhs = lhs.clone();
+        rhs[15] = 100;
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "lhs == rhs",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Normal)
+        .with_argument("lhs", "x", &lhs)
+        .unwrap()
+        .with_argument("rhs", "y", &rhs)
+        .unwrap()
+        .format();
+
+        assert!(message.contains(
+            "  lhs: x
+       == \u{2026} 14 identical lines \u{2026}
+           13,
+           14,
+           15,
+           16,
+           17,
+           \u{2026} 13 identical lines \u{2026}
+  rhs: y
+       == \u{2026} 14 identical lines \u{2026}
+           13,
+           14,
+           100,
+           16,
+           17,
+           \u{2026} 13 identical lines \u{2026}
+"
+        ));
+    }
+
+    #[test]
+    fn format_does_not_collapse_small_values() {
+        set_color_choice(ColorChoice::Never);
+
+        let lhs: Vec<i32> = (0..10).collect();
+        let mut rhs = lhs.clone();
+        rhs[5] = 100;
+
+        let message = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "lhs == rhs",
+            Location::caller(),
+        )
+        .with_verbosity(Verbosity::Normal)
+        .with_argument("lhs", "x", &lhs)
+        .unwrap()
+        .with_argument("rhs", "y", &rhs)
+        .unwrap()
+        .format();
+
+        assert!(!message.contains("identical lines"));
+    }
+
+    #[test]
+    fn format_does_not_collapse_when_disabled_or_verbose() {
+        set_color_choice(ColorChoice::Never);
+
+        let lhs: Vec<i32> = (0..30).collect();
+        let mut rhs = lhs.clone();
+        rhs[15] = 100;
+
+        for panic_message_builder in [
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
+                .with_verbosity(Verbosity::Normal)
+                .with_collapsed_identical_lines(false),
+            PanicMessageBuilder::new(MessageType::AssertionFailure, "", Location::caller())
+                .with_verbosity(Verbosity::Verbose),
+        ] {
+            let message = panic_message_builder
+                .with_argument("lhs", "x", &lhs)
+                .unwrap()
+                .with_argument("rhs", "y", &rhs)
+                .unwrap()
+                .format();
+
+            assert!(!message.contains("identical lines"));
+            assert!(message.contains("\n           29,\n"));
+        }
+    }
 }
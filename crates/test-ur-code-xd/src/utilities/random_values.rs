@@ -0,0 +1,777 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Support for tests with randomly generated parameter values.
+//!
+//! This is used by the `#[test_with_random_values]` and `#[test_with_seed]` attributes.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/tests/random-values](https://sophie-katz.github.io/test-ur-code-XD/tests/random-values/)
+//! for a usage guide.
+
+// Allow arithmetic side effects because all integer arithmetic is done with `i128` values that
+// cannot overflow for the supported types.
+#![allow(clippy::arithmetic_side_effects)]
+
+use crate::utilities::color_choice::style;
+use crate::utilities::panic_message_builder::{
+    catch_unwind_silently, is_panic_output_suppressed, MessageType, PanicMessageBuilder,
+};
+use std::{
+    env,
+    fmt::Debug,
+    ops::Range,
+    panic::{self, AssertUnwindSafe, Location},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The number of cases to generate when no case count is given.
+pub const DEFAULT_CASE_COUNT: usize = 256;
+
+/// The environment variable that can be used to override the seed for every test.
+pub const SEED_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_SEED";
+
+/// The maximum number of times a failing value will be shrunk.
+const MAX_SHRINK_STEP_COUNT: usize = 1024;
+
+/// A small deterministic pseudo-random number generator.
+///
+/// This uses the `SplitMix64` algorithm. It is fast and has good statistical properties for
+/// testing, but is not suitable for cryptography.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::random_values::Rng;
+/// #
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+///
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rng {
+    /// The internal state of the generator.
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new generator from a seed.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generates a random `u64` value.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut result = self.state;
+        result = (result ^ (result >> 30_i32)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        result = (result ^ (result >> 27_i32)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        result ^ (result >> 31_i32)
+    }
+
+    /// Generates a random `u32` value.
+    pub fn next_u32(&mut self) -> u32 {
+        u32::try_from(self.next_u64() >> 32_i32).unwrap_or_default()
+    }
+
+    /// Generates a random `f64` value in the range `[0.0, 1.0)`.
+    pub fn next_f64(&mut self) -> f64 {
+        // Fill the mantissa of a float in the range [1.0, 2.0) with random bits
+        f64::from_bits(0x3FF0_0000_0000_0000 | (self.next_u64() >> 12_i32)) - 1.0
+    }
+
+    /// Generates a random `f32` value in the range `[0.0, 1.0)`.
+    pub fn next_f32(&mut self) -> f32 {
+        // Fill the mantissa of a float in the range [1.0, 2.0) with random bits
+        f32::from_bits(0x3F80_0000 | (self.next_u32() >> 9_i32)) - 1.0
+    }
+}
+
+/// A way of generating random values and shrinking them when they cause a test to fail.
+pub trait Strategy {
+    /// The type of value generated.
+    type Value: Clone + Debug;
+
+    /// Generates a random value.
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Gets a list of simpler values to try in place of a failing value.
+    ///
+    /// The simplest candidates should be first. An empty list means that the value cannot be
+    /// shrunk any further.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// A strategy that generates values within a range.
+///
+/// Failing values are shrunk towards zero if the range contains zero, or towards the start of the
+/// range otherwise.
+///
+/// Created with [`range`].
+#[derive(Clone, Debug)]
+pub struct RangeStrategy<ValueType> {
+    /// The range to generate values in.
+    range: Range<ValueType>,
+}
+
+/// Creates a strategy that generates values within a range.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::random_values::{range, Rng, Strategy};
+/// #
+/// let value = range(0..100).generate(&mut Rng::new(42));
+///
+/// assert!((0..100).contains(&value));
+/// ```
+///
+/// # Panics
+///
+/// * When generating a value if the range is empty.
+#[must_use]
+pub const fn range<ValueType>(range: Range<ValueType>) -> RangeStrategy<ValueType> {
+    RangeStrategy { range }
+}
+
+/// Panics because a value was generated from an empty range.
+//
+// Panics being allowed is obvious.
+#[allow(clippy::panic)]
+fn panic_empty_range(range: &impl Debug) -> ! {
+    panic!("cannot generate a value from an empty range {range:?}");
+}
+
+/// Gets the value that failing values in a range should be shrunk towards.
+fn get_shrink_origin<ValueType: Copy + Default + PartialOrd>(
+    range: &Range<ValueType>,
+) -> ValueType {
+    if range.contains(&ValueType::default()) {
+        ValueType::default()
+    } else {
+        range.start
+    }
+}
+
+/// Implements [`Strategy`] for ranges of integer types.
+macro_rules! impl_strategy_for_integer_range {
+    ($($value_type:ty),*) => {
+        $(
+            // Conversions are lossless because `i128` can represent every value of the supported
+            // types, and values that are converted back are always within the original range. Integer
+            // division is intended when halving the distance to the shrink origin.
+            #[allow(
+                clippy::as_conversions,
+                clippy::cast_lossless,
+                clippy::cast_possible_truncation,
+                clippy::cast_possible_wrap,
+                clippy::cast_sign_loss,
+                clippy::integer_division
+            )]
+            impl Strategy for RangeStrategy<$value_type> {
+                type Value = $value_type;
+
+                fn generate(&self, rng: &mut Rng) -> $value_type {
+                    if self.range.is_empty() {
+                        panic_empty_range(&self.range);
+                    }
+
+                    let start = self.range.start as i128;
+                    let len = (self.range.end as i128 - start) as u128;
+                    let offset = (u128::from(rng.next_u64()) % len) as i128;
+
+                    (start + offset) as $value_type
+                }
+
+                fn shrink(&self, value: &$value_type) -> Vec<$value_type> {
+                    let origin = get_shrink_origin(&self.range) as i128;
+                    let value = *value as i128;
+
+                    let mut candidates: Vec<$value_type> = Vec::new();
+
+                    for candidate in [
+                        origin,
+                        value - (value - origin) / 2,
+                        value - (value - origin).signum(),
+                    ] {
+                        let candidate = candidate as $value_type;
+
+                        if candidate as i128 != value && !candidates.contains(&candidate) {
+                            candidates.push(candidate);
+                        }
+                    }
+
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_strategy_for_integer_range!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Implements [`Strategy`] for ranges of float types.
+macro_rules! impl_strategy_for_float_range {
+    ($($value_type:ty => $next_method:ident),*) => {
+        $(
+            impl Strategy for RangeStrategy<$value_type> {
+                type Value = $value_type;
+
+                fn generate(&self, rng: &mut Rng) -> $value_type {
+                    if self.range.is_empty() {
+                        panic_empty_range(&self.range);
+                    }
+
+                    let value =
+                        self.range.start + (self.range.end - self.range.start) * rng.$next_method();
+
+                    // Rounding can cause the value to land on the end of the range
+                    if value < self.range.end {
+                        value
+                    } else {
+                        self.range.start
+                    }
+                }
+
+                fn shrink(&self, value: &$value_type) -> Vec<$value_type> {
+                    let origin = get_shrink_origin(&self.range);
+
+                    let mut candidates: Vec<$value_type> = Vec::new();
+
+                    for candidate in [origin, value.trunc(), (value + origin) / 2.0] {
+                        if self.range.contains(&candidate)
+                            && (candidate - origin).abs() < (value - origin).abs()
+                            && !candidates.contains(&candidate)
+                        {
+                            candidates.push(candidate);
+                        }
+                    }
+
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_strategy_for_float_range!(f32 => next_f32, f64 => next_f64);
+
+/// Implements [`Strategy`] for tuples of strategies.
+///
+/// Tuples are shrunk one element at a time.
+macro_rules! impl_strategy_for_tuple {
+    ($(($index:tt, $strategy_type:ident)),+) => {
+        impl<$($strategy_type: Strategy),+> Strategy for ($($strategy_type,)+) {
+            type Value = ($($strategy_type::Value,)+);
+
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$index.generate(rng),)+)
+            }
+
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                let mut candidates = Vec::new();
+
+                $(
+                    for element in self.$index.shrink(&value.$index) {
+                        let mut candidate = value.clone();
+                        candidate.$index = element;
+                        candidates.push(candidate);
+                    }
+                )+
+
+                candidates
+            }
+        }
+    };
+}
+
+impl_strategy_for_tuple!((0, Strategy0));
+impl_strategy_for_tuple!((0, Strategy0), (1, Strategy1));
+impl_strategy_for_tuple!((0, Strategy0), (1, Strategy1), (2, Strategy2));
+impl_strategy_for_tuple!(
+    (0, Strategy0),
+    (1, Strategy1),
+    (2, Strategy2),
+    (3, Strategy3)
+);
+impl_strategy_for_tuple!(
+    (0, Strategy0),
+    (1, Strategy1),
+    (2, Strategy2),
+    (3, Strategy3),
+    (4, Strategy4)
+);
+impl_strategy_for_tuple!(
+    (0, Strategy0),
+    (1, Strategy1),
+    (2, Strategy2),
+    (3, Strategy3),
+    (4, Strategy4),
+    (5, Strategy5)
+);
+impl_strategy_for_tuple!(
+    (0, Strategy0),
+    (1, Strategy1),
+    (2, Strategy2),
+    (3, Strategy3),
+    (4, Strategy4),
+    (5, Strategy5),
+    (6, Strategy6)
+);
+impl_strategy_for_tuple!(
+    (0, Strategy0),
+    (1, Strategy1),
+    (2, Strategy2),
+    (3, Strategy3),
+    (4, Strategy4),
+    (5, Strategy5),
+    (6, Strategy6),
+    (7, Strategy7)
+);
+
+/// Gets the seed from the environment variable, if it is set.
+fn get_seed_from_environment() -> Option<u64> {
+    env::var(SEED_ENVIRONMENT_VARIABLE)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+}
+
+/// Generates a seed from the current time.
+fn generate_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| {
+            duration
+                .as_secs()
+                .wrapping_mul(1_000_000_000)
+                .wrapping_add(u64::from(duration.subsec_nanos()))
+        })
+        .unwrap_or_default()
+}
+
+/// Derives a seed from a test's name.
+///
+/// This uses the 64-bit FNV-1a hash, which unlike the standard library's hashers is guaranteed to
+/// give the same result on every platform and Rust version.
+fn get_seed_from_test_name(test_name: &str) -> u64 {
+    test_name.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// Helper function to print the seed that a failing test used.
+// Stderr printing allowed because the panic message for the test is also printed to stderr.
+#[allow(clippy::print_stderr)]
+fn report_seed(seed: u64) {
+    if is_panic_output_suppressed() {
+        return;
+    }
+
+    eprintln!(
+        "  {}",
+        style(format!(
+            "test used seed {seed}, set the environment variable {SEED_ENVIRONMENT_VARIABLE}={seed} to reproduce"
+        ))
+        .dim()
+    );
+}
+
+/// Runs a test body with a deterministic random number generator. This will almost always be
+/// called by code generated by the `#[test_with_seed]` attribute.
+///
+/// If the test body panics, the seed is printed after its panic message so that the failure can be
+/// reproduced.
+///
+/// # Arguments
+///
+/// * `test_name` - The full name of the test. The seed is derived from it if no other seed is
+///                 given, so each test gets different values that stay the same between runs.
+/// * `seed` - The seed to use. It is overridden by the `TEST_UR_CODE_XD_SEED` environment variable
+///            if it is set.
+/// * `action` - The test body.
+///
+/// # Returns
+///
+/// The value returned by the test body.
+///
+/// # Panics
+///
+/// * If the test body panics, the panic is resumed after the seed is printed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::random_values::{range, run_with_seed, Strategy};
+/// #
+/// let value = run_with_seed("tests::example", Some(42), |rng| range(0..100).generate(rng));
+///
+/// assert!((0..100).contains(&value));
+/// ```
+pub fn run_with_seed<ResultType>(
+    test_name: &str,
+    seed: Option<u64>,
+    action: impl FnOnce(&mut Rng) -> ResultType,
+) -> ResultType {
+    let seed = get_seed_from_environment()
+        .or(seed)
+        .unwrap_or_else(|| get_seed_from_test_name(test_name));
+
+    let mut rng = Rng::new(seed);
+
+    match panic::catch_unwind(AssertUnwindSafe(|| action(&mut rng))) {
+        Ok(value) => value,
+        Err(payload) => {
+            report_seed(seed);
+
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// Shrinks a failing value until none of its shrink candidates fail.
+///
+/// # Arguments
+///
+/// * `strategy` - The strategy that generated the value.
+/// * `test` - The test to run for each candidate.
+/// * `value` - The original failing value.
+///
+/// # Returns
+///
+/// The simplest failing value found.
+fn shrink_failing_value<StrategyType: Strategy>(
+    strategy: &StrategyType,
+    test: &impl Fn(StrategyType::Value),
+    mut value: StrategyType::Value,
+) -> StrategyType::Value {
+    for _ in 0..MAX_SHRINK_STEP_COUNT {
+        let next_value = strategy
+            .shrink(&value)
+            .into_iter()
+            .find(|candidate| catch_unwind_silently(|| test(candidate.clone())).is_err());
+
+        match next_value {
+            Some(next_value) => value = next_value,
+            None => break,
+        }
+    }
+
+    value
+}
+
+/// Runs a test for randomly generated values. This will almost always be called by code generated
+/// by the `#[test_with_random_values]` attribute.
+///
+/// If any case fails, the failing value is shrunk to the simplest value that still fails and the
+/// test panics with the seed needed to reproduce the failure.
+///
+/// # Arguments
+///
+/// * `seed` - The seed to use. It is overridden by the `TEST_UR_CODE_XD_SEED` environment variable
+///            if it is set, and a seed is generated from the current time if neither is present.
+/// * `case_count` - The number of cases to generate.
+/// * `strategy` - The strategy to generate values with.
+/// * `test` - The test to run for each value.
+///
+/// # Panics
+///
+/// * If the test fails for any of the generated values.
+#[track_caller]
+pub fn run_random_value_test<StrategyType: Strategy>(
+    seed: Option<u64>,
+    case_count: usize,
+    strategy: &StrategyType,
+    test: impl Fn(StrategyType::Value),
+) {
+    let seed = get_seed_from_environment()
+        .or(seed)
+        .unwrap_or_else(generate_seed);
+
+    let mut rng = Rng::new(seed);
+
+    for case_index in 0..case_count {
+        let value = strategy.generate(&mut rng);
+
+        if panic::catch_unwind(AssertUnwindSafe(|| test(value.clone()))).is_ok() {
+            continue;
+        }
+
+        let shrunk_value = shrink_failing_value(strategy, &test, value.clone());
+
+        // Run the shrunk value one more time so that its failure message is shown
+        if panic::catch_unwind(AssertUnwindSafe(|| test(shrunk_value.clone()))).is_ok() {
+            // The test is not deterministic, so just report the original value
+        }
+
+        let panic_message_builder = PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            "test passes for randomly generated values",
+            Location::caller(),
+        )
+        .with_description(format!(
+            "set the environment variable {SEED_ENVIRONMENT_VARIABLE}={seed} to reproduce"
+        ))
+        .and_then(|panic_message_builder| panic_message_builder.with_argument("seed", "--", &seed))
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("case", "--", &case_index)
+        })
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("original value", "--", &value)
+        })
+        .and_then(|panic_message_builder| {
+            panic_message_builder.with_argument("shrunk value", "--", &shrunk_value)
+        });
+
+        PanicMessageBuilder::unwrap_error_with(
+            panic_message_builder,
+            MessageType::InternalError,
+            "unable to create panic message builder for random value test",
+            PanicMessageBuilder::no_configuration,
+        )
+        .panic();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn rng_different_seeds() {
+        assert!(Rng::new(1).next_u64() != Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn rng_next_f64_in_unit_range() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            let value = rng.next_f64();
+
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn rng_next_f32_in_unit_range() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            let value = rng.next_f32();
+
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn range_integer_generate_in_range() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            let value = range(-5_i8..5).generate(&mut rng);
+
+            assert!((-5..5).contains(&value));
+        }
+    }
+
+    #[test]
+    fn range_integer_generate_full_range() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            let value = range(i64::MIN..i64::MAX).generate(&mut rng);
+
+            assert!(value < i64::MAX);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot generate a value from an empty range")]
+    fn range_integer_generate_empty() {
+        let _: i32 = range(5..5).generate(&mut Rng::new(42));
+    }
+
+    #[test]
+    fn range_integer_shrink_towards_zero() {
+        let candidates = range(-100..100).shrink(&50);
+
+        assert_eq!(candidates, vec![0, 25, 49]);
+    }
+
+    #[test]
+    fn range_integer_shrink_negative_towards_zero() {
+        let candidates = range(-100..100).shrink(&-50);
+
+        assert_eq!(candidates, vec![0, -25, -49]);
+    }
+
+    #[test]
+    fn range_integer_shrink_towards_start() {
+        let candidates = range(10_u32..100).shrink(&20);
+
+        assert_eq!(candidates, vec![10, 15, 19]);
+    }
+
+    #[test]
+    fn range_integer_shrink_at_origin() {
+        let candidates = range(-100..100).shrink(&0);
+
+        assert!(candidates.is_empty());
+    }
+
+    #[test]
+    fn range_integer_shrink_next_to_origin() {
+        let candidates = range(-100..100).shrink(&1);
+
+        assert_eq!(candidates, vec![0]);
+    }
+
+    #[test]
+    fn range_float_generate_in_range() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            let value = range(-1.0..1.0).generate(&mut rng);
+
+            assert!((-1.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn range_float_shrink_towards_zero() {
+        let candidates = range(-10.0..10.0).shrink(&5.5);
+
+        assert_eq!(candidates, vec![0.0, 5.0, 2.75]);
+    }
+
+    #[test]
+    fn range_float_shrink_towards_start() {
+        let candidates = range(1.0_f32..10.0).shrink(&1.5);
+
+        assert_eq!(candidates, vec![1.0, 1.25]);
+    }
+
+    #[test]
+    fn tuple_generate() {
+        let (a, b) = (range(0..10), range(10..20)).generate(&mut Rng::new(42));
+
+        assert!((0..10).contains(&a));
+        assert!((10..20).contains(&b));
+    }
+
+    #[test]
+    fn tuple_shrink() {
+        let candidates = (range(0..10), range(0..10)).shrink(&(1, 2));
+
+        assert_eq!(candidates, vec![(0, 2), (1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn run_random_value_test_passing() {
+        let count = Cell::new(0);
+
+        run_random_value_test(Some(42), 100, &(range(0..10),), |(value,)| {
+            assert!(value < 10);
+            count.set(count.get() + 1);
+        });
+
+        assert_eq!(count.get(), 100);
+    }
+
+    #[test]
+    fn run_random_value_test_same_seed_same_values() {
+        let a = RefCell::new(Vec::new());
+        let b = RefCell::new(Vec::new());
+
+        run_random_value_test(Some(42), 10, &(range(0..1000),), |(value,)| {
+            a.borrow_mut().push(value);
+        });
+
+        run_random_value_test(Some(42), 10, &(range(0..1000),), |(value,)| {
+            b.borrow_mut().push(value);
+        });
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "test passes for randomly generated values")]
+    fn run_random_value_test_failing() {
+        run_random_value_test(Some(42), 100, &(range(0..1000),), |(value,)| {
+            assert!(value < 10);
+        });
+    }
+
+    #[test]
+    fn get_seed_from_test_name_is_stable() {
+        assert_eq!(get_seed_from_test_name(""), 0xCBF2_9CE4_8422_2325_u64);
+        assert_eq!(get_seed_from_test_name("a"), 0xAF63_DC4C_8601_EC8C_u64);
+        assert!(get_seed_from_test_name("tests::a") != get_seed_from_test_name("tests::b"));
+    }
+
+    #[test]
+    fn run_with_seed_same_name_same_values() {
+        let a = run_with_seed("tests::example", None, Rng::next_u64);
+        let b = run_with_seed("tests::example", None, Rng::next_u64);
+        let c = run_with_seed("tests::other", None, Rng::next_u64);
+
+        assert_eq!(a, b);
+        assert!(a != c);
+    }
+
+    #[test]
+    fn run_with_seed_explicit_seed() {
+        assert_eq!(
+            run_with_seed("tests::example", Some(42), Rng::next_u64),
+            Rng::new(42).next_u64()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "value is true")]
+    fn run_with_seed_failing() {
+        run_with_seed("tests::example", Some(42), |rng| {
+            assert!(rng.next_u64() == 0);
+        });
+    }
+
+    #[test]
+    fn shrink_failing_value_finds_simplest() {
+        let shrunk = shrink_failing_value(
+            &(range(0..1000), range(0..1000)),
+            &|(a, b): (i32, i32)| {
+                assert!(a < 10 || b < 20);
+            },
+            (500, 700),
+        );
+
+        assert_eq!(shrunk, (10, 20));
+    }
+}
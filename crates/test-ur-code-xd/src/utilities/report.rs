@@ -0,0 +1,566 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Structured reports of assertion failures and test outcomes.
+//!
+//! When the `TEST_UR_CODE_XD_REPORT` environment variable is set to a file path, every assertion
+//! failure is recorded in that file, along with the outcomes of tests that use
+//! [`report_test_outcome`]. This gives CI systems structured results without swapping out the test
+//! harness.
+//!
+//! Paths ending in `.xml` get a `JUnit` XML report, which is rewritten as each entry is recorded so
+//! that it is always a complete document. Any other path gets a JSON Lines report, with one JSON
+//! object appended per entry.
+//!
+//! Each test binary runs in its own process, so `{pid}` in the path is replaced with the process ID
+//! to give each binary its own report file.
+
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write as _},
+    mem,
+    panic::Location,
+    path::{Path, PathBuf},
+    process,
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+/// The environment variable that sets the path of the report file.
+pub const REPORT_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_REPORT";
+
+/// The placeholder in the report path that is replaced with the process ID.
+pub const PROCESS_ID_PLACEHOLDER: &str = "{pid}";
+
+/// The entries recorded so far by this process, which are needed to rewrite `JUnit` XML reports.
+static RECORDED_ENTRIES: Mutex<Vec<ReportEntry>> = Mutex::new(Vec::new());
+
+/// The format of a report file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReportFormat {
+    /// One JSON object per line.
+    JsonLines,
+
+    /// A `JUnit` XML document.
+    JUnit,
+}
+
+impl ReportFormat {
+    /// Gets the format for a report path from its extension.
+    ///
+    /// Paths ending in `.xml` are [`ReportFormat::JUnit`] and everything else is
+    /// [`ReportFormat::JsonLines`].
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("xml") => Self::JUnit,
+            _ => Self::JsonLines,
+        }
+    }
+}
+
+/// What happened in a report entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReportOutcome {
+    /// An assertion failed.
+    AssertionFailed,
+
+    /// A test finished without panicking.
+    Passed,
+
+    /// A test panicked.
+    Failed,
+}
+
+impl ReportOutcome {
+    /// Gets the name of the outcome as it is written in JSON Lines reports.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AssertionFailed => "assertion_failed",
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// A single entry in a report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct ReportEntry {
+    /// The name of the test. The test harness names each test's thread after the test, so this is
+    /// the name of the current thread.
+    pub test: String,
+
+    /// What happened.
+    pub outcome: ReportOutcome,
+
+    /// The source location of the failed assertion, if this is an assertion failure.
+    pub location: Option<String>,
+
+    /// The predicate description of the failed assertion, if this is an assertion failure.
+    pub description: Option<String>,
+
+    /// The failure message without colors, if this is an assertion failure.
+    pub message: Option<String>,
+}
+
+impl ReportEntry {
+    /// Creates a report entry for a test outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `test` - The name of the test.
+    /// * `outcome` - What happened.
+    #[must_use]
+    pub fn new(test: impl Into<String>, outcome: ReportOutcome) -> Self {
+        Self {
+            test: test.into(),
+            outcome,
+            location: None,
+            description: None,
+            message: None,
+        }
+    }
+
+    /// Formats the entry as a single line of JSON, without a trailing newline.
+    #[must_use]
+    pub fn format_json_line(&self) -> String {
+        serde_json::json!({
+            "test": self.test,
+            "outcome": self.outcome.name(),
+            "location": self.location,
+            "description": self.description,
+            "message": self.message,
+        })
+        .to_string()
+    }
+}
+
+/// Gets the name of the current test from the name of the current thread.
+#[must_use]
+pub fn current_test_name() -> String {
+    thread::current().name().unwrap_or("<unnamed>").to_owned()
+}
+
+/// Gets the path of the report file from the environment, if reporting is enabled.
+#[must_use]
+pub fn report_path() -> Option<PathBuf> {
+    let path = env::var(REPORT_ENVIRONMENT_VARIABLE).ok()?;
+
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(PathBuf::from(
+        path.replace(PROCESS_ID_PLACEHOLDER, &process::id().to_string()),
+    ))
+}
+
+/// Escapes text so that it can be used in XML attributes and elements.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/// Formats entries as a `JUnit` XML document.
+///
+/// Entries are grouped into one test case per test name, in the order that each test was first
+/// recorded. Each assertion failure becomes a `<failure>` element of its test case. A test that
+/// failed without a recorded assertion failure, like from a plain `panic!`, gets a generic
+/// `<failure>` element.
+///
+/// # Arguments
+///
+/// * `suite_name` - The name of the test suite, usually the name of the test binary.
+/// * `entries` - The entries to format.
+#[must_use]
+pub fn format_junit(suite_name: &str, entries: &[ReportEntry]) -> String {
+    let mut test_names: Vec<&str> = Vec::new();
+
+    for entry in entries {
+        if !test_names.contains(&entry.test.as_str()) {
+            test_names.push(&entry.test);
+        }
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut failed_test_count: usize = 0;
+
+    for test_name in &test_names {
+        let test_entries: Vec<&ReportEntry> = entries
+            .iter()
+            .filter(|entry| entry.test == *test_name)
+            .collect();
+
+        let failure_lines: Vec<String> = test_entries
+            .iter()
+            .filter(|entry| entry.outcome == ReportOutcome::AssertionFailed)
+            .map(|entry| {
+                format!(
+                    "    <failure message=\"{}\" type=\"assertion\">{}</failure>",
+                    escape_xml(entry.description.as_deref().unwrap_or_default()),
+                    escape_xml(entry.message.as_deref().unwrap_or_default())
+                )
+            })
+            .collect();
+
+        let is_panicked = test_entries
+            .iter()
+            .any(|entry| entry.outcome == ReportOutcome::Failed);
+
+        let test_case_start = format!("  <testcase name=\"{}\">", escape_xml(test_name));
+
+        if failure_lines.is_empty() && !is_panicked {
+            lines.push(format!("{test_case_start}</testcase>"));
+            continue;
+        }
+
+        failed_test_count = failed_test_count.saturating_add(1);
+
+        lines.push(test_case_start);
+
+        if failure_lines.is_empty() {
+            lines.push("    <failure message=\"test panicked\" type=\"panic\"/>".to_owned());
+        } else {
+            lines.extend(failure_lines);
+        }
+
+        lines.push("  </testcase>".to_owned());
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"{}\" tests=\"{}\" failures=\"{failed_test_count}\">\n{}\n</testsuite>\n",
+        escape_xml(suite_name),
+        test_names.len(),
+        lines.join("\n")
+    )
+}
+
+/// Writes an entry to a report file.
+///
+/// # Arguments
+///
+/// * `path` - The path of the report file.
+/// * `entry` - The entry to write.
+///
+/// # Errors
+///
+/// * Returns any errors from writing the file.
+pub fn write_report_entry(path: &Path, entry: ReportEntry) -> io::Result<()> {
+    match ReportFormat::from_path(path) {
+        ReportFormat::JsonLines => {
+            let line = format!("{}\n", entry.format_json_line());
+
+            // A single write per line keeps lines from different threads from interleaving
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)?
+                .write_all(line.as_bytes())
+        }
+        ReportFormat::JUnit => {
+            let mut entries = RECORDED_ENTRIES
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            entries.push(entry);
+
+            let suite_name = env::current_exe()
+                .ok()
+                .and_then(|executable| {
+                    executable
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                })
+                .unwrap_or_default();
+
+            fs::write(path, format_junit(&suite_name, &entries))
+        }
+    }
+}
+
+/// Records an entry in the report file, if reporting is enabled.
+///
+/// Errors writing the report are printed to stderr instead of panicking so that they do not change
+/// the outcome of the test.
+///
+/// # Arguments
+///
+/// * `entry` - The entry to record.
+//
+// Stderr printing is allowed because there is nowhere else to report the error.
+#[allow(clippy::print_stderr)]
+pub fn record_report_entry(entry: ReportEntry) {
+    if let Some(path) = report_path() {
+        if let Err(error) = write_report_entry(&path, entry) {
+            eprintln!(
+                "warning: unable to write test report to {}: {error}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Records an assertion failure in the report file, if reporting is enabled.
+///
+/// This is called by [`PanicMessageBuilder::panic`](crate::utilities::panic_message_builder::PanicMessageBuilder::panic),
+/// so it does not need to be called for assertions in this crate.
+///
+/// # Arguments
+///
+/// * `description` - The predicate description of the assertion.
+/// * `location` - The location of the assertion.
+/// * `message` - The failure message. Colors are stripped from it.
+pub fn record_assertion_failure(description: &str, location: &Location<'_>, message: &str) {
+    if report_path().is_none() {
+        return;
+    }
+
+    let mut entry = ReportEntry::new(current_test_name(), ReportOutcome::AssertionFailed);
+
+    entry.location = Some(format!(
+        "{}:{}:{}",
+        location.file(),
+        location.line(),
+        location.column()
+    ));
+    entry.description = Some(description.to_owned());
+    entry.message = Some(console::strip_ansi_codes(message).into_owned());
+
+    record_report_entry(entry);
+}
+
+/// A guard that records the outcome of the current test when it is dropped.
+///
+/// Create one with [`report_test_outcome`].
+#[derive(Debug)]
+#[must_use = "the outcome is recorded when the guard is dropped, so it must be held until the end of the test"]
+pub struct TestOutcomeGuard {
+    /// The name of the test.
+    test: String,
+}
+
+impl Drop for TestOutcomeGuard {
+    fn drop(&mut self) {
+        let outcome = if thread::panicking() {
+            ReportOutcome::Failed
+        } else {
+            ReportOutcome::Passed
+        };
+
+        record_report_entry(ReportEntry::new(mem::take(&mut self.test), outcome));
+    }
+}
+
+/// Records the outcome of the current test in the report file when the returned guard is dropped.
+///
+/// Assertion failures are always recorded, but the test harness does not say when tests pass.
+/// Holding this guard for the whole test records whether it passed or failed, including failures
+/// from plain panics.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::report::report_test_outcome;
+/// #
+/// #[test]
+/// fn example() {
+///     let _report = report_test_outcome();
+///
+///     // ...
+/// }
+/// ```
+pub fn report_test_outcome() -> TestOutcomeGuard {
+    TestOutcomeGuard {
+        test: current_test_name(),
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    /// The environment variable that tells the test process that it is the child process in
+    /// [`report_written_by_child_process`].
+    const CHILD_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_REPORT_CHILD";
+
+    fn assertion_failure_entry(test: &str) -> ReportEntry {
+        let mut entry = ReportEntry::new(test, ReportOutcome::AssertionFailed);
+
+        entry.location = Some("src/lib.rs:5:9".to_owned());
+        entry.description = Some("lhs == rhs".to_owned());
+        entry.message = Some("lhs: 1 & rhs: <2>".to_owned());
+
+        entry
+    }
+
+    #[test]
+    fn report_format_from_path() {
+        assert_eq!(
+            ReportFormat::from_path(Path::new("report.xml")),
+            ReportFormat::JUnit
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("report.XML")),
+            ReportFormat::JUnit
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("report.jsonl")),
+            ReportFormat::JsonLines
+        );
+        assert_eq!(
+            ReportFormat::from_path(Path::new("report")),
+            ReportFormat::JsonLines
+        );
+    }
+
+    #[test]
+    fn format_json_line_assertion_failure() {
+        assert_eq!(
+            assertion_failure_entry("tests::a").format_json_line(),
+            r#"{"description":"lhs == rhs","location":"src/lib.rs:5:9","message":"lhs: 1 & rhs: <2>","outcome":"assertion_failed","test":"tests::a"}"#
+        );
+    }
+
+    #[test]
+    fn format_json_line_passed() {
+        assert_eq!(
+            ReportEntry::new("tests::b", ReportOutcome::Passed).format_json_line(),
+            r#"{"description":null,"location":null,"message":null,"outcome":"passed","test":"tests::b"}"#
+        );
+    }
+
+    #[test]
+    fn format_junit_grouped() {
+        assert_eq!(
+            format_junit(
+                "suite",
+                &[
+                    assertion_failure_entry("tests::a"),
+                    ReportEntry::new("tests::b", ReportOutcome::Passed),
+                    ReportEntry::new("tests::a", ReportOutcome::Failed),
+                    ReportEntry::new("tests::c", ReportOutcome::Failed),
+                ]
+            ),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="suite" tests="3" failures="2">
+  <testcase name="tests::a">
+    <failure message="lhs == rhs" type="assertion">lhs: 1 &amp; rhs: &lt;2&gt;</failure>
+  </testcase>
+  <testcase name="tests::b"></testcase>
+  <testcase name="tests::c">
+    <failure message="test panicked" type="panic"/>
+  </testcase>
+</testsuite>
+"#
+        );
+    }
+
+    #[test]
+    fn write_report_entry_json_lines_appends() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("report.jsonl");
+
+        write_report_entry(&path, ReportEntry::new("a", ReportOutcome::Passed)).unwrap();
+        write_report_entry(&path, ReportEntry::new("b", ReportOutcome::Failed)).unwrap();
+
+        let lines: Vec<String> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(ToOwned::to_owned)
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains(r#""test":"a""#));
+        assert!(lines[1].contains(r#""outcome":"failed""#));
+    }
+
+    #[test]
+    fn report_written_by_child_process() {
+        // Setting the environment variable would affect every other test, so the failure happens
+        // in a child process that only runs this test
+        if env::var_os(CHILD_ENVIRONMENT_VARIABLE).is_some() {
+            let _report = report_test_outcome();
+
+            assert_eq!(1 + 1, 3);
+
+            return;
+        }
+
+        let directory = tempdir().unwrap();
+
+        let output = Command::new(env::current_exe().unwrap())
+            .args([
+                "utilities::report::tests::report_written_by_child_process",
+                "--exact",
+            ])
+            .env(CHILD_ENVIRONMENT_VARIABLE, "1")
+            .env(
+                REPORT_ENVIRONMENT_VARIABLE,
+                directory.path().join("report-{pid}.jsonl"),
+            )
+            .output()
+            .unwrap();
+
+        assert!(!output.status.success());
+
+        let report_path = fs::read_dir(directory.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+
+        assert!(report_path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("report-"));
+        assert!(!report_path
+            .to_string_lossy()
+            .contains(PROCESS_ID_PLACEHOLDER));
+
+        let report = fs::read_to_string(report_path).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains(r#""outcome":"assertion_failed""#));
+        assert!(lines[0].contains(r#""description":"lhs == rhs""#));
+        assert!(lines[1].contains(r#""outcome":"failed""#));
+        assert!(lines[1].contains("report_written_by_child_process"));
+    }
+}
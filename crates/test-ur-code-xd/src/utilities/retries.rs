@@ -0,0 +1,176 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime support for the `#[test_with_retries]` attribute.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/tests/retries](https://sophie-katz.github.io/test-ur-code-XD/tests/retries/)
+//! for a usage guide.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::Duration,
+};
+
+use super::{
+    color_choice::style, format::format_duration, panic_message_builder::is_panic_output_suppressed,
+};
+
+/// Helper function to print that an attempt failed and that the test will be retried.
+// Stderr printing allowed because the panic message for the attempt is also printed to stderr.
+#[allow(clippy::print_stderr)]
+fn report_retry(attempt_number: usize, attempt_count: usize, delay: Duration) {
+    if is_panic_output_suppressed() {
+        return;
+    }
+
+    eprintln!(
+        "  {}",
+        style(format!(
+            "attempt {attempt_number} of {attempt_count} failed, retrying in {}",
+            format_duration(delay)
+        ))
+        .dim()
+    );
+}
+
+/// Runs a test body, re-running it if it panics.
+///
+/// This is usually called by code generated by the `#[test_with_retries]` attribute.
+///
+/// The panic message of each failed attempt is printed as usual, followed by a line saying that the
+/// test will be retried.
+///
+/// # Arguments
+///
+/// * `retry_count` - The maximum number of times to re-run the test body after the first attempt.
+/// * `delay` - The amount of time to wait before each retry.
+/// * `action` - The test body.
+///
+/// # Returns
+///
+/// The value returned by the first attempt that does not panic.
+///
+/// # Panics
+///
+/// * If every attempt panics, the panic from the last attempt is resumed.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::retries::run_with_retries;
+/// # use std::{cell::Cell, time::Duration};
+/// #
+/// let attempt_count = Cell::new(0);
+///
+/// run_with_retries(3, Duration::ZERO, || {
+///     attempt_count.set(attempt_count.get() + 1);
+///
+///     assert!(attempt_count.get() >= 2);
+/// });
+///
+/// assert_eq!(attempt_count.get(), 2);
+/// ```
+pub fn run_with_retries<ResultType>(
+    retry_count: usize,
+    delay: Duration,
+    action: impl Fn() -> ResultType,
+) -> ResultType {
+    let attempt_count = retry_count.saturating_add(1);
+    let mut attempt_number: usize = 1;
+
+    loop {
+        match panic::catch_unwind(AssertUnwindSafe(&action)) {
+            Ok(value) => return value,
+            Err(payload) => {
+                if attempt_number >= attempt_count {
+                    panic::resume_unwind(payload);
+                }
+
+                report_retry(attempt_number, attempt_count, delay);
+
+                thread::sleep(delay);
+
+                attempt_number = attempt_number.saturating_add(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+// Panics allowed to generate panics for testing.
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::cell::Cell;
+
+    #[test]
+    fn passing_first_attempt() {
+        let attempt_count = Cell::new(0);
+
+        let value = run_with_retries(3, Duration::ZERO, || {
+            attempt_count.set(attempt_count.get() + 1);
+
+            5
+        });
+
+        assert_eq!(value, 5);
+        assert_eq!(attempt_count.get(), 1);
+    }
+
+    #[test]
+    fn passing_after_retries() {
+        let attempt_count = Cell::new(0);
+
+        run_with_retries(3, Duration::ZERO, || {
+            attempt_count.set(attempt_count.get() + 1);
+
+            assert!(attempt_count.get() == 4);
+        });
+
+        assert_eq!(attempt_count.get(), 4);
+    }
+
+    #[test]
+    fn failing_all_attempts() {
+        let attempt_count = Cell::new(0);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_with_retries(2, Duration::ZERO, || {
+                attempt_count.set(attempt_count.get() + 1);
+
+                panic!("attempt {}", attempt_count.get());
+            });
+        }));
+
+        let message = result
+            .err()
+            .and_then(|payload| payload.downcast::<String>().ok())
+            .map(|message| *message);
+
+        assert_eq!(attempt_count.get(), 3);
+        assert_eq!(message, Some("attempt 3".to_owned()));
+    }
+
+    #[test]
+    #[should_panic(expected = "always fails")]
+    fn failing_no_retries() {
+        run_with_retries(0, Duration::ZERO, || {
+            panic!("always fails");
+        });
+    }
+}
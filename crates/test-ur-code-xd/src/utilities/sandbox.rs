@@ -0,0 +1,367 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Temporary directories for tests that work with the filesystem.
+//!
+//! The current directory is shared by every thread in the process, so tests that change into a
+//! directory race each other when they run in parallel. [`TestDir::enter`] holds a process-wide lock
+//! while the current directory is changed, so that only one test at a time can be in a test
+//! directory.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/assertions/filesystem](https://sophie-katz.github.io/test-ur-code-XD/assertions/filesystem/)
+//! for a usage guide.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::{Condvar, Mutex, PoisonError},
+    thread::{self, ThreadId},
+};
+
+use tempfile::TempDir;
+
+/// The thread that has changed the current directory, and how many times it has done so without
+/// restoring it.
+static CURRENT_DIR_OWNER: Mutex<Option<(ThreadId, usize)>> = Mutex::new(None);
+
+/// Notified when the current directory is restored, so that other threads can change it.
+static CURRENT_DIR_RELEASED: Condvar = Condvar::new();
+
+/// Waits until no other thread has changed the current directory, then marks the current thread as
+/// the owner.
+///
+/// The same thread can acquire this multiple times, like when test directories are nested.
+fn acquire_current_dir() {
+    let current_thread = thread::current().id();
+
+    let mut owner = CURRENT_DIR_OWNER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    loop {
+        match *owner {
+            None => {
+                *owner = Some((current_thread, 1));
+                return;
+            }
+            Some((owner_thread, depth)) if owner_thread == current_thread => {
+                *owner = Some((owner_thread, depth.saturating_add(1)));
+                return;
+            }
+            Some(_) => {
+                owner = CURRENT_DIR_RELEASED
+                    .wait(owner)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+    }
+}
+
+/// Releases one acquisition of the current directory by the current thread.
+fn release_current_dir() {
+    let mut owner = CURRENT_DIR_OWNER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some((owner_thread, depth)) = *owner {
+        if depth > 1 {
+            *owner = Some((owner_thread, depth.saturating_sub(1)));
+        } else {
+            *owner = None;
+
+            CURRENT_DIR_RELEASED.notify_all();
+        }
+    }
+}
+
+/// A temporary directory for a test, which is deleted when it is dropped.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_path_is_file, utilities::sandbox::TestDir};
+/// #
+/// let test_dir = TestDir::new().unwrap();
+///
+/// test_dir.write_file("config/settings.toml", "verbose = true").unwrap();
+///
+/// assert_path_is_file!(test_dir.path_of("config/settings.toml"));
+/// ```
+#[derive(Debug)]
+pub struct TestDir {
+    /// The temporary directory.
+    temp_dir: TempDir,
+}
+
+impl TestDir {
+    /// Creates a new, empty test directory.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors from creating the directory.
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            temp_dir: tempfile::tempdir()?,
+        })
+    }
+
+    /// Gets the absolute path of the test directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        self.temp_dir.path()
+    }
+
+    /// Gets the absolute path of a path relative to the test directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `relative_path` - The path relative to the test directory.
+    #[must_use]
+    pub fn path_of(&self, relative_path: impl AsRef<Path>) -> PathBuf {
+        self.path().join(relative_path)
+    }
+
+    /// Writes a file within the test directory, creating any parent directories that are missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `relative_path` - The path of the file relative to the test directory.
+    /// * `contents` - The contents to write to the file.
+    ///
+    /// # Returns
+    ///
+    /// The absolute path of the file.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors from creating the parent directories or writing the file.
+    pub fn write_file(
+        &self,
+        relative_path: impl AsRef<Path>,
+        contents: impl AsRef<[u8]>,
+    ) -> io::Result<PathBuf> {
+        let path = self.path_of(relative_path);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&path, contents)?;
+
+        Ok(path)
+    }
+
+    /// Creates a directory within the test directory, along with any parent directories that are
+    /// missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `relative_path` - The path of the directory relative to the test directory.
+    ///
+    /// # Returns
+    ///
+    /// The absolute path of the directory.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors from creating the directories.
+    pub fn create_dir_all(&self, relative_path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let path = self.path_of(relative_path);
+
+        fs::create_dir_all(&path)?;
+
+        Ok(path)
+    }
+
+    /// Changes the current directory to the test directory until the returned guard is dropped.
+    ///
+    /// While the guard is held, other threads that call this wait until it is dropped. The same
+    /// thread can enter multiple test directories at once, as long as the guards are dropped in
+    /// reverse order.
+    ///
+    /// # Returns
+    ///
+    /// A guard that changes back to the previous current directory when it is dropped.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any errors from getting or changing the current directory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::{assert_path_exists, utilities::sandbox::TestDir};
+    /// #
+    /// let test_dir = TestDir::new().unwrap();
+    ///
+    /// test_dir.write_file("some_file", "").unwrap();
+    ///
+    /// let _current_dir = test_dir.enter().unwrap();
+    ///
+    /// assert_path_exists!("some_file");
+    /// ```
+    pub fn enter(&self) -> io::Result<CurrentDirGuard<'_>> {
+        acquire_current_dir();
+
+        let previous_dir = env::current_dir().and_then(|previous_dir| {
+            env::set_current_dir(self.path())?;
+
+            Ok(previous_dir)
+        });
+
+        match previous_dir {
+            Ok(previous_dir) => Ok(CurrentDirGuard {
+                previous_dir,
+                _test_dir: self,
+            }),
+            Err(error) => {
+                release_current_dir();
+
+                Err(error)
+            }
+        }
+    }
+}
+
+/// A guard that changes back to the previous current directory when it is dropped.
+///
+/// Create one with [`TestDir::enter`].
+#[derive(Debug)]
+#[must_use = "the current directory is restored when the guard is dropped, so it must be held while the directory is used"]
+pub struct CurrentDirGuard<'test_dir> {
+    /// The current directory before the test directory was entered.
+    previous_dir: PathBuf,
+
+    /// The test directory that was entered, which must not be deleted while it is the current
+    /// directory.
+    _test_dir: &'test_dir TestDir,
+}
+
+impl Drop for CurrentDirGuard<'_> {
+    fn drop(&mut self) {
+        // If the previous directory was deleted, there is nowhere better to go, so the error is
+        // ignored
+        let _result: io::Result<()> = env::set_current_dir(&self.previous_dir);
+
+        release_current_dir();
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{sync::mpsc, time::Duration};
+
+    #[test]
+    fn write_file_creates_parents() {
+        let test_dir = TestDir::new().unwrap();
+
+        let path = test_dir.write_file("a/b/c.txt", "hello").unwrap();
+
+        assert_eq!(&path, &test_dir.path_of("a/b/c.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello");
+    }
+
+    #[test]
+    fn create_dir_all_nested() {
+        let test_dir = TestDir::new().unwrap();
+
+        let path = test_dir.create_dir_all("a/b").unwrap();
+
+        assert!(path.is_dir());
+    }
+
+    #[test]
+    fn drop_deletes_directory() {
+        let test_dir = TestDir::new().unwrap();
+        let path = test_dir.path().to_path_buf();
+
+        drop(test_dir);
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn enter_restores_previous_dir() {
+        let previous_dir = env::current_dir().unwrap();
+        let test_dir = TestDir::new().unwrap();
+
+        {
+            let _current_dir = test_dir.enter().unwrap();
+
+            assert_eq!(
+                env::current_dir().unwrap().canonicalize().unwrap(),
+                test_dir.path().canonicalize().unwrap()
+            );
+        }
+
+        assert_eq!(env::current_dir().unwrap(), previous_dir);
+    }
+
+    #[test]
+    fn enter_nested() {
+        let previous_dir = env::current_dir().unwrap();
+        let outer = TestDir::new().unwrap();
+        let inner = TestDir::new().unwrap();
+
+        {
+            let _outer_current_dir = outer.enter().unwrap();
+
+            {
+                let _inner_current_dir = inner.enter().unwrap();
+
+                assert_eq!(
+                    env::current_dir().unwrap().canonicalize().unwrap(),
+                    inner.path().canonicalize().unwrap()
+                );
+            }
+
+            assert_eq!(
+                env::current_dir().unwrap().canonicalize().unwrap(),
+                outer.path().canonicalize().unwrap()
+            );
+        }
+
+        assert_eq!(env::current_dir().unwrap(), previous_dir);
+    }
+
+    #[test]
+    fn enter_waits_for_other_thread() {
+        let test_dir = TestDir::new().unwrap();
+        let current_dir = test_dir.enter().unwrap();
+
+        let (sender, receiver) = mpsc::channel::<()>();
+
+        let handle = thread::spawn(move || {
+            let other_test_dir = TestDir::new().unwrap();
+            let _other_current_dir = other_test_dir.enter().unwrap();
+
+            sender.send(()).unwrap();
+        });
+
+        // The other thread cannot enter its directory until this one is restored
+        assert!(receiver.recv_timeout(Duration::from_millis(50)).is_err());
+
+        drop(current_dir);
+
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime support for the `#[test_with_setup]` and `#[test_suite]` attributes.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown](https://sophie-katz.github.io/test-ur-code-XD/tests/setup-and-teardown/)
+//! for a usage guide.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    thread,
+};
+
+/// A guard that calls a teardown function when it is dropped, including when the test panics.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::setup::TeardownGuard;
+/// #
+/// let _guard = TeardownGuard::new(|| println!("tearing down"));
+///
+/// // ...
+/// ```
+#[must_use = "teardown happens when the guard is dropped, so it must be held until the end of the test"]
+pub struct TeardownGuard<TeardownType: FnOnce()> {
+    /// The teardown function, which is taken when it is called.
+    teardown: Option<TeardownType>,
+}
+
+impl<TeardownType: FnOnce()> TeardownGuard<TeardownType> {
+    /// Creates a new guard.
+    ///
+    /// # Arguments
+    ///
+    /// * `teardown` - The function to call when the guard is dropped.
+    pub const fn new(teardown: TeardownType) -> Self {
+        Self {
+            teardown: Some(teardown),
+        }
+    }
+}
+
+impl<TeardownType: FnOnce()> Drop for TeardownGuard<TeardownType> {
+    fn drop(&mut self) {
+        let Some(teardown) = self.teardown.take() else {
+            return;
+        };
+
+        if thread::panicking() {
+            // Panicking while already panicking aborts the process, so a panic in teardown is
+            // caught here. Its message is still printed and the test fails from the original panic.
+            let _result: thread::Result<()> = panic::catch_unwind(AssertUnwindSafe(teardown));
+        } else {
+            teardown();
+        }
+    }
+}
+
+/// Runs a test body between a setup function and a teardown function.
+///
+/// This is usually called by code generated by the `#[test_with_setup]` and `#[test_suite]`
+/// attributes.
+///
+/// # Arguments
+///
+/// * `setup` - The function to call before the test body.
+/// * `teardown` - The function to call after the test body. It is called even if the test body
+///                panics, but not if `setup` panics.
+/// * `action` - The test body.
+///
+/// # Returns
+///
+/// The value returned by the test body.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::setup::run_with_setup;
+/// #
+/// let value = run_with_setup(
+///     || println!("setting up"),
+///     || println!("tearing down"),
+///     || 5,
+/// );
+///
+/// assert_eq!(value, 5);
+/// ```
+pub fn run_with_setup<ResultType>(
+    setup: impl FnOnce(),
+    teardown: impl FnOnce(),
+    action: impl FnOnce() -> ResultType,
+) -> ResultType {
+    setup();
+
+    let _guard = TeardownGuard::new(teardown);
+
+    action()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{cell::RefCell, panic::AssertUnwindSafe};
+
+    #[test]
+    fn run_with_setup_order() {
+        let events = RefCell::new(Vec::new());
+
+        let value = run_with_setup(
+            || events.borrow_mut().push("setup"),
+            || events.borrow_mut().push("teardown"),
+            || {
+                events.borrow_mut().push("test");
+
+                5
+            },
+        );
+
+        assert_eq!(value, 5);
+        assert_eq!(*events.borrow(), vec!["setup", "test", "teardown"]);
+    }
+
+    #[test]
+    // Panicking is the point of this test
+    #[allow(clippy::panic)]
+    fn run_with_setup_teardown_after_panic() {
+        let events = RefCell::new(Vec::new());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_with_setup(
+                || events.borrow_mut().push("setup"),
+                || events.borrow_mut().push("teardown"),
+                || panic!("test failed"),
+            );
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(*events.borrow(), vec!["setup", "teardown"]);
+    }
+
+    #[test]
+    // Panicking is the point of this test
+    #[allow(clippy::panic)]
+    fn run_with_setup_teardown_panics_after_panic() {
+        let result = panic::catch_unwind(|| {
+            run_with_setup(
+                || {},
+                || panic!("teardown failed"),
+                || panic!("test failed"),
+            );
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "teardown failed")]
+    // Panicking is the point of this test
+    #[allow(clippy::panic)]
+    fn run_with_setup_teardown_panics() {
+        run_with_setup(|| {}, || panic!("teardown failed"), || {});
+    }
+}
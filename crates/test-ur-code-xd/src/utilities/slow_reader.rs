@@ -0,0 +1,264 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! A reader test double that delays reads so that timeout handling can be tested
+//! deterministically.
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+    thread,
+    time::Duration,
+};
+
+/// A wrapper around a reader that sleeps before every read.
+///
+/// Each call to [`Read::read`] waits for the next configured delay before reading from the inner
+/// reader. This makes it possible to simulate slow files, pipes, or network streams without any
+/// real I/O. Use it with [`assert_completes_within`](crate::assert_completes_within) to check that
+/// code gives up on slow reads in time.
+///
+/// By default the delays are real sleeps. Use [`SlowReader::with_sleep`] to replace them, like to
+/// record the delays instead of waiting for them.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::slow_reader::SlowReader;
+/// use std::{io::Read, time::{Duration, Instant}};
+///
+/// let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::from_millis(10));
+///
+/// let start = Instant::now();
+/// let mut text = String::new();
+/// reader.read_to_string(&mut text).unwrap();
+///
+/// assert_eq!(text, "hello, world");
+/// assert!(start.elapsed() >= Duration::from_millis(10));
+/// ```
+pub struct SlowReader<ReaderType: Read> {
+    /// The reader being wrapped.
+    inner: ReaderType,
+
+    /// Delays to use for upcoming reads, in order.
+    delays: VecDeque<Duration>,
+
+    /// The delay to use once `delays` is exhausted.
+    default_delay: Duration,
+
+    /// The function that waits for each delay.
+    sleep: Box<dyn FnMut(Duration) + Send>,
+}
+
+impl<ReaderType: Read> SlowReader<ReaderType> {
+    /// Creates a reader that waits for `delay` before every read.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The reader to wrap.
+    /// * `delay` - The delay before each read.
+    #[must_use]
+    pub fn new(inner: ReaderType, delay: Duration) -> Self {
+        Self {
+            inner,
+            delays: VecDeque::new(),
+            default_delay: delay,
+            sleep: Box::new(thread::sleep),
+        }
+    }
+
+    /// Creates a reader with a different delay for each read.
+    ///
+    /// The first read waits for the first delay, the second read for the second delay, and so
+    /// on. Once all of the delays have been used, reads are no longer delayed.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The reader to wrap.
+    /// * `delays` - The delays to use for each read, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::utilities::slow_reader::SlowReader;
+    /// use std::time::Duration;
+    ///
+    /// // The first read is instant and the second read takes a second
+    /// let reader = SlowReader::with_delays(
+    ///     "hello, world".as_bytes(),
+    ///     [Duration::ZERO, Duration::from_secs(1)],
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_delays(inner: ReaderType, delays: impl IntoIterator<Item = Duration>) -> Self {
+        Self {
+            inner,
+            delays: delays.into_iter().collect(),
+            default_delay: Duration::ZERO,
+            sleep: Box::new(thread::sleep),
+        }
+    }
+
+    /// Replaces the function that waits for each delay, which is [`thread::sleep`] by default.
+    ///
+    /// The function is only called for delays that aren't zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `sleep` - The function to call with each delay.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use test_ur_code_xd::utilities::slow_reader::SlowReader;
+    /// use std::{
+    ///     io::Read,
+    ///     sync::{Arc, Mutex},
+    ///     time::Duration,
+    /// };
+    ///
+    /// let delays = Arc::new(Mutex::new(Vec::new()));
+    /// let recorded_delays = Arc::clone(&delays);
+    ///
+    /// let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::from_secs(60))
+    ///     .with_sleep(move |delay| recorded_delays.lock().unwrap().push(delay));
+    ///
+    /// // Returns right away instead of after a minute
+    /// let mut buffer = [0; 5];
+    /// reader.read_exact(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(*delays.lock().unwrap(), [Duration::from_secs(60)]);
+    /// ```
+    #[must_use]
+    pub fn with_sleep(mut self, sleep: impl FnMut(Duration) + Send + 'static) -> Self {
+        self.sleep = Box::new(sleep);
+        self
+    }
+
+    /// Consumes the wrapper and returns the inner reader.
+    #[must_use]
+    pub fn into_inner(self) -> ReaderType {
+        self.inner
+    }
+}
+
+// The default implementations of the other methods all delegate to `read`, which is what we want so
+// that every read is delayed.
+#[allow(clippy::missing_trait_methods)]
+impl<ReaderType: Read> Read for SlowReader<ReaderType> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let delay = self.delays.pop_front().unwrap_or(self.default_delay);
+
+        if !delay.is_zero() {
+            (self.sleep)(delay);
+        }
+
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use std::{
+        sync::{Arc, Mutex},
+        time::Instant,
+    };
+
+    /// Helper function to wrap a reader so that its delays are recorded instead of slept.
+    fn record_delays<ReaderType: Read>(
+        reader: SlowReader<ReaderType>,
+    ) -> (SlowReader<ReaderType>, Arc<Mutex<Vec<Duration>>>) {
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let recorded_delays = Arc::clone(&delays);
+
+        (
+            reader.with_sleep(move |delay| recorded_delays.lock().unwrap().push(delay)),
+            delays,
+        )
+    }
+
+    #[test]
+    fn reads_inner_text() {
+        let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::ZERO);
+
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text).unwrap();
+
+        assert_eq!(text, "hello, world");
+    }
+
+    #[test]
+    fn constant_delay() {
+        let (mut reader, delays) = record_delays(SlowReader::new(
+            "hello, world".as_bytes(),
+            Duration::from_millis(20),
+        ));
+
+        let mut buffer = [0; 5];
+
+        reader.read_exact(&mut buffer).unwrap();
+        reader.read_exact(&mut buffer).unwrap();
+
+        assert_eq!(
+            *delays.lock().unwrap(),
+            [Duration::from_millis(20), Duration::from_millis(20)]
+        );
+        assert_eq!(&buffer, b", wor");
+    }
+
+    #[test]
+    fn delays_are_used_in_order() {
+        let (mut reader, delays) = record_delays(SlowReader::with_delays(
+            "hello, world".as_bytes(),
+            [Duration::ZERO, Duration::from_millis(20)],
+        ));
+
+        let mut buffer = [0; 5];
+
+        reader.read_exact(&mut buffer).unwrap();
+
+        assert!(delays.lock().unwrap().is_empty());
+
+        reader.read_exact(&mut buffer).unwrap();
+        reader.read_exact(&mut [0; 2]).unwrap();
+
+        assert_eq!(*delays.lock().unwrap(), [Duration::from_millis(20)]);
+    }
+
+    #[test]
+    fn default_sleep_waits() {
+        let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::from_millis(20));
+
+        let start = Instant::now();
+
+        reader.read_exact(&mut [0; 5]).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn into_inner() {
+        let reader = SlowReader::new("hello, world".as_bytes(), Duration::ZERO);
+
+        let inner = reader.into_inner();
+
+        assert_eq!(inner, b"hello, world");
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Source code snippets for assertion failure messages.
+//!
+//! With the `source-snippet` feature enabled, failure messages include the line of source code
+//! that contains the failing assertion, with carets under it like rustc diagnostics. The source
+//! file is read when the message is formatted, so nothing is shown if it isn't available.
+
+use console::Color;
+use std::{
+    env, fs,
+    panic::Location,
+    path::{Path, PathBuf},
+};
+
+use super::{color_choice::style, theme::Theme};
+
+/// Finds a source file from the path recorded in a [`Location`].
+///
+/// Recorded paths are usually relative to the root of the workspace that was compiled, while tests
+/// are run from the directory of the package. Each ancestor of the current directory is tried in
+/// turn so that both work. Tests can change the current directory, so the ancestors of the package
+/// directory that Cargo sets in `CARGO_MANIFEST_DIR` are tried after that.
+///
+/// # Arguments
+///
+/// * `path` - The path recorded in the location.
+#[must_use]
+fn find_source_file(path: &Path) -> Option<PathBuf> {
+    if path.is_absolute() {
+        return path.is_file().then(|| path.to_owned());
+    }
+
+    let search_roots = env::current_dir()
+        .ok()
+        .into_iter()
+        .chain(env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from));
+
+    search_roots
+        .flat_map(|root| {
+            root.ancestors()
+                .map(|ancestor| ancestor.join(path))
+                .collect::<Vec<_>>()
+        })
+        .find(|candidate| candidate.is_file())
+}
+
+/// Reads the line of source code at a location.
+///
+/// # Arguments
+///
+/// * `location` - The location to read.
+///
+/// # Returns
+///
+/// * The line without its line ending, if the source file could be found and read.
+/// * Otherwise, `None`.
+#[must_use]
+pub fn read_source_line(location: &Location<'_>) -> Option<String> {
+    let path = find_source_file(Path::new(location.file()))?;
+    let line_index = usize::try_from(location.line()).ok()?.checked_sub(1)?;
+
+    fs::read_to_string(path)
+        .ok()?
+        .lines()
+        .nth(line_index)
+        .map(str::to_owned)
+}
+
+/// Formats a line of source code with carets under the code starting at a column.
+///
+/// The carets go from the column to the end of the line, and tabs before the column are kept so
+/// that they line up.
+///
+/// # Arguments
+///
+/// * `line_number` - The 1-based line number to show in the gutter.
+/// * `column` - The 1-based column where the carets start.
+/// * `line` - The line of source code.
+/// * `caret_color` - The color of the carets.
+/// * `theme` - The theme to use for the gutter.
+#[must_use]
+pub fn format_source_snippet(
+    line_number: u32,
+    column: u32,
+    line: &str,
+    caret_color: Color,
+    theme: &Theme,
+) -> String {
+    let line_number = line_number.to_string();
+    let gutter_padding = " ".repeat(line_number.len());
+    let column_index = usize::try_from(column).map_or(0, |column| column.saturating_sub(1));
+
+    let caret_offset: String = line
+        .chars()
+        .take(column_index)
+        .map(|character| if character == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    let caret_count = line
+        .chars()
+        .skip(column_index)
+        .collect::<String>()
+        .trim_end()
+        .chars()
+        .count()
+        .max(1);
+
+    format!(
+        "\n  {} {line}\n  {gutter_padding} {} {caret_offset}{}",
+        theme.dim(format!("{line_number} |")),
+        theme.dim("|"),
+        style("^".repeat(caret_count)).fg(caret_color).bold(),
+    )
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn read_source_line_this_file() {
+        let location = Location::caller();
+
+        assert!(read_source_line(location)
+            .unwrap()
+            .contains("let location = Location::caller();"));
+    }
+
+    #[test]
+    fn find_source_file_missing() {
+        assert!(find_source_file(Path::new("does/not/exist.rs")).is_none());
+    }
+
+    #[test]
+    fn format_source_snippet_simple() {
+        assert_eq!(
+            console::strip_ansi_codes(&format_source_snippet(
+                12,
+                5,
+                "    assert_eq!(x, 6);",
+                Color::Red,
+                &Theme::new()
+            )),
+            "\n  12 |     assert_eq!(x, 6);\n     |     ^^^^^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn format_source_snippet_tabs_and_trailing_whitespace() {
+        assert_eq!(
+            console::strip_ansi_codes(&format_source_snippet(
+                3,
+                2,
+                "\tassert!(x);  ",
+                Color::Red,
+                &Theme::new()
+            )),
+            "\n  3 | \tassert!(x);  \n    | \t^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn format_source_snippet_column_past_end() {
+        assert_eq!(
+            console::strip_ansi_codes(&format_source_snippet(
+                1,
+                10,
+                "x",
+                Color::Red,
+                &Theme::new()
+            )),
+            "\n  1 | x\n    |  ^"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Utilities for providing scripted input on stdin.
+//!
+//! This makes it possible to test interactive prompts, usually together with
+//! [`assert_outputs`](crate::assert_outputs).
+//!
+//! Code that is written against [`std::io::BufRead`] or [`std::io::Read`] does not need this, since
+//! it can be passed a byte slice like `"input text".as_bytes()` directly.
+
+use crate::utilities::panic_message_builder::{MessageType, PanicMessageBuilder};
+use filedescriptor::{FileDescriptor, StdioDescriptor};
+use std::{
+    io::{self, BufRead, Seek, SeekFrom, Write},
+    panic::{self, AssertUnwindSafe},
+    sync::{Mutex, PoisonError},
+    thread,
+};
+use thiserror::Error;
+
+/// A mutex to make sure that only one thread redirects stdin at a time.
+static STDIN_MUTEX: Mutex<()> = Mutex::new(());
+
+/// An error that can occur when redirecting stdin.
+#[derive(Error, Debug)]
+// Making the enum non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub enum InputRedirectingError {
+    /// An error that occurs when writing the input to a temporary file.
+    #[error("error while writing input to temporary file: {0}")]
+    TemporaryFileError(io::Error),
+
+    /// An error that occurs when redirecting the stdin file descriptor.
+    #[error("error while redirecting stdin: {0}")]
+    RedirectError(filedescriptor::Error),
+
+    /// An error that occurs when discarding unread input before restoring stdin.
+    #[error("error while discarding unread input: {0}")]
+    DiscardError(io::Error),
+}
+
+/// Calls an action with stdin redirected to read from the given input.
+///
+/// Stdin is restored after the action returns, even if it panics. Any input that the action did
+/// not read is discarded.
+///
+/// # Arguments
+///
+/// * `input` - The text or bytes that the action will read from stdin.
+/// * `action` - The action to call.
+///
+/// # Returns
+///
+/// The value returned by the action.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::stdin::with_stdin;
+/// use std::io;
+///
+/// let name = with_stdin("Sophie\n", || {
+///     let mut line = String::new();
+///     io::stdin().read_line(&mut line).unwrap();
+///     line.trim().to_owned()
+/// });
+///
+/// assert_eq!(name, "Sophie");
+/// ```
+///
+/// # Panics
+///
+/// * If stdin cannot be redirected.
+/// * If the action panics, the panic is resumed after stdin is restored.
+#[track_caller]
+pub fn with_stdin<ResultType>(
+    input: impl AsRef<[u8]>,
+    action: impl FnOnce() -> ResultType,
+) -> ResultType {
+    let result = PanicMessageBuilder::unwrap_error_with(
+        with_stdin_raw(input.as_ref(), action),
+        MessageType::ErrorWhileCheckingAssertion,
+        "unable to redirect stdin",
+        PanicMessageBuilder::no_configuration,
+    );
+
+    match result {
+        Ok(value) => value,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Calls an action with stdin redirected to read from the given input.
+///
+/// This is the same as [`with_stdin`], except that errors are returned and panics from the action
+/// are caught instead of resumed.
+///
+/// # Arguments
+///
+/// * `input` - The bytes that the action will read from stdin.
+/// * `action` - The action to call.
+///
+/// # Returns
+///
+/// The result of calling the action with [`panic::catch_unwind`].
+///
+/// # Errors
+///
+/// * If stdin cannot be redirected or restored.
+pub fn with_stdin_raw<ResultType>(
+    input: &[u8],
+    action: impl FnOnce() -> ResultType,
+) -> Result<thread::Result<ResultType>, InputRedirectingError> {
+    // A poisoned mutex only means that another redirection panicked, and stdin is always restored
+    // in that case, so it is safe to continue
+    let _guard = STDIN_MUTEX.lock().unwrap_or_else(PoisonError::into_inner);
+
+    // Write the input to a temporary file
+    let mut file = tempfile::tempfile().map_err(InputRedirectingError::TemporaryFileError)?;
+
+    file.write_all(input)
+        .and_then(|()| file.seek(SeekFrom::Start(0)))
+        .map_err(InputRedirectingError::TemporaryFileError)?;
+
+    // Redirect stdin to the temporary file
+    let original_stdin = FileDescriptor::redirect_stdio(&file, StdioDescriptor::Stdin)
+        .map_err(InputRedirectingError::RedirectError)?;
+
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    // Discard any input still in the stdin buffer so that it isn't read after stdin is restored
+    discard_buffered_stdin().map_err(InputRedirectingError::DiscardError)?;
+
+    // Restore stdin
+    FileDescriptor::redirect_stdio(&original_stdin, StdioDescriptor::Stdin)
+        .map_err(InputRedirectingError::RedirectError)?;
+
+    Ok(result)
+}
+
+/// Reads and discards everything left in stdin until the end of the input.
+fn discard_buffered_stdin() -> io::Result<()> {
+    let mut stdin = io::stdin().lock();
+
+    loop {
+        let len = stdin.fill_buf()?.len();
+
+        if len == 0 {
+            return Ok(());
+        }
+
+        stdin.consume(len);
+    }
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+//
+// Panic allowed to generate panics for testing.
+#[allow(clippy::unwrap_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::assert_eq;
+    use std::io::Read;
+
+    #[test]
+    fn read_line() {
+        let line = with_stdin("hello\nworld\n", || {
+            let mut line = String::new();
+            io::stdin().read_line(&mut line).unwrap();
+            line
+        });
+
+        assert_eq!(line, "hello\n");
+    }
+
+    #[test]
+    fn read_to_string() {
+        let text = with_stdin("hello\nworld\n", || {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).unwrap();
+            text
+        });
+
+        assert_eq!(text, "hello\nworld\n");
+    }
+
+    #[test]
+    fn bytes() {
+        let text = with_stdin([1, 2, 3], || {
+            let mut text = Vec::new();
+            io::stdin().read_to_end(&mut text).unwrap();
+            text
+        });
+
+        assert_eq!(text, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn unread_input_is_discarded() {
+        with_stdin("first\nunread\n", || {
+            let mut line = String::new();
+            io::stdin().read_line(&mut line).unwrap();
+        });
+
+        let line = with_stdin("second\n", || {
+            let mut line = String::new();
+            io::stdin().read_line(&mut line).unwrap();
+            line
+        });
+
+        assert_eq!(line, "second\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "panic in action")]
+    fn panic_is_resumed() {
+        with_stdin("", || panic!("panic in action"));
+    }
+
+    #[test]
+    fn raw_catches_panic() {
+        let result = with_stdin_raw(b"", || panic!("panic in action")).unwrap();
+
+        crate::assert!(result.is_err());
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Named sections within a single test.
+//!
+//! See [`subtest`](crate::subtest) for more information.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+};
+
+use super::{color_choice::style, panic_message_builder::is_panic_output_suppressed};
+
+/// The separator between subtest names in a subtest path.
+const SUBTEST_PATH_SEPARATOR: &str = " / ";
+
+thread_local! {
+    /// The names of the subtests that are currently running on this thread, from outermost to
+    /// innermost.
+    static SUBTEST_NAMES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+
+    /// The address of the last panic payload that was reported by a subtest on this thread.
+    ///
+    /// This is used so that only the innermost subtest reports a failure as it unwinds through
+    /// nested subtests.
+    static REPORTED_PAYLOAD_ADDRESS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Gets the names of the subtests that are currently running on this thread.
+///
+/// # Returns
+///
+/// The names from outermost to innermost. This is empty if no subtest is running.
+#[must_use]
+pub fn current_subtest_names() -> Vec<String> {
+    SUBTEST_NAMES.with(|names| names.borrow().clone())
+}
+
+/// Gets the path of the subtest that is currently running on this thread.
+///
+/// # Returns
+///
+/// The names of the running subtests joined by `" / "`, or `None` if no subtest is running.
+#[must_use]
+pub fn current_subtest_path() -> Option<String> {
+    SUBTEST_NAMES.with(|names| {
+        let names = names.borrow();
+
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join(SUBTEST_PATH_SEPARATOR))
+        }
+    })
+}
+
+/// Gets an address that identifies a panic payload as it is resumed through nested subtests.
+#[must_use]
+fn get_payload_address(payload: &(dyn Any + Send)) -> usize {
+    // Casting the pointer to an address is intended, since the address is only used for identity
+    #[allow(clippy::as_conversions)]
+    let address = (payload as *const (dyn Any + Send)).cast::<()>() as usize;
+
+    address
+}
+
+/// Runs a named section of a test.
+///
+/// This is usually called with the [`subtest`](crate::subtest) macro.
+///
+/// If the action panics, the path of the subtest is printed to `stderr` after the panic message and
+/// the panic is resumed.
+///
+/// # Arguments
+///
+/// * `name` - The name of the subtest.
+/// * `action` - The body of the subtest.
+///
+/// # Returns
+///
+/// The value returned by the action.
+///
+/// # Panics
+///
+/// * If the action panics, the panic is resumed after the subtest is reported.
+pub fn run_subtest<ResultType>(
+    name: impl Into<String>,
+    action: impl FnOnce() -> ResultType,
+) -> ResultType {
+    SUBTEST_NAMES.with(|names| names.borrow_mut().push(name.into()));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(action));
+
+    match result {
+        Ok(value) => {
+            SUBTEST_NAMES.with(|names| names.borrow_mut().pop());
+
+            value
+        }
+        Err(payload) => {
+            report_failure(&*payload);
+
+            SUBTEST_NAMES.with(|names| names.borrow_mut().pop());
+
+            panic::resume_unwind(payload)
+        }
+    }
+}
+
+/// Helper function to print the path of the failing subtest, unless a nested subtest already
+/// reported it.
+// Stderr printing allowed because the panic message is also printed to stderr.
+#[allow(clippy::print_stderr)]
+fn report_failure(payload: &(dyn Any + Send)) {
+    let payload_address = get_payload_address(payload);
+
+    if REPORTED_PAYLOAD_ADDRESS.with(Cell::get) == Some(payload_address) {
+        return;
+    }
+
+    REPORTED_PAYLOAD_ADDRESS.with(|address| address.set(Some(payload_address)));
+
+    if is_panic_output_suppressed() {
+        return;
+    }
+
+    if let Some(path) = current_subtest_path() {
+        eprintln!("  {} {path}", style("in subtest:").dim());
+    }
+}
+
+/// Runs a named section of a test, like `t.Run(...)` in Go.
+///
+/// This makes it possible for a single `#[test]` function to contain many labeled sections. If an
+/// assertion fails within a subtest, the names of the enclosing subtests are printed after the
+/// failure message. Subtests can be nested.
+///
+/// # Arguments
+///
+/// * `name` - The name of the subtest. Anything that can be converted into a `String` can be used.
+/// * `action` - A closure with no arguments that contains the body of the subtest. Its return value
+///              is returned from the macro.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::{assert_eq, subtest};
+/// #
+/// for (input, expected) in [(1, 2), (2, 4), (3, 6)] {
+///     subtest!(format!("doubles {input}"), || {
+///         assert_eq!(input * 2, expected);
+///     });
+/// }
+/// ```
+#[macro_export]
+macro_rules! subtest {
+    ($name:expr, $action:expr $(,)?) => {
+        $crate::utilities::subtest::run_subtest($name, $action)
+    };
+}
+
+#[cfg(test)]
+// Panics allowed to generate panics for testing.
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn no_subtest() {
+        assert!(current_subtest_names().is_empty());
+        assert!(current_subtest_path().is_none());
+    }
+
+    #[test]
+    fn returns_value() {
+        let value = subtest!("returns value", || 5);
+
+        assert_eq!(value, 5);
+    }
+
+    #[test]
+    fn nested_names() {
+        subtest!("outer", || {
+            assert_eq!(current_subtest_names(), vec!["outer"]);
+
+            subtest!(String::from("inner"), || {
+                assert_eq!(current_subtest_names(), vec!["outer", "inner"]);
+                assert_eq!(current_subtest_path(), Some("outer / inner".to_owned()));
+            });
+
+            assert_eq!(current_subtest_path(), Some("outer".to_owned()));
+        });
+
+        assert!(current_subtest_path().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "lhs == rhs")]
+    fn failing_assertion() {
+        subtest!("fails", || {
+            assert_eq!(1, 2);
+        });
+    }
+
+    #[test]
+    fn names_are_popped_after_panic() {
+        let result = panic::catch_unwind(|| {
+            subtest!("outer", || {
+                subtest!("inner", || {
+                    panic!("subtest failure");
+                });
+            });
+        });
+
+        assert!(result.is_err());
+        assert!(current_subtest_path().is_none());
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Color themes for assertion failure messages.
+//!
+//! The theme is set for the whole process with
+//! [`PanicMessageBuilder::set_global_theme`](crate::utilities::panic_message_builder::PanicMessageBuilder::set_global_theme).
+
+use console::StyledObject;
+use std::sync::{Mutex, PoisonError};
+
+pub use console::Color;
+
+use super::{color_choice::style, panic_message_builder::MessageType};
+
+/// The colors used to format assertion failure messages.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::panic_message_builder::PanicMessageBuilder;
+/// use test_ur_code_xd::utilities::theme::{Color, Theme};
+///
+/// // Use blue and orange instead of red and cyan
+/// PanicMessageBuilder::set_global_theme(
+///     Theme::new()
+///         .with_assertion_failure_color(Color::Color256(208))
+///         .with_value_color(Color::Blue),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Making the struct non-exhaustive as future-proofing.
+#[non_exhaustive]
+pub struct Theme {
+    /// The color of the symbol at the start of an assertion failure message.
+    pub assertion_failure_color: Color,
+
+    /// The color of the symbol at the start of a message for an error while checking an assertion.
+    pub error_while_checking_assertion_color: Color,
+
+    /// The color of the symbol at the start of an internal error message.
+    pub internal_error_color: Color,
+
+    /// The color of the predicate description, like `lhs == rhs`.
+    pub predicate_color: Color,
+
+    /// The color of the descriptions of arguments, which are usually the source code that was
+    /// passed to the assertion.
+    pub argument_color: Color,
+
+    /// The color of the debug representations of values.
+    pub value_color: Color,
+
+    /// The color of secondary text like locations, `==` prefixes, and backtraces, or `None` to dim
+    /// it instead.
+    ///
+    /// Dimmed text can be hard to read on some terminals, especially those with light backgrounds.
+    pub dim_color: Option<Color>,
+}
+
+impl Theme {
+    /// Creates the default theme, which is designed for terminals with dark backgrounds.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            assertion_failure_color: Color::Red,
+            error_while_checking_assertion_color: Color::Magenta,
+            internal_error_color: Color::Cyan,
+            predicate_color: Color::White,
+            argument_color: Color::White,
+            value_color: Color::Cyan,
+            dim_color: None,
+        }
+    }
+
+    /// Creates a theme for terminals with light backgrounds.
+    ///
+    /// White text is replaced with black, and secondary text uses gray instead of being dimmed.
+    #[must_use]
+    pub const fn light() -> Self {
+        Self {
+            assertion_failure_color: Color::Red,
+            error_while_checking_assertion_color: Color::Magenta,
+            internal_error_color: Color::Blue,
+            predicate_color: Color::Black,
+            argument_color: Color::Black,
+            value_color: Color::Blue,
+            dim_color: Some(Color::Color256(244)),
+        }
+    }
+
+    /// Sets the color of the symbol at the start of an assertion failure message.
+    #[must_use]
+    pub const fn with_assertion_failure_color(mut self, color: Color) -> Self {
+        self.assertion_failure_color = color;
+        self
+    }
+
+    /// Sets the color of the symbol at the start of a message for an error while checking an
+    /// assertion.
+    #[must_use]
+    pub const fn with_error_while_checking_assertion_color(mut self, color: Color) -> Self {
+        self.error_while_checking_assertion_color = color;
+        self
+    }
+
+    /// Sets the color of the symbol at the start of an internal error message.
+    #[must_use]
+    pub const fn with_internal_error_color(mut self, color: Color) -> Self {
+        self.internal_error_color = color;
+        self
+    }
+
+    /// Sets the color of the predicate description.
+    #[must_use]
+    pub const fn with_predicate_color(mut self, color: Color) -> Self {
+        self.predicate_color = color;
+        self
+    }
+
+    /// Sets the color of the descriptions of arguments.
+    #[must_use]
+    pub const fn with_argument_color(mut self, color: Color) -> Self {
+        self.argument_color = color;
+        self
+    }
+
+    /// Sets the color of the debug representations of values.
+    #[must_use]
+    pub const fn with_value_color(mut self, color: Color) -> Self {
+        self.value_color = color;
+        self
+    }
+
+    /// Sets the color of secondary text, or `None` to dim it instead.
+    #[must_use]
+    pub const fn with_dim_color(mut self, color: Option<Color>) -> Self {
+        self.dim_color = color;
+        self
+    }
+
+    /// Gets the color of the symbol at the start of a message of the given type.
+    #[must_use]
+    pub const fn symbol_color(&self, message_type: MessageType) -> Color {
+        match message_type {
+            MessageType::AssertionFailure => self.assertion_failure_color,
+            MessageType::ErrorWhileCheckingAssertion => self.error_while_checking_assertion_color,
+            MessageType::InternalError => self.internal_error_color,
+        }
+    }
+
+    /// Styles secondary text with either the dim color or the dim attribute.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The text to style.
+    #[must_use]
+    pub fn dim<ValueType>(&self, value: ValueType) -> StyledObject<ValueType> {
+        match self.dim_color {
+            Some(color) => style(value).fg(color),
+            None => style(value).dim(),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The current theme for the process.
+static THEME: Mutex<Theme> = Mutex::new(Theme::new());
+
+/// Sets the theme for the whole process.
+///
+/// This is called by
+/// [`PanicMessageBuilder::set_global_theme`](crate::utilities::panic_message_builder::PanicMessageBuilder::set_global_theme).
+pub(crate) fn set_theme(theme: Theme) {
+    *THEME.lock().unwrap_or_else(PoisonError::into_inner) = theme;
+}
+
+/// Gets the current theme for the process.
+#[must_use]
+pub fn theme() -> Theme {
+    *THEME.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_eq, utilities::panic_message_builder::PanicMessageBuilder};
+
+    #[test]
+    fn symbol_color() {
+        let theme = Theme::new().with_internal_error_color(Color::Yellow);
+
+        assert_eq!(
+            theme.symbol_color(MessageType::AssertionFailure),
+            Color::Red
+        );
+        assert_eq!(
+            theme.symbol_color(MessageType::InternalError),
+            Color::Yellow
+        );
+    }
+
+    #[test]
+    fn dim_color() {
+        let theme = Theme::new().with_dim_color(Some(Color::Color256(244)));
+
+        assert_eq!(
+            theme.dim("at").force_styling(true).to_string(),
+            style("at")
+                .fg(Color::Color256(244))
+                .force_styling(true)
+                .to_string()
+        );
+
+        assert_eq!(
+            Theme::new().dim("at").force_styling(true).to_string(),
+            style("at").dim().force_styling(true).to_string()
+        );
+    }
+
+    #[test]
+    fn set_global_theme() {
+        PanicMessageBuilder::set_global_theme(Theme::light());
+
+        assert_eq!(theme(), Theme::light());
+
+        PanicMessageBuilder::set_global_theme(Theme::default());
+
+        assert_eq!(theme(), Theme::new());
+    }
+}
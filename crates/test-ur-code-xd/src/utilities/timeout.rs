@@ -0,0 +1,159 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime support for the `#[test_with_timeout]` attribute.
+//!
+//! See
+//! [sophie-katz.github.io/test-ur-code-XD/tests/timeouts](https://sophie-katz.github.io/test-ur-code-XD/tests/timeouts/)
+//! for a usage guide.
+
+use std::{
+    panic::{self, Location},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use super::{
+    format::format_duration,
+    panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+/// Runs a test body on a separate thread, panicking if it does not finish within a time limit.
+///
+/// This is usually called by code generated by the `#[test_with_timeout]` attribute.
+///
+/// The thread running the test body is given the same name as the current thread so that panic
+/// messages from the test body still name the test.
+///
+/// # Arguments
+///
+/// * `limit` - The maximum amount of time that the test body can run for.
+/// * `action` - The test body.
+///
+/// # Returns
+///
+/// The value returned by the test body.
+///
+/// # Panics
+///
+/// * If the test body does not finish within the time limit. The test body's thread cannot be
+///   stopped, so it is left running in the background.
+/// * If the test body panics, the panic is resumed on the current thread.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::timeout::run_with_timeout;
+/// use std::time::Duration;
+///
+/// let value = run_with_timeout(Duration::from_secs(1), || 5);
+///
+/// assert_eq!(value, 5);
+/// ```
+#[track_caller]
+pub fn run_with_timeout<ResultType: Send + 'static>(
+    limit: Duration,
+    action: impl FnOnce() -> ResultType + Send + 'static,
+) -> ResultType {
+    let location = Location::caller();
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut builder = thread::Builder::new();
+
+    if let Some(name) = thread::current().name() {
+        builder = builder.name(name.to_owned());
+    }
+
+    let handle = builder.spawn(move || {
+        // The receiver is dropped if the test has already timed out, in which case there is nobody
+        // to send the value to
+        drop(sender.send(action()));
+    });
+
+    let handle = match handle {
+        Ok(handle) => handle,
+        Err(error) => PanicMessageBuilder::unwrap_error_with(
+            PanicMessageBuilder::new_from_error(
+                MessageType::ErrorWhileCheckingAssertion,
+                "unable to spawn thread for test body",
+                location,
+                &error,
+            ),
+            MessageType::InternalError,
+            "unable to create panic message builder for test timeout",
+            PanicMessageBuilder::no_configuration,
+        )
+        .panic(),
+    };
+
+    match receiver.recv_timeout(limit) {
+        Ok(value) => value,
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => PanicMessageBuilder::new(
+                MessageType::InternalError,
+                "test body finished without sending its result",
+                location,
+            )
+            .panic(),
+        },
+        Err(RecvTimeoutError::Timeout) => PanicMessageBuilder::new(
+            MessageType::AssertionFailure,
+            format!("test timed out after {}", format_duration(limit)),
+            location,
+        )
+        .panic(),
+    }
+}
+
+#[cfg(test)]
+// Panics allowed to generate panics for testing.
+#[allow(clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::assert_eq;
+
+    #[test]
+    fn passing() {
+        assert_eq!(run_with_timeout(Duration::from_secs(5), || 5), 5);
+    }
+
+    #[test]
+    fn passing_same_thread_name() {
+        let name = run_with_timeout(Duration::from_secs(5), || {
+            thread::current().name().map(ToOwned::to_owned)
+        });
+
+        assert_eq!(name, thread::current().name().map(ToOwned::to_owned));
+    }
+
+    #[test]
+    #[should_panic(expected = "test timed out after 10 ms")]
+    fn failing_timeout() {
+        run_with_timeout(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_secs(1));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "failure in test body")]
+    fn failing_panic_in_body() {
+        run_with_timeout(Duration::from_secs(5), || {
+            panic!("failure in test body");
+        });
+    }
+}
@@ -32,6 +32,60 @@ pub enum TruncationMode {
     End,
 }
 
+/// Truncates a string to a maximum size in bytes by removing its middle.
+///
+/// The start and end of the string are kept, split on character boundaries, with a marker in
+/// between saying how many bytes were removed. The marker is not counted towards the maximum size.
+///
+/// # Example
+///
+/// ```
+/// # use test_ur_code_xd::utilities::truncate::truncate_middle_bytes;
+/// #
+/// assert_eq!(
+///     truncate_middle_bytes("abcdefghij", 4).as_deref(),
+///     Some("ab \u{2026} 6 bytes omitted \u{2026} ij")
+/// );
+///
+/// assert_eq!(truncate_middle_bytes("abc", 4), None);
+/// ```
+///
+/// # Arguments
+///
+/// * `value` - The string to truncate.
+/// * `max_byte_len` - The maximum number of bytes to keep.
+///
+/// # Returns
+///
+/// The truncated string, or `None` if the string is already short enough.
+#[must_use]
+// Integer division is intended when splitting the kept bytes between the head and the tail.
+#[allow(clippy::integer_division)]
+pub fn truncate_middle_bytes(value: &str, max_byte_len: usize) -> Option<String> {
+    if value.len() <= max_byte_len {
+        return None;
+    }
+
+    let mut head_end = max_byte_len / 2;
+
+    while !value.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+
+    let mut tail_start = value.len() - (max_byte_len - max_byte_len / 2);
+
+    while !value.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+
+    Some(format!(
+        "{} \u{2026} {} bytes omitted \u{2026} {}",
+        value.get(..head_end)?,
+        tail_start - head_end,
+        value.get(tail_start..)?
+    ))
+}
+
 /// A trait for truncation
 pub trait Truncate {
     /// Truncates a string.
@@ -0,0 +1,220 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Support for rewriting expected files with actual values instead of failing.
+//!
+//! When the `UPDATE` environment variable is set to `1`, assertions that compare against an
+//! expected file rewrite it and pass instead of failing. This works with
+//! [`assert_file_text_eq`](crate::assert_file_text_eq), [`assert_files_eq`](crate::assert_files_eq),
+//! and [`assert_snapshot`](crate::assert_snapshot). Each rewritten file is printed to stderr as it
+//! is updated.
+
+use std::{
+    env, fs,
+    io::{self, Write},
+    panic::Location,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use crate::utilities::{
+    color_choice::style,
+    panic_message_builder::{MessageType, PanicMessageBuilder},
+};
+
+/// The environment variable that makes assertions rewrite expected files instead of failing.
+pub const UPDATE_ENVIRONMENT_VARIABLE: &str = "UPDATE";
+
+/// The files that have been rewritten by assertions in this process, in the order that they were
+/// rewritten.
+static UPDATED_FILES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// Parses whether or not update mode is enabled from the value of [`UPDATE_ENVIRONMENT_VARIABLE`].
+///
+/// The value is case-insensitive and can be `1`, `true`, or `yes`. Anything else leaves update mode
+/// disabled.
+#[must_use]
+pub fn parse_update_mode(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes"
+    )
+}
+
+/// Gets whether or not update mode is enabled by the environment.
+#[must_use]
+pub fn is_update_mode_enabled() -> bool {
+    env::var(UPDATE_ENVIRONMENT_VARIABLE).map_or(false, |value| parse_update_mode(&value))
+}
+
+/// Gets the files that have been rewritten by assertions in this process.
+///
+/// This is useful for custom harnesses that want to print their own summary after all tests have
+/// run.
+///
+/// # Returns
+///
+/// The paths of the rewritten files in the order that they were rewritten. A file that was
+/// rewritten more than once appears once for each time.
+#[must_use]
+pub fn updated_files() -> Vec<PathBuf> {
+    // A poisoned mutex only means that a thread panicked while printing an update, in which case
+    // the list is still valid
+    UPDATED_FILES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Helper function to write an expected file, creating its parent directories if needed.
+fn write_expected_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+
+    fs::write(path, contents)
+}
+
+/// Helper function to record that a file was rewritten and print it.
+///
+/// The line is written to the real `stderr` instead of with `eprintln!` so that it isn't captured
+/// by the test harness along with the output of the passing test. Errors are ignored since there is
+/// nowhere to report them.
+fn record_updated_file(path: &Path) {
+    let mut updated_files = UPDATED_FILES.lock().unwrap_or_else(PoisonError::into_inner);
+
+    updated_files.push(path.to_owned());
+
+    let updated_file_count = updated_files.len();
+
+    let _stderr_result: io::Result<()> = writeln!(
+        io::stderr(),
+        "{}",
+        style(format!(
+            "updated expected file {} ({updated_file_count} {} updated so far)",
+            path.display(),
+            if updated_file_count == 1 {
+                "file"
+            } else {
+                "files"
+            }
+        ))
+        .dim()
+    );
+}
+
+/// Rewrites an expected file with the actual contents if they are different.
+///
+/// This will almost always be called by assertions when update mode is enabled.
+///
+/// # Arguments
+///
+/// * `path` - The path of the expected file. It and its parent directories are created if they
+///            don't exist.
+/// * `contents` - The actual contents with which to replace the expected file.
+///
+/// # Returns
+///
+/// `true` if the file was rewritten, or `false` if it already had the contents.
+///
+/// # Panics
+///
+/// * If the file can't be written.
+// Expect allowed because any failure to format the panic message is hard coded in, not dependent on
+// how this function is called.
+#[allow(clippy::expect_used)]
+#[track_caller]
+pub fn update_expected_file(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> bool {
+    let path = path.as_ref();
+    let contents = contents.as_ref();
+
+    if fs::read(path).map_or(false, |existing_contents| existing_contents == contents) {
+        return false;
+    }
+
+    if let Err(error) = write_expected_file(path, contents) {
+        PanicMessageBuilder::new_from_error(
+            MessageType::ErrorWhileCheckingAssertion,
+            "error updating expected file",
+            Location::caller(),
+            &error,
+        )
+        .and_then(|panic_message_builder| panic_message_builder.with_argument("path", "--", &path))
+        .expect("error while creating panic message builder")
+        .panic();
+    }
+
+    record_updated_file(path);
+
+    true
+}
+
+#[cfg(test)]
+// Unwrap allowed to reduce length of test code.
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+    use tempfile::tempdir;
+
+    #[test]
+    fn parse_update_mode_enabled() {
+        assert!(parse_update_mode("1"));
+        assert!(parse_update_mode("true"));
+        assert!(parse_update_mode(" YES "));
+    }
+
+    #[test]
+    fn parse_update_mode_disabled() {
+        assert!(!parse_update_mode(""));
+        assert!(!parse_update_mode("0"));
+        assert!(!parse_update_mode("false"));
+        assert!(!parse_update_mode("always"));
+    }
+
+    #[test]
+    fn update_expected_file_missing() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("golden").join("output.txt");
+
+        assert!(update_expected_file(&path, "hello, world"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello, world");
+        assert!(updated_files().contains(&path));
+    }
+
+    #[test]
+    fn update_expected_file_different() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("output.txt");
+
+        fs::write(&path, "hello! world").unwrap();
+
+        assert!(update_expected_file(&path, "hello, world"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "hello, world");
+    }
+
+    #[test]
+    fn update_expected_file_same() {
+        let directory = tempdir().unwrap();
+        let path = directory.path().join("output.txt");
+
+        fs::write(&path, "hello, world").unwrap();
+
+        assert!(!update_expected_file(&path, "hello, world"));
+        assert!(!updated_files().contains(&path));
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+//! Configuration for how much detail is in assertion failure messages.
+//!
+//! The verbosity is read from the `TEST_UR_CODE_XD_VERBOSITY` environment variable so that it can
+//! be changed without recompiling, like in CI logs where compact messages are easier to scan. The
+//! maximum size of values is read from `TEST_UR_CODE_XD_MAX_VALUE_BYTES` in the same way.
+
+use std::{env, ffi::OsStr};
+
+/// The environment variable that sets the verbosity of failure messages.
+pub const VERBOSITY_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_VERBOSITY";
+
+/// The environment variable that disables colors in failure messages when it is set to anything
+/// other than an empty string.
+///
+/// See [no-color.org](https://no-color.org/) for more information.
+pub const NO_COLOR_ENVIRONMENT_VARIABLE: &str = "NO_COLOR";
+
+/// The environment variable that sets the maximum size of each value in failure messages, in
+/// bytes.
+///
+/// Values that are larger have their middle removed. Set it to `0` to never truncate values.
+pub const MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE: &str = "TEST_UR_CODE_XD_MAX_VALUE_BYTES";
+
+/// The maximum size of each value in failure messages, in bytes, if it is not set by
+/// [`MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE`].
+pub const DEFAULT_MAX_VALUE_BYTES: usize = 16 * 1024;
+
+/// How much detail is in assertion failure messages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Verbosity {
+    /// One line per argument using the compact debug representation, without the backtrace hint.
+    Compact,
+
+    /// Pretty debug representations with the backtrace shown if `RUST_BACKTRACE` is set. This is
+    /// the default.
+    #[default]
+    Normal,
+
+    /// Pretty debug representations with untruncated value descriptions, and the backtrace is
+    /// always shown.
+    Verbose,
+}
+
+impl Verbosity {
+    /// Parses a verbosity from the value of [`VERBOSITY_ENVIRONMENT_VARIABLE`].
+    ///
+    /// The value is case-insensitive and can be `compact`, `normal`, or `verbose`. Anything else is
+    /// treated as [`Verbosity::Normal`].
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "compact" => Self::Compact,
+            "verbose" => Self::Verbose,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Gets the verbosity of failure messages from the environment.
+#[must_use]
+pub fn verbosity() -> Verbosity {
+    env::var(VERBOSITY_ENVIRONMENT_VARIABLE)
+        .map(|value| Verbosity::parse(&value))
+        .unwrap_or_default()
+}
+
+/// Parses a maximum value size from the value of [`MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE`].
+///
+/// # Returns
+///
+/// The maximum size in bytes, or `None` if the value is `0` and values should never be truncated.
+/// Anything that isn't a number is treated as [`DEFAULT_MAX_VALUE_BYTES`].
+#[must_use]
+pub fn parse_max_value_bytes(value: &str) -> Option<usize> {
+    match value.trim().parse::<usize>() {
+        Ok(0) => None,
+        Ok(max_value_bytes) => Some(max_value_bytes),
+        Err(_) => Some(DEFAULT_MAX_VALUE_BYTES),
+    }
+}
+
+/// Gets the maximum size of each value in failure messages from the environment, in bytes.
+///
+/// # Returns
+///
+/// The maximum size in bytes, or `None` if values should never be truncated.
+#[must_use]
+pub fn max_value_bytes() -> Option<usize> {
+    env::var(MAX_VALUE_BYTES_ENVIRONMENT_VARIABLE).map_or(Some(DEFAULT_MAX_VALUE_BYTES), |value| {
+        parse_max_value_bytes(&value)
+    })
+}
+
+/// Parses whether or not colors are disabled from the value of [`NO_COLOR_ENVIRONMENT_VARIABLE`].
+///
+/// # Arguments
+///
+/// * `value` - The value of the environment variable, or `None` if it isn't set.
+///
+/// # Returns
+///
+/// `true` if the variable is set to anything other than an empty string.
+#[must_use]
+pub fn parse_no_color(value: Option<&OsStr>) -> bool {
+    value.map_or(false, |value| !value.is_empty())
+}
+
+/// Gets whether or not colors are disabled in failure messages by the environment.
+#[must_use]
+pub fn is_color_disabled() -> bool {
+    parse_no_color(env::var_os(NO_COLOR_ENVIRONMENT_VARIABLE).as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert, assert_eq};
+
+    #[test]
+    fn parse_known() {
+        assert_eq!(Verbosity::parse("compact"), Verbosity::Compact);
+        assert_eq!(Verbosity::parse("normal"), Verbosity::Normal);
+        assert_eq!(Verbosity::parse("verbose"), Verbosity::Verbose);
+    }
+
+    #[test]
+    fn parse_case_and_whitespace() {
+        assert_eq!(Verbosity::parse(" Compact\n"), Verbosity::Compact);
+        assert_eq!(Verbosity::parse("VERBOSE"), Verbosity::Verbose);
+    }
+
+    #[test]
+    fn parse_unknown() {
+        assert_eq!(Verbosity::parse(""), Verbosity::Normal);
+        assert_eq!(Verbosity::parse("loud"), Verbosity::Normal);
+    }
+
+    #[test]
+    fn parse_max_value_bytes_number() {
+        assert_eq!(parse_max_value_bytes("100"), Some(100));
+        assert_eq!(parse_max_value_bytes(" 2048\n"), Some(2048));
+    }
+
+    #[test]
+    fn parse_max_value_bytes_zero_is_unlimited() {
+        assert!(parse_max_value_bytes("0").is_none());
+    }
+
+    #[test]
+    fn parse_max_value_bytes_invalid() {
+        assert_eq!(parse_max_value_bytes(""), Some(DEFAULT_MAX_VALUE_BYTES));
+        assert_eq!(parse_max_value_bytes("-1"), Some(DEFAULT_MAX_VALUE_BYTES));
+        assert_eq!(parse_max_value_bytes("lots"), Some(DEFAULT_MAX_VALUE_BYTES));
+    }
+
+    #[test]
+    fn parse_no_color_set() {
+        assert!(parse_no_color(Some(OsStr::new("1"))));
+        assert!(parse_no_color(Some(OsStr::new("false"))));
+    }
+
+    #[test]
+    fn parse_no_color_empty_or_unset() {
+        assert!(!parse_no_color(Some(OsStr::new(""))));
+        assert!(!parse_no_color(None));
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2023 Sophie Katz
+//
+// This file is part of test ur code XD.
+//
+// test ur code XD is free software: you can redistribute it and/or modify it under the terms of the
+// GNU General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// test ur code XD is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+// without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with test ur code XD. If
+// not, see <https://www.gnu.org/licenses/>.
+
+#![allow(
+    // Unwrap allowed to reduce length of test code.
+    clippy::unwrap_used,
+    // Panics used to test the panic hook.
+    clippy::panic
+)]
+
+//! Checks that assertion failures don't replace panic hooks installed by the user, that the user's
+//! hook isn't called for assertion failures, and that non-assertion panics clear the last assertion
+//! failure.
+//!
+//! The panic hook is process-wide, so this is its own test binary with a single test so that other
+//! tests can't interfere with it.
+
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use test_ur_code_xd::{
+    assert, assert_eq,
+    utilities::assertion_failure::{capture_assertion_failure, last_assertion_failure},
+};
+
+/// The number of times that the user's panic hook has been called.
+static USER_HOOK_CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+#[test]
+fn user_panic_hook_runs_for_other_panics_but_not_assertion_failures() {
+    panic::set_hook(Box::new(|_| {
+        USER_HOOK_CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    // The user's hook runs for panics that aren't assertion failures
+    assert!(panic::catch_unwind(|| panic!("not an assertion")).is_err());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 1);
+
+    // Assertion failures are printed by test ur code XD's hook and are not passed on to the user's
+    // hook, since it can't be told apart from the default hook which would print them a second time
+    assert!(panic::catch_unwind(|| assert_eq!(1, 2)).is_err());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(last_assertion_failure().unwrap().predicate, "lhs == rhs");
+
+    assert!(panic::catch_unwind(|| assert!(false)).is_err());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 1);
+    assert_eq!(last_assertion_failure().unwrap().predicate, "value is true");
+
+    // The user's hook still runs for later panics, which also clear the last assertion failure
+    assert!(panic::catch_unwind(|| panic!("not an assertion")).is_err());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 2);
+    assert!(last_assertion_failure().is_none());
+
+    // Silently caught assertion failures don't leave anything behind for the next panic to print
+    assert!(panic::catch_unwind(|| {
+        test_ur_code_xd::assert_panics!(|| assert_eq!(1, 2));
+
+        panic!("not an assertion");
+    })
+    .is_err());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 3);
+
+    // Panic output is only suppressed on the thread catching the panic, so the user's hook still
+    // runs for panics on other threads
+    let failure = capture_assertion_failure(|| {
+        assert!(thread::spawn(|| panic!("not an assertion")).join().is_err());
+
+        assert_eq!(1, 2);
+    });
+    assert!(failure.is_some());
+    assert_eq!(USER_HOOK_CALL_COUNT.load(Ordering::SeqCst), 4);
+
+    drop(panic::take_hook());
+}
@@ -40,9 +40,7 @@ fn benchmark_empty_create_config() {
     let start = Instant::now();
 
     for _ in 0..SAMPLE_SIZE {
-        let _: Config = Config {
-            ..Default::default()
-        };
+        let _: Config = Config::default();
     }
 
     println!("{:?}ms elapsed", start.elapsed().as_millis());
@@ -23,7 +23,9 @@
     // Allow stdout printing to generate output.
     clippy::print_stdout,
     // Allow arithmetic side effects to reduce length of test code.
-    clippy::arithmetic_side_effects
+    clippy::arithmetic_side_effects,
+    // Parameterized test values are always passed by value.
+    clippy::needless_pass_by_value
 )]
 
 #[cfg(test)]
@@ -56,11 +58,43 @@ mod home {
 }
 
 mod getting_started {
+    use test_ur_code_xd::{
+        output::{set_color_choice, ColorChoice},
+        utilities::{
+            panic_message_builder::PanicMessageBuilder,
+            theme::{Color, Theme},
+        },
+    };
+
     #[test]
     fn example() {
         let hello_world = "hello, world";
         assert_str_eq!(hello_world, "hello, world");
     }
+
+    #[test]
+    fn example_theme() {
+        // Use the built-in theme for light backgrounds
+        PanicMessageBuilder::set_global_theme(Theme::light());
+
+        // Or customize individual colors
+        PanicMessageBuilder::set_global_theme(
+            Theme::new()
+                .with_assertion_failure_color(Color::Color256(208))
+                .with_value_color(Color::Blue)
+                .with_dim_color(Some(Color::Color256(244))),
+        );
+
+        PanicMessageBuilder::set_global_theme(Theme::default());
+    }
+
+    #[test]
+    fn example_color_choice() {
+        // Never color failure messages
+        set_color_choice(ColorChoice::Never);
+
+        set_color_choice(ColorChoice::Auto);
+    }
 }
 
 mod assertions {
@@ -77,6 +111,18 @@ mod assertions {
             // Ensure that value is false
             assert_not!(value);
         }
+
+        #[test]
+        fn example_combining_conditions() {
+            let name = String::from("sophie");
+            let path = "config.toml";
+
+            // Ensure that every condition is true
+            assert_all_of!(!name.is_empty(), name.len() < 10, name.is_ascii());
+
+            // Ensure that at least one condition is true
+            assert_any_of!(path.ends_with(".toml"), path.ends_with(".yaml"));
+        }
     }
 
     mod arithmetic {
@@ -94,6 +140,17 @@ mod assertions {
             assert_ne!(x, y);
         }
 
+        #[test]
+        fn example_equality_different_types() {
+            let name = String::from("Sophie");
+
+            // String vs &str
+            assert_eq!(name, "Sophie");
+
+            // Vec vs array
+            assert_eq!(vec![1, 2], [1, 2]);
+        }
+
         #[test]
         fn example_ordering() {
             let x = 4;
@@ -114,6 +171,83 @@ mod assertions {
             // Ensure that x is greater than or equal to y
             assert_ge!(x, y);
         }
+
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Release {
+            major: u32,
+            minor: u32,
+            name: &'static str,
+        }
+
+        #[test]
+        #[should_panic(expected = "lhs < rhs")]
+        fn example_compare_key() {
+            let older_release = Release {
+                major: 1,
+                minor: 2,
+                name: "cobalt",
+            };
+
+            let newer_release = Release {
+                major: 1,
+                minor: 0,
+                name: "argon",
+            };
+
+            assert_lt!(
+                older_release,
+                newer_release,
+                compare_key = |release| (release.major, release.minor)
+            );
+        }
+
+        #[test]
+        fn example_between() {
+            let x = 5;
+
+            // Ensure that 1 <= x <= 10
+            assert_between!(x, 1, 10);
+
+            // Ensure that 4 < x < 6
+            assert_between!(x, 4, 6, exclusive = true);
+
+            // Ensure that 1 <= x < 10
+            assert_in_range!(x, 1..10);
+
+            // Ensure that x >= 0
+            assert_in_range!(x, 0..);
+        }
+
+        #[test]
+        fn example_ordered() {
+            let low = 1;
+            let x = 5;
+            let high = 10;
+
+            // Ensure that low <= x < high
+            assert_ordered!(low <= x < high);
+
+            // Ensure that the values are strictly decreasing
+            assert_ordered!(high > x > low > 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "values are ordered")]
+        fn example_ordered_failure() {
+            let low = 1;
+            let x = 5;
+            let high = 4;
+
+            assert_ordered!(low <= x < high);
+        }
+
+        #[test]
+        fn example_abs_diff() {
+            let elapsed_ticks: u64 = 1_002;
+
+            // Ensure that elapsed_ticks is within 5 of 1000
+            assert_abs_diff_le!(elapsed_ticks, 1_000, 5);
+        }
     }
 
     mod string {
@@ -136,9 +270,124 @@ mod assertions {
             // Ensure that the first string matches the second regex
             assert_str_matches!("hello, world", "[a-z, ]+");
         }
+
+        #[test]
+        fn example_normalized() {
+            // Compare case-insensitively
+            assert_str_eq!("Hello, World", "hello, world", ignore_case = true);
+
+            // Trim the ends and collapse runs of whitespace into a single space
+            assert_str_eq!(
+                "hello,\n    world\n",
+                "hello, world",
+                normalize_whitespace = true
+            );
+        }
+
+        #[test]
+        fn example_lines() {
+            let output = "hello\r\nworld  \r\n";
+
+            assert_lines_eq!(output, "hello\nworld  \n");
+            assert_lines_eq!(output, "hello\nworld\n", trim_trailing = true);
+        }
+
+        #[test]
+        #[should_panic(expected = "actual lines == expected lines")]
+        fn example_lines_failure() {
+            let output = "hello\r\nworld  \r\n";
+
+            assert_lines_eq!(output, "hello\nthere\n");
+        }
+
+        #[test]
+        fn example_counting() {
+            let log = "error: a\nok\nerror: b\n";
+            let html = "<ul><li>a</li><li>b</li></ul>";
+            let path = "/search?q=rust";
+
+            assert_str_count_eq!(log, "error:", 2);
+            assert_str_count_ge!(html, "<li>", 2);
+            assert_str_count_le!(path, '?', 1);
+            assert_str_count_eq!("aaaa", "aa", 2);
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn example_counting_regex() {
+            use regex::Regex;
+
+            let log = "request 1 ok\nrequest 2 ok\n";
+
+            assert_str_count_eq!(log, Regex::new(r"request \d+ failed").unwrap(), 0);
+        }
+
+        #[test]
+        #[should_panic(expected = "occurrence count == count")]
+        fn example_counting_failure() {
+            let log = "error: a\nok\nerror: b\n";
+
+            assert_str_count_eq!(log, "error:", 1);
+        }
+
+        #[test]
+        fn example_encoding() {
+            let response_body = b"hello, world".to_vec();
+            let header_name = "Content-Type";
+
+            assert_is_utf8!(response_body);
+            assert_str_is_ascii!(header_name);
+        }
+
+        #[test]
+        #[should_panic(expected = "value is valid UTF-8")]
+        fn example_encoding_failure() {
+            let response_body = b"hi\xffthere";
+
+            assert_is_utf8!(response_body);
+        }
+
+        #[cfg(feature = "unicode-normalization")]
+        #[test]
+        fn example_normalization() {
+            use test_ur_code_xd::assertions::string::NormalizationForm;
+
+            let name = "caf\u{e9}";
+
+            // Ensure that the string is NFC normalized
+            assert_str_normalized!(name);
+
+            let name = "cafe\u{301}";
+
+            // Ensure that the string is NFD normalized
+            assert_str_normalized!(name, form = NormalizationForm::Nfd);
+        }
+    }
+
+    #[cfg(feature = "json")]
+    mod json {
+        use serde_json::json;
+
+        #[test]
+        fn example() {
+            // Ensure that two values are equal as JSON
+            assert_json_eq!(
+                json!({ "name": "Sophie", "languages": ["Rust", "C++"] }),
+                json!({ "languages": ["Rust", "C++"], "name": "Sophie" })
+            );
+
+            // Ensure that the first value contains the second
+            assert_json_contains!(
+                json!({ "id": 5, "name": "Sophie", "tags": ["a", "b"] }),
+                json!({ "name": "Sophie", "tags": ["a"] })
+            );
+        }
     }
 
     mod float {
+        use std::time::Duration;
+        use test_ur_code_xd::assertions::approx::ApproxEq;
+
         #[test]
         #[should_panic(expected = "lhs == rhs")]
         fn example_failing() {
@@ -165,11 +414,134 @@ mod assertions {
 
             assert_f32_eq!(x, y, ulps = 1, epsilon_near_zero = 1e-6);
         }
+
+        #[test]
+        fn example_defaults() {
+            let x = 5.0;
+            let y = 5.0;
+
+            assert_f32_eq!(x, y);
+            assert_f32_eq!(x, y, ulps = 1);
+        }
+
+        #[test]
+        fn example_generic() {
+            let x = 5.0_f64;
+            let y = 5.0_f64;
+
+            assert_float_eq!(0.1_f32 + 0.2_f32, 0.3_f32);
+            assert_float_eq!(x, y, ulps = 2, epsilon_near_zero = 1e-12);
+        }
+
+        #[cfg(feature = "half")]
+        #[test]
+        fn example_generic_half() {
+            use half::f16;
+
+            assert_float_eq!(f16::from_f32(0.1) + f16::from_f32(0.2), f16::from_f32(0.3));
+        }
+
+        #[test]
+        fn example_nan_equal() {
+            let x = 5.0;
+            let y = 5.0;
+
+            assert_f64_eq!(x, y, nan_equal = false);
+            assert_f64_eq!(x, y, ulps = 2, epsilon_near_zero = 1e-12, nan_equal = false);
+        }
+
+        #[test]
+        fn example_classification() {
+            let x = 5.0_f32;
+
+            assert_finite!(x);
+            assert_nan!(0.0_f64 / 0.0);
+        }
+
+        #[test]
+        fn example_slice() {
+            let x = vec![0.1 + 0.2, 2.0, 3.0];
+
+            assert_f64_slice_eq!(x, [0.3, 2.0, 3.0]);
+            assert_f64_slice_eq!(x, [0.3, 2.0, 3.0], relative_epsilon = f64::EPSILON);
+        }
+
+        #[test]
+        fn example_grid() {
+            let matrix = [[0.1 + 0.2, 0.0], [0.0, 1.0]];
+            let expected = vec![vec![0.3, 0.0], vec![0.0, 1.0]];
+
+            assert_f64_grid_eq!(matrix, [[0.3, 0.0], [0.0, 1.0]]);
+            assert_f64_grid_eq!(matrix, expected, ulps = 8);
+        }
+
+        #[test]
+        #[should_panic(expected = "lhs == rhs")]
+        fn example_grid_failure() {
+            let matrix = [[1.0, 2.0], [3.0, 4.0]];
+            let expected = [[1.0, 2.0], [3.5, 4.0]];
+
+            assert_f64_grid_eq!(matrix, expected, ulps = 8);
+        }
+
+        #[test]
+        fn example_points() {
+            let path = vec![(0.0, 0.0), (1.0, 0.5), (2.0, 1.0)];
+
+            assert_points_close!(path, [(0.0, 0.0), (1.0, 0.5), (2.0, 1.0)], epsilon = 1e-6);
+        }
+
+        #[test]
+        fn example_approx_eq() {
+            let elapsed = Duration::from_millis(1002);
+
+            assert_approx_eq!(0.1 + 0.2, 0.3, tolerance = 1e-9);
+            assert_approx_eq!(
+                elapsed,
+                Duration::from_secs(1),
+                tolerance = Duration::from_millis(5)
+            );
+            assert_approx_eq!((1.0, 2.0), (1.01, 1.99), tolerance = (0.1, 0.1));
+        }
+
+        #[test]
+        fn example_approx_eq_custom() {
+            #[derive(Debug)]
+            struct Color {
+                red: f32,
+                green: f32,
+                blue: f32,
+            }
+
+            impl ApproxEq for Color {
+                type Tolerance = f32;
+
+                fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+                    self.red.approx_eq(&other.red, tolerance)
+                        && self.green.approx_eq(&other.green, tolerance)
+                        && self.blue.approx_eq(&other.blue, tolerance)
+                }
+            }
+
+            assert_approx_eq!(
+                Color {
+                    red: 0.5,
+                    green: 0.25,
+                    blue: 1.0
+                },
+                Color {
+                    red: 0.501,
+                    green: 0.25,
+                    blue: 0.999
+                },
+                tolerance = 0.01
+            );
+        }
     }
 
     mod filesystem {
-        use std::{env, fs, io::Write};
-        use tempfile::tempdir;
+        use std::{fs, io::Write, time::Duration};
+        use test_ur_code_xd::utilities::sandbox::TestDir;
 
         #[cfg(target_family = "unix")]
         use std::os::unix::fs::symlink;
@@ -180,8 +552,8 @@ mod assertions {
         #[test]
 
         fn example() {
-            let temp_dir = tempdir().unwrap();
-            env::set_current_dir(temp_dir.path()).unwrap();
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
             fs::File::create("some_path").unwrap();
             fs::File::create("some_file").unwrap();
             symlink("some_file", "some_symlink").unwrap();
@@ -213,12 +585,38 @@ mod assertions {
 
             // Ensure that the first path is suffixed by the second
             assert_path_ends_with!("a/b/c", "b/c");
+
+            // Ensure that the path has an extension
+            assert_path_has_extension!("a/b/c.txt", "txt");
+        }
+
+        #[test]
+        fn example_metadata() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+            fs::write("some_readonly_file", "").unwrap();
+            let mut permissions = fs::metadata("some_readonly_file").unwrap().permissions();
+            permissions.set_readonly(true);
+            fs::set_permissions("some_readonly_file", permissions).unwrap();
+            fs::write("hello_world.txt", "hello, world").unwrap();
+
+            // Ensure that the path is read-only
+            assert_path_is_readonly!("some_readonly_file");
+
+            // Ensure that the file is exactly 12 bytes
+            assert_file_size_eq!("hello_world.txt", 12);
+
+            // Ensure that the file is at most 1 KiB
+            assert_file_size_le!("hello_world.txt", 1024);
+
+            // Ensure that the file was modified within the last minute
+            assert_file_modified_within!("hello_world.txt", Duration::from_secs(60));
         }
 
         #[test]
         fn example_file_text() {
-            let temp_dir = tempdir().unwrap();
-            env::set_current_dir(temp_dir.path()).unwrap();
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
             let mut file = fs::File::create("hello_world.txt").unwrap();
             file.write_all(b"hello, world").unwrap();
 
@@ -233,8 +631,8 @@ mod assertions {
 
         #[test]
         fn example_file_text_raw() {
-            let temp_dir = tempdir().unwrap();
-            env::set_current_dir(temp_dir.path()).unwrap();
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
             let mut file = fs::File::create("hello_world.txt").unwrap();
             file.write_all(b"hello, world").unwrap();
 
@@ -247,6 +645,100 @@ mod assertions {
                 }
             );
         }
+
+        #[test]
+        fn example_file_text_direct() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+            let mut file = fs::File::create("hello_world.txt").unwrap();
+            file.write_all(b"hello, world").unwrap();
+
+            // Ensure that the file text is equal to a string
+            assert_file_text_eq!("hello_world.txt", "hello, world");
+
+            // Ensure that the file text matches a regular expression
+            assert_file_text_matches!("hello_world.txt", "^hello, [a-z]+$");
+        }
+
+        #[test]
+        fn example_file_text_streaming() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+            fs::write("server.log", "starting\nlistening on port 8080\n").unwrap();
+
+            assert_file_text_matches!("server.log", "listening on port [0-9]+", streaming = true);
+        }
+
+        #[test]
+        fn example_write_then_read() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+
+            assert_write_then_read_eq!("hello_world.txt", "hello, world", |path| {
+                fs::write(path, "hello, world")
+            });
+        }
+
+        #[test]
+        fn example_files_eq() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+            fs::write("actual.bin", b"hello, world").unwrap();
+            fs::write("expected.bin", b"hello, world").unwrap();
+
+            assert_files_eq!("actual.bin", "expected.bin");
+        }
+
+        #[test]
+        fn example_creates_files() {
+            let test_dir = TestDir::new().unwrap();
+            let _current_dir = test_dir.enter().unwrap();
+
+            fn generate_report(dir: &str) {
+                fs::create_dir(dir).unwrap();
+                fs::write(format!("{dir}/a.txt"), "a").unwrap();
+                fs::write(format!("{dir}/b.txt"), "b").unwrap();
+            }
+
+            assert_creates_files!(
+                || generate_report("out"),
+                creates = ["out/a.txt", "out/b.txt"],
+                does_not_create = ["out/c.txt"]
+            );
+        }
+
+        #[test]
+        fn example_test_dir() {
+            let test_dir = TestDir::new().unwrap();
+
+            // Write a file, creating any missing parent directories
+            test_dir
+                .write_file("config/settings.toml", "verbose = true")
+                .unwrap();
+
+            // Create a directory, along with any missing parent directories
+            test_dir.create_dir_all("data/cache").unwrap();
+
+            // Get the absolute path of something in the test directory
+            assert_path_is_file!(test_dir.path_of("config/settings.toml"));
+        }
+
+        #[test]
+        fn example_test_dir_enter() {
+            let test_dir = TestDir::new().unwrap();
+
+            test_dir
+                .write_file("hello_world.txt", "hello, world")
+                .unwrap();
+
+            {
+                let _current_dir = test_dir.enter().unwrap();
+
+                assert_file_text_eq!("hello_world.txt", "hello, world");
+            }
+
+            // The previous current directory is restored here
+        }
     }
 
     mod panic {
@@ -284,6 +776,79 @@ mod assertions {
 
             // This code will still run
         }
+
+        #[test]
+        fn example_with_type() {
+            #[derive(Debug)]
+            struct InvalidState;
+
+            assert_panics!(
+                || {
+                    std::panic::panic_any(InvalidState);
+                },
+                with_type = InvalidState
+            );
+        }
+
+        #[test]
+        fn example_with_type_on_message() {
+            let path = "config.toml";
+
+            assert_panics!(
+                || {
+                    panic!("unable to open {}", path);
+                },
+                with_type = String,
+                on_message = |message| {
+                    assert_eq!(message, "unable to open config.toml");
+                }
+            );
+        }
+
+        #[test]
+        fn example_on_stderr() {
+            fn connect(_host: &str) {
+                panic!("connection refused");
+            }
+
+            assert_panics!(
+                || {
+                    eprintln!("retrying connection");
+                    connect("localhost");
+                },
+                on_message = |message| {
+                    assert_eq!(message, "connection refused");
+                },
+                on_stderr = |stderr| {
+                    assert_str_eq!(stderr, "retrying connection\n");
+                }
+            );
+        }
+
+        struct Connection {
+            is_closed: bool,
+        }
+
+        impl Drop for Connection {
+            fn drop(&mut self) {
+                assert!(self.is_closed, description = "connection was not closed");
+            }
+        }
+
+        #[test]
+        fn example_no_panic_on_drop() {
+            let connection = Connection { is_closed: true };
+
+            assert_no_panic_on_drop!(connection);
+        }
+
+        #[test]
+        #[should_panic(expected = "value does not panic when dropped")]
+        fn example_no_panic_on_drop_failure() {
+            let connection = Connection { is_closed: false };
+
+            assert_no_panic_on_drop!(connection);
+        }
     }
 
     mod output {
@@ -311,30 +876,682 @@ mod assertions {
                 }
             );
         }
-    }
 
-    mod custom {
         #[test]
-        fn example() {
-            // Instead of this:
-            let x = 3 + 5;
-            let y = 8;
-            assert_eq!(x, y);
-
-            // We can write this:
-            assert_custom!("lhs == rhs", x == y, |panic_message_builder| {
-                panic_message_builder
-                    .with_argument("lhs", "x", &x)
-                    .with_argument("rhs", "y", &y)
-            });
+        fn example_strip_ansi() {
+            assert_outputs!(
+                || {
+                    println!("\u{1b}[31mhello, world\u{1b}[0m");
+                },
+                on_stdout = |stdout| {
+                    assert_eq!(stdout, "hello, world\n");
+                },
+                strip_ansi = true
+            );
         }
-    }
-
-    mod configuring_assertions {
-        use num_traits::Float;
 
         #[test]
-        fn example_negation() {
+        fn example_tee() {
+            assert_outputs!(
+                || {
+                    println!("hello, world");
+                },
+                on_stdout = |stdout| {
+                    assert_eq!(stdout, "hello, world\n");
+                },
+                tee = true
+            );
+        }
+
+        #[test]
+        fn example_child_process() {
+            use std::process::Command;
+
+            assert_outputs!(
+                || {
+                    Command::new("echo").arg("hello, world").status().unwrap();
+                },
+                on_stdout = |stdout| {
+                    assert_eq!(stdout, "hello, world\n");
+                }
+            );
+        }
+
+        #[test]
+        fn example_input() {
+            use std::io;
+            use test_ur_code_xd::utilities::stdin::with_stdin;
+
+            with_stdin("Sophie\n", || {
+                assert_outputs!(
+                    || {
+                        let mut name = String::new();
+                        io::stdin().read_line(&mut name).unwrap();
+                        println!("hello, {}", name.trim());
+                    },
+                    on_stdout = |stdout| {
+                        assert_eq!(stdout, "hello, Sophie\n");
+                    }
+                );
+            });
+        }
+    }
+
+    mod process {
+        #[cfg(unix)]
+        #[test]
+        fn example() {
+            use std::process::Command;
+
+            assert_command!(
+                Command::new("echo").arg("hello, world"),
+                on_status = |status| {
+                    assert!(status.success());
+                },
+                on_stdout = |stdout| {
+                    assert_eq!(stdout, "hello, world\n");
+                }
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn example_snapshot() {
+            use std::process::Command;
+
+            assert_command_snapshot!(
+                "user_doc_process_example_snapshot",
+                Command::new("echo").arg("hello, world")
+            );
+        }
+    }
+
+    mod snapshot {
+        #[test]
+        fn example() {
+            assert_snapshot!("user_doc_snapshot_example", "total: 5\n");
+        }
+
+        #[test]
+        fn example_redact() {
+            let temp_dir = tempfile::tempdir().unwrap();
+            let temp_path = temp_dir.path().to_string_lossy();
+
+            assert_snapshot!(
+                "user_doc_snapshot_example_redact",
+                format!("wrote {temp_path}/out.txt\n"),
+                redact = [(temp_path.as_ref(), "[TEMP]")]
+            );
+        }
+    }
+
+    mod polling {
+        use std::{
+            fs,
+            io::Read,
+            sync::{mpsc, Mutex},
+            thread,
+            time::{Duration, Instant},
+        };
+
+        use test_ur_code_xd::utilities::slow_reader::SlowReader;
+
+        #[test]
+        fn example() {
+            let start = Instant::now();
+
+            assert_eventually!(|| start.elapsed() >= Duration::from_millis(10));
+
+            let temp_dir = tempfile::tempdir().unwrap();
+            let path = temp_dir.path().join("server.log");
+
+            let writer_path = path.clone();
+            let writer = thread::spawn(move || fs::write(writer_path, "listening on port 8080"));
+
+            assert_file_eventually_contains!(&path, "listening on port");
+
+            writer.join().unwrap().unwrap();
+        }
+
+        #[test]
+        fn example_timeout() {
+            let start = Instant::now();
+
+            assert_eventually!(
+                || start.elapsed() >= Duration::from_millis(10),
+                timeout = Duration::from_secs(30),
+                interval = Duration::from_millis(5)
+            );
+        }
+
+        #[test]
+        fn example_channels_and_mutexes() {
+            let (sender, receiver) = mpsc::channel();
+            let mutex = Mutex::new(0);
+
+            thread::spawn(move || sender.send("hello"));
+
+            let message = assert_recv_within!(receiver, Duration::from_secs(1));
+            let guard = assert_lock_acquired_within!(mutex, Duration::from_secs(1));
+
+            assert_eq!(message, Some("hello"));
+            assert_eq!(guard.as_deref(), Some(&0));
+        }
+
+        #[test]
+        fn example_slow_operations() {
+            // Each read from this reader takes 10 ms
+            let mut reader = SlowReader::new("hello, world".as_bytes(), Duration::from_millis(10));
+
+            // Ensure that reading everything still finishes in time
+            let text = assert_completes_within!(
+                move || {
+                    let mut text = String::new();
+                    reader.read_to_string(&mut text).unwrap();
+                    text
+                },
+                Duration::from_secs(1)
+            );
+
+            assert_eq!(text.as_deref(), Some("hello, world"));
+        }
+    }
+
+    mod result {
+        #[test]
+        fn example() {
+            let lines = ["1", "2", "3"];
+
+            let numbers = assert_all_ok!(lines.iter().map(|line| line.parse::<i32>()));
+
+            assert_eq!(numbers, vec![1, 2, 3]);
+        }
+
+        #[test]
+        #[should_panic(expected = "all results are ok")]
+        fn example_failing() {
+            let lines = ["1", "x", "3", "y"];
+
+            assert_all_ok!(lines.iter().map(|line| line.parse::<i32>()));
+        }
+    }
+
+    mod fields {
+        use std::time::SystemTime;
+
+        // IDs are never compared in these examples.
+        #[allow(dead_code)]
+        struct User {
+            id: u64,
+            name: String,
+            created_at: SystemTime,
+        }
+
+        struct UserSummary {
+            name: &'static str,
+        }
+
+        #[test]
+        fn example() {
+            let actual = User {
+                id: 17,
+                name: "Sophie".to_owned(),
+                created_at: SystemTime::now(),
+            };
+            let expected = User {
+                id: 0,
+                name: "Sophie".to_owned(),
+                created_at: SystemTime::UNIX_EPOCH,
+            };
+
+            assert_fields_eq!(actual, expected, fields = [name]);
+
+            let summary = UserSummary { name: "Sophie" };
+
+            assert_fields_eq!(actual, summary, fields = [name]);
+        }
+
+        #[test]
+        #[should_panic(expected = "selected fields of actual == selected fields of expected")]
+        fn example_failing() {
+            assert_fields_eq!(
+                User {
+                    id: 1,
+                    name: "Sophie".to_owned(),
+                    created_at: SystemTime::UNIX_EPOCH,
+                },
+                User {
+                    id: 2,
+                    name: "Alice".to_owned(),
+                    created_at: SystemTime::UNIX_EPOCH,
+                },
+                fields = [name, created_at]
+            );
+        }
+    }
+
+    mod map {
+        use std::collections::HashMap;
+
+        #[test]
+        fn example() {
+            let ports = HashMap::from([("http", 80), ("https", 443), ("ssh", 22)]);
+
+            assert_map_values_all!(ports, |port| *port < 1024);
+            assert_map_keys_all!(ports, |name| name.chars().all(|c| c.is_ascii_lowercase()));
+        }
+
+        #[test]
+        #[should_panic(expected = "all map values satisfy predicate")]
+        fn example_failing() {
+            let ports = HashMap::from([("http", 80), ("dev", 8080), ("metrics", 9090)]);
+
+            assert_map_values_all!(ports, |port| *port < 1024);
+        }
+
+        #[test]
+        fn example_eq() {
+            let ports = HashMap::from([("http", 80), ("https", 443)]);
+
+            assert_map_eq!(ports, HashMap::from([("https", 443), ("http", 80)]));
+        }
+
+        #[test]
+        #[should_panic(expected = "actual map == expected map")]
+        fn example_eq_failing() {
+            let ports = HashMap::from([("http", 8080), ("https", 443), ("dev", 3000)]);
+
+            assert_map_eq!(
+                ports,
+                HashMap::from([("http", 80), ("https", 443), ("ssh", 22)])
+            );
+        }
+    }
+
+    mod set {
+        use std::collections::HashSet;
+
+        #[test]
+        fn example() {
+            let granted = HashSet::from(["read", "write"]);
+            let all_permissions = HashSet::from(["read", "write", "delete"]);
+
+            // Ensure that every element of the first set is in the second
+            assert_subset_of!(granted, all_permissions);
+
+            // Ensure that every element of the second set is in the first
+            assert_superset_of!(all_permissions, granted);
+
+            // Ensure that the sets have no elements in common
+            assert_disjoint!(granted, HashSet::from(["admin"]));
+        }
+
+        #[test]
+        #[should_panic(expected = "subset is subset of superset")]
+        fn example_failing() {
+            let granted = HashSet::from(["read", "write", "admin", "sudo"]);
+
+            assert_subset_of!(granted, HashSet::from(["read", "write", "delete"]));
+        }
+    }
+
+    mod sequence {
+        #[test]
+        fn example() {
+            let log = "starting\nloading config\nlistening on port 80\nshutting down";
+
+            assert_contains_in_order!(
+                log.lines(),
+                ["starting", "listening on port 80", "shutting down"]
+            );
+        }
+
+        #[test]
+        fn example_strings() {
+            let events = vec![
+                String::from("connect"),
+                String::from("send"),
+                String::from("disconnect"),
+            ];
+
+            assert_contains_in_order!(&events, ["connect", "disconnect"]);
+        }
+
+        #[test]
+        #[should_panic(expected = "haystack contains expected items in order")]
+        fn example_failing() {
+            let log = "starting\nlistening on port 80\nerror: port in use\nshutting down";
+
+            assert_contains_in_order!(log.lines(), ["starting", "ready", "shutting down"]);
+        }
+
+        #[test]
+        fn example_sorted() {
+            assert_sorted!(vec![1, 2, 2, 3]);
+
+            assert_sorted_by_key!(["a", "bb", "ccc"], |name| name.len());
+        }
+
+        #[test]
+        fn example_sorted_descending() {
+            assert_sorted!(["c", "b", "a"], descending = true);
+
+            assert_sorted_by_key!(["ccc", "bb", "a"], |name| name.len(), descending = true);
+        }
+
+        #[test]
+        #[should_panic(expected = "items are sorted")]
+        fn example_sorted_failing() {
+            assert_sorted!([1, 2, 5, 3]);
+        }
+    }
+
+    mod pattern {
+        #[test]
+        fn example() {
+            let response: Result<u16, String> = Ok(200);
+
+            assert_matches!(response, Ok(_));
+            assert_matches!(response, Ok(status) if status < 400);
+        }
+
+        #[test]
+        fn example_let() {
+            let response: Result<u16, String> = Ok(200);
+
+            assert_let!(Ok(status) = response);
+
+            assert_lt!(status, 400);
+        }
+    }
+
+    mod soft {
+        use test_ur_code_xd::assertions::soft::SoftAssertions;
+
+        fn double(value: i32) -> i32 {
+            value * 2
+        }
+
+        #[test]
+        fn example() {
+            let mut check = SoftAssertions::new();
+
+            for (input, expected) in [(1, 2), (2, 4), (3, 6)] {
+                check.eq(double(input), expected);
+            }
+
+            check.verify();
+        }
+
+        #[test]
+        #[should_panic(expected = "all soft assertions pass")]
+        fn example_failing() {
+            let mut check = SoftAssertions::new();
+
+            for (input, expected) in [(1, 2), (3, 5), (4, 7)] {
+                check.eq(double(input), expected);
+            }
+
+            check.verify();
+        }
+    }
+
+    mod future {
+        use std::{future, rc::Rc, time::Duration};
+
+        async fn fetch() -> i32 {
+            let value = Box::new(42);
+
+            future::ready(()).await;
+
+            *value
+        }
+
+        async fn fetch_rc() -> i32 {
+            let value = Rc::new(42);
+
+            future::ready(()).await;
+
+            *value
+        }
+
+        #[test]
+        fn example() {
+            let _future = assert_send_across_await!(fetch());
+        }
+
+        #[test]
+        #[should_panic(expected = "future is Send")]
+        fn example_failing() {
+            let _future = assert_send_across_await!(fetch_rc());
+        }
+
+        #[test]
+        fn example_polling() {
+            let mut future = Box::pin(fetch());
+
+            assert_eq!(assert_ready!(future), Some(42));
+
+            let mut never = future::pending::<i32>();
+
+            assert_pending!(never);
+        }
+
+        #[test]
+        fn example_resolves_within() {
+            let value = assert_resolves_within!(fetch(), Duration::from_secs(1));
+
+            assert_eq!(value, Some(42));
+        }
+    }
+
+    #[cfg(feature = "allocation")]
+    mod allocation {
+        use test_ur_code_xd::utilities::allocation_counting::count_allocations;
+
+        fn parse_header(input: &str) -> Vec<&str> {
+            input.split(':').map(str::trim).collect()
+        }
+
+        #[test]
+        fn example_no_alloc() {
+            let values = [3, 1, 2];
+
+            assert_no_alloc!(|| values.iter().copied().max());
+        }
+
+        #[test]
+        fn example_allocates_at_most() {
+            let input = "Content-Type: text/plain";
+
+            assert_allocates_at_most!(|| parse_header(input), bytes = 256);
+        }
+
+        #[test]
+        #[should_panic(expected = "action allocates at most 16 bytes")]
+        fn example_allocates_at_most_failure() {
+            let input = "Content-Type: text/plain";
+
+            assert_allocates_at_most!(|| parse_header(input), bytes = 16);
+        }
+
+        #[test]
+        fn example_count_allocations() {
+            let input = "Content-Type: text/plain";
+
+            let (result, counts) = count_allocations(|| parse_header(input));
+
+            assert_eq!(result, ["Content-Type", "text/plain"]);
+            assert_eq!(counts.count, 1);
+        }
+    }
+
+    #[cfg(feature = "http")]
+    mod http {
+        use http::{Response, StatusCode};
+        use serde_json::json;
+
+        fn create_user() -> Response<Vec<u8>> {
+            Response::builder()
+                .status(201)
+                .header("Content-Type", "application/json")
+                .body(br#"{ "id": 5, "name": "Sophie" }"#.to_vec())
+                .unwrap()
+        }
+
+        #[test]
+        fn example() {
+            let response = create_user();
+
+            assert_status_eq!(response, StatusCode::CREATED);
+            assert_status_eq!(response, 201);
+            assert_header_eq!(response, "content-type", "application/json");
+            assert_body_json_matches!(response, json!({ "name": "Sophie" }));
+        }
+
+        #[test]
+        #[should_panic(expected = "response status == expected")]
+        fn example_failure() {
+            assert_status_eq!(create_user(), 200);
+        }
+    }
+
+    #[cfg(feature = "semver")]
+    mod semver {
+        #[test]
+        fn example() {
+            assert_semver_matches!("1.4.0", "^1.2");
+            assert_semver_matches!(env!("CARGO_PKG_VERSION"), ">=0.1, <1");
+            assert_semver_lt!("1.9.0", "1.10.0");
+            assert_semver_lt!("2.0.0-rc.1", "2.0.0");
+        }
+
+        #[test]
+        #[should_panic(expected = "version matches requirement")]
+        fn example_failure() {
+            assert_semver_matches!("2.0.0-beta", "^1.2");
+        }
+    }
+
+    mod matchers {
+        use test_ur_code_xd::{
+            assertions::matcher::{contains, contains_substring, eq, len, within, Matcher},
+            errors::TestUrCodeXDError,
+            utilities::panic_message_builder::PanicMessageBuilder,
+        };
+
+        #[test]
+        fn example() {
+            let numbers = vec![1, 2, 3];
+
+            assert_that!(numbers, contains(2));
+            assert_that!(numbers, len(3));
+            assert_that!(numbers.iter().sum::<i32>(), eq(6));
+            assert_that!("hello, world", contains_substring("world"));
+            assert_that!(0.1 + 0.2, within(0.3, 1e-9));
+        }
+
+        struct IsEven;
+
+        impl Matcher<i32> for IsEven {
+            fn matches(&self, value: &i32) -> bool {
+                value % 2 == 0
+            }
+
+            fn describe(
+                &self,
+                panic_message_builder: PanicMessageBuilder,
+            ) -> Result<PanicMessageBuilder, TestUrCodeXDError> {
+                panic_message_builder.with_argument_formatted("expected", "--", "an even number")
+            }
+        }
+
+        #[test]
+        fn example_custom() {
+            assert_that!(4, IsEven);
+        }
+    }
+
+    mod custom {
+        #[test]
+        fn example() {
+            // Instead of this:
+            let x = 3 + 5;
+            let y = 8;
+            assert_eq!(x, y);
+
+            // We can write this:
+            assert_custom!("lhs == rhs", x == y, |panic_message_builder| {
+                panic_message_builder
+                    .with_argument("lhs", "x", &x)?
+                    .with_argument("rhs", "y", &y)
+            });
+        }
+
+        #[test]
+        fn example_explicit_configuration() {
+            use test_ur_code_xd::assertions::config::Config;
+
+            let x = 3 + 5;
+            let y = 9;
+
+            assert_custom!(
+                "lhs == rhs",
+                x == y,
+                |panic_message_builder| {
+                    panic_message_builder
+                        .with_argument("lhs", "x", &x)?
+                        .with_argument("rhs", "y", &y)
+                },
+                config = Config::new()
+                    .with_negate(true)
+                    .with_description("x and y should differ")
+            );
+        }
+
+        #[test]
+        fn example_testing() {
+            use test_ur_code_xd::utilities::assertion_failure::capture_assertion_failure;
+
+            macro_rules! assert_even {
+                ($value:expr) => {
+                    assert_custom!("value is even", $value % 2 == 0, |panic_message_builder| {
+                        panic_message_builder.with_argument("value", stringify!($value), &$value)
+                    })
+                };
+            }
+
+            let failure = capture_assertion_failure(|| {
+                assert_even!(3);
+            })
+            .unwrap();
+
+            assert_eq!(failure.predicate, "value is even");
+            assert_eq!(failure.argument("value").unwrap().value, "3");
+        }
+
+        #[test]
+        fn example_custom_harness() {
+            use std::panic;
+            use test_ur_code_xd::utilities::assertion_failure::last_assertion_failure;
+
+            fn run_test() {
+                assert_eq!(1 + 1, 3);
+            }
+
+            assert!(panic::catch_unwind(run_test).is_err());
+
+            let failure = last_assertion_failure().unwrap();
+
+            assert_eq!(failure.predicate, "lhs == rhs");
+        }
+    }
+
+    mod configuring_assertions {
+        use num_traits::Float;
+
+        #[test]
+        fn example_negation() {
             assert_str_contains!("hello, world", "asdf", negate = true);
         }
 
@@ -363,6 +1580,95 @@ mod assertions {
                 description_owned = format!("x should be within {} of y", THRESHOLD)
             );
         }
+
+        #[test]
+        fn example_category() {
+            let contents = "hello, world";
+
+            assert_str_eq!(contents, "hello, world", category = "io");
+        }
+
+        #[test]
+        fn example_context() {
+            fn load_balance(_customer_id: u32) -> i32 {
+                0
+            }
+
+            for customer_id in [1, 2, 3] {
+                assertion_context!("customer_id" => customer_id, {
+                    assert_eq!(load_balance(customer_id), 0);
+                });
+            }
+        }
+
+        #[test]
+        fn example_for_each() {
+            #[derive(Debug)]
+            struct Order {
+                id: u32,
+                total: u32,
+            }
+
+            let orders = vec![Order { id: 1, total: 5 }, Order { id: 2, total: 10 }];
+
+            assert_for_each!(orders, |order, _index| {
+                assert_gt!(order.id, 0);
+                assert_gt!(order.total, 0);
+            });
+        }
+
+        #[test]
+        fn example_output_target() {
+            use test_ur_code_xd::utilities::failure_behavior::OutputTarget;
+
+            let x = 1;
+            let y = 1;
+
+            assert_eq!(x, y, output_target = OutputTarget::Stdout);
+        }
+
+        #[test]
+        fn example_failure_behavior_return() {
+            use test_ur_code_xd::utilities::{
+                assertion_failure::take_returned_assertion_failure,
+                failure_behavior::FailureBehavior,
+            };
+
+            let x = 1;
+            let y = 2;
+
+            assert_eq!(x, y, failure_behavior = Some(FailureBehavior::Return));
+
+            if let Some(failure) = take_returned_assertion_failure() {
+                println!("{failure}");
+            }
+        }
+
+        #[test]
+        fn example_try_assert() {
+            use test_ur_code_xd::utilities::assertion_failure::AssertionFailure;
+
+            fn check_sum(values: &[i32], expected: i32) -> Result<(), AssertionFailure> {
+                try_assert!(assert_eq!(values.iter().sum::<i32>(), expected))
+            }
+
+            assert!(check_sum(&[1, 2], 3).is_ok());
+            assert!(check_sum(&[1, 2], 4).is_err());
+        }
+
+        #[test]
+        fn example_try_assertion() {
+            use test_ur_code_xd::utilities::assertion_failure::{try_assertion, AssertionFailure};
+
+            fn check_sum(values: &[i32], expected: i32) -> Result<(), AssertionFailure> {
+                try_assertion(|| {
+                    assert_eq!(values.iter().sum::<i32>(), expected);
+                })
+            }
+
+            assert!(check_sum(&[1, 2], 3).is_ok());
+            assert!(check_sum(&[1, 2], 4).is_err());
+        }
     }
 }
 
@@ -396,5 +1702,364 @@ mod tests {
 
             assert!(z < 0);
         }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = [5, 6, 7],
+            y = [1, 2],
+            name_format = "x_{x}_y_{y}"
+        )]
+        fn example_name_format(x: i32, y: i32) {
+            assert!(x + y > 0);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = [5, 6, 7],
+            y = [1, 2],
+            exclude = [(x = 5, y = 2), (x = 7)]
+        )]
+        fn example_exclude(x: i32, y: i32) {
+            assert!(x + y < 9);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = [5, 6, 7],
+            y = [5, 6, 7],
+            filter = |x, y| x != y
+        )]
+        fn example_filter(x: i32, y: i32) {
+            assert_ne!(x - y, 0);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            input = ["a", "bb", "ccc"],
+            expected_length = [1, 2, 3],
+            mode = zip
+        )]
+        fn example_zip(input: &str, expected_length: usize) {
+            assert_eq!(input.len(), expected_length);
+        }
+
+        const CASES: [i32; 3] = [5, 6, 7];
+
+        fn generate_names() -> Vec<String> {
+            vec!["Sophie".to_owned(), "Gorbie".to_owned()]
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = from(CASES),
+            name = from(generate_names()),
+            y = [1, 2]
+        )]
+        fn example_from(x: i32, name: String, y: i32) {
+            assert!(x + y > 0);
+            assert!(!name.is_empty());
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = boundaries(i32),
+            flag = boundaries(bool)
+        )]
+        fn example_boundaries(x: i32, flag: bool) {
+            assert_eq!(x.checked_abs().is_none(), x == i32::MIN);
+            assert_eq!(u8::from(flag) == 1, flag);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = 0..3,
+            y = -1..=1
+        )]
+        fn example_range(x: u8, y: i8) {
+            assert!(i16::from(x) + i16::from(y) >= -1);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            (a, b, expected) = [(1, 2, 3), (2, 3, 5)],
+            c = [0, 1]
+        )]
+        fn example_cases(a: i32, b: i32, expected: i32, c: i32) {
+            assert_eq!(a + b + c, expected + c);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = 0..20,
+            y = 0..15,
+            max_permutations = 300
+        )]
+        fn example_max_permutations(x: u8, y: u8) {
+            assert!(u16::from(x) * u16::from(y) < 300);
+        }
+
+        mod cases {
+            pub const POSITIVE: &[i32] = &[1, 2, 3];
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(x = cases::POSITIVE)]
+        fn example_path(x: i32) {
+            assert!(x > 0);
+        }
+
+        // WARNING: Rust Analyzer displays a false negative error here. This is due to a bug in Rust
+        //          Analyzer, not an actual issue with the code.
+        //
+        //          See https://github.com/rust-lang/rust-analyzer/issues/12450 for more info.
+        #[test_with_parameter_values(
+            x = [5, 6, 7],
+            y = ["a", "b"]
+        )]
+        fn example_generic<T: PartialOrd + From<u8>>(x: T, y: impl AsRef<str>) {
+            assert!(x > T::from(0));
+            assert!(!y.as_ref().is_empty());
+        }
+    }
+
+    mod random_values {
+        use test_ur_code_xd::utilities::random_values::{range, Rng, Strategy};
+
+        #[test_with_random_values(
+            x: i32 = range(0..100),
+            y: f64 = range(0.0..1.0)
+        )]
+        fn example(x: i32, y: f64) {
+            assert!(f64::from(x) + y >= 0.0);
+        }
+
+        #[test_with_random_values(x = range(0..100))]
+        fn example_untyped(x: i32) {
+            assert!(x >= 0);
+        }
+
+        #[test_with_random_values(x: i32 = range(0..100), seed = 42, cases = 1000)]
+        fn example_options(x: i32) {
+            assert!(x >= 0);
+        }
+
+        #[test_with_random_values(x: i32 = range(0..100))]
+        #[should_panic(expected = "test passes for randomly generated values")]
+        fn example_failure(x: i32) {
+            assert!(x < 50);
+        }
+
+        #[test_with_seed]
+        fn example_seed(rng: &mut Rng) {
+            let mut values: Vec<i32> = (0..10).map(|_| range(0..100).generate(rng)).collect();
+
+            values.sort_unstable();
+
+            assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+
+        #[test_with_seed(seed = 42)]
+        fn example_seed_fixed(rng: &mut Rng) {
+            assert_eq!(rng.next_u64(), Rng::new(42).next_u64());
+        }
+    }
+
+    mod fixtures {
+        use test_ur_code_xd::utilities::lazy_fixture::teardown_lazy_fixtures;
+
+        fn load_words() -> Vec<String> {
+            vec!["hello".to_owned(), "world".to_owned()]
+        }
+
+        lazy_fixture! {
+            static WORDS: Vec<String> = load_words();
+        }
+
+        #[test]
+        fn example() {
+            let words = WORDS.get();
+
+            assert!(!words.is_empty());
+        }
+
+        #[test]
+        fn example_teardown() {
+            lazy_fixture! {
+                static LETTERS: Vec<char> = vec!['a', 'b'];
+            }
+
+            let _letters = LETTERS.get();
+
+            teardown_lazy_fixtures();
+
+            assert_not!(LETTERS.is_initialized());
+        }
+    }
+
+    mod retries {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static ATTEMPT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        #[test_with_retries(count = 3, delay_ms = 10)]
+        fn example() {
+            let attempt_number = ATTEMPT_COUNT.fetch_add(1, Ordering::SeqCst) + 1;
+
+            // Fails the first two times it is run
+            assert!(attempt_number >= 3);
+        }
+
+        #[test_with_retries(count = 2)]
+        #[should_panic(expected = "value is true")]
+        fn example_failure() {
+            assert!(false);
+        }
+    }
+
+    mod timeouts {
+        use std::{thread, time::Duration};
+
+        #[test_with_timeout(ms = 1000)]
+        fn example() {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        #[test_with_timeout(ms = 10)]
+        #[should_panic(expected = "test timed out after 10 ms")]
+        fn example_failure() {
+            thread::sleep(Duration::from_secs(1));
+        }
+    }
+
+    mod subtests {
+        use test_ur_code_xd::utilities::subtest::current_subtest_path;
+
+        #[test]
+        fn example() {
+            for (input, expected) in [(1, 2), (2, 4), (3, 6)] {
+                subtest!(format!("doubles {input}"), || {
+                    assert_eq!(input * 2, expected);
+                });
+            }
+        }
+
+        #[test]
+        fn example_nested() {
+            subtest!("parsing", || {
+                subtest!("empty input", || {
+                    assert_eq!(
+                        current_subtest_path(),
+                        Some("parsing / empty input".to_owned())
+                    );
+                });
+            });
+        }
+    }
+
+    mod setup_and_teardown {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static EVENTS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+        }
+
+        fn create_database() {
+            EVENTS.with(|events| events.borrow_mut().push("create"));
+        }
+
+        fn drop_database() {
+            EVENTS.with(|events| events.borrow_mut().push("drop"));
+        }
+
+        #[test_with_setup(setup = create_database, teardown = drop_database)]
+        fn example() {
+            EVENTS.with(|events| assert_eq!(*events.borrow(), vec!["create"]));
+        }
+
+        #[test_with_setup(teardown = drop_database)]
+        #[should_panic(expected = "value is true")]
+        fn example_failure() {
+            assert!(false);
+        }
+
+        #[test_suite]
+        mod example_suite {
+            use super::EVENTS;
+
+            fn setup() {
+                EVENTS.with(|events| events.borrow_mut().push("setup"));
+            }
+
+            fn teardown() {
+                EVENTS.with(|events| events.borrow_mut().push("teardown"));
+            }
+
+            #[test]
+            fn first() {
+                EVENTS.with(|events| assert_eq!(*events.borrow(), vec!["setup"]));
+            }
+
+            #[test]
+            fn second() -> Result<(), String> {
+                EVENTS.with(|events| assert_eq!(*events.borrow(), vec!["setup"]));
+
+                Ok(())
+            }
+        }
+    }
+}
+
+mod extending {
+    mod writing_your_own_assertions {
+        use std::panic::Location;
+        use test_ur_code_xd::utilities::{
+            normalize::normalize_panic_message,
+            panic_message_builder::{MessageType, PanicMessageBuilder},
+        };
+
+        #[test]
+        fn example_normalize() {
+            let message = PanicMessageBuilder::new(
+                MessageType::AssertionFailure,
+                "value is even",
+                Location::caller(),
+            )
+            .format();
+
+            assert_str_eq!(
+                normalize_panic_message(&message),
+                "\u{26CC} assertion failure at <file>:<line>:<column>: value is even\n\n<backtrace>"
+            );
+        }
     }
 }